@@ -1,5 +1,17 @@
+pub mod api_key_repository;
+pub mod authorization_code_repository;
+pub mod device_authorization_repository;
+pub mod external_identity_repository;
+pub mod federated_authorization_repository;
+pub mod group_repository;
+pub mod identity_provider;
+pub mod impersonation_repository;
 pub mod keypair_repository;
+pub mod legacy_authenticator;
+pub mod oauth_client_repository;
 pub mod random_service;
+pub mod role_repository;
 pub mod session_repository;
+pub mod signup_notifier;
 pub mod time_service;
 pub mod user_repository;
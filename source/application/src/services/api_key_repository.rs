@@ -0,0 +1,23 @@
+use nimbus_auth_domain::{
+    entities::{api_key::ApiKey, user::User},
+    value_objects::identifier::Identifier,
+};
+use nimbus_auth_shared::futures::StaticPinnedFuture;
+use ulid::Ulid;
+
+use crate::services::api_key_repository::errors::ApiKeyRepositoryError;
+
+pub mod errors;
+
+pub trait ApiKeyRepository: Send + Sync {
+    fn get_by_id(
+        &self,
+        id: &Identifier<Ulid, ApiKey>,
+    ) -> StaticPinnedFuture<Option<ApiKey>, ApiKeyRepositoryError>;
+    /// Revoked and expired keys are included, ordered by creation time
+    fn get_by_user_id(
+        &self,
+        user_id: &Identifier<Ulid, User>,
+    ) -> StaticPinnedFuture<Vec<ApiKey>, ApiKeyRepositoryError>;
+    fn save(&self, api_key: &ApiKey) -> StaticPinnedFuture<(), ApiKeyRepositoryError>;
+}
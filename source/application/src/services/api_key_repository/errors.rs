@@ -0,0 +1,10 @@
+use nimbus_auth_shared::errors::ErrorBoxed;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ApiKeyRepositoryError {
+    #[error("can not restore api key from db. Error: {0}")]
+    ApiKeyRestoration(#[source] ErrorBoxed),
+    #[error(transparent)]
+    Other(#[from] ErrorBoxed),
+}
@@ -0,0 +1,21 @@
+use nimbus_auth_domain::{
+    entities::authorization_code::AuthorizationCode, value_objects::identifier::Identifier,
+};
+use nimbus_auth_shared::futures::StaticPinnedFuture;
+use ulid::Ulid;
+
+use crate::services::authorization_code_repository::errors::AuthorizationCodeRepositoryError;
+
+pub mod errors;
+
+pub trait AuthorizationCodeRepository: Send + Sync {
+    fn save(
+        &self,
+        code: &AuthorizationCode,
+    ) -> StaticPinnedFuture<(), AuthorizationCodeRepositoryError>;
+    /// Removes the code while returning it, so concurrent exchanges can not both get it
+    fn take(
+        &self,
+        id: &Identifier<Ulid, AuthorizationCode>,
+    ) -> StaticPinnedFuture<Option<AuthorizationCode>, AuthorizationCodeRepositoryError>;
+}
@@ -0,0 +1,10 @@
+use nimbus_auth_shared::errors::ErrorBoxed;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum AuthorizationCodeRepositoryError {
+    #[error("can not restore authorization code from db. Error: {0}")]
+    AuthorizationCodeRestoration(#[source] ErrorBoxed),
+    #[error(transparent)]
+    Other(#[from] ErrorBoxed),
+}
@@ -0,0 +1,31 @@
+use nimbus_auth_domain::{
+    entities::device_authorization::{DeviceAuthorization, value_objects::user_code::UserCode},
+    value_objects::identifier::Identifier,
+};
+use nimbus_auth_shared::futures::StaticPinnedFuture;
+use ulid::Ulid;
+
+use crate::services::device_authorization_repository::errors::DeviceAuthorizationRepositoryError;
+
+pub mod errors;
+
+pub trait DeviceAuthorizationRepository: Send + Sync {
+    fn get_by_id(
+        &self,
+        id: &Identifier<Ulid, DeviceAuthorization>,
+    ) -> StaticPinnedFuture<Option<DeviceAuthorization>, DeviceAuthorizationRepositoryError>;
+    /// User codes are short, so they may repeat across expired authorizations, the latest one is returned
+    fn get_by_user_code(
+        &self,
+        user_code: &UserCode,
+    ) -> StaticPinnedFuture<Option<DeviceAuthorization>, DeviceAuthorizationRepositoryError>;
+    fn save(
+        &self,
+        device_authorization: &DeviceAuthorization,
+    ) -> StaticPinnedFuture<(), DeviceAuthorizationRepositoryError>;
+    /// Removes the authorization while returning it, so concurrent polls can not both get tokens
+    fn take(
+        &self,
+        id: &Identifier<Ulid, DeviceAuthorization>,
+    ) -> StaticPinnedFuture<Option<DeviceAuthorization>, DeviceAuthorizationRepositoryError>;
+}
@@ -0,0 +1,10 @@
+use nimbus_auth_shared::errors::ErrorBoxed;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum DeviceAuthorizationRepositoryError {
+    #[error("can not restore device authorization from db. Error: {0}")]
+    DeviceAuthorizationRestoration(#[source] ErrorBoxed),
+    #[error(transparent)]
+    Other(#[from] ErrorBoxed),
+}
@@ -0,0 +1,33 @@
+use nimbus_auth_domain::{
+    entities::{external_identity::ExternalIdentity, user::User},
+    value_objects::identifier::Identifier,
+};
+use nimbus_auth_shared::futures::StaticPinnedFuture;
+use ulid::Ulid;
+
+use crate::services::external_identity_repository::errors::ExternalIdentityRepositoryError;
+
+pub mod errors;
+
+/// Identities of users at upstream providers, each one is linked to a single user
+/// and a user has at most one identity per provider
+pub trait ExternalIdentityRepository: Send + Sync {
+    fn get_by_subject(
+        &self,
+        provider: &str,
+        subject: &str,
+    ) -> StaticPinnedFuture<Option<ExternalIdentity>, ExternalIdentityRepositoryError>;
+    /// Ordered by linking time
+    fn get_by_user_id(
+        &self,
+        user_id: &Identifier<Ulid, User>,
+    ) -> StaticPinnedFuture<Vec<ExternalIdentity>, ExternalIdentityRepositoryError>;
+    fn save(
+        &self,
+        identity: &ExternalIdentity,
+    ) -> StaticPinnedFuture<(), ExternalIdentityRepositoryError>;
+    fn delete(
+        &self,
+        identity: &ExternalIdentity,
+    ) -> StaticPinnedFuture<(), ExternalIdentityRepositoryError>;
+}
@@ -0,0 +1,10 @@
+use nimbus_auth_shared::errors::ErrorBoxed;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ExternalIdentityRepositoryError {
+    #[error("can not restore external identity from db. Error: {0}")]
+    ExternalIdentityRestoration(#[source] ErrorBoxed),
+    #[error(transparent)]
+    Other(#[from] ErrorBoxed),
+}
@@ -0,0 +1,22 @@
+use nimbus_auth_domain::{
+    entities::federated_authorization::FederatedAuthorization,
+    value_objects::identifier::Identifier,
+};
+use nimbus_auth_shared::futures::StaticPinnedFuture;
+use ulid::Ulid;
+
+use crate::services::federated_authorization_repository::errors::FederatedAuthorizationRepositoryError;
+
+pub mod errors;
+
+pub trait FederatedAuthorizationRepository: Send + Sync {
+    fn save(
+        &self,
+        authorization: &FederatedAuthorization,
+    ) -> StaticPinnedFuture<(), FederatedAuthorizationRepositoryError>;
+    /// Removes the authorization while returning it, so its state can be redeemed only once
+    fn take(
+        &self,
+        id: &Identifier<Ulid, FederatedAuthorization>,
+    ) -> StaticPinnedFuture<Option<FederatedAuthorization>, FederatedAuthorizationRepositoryError>;
+}
@@ -0,0 +1,10 @@
+use nimbus_auth_shared::errors::ErrorBoxed;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum FederatedAuthorizationRepositoryError {
+    #[error("can not restore federated authorization from db. Error: {0}")]
+    FederatedAuthorizationRestoration(#[source] ErrorBoxed),
+    #[error(transparent)]
+    Other(#[from] ErrorBoxed),
+}
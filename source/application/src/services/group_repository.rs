@@ -0,0 +1,23 @@
+use nimbus_auth_domain::entities::group::{Group, value_objects::group_name::GroupName};
+use nimbus_auth_shared::futures::StaticPinnedFuture;
+
+use crate::services::group_repository::errors::GroupRepositoryError;
+
+pub mod errors;
+
+pub trait GroupRepository: Send + Sync {
+    fn get_by_name(
+        &self,
+        name: &GroupName,
+    ) -> StaticPinnedFuture<Option<Group>, GroupRepositoryError>;
+    /// Unknown names are skipped
+    fn get_by_names(
+        &self,
+        names: &[GroupName],
+    ) -> StaticPinnedFuture<Vec<Group>, GroupRepositoryError>;
+    /// Groups are listed ordered by name
+    fn list(&self) -> StaticPinnedFuture<Vec<Group>, GroupRepositoryError>;
+    fn save(&self, group: &Group) -> StaticPinnedFuture<(), GroupRepositoryError>;
+    /// All members leave the group
+    fn delete(&self, group: &Group) -> StaticPinnedFuture<(), GroupRepositoryError>;
+}
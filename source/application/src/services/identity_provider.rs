@@ -0,0 +1,32 @@
+use nimbus_auth_domain::{
+    entities::authorization_code::value_objects::code_challenge::CodeChallenge,
+    value_objects::id_token::IdTokenKeys,
+};
+use nimbus_auth_shared::futures::StaticPinnedFuture;
+
+use crate::services::identity_provider::errors::IdentityProviderError;
+
+pub mod errors;
+
+/// Upstream OpenID Connect provider users sign in with by the authorization code flow with PKCE
+pub trait IdentityProvider: Send + Sync {
+    fn name(&self) -> &str;
+    /// Issuer ID tokens of the provider are expected from
+    fn issuer(&self) -> &str;
+    /// Audience of ID tokens issued to this service
+    fn client_id(&self) -> &str;
+    /// Page of the provider the user is sent to, it redirects back with a code and the state
+    fn get_authorization_url(
+        &self,
+        state: &str,
+        nonce: &str,
+        code_challenge: &CodeChallenge,
+    ) -> StaticPinnedFuture<String, IdentityProviderError>;
+    /// Redeems the code at the token endpoint of the provider, returns the signed ID token
+    fn exchange_code(
+        &self,
+        code: &str,
+        code_verifier: &str,
+    ) -> StaticPinnedFuture<String, IdentityProviderError>;
+    fn get_signing_keys(&self) -> StaticPinnedFuture<IdTokenKeys, IdentityProviderError>;
+}
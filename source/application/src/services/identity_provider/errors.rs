@@ -0,0 +1,12 @@
+use nimbus_auth_shared::errors::ErrorBoxed;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum IdentityProviderError {
+    #[error("identity provider rejected the authorization code")]
+    CodeIsRejected,
+    #[error("identity provider discovery names issuer {actual}, expected {expected}")]
+    IssuerMismatch { expected: String, actual: String },
+    #[error(transparent)]
+    Other(#[from] ErrorBoxed),
+}
@@ -0,0 +1,14 @@
+use nimbus_auth_domain::entities::impersonation::Impersonation;
+use nimbus_auth_shared::futures::StaticPinnedFuture;
+
+use crate::services::impersonation_repository::errors::ImpersonationRepositoryError;
+
+pub mod errors;
+
+/// Audit trail of impersonations, records are only ever added
+pub trait ImpersonationRepository: Send + Sync {
+    fn save(
+        &self,
+        impersonation: &Impersonation,
+    ) -> StaticPinnedFuture<(), ImpersonationRepositoryError>;
+}
@@ -18,6 +18,10 @@ pub trait KeyPairRepository: Send + Sync {
         id: &Identifier<Ulid, SomeKeyPair<'static>>,
     ) -> StaticPinnedFuture<Option<SomeKeyPair<'static>>, KeyPairRepositoryError>;
     fn get_active(&self) -> StaticPinnedFuture<Option<KeyPair<Active>>, KeyPairRepositoryError>;
+    /// Returns active and expiring key pairs, which verify access tokens
+    fn get_verifying(
+        &self,
+    ) -> StaticPinnedFuture<Vec<SomeKeyPair<'static>>, KeyPairRepositoryError>;
     fn save(&self, keypair: SomeKeyPair) -> StaticPinnedFuture<(), KeyPairRepositoryError>;
 }
 
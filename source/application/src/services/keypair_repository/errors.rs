@@ -1,4 +1,12 @@
+use nimbus_auth_shared::errors::ErrorBoxed;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
-pub enum KeyPairRepositoryError {}
+pub enum KeyPairRepositoryError {
+    #[error("can not restore key pair. Error: {0}")]
+    KeyPairRestoration(#[source] ErrorBoxed),
+    #[error("key pair: {key_id} is not stored, so it can not change its state")]
+    KeyPairIsNotStored { key_id: String },
+    #[error(transparent)]
+    Other(#[from] ErrorBoxed),
+}
@@ -0,0 +1,18 @@
+use nimbus_auth_domain::entities::user::value_objects::{password::Password, user_name::UserName};
+use nimbus_auth_shared::futures::StaticPinnedFuture;
+
+use crate::services::legacy_authenticator::errors::LegacyAuthenticatorError;
+
+pub mod errors;
+
+/// Legacy authentication backend used to migrate users just in time on their first signin
+///
+/// With user enumeration protection it is asked about migrated users too, its answer is ignored then
+pub trait LegacyAuthenticator: Send + Sync {
+    /// Returns `true` only if the user exists in legacy backend and the password matches
+    fn authenticate(
+        &self,
+        user_name: &UserName,
+        password: &Password,
+    ) -> StaticPinnedFuture<bool, LegacyAuthenticatorError>;
+}
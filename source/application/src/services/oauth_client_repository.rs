@@ -0,0 +1,17 @@
+use nimbus_auth_domain::{
+    entities::oauth_client::OAuthClient, value_objects::identifier::Identifier,
+};
+use nimbus_auth_shared::futures::StaticPinnedFuture;
+use ulid::Ulid;
+
+use crate::services::oauth_client_repository::errors::OAuthClientRepositoryError;
+
+pub mod errors;
+
+pub trait OAuthClientRepository: Send + Sync {
+    fn get_by_id(
+        &self,
+        id: &Identifier<Ulid, OAuthClient>,
+    ) -> StaticPinnedFuture<Option<OAuthClient>, OAuthClientRepositoryError>;
+    fn save(&self, client: &OAuthClient) -> StaticPinnedFuture<(), OAuthClientRepositoryError>;
+}
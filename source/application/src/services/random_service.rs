@@ -1,3 +1,4 @@
+use nimbus_auth_domain::entities::device_authorization::value_objects::user_code::UserCode;
 use nimbus_auth_shared::futures::StaticPinnedFuture;
 use zeroize::Zeroizing;
 
@@ -10,4 +11,7 @@ pub trait RandomService: Send + Sync {
         &self,
     ) -> StaticPinnedFuture<Zeroizing<String>, RandomServiceError>;
     fn get_random_salt_b64(&self) -> StaticPinnedFuture<String, RandomServiceError>;
+    /// Secrets are shown once when they are issued, only their hashes are stored
+    fn get_random_secret(&self) -> StaticPinnedFuture<Zeroizing<String>, RandomServiceError>;
+    fn get_random_user_code(&self) -> StaticPinnedFuture<UserCode, RandomServiceError>;
 }
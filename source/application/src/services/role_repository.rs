@@ -0,0 +1,22 @@
+use nimbus_auth_domain::entities::role::{Role, value_objects::role_name::RoleName};
+use nimbus_auth_shared::futures::StaticPinnedFuture;
+
+use crate::services::role_repository::errors::RoleRepositoryError;
+
+pub mod errors;
+
+pub trait RoleRepository: Send + Sync {
+    fn get_by_name(&self, name: &RoleName)
+    -> StaticPinnedFuture<Option<Role>, RoleRepositoryError>;
+    /// Unknown names are skipped
+    fn get_by_names(
+        &self,
+        names: &[RoleName],
+    ) -> StaticPinnedFuture<Vec<Role>, RoleRepositoryError>;
+    /// Roles are listed ordered by name
+    fn list(&self) -> StaticPinnedFuture<Vec<Role>, RoleRepositoryError>;
+    /// Creates the role or replaces its permissions
+    fn save(&self, role: &Role) -> StaticPinnedFuture<(), RoleRepositoryError>;
+    /// Role is unassigned from all users
+    fn delete(&self, role: &Role) -> StaticPinnedFuture<(), RoleRepositoryError>;
+}
@@ -1,11 +1,22 @@
-use nimbus_auth_domain::{entities::session::SomeSession, value_objects::identifier::Identifier};
+use nimbus_auth_domain::{
+    entities::{
+        session::{Active, Session, SomeSession},
+        user::User,
+    },
+    value_objects::identifier::Identifier,
+};
 use nimbus_auth_shared::futures::StaticPinnedFuture;
+use time::OffsetDateTime;
 use ulid::Ulid;
 
 use crate::services::session_repository::errors::SessionRepositoryError;
 
 pub mod errors;
 
+/// Result of a query in a transaction, the transaction is handed back along with it
+pub type SessionTransactionFuture<T> =
+    StaticPinnedFuture<(Box<dyn SessionRepositoryWithTransaction>, T), SessionRepositoryError>;
+
 pub trait SessionRepository: Send + Sync {
     fn start_transaction(
         &self,
@@ -14,6 +25,12 @@ pub trait SessionRepository: Send + Sync {
         &self,
         id: &Identifier<Ulid, SomeSession<'static>>,
     ) -> StaticPinnedFuture<Option<SomeSession<'static>>, SessionRepositoryError>;
+    /// Sessions expired at `current_time` are left out
+    fn get_active_by_user_id(
+        &self,
+        user_id: &Identifier<Ulid, User>,
+        current_time: OffsetDateTime,
+    ) -> StaticPinnedFuture<Vec<Session<Active>>, SessionRepositoryError>;
     fn save(&self, session: SomeSession) -> StaticPinnedFuture<(), SessionRepositoryError>;
 }
 
@@ -23,15 +40,12 @@ pub trait SessionRepositoryWithTransaction: Send + Sync {
     fn get_by_id(
         self: Box<Self>,
         id: &Identifier<Ulid, SomeSession<'static>>,
-    ) -> StaticPinnedFuture<
-        (
-            Box<dyn SessionRepositoryWithTransaction>,
-            Option<SomeSession<'static>>,
-        ),
-        SessionRepositoryError,
-    >;
-    fn save(
+    ) -> SessionTransactionFuture<Option<SomeSession<'static>>>;
+    /// Sessions expired at `current_time` are left out
+    fn get_active_by_user_id(
         self: Box<Self>,
-        session: SomeSession,
-    ) -> StaticPinnedFuture<(Box<dyn SessionRepositoryWithTransaction>, ()), SessionRepositoryError>;
+        user_id: &Identifier<Ulid, User>,
+        current_time: OffsetDateTime,
+    ) -> SessionTransactionFuture<Vec<Session<Active>>>;
+    fn save(self: Box<Self>, session: SomeSession) -> SessionTransactionFuture<()>;
 }
@@ -0,0 +1,21 @@
+use nimbus_auth_domain::entities::user::value_objects::user_name::UserName;
+use nimbus_auth_shared::futures::StaticPinnedFuture;
+
+use crate::services::signup_notifier::errors::SignUpNotifierError;
+
+pub mod errors;
+
+/// Outcome of a signup which is not returned to the client when user enumeration protection is enabled
+#[derive(Clone, Debug)]
+pub enum SignUpNotification {
+    UserCreated { user_name: UserName },
+    UserAlreadyExists { user_name: UserName },
+}
+
+/// Delivers signup outcomes out of band, e.g. to a service sending emails to the owner of the user name
+pub trait SignUpNotifier: Send + Sync {
+    fn notify(
+        &self,
+        notification: SignUpNotification,
+    ) -> StaticPinnedFuture<(), SignUpNotifierError>;
+}
@@ -0,0 +1,8 @@
+use nimbus_auth_shared::errors::ErrorBoxed;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum SignUpNotifierError {
+    #[error(transparent)]
+    Other(#[from] ErrorBoxed),
+}
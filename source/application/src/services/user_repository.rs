@@ -1,17 +1,31 @@
 use nimbus_auth_domain::{
     entities::{
+        role::value_objects::role_name::RoleName,
+        external_identity::ExternalIdentity,
         session::{Active, Session},
-        user::{User, value_objects::user_name::UserName},
+        user::{SomeUser, User, value_objects::user_name::UserName},
     },
     value_objects::identifier::Identifier,
 };
-use nimbus_auth_shared::futures::StaticPinnedFuture;
+use nimbus_auth_shared::{futures::StaticPinnedFuture, types::UserStatus};
 use ulid::Ulid;
 
 use crate::services::user_repository::errors::UserRepositoryError;
 
 pub mod errors;
 
+/// Users are listed ordered by id, which follows the order of their creation
+pub struct UserListFilter {
+    /// Case insensitive part of the user name
+    pub name_query: Option<String>,
+    /// Only users holding the role are listed
+    pub role: Option<RoleName>,
+    pub status: Option<UserStatus>,
+    /// Listing starts right after the user with this id
+    pub after_id: Option<Identifier<Ulid, User>>,
+    pub limit: usize,
+}
+
 pub trait UserRepository: Send + Sync {
     fn start_transaction(
         &self,
@@ -19,16 +33,21 @@ pub trait UserRepository: Send + Sync {
     fn get_by_id(
         &self,
         id: &Identifier<Ulid, User>,
-    ) -> StaticPinnedFuture<Option<User>, UserRepositoryError>;
+    ) -> StaticPinnedFuture<Option<SomeUser<'static>>, UserRepositoryError>;
     fn get_by_name(
         &self,
         user_name: &UserName,
-    ) -> StaticPinnedFuture<Option<User>, UserRepositoryError>;
+    ) -> StaticPinnedFuture<Option<SomeUser<'static>>, UserRepositoryError>;
+    /// Returns the user only if it is active
     fn get_by_session(
         &self,
         session: &Session<Active>,
     ) -> StaticPinnedFuture<Option<User>, UserRepositoryError>;
-    fn save(&self, user: &User) -> StaticPinnedFuture<(), UserRepositoryError>;
+    fn list(
+        &self,
+        filter: &UserListFilter,
+    ) -> StaticPinnedFuture<Vec<SomeUser<'static>>, UserRepositoryError>;
+    fn save(&self, user: SomeUser) -> StaticPinnedFuture<(), UserRepositoryError>;
 }
 
 pub trait UserRepositoryWithTransaction: Send + Sync {
@@ -38,16 +57,24 @@ pub trait UserRepositoryWithTransaction: Send + Sync {
         self: Box<Self>,
         id: &Identifier<Ulid, User>,
     ) -> StaticPinnedFuture<
-        (Box<dyn UserRepositoryWithTransaction>, Option<User>),
+        (
+            Box<dyn UserRepositoryWithTransaction>,
+            Option<SomeUser<'static>>,
+        ),
         UserRepositoryError,
     >;
+    /// Locks the user until the transaction ends
     fn get_by_name(
         self: Box<Self>,
         user_name: &UserName,
     ) -> StaticPinnedFuture<
-        (Box<dyn UserRepositoryWithTransaction>, Option<User>),
+        (
+            Box<dyn UserRepositoryWithTransaction>,
+            Option<SomeUser<'static>>,
+        ),
         UserRepositoryError,
     >;
+    /// Returns the user only if it is active
     fn get_by_session(
         self: Box<Self>,
         session: &Session<Active>,
@@ -55,8 +82,24 @@ pub trait UserRepositoryWithTransaction: Send + Sync {
         (Box<dyn UserRepositoryWithTransaction>, Option<User>),
         UserRepositoryError,
     >;
+    /// Locks the role of the filter until the transaction ends, so concurrent listings of its holders wait for it
+    fn list(
+        self: Box<Self>,
+        filter: &UserListFilter,
+    ) -> StaticPinnedFuture<
+        (
+            Box<dyn UserRepositoryWithTransaction>,
+            Vec<SomeUser<'static>>,
+        ),
+        UserRepositoryError,
+    >;
     fn save(
         self: Box<Self>,
-        user: &User,
+        user: SomeUser,
+    ) -> StaticPinnedFuture<(Box<dyn UserRepositoryWithTransaction>, ()), UserRepositoryError>;
+    /// Identity is saved along with its user, so a provisioned user is never left without its link
+    fn save_external_identity(
+        self: Box<Self>,
+        identity: &ExternalIdentity,
     ) -> StaticPinnedFuture<(Box<dyn UserRepositoryWithTransaction>, ()), UserRepositoryError>;
 }
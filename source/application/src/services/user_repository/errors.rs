@@ -7,6 +7,8 @@ pub enum UserRepositoryError {
     UserRestoration(#[source] ErrorBoxed),
     #[error("session is not found")]
     SessionIsNotFound,
+    #[error("user name is taken by another user")]
+    UserNameIsTaken,
     #[error(transparent)]
     Other(#[from] ErrorBoxed),
 }
@@ -1,23 +1,72 @@
-use nimbus_auth_shared::types::{AccessTokenExpirationSeconds, SessionExpirationSeconds};
+use nimbus_auth_domain::{
+    entities::user::value_objects::password_hash::PasswordHash,
+    value_objects::{
+        breached_passwords_filter::BreachedPasswordsFilter, password_peppers::PasswordPeppers,
+    },
+};
+use nimbus_auth_shared::types::{
+    AccessTokenAttributes, AccessTokenExpirationSeconds, AccessTokenMaxGroups,
+    PasswordHashingParams, PasswordPolicy, Realm, SessionExpirationSeconds, SigninLockoutPolicy,
+};
 
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use crate::{
     services::{
-        keypair_repository::KeyPairRepository, random_service::RandomService,
-        session_repository::SessionRepository, time_service::TimeService,
-        user_repository::UserRepository,
+        api_key_repository::ApiKeyRepository,
+        authorization_code_repository::AuthorizationCodeRepository,
+        device_authorization_repository::DeviceAuthorizationRepository,
+        external_identity_repository::ExternalIdentityRepository,
+        federated_authorization_repository::FederatedAuthorizationRepository,
+        group_repository::GroupRepository, identity_provider::IdentityProvider,
+        impersonation_repository::ImpersonationRepository, keypair_repository::KeyPairRepository,
+        legacy_authenticator::LegacyAuthenticator, oauth_client_repository::OAuthClientRepository,
+        random_service::RandomService, role_repository::RoleRepository,
+        session_repository::SessionRepository, signup_notifier::SignUpNotifier,
+        time_service::TimeService, user_repository::UserRepository,
     },
     use_cases::{
-        authorize::handle_authorize, get_public_key::handle_get_public_key,
-        refresh::handle_refresh, rotate_keypairs::handle_rotate_keypairs, signin::handle_signin,
-        signup::handle_signup,
+        approve_device_authorization::handle_approve_device_authorization,
+        authorize::handle_authorize, authorize_api_key::handle_authorize_api_key,
+        bootstrap_admin::handle_bootstrap_admin, change_user_groups::handle_change_user_groups,
+        change_user_roles::handle_change_user_roles, create_api_key::handle_create_api_key,
+        create_group::handle_create_group, create_user::handle_create_user,
+        delete_group::handle_delete_group, delete_role::handle_delete_role,
+        delete_user::handle_delete_user, federated_authorize::handle_federated_authorize,
+        federated_signin::handle_federated_signin, get_public_key::handle_get_public_key,
+        get_user::handle_get_user, get_user_signin_lockout::handle_get_user_signin_lockout,
+        impersonate_user::handle_impersonate_user, import_users::handle_import_users,
+        link_external_identity::handle_link_external_identity, list_api_keys::handle_list_api_keys,
+        list_external_identities::handle_list_external_identities, list_groups::handle_list_groups,
+        list_public_keys::handle_list_public_keys, list_roles::handle_list_roles,
+        list_user_sessions::handle_list_user_sessions, list_users::handle_list_users,
+        oauth_authorize::handle_oauth_authorize,
+        oauth_device_authorize::handle_oauth_device_authorize, oauth_token::handle_oauth_token,
+        put_role::handle_put_role, refresh::handle_refresh,
+        register_oauth_client::handle_register_oauth_client,
+        reset_user_signin_lockout::handle_reset_user_signin_lockout,
+        revoke_api_key::handle_revoke_api_key, revoke_keypair::handle_revoke_keypair,
+        revoke_user_sessions::handle_revoke_user_sessions, rotate_keypairs::handle_rotate_keypairs,
+        signin::handle_signin, signup::handle_signup, suspend_user::handle_suspend_user,
+        unlink_external_identity::handle_unlink_external_identity,
+        unsuspend_user::handle_unsuspend_user,
+        update_user_attributes::handle_update_user_attributes,
     },
 };
 
+mod guards;
+pub use guards::errors::*;
+
 mod dtos;
 pub use dtos::access_token::*;
+pub use dtos::api_key::*;
+pub use dtos::external_identity::*;
+pub use dtos::group::*;
+pub use dtos::oauth_client::*;
+pub use dtos::principal::*;
+pub use dtos::role::*;
 pub use dtos::session::*;
+pub use dtos::signin_lockout::*;
 pub use dtos::user::*;
 
 mod authorize;
@@ -44,6 +93,158 @@ mod rotate_keypairs;
 pub use rotate_keypairs::errors::*;
 pub use rotate_keypairs::schema::*;
 
+mod import_users;
+pub use import_users::errors::*;
+pub use import_users::schema::*;
+
+mod bootstrap_admin;
+pub use bootstrap_admin::errors::*;
+pub use bootstrap_admin::schema::*;
+
+mod get_user_signin_lockout;
+pub use get_user_signin_lockout::errors::*;
+pub use get_user_signin_lockout::schema::*;
+
+mod reset_user_signin_lockout;
+pub use reset_user_signin_lockout::errors::*;
+pub use reset_user_signin_lockout::schema::*;
+
+mod suspend_user;
+pub use suspend_user::errors::*;
+pub use suspend_user::schema::*;
+
+mod delete_user;
+pub use delete_user::errors::*;
+pub use delete_user::schema::*;
+
+mod unsuspend_user;
+pub use unsuspend_user::errors::*;
+pub use unsuspend_user::schema::*;
+
+mod list_users;
+pub use list_users::errors::*;
+pub use list_users::schema::*;
+
+mod get_user;
+pub use get_user::errors::*;
+pub use get_user::schema::*;
+
+mod change_user_roles;
+pub use change_user_roles::errors::*;
+pub use change_user_roles::schema::*;
+
+mod revoke_user_sessions;
+pub use revoke_user_sessions::errors::*;
+pub use revoke_user_sessions::schema::*;
+
+mod create_user;
+pub use create_user::errors::*;
+pub use create_user::schema::*;
+
+mod revoke_keypair;
+pub use revoke_keypair::errors::*;
+pub use revoke_keypair::schema::*;
+
+mod list_user_sessions;
+pub use list_user_sessions::errors::*;
+pub use list_user_sessions::schema::*;
+
+mod list_public_keys;
+pub use list_public_keys::errors::*;
+pub use list_public_keys::schema::*;
+
+mod list_roles;
+pub use list_roles::errors::*;
+pub use list_roles::schema::*;
+
+mod put_role;
+pub use put_role::errors::*;
+pub use put_role::schema::*;
+
+mod delete_role;
+pub use delete_role::errors::*;
+pub use delete_role::schema::*;
+
+mod list_groups;
+pub use list_groups::errors::*;
+pub use list_groups::schema::*;
+
+mod create_group;
+pub use create_group::errors::*;
+pub use create_group::schema::*;
+
+mod delete_group;
+pub use delete_group::errors::*;
+pub use delete_group::schema::*;
+
+mod change_user_groups;
+pub use change_user_groups::errors::*;
+pub use change_user_groups::schema::*;
+
+mod update_user_attributes;
+pub use update_user_attributes::errors::*;
+pub use update_user_attributes::schema::*;
+
+mod register_oauth_client;
+pub use register_oauth_client::errors::*;
+pub use register_oauth_client::schema::*;
+
+mod oauth_authorize;
+pub use oauth_authorize::errors::*;
+pub use oauth_authorize::schema::*;
+
+mod oauth_token;
+pub use oauth_token::errors::*;
+pub use oauth_token::schema::*;
+
+mod oauth_device_authorize;
+pub use oauth_device_authorize::errors::*;
+pub use oauth_device_authorize::schema::*;
+
+mod approve_device_authorization;
+pub use approve_device_authorization::errors::*;
+pub use approve_device_authorization::schema::*;
+
+mod create_api_key;
+pub use create_api_key::errors::*;
+pub use create_api_key::schema::*;
+
+mod list_api_keys;
+pub use list_api_keys::errors::*;
+pub use list_api_keys::schema::*;
+
+mod revoke_api_key;
+pub use revoke_api_key::errors::*;
+pub use revoke_api_key::schema::*;
+
+mod authorize_api_key;
+pub use authorize_api_key::errors::*;
+pub use authorize_api_key::schema::*;
+
+mod impersonate_user;
+pub use impersonate_user::errors::*;
+pub use impersonate_user::schema::*;
+
+mod federated_authorize;
+pub use federated_authorize::errors::*;
+pub use federated_authorize::schema::*;
+
+mod federated_signin;
+pub use federated_signin::errors::*;
+pub use federated_signin::schema::*;
+
+mod link_external_identity;
+pub use link_external_identity::errors::*;
+pub use link_external_identity::schema::*;
+
+mod unlink_external_identity;
+pub use unlink_external_identity::errors::*;
+pub use unlink_external_identity::schema::*;
+
+mod list_external_identities;
+pub use list_external_identities::errors::*;
+pub use list_external_identities::schema::*;
+
 #[derive(Clone)]
 pub struct UseCases {
     config: UseCasesConfig,
@@ -54,15 +255,47 @@ pub struct UseCases {
 pub struct UseCasesConfig {
     pub session_expiration_seconds: SessionExpirationSeconds,
     pub access_token_expiration_seconds: AccessTokenExpirationSeconds,
+    /// Groups over this limit are left out of access tokens
+    pub access_token_max_groups: AccessTokenMaxGroups,
+    /// Allowlist of user attributes exposed in access tokens
+    pub access_token_attributes: AccessTokenAttributes,
+    /// Realm these use cases are scoped to, repositories are expected to be scoped to it too
+    pub realm: Realm,
+    pub password_policy: PasswordPolicy,
+    pub password_hashing_params: PasswordHashingParams,
+    pub password_peppers: Arc<PasswordPeppers>,
+    pub breached_passwords_filter: Arc<BreachedPasswordsFilter>,
+    pub signin_lockout_policy: SigninLockoutPolicy,
+    /// Enables user enumeration protection, signin of unknown users is verified against this hash
+    /// and signup responds the same way whether the user was created or not
+    pub dummy_password_hash: Option<Arc<PasswordHash>>,
+    /// Page where users enter the codes shown by devices, device authorization grant is disabled if not set
+    pub oauth_device_verification_uri: Option<String>,
 }
 
 #[derive(Clone)]
 pub struct UseCasesServices {
     pub session_repository: Arc<dyn SessionRepository>,
     pub user_repository: Arc<dyn UserRepository>,
+    pub role_repository: Arc<dyn RoleRepository>,
+    pub group_repository: Arc<dyn GroupRepository>,
     pub keypair_repository: Arc<dyn KeyPairRepository>,
+    pub oauth_client_repository: Arc<dyn OAuthClientRepository>,
+    pub authorization_code_repository: Arc<dyn AuthorizationCodeRepository>,
+    pub device_authorization_repository: Arc<dyn DeviceAuthorizationRepository>,
+    pub api_key_repository: Arc<dyn ApiKeyRepository>,
+    /// Audit trail of admins acting as other users
+    pub impersonation_repository: Arc<dyn ImpersonationRepository>,
+    /// Upstream OpenID Connect providers users can sign in with, keyed by name
+    pub identity_providers: HashMap<String, Arc<dyn IdentityProvider>>,
+    pub external_identity_repository: Arc<dyn ExternalIdentityRepository>,
+    pub federated_authorization_repository: Arc<dyn FederatedAuthorizationRepository>,
     pub time_service: Arc<dyn TimeService>,
     pub random_service: Arc<dyn RandomService>,
+    /// Enables just in time migration of users from legacy backend on signin
+    pub legacy_authenticator: Option<Arc<dyn LegacyAuthenticator>>,
+    /// Delivers signup outcomes hidden from the client by user enumeration protection
+    pub signup_notifier: Option<Arc<dyn SignUpNotifier>>,
 }
 
 impl UseCases {
@@ -70,82 +303,323 @@ impl UseCases {
         Self { config, services }
     }
 
+    pub fn realm(&self) -> &Realm {
+        &self.config.realm
+    }
+
     /// Authenticate works only with provided token and private key with what it was signed
     /// It does not fetch user from any persistance layer
     pub async fn authorize<'a>(
         &self,
         request: AuthorizationRequest<'a>,
     ) -> Result<AuthorizationResponse, AuthorizationError> {
-        handle_authorize(request, self.services.keypair_repository.clone()).await
+        handle_authorize(request, &self.services, &self.config).await
     }
 
     pub async fn rotate_keypairs(
         &self,
         request: RotateKeyPairsRequest,
     ) -> Result<RotateKeyPairsResponse, RotateKeyPairsError> {
-        handle_rotate_keypairs(
-            request,
-            self.services.keypair_repository.clone(),
-            self.services.time_service.clone(),
-            self.services.random_service.clone(),
-            self.config.access_token_expiration_seconds,
-        )
-        .await
+        handle_rotate_keypairs(request, &self.services, &self.config).await
     }
 
     pub async fn signup<'a>(
         &self,
         request: SignUpRequest<'a>,
     ) -> Result<SignUpResponse, SignUpError> {
-        handle_signup(
-            request,
-            self.services.user_repository.clone(),
-            self.services.session_repository.clone(),
-            self.services.keypair_repository.clone(),
-            self.services.time_service.clone(),
-            self.services.random_service.clone(),
-            self.config.session_expiration_seconds,
-            self.config.access_token_expiration_seconds,
-        )
-        .await
+        handle_signup(request, &self.services, &self.config).await
     }
 
     pub async fn signin<'a>(
         &self,
         request: SignInRequest<'a>,
     ) -> Result<SignInResponse, SignInError> {
-        handle_signin(
-            request,
-            self.services.user_repository.clone(),
-            self.services.session_repository.clone(),
-            self.services.keypair_repository.clone(),
-            self.services.time_service.clone(),
-            self.config.session_expiration_seconds,
-            self.config.access_token_expiration_seconds,
-        )
-        .await
+        handle_signin(request, &self.services, &self.config).await
     }
 
     pub async fn get_public_key<'a>(
         &self,
         request: GetPublicKeyRequest<'a>,
     ) -> Result<GetPublicKeyResponse, GetPublicKeyError> {
-        handle_get_public_key(request, self.services.keypair_repository.clone()).await
+        handle_get_public_key(request, &self.services, &self.config).await
     }
 
     pub async fn refresh<'a>(
         &self,
         request: RefreshRequest<'a>,
     ) -> Result<RefreshResponse, RefreshError> {
-        handle_refresh(
-            request,
-            self.services.user_repository.clone(),
-            self.services.session_repository.clone(),
-            self.services.keypair_repository.clone(),
-            self.services.time_service.clone(),
-            self.config.session_expiration_seconds,
-            self.config.access_token_expiration_seconds,
-        )
-        .await
+        handle_refresh(request, &self.services, &self.config).await
+    }
+
+    pub async fn import_users<'a>(
+        &self,
+        request: ImportUsersRequest<'a>,
+    ) -> Result<ImportUsersResponse, ImportUsersError> {
+        handle_import_users(request, &self.services, &self.config).await
+    }
+
+    pub async fn bootstrap_admin<'a>(
+        &self,
+        request: BootstrapAdminRequest<'a>,
+    ) -> Result<BootstrapAdminResponse, BootstrapAdminError> {
+        handle_bootstrap_admin(request, &self.services, &self.config).await
+    }
+
+    pub async fn get_user_signin_lockout<'a>(
+        &self,
+        request: GetUserSigninLockoutRequest<'a>,
+    ) -> Result<GetUserSigninLockoutResponse, GetUserSigninLockoutError> {
+        handle_get_user_signin_lockout(request, &self.services, &self.config).await
+    }
+
+    pub async fn reset_user_signin_lockout<'a>(
+        &self,
+        request: ResetUserSigninLockoutRequest<'a>,
+    ) -> Result<ResetUserSigninLockoutResponse, ResetUserSigninLockoutError> {
+        handle_reset_user_signin_lockout(request, &self.services, &self.config).await
+    }
+
+    /// Suspension revokes all active sessions of the user
+    pub async fn suspend_user<'a>(
+        &self,
+        request: SuspendUserRequest<'a>,
+    ) -> Result<SuspendUserResponse, SuspendUserError> {
+        handle_suspend_user(request, &self.services, &self.config).await
+    }
+
+    pub async fn delete_user<'a>(
+        &self,
+        request: DeleteUserRequest<'a>,
+    ) -> Result<DeleteUserResponse, DeleteUserError> {
+        handle_delete_user(request, &self.services, &self.config).await
+    }
+
+    pub async fn unsuspend_user<'a>(
+        &self,
+        request: UnsuspendUserRequest<'a>,
+    ) -> Result<UnsuspendUserResponse, UnsuspendUserError> {
+        handle_unsuspend_user(request, &self.services, &self.config).await
+    }
+
+    pub async fn list_users<'a>(
+        &self,
+        request: ListUsersRequest<'a>,
+    ) -> Result<ListUsersResponse, ListUsersError> {
+        handle_list_users(request, &self.services, &self.config).await
+    }
+
+    pub async fn get_user<'a>(
+        &self,
+        request: GetUserRequest<'a>,
+    ) -> Result<GetUserResponse, GetUserError> {
+        handle_get_user(request, &self.services, &self.config).await
+    }
+
+    pub async fn change_user_roles<'a>(
+        &self,
+        request: ChangeUserRolesRequest<'a>,
+    ) -> Result<ChangeUserRolesResponse, ChangeUserRolesError> {
+        handle_change_user_roles(request, &self.services, &self.config).await
+    }
+
+    pub async fn revoke_user_sessions<'a>(
+        &self,
+        request: RevokeUserSessionsRequest<'a>,
+    ) -> Result<RevokeUserSessionsResponse, RevokeUserSessionsError> {
+        handle_revoke_user_sessions(request, &self.services, &self.config).await
+    }
+
+    pub async fn impersonate_user<'a>(
+        &self,
+        request: ImpersonateUserRequest<'a>,
+    ) -> Result<ImpersonateUserResponse, ImpersonateUserError> {
+        handle_impersonate_user(request, &self.services, &self.config).await
+    }
+
+    pub async fn create_user<'a>(
+        &self,
+        request: CreateUserRequest<'a>,
+    ) -> Result<CreateUserResponse, CreateUserError> {
+        handle_create_user(request, &self.services, &self.config).await
+    }
+
+    pub async fn revoke_keypair<'a>(
+        &self,
+        request: RevokeKeyPairRequest<'a>,
+    ) -> Result<RevokeKeyPairResponse, RevokeKeyPairError> {
+        handle_revoke_keypair(request, &self.services, &self.config).await
+    }
+
+    pub async fn list_user_sessions<'a>(
+        &self,
+        request: ListUserSessionsRequest<'a>,
+    ) -> Result<ListUserSessionsResponse, ListUserSessionsError> {
+        handle_list_user_sessions(request, &self.services, &self.config).await
+    }
+
+    pub async fn list_public_keys(
+        &self,
+        request: ListPublicKeysRequest,
+    ) -> Result<ListPublicKeysResponse, ListPublicKeysError> {
+        handle_list_public_keys(request, &self.services, &self.config).await
+    }
+
+    pub async fn list_roles(
+        &self,
+        request: ListRolesRequest,
+    ) -> Result<ListRolesResponse, ListRolesError> {
+        handle_list_roles(request, &self.services, &self.config).await
+    }
+
+    pub async fn put_role<'a>(
+        &self,
+        request: PutRoleRequest<'a>,
+    ) -> Result<PutRoleResponse, PutRoleError> {
+        handle_put_role(request, &self.services, &self.config).await
+    }
+
+    pub async fn delete_role<'a>(
+        &self,
+        request: DeleteRoleRequest<'a>,
+    ) -> Result<DeleteRoleResponse, DeleteRoleError> {
+        handle_delete_role(request, &self.services, &self.config).await
+    }
+
+    pub async fn list_groups(
+        &self,
+        request: ListGroupsRequest,
+    ) -> Result<ListGroupsResponse, ListGroupsError> {
+        handle_list_groups(request, &self.services, &self.config).await
+    }
+
+    pub async fn create_group<'a>(
+        &self,
+        request: CreateGroupRequest<'a>,
+    ) -> Result<CreateGroupResponse, CreateGroupError> {
+        handle_create_group(request, &self.services, &self.config).await
+    }
+
+    pub async fn delete_group<'a>(
+        &self,
+        request: DeleteGroupRequest<'a>,
+    ) -> Result<DeleteGroupResponse, DeleteGroupError> {
+        handle_delete_group(request, &self.services, &self.config).await
+    }
+
+    pub async fn change_user_groups<'a>(
+        &self,
+        request: ChangeUserGroupsRequest<'a>,
+    ) -> Result<ChangeUserGroupsResponse, ChangeUserGroupsError> {
+        handle_change_user_groups(request, &self.services, &self.config).await
+    }
+
+    pub async fn update_user_attributes<'a>(
+        &self,
+        request: UpdateUserAttributesRequest<'a>,
+    ) -> Result<UpdateUserAttributesResponse, UpdateUserAttributesError> {
+        handle_update_user_attributes(request, &self.services, &self.config).await
+    }
+
+    pub async fn register_oauth_client<'a>(
+        &self,
+        request: RegisterOAuthClientRequest<'a>,
+    ) -> Result<RegisterOAuthClientResponse, RegisterOAuthClientError> {
+        handle_register_oauth_client(request, &self.services, &self.config).await
+    }
+
+    /// Issues an authorization code to the client for the user signed in with the session
+    pub async fn oauth_authorize<'a>(
+        &self,
+        request: OAuthAuthorizeRequest<'a>,
+    ) -> Result<OAuthAuthorizeResponse, OAuthAuthorizeError> {
+        handle_oauth_authorize(request, &self.services, &self.config).await
+    }
+
+    pub async fn oauth_token<'a>(
+        &self,
+        request: OAuthTokenRequest<'a>,
+    ) -> Result<OAuthTokenResponse, OAuthTokenError> {
+        handle_oauth_token(request, &self.services, &self.config).await
+    }
+
+    /// Device authorization grant is disabled unless the verification uri is configured
+    pub async fn oauth_device_authorize<'a>(
+        &self,
+        request: OAuthDeviceAuthorizeRequest<'a>,
+    ) -> Result<OAuthDeviceAuthorizeResponse, OAuthDeviceAuthorizeError> {
+        handle_oauth_device_authorize(request, &self.services, &self.config).await
+    }
+
+    pub async fn approve_device_authorization<'a>(
+        &self,
+        request: ApproveDeviceAuthorizationRequest<'a>,
+    ) -> Result<ApproveDeviceAuthorizationResponse, ApproveDeviceAuthorizationError> {
+        handle_approve_device_authorization(request, &self.services, &self.config).await
+    }
+
+    /// Api keys are created for the calling user, shown once and stored hashed
+    pub async fn create_api_key<'a>(
+        &self,
+        request: CreateApiKeyRequest<'a>,
+    ) -> Result<CreateApiKeyResponse, CreateApiKeyError> {
+        handle_create_api_key(request, &self.services, &self.config).await
+    }
+
+    pub async fn list_api_keys(
+        &self,
+        request: ListApiKeysRequest,
+    ) -> Result<ListApiKeysResponse, ListApiKeysError> {
+        handle_list_api_keys(request, &self.services, &self.config).await
+    }
+
+    pub async fn revoke_api_key<'a>(
+        &self,
+        request: RevokeApiKeyRequest<'a>,
+    ) -> Result<RevokeApiKeyResponse, RevokeApiKeyError> {
+        handle_revoke_api_key(request, &self.services, &self.config).await
+    }
+
+    /// Alternative to authorize for api keys, it fetches the key and its owner
+    pub async fn authorize_api_key<'a>(
+        &self,
+        request: AuthorizeApiKeyRequest<'a>,
+    ) -> Result<AuthorizationResponse, AuthorizeApiKeyError> {
+        handle_authorize_api_key(request, &self.services, &self.config).await
+    }
+
+    /// Starts signin with an identity provider, or linking of an identity when the user is given
+    pub async fn federated_authorize<'a>(
+        &self,
+        request: FederatedAuthorizeRequest<'a>,
+    ) -> Result<FederatedAuthorizeResponse, FederatedAuthorizeError> {
+        handle_federated_authorize(request, &self.services, &self.config).await
+    }
+
+    pub async fn federated_signin<'a>(
+        &self,
+        request: FederatedSignInRequest<'a>,
+    ) -> Result<FederatedSignInResponse, FederatedSignInError> {
+        handle_federated_signin(request, &self.services, &self.config).await
+    }
+
+    pub async fn link_external_identity<'a>(
+        &self,
+        request: LinkExternalIdentityRequest<'a>,
+    ) -> Result<LinkExternalIdentityResponse, LinkExternalIdentityError> {
+        handle_link_external_identity(request, &self.services, &self.config).await
+    }
+
+    pub async fn unlink_external_identity<'a>(
+        &self,
+        request: UnlinkExternalIdentityRequest<'a>,
+    ) -> Result<UnlinkExternalIdentityResponse, UnlinkExternalIdentityError> {
+        handle_unlink_external_identity(request, &self.services, &self.config).await
+    }
+
+    pub async fn list_external_identities(
+        &self,
+        request: ListExternalIdentitiesRequest,
+    ) -> Result<ListExternalIdentitiesResponse, ListExternalIdentitiesError> {
+        handle_list_external_identities(request, &self.services, &self.config).await
     }
 }
@@ -0,0 +1,52 @@
+use nimbus_auth_domain::{
+    entities::device_authorization::value_objects::user_code::UserCode,
+    value_objects::identifier::Identifier,
+};
+use ulid::Ulid;
+
+use crate::use_cases::{
+    ApproveDeviceAuthorizationError, ApproveDeviceAuthorizationRequest,
+    ApproveDeviceAuthorizationResponse, OAuthClientDto, UseCasesConfig, UseCasesServices,
+    guards::require_no_actor,
+};
+
+pub mod errors;
+pub mod schema;
+
+/// Signed in user approves the device showing the user code, the device gets tokens of the user on its next poll
+///
+/// Impersonated and delegated tokens can not approve devices, as the device would get a session of the user
+pub async fn handle_approve_device_authorization<'a>(
+    ApproveDeviceAuthorizationRequest { user, user_code }: ApproveDeviceAuthorizationRequest<'a>,
+    UseCasesServices {
+        oauth_client_repository,
+        device_authorization_repository,
+        time_service,
+        ..
+    }: &UseCasesServices,
+    _: &UseCasesConfig,
+) -> Result<ApproveDeviceAuthorizationResponse, ApproveDeviceAuthorizationError> {
+    require_no_actor(&user)?;
+
+    let user_id = Identifier::from(Ulid::from_string(&user.id)?);
+    let user_code = UserCode::from(user_code)?;
+
+    let device_authorization = device_authorization_repository
+        .get_by_user_code(&user_code)
+        .await?
+        .ok_or(ApproveDeviceAuthorizationError::DeviceAuthorizationIsNotFound)?;
+    let client = oauth_client_repository
+        .get_by_id(device_authorization.client_id())
+        .await?
+        .ok_or(ApproveDeviceAuthorizationError::ClientIsNotFound)?;
+
+    let current_time = time_service.get_current_time().await?;
+    let device_authorization = device_authorization.approve(user_id, current_time)?;
+    device_authorization_repository
+        .save(&device_authorization)
+        .await?;
+
+    Ok(ApproveDeviceAuthorizationResponse {
+        client: OAuthClientDto::from(&client),
+    })
+}
@@ -0,0 +1,36 @@
+use nimbus_auth_domain::entities::device_authorization::{
+    errors::DeviceAuthorizationError, value_objects::user_code::errors::UserCodeError,
+};
+use thiserror::Error;
+use ulid::DecodeError;
+
+use crate::{
+    services::{
+        device_authorization_repository::errors::DeviceAuthorizationRepositoryError,
+        oauth_client_repository::errors::OAuthClientRepositoryError,
+        time_service::errors::TimeServiceError,
+    },
+    use_cases::ActorDeniedError,
+};
+
+#[derive(Debug, Error)]
+pub enum ApproveDeviceAuthorizationError {
+    #[error(transparent)]
+    Forbidden(#[from] ActorDeniedError),
+    #[error("invalid user id. Error: {0}")]
+    InvalidUserId(#[from] DecodeError),
+    #[error(transparent)]
+    InvalidUserCode(#[from] UserCodeError),
+    #[error("device authorization with the user code is not found")]
+    DeviceAuthorizationIsNotFound,
+    #[error("oauth client of the device authorization is not found")]
+    ClientIsNotFound,
+    #[error(transparent)]
+    DeviceAuthorization(#[from] DeviceAuthorizationError),
+    #[error(transparent)]
+    OAuthClientRepository(#[from] OAuthClientRepositoryError),
+    #[error(transparent)]
+    DeviceAuthorizationRepository(#[from] DeviceAuthorizationRepositoryError),
+    #[error(transparent)]
+    TimeService(#[from] TimeServiceError),
+}
@@ -0,0 +1,12 @@
+use crate::use_cases::{OAuthClientDto, UserClaimsDto};
+
+pub struct ApproveDeviceAuthorizationRequest<'a> {
+    pub user: UserClaimsDto,
+    /// As typed by the user, case and separators do not matter
+    pub user_code: &'a str,
+}
+
+pub struct ApproveDeviceAuthorizationResponse {
+    /// Client the device runs, so the user sees what was approved
+    pub client: OAuthClientDto,
+}
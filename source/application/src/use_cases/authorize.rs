@@ -1,40 +1,81 @@
 use std::sync::Arc;
 
 use nimbus_auth_domain::{
-    entities::keypair::SomeKeyPair, value_objects::access_token::AccessToken,
+    entities::keypair::SomeKeyPair,
+    value_objects::{
+        access_token::{AccessToken, AccessTokenType},
+        client_access_token::ClientAccessToken,
+    },
 };
+use nimbus_auth_shared::types::Realm;
 
 use crate::{
     services::keypair_repository::KeyPairRepository,
     use_cases::{
-        AuthorizationRequest, AuthorizationResponse, UserClaimsDto,
-        authorize::errors::AuthorizationError,
+        AuthorizationRequest, AuthorizationResponse, ClientClaimsDto, PrincipalDto, UseCasesConfig,
+        UseCasesServices, UserClaimsDto, authorize::errors::AuthorizationError,
     },
 };
 
 pub mod errors;
 pub mod schema;
 
+/// Access token verified by the keypair it is signed with, of either kind of principal
+pub(crate) enum VerifiedAccessToken {
+    User(AccessToken),
+    Client(ClientAccessToken),
+}
+
 pub async fn handle_authorize<'a>(
     AuthorizationRequest { signed_token }: AuthorizationRequest<'a>,
-    keypair_repository: Arc<dyn KeyPairRepository>,
+    UseCasesServices {
+        keypair_repository, ..
+    }: &UseCasesServices,
+    config: &UseCasesConfig,
 ) -> Result<AuthorizationResponse, AuthorizationError> {
+    let realm = &config.realm;
+
+    let principal =
+        match verify_access_token(signed_token, keypair_repository.clone(), realm).await? {
+            VerifiedAccessToken::User(access_token) => {
+                PrincipalDto::User(UserClaimsDto::from(&access_token))
+            }
+            VerifiedAccessToken::Client(access_token) => {
+                PrincipalDto::Client(ClientClaimsDto::from(access_token.client_claims()))
+            }
+        };
+
+    Ok(AuthorizationResponse { principal })
+}
+
+pub(crate) async fn verify_access_token(
+    signed_token: &str,
+    keypair_repository: Arc<dyn KeyPairRepository>,
+    realm: &Realm,
+) -> Result<VerifiedAccessToken, AuthorizationError> {
     let keypair_id = AccessToken::extract_keypair_id(signed_token)?;
+    let token_type = AccessToken::extract_type(signed_token)?;
     let keypair = keypair_repository
         .get_by_id(keypair_id.as_other_entity_ref())
         .await?
         .ok_or(AuthorizationError::KeyPairNotFound)?;
 
-    let access_token = match keypair {
-        SomeKeyPair::Active(active) => AccessToken::verify_with_active(signed_token, &active),
-        SomeKeyPair::Expiring(expiring) => {
-            AccessToken::verify_with_expiring(signed_token, &expiring)
+    match (keypair, token_type) {
+        (SomeKeyPair::Expired(_), _) => Err(AuthorizationError::KeyPairExpired),
+        (SomeKeyPair::Revoked(_), _) => Err(AuthorizationError::KeyPairRevoked),
+        (SomeKeyPair::Active(active), AccessTokenType::User) => Ok(VerifiedAccessToken::User(
+            AccessToken::verify_with_active(signed_token, &active, realm)?,
+        )),
+        (SomeKeyPair::Expiring(expiring), AccessTokenType::User) => Ok(VerifiedAccessToken::User(
+            AccessToken::verify_with_expiring(signed_token, &expiring, realm)?,
+        )),
+        (SomeKeyPair::Active(active), AccessTokenType::Client) => Ok(VerifiedAccessToken::Client(
+            ClientAccessToken::verify_with_active(signed_token, &active, realm)?,
+        )),
+        (SomeKeyPair::Expiring(expiring), AccessTokenType::Client) => {
+            Ok(VerifiedAccessToken::Client(
+                ClientAccessToken::verify_with_expiring(signed_token, &expiring, realm)?,
+            ))
         }
-        SomeKeyPair::Expired(_) => return Err(AuthorizationError::KeyPairExpired),
-        SomeKeyPair::Revoked(_) => return Err(AuthorizationError::KeyPairRevoked),
-    }?;
-
-    Ok(AuthorizationResponse {
-        user: UserClaimsDto::from(access_token.user_claims()),
-    })
+    }
 }
@@ -1,9 +1,9 @@
-use crate::use_cases::UserClaimsDto;
+use crate::use_cases::PrincipalDto;
 
 pub struct AuthorizationRequest<'a> {
     pub signed_token: &'a str,
 }
 
 pub struct AuthorizationResponse {
-    pub user: UserClaimsDto,
+    pub principal: PrincipalDto,
 }
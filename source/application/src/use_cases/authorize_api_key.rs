@@ -0,0 +1,59 @@
+use nimbus_auth_domain::entities::{Entity, api_key::ApiKey, user::SomeUser};
+use nimbus_auth_shared::types::{AccessTokenAttributes, AccessTokenMaxGroups};
+
+use crate::use_cases::{
+    AuthorizationResponse, AuthorizeApiKeyError, AuthorizeApiKeyRequest, PrincipalDto,
+    UseCasesConfig, UseCasesServices, UserClaimsDto,
+};
+
+pub mod errors;
+pub mod schema;
+
+/// Resolves an api key to the claims of its owner, shaped as in access tokens and limited to the key's scopes
+///
+/// Unlike access tokens, the user is fetched on every call, so suspension applies immediately
+pub async fn handle_authorize_api_key<'a>(
+    AuthorizeApiKeyRequest { api_key }: AuthorizeApiKeyRequest<'a>,
+    UseCasesServices {
+        api_key_repository,
+        user_repository,
+        time_service,
+        ..
+    }: &UseCasesServices,
+    config: &UseCasesConfig,
+) -> Result<AuthorizationResponse, AuthorizeApiKeyError> {
+    let AccessTokenMaxGroups(max_groups) = config.access_token_max_groups;
+    let AccessTokenAttributes(attributes) = &config.access_token_attributes;
+
+    let (api_key_id, secret) = ApiKey::parse_token(api_key)?;
+    let api_key = api_key_repository
+        .get_by_id(&api_key_id)
+        .await?
+        .ok_or(AuthorizeApiKeyError::ApiKeyIsNotFound)?;
+
+    let current_time = time_service.get_current_time().await?;
+    api_key.authenticate(secret, current_time)?;
+
+    let user = match user_repository
+        .get_by_id(api_key.user_id())
+        .await?
+        .ok_or(AuthorizeApiKeyError::UserIsNotFound)?
+    {
+        SomeUser::Active(user) => user.into_owned(),
+        _ => return Err(AuthorizeApiKeyError::UserIsNotActive),
+    };
+
+    let claims = api_key.restrict_claims(
+        user.claims()
+            .clone()
+            .with_groups_limit(max_groups)
+            .with_attributes_projection(attributes),
+    );
+
+    Ok(AuthorizationResponse {
+        principal: PrincipalDto::User(UserClaimsDto {
+            api_key_id: Some(api_key.id().to_string()),
+            ..UserClaimsDto::from(&claims)
+        }),
+    })
+}
@@ -0,0 +1,25 @@
+use nimbus_auth_domain::entities::api_key::errors::ApiKeyError;
+use thiserror::Error;
+
+use crate::services::{
+    api_key_repository::errors::ApiKeyRepositoryError, time_service::errors::TimeServiceError,
+    user_repository::errors::UserRepositoryError,
+};
+
+#[derive(Debug, Error)]
+pub enum AuthorizeApiKeyError {
+    #[error(transparent)]
+    ApiKey(#[from] ApiKeyError),
+    #[error("api key is not found")]
+    ApiKeyIsNotFound,
+    #[error("owner of the api key is not found")]
+    UserIsNotFound,
+    #[error("owner of the api key is not active")]
+    UserIsNotActive,
+    #[error(transparent)]
+    ApiKeyRepository(#[from] ApiKeyRepositoryError),
+    #[error(transparent)]
+    UserRepository(#[from] UserRepositoryError),
+    #[error(transparent)]
+    TimeService(#[from] TimeServiceError),
+}
@@ -0,0 +1,4 @@
+pub struct AuthorizeApiKeyRequest<'a> {
+    /// Key in the `nak_<key id>_<secret>` form
+    pub api_key: &'a str,
+}
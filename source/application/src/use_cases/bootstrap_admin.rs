@@ -0,0 +1,128 @@
+use std::sync::Arc;
+
+use nimbus_auth_domain::entities::{
+    role::{Role, value_objects::role_name::RoleName},
+    user::{
+        SomeUser, User,
+        specifications::NewUserSpecification,
+        value_objects::{password_hash::PasswordHash, user_name::UserName},
+    },
+};
+use nimbus_auth_shared::types::UserStatus;
+
+use crate::{
+    services::{role_repository::RoleRepository, user_repository::UserListFilter},
+    use_cases::{
+        BootstrapAdminError, BootstrapAdminRequest, BootstrapAdminResponse, UseCasesConfig,
+        UseCasesServices, UserClaimsDto,
+    },
+};
+
+pub mod errors;
+pub mod schema;
+
+/// Creates or promotes the first admin, refuses to run once any admin who is not deleted exists.
+/// The check and the save share a transaction, so concurrent runs can not both create an admin
+pub async fn handle_bootstrap_admin<'a>(
+    BootstrapAdminRequest {
+        user_name,
+        password_hash,
+    }: BootstrapAdminRequest<'a>,
+    UseCasesServices {
+        user_repository,
+        role_repository,
+        ..
+    }: &UseCasesServices,
+    _: &UseCasesConfig,
+) -> Result<BootstrapAdminResponse, BootstrapAdminError> {
+    let user_name = UserName::from(user_name)?;
+
+    let mut transactional_user_repository = user_repository.start_transaction().await?;
+
+    // deleted admins are ignored, otherwise losing the last admin would lock the deployment out
+    for status in [UserStatus::Active, UserStatus::Suspended] {
+        let (repository, admins) = transactional_user_repository
+            .list(&UserListFilter {
+                name_query: None,
+                role: Some(RoleName::admin()),
+                status: Some(status),
+                after_id: None,
+                limit: 1,
+            })
+            .await?;
+        transactional_user_repository = repository;
+        if !admins.is_empty() {
+            transactional_user_repository.rollback().await?;
+            return Err(BootstrapAdminError::AdminAlreadyExists);
+        }
+    }
+
+    let (repository, existing_user) = transactional_user_repository
+        .get_by_name(&user_name)
+        .await?;
+    transactional_user_repository = repository;
+
+    let (admin, is_created) = match existing_user {
+        Some(SomeUser::Active(user)) => {
+            let user = user.into_owned();
+            let mut role_names = user.roles().iter().cloned().collect::<Vec<_>>();
+            role_names.push(RoleName::admin());
+            let roles = get_roles(&role_names, role_repository.clone()).await?;
+            (user.with_roles(&roles), false)
+        }
+        Some(user) => {
+            transactional_user_repository.rollback().await?;
+            return Err(BootstrapAdminError::UserIsNotActive {
+                user_name: user_name.to_string(),
+                status: user.status(),
+            });
+        }
+        None => {
+            let Some(password_hash) = password_hash else {
+                transactional_user_repository.rollback().await?;
+                return Err(BootstrapAdminError::PasswordHashIsRequired {
+                    user_name: user_name.to_string(),
+                });
+            };
+            let password_hash = PasswordHash::from(password_hash)?;
+            let roles = get_roles(
+                &[RoleName::default_role(), RoleName::admin()],
+                role_repository.clone(),
+            )
+            .await?;
+            let user = User::new(NewUserSpecification {
+                user_name,
+                password_hash,
+                roles,
+            });
+            (user, true)
+        }
+    };
+
+    let (transactional_user_repository, _) = transactional_user_repository
+        .save(SomeUser::from(&admin))
+        .await?;
+    transactional_user_repository.commit().await?;
+
+    Ok(BootstrapAdminResponse {
+        user: UserClaimsDto::from(admin.claims()),
+        is_created,
+    })
+}
+
+async fn get_roles(
+    role_names: &[RoleName],
+    role_repository: Arc<dyn RoleRepository>,
+) -> Result<Vec<Role>, BootstrapAdminError> {
+    let roles = role_repository.get_by_names(role_names).await?;
+
+    match role_names
+        .iter()
+        .find(|role_name| !roles.iter().any(|role| role.name() == *role_name))
+    {
+        Some(role_name) => Err(BootstrapAdminError::RoleIsNotFound {
+            role_name: role_name.to_string(),
+        }),
+        None => Ok(roles),
+    }
+}
@@ -0,0 +1,32 @@
+use nimbus_auth_domain::entities::user::value_objects::{
+    password_hash::errors::PasswordHashError, user_name::errors::UserNameError,
+};
+use nimbus_auth_shared::types::UserStatus;
+use thiserror::Error;
+
+use crate::services::{
+    role_repository::errors::RoleRepositoryError, user_repository::errors::UserRepositoryError,
+};
+
+#[derive(Debug, Error)]
+pub enum BootstrapAdminError {
+    #[error(transparent)]
+    InvalidUserName(#[from] UserNameError),
+    #[error(transparent)]
+    InvalidPasswordHash(#[from] PasswordHashError),
+    #[error(transparent)]
+    UserRepository(#[from] UserRepositoryError),
+    #[error(transparent)]
+    RoleRepository(#[from] RoleRepositoryError),
+    #[error("role with name: {role_name} is not found")]
+    RoleIsNotFound { role_name: String },
+    #[error("admin already exists, new admins should be promoted by it")]
+    AdminAlreadyExists,
+    #[error("user with name: {user_name} does not exist, password hash is required to create it")]
+    PasswordHashIsRequired { user_name: String },
+    #[error("user with name: {user_name} can not be promoted, its status is {status}")]
+    UserIsNotActive {
+        user_name: String,
+        status: UserStatus,
+    },
+}
@@ -0,0 +1,13 @@
+use crate::use_cases::UserClaimsDto;
+
+pub struct BootstrapAdminRequest<'a> {
+    pub user_name: &'a str,
+    /// Required only if the user does not exist yet
+    pub password_hash: Option<&'a str>,
+}
+
+pub struct BootstrapAdminResponse {
+    pub user: UserClaimsDto,
+    /// False if an existing user was promoted
+    pub is_created: bool,
+}
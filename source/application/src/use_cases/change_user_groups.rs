@@ -0,0 +1,92 @@
+use std::collections::BTreeSet;
+
+use nimbus_auth_domain::entities::{
+    group::value_objects::group_name::GroupName,
+    user::{SomeUser, value_objects::user_name::UserName},
+};
+use nimbus_auth_shared::constants::PERMISSION_MANAGE_GROUPS;
+
+use crate::use_cases::{
+    ChangeUserGroupsError, ChangeUserGroupsRequest, ChangeUserGroupsResponse, UseCasesConfig,
+    UseCasesServices, UserDetailsDto, guards::require_permission,
+};
+
+pub mod errors;
+pub mod schema;
+
+/// New memberships get into access tokens issued after the change
+pub async fn handle_change_user_groups<'a>(
+    ChangeUserGroupsRequest {
+        user,
+        user_name,
+        joined_groups,
+        left_groups,
+    }: ChangeUserGroupsRequest<'a>,
+    UseCasesServices {
+        user_repository,
+        group_repository,
+        ..
+    }: &UseCasesServices,
+    _: &UseCasesConfig,
+) -> Result<ChangeUserGroupsResponse, ChangeUserGroupsError> {
+    require_permission(&user, PERMISSION_MANAGE_GROUPS)?;
+
+    let user_name = UserName::from(user_name)?;
+    let joined_groups = joined_groups
+        .iter()
+        .map(|group_name| GroupName::from(group_name))
+        .collect::<Result<BTreeSet<_>, _>>()?;
+    let left_groups = left_groups
+        .iter()
+        .map(|group_name| GroupName::from(group_name))
+        .collect::<Result<BTreeSet<_>, _>>()?;
+
+    let target_user = user_repository.get_by_name(&user_name).await?.ok_or(
+        ChangeUserGroupsError::UserIsNotFound {
+            user_name: user_name.to_string(),
+        },
+    )?;
+
+    if let SomeUser::Deleted(_) = target_user {
+        return Err(ChangeUserGroupsError::UserIsDeleted {
+            user_name: user_name.to_string(),
+        });
+    }
+
+    let current_groups = target_user
+        .groups()
+        .iter()
+        .map(|group| group.name().clone())
+        .collect::<BTreeSet<_>>();
+    let group_names = current_groups
+        .iter()
+        .chain(joined_groups.iter())
+        .filter(|group_name| !left_groups.contains(*group_name))
+        .cloned()
+        .collect::<BTreeSet<_>>();
+
+    if group_names == current_groups {
+        return Ok(ChangeUserGroupsResponse {
+            user: UserDetailsDto::from(&target_user),
+        });
+    }
+
+    let group_names = group_names.into_iter().collect::<Vec<_>>();
+    let groups = group_repository.get_by_names(&group_names).await?;
+
+    if let Some(group_name) = group_names
+        .iter()
+        .find(|group_name| !groups.iter().any(|group| group.name() == *group_name))
+    {
+        return Err(ChangeUserGroupsError::GroupIsNotFound {
+            group_name: group_name.to_string(),
+        });
+    }
+
+    let target_user = target_user.with_groups(&groups);
+    user_repository.save(target_user.clone()).await?;
+
+    Ok(ChangeUserGroupsResponse {
+        user: UserDetailsDto::from(&target_user),
+    })
+}
@@ -0,0 +1,33 @@
+use nimbus_auth_domain::entities::{
+    group::value_objects::group_name::errors::GroupNameError,
+    user::value_objects::user_name::errors::UserNameError,
+};
+use thiserror::Error;
+
+use crate::{
+    services::{
+        group_repository::errors::GroupRepositoryError,
+        user_repository::errors::UserRepositoryError,
+    },
+    use_cases::PermissionDeniedError,
+};
+
+#[derive(Debug, Error)]
+pub enum ChangeUserGroupsError {
+    #[error(transparent)]
+    Forbidden(#[from] PermissionDeniedError),
+    #[error(transparent)]
+    InvalidUserName(#[from] UserNameError),
+    #[error(transparent)]
+    InvalidGroupName(#[from] GroupNameError),
+    #[error(transparent)]
+    UserRepository(#[from] UserRepositoryError),
+    #[error(transparent)]
+    GroupRepository(#[from] GroupRepositoryError),
+    #[error("user with name: {user_name} is not found")]
+    UserIsNotFound { user_name: String },
+    #[error("user with name: {user_name} is deleted")]
+    UserIsDeleted { user_name: String },
+    #[error("group with name: {group_name} is not found")]
+    GroupIsNotFound { group_name: String },
+}
@@ -0,0 +1,13 @@
+use crate::use_cases::{UserClaimsDto, UserDetailsDto};
+
+/// Groups both joined and left end up left
+pub struct ChangeUserGroupsRequest<'a> {
+    pub user: UserClaimsDto,
+    pub user_name: &'a str,
+    pub joined_groups: &'a [String],
+    pub left_groups: &'a [String],
+}
+
+pub struct ChangeUserGroupsResponse {
+    pub user: UserDetailsDto,
+}
@@ -0,0 +1,122 @@
+use std::collections::BTreeSet;
+
+use nimbus_auth_domain::entities::{
+    Entity,
+    role::value_objects::role_name::RoleName,
+    user::{SomeUser, value_objects::user_name::UserName},
+};
+use nimbus_auth_shared::constants::PERMISSION_MANAGE_ROLES;
+
+use crate::use_cases::{
+    ChangeUserRolesError, ChangeUserRolesRequest, ChangeUserRolesResponse, UseCasesConfig,
+    UseCasesServices, UserDetailsDto, guards::require_permission,
+    revoke_user_sessions::revoke_active_sessions,
+};
+
+pub mod errors;
+pub mod schema;
+
+/// New roles get into access tokens issued after the change,
+/// revoking sessions makes them take effect on the next signin instead of the next refresh
+pub async fn handle_change_user_roles<'a>(
+    ChangeUserRolesRequest {
+        user,
+        user_name,
+        granted_roles,
+        revoked_roles,
+        revoke_sessions,
+    }: ChangeUserRolesRequest<'a>,
+    UseCasesServices {
+        user_repository,
+        role_repository,
+        session_repository,
+        time_service,
+        ..
+    }: &UseCasesServices,
+    _: &UseCasesConfig,
+) -> Result<ChangeUserRolesResponse, ChangeUserRolesError> {
+    require_permission(&user, PERMISSION_MANAGE_ROLES)?;
+
+    let user_name = UserName::from(user_name)?;
+    let granted_roles = granted_roles
+        .iter()
+        .map(|role_name| RoleName::from(role_name))
+        .collect::<Result<BTreeSet<_>, _>>()?;
+    let revoked_roles = revoked_roles
+        .iter()
+        .map(|role_name| RoleName::from(role_name))
+        .collect::<Result<BTreeSet<_>, _>>()?;
+
+    let target_user = user_repository.get_by_name(&user_name).await?.ok_or(
+        ChangeUserRolesError::UserIsNotFound {
+            user_name: user_name.to_string(),
+        },
+    )?;
+
+    if let SomeUser::Deleted(_) = target_user {
+        return Err(ChangeUserRolesError::UserIsDeleted {
+            user_name: user_name.to_string(),
+        });
+    }
+
+    let role_names = target_user
+        .roles()
+        .iter()
+        .chain(granted_roles.iter())
+        .filter(|role_name| !revoked_roles.contains(*role_name))
+        .cloned()
+        .collect::<BTreeSet<_>>();
+
+    if role_names == *target_user.roles() {
+        return Ok(ChangeUserRolesResponse {
+            user: UserDetailsDto::from(&target_user),
+            revoked_sessions_count: 0,
+        });
+    }
+
+    let role_names = role_names.into_iter().collect::<Vec<_>>();
+    let roles = role_repository.get_by_names(&role_names).await?;
+
+    if let Some(role_name) = role_names
+        .iter()
+        .find(|role_name| !roles.iter().any(|role| role.name() == *role_name))
+    {
+        return Err(ChangeUserRolesError::RoleIsNotFound {
+            role_name: role_name.to_string(),
+        });
+    }
+
+    let target_user = target_user.with_roles(&roles);
+
+    if !revoke_sessions {
+        user_repository.save(target_user.clone()).await?;
+
+        return Ok(ChangeUserRolesResponse {
+            user: UserDetailsDto::from(&target_user),
+            revoked_sessions_count: 0,
+        });
+    }
+
+    let transactional_user_repository = user_repository.start_transaction().await?;
+
+    let (transactional_user_repository, _) = transactional_user_repository
+        .save(target_user.clone())
+        .await?;
+
+    let transactional_session_repository = session_repository.start_transaction().await?;
+
+    let (transactional_session_repository, revoked_sessions_count) = revoke_active_sessions(
+        transactional_session_repository,
+        target_user.id(),
+        time_service.get_current_time().await?,
+    )
+    .await?;
+
+    transactional_session_repository.commit().await?;
+    transactional_user_repository.commit().await?;
+
+    Ok(ChangeUserRolesResponse {
+        user: UserDetailsDto::from(&target_user),
+        revoked_sessions_count,
+    })
+}
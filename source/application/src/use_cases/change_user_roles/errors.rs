@@ -0,0 +1,38 @@
+use nimbus_auth_domain::entities::{
+    role::value_objects::role_name::errors::RoleNameError,
+    user::value_objects::user_name::errors::UserNameError,
+};
+use thiserror::Error;
+
+use crate::{
+    services::{
+        role_repository::errors::RoleRepositoryError,
+        session_repository::errors::SessionRepositoryError, time_service::errors::TimeServiceError,
+        user_repository::errors::UserRepositoryError,
+    },
+    use_cases::PermissionDeniedError,
+};
+
+#[derive(Debug, Error)]
+pub enum ChangeUserRolesError {
+    #[error(transparent)]
+    Forbidden(#[from] PermissionDeniedError),
+    #[error(transparent)]
+    InvalidUserName(#[from] UserNameError),
+    #[error(transparent)]
+    InvalidRoleName(#[from] RoleNameError),
+    #[error(transparent)]
+    UserRepository(#[from] UserRepositoryError),
+    #[error(transparent)]
+    RoleRepository(#[from] RoleRepositoryError),
+    #[error(transparent)]
+    SessionRepository(#[from] SessionRepositoryError),
+    #[error(transparent)]
+    TimeService(#[from] TimeServiceError),
+    #[error("user with name: {user_name} is not found")]
+    UserIsNotFound { user_name: String },
+    #[error("user with name: {user_name} is deleted")]
+    UserIsDeleted { user_name: String },
+    #[error("role with name: {role_name} is not found")]
+    RoleIsNotFound { role_name: String },
+}
@@ -0,0 +1,16 @@
+use crate::use_cases::{UserClaimsDto, UserDetailsDto};
+
+/// Roles both granted and revoked end up revoked
+pub struct ChangeUserRolesRequest<'a> {
+    pub user: UserClaimsDto,
+    pub user_name: &'a str,
+    pub granted_roles: &'a [String],
+    pub revoked_roles: &'a [String],
+    /// Active sessions of the user are revoked, so old claims can't be refreshed
+    pub revoke_sessions: bool,
+}
+
+pub struct ChangeUserRolesResponse {
+    pub user: UserDetailsDto,
+    pub revoked_sessions_count: usize,
+}
@@ -0,0 +1,76 @@
+use std::collections::BTreeSet;
+
+use nimbus_auth_domain::{
+    entities::{
+        api_key::{
+            ApiKey, specifications::NewApiKeySpecification, value_objects::api_key_name::ApiKeyName,
+        },
+        role::value_objects::permission::Permission,
+    },
+    value_objects::{identifier::Identifier, secret_hash::SecretHash},
+};
+use ulid::Ulid;
+
+use crate::use_cases::{
+    ApiKeyDto, CreateApiKeyError, CreateApiKeyRequest, CreateApiKeyResponse, UseCasesConfig,
+    UseCasesServices,
+    guards::{require_no_actor, require_no_api_key},
+};
+
+pub mod errors;
+pub mod schema;
+
+/// Creates an api key of the user, it can be granted only permissions the user has
+///
+/// Impersonated and delegated tokens and api keys can not create keys, as those would outlive them
+pub async fn handle_create_api_key<'a>(
+    CreateApiKeyRequest {
+        user,
+        name,
+        scopes,
+        expires_in_seconds,
+    }: CreateApiKeyRequest<'a>,
+    UseCasesServices {
+        api_key_repository,
+        time_service,
+        random_service,
+        ..
+    }: &UseCasesServices,
+    _: &UseCasesConfig,
+) -> Result<CreateApiKeyResponse, CreateApiKeyError> {
+    require_no_actor(&user)?;
+    require_no_api_key(&user)?;
+
+    let user_id = Identifier::from(Ulid::from_string(&user.id)?);
+    let name = ApiKeyName::from(name)?;
+    if let Some(scope) = scopes
+        .iter()
+        .find(|scope| !user.permissions.contains(scope))
+    {
+        return Err(CreateApiKeyError::ScopeIsNotGranted {
+            scope: scope.to_string(),
+        });
+    }
+    let scopes = scopes
+        .iter()
+        .map(|scope| Permission::from(scope))
+        .collect::<Result<BTreeSet<_>, _>>()?;
+
+    let secret = random_service.get_random_secret().await?;
+    let current_time = time_service.get_current_time().await?;
+
+    let api_key = ApiKey::new(NewApiKeySpecification {
+        user_id,
+        name,
+        secret_hash: SecretHash::hash(&secret),
+        scopes,
+        current_time,
+        expiration_seconds: expires_in_seconds,
+    });
+    api_key_repository.save(&api_key).await?;
+
+    Ok(CreateApiKeyResponse {
+        token: api_key.format_token(&secret),
+        api_key: ApiKeyDto::from(&api_key),
+    })
+}
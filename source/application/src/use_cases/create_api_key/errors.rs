@@ -0,0 +1,36 @@
+use nimbus_auth_domain::entities::{
+    api_key::value_objects::api_key_name::errors::ApiKeyNameError,
+    role::value_objects::permission::errors::PermissionError,
+};
+use thiserror::Error;
+use ulid::DecodeError;
+
+use crate::{
+    services::{
+        api_key_repository::errors::ApiKeyRepositoryError,
+        random_service::errors::RandomServiceError, time_service::errors::TimeServiceError,
+    },
+    use_cases::{ActorDeniedError, ApiKeyDeniedError},
+};
+
+#[derive(Debug, Error)]
+pub enum CreateApiKeyError {
+    #[error(transparent)]
+    Forbidden(#[from] ActorDeniedError),
+    #[error(transparent)]
+    ApiKeyForbidden(#[from] ApiKeyDeniedError),
+    #[error("invalid user id. Error: {0}")]
+    InvalidUserId(#[from] DecodeError),
+    #[error(transparent)]
+    InvalidName(#[from] ApiKeyNameError),
+    #[error("invalid scope. Error: {0}")]
+    InvalidScope(#[from] PermissionError),
+    #[error("scope: {scope} is not granted to the user")]
+    ScopeIsNotGranted { scope: String },
+    #[error(transparent)]
+    ApiKeyRepository(#[from] ApiKeyRepositoryError),
+    #[error(transparent)]
+    TimeService(#[from] TimeServiceError),
+    #[error(transparent)]
+    RandomService(#[from] RandomServiceError),
+}
@@ -0,0 +1,18 @@
+use zeroize::Zeroizing;
+
+use crate::use_cases::{ApiKeyDto, UserClaimsDto};
+
+pub struct CreateApiKeyRequest<'a> {
+    pub user: UserClaimsDto,
+    pub name: &'a str,
+    /// Permissions of the user the key grants
+    pub scopes: &'a [String],
+    /// Keys without expiration are valid until revoked
+    pub expires_in_seconds: Option<u64>,
+}
+
+pub struct CreateApiKeyResponse {
+    pub api_key: ApiKeyDto,
+    /// Key is shown only once, it can not be retrieved later
+    pub token: Zeroizing<String>,
+}
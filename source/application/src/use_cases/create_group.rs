@@ -0,0 +1,37 @@
+use nimbus_auth_domain::entities::group::{
+    Group, specifications::NewGroupSpecification, value_objects::group_name::GroupName,
+};
+use nimbus_auth_shared::constants::PERMISSION_MANAGE_GROUPS;
+
+use crate::use_cases::{
+    CreateGroupError, CreateGroupRequest, CreateGroupResponse, GroupDto, UseCasesConfig,
+    UseCasesServices, guards::require_permission,
+};
+
+pub mod errors;
+pub mod schema;
+
+pub async fn handle_create_group<'a>(
+    CreateGroupRequest { user, group_name }: CreateGroupRequest<'a>,
+    UseCasesServices {
+        group_repository, ..
+    }: &UseCasesServices,
+    _: &UseCasesConfig,
+) -> Result<CreateGroupResponse, CreateGroupError> {
+    require_permission(&user, PERMISSION_MANAGE_GROUPS)?;
+
+    let group_name = GroupName::from(group_name)?;
+
+    if group_repository.get_by_name(&group_name).await?.is_some() {
+        return Err(CreateGroupError::GroupAlreadyExists {
+            group_name: group_name.to_string(),
+        });
+    }
+
+    let group = Group::new(NewGroupSpecification { name: group_name });
+    group_repository.save(&group).await?;
+
+    Ok(CreateGroupResponse {
+        group: GroupDto::from(&group),
+    })
+}
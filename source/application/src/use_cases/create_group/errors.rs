@@ -0,0 +1,18 @@
+use nimbus_auth_domain::entities::group::value_objects::group_name::errors::GroupNameError;
+use thiserror::Error;
+
+use crate::{
+    services::group_repository::errors::GroupRepositoryError, use_cases::PermissionDeniedError,
+};
+
+#[derive(Debug, Error)]
+pub enum CreateGroupError {
+    #[error(transparent)]
+    Forbidden(#[from] PermissionDeniedError),
+    #[error(transparent)]
+    InvalidGroupName(#[from] GroupNameError),
+    #[error(transparent)]
+    GroupRepository(#[from] GroupRepositoryError),
+    #[error("group with name: {group_name} already exists")]
+    GroupAlreadyExists { group_name: String },
+}
@@ -0,0 +1,10 @@
+use crate::use_cases::{GroupDto, UserClaimsDto};
+
+pub struct CreateGroupRequest<'a> {
+    pub user: UserClaimsDto,
+    pub group_name: &'a str,
+}
+
+pub struct CreateGroupResponse {
+    pub group: GroupDto,
+}
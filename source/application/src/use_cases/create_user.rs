@@ -0,0 +1,90 @@
+use std::collections::BTreeSet;
+
+use nimbus_auth_domain::entities::{
+    role::value_objects::role_name::RoleName,
+    user::{
+        SomeUser, User,
+        specifications::NewUserSpecification,
+        value_objects::{password::Password, password_hash::PasswordHash, user_name::UserName},
+    },
+};
+use nimbus_auth_shared::constants::PERMISSION_MANAGE_USERS;
+
+use crate::use_cases::{
+    CreateUserError, CreateUserRequest, CreateUserResponse, UseCasesConfig, UseCasesServices,
+    UserDetailsDto, guards::require_permission,
+};
+
+pub mod errors;
+pub mod schema;
+
+/// Creates a user without a session, the password is checked against the same policy as on signup
+pub async fn handle_create_user<'a>(
+    CreateUserRequest {
+        user,
+        user_name,
+        password,
+        roles,
+    }: CreateUserRequest<'a>,
+    UseCasesServices {
+        user_repository,
+        role_repository,
+        random_service,
+        ..
+    }: &UseCasesServices,
+    config: &UseCasesConfig,
+) -> Result<CreateUserResponse, CreateUserError> {
+    require_permission(&user, PERMISSION_MANAGE_USERS)?;
+
+    let user_name = UserName::from(user_name)?;
+    let role_names = roles
+        .iter()
+        .map(|role_name| RoleName::from(role_name))
+        .chain([Ok(RoleName::default_role())])
+        .collect::<Result<BTreeSet<_>, _>>()?
+        .into_iter()
+        .collect::<Vec<_>>();
+
+    if user_repository.get_by_name(&user_name).await?.is_some() {
+        return Err(CreateUserError::UserAlreadyExists {
+            user_name: user_name.to_string(),
+        });
+    }
+
+    let password = Password::from(
+        password,
+        &config.password_policy,
+        &config.breached_passwords_filter,
+    )?;
+    let salt_b64 = random_service.get_random_salt_b64().await?;
+    let password_hash = PasswordHash::hash(
+        password,
+        &salt_b64,
+        &config.password_hashing_params,
+        &config.password_peppers,
+    )?;
+
+    let roles = role_repository.get_by_names(&role_names).await?;
+
+    if let Some(role_name) = role_names
+        .iter()
+        .find(|role_name| !roles.iter().any(|role| role.name() == *role_name))
+    {
+        return Err(CreateUserError::RoleIsNotFound {
+            role_name: role_name.to_string(),
+        });
+    }
+
+    let created_user = User::new(NewUserSpecification {
+        user_name,
+        password_hash,
+        roles,
+    });
+    let created_user = SomeUser::from(created_user);
+
+    user_repository.save(created_user.clone()).await?;
+
+    Ok(CreateUserResponse {
+        user: UserDetailsDto::from(&created_user),
+    })
+}
@@ -0,0 +1,40 @@
+use nimbus_auth_domain::entities::{
+    role::value_objects::role_name::errors::RoleNameError,
+    user::value_objects::{
+        password::errors::PasswordError, password_hash::errors::PasswordHashError,
+        user_name::errors::UserNameError,
+    },
+};
+use thiserror::Error;
+
+use crate::{
+    services::{
+        random_service::errors::RandomServiceError, role_repository::errors::RoleRepositoryError,
+        user_repository::errors::UserRepositoryError,
+    },
+    use_cases::PermissionDeniedError,
+};
+
+#[derive(Debug, Error)]
+pub enum CreateUserError {
+    #[error(transparent)]
+    Forbidden(#[from] PermissionDeniedError),
+    #[error(transparent)]
+    InvalidUserName(#[from] UserNameError),
+    #[error(transparent)]
+    InvalidRoleName(#[from] RoleNameError),
+    #[error(transparent)]
+    InvalidPassword(#[from] PasswordError),
+    #[error(transparent)]
+    PasswordHash(#[from] PasswordHashError),
+    #[error(transparent)]
+    RandomService(#[from] RandomServiceError),
+    #[error(transparent)]
+    UserRepository(#[from] UserRepositoryError),
+    #[error(transparent)]
+    RoleRepository(#[from] RoleRepositoryError),
+    #[error("role with name: {role_name} is not found")]
+    RoleIsNotFound { role_name: String },
+    #[error("user with name: {user_name} already exists")]
+    UserAlreadyExists { user_name: String },
+}
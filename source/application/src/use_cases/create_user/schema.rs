@@ -0,0 +1,15 @@
+use zeroize::Zeroizing;
+
+use crate::use_cases::{UserClaimsDto, UserDetailsDto};
+
+pub struct CreateUserRequest<'a> {
+    pub user: UserClaimsDto,
+    pub user_name: &'a str,
+    pub password: &'a Zeroizing<String>,
+    /// Granted along with the default role
+    pub roles: &'a [String],
+}
+
+pub struct CreateUserResponse {
+    pub user: UserDetailsDto,
+}
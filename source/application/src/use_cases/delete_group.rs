@@ -0,0 +1,33 @@
+use nimbus_auth_domain::entities::group::value_objects::group_name::GroupName;
+use nimbus_auth_shared::constants::PERMISSION_MANAGE_GROUPS;
+
+use crate::use_cases::{
+    DeleteGroupError, DeleteGroupRequest, DeleteGroupResponse, UseCasesConfig, UseCasesServices,
+    guards::require_permission,
+};
+
+pub mod errors;
+pub mod schema;
+
+/// Members leave the group, access tokens issued before the deletion still carry it
+pub async fn handle_delete_group<'a>(
+    DeleteGroupRequest { user, group_name }: DeleteGroupRequest<'a>,
+    UseCasesServices {
+        group_repository, ..
+    }: &UseCasesServices,
+    _: &UseCasesConfig,
+) -> Result<DeleteGroupResponse, DeleteGroupError> {
+    require_permission(&user, PERMISSION_MANAGE_GROUPS)?;
+
+    let group_name = GroupName::from(group_name)?;
+
+    let group = group_repository.get_by_name(&group_name).await?.ok_or(
+        DeleteGroupError::GroupIsNotFound {
+            group_name: group_name.to_string(),
+        },
+    )?;
+
+    group_repository.delete(&group).await?;
+
+    Ok(DeleteGroupResponse {})
+}
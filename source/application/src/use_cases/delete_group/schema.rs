@@ -0,0 +1,8 @@
+use crate::use_cases::UserClaimsDto;
+
+pub struct DeleteGroupRequest<'a> {
+    pub user: UserClaimsDto,
+    pub group_name: &'a str,
+}
+
+pub struct DeleteGroupResponse {}
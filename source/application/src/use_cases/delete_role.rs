@@ -0,0 +1,41 @@
+use nimbus_auth_domain::entities::role::value_objects::role_name::RoleName;
+use nimbus_auth_shared::constants::PERMISSION_MANAGE_ROLES;
+
+use crate::use_cases::{
+    DeleteRoleError, DeleteRoleRequest, DeleteRoleResponse, UseCasesConfig, UseCasesServices,
+    guards::require_permission,
+};
+
+pub mod errors;
+pub mod schema;
+
+/// Role is taken away from all of its holders, built-in roles can not be deleted
+pub async fn handle_delete_role<'a>(
+    DeleteRoleRequest { user, role_name }: DeleteRoleRequest<'a>,
+    UseCasesServices {
+        role_repository, ..
+    }: &UseCasesServices,
+    _: &UseCasesConfig,
+) -> Result<DeleteRoleResponse, DeleteRoleError> {
+    require_permission(&user, PERMISSION_MANAGE_ROLES)?;
+
+    let role_name = RoleName::from(role_name)?;
+
+    let role =
+        role_repository
+            .get_by_name(&role_name)
+            .await?
+            .ok_or(DeleteRoleError::RoleIsNotFound {
+                role_name: role_name.to_string(),
+            })?;
+
+    if role.is_built_in() {
+        return Err(DeleteRoleError::RoleIsBuiltIn {
+            role_name: role_name.to_string(),
+        });
+    }
+
+    role_repository.delete(&role).await?;
+
+    Ok(DeleteRoleResponse {})
+}
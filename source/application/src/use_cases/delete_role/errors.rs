@@ -0,0 +1,20 @@
+use nimbus_auth_domain::entities::role::value_objects::role_name::errors::RoleNameError;
+use thiserror::Error;
+
+use crate::{
+    services::role_repository::errors::RoleRepositoryError, use_cases::PermissionDeniedError,
+};
+
+#[derive(Debug, Error)]
+pub enum DeleteRoleError {
+    #[error(transparent)]
+    Forbidden(#[from] PermissionDeniedError),
+    #[error(transparent)]
+    InvalidRoleName(#[from] RoleNameError),
+    #[error(transparent)]
+    RoleRepository(#[from] RoleRepositoryError),
+    #[error("role with name: {role_name} is not found")]
+    RoleIsNotFound { role_name: String },
+    #[error("role with name: {role_name} is built-in")]
+    RoleIsBuiltIn { role_name: String },
+}
@@ -0,0 +1,8 @@
+use crate::use_cases::UserClaimsDto;
+
+pub struct DeleteRoleRequest<'a> {
+    pub user: UserClaimsDto,
+    pub role_name: &'a str,
+}
+
+pub struct DeleteRoleResponse {}
@@ -0,0 +1,70 @@
+use nimbus_auth_domain::entities::{
+    Entity,
+    user::{SomeUser, value_objects::user_name::UserName},
+};
+use nimbus_auth_shared::constants::PERMISSION_MANAGE_USERS;
+
+use crate::use_cases::{
+    DeleteUserError, DeleteUserRequest, DeleteUserResponse, UseCasesConfig, UseCasesServices,
+    guards::require_permission, revoke_user_sessions::revoke_active_sessions,
+};
+
+pub mod errors;
+pub mod schema;
+
+/// Deletion is soft, user is kept with its name taken, so it can not be claimed by someone else
+pub async fn handle_delete_user<'a>(
+    DeleteUserRequest { user, user_name }: DeleteUserRequest<'a>,
+    UseCasesServices {
+        user_repository,
+        session_repository,
+        time_service,
+        ..
+    }: &UseCasesServices,
+    _: &UseCasesConfig,
+) -> Result<DeleteUserResponse, DeleteUserError> {
+    require_permission(&user, PERMISSION_MANAGE_USERS)?;
+
+    let user_name = UserName::from(user_name)?;
+
+    let target_user =
+        user_repository
+            .get_by_name(&user_name)
+            .await?
+            .ok_or(DeleteUserError::UserIsNotFound {
+                user_name: user_name.to_string(),
+            })?;
+
+    let current_time = time_service.get_current_time().await?;
+    let deleted_user = match target_user {
+        SomeUser::Active(target_user) => target_user.into_owned().delete(current_time),
+        SomeUser::Suspended(target_user) => target_user.into_owned().delete(current_time),
+        SomeUser::Deleted(_) => {
+            return Err(DeleteUserError::UserIsAlreadyDeleted {
+                user_name: user_name.to_string(),
+            });
+        }
+    };
+
+    let transactional_user_repository = user_repository.start_transaction().await?;
+
+    let (transactional_user_repository, _) = transactional_user_repository
+        .save(SomeUser::from(&deleted_user))
+        .await?;
+
+    let transactional_session_repository = session_repository.start_transaction().await?;
+
+    let (transactional_session_repository, revoked_sessions_count) = revoke_active_sessions(
+        transactional_session_repository,
+        deleted_user.id(),
+        current_time,
+    )
+    .await?;
+
+    transactional_session_repository.commit().await?;
+    transactional_user_repository.commit().await?;
+
+    Ok(DeleteUserResponse {
+        revoked_sessions_count,
+    })
+}
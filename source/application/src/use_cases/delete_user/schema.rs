@@ -0,0 +1,10 @@
+use crate::use_cases::UserClaimsDto;
+
+pub struct DeleteUserRequest<'a> {
+    pub user: UserClaimsDto,
+    pub user_name: &'a str,
+}
+
+pub struct DeleteUserResponse {
+    pub revoked_sessions_count: usize,
+}
@@ -1,3 +1,10 @@
 pub mod access_token;
+pub mod api_key;
+pub mod external_identity;
+pub mod group;
+pub mod oauth_client;
+pub mod principal;
+pub mod role;
 pub mod session;
+pub mod signin_lockout;
 pub mod user;
@@ -0,0 +1,32 @@
+use nimbus_auth_domain::entities::{Entity, api_key::ApiKey};
+
+/// Api key as seen by its owner, without the secret
+pub struct ApiKeyDto {
+    pub id: String,
+    pub name: String,
+    pub scopes: Vec<String>,
+    pub created_at_unix_timestamp: i64,
+    pub expires_at_unix_timestamp: Option<i64>,
+    pub revoked_at_unix_timestamp: Option<i64>,
+}
+
+impl From<&ApiKey> for ApiKeyDto {
+    fn from(value: &ApiKey) -> Self {
+        Self {
+            id: value.id().to_string(),
+            name: value.name().to_string(),
+            scopes: value
+                .scopes()
+                .iter()
+                .map(|scope| scope.to_string())
+                .collect(),
+            created_at_unix_timestamp: value.created_at().unix_timestamp(),
+            expires_at_unix_timestamp: value
+                .expires_at()
+                .map(|expires_at| expires_at.unix_timestamp()),
+            revoked_at_unix_timestamp: value
+                .revoked_at()
+                .map(|revoked_at| revoked_at.unix_timestamp()),
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use nimbus_auth_domain::entities::external_identity::ExternalIdentity;
+
+/// Identity at an upstream provider as seen by the user it is linked to
+pub struct ExternalIdentityDto {
+    pub provider: String,
+    pub subject: String,
+    pub email: Option<String>,
+    /// The user was created on the first signin with this identity, so it can not be unlinked
+    pub provisioned: bool,
+    pub linked_at_unix_timestamp: i64,
+}
+
+impl From<&ExternalIdentity> for ExternalIdentityDto {
+    fn from(value: &ExternalIdentity) -> Self {
+        Self {
+            provider: value.provider().to_string(),
+            subject: value.subject().to_string(),
+            email: value.email().map(str::to_string),
+            provisioned: value.provisioned(),
+            linked_at_unix_timestamp: value.linked_at().unix_timestamp(),
+        }
+    }
+}
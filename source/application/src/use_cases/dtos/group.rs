@@ -0,0 +1,15 @@
+use nimbus_auth_domain::entities::{Entity, group::Group};
+
+pub struct GroupDto {
+    pub id: String,
+    pub name: String,
+}
+
+impl From<&Group> for GroupDto {
+    fn from(value: &Group) -> Self {
+        Self {
+            id: value.id().to_string(),
+            name: value.name().to_string(),
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use nimbus_auth_domain::{
+    entities::{Entity, oauth_client::OAuthClient},
+    value_objects::client_claims::ClientClaims,
+};
+
+pub struct OAuthClientDto {
+    pub id: String,
+    pub name: String,
+    pub redirect_uris: Vec<String>,
+    /// Confidential clients authenticate with a secret and can use the client credentials grant
+    pub confidential: bool,
+    pub scopes: Vec<String>,
+}
+
+impl From<&OAuthClient> for OAuthClientDto {
+    fn from(value: &OAuthClient) -> Self {
+        Self {
+            id: value.id().to_string(),
+            name: value.name().to_string(),
+            redirect_uris: value
+                .redirect_uris()
+                .iter()
+                .map(|redirect_uri| redirect_uri.to_string())
+                .collect(),
+            confidential: value.is_confidential(),
+            scopes: value
+                .scopes()
+                .iter()
+                .map(|scope| scope.to_string())
+                .collect(),
+        }
+    }
+}
+
+pub struct ClientClaimsDto {
+    pub id: String,
+    pub name: String,
+    pub scopes: Vec<String>,
+}
+
+impl From<&ClientClaims> for ClientClaimsDto {
+    fn from(value: &ClientClaims) -> Self {
+        Self {
+            id: value.id().to_string(),
+            name: value.name().to_string(),
+            scopes: value
+                .scopes()
+                .iter()
+                .map(|scope| scope.to_string())
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,8 @@
+use crate::use_cases::{ClientClaimsDto, UserClaimsDto};
+
+/// Whoever an access token is issued to
+pub enum PrincipalDto {
+    User(UserClaimsDto),
+    /// Client acting on its own behalf, without any user involved
+    Client(ClientClaimsDto),
+}
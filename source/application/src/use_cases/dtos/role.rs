@@ -0,0 +1,21 @@
+use nimbus_auth_domain::entities::role::Role;
+
+pub struct RoleDto {
+    pub name: String,
+    pub permissions: Vec<String>,
+    pub is_built_in: bool,
+}
+
+impl From<&Role> for RoleDto {
+    fn from(value: &Role) -> Self {
+        Self {
+            name: value.name().to_string(),
+            permissions: value
+                .permissions()
+                .iter()
+                .map(|permission| permission.to_string())
+                .collect(),
+            is_built_in: value.is_built_in(),
+        }
+    }
+}
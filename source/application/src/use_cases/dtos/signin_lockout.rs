@@ -0,0 +1,17 @@
+use nimbus_auth_domain::entities::user::value_objects::signin_lockout::SigninLockout;
+
+pub struct SigninLockoutDto {
+    pub failed_attempts: u32,
+    pub locked_until_unix_timestamp: Option<i64>,
+}
+
+impl From<&SigninLockout> for SigninLockoutDto {
+    fn from(value: &SigninLockout) -> Self {
+        Self {
+            failed_attempts: value.failed_attempts(),
+            locked_until_unix_timestamp: value
+                .locked_until()
+                .map(|locked_until| locked_until.unix_timestamp()),
+        }
+    }
+}
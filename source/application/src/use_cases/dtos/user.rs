@@ -1,10 +1,31 @@
-use nimbus_auth_domain::value_objects::user_claims::UserClaims;
-use nimbus_auth_shared::types::UserRole;
+use nimbus_auth_domain::{
+    entities::user::{SomeUser, value_objects::user_attributes::UserAttributes},
+    value_objects::{access_token::AccessToken, user_claims::UserClaims},
+};
+use nimbus_auth_shared::{
+    constants::{ADMIN_ROLE_NAME, AUTH_PERMISSIONS},
+    types::UserStatus,
+};
+use ulid::Ulid;
+
+use crate::use_cases::{GroupDto, SigninLockoutDto};
+
+const OPERATOR_NAME: &str = "operator";
 
 pub struct UserClaimsDto {
     pub id: String,
     pub name: String,
-    pub role: UserRole,
+    pub roles: Vec<String>,
+    pub permissions: Vec<String>,
+    pub groups: Vec<GroupDto>,
+    /// Some groups of the user are left out of the token
+    pub groups_overflow: bool,
+    /// JSON object
+    pub attributes: String,
+    /// Id of whoever uses the token of the user, e.g. an impersonating admin
+    pub actor: Option<String>,
+    /// Id of the api key the claims are resolved from, none for access tokens
+    pub api_key_id: Option<String>,
 }
 
 impl From<&UserClaims> for UserClaimsDto {
@@ -12,7 +33,97 @@ impl From<&UserClaims> for UserClaimsDto {
         Self {
             id: value.id().to_string(),
             name: value.name().to_string(),
-            role: value.role().clone(),
+            roles: value.roles().iter().map(|role| role.to_string()).collect(),
+            permissions: value
+                .permissions()
+                .iter()
+                .map(|permission| permission.to_string())
+                .collect(),
+            groups: value.groups().iter().map(GroupDto::from).collect(),
+            groups_overflow: value.groups_overflow(),
+            attributes: value.attributes().to_json(),
+            actor: None,
+            api_key_id: None,
+        }
+    }
+}
+
+impl From<&AccessToken> for UserClaimsDto {
+    fn from(value: &AccessToken) -> Self {
+        Self {
+            actor: value.actor().map(|actor| actor.subject().to_string()),
+            ..Self::from(value.user_claims())
+        }
+    }
+}
+
+impl UserClaimsDto {
+    /// Claims of an operator running offline admin commands
+    ///
+    /// The operator has direct access to the storage, so it is trusted as an admin without a token
+    pub fn operator() -> Self {
+        Self {
+            id: Ulid::nil().to_string(),
+            name: OPERATOR_NAME.to_string(),
+            roles: vec![ADMIN_ROLE_NAME.to_string()],
+            permissions: AUTH_PERMISSIONS
+                .iter()
+                .map(|permission| permission.to_string())
+                .collect(),
+            groups: Vec::new(),
+            groups_overflow: false,
+            attributes: UserAttributes::default().to_json(),
+            actor: None,
+            api_key_id: None,
+        }
+    }
+}
+
+/// User as seen by admins
+pub struct UserDetailsDto {
+    pub id: String,
+    pub name: String,
+    pub roles: Vec<String>,
+    pub permissions: Vec<String>,
+    pub groups: Vec<GroupDto>,
+    /// JSON object with all attributes, not only those in access tokens
+    pub attributes: String,
+    pub status: UserStatus,
+    pub suspension_reason: Option<String>,
+    pub suspended_until_unix_timestamp: Option<i64>,
+    pub deleted_at_unix_timestamp: Option<i64>,
+    pub signin_lockout: SigninLockoutDto,
+}
+
+impl From<&SomeUser<'_>> for UserDetailsDto {
+    fn from(value: &SomeUser<'_>) -> Self {
+        let (suspension_reason, suspended_until_unix_timestamp, deleted_at_unix_timestamp) =
+            match value {
+                SomeUser::Active(_) => (None, None, None),
+                SomeUser::Suspended(user) => (
+                    Some(user.reason().to_string()),
+                    user.suspended_until()
+                        .map(|suspended_until| suspended_until.unix_timestamp()),
+                    None,
+                ),
+                SomeUser::Deleted(user) => (None, None, Some(user.deleted_at().unix_timestamp())),
+            };
+        Self {
+            id: value.claims().id().to_string(),
+            name: value.name().to_string(),
+            roles: value.roles().iter().map(|role| role.to_string()).collect(),
+            permissions: value
+                .permissions()
+                .iter()
+                .map(|permission| permission.to_string())
+                .collect(),
+            groups: value.groups().iter().map(GroupDto::from).collect(),
+            attributes: value.attributes().to_json(),
+            status: value.status(),
+            suspension_reason,
+            suspended_until_unix_timestamp,
+            deleted_at_unix_timestamp,
+            signin_lockout: SigninLockoutDto::from(value.signin_lockout()),
         }
     }
 }
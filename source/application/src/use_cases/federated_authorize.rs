@@ -0,0 +1,125 @@
+use std::sync::Arc;
+
+use nimbus_auth_domain::{
+    entities::{
+        Entity,
+        federated_authorization::{
+            FederatedAuthorization,
+            specifications::{
+                NewFederatedAuthorizationSpecification, RedeemFederatedAuthorizationSpecification,
+            },
+        },
+        user::User,
+    },
+    value_objects::{id_token::IdToken, identifier::Identifier},
+};
+use nimbus_auth_shared::constants::FEDERATED_AUTHORIZATION_EXPIRATION_SECONDS;
+use ulid::Ulid;
+
+use crate::{
+    services::{
+        federated_authorization_repository::FederatedAuthorizationRepository,
+        identity_provider::IdentityProvider, time_service::TimeService,
+    },
+    use_cases::{
+        FederatedAuthorizeError, FederatedAuthorizeRequest, FederatedAuthorizeResponse,
+        RedeemFederatedAuthorizationError, UseCasesConfig, UseCasesServices,
+        guards::require_no_actor,
+    },
+};
+
+pub mod errors;
+pub mod schema;
+
+/// Starts the authorization code flow with an identity provider, for signin or for linking
+/// an identity to the account of the user
+///
+/// Impersonated and delegated tokens can not link identities, as those would let whoever acts sign in as the user
+pub async fn handle_federated_authorize<'a>(
+    FederatedAuthorizeRequest { provider, user }: FederatedAuthorizeRequest<'a>,
+    UseCasesServices {
+        identity_providers,
+        federated_authorization_repository,
+        time_service,
+        random_service,
+        ..
+    }: &UseCasesServices,
+    _: &UseCasesConfig,
+) -> Result<FederatedAuthorizeResponse, FederatedAuthorizeError> {
+    let identity_provider =
+        identity_providers
+            .get(provider)
+            .ok_or(FederatedAuthorizeError::ProviderIsNotFound {
+                provider: provider.to_string(),
+            })?;
+    let user_id = match user {
+        Some(user) => {
+            require_no_actor(&user)?;
+            Some(Identifier::from(Ulid::from_string(&user.id)?))
+        }
+        None => None,
+    };
+
+    let nonce = random_service.get_random_secret().await?;
+    // random secrets are 43 base64url characters, which is a valid PKCE code verifier
+    let code_verifier = random_service.get_random_secret().await?;
+    let authorization = FederatedAuthorization::new(NewFederatedAuthorizationSpecification {
+        provider: identity_provider.name(),
+        user_id,
+        nonce: nonce.to_string(),
+        code_verifier: code_verifier.to_string(),
+        current_time: time_service.get_current_time().await?,
+        expiration_seconds: FEDERATED_AUTHORIZATION_EXPIRATION_SECONDS,
+    });
+    federated_authorization_repository
+        .save(&authorization)
+        .await?;
+
+    let authorization_url = identity_provider
+        .get_authorization_url(
+            &authorization.id().to_string(),
+            authorization.nonce(),
+            &authorization.code_challenge(),
+        )
+        .await?;
+
+    Ok(FederatedAuthorizeResponse {
+        authorization_url,
+        state: authorization.id().to_string(),
+    })
+}
+
+/// Redeems the state the provider redirected back with and exchanges the code for a verified ID token
+pub(crate) async fn redeem_federated_authorization(
+    identity_provider: &dyn IdentityProvider,
+    state: &str,
+    code: &str,
+    user_id: Option<&Identifier<Ulid, User>>,
+    federated_authorization_repository: Arc<dyn FederatedAuthorizationRepository>,
+    time_service: Arc<dyn TimeService>,
+) -> Result<IdToken, RedeemFederatedAuthorizationError> {
+    let state =
+        Ulid::from_string(state).map_err(|_| RedeemFederatedAuthorizationError::StateIsNotFound)?;
+    let authorization = federated_authorization_repository
+        .take(&Identifier::from(state))
+        .await?
+        .ok_or(RedeemFederatedAuthorizationError::StateIsNotFound)?;
+    authorization.redeem(RedeemFederatedAuthorizationSpecification {
+        provider: identity_provider.name(),
+        user_id,
+        current_time: time_service.get_current_time().await?,
+    })?;
+
+    let signed_id_token = identity_provider
+        .exchange_code(code, authorization.code_verifier())
+        .await?;
+    let keys = identity_provider.get_signing_keys().await?;
+
+    Ok(IdToken::verify(
+        &signed_id_token,
+        &keys,
+        identity_provider.issuer(),
+        identity_provider.client_id(),
+        authorization.nonce(),
+    )?)
+}
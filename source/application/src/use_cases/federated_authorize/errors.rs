@@ -0,0 +1,49 @@
+use nimbus_auth_domain::{
+    entities::federated_authorization::errors::FederatedAuthorizationError,
+    value_objects::id_token::errors::IdTokenError,
+};
+use thiserror::Error;
+use ulid::DecodeError;
+
+use crate::{
+    services::{
+        federated_authorization_repository::errors::FederatedAuthorizationRepositoryError,
+        identity_provider::errors::IdentityProviderError,
+        random_service::errors::RandomServiceError, time_service::errors::TimeServiceError,
+    },
+    use_cases::ActorDeniedError,
+};
+
+#[derive(Debug, Error)]
+pub enum FederatedAuthorizeError {
+    #[error("identity provider: {provider} is not configured")]
+    ProviderIsNotFound { provider: String },
+    #[error(transparent)]
+    Forbidden(#[from] ActorDeniedError),
+    #[error("invalid user id. Error: {0}")]
+    InvalidUserId(#[from] DecodeError),
+    #[error(transparent)]
+    FederatedAuthorizationRepository(#[from] FederatedAuthorizationRepositoryError),
+    #[error(transparent)]
+    IdentityProvider(#[from] IdentityProviderError),
+    #[error(transparent)]
+    RandomService(#[from] RandomServiceError),
+    #[error(transparent)]
+    TimeService(#[from] TimeServiceError),
+}
+
+#[derive(Debug, Error)]
+pub enum RedeemFederatedAuthorizationError {
+    #[error("federated authorization with the state is not found")]
+    StateIsNotFound,
+    #[error(transparent)]
+    FederatedAuthorization(#[from] FederatedAuthorizationError),
+    #[error(transparent)]
+    FederatedAuthorizationRepository(#[from] FederatedAuthorizationRepositoryError),
+    #[error(transparent)]
+    IdentityProvider(#[from] IdentityProviderError),
+    #[error("invalid ID token. Error: {0}")]
+    InvalidIdToken(#[from] IdTokenError),
+    #[error(transparent)]
+    TimeService(#[from] TimeServiceError),
+}
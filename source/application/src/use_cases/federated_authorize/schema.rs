@@ -0,0 +1,14 @@
+use crate::use_cases::UserClaimsDto;
+
+pub struct FederatedAuthorizeRequest<'a> {
+    pub provider: &'a str,
+    /// User linking an identity to their account, there is none on signin
+    pub user: Option<UserClaimsDto>,
+}
+
+pub struct FederatedAuthorizeResponse {
+    /// Page of the provider the user should be sent to
+    pub authorization_url: String,
+    /// Client has to present it back along with the code the provider redirects with
+    pub state: String,
+}
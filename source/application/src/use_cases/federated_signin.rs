@@ -0,0 +1,218 @@
+use std::borrow::Cow;
+
+use nimbus_auth_domain::{
+    entities::{
+        Entity,
+        external_identity::{ExternalIdentity, specifications::NewExternalIdentitySpecification},
+        role::value_objects::role_name::RoleName,
+        session::{SomeSession, specifications::NewSessionSpecification},
+        user::{
+            SomeUser, User,
+            specifications::NewUserSpecification,
+            value_objects::{password::Password, password_hash::PasswordHash, user_name::UserName},
+        },
+    },
+    value_objects::{audiences::Audiences, id_token::IdToken},
+};
+
+use crate::use_cases::{
+    FederatedSignInError, FederatedSignInRequest, FederatedSignInResponse, UseCasesConfig,
+    UseCasesServices, UserClaimsDto,
+    dtos::{access_token::AccessTokenDto, session::SessionDto},
+    federated_authorize::redeem_federated_authorization,
+};
+
+pub mod errors;
+pub mod schema;
+
+/// Signs in the user linked to the identity the provider authenticated,
+/// a user is created for identities that are not linked yet
+///
+/// Existing users are never matched by email, they have to link the identity themselves
+pub async fn handle_federated_signin<'a>(
+    FederatedSignInRequest {
+        provider,
+        code,
+        state,
+        audiences,
+    }: FederatedSignInRequest<'a>,
+    services: &UseCasesServices,
+    config: &UseCasesConfig,
+) -> Result<FederatedSignInResponse, FederatedSignInError> {
+    let UseCasesServices {
+        identity_providers,
+        federated_authorization_repository,
+        external_identity_repository,
+        user_repository,
+        session_repository,
+        keypair_repository,
+        time_service,
+        ..
+    } = services;
+    let identity_provider =
+        identity_providers
+            .get(provider)
+            .ok_or(FederatedSignInError::ProviderIsNotFound {
+                provider: provider.to_string(),
+            })?;
+    let audiences = Audiences::from(audiences, &config.realm)?;
+
+    let id_token = redeem_federated_authorization(
+        identity_provider.as_ref(),
+        state,
+        code,
+        None,
+        federated_authorization_repository.clone(),
+        time_service.clone(),
+    )
+    .await?;
+
+    let user = match external_identity_repository
+        .get_by_subject(identity_provider.name(), id_token.subject())
+        .await?
+    {
+        Some(identity) => {
+            let user = user_repository
+                .get_by_id(identity.user_id())
+                .await?
+                .ok_or(FederatedSignInError::UserIsNotFound)?;
+            match user {
+                SomeUser::Active(user) => user.into_owned(),
+                SomeUser::Suspended(user) => {
+                    match user.is_suspension_over(time_service.get_current_time().await?) {
+                        true => {
+                            let user = user.into_owned().unsuspend();
+                            user_repository.save(SomeUser::from(&user)).await?;
+                            user
+                        }
+                        false => {
+                            return Err(FederatedSignInError::UserIsSuspended {
+                                user_name: user.name().to_string(),
+                                suspended_until: user.suspended_until(),
+                            });
+                        }
+                    }
+                }
+                SomeUser::Deleted(user) => {
+                    return Err(FederatedSignInError::UserIsDeleted {
+                        user_name: user.name().to_string(),
+                    });
+                }
+            }
+        }
+        None => provision_user(identity_provider.name(), &id_token, services, config).await?,
+    };
+
+    let active_keypair = keypair_repository
+        .get_active()
+        .await?
+        .ok_or(FederatedSignInError::ActiveKeyPairNotFound)?;
+
+    let session = SomeSession::new(NewSessionSpecification {
+        user_claims: user.claims().clone(),
+        client_id: None,
+        current_time: time_service.get_current_time().await?,
+        expiration_seconds: config.session_expiration_seconds,
+    });
+
+    let transactional_session_repository = session_repository.start_transaction().await?;
+
+    let (transactional_session_repository, _) = transactional_session_repository
+        .save(SomeSession::Active(Cow::Borrowed(&session)))
+        .await?;
+
+    let access_token = &session
+        .generate_access_token(
+            time_service.get_current_time().await?,
+            config.access_token_expiration_seconds,
+            config.access_token_max_groups,
+            &config.access_token_attributes,
+        )
+        .with_audiences(audiences);
+    let signed_access_token = access_token.sign(&active_keypair, &config.realm)?;
+
+    transactional_session_repository.commit().await?;
+
+    Ok(FederatedSignInResponse {
+        user: UserClaimsDto::from(access_token.user_claims()),
+        session: SessionDto {
+            session_id: session.id().to_string(),
+            session_expires_at_unix_timestamp: session.expires_at().unix_timestamp(),
+        },
+        access_token: AccessTokenDto {
+            signed_access_token,
+            signed_access_token_expires_at_unix_timestamp: access_token
+                .expires_at()
+                .unix_timestamp(),
+        },
+    })
+}
+
+/// Creates user for the identity, nobody knows the password of such user so it signs in only with the provider
+///
+/// User name is checked and the user is saved with its identity in a transaction, so concurrent signins do not take
+/// the same name and an interrupted provisioning does not leave the user without its identity
+async fn provision_user(
+    provider: &str,
+    id_token: &IdToken,
+    UseCasesServices {
+        user_repository,
+        role_repository,
+        time_service,
+        random_service,
+        ..
+    }: &UseCasesServices,
+    config: &UseCasesConfig,
+) -> Result<User, FederatedSignInError> {
+    let user_name = UserName::from(
+        id_token
+            .suggested_user_name()
+            .ok_or(FederatedSignInError::UserNameIsMissing)?,
+    )?;
+
+    let password = random_service.get_random_secret().await?;
+    let salt_b64 = random_service.get_random_salt_b64().await?;
+    let password_hash = PasswordHash::hash(
+        Password::from_unvalidated(&password),
+        &salt_b64,
+        &config.password_hashing_params,
+        &config.password_peppers,
+    )?;
+    let default_role = role_repository
+        .get_by_name(&RoleName::default_role())
+        .await?
+        .ok_or(FederatedSignInError::DefaultRoleNotFound)?;
+    let user = User::new(NewUserSpecification {
+        user_name,
+        password_hash,
+        roles: vec![default_role],
+    });
+    let identity = ExternalIdentity::new(NewExternalIdentitySpecification {
+        user_id: user.id().clone(),
+        provider,
+        subject: id_token.subject(),
+        email: id_token.email(),
+        provisioned: true,
+        current_time: time_service.get_current_time().await?,
+    });
+
+    let transactional_user_repository = user_repository.start_transaction().await?;
+    let (transactional_user_repository, existing_user) = transactional_user_repository
+        .get_by_name(user.name())
+        .await?;
+    if existing_user.is_some() {
+        transactional_user_repository.rollback().await?;
+        return Err(FederatedSignInError::UserNameIsTaken {
+            user_name: user.name().to_string(),
+        });
+    }
+    let (transactional_user_repository, _) = transactional_user_repository
+        .save(SomeUser::from(&user))
+        .await?;
+    let (transactional_user_repository, _) = transactional_user_repository
+        .save_external_identity(&identity)
+        .await?;
+    transactional_user_repository.commit().await?;
+
+    Ok(user)
+}
@@ -0,0 +1,68 @@
+use nimbus_auth_domain::{
+    entities::user::value_objects::{
+        password_hash::errors::PasswordHashError, user_name::errors::UserNameError,
+    },
+    value_objects::{
+        access_token::errors::SignAccessTokenError, audiences::errors::AudiencesError,
+    },
+};
+use thiserror::Error;
+use time::OffsetDateTime;
+
+use crate::{
+    services::{
+        external_identity_repository::errors::ExternalIdentityRepositoryError,
+        keypair_repository::errors::KeyPairRepositoryError,
+        random_service::errors::RandomServiceError, role_repository::errors::RoleRepositoryError,
+        session_repository::errors::SessionRepositoryError, time_service::errors::TimeServiceError,
+        user_repository::errors::UserRepositoryError,
+    },
+    use_cases::RedeemFederatedAuthorizationError,
+};
+
+#[derive(Debug, Error)]
+pub enum FederatedSignInError {
+    #[error("identity provider: {provider} is not configured")]
+    ProviderIsNotFound { provider: String },
+    #[error(transparent)]
+    InvalidAudiences(#[from] AudiencesError),
+    #[error(transparent)]
+    RedeemFederatedAuthorization(#[from] RedeemFederatedAuthorizationError),
+    #[error(transparent)]
+    ExternalIdentityRepository(#[from] ExternalIdentityRepositoryError),
+    #[error(transparent)]
+    UserRepository(#[from] UserRepositoryError),
+    #[error("user linked to the identity is not found")]
+    UserIsNotFound,
+    #[error("user with name: {user_name} is suspended until {suspended_until:?}")]
+    UserIsSuspended {
+        user_name: String,
+        suspended_until: Option<OffsetDateTime>,
+    },
+    #[error("user with name: {user_name} is deleted")]
+    UserIsDeleted { user_name: String },
+    #[error("ID token has neither preferred user name nor email to name the user after")]
+    UserNameIsMissing,
+    #[error(transparent)]
+    InvalidUserName(#[from] UserNameError),
+    #[error("user with name: {user_name} already exists")]
+    UserNameIsTaken { user_name: String },
+    #[error(transparent)]
+    PasswordHash(#[from] PasswordHashError),
+    #[error(transparent)]
+    RandomService(#[from] RandomServiceError),
+    #[error(transparent)]
+    RoleRepository(#[from] RoleRepositoryError),
+    #[error("default role not found")]
+    DefaultRoleNotFound,
+    #[error(transparent)]
+    SessionRepository(#[from] SessionRepositoryError),
+    #[error(transparent)]
+    TimeService(#[from] TimeServiceError),
+    #[error(transparent)]
+    KeyPairRepository(#[from] KeyPairRepositoryError),
+    #[error("active key pair not found")]
+    ActiveKeyPairNotFound,
+    #[error(transparent)]
+    SignAccessToken(#[from] SignAccessTokenError),
+}
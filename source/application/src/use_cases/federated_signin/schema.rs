@@ -0,0 +1,19 @@
+use crate::use_cases::{
+    UserClaimsDto,
+    dtos::{access_token::AccessTokenDto, session::SessionDto},
+};
+
+pub struct FederatedSignInRequest<'a> {
+    pub provider: &'a str,
+    /// Authorization code the provider redirected back with
+    pub code: &'a str,
+    pub state: &'a str,
+    /// Audiences the access token should target, limited to the realm allowlist
+    pub audiences: &'a [String],
+}
+
+pub struct FederatedSignInResponse {
+    pub user: UserClaimsDto,
+    pub session: SessionDto,
+    pub access_token: AccessTokenDto,
+}
@@ -1,12 +1,11 @@
-use std::{str::FromStr, sync::Arc};
+use std::str::FromStr;
 
 use nimbus_auth_domain::{entities::keypair::SomeKeyPair, value_objects::identifier::Identifier};
 use nimbus_auth_shared::errors::ErrorBoxed;
 use ulid::Ulid;
 
-use crate::{
-    services::keypair_repository::KeyPairRepository,
-    use_cases::{GetPublicKeyError, GetPublicKeyRequest, GetPublicKeyResponse},
+use crate::use_cases::{
+    GetPublicKeyError, GetPublicKeyRequest, GetPublicKeyResponse, UseCasesConfig, UseCasesServices,
 };
 
 pub mod errors;
@@ -14,7 +13,10 @@ pub mod schema;
 
 pub async fn handle_get_public_key<'a>(
     GetPublicKeyRequest { key_id }: GetPublicKeyRequest<'a>,
-    keypair_repository: Arc<dyn KeyPairRepository>,
+    UseCasesServices {
+        keypair_repository, ..
+    }: &UseCasesServices,
+    _: &UseCasesConfig,
 ) -> Result<GetPublicKeyResponse, GetPublicKeyError> {
     let keypair = match key_id {
         Some(key_id) => keypair_repository
@@ -0,0 +1,34 @@
+use nimbus_auth_domain::entities::user::value_objects::user_name::UserName;
+use nimbus_auth_shared::constants::PERMISSION_READ_USERS;
+
+use crate::use_cases::{
+    GetUserError, GetUserRequest, GetUserResponse, UseCasesConfig, UseCasesServices,
+    UserDetailsDto, guards::require_permission,
+};
+
+pub mod errors;
+pub mod schema;
+
+pub async fn handle_get_user<'a>(
+    GetUserRequest { user, user_name }: GetUserRequest<'a>,
+    UseCasesServices {
+        user_repository, ..
+    }: &UseCasesServices,
+    _: &UseCasesConfig,
+) -> Result<GetUserResponse, GetUserError> {
+    require_permission(&user, PERMISSION_READ_USERS)?;
+
+    let user_name = UserName::from(user_name)?;
+
+    let target_user =
+        user_repository
+            .get_by_name(&user_name)
+            .await?
+            .ok_or(GetUserError::UserIsNotFound {
+                user_name: user_name.to_string(),
+            })?;
+
+    Ok(GetUserResponse {
+        user: UserDetailsDto::from(&target_user),
+    })
+}
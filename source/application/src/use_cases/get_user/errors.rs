@@ -0,0 +1,18 @@
+use nimbus_auth_domain::entities::user::value_objects::user_name::errors::UserNameError;
+use thiserror::Error;
+
+use crate::{
+    services::user_repository::errors::UserRepositoryError, use_cases::PermissionDeniedError,
+};
+
+#[derive(Debug, Error)]
+pub enum GetUserError {
+    #[error(transparent)]
+    Forbidden(#[from] PermissionDeniedError),
+    #[error(transparent)]
+    InvalidUserName(#[from] UserNameError),
+    #[error(transparent)]
+    UserRepository(#[from] UserRepositoryError),
+    #[error("user with name: {user_name} is not found")]
+    UserIsNotFound { user_name: String },
+}
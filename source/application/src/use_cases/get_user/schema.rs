@@ -0,0 +1,10 @@
+use crate::use_cases::{UserClaimsDto, UserDetailsDto};
+
+pub struct GetUserRequest<'a> {
+    pub user: UserClaimsDto,
+    pub user_name: &'a str,
+}
+
+pub struct GetUserResponse {
+    pub user: UserDetailsDto,
+}
@@ -0,0 +1,32 @@
+use nimbus_auth_domain::entities::user::value_objects::user_name::UserName;
+use nimbus_auth_shared::constants::PERMISSION_READ_USERS;
+
+use crate::use_cases::{
+    GetUserSigninLockoutError, GetUserSigninLockoutRequest, GetUserSigninLockoutResponse,
+    SigninLockoutDto, UseCasesConfig, UseCasesServices, guards::require_permission,
+};
+
+pub mod errors;
+pub mod schema;
+
+pub async fn handle_get_user_signin_lockout<'a>(
+    GetUserSigninLockoutRequest { user, user_name }: GetUserSigninLockoutRequest<'a>,
+    UseCasesServices {
+        user_repository, ..
+    }: &UseCasesServices,
+    _: &UseCasesConfig,
+) -> Result<GetUserSigninLockoutResponse, GetUserSigninLockoutError> {
+    require_permission(&user, PERMISSION_READ_USERS)?;
+
+    let user_name = UserName::from(user_name)?;
+
+    let target_user = user_repository.get_by_name(&user_name).await?.ok_or(
+        GetUserSigninLockoutError::UserIsNotFound {
+            user_name: user_name.to_string(),
+        },
+    )?;
+
+    Ok(GetUserSigninLockoutResponse {
+        signin_lockout: SigninLockoutDto::from(target_user.signin_lockout()),
+    })
+}
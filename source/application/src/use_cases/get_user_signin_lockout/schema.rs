@@ -0,0 +1,10 @@
+use crate::use_cases::{SigninLockoutDto, UserClaimsDto};
+
+pub struct GetUserSigninLockoutRequest<'a> {
+    pub user: UserClaimsDto,
+    pub user_name: &'a str,
+}
+
+pub struct GetUserSigninLockoutResponse {
+    pub signin_lockout: SigninLockoutDto,
+}
@@ -0,0 +1,39 @@
+use crate::use_cases::{ActorDeniedError, ApiKeyDeniedError, PermissionDeniedError, UserClaimsDto};
+
+pub mod errors;
+
+/// Checks that the user's access token grants the permission
+pub fn require_permission(
+    user: &UserClaimsDto,
+    permission: &str,
+) -> Result<(), PermissionDeniedError> {
+    match user.permissions.iter().any(|granted| granted == permission) {
+        true => Ok(()),
+        false => Err(PermissionDeniedError {
+            user_name: user.name.clone(),
+            permission: permission.to_string(),
+        }),
+    }
+}
+
+/// Checks that the user uses their own token, credentials can not be created by whoever acts for them
+pub fn require_no_actor(user: &UserClaimsDto) -> Result<(), ActorDeniedError> {
+    match &user.actor {
+        None => Ok(()),
+        Some(actor) => Err(ActorDeniedError {
+            user_name: user.name.clone(),
+            actor: actor.clone(),
+        }),
+    }
+}
+
+/// Checks that the user is authorized by an access token, keys can not mint credentials outliving them
+pub fn require_no_api_key(user: &UserClaimsDto) -> Result<(), ApiKeyDeniedError> {
+    match &user.api_key_id {
+        None => Ok(()),
+        Some(api_key_id) => Err(ApiKeyDeniedError {
+            user_name: user.name.clone(),
+            api_key_id: api_key_id.clone(),
+        }),
+    }
+}
@@ -0,0 +1,22 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+#[error("operation forbidden, user: {user_name} lacks permission: {permission}")]
+pub struct PermissionDeniedError {
+    pub user_name: String,
+    pub permission: String,
+}
+
+#[derive(Debug, Error)]
+#[error("operation forbidden, token of user: {user_name} is used by actor: {actor}")]
+pub struct ActorDeniedError {
+    pub user_name: String,
+    pub actor: String,
+}
+
+#[derive(Debug, Error)]
+#[error("operation forbidden, user: {user_name} is authorized by api key: {api_key_id}")]
+pub struct ApiKeyDeniedError {
+    pub user_name: String,
+    pub api_key_id: String,
+}
@@ -0,0 +1,88 @@
+use nimbus_auth_domain::{
+    entities::{
+        Entity,
+        impersonation::{Impersonation, specifications::NewImpersonationSpecification},
+        user::{SomeUser, value_objects::user_name::UserName},
+    },
+    value_objects::identifier::Identifier,
+};
+use nimbus_auth_shared::constants::{
+    IMPERSONATION_ACCESS_TOKEN_EXPIRATION_SECONDS, PERMISSION_IMPERSONATE_USERS,
+};
+use ulid::Ulid;
+
+use crate::use_cases::{
+    ImpersonateUserError, ImpersonateUserRequest, ImpersonateUserResponse, UseCasesConfig,
+    UseCasesServices, dtos::access_token::AccessTokenDto, guards::require_permission,
+};
+
+pub mod errors;
+pub mod schema;
+
+/// Issues a short lived token of the user naming the admin as its actor, there is no session to refresh it
+///
+/// The impersonation is recorded before the token is signed, so no token is issued without an audit record
+pub async fn handle_impersonate_user<'a>(
+    ImpersonateUserRequest {
+        user,
+        user_name,
+        reason,
+    }: ImpersonateUserRequest<'a>,
+    UseCasesServices {
+        user_repository,
+        keypair_repository,
+        impersonation_repository,
+        time_service,
+        ..
+    }: &UseCasesServices,
+    config: &UseCasesConfig,
+) -> Result<ImpersonateUserResponse, ImpersonateUserError> {
+    require_permission(&user, PERMISSION_IMPERSONATE_USERS)?;
+
+    let admin_id = Identifier::from(Ulid::from_string(&user.id)?);
+    let user_name = UserName::from(user_name)?;
+
+    let target_user = match user_repository.get_by_name(&user_name).await?.ok_or(
+        ImpersonateUserError::UserIsNotFound {
+            user_name: user_name.to_string(),
+        },
+    )? {
+        SomeUser::Active(user) => user.into_owned(),
+        _ => {
+            return Err(ImpersonateUserError::UserIsNotActive {
+                user_name: user_name.to_string(),
+            });
+        }
+    };
+
+    let active_keypair = keypair_repository
+        .get_active()
+        .await?
+        .ok_or(ImpersonateUserError::ActiveKeyPairNotFound)?;
+
+    let impersonation = Impersonation::new(NewImpersonationSpecification {
+        admin_id,
+        user_id: target_user.id().clone(),
+        reason,
+        current_time: time_service.get_current_time().await?,
+        expiration_seconds: IMPERSONATION_ACCESS_TOKEN_EXPIRATION_SECONDS,
+    })?;
+    impersonation_repository.save(&impersonation).await?;
+
+    let access_token = impersonation.generate_access_token(
+        target_user.claims().clone(),
+        config.access_token_max_groups,
+        &config.access_token_attributes,
+    );
+    let signed_access_token = access_token.sign(&active_keypair, &config.realm)?;
+
+    Ok(ImpersonateUserResponse {
+        impersonation_id: impersonation.id().to_string(),
+        access_token: AccessTokenDto {
+            signed_access_token,
+            signed_access_token_expires_at_unix_timestamp: access_token
+                .expires_at()
+                .unix_timestamp(),
+        },
+    })
+}
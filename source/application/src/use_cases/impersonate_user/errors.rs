@@ -0,0 +1,46 @@
+use nimbus_auth_domain::{
+    entities::{
+        impersonation::errors::ImpersonationError,
+        user::value_objects::user_name::errors::UserNameError,
+    },
+    value_objects::access_token::errors::SignAccessTokenError,
+};
+use thiserror::Error;
+use ulid::DecodeError;
+
+use crate::{
+    services::{
+        impersonation_repository::errors::ImpersonationRepositoryError,
+        keypair_repository::errors::KeyPairRepositoryError, time_service::errors::TimeServiceError,
+        user_repository::errors::UserRepositoryError,
+    },
+    use_cases::PermissionDeniedError,
+};
+
+#[derive(Debug, Error)]
+pub enum ImpersonateUserError {
+    #[error(transparent)]
+    Forbidden(#[from] PermissionDeniedError),
+    #[error("invalid admin id. Error: {0}")]
+    InvalidAdminId(#[from] DecodeError),
+    #[error(transparent)]
+    InvalidUserName(#[from] UserNameError),
+    #[error("user with name: {user_name} is not found")]
+    UserIsNotFound { user_name: String },
+    #[error("user with name: {user_name} is not active")]
+    UserIsNotActive { user_name: String },
+    #[error(transparent)]
+    Impersonation(#[from] ImpersonationError),
+    #[error(transparent)]
+    UserRepository(#[from] UserRepositoryError),
+    #[error(transparent)]
+    ImpersonationRepository(#[from] ImpersonationRepositoryError),
+    #[error(transparent)]
+    KeyPairRepository(#[from] KeyPairRepositoryError),
+    #[error(transparent)]
+    TimeService(#[from] TimeServiceError),
+    #[error("active key pair not found")]
+    ActiveKeyPairNotFound,
+    #[error(transparent)]
+    SignAccessToken(#[from] SignAccessTokenError),
+}
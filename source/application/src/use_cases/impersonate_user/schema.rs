@@ -0,0 +1,13 @@
+use crate::use_cases::{UserClaimsDto, dtos::access_token::AccessTokenDto};
+
+pub struct ImpersonateUserRequest<'a> {
+    pub user: UserClaimsDto,
+    pub user_name: &'a str,
+    /// Kept in the audit trail, e.g. the support ticket being reproduced
+    pub reason: &'a str,
+}
+
+pub struct ImpersonateUserResponse {
+    pub impersonation_id: String,
+    pub access_token: AccessTokenDto,
+}
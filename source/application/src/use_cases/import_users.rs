@@ -0,0 +1,101 @@
+use std::collections::HashSet;
+
+use nimbus_auth_domain::entities::{
+    role::{Role, value_objects::role_name::RoleName},
+    user::{
+        SomeUser, User,
+        specifications::NewUserSpecification,
+        value_objects::{password_hash::PasswordHash, user_name::UserName},
+    },
+};
+
+use crate::use_cases::{
+    UseCasesConfig, UseCasesServices,
+    import_users::{
+        errors::{ImportUserRejection, ImportUsersError},
+        schema::{ImportUserRecord, ImportUsersRequest, ImportUsersResponse, RejectedImportUser},
+    },
+};
+
+pub mod errors;
+pub mod schema;
+
+/// Imports users with password hashes from other systems in a single transaction
+///
+/// Records which can not be imported are skipped and reported in response, legacy hashes are upgraded on the next signin.
+/// A taken or duplicated user name rolls back the whole import, so it can be fixed and run again
+pub async fn handle_import_users<'a>(
+    ImportUsersRequest { users }: ImportUsersRequest<'a>,
+    UseCasesServices {
+        user_repository,
+        role_repository,
+        ..
+    }: &UseCasesServices,
+    _: &UseCasesConfig,
+) -> Result<ImportUsersResponse, ImportUsersError> {
+    let default_role = role_repository
+        .get_by_name(&RoleName::default_role())
+        .await?
+        .ok_or(ImportUsersError::DefaultRoleNotFound)?;
+
+    let mut transactional_user_repository = user_repository.start_transaction().await?;
+    let mut imported_user_names = HashSet::new();
+    let mut imported_count = 0;
+    let mut rejected = Vec::new();
+
+    for record in users {
+        let user = match new_imported_user(record, &default_role) {
+            Ok(user) => user,
+            Err(reason) => {
+                rejected.push(RejectedImportUser {
+                    user_name: record.user_name.clone(),
+                    reason,
+                });
+                continue;
+            }
+        };
+
+        if !imported_user_names.insert(user.name().to_string()) {
+            transactional_user_repository.rollback().await?;
+            return Err(ImportUsersError::DuplicateInImport {
+                user_name: user.name().to_string(),
+            });
+        }
+
+        let (repository, existing_user) = transactional_user_repository
+            .get_by_name(user.name())
+            .await?;
+        transactional_user_repository = repository;
+
+        if existing_user.is_some() {
+            transactional_user_repository.rollback().await?;
+            return Err(ImportUsersError::UserAlreadyExists {
+                user_name: user.name().to_string(),
+            });
+        }
+
+        let (repository, _) = transactional_user_repository
+            .save(SomeUser::from(user))
+            .await?;
+        transactional_user_repository = repository;
+        imported_count += 1;
+    }
+
+    transactional_user_repository.commit().await?;
+
+    Ok(ImportUsersResponse {
+        imported_count,
+        rejected,
+    })
+}
+
+fn new_imported_user(
+    record: &ImportUserRecord,
+    default_role: &Role,
+) -> Result<User, ImportUserRejection> {
+    Ok(User::new(NewUserSpecification {
+        user_name: UserName::from(&record.user_name)?,
+        password_hash: PasswordHash::from(&record.password_hash)?,
+        roles: vec![default_role.clone()],
+    }))
+}
@@ -0,0 +1,30 @@
+use nimbus_auth_domain::entities::user::value_objects::{
+    password_hash::errors::PasswordHashError, user_name::errors::UserNameError,
+};
+use thiserror::Error;
+
+use crate::services::{
+    role_repository::errors::RoleRepositoryError, user_repository::errors::UserRepositoryError,
+};
+
+#[derive(Debug, Error)]
+pub enum ImportUsersError {
+    #[error(transparent)]
+    UserRepository(#[from] UserRepositoryError),
+    #[error(transparent)]
+    RoleRepository(#[from] RoleRepositoryError),
+    #[error("default role not found")]
+    DefaultRoleNotFound,
+    #[error("user {user_name} already exists")]
+    UserAlreadyExists { user_name: String },
+    #[error("user {user_name} is duplicated in import")]
+    DuplicateInImport { user_name: String },
+}
+
+#[derive(Debug, Error)]
+pub enum ImportUserRejection {
+    #[error(transparent)]
+    InvalidUserName(#[from] UserNameError),
+    #[error(transparent)]
+    InvalidPasswordHash(#[from] PasswordHashError),
+}
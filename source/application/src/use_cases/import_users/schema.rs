@@ -0,0 +1,20 @@
+use crate::use_cases::import_users::errors::ImportUserRejection;
+
+pub struct ImportUsersRequest<'a> {
+    pub users: &'a [ImportUserRecord],
+}
+
+pub struct ImportUserRecord {
+    pub user_name: String,
+    pub password_hash: String,
+}
+
+pub struct ImportUsersResponse {
+    pub imported_count: usize,
+    pub rejected: Vec<RejectedImportUser>,
+}
+
+pub struct RejectedImportUser {
+    pub user_name: String,
+    pub reason: ImportUserRejection,
+}
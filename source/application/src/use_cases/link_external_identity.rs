@@ -0,0 +1,92 @@
+use nimbus_auth_domain::{
+    entities::external_identity::{
+        ExternalIdentity, specifications::NewExternalIdentitySpecification,
+    },
+    value_objects::identifier::Identifier,
+};
+use ulid::Ulid;
+
+use crate::use_cases::{
+    ExternalIdentityDto, LinkExternalIdentityError, LinkExternalIdentityRequest,
+    LinkExternalIdentityResponse, UseCasesConfig, UseCasesServices,
+    federated_authorize::redeem_federated_authorization, guards::require_no_actor,
+};
+
+pub mod errors;
+pub mod schema;
+
+/// Links the identity the provider authenticated to the user, the flow has to be started by the same user
+///
+/// Impersonated and delegated tokens can not link identities, as those would let whoever acts sign in as the user
+pub async fn handle_link_external_identity<'a>(
+    LinkExternalIdentityRequest {
+        user,
+        provider,
+        code,
+        state,
+    }: LinkExternalIdentityRequest<'a>,
+    UseCasesServices {
+        identity_providers,
+        federated_authorization_repository,
+        external_identity_repository,
+        time_service,
+        ..
+    }: &UseCasesServices,
+    _: &UseCasesConfig,
+) -> Result<LinkExternalIdentityResponse, LinkExternalIdentityError> {
+    require_no_actor(&user)?;
+
+    let identity_provider =
+        identity_providers
+            .get(provider)
+            .ok_or(LinkExternalIdentityError::ProviderIsNotFound {
+                provider: provider.to_string(),
+            })?;
+    let user_id = Identifier::from(Ulid::from_string(&user.id)?);
+
+    let id_token = redeem_federated_authorization(
+        identity_provider.as_ref(),
+        state,
+        code,
+        Some(&user_id),
+        federated_authorization_repository.clone(),
+        time_service.clone(),
+    )
+    .await?;
+
+    if let Some(identity) = external_identity_repository
+        .get_by_subject(identity_provider.name(), id_token.subject())
+        .await?
+    {
+        return match identity.user_id() == &user_id {
+            true => Ok(LinkExternalIdentityResponse {
+                identity: ExternalIdentityDto::from(&identity),
+            }),
+            false => Err(LinkExternalIdentityError::IdentityIsLinkedToAnotherUser),
+        };
+    }
+    if external_identity_repository
+        .get_by_user_id(&user_id)
+        .await?
+        .iter()
+        .any(|identity| identity.provider() == identity_provider.name())
+    {
+        return Err(LinkExternalIdentityError::ProviderIsAlreadyLinked {
+            provider: provider.to_string(),
+        });
+    }
+
+    let identity = ExternalIdentity::new(NewExternalIdentitySpecification {
+        user_id,
+        provider: identity_provider.name(),
+        subject: id_token.subject(),
+        email: id_token.email(),
+        provisioned: false,
+        current_time: time_service.get_current_time().await?,
+    });
+    external_identity_repository.save(&identity).await?;
+
+    Ok(LinkExternalIdentityResponse {
+        identity: ExternalIdentityDto::from(&identity),
+    })
+}
@@ -0,0 +1,30 @@
+use thiserror::Error;
+use ulid::DecodeError;
+
+use crate::{
+    services::{
+        external_identity_repository::errors::ExternalIdentityRepositoryError,
+        time_service::errors::TimeServiceError,
+    },
+    use_cases::{ActorDeniedError, RedeemFederatedAuthorizationError},
+};
+
+#[derive(Debug, Error)]
+pub enum LinkExternalIdentityError {
+    #[error(transparent)]
+    Forbidden(#[from] ActorDeniedError),
+    #[error("identity provider: {provider} is not configured")]
+    ProviderIsNotFound { provider: String },
+    #[error("invalid user id. Error: {0}")]
+    InvalidUserId(#[from] DecodeError),
+    #[error(transparent)]
+    RedeemFederatedAuthorization(#[from] RedeemFederatedAuthorizationError),
+    #[error("identity is linked to another user")]
+    IdentityIsLinkedToAnotherUser,
+    #[error("user already has an identity linked at provider: {provider}")]
+    ProviderIsAlreadyLinked { provider: String },
+    #[error(transparent)]
+    ExternalIdentityRepository(#[from] ExternalIdentityRepositoryError),
+    #[error(transparent)]
+    TimeService(#[from] TimeServiceError),
+}
@@ -0,0 +1,13 @@
+use crate::use_cases::{ExternalIdentityDto, UserClaimsDto};
+
+pub struct LinkExternalIdentityRequest<'a> {
+    pub user: UserClaimsDto,
+    pub provider: &'a str,
+    /// Authorization code the provider redirected back with
+    pub code: &'a str,
+    pub state: &'a str,
+}
+
+pub struct LinkExternalIdentityResponse {
+    pub identity: ExternalIdentityDto,
+}
@@ -0,0 +1,27 @@
+use nimbus_auth_domain::value_objects::identifier::Identifier;
+use ulid::Ulid;
+
+use crate::use_cases::{
+    ApiKeyDto, ListApiKeysError, ListApiKeysRequest, ListApiKeysResponse, UseCasesConfig,
+    UseCasesServices,
+};
+
+pub mod errors;
+pub mod schema;
+
+/// Lists api keys of the user, including revoked and expired ones
+pub async fn handle_list_api_keys(
+    ListApiKeysRequest { user }: ListApiKeysRequest,
+    UseCasesServices {
+        api_key_repository, ..
+    }: &UseCasesServices,
+    _: &UseCasesConfig,
+) -> Result<ListApiKeysResponse, ListApiKeysError> {
+    let user_id = Identifier::from(Ulid::from_string(&user.id)?);
+
+    let api_keys = api_key_repository.get_by_user_id(&user_id).await?;
+
+    Ok(ListApiKeysResponse {
+        api_keys: api_keys.iter().map(ApiKeyDto::from).collect(),
+    })
+}
@@ -0,0 +1,12 @@
+use thiserror::Error;
+use ulid::DecodeError;
+
+use crate::services::api_key_repository::errors::ApiKeyRepositoryError;
+
+#[derive(Debug, Error)]
+pub enum ListApiKeysError {
+    #[error("invalid user id. Error: {0}")]
+    InvalidUserId(#[from] DecodeError),
+    #[error(transparent)]
+    ApiKeyRepository(#[from] ApiKeyRepositoryError),
+}
@@ -0,0 +1,9 @@
+use crate::use_cases::{ApiKeyDto, UserClaimsDto};
+
+pub struct ListApiKeysRequest {
+    pub user: UserClaimsDto,
+}
+
+pub struct ListApiKeysResponse {
+    pub api_keys: Vec<ApiKeyDto>,
+}
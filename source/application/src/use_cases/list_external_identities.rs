@@ -0,0 +1,30 @@
+use nimbus_auth_domain::value_objects::identifier::Identifier;
+use ulid::Ulid;
+
+use crate::use_cases::{
+    ExternalIdentityDto, ListExternalIdentitiesError, ListExternalIdentitiesRequest,
+    ListExternalIdentitiesResponse, UseCasesConfig, UseCasesServices,
+};
+
+pub mod errors;
+pub mod schema;
+
+/// Lists identities linked to the user, including ones at providers that are not configured anymore
+pub async fn handle_list_external_identities(
+    ListExternalIdentitiesRequest { user }: ListExternalIdentitiesRequest,
+    UseCasesServices {
+        external_identity_repository,
+        ..
+    }: &UseCasesServices,
+    _: &UseCasesConfig,
+) -> Result<ListExternalIdentitiesResponse, ListExternalIdentitiesError> {
+    let user_id = Identifier::from(Ulid::from_string(&user.id)?);
+
+    let identities = external_identity_repository
+        .get_by_user_id(&user_id)
+        .await?;
+
+    Ok(ListExternalIdentitiesResponse {
+        identities: identities.iter().map(ExternalIdentityDto::from).collect(),
+    })
+}
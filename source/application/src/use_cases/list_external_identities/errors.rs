@@ -0,0 +1,12 @@
+use thiserror::Error;
+use ulid::DecodeError;
+
+use crate::services::external_identity_repository::errors::ExternalIdentityRepositoryError;
+
+#[derive(Debug, Error)]
+pub enum ListExternalIdentitiesError {
+    #[error("invalid user id. Error: {0}")]
+    InvalidUserId(#[from] DecodeError),
+    #[error(transparent)]
+    ExternalIdentityRepository(#[from] ExternalIdentityRepositoryError),
+}
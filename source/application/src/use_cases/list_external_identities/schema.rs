@@ -0,0 +1,9 @@
+use crate::use_cases::{ExternalIdentityDto, UserClaimsDto};
+
+pub struct ListExternalIdentitiesRequest {
+    pub user: UserClaimsDto,
+}
+
+pub struct ListExternalIdentitiesResponse {
+    pub identities: Vec<ExternalIdentityDto>,
+}
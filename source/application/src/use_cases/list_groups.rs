@@ -0,0 +1,25 @@
+use nimbus_auth_shared::constants::PERMISSION_MANAGE_GROUPS;
+
+use crate::use_cases::{
+    GroupDto, ListGroupsError, ListGroupsRequest, ListGroupsResponse, UseCasesConfig,
+    UseCasesServices, guards::require_permission,
+};
+
+pub mod errors;
+pub mod schema;
+
+pub async fn handle_list_groups(
+    ListGroupsRequest { user }: ListGroupsRequest,
+    UseCasesServices {
+        group_repository, ..
+    }: &UseCasesServices,
+    _: &UseCasesConfig,
+) -> Result<ListGroupsResponse, ListGroupsError> {
+    require_permission(&user, PERMISSION_MANAGE_GROUPS)?;
+
+    let groups = group_repository.list().await?;
+
+    Ok(ListGroupsResponse {
+        groups: groups.iter().map(GroupDto::from).collect(),
+    })
+}
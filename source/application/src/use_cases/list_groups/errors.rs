@@ -0,0 +1,13 @@
+use thiserror::Error;
+
+use crate::{
+    services::group_repository::errors::GroupRepositoryError, use_cases::PermissionDeniedError,
+};
+
+#[derive(Debug, Error)]
+pub enum ListGroupsError {
+    #[error(transparent)]
+    Forbidden(#[from] PermissionDeniedError),
+    #[error(transparent)]
+    GroupRepository(#[from] GroupRepositoryError),
+}
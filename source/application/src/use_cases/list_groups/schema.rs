@@ -0,0 +1,9 @@
+use crate::use_cases::{GroupDto, UserClaimsDto};
+
+pub struct ListGroupsRequest {
+    pub user: UserClaimsDto,
+}
+
+pub struct ListGroupsResponse {
+    pub groups: Vec<GroupDto>,
+}
@@ -0,0 +1,41 @@
+use nimbus_auth_domain::entities::{Entity, keypair::SomeKeyPair};
+
+use crate::use_cases::{
+    ListPublicKeysError, ListPublicKeysRequest, ListPublicKeysResponse, PublicKeyDto,
+    UseCasesConfig, UseCasesServices,
+};
+
+pub mod errors;
+pub mod schema;
+
+/// Lists public keys of all key pairs which verify access tokens, i.e. active and expiring ones
+pub async fn handle_list_public_keys(
+    _: ListPublicKeysRequest,
+    UseCasesServices {
+        keypair_repository, ..
+    }: &UseCasesServices,
+    _: &UseCasesConfig,
+) -> Result<ListPublicKeysResponse, ListPublicKeysError> {
+    let keypairs = keypair_repository.get_verifying().await?;
+
+    Ok(ListPublicKeysResponse {
+        public_keys: keypairs
+            .iter()
+            .filter_map(|keypair| {
+                let (public_key_pem, expires_at_unix_timestamp) = match keypair {
+                    SomeKeyPair::Active(keypair) => (keypair.value().public_key_pem(), None),
+                    SomeKeyPair::Expiring(keypair) => (
+                        keypair.value().public_key_pem(),
+                        Some(keypair.expires_at().unix_timestamp()),
+                    ),
+                    SomeKeyPair::Expired(_) | SomeKeyPair::Revoked(_) => return None,
+                };
+                Some(PublicKeyDto {
+                    key_id: keypair.id().to_string(),
+                    public_key_pem,
+                    expires_at_unix_timestamp,
+                })
+            })
+            .collect(),
+    })
+}
@@ -0,0 +1,9 @@
+use thiserror::Error;
+
+use crate::services::keypair_repository::errors::KeyPairRepositoryError;
+
+#[derive(Debug, Error)]
+pub enum ListPublicKeysError {
+    #[error(transparent)]
+    KeyPairRepository(#[from] KeyPairRepositoryError),
+}
@@ -0,0 +1,12 @@
+pub struct ListPublicKeysRequest {}
+
+pub struct ListPublicKeysResponse {
+    pub public_keys: Vec<PublicKeyDto>,
+}
+
+pub struct PublicKeyDto {
+    pub key_id: String,
+    pub public_key_pem: String,
+    /// Absent for the active key pair
+    pub expires_at_unix_timestamp: Option<i64>,
+}
@@ -0,0 +1,25 @@
+use nimbus_auth_shared::constants::PERMISSION_MANAGE_ROLES;
+
+use crate::use_cases::{
+    ListRolesError, ListRolesRequest, ListRolesResponse, RoleDto, UseCasesConfig, UseCasesServices,
+    guards::require_permission,
+};
+
+pub mod errors;
+pub mod schema;
+
+pub async fn handle_list_roles(
+    ListRolesRequest { user }: ListRolesRequest,
+    UseCasesServices {
+        role_repository, ..
+    }: &UseCasesServices,
+    _: &UseCasesConfig,
+) -> Result<ListRolesResponse, ListRolesError> {
+    require_permission(&user, PERMISSION_MANAGE_ROLES)?;
+
+    let roles = role_repository.list().await?;
+
+    Ok(ListRolesResponse {
+        roles: roles.iter().map(RoleDto::from).collect(),
+    })
+}
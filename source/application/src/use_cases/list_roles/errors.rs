@@ -0,0 +1,13 @@
+use thiserror::Error;
+
+use crate::{
+    services::role_repository::errors::RoleRepositoryError, use_cases::PermissionDeniedError,
+};
+
+#[derive(Debug, Error)]
+pub enum ListRolesError {
+    #[error(transparent)]
+    Forbidden(#[from] PermissionDeniedError),
+    #[error(transparent)]
+    RoleRepository(#[from] RoleRepositoryError),
+}
@@ -0,0 +1,9 @@
+use crate::use_cases::{RoleDto, UserClaimsDto};
+
+pub struct ListRolesRequest {
+    pub user: UserClaimsDto,
+}
+
+pub struct ListRolesResponse {
+    pub roles: Vec<RoleDto>,
+}
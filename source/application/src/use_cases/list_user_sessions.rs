@@ -0,0 +1,46 @@
+use nimbus_auth_domain::entities::{Entity, user::value_objects::user_name::UserName};
+use nimbus_auth_shared::constants::PERMISSION_READ_USERS;
+
+use crate::use_cases::{
+    ListUserSessionsError, ListUserSessionsRequest, ListUserSessionsResponse, SessionDto,
+    UseCasesConfig, UseCasesServices, guards::require_permission,
+};
+
+pub mod errors;
+pub mod schema;
+
+/// Lists active sessions of the user
+pub async fn handle_list_user_sessions<'a>(
+    ListUserSessionsRequest { user, user_name }: ListUserSessionsRequest<'a>,
+    UseCasesServices {
+        user_repository,
+        session_repository,
+        time_service,
+        ..
+    }: &UseCasesServices,
+    _: &UseCasesConfig,
+) -> Result<ListUserSessionsResponse, ListUserSessionsError> {
+    require_permission(&user, PERMISSION_READ_USERS)?;
+
+    let user_name = UserName::from(user_name)?;
+
+    let target_user = user_repository.get_by_name(&user_name).await?.ok_or(
+        ListUserSessionsError::UserIsNotFound {
+            user_name: user_name.to_string(),
+        },
+    )?;
+
+    let sessions = session_repository
+        .get_active_by_user_id(target_user.id(), time_service.get_current_time().await?)
+        .await?;
+
+    Ok(ListUserSessionsResponse {
+        sessions: sessions
+            .iter()
+            .map(|session| SessionDto {
+                session_id: session.id().to_string(),
+                session_expires_at_unix_timestamp: session.expires_at().unix_timestamp(),
+            })
+            .collect(),
+    })
+}
@@ -0,0 +1,26 @@
+use nimbus_auth_domain::entities::user::value_objects::user_name::errors::UserNameError;
+use thiserror::Error;
+
+use crate::{
+    services::{
+        session_repository::errors::SessionRepositoryError, time_service::errors::TimeServiceError,
+        user_repository::errors::UserRepositoryError,
+    },
+    use_cases::PermissionDeniedError,
+};
+
+#[derive(Debug, Error)]
+pub enum ListUserSessionsError {
+    #[error(transparent)]
+    Forbidden(#[from] PermissionDeniedError),
+    #[error(transparent)]
+    InvalidUserName(#[from] UserNameError),
+    #[error(transparent)]
+    UserRepository(#[from] UserRepositoryError),
+    #[error(transparent)]
+    SessionRepository(#[from] SessionRepositoryError),
+    #[error(transparent)]
+    TimeService(#[from] TimeServiceError),
+    #[error("user with name: {user_name} is not found")]
+    UserIsNotFound { user_name: String },
+}
@@ -0,0 +1,10 @@
+use crate::use_cases::{SessionDto, UserClaimsDto};
+
+pub struct ListUserSessionsRequest<'a> {
+    pub user: UserClaimsDto,
+    pub user_name: &'a str,
+}
+
+pub struct ListUserSessionsResponse {
+    pub sessions: Vec<SessionDto>,
+}
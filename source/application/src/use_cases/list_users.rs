@@ -0,0 +1,77 @@
+use nimbus_auth_domain::{
+    entities::{Entity, role::value_objects::role_name::RoleName},
+    value_objects::identifier::Identifier,
+};
+use nimbus_auth_shared::constants::{
+    LIST_USERS_PAGE_SIZE_DEFAULT, LIST_USERS_PAGE_SIZE_MAX, PERMISSION_READ_USERS,
+};
+use ulid::Ulid;
+
+use crate::{
+    services::user_repository::UserListFilter,
+    use_cases::{
+        ListUsersError, ListUsersRequest, ListUsersResponse, UseCasesConfig, UseCasesServices,
+        UserDetailsDto, guards::require_permission,
+    },
+};
+
+pub mod errors;
+pub mod schema;
+
+pub async fn handle_list_users<'a>(
+    ListUsersRequest {
+        user,
+        name_query,
+        role,
+        status,
+        cursor,
+        page_size,
+    }: ListUsersRequest<'a>,
+    UseCasesServices {
+        user_repository, ..
+    }: &UseCasesServices,
+    _: &UseCasesConfig,
+) -> Result<ListUsersResponse, ListUsersError> {
+    require_permission(&user, PERMISSION_READ_USERS)?;
+
+    let page_size = page_size.unwrap_or(LIST_USERS_PAGE_SIZE_DEFAULT);
+    if page_size == 0 || page_size > LIST_USERS_PAGE_SIZE_MAX {
+        return Err(ListUsersError::InvalidPageSize {
+            page_size,
+            max_page_size: LIST_USERS_PAGE_SIZE_MAX,
+        });
+    }
+
+    let role = role.map(RoleName::from).transpose()?;
+
+    // cursor is the id of the last user on the previous page
+    let after_id = cursor
+        .map(|cursor| Ulid::from_string(cursor).map(Identifier::from))
+        .transpose()?;
+
+    // one extra user shows whether there is a next page
+    let mut users = user_repository
+        .list(&UserListFilter {
+            name_query: name_query
+                .filter(|name_query| !name_query.is_empty())
+                .map(str::to_string),
+            role,
+            status,
+            after_id,
+            limit: page_size + 1,
+        })
+        .await?;
+
+    let next_cursor = match users.len() > page_size {
+        true => {
+            users.truncate(page_size);
+            users.last().map(|user| user.id().to_string())
+        }
+        false => None,
+    };
+
+    Ok(ListUsersResponse {
+        users: users.iter().map(UserDetailsDto::from).collect(),
+        next_cursor,
+    })
+}
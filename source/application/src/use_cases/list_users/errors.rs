@@ -0,0 +1,24 @@
+use nimbus_auth_domain::entities::role::value_objects::role_name::errors::RoleNameError;
+use thiserror::Error;
+use ulid::DecodeError;
+
+use crate::{
+    services::user_repository::errors::UserRepositoryError, use_cases::PermissionDeniedError,
+};
+
+#[derive(Debug, Error)]
+pub enum ListUsersError {
+    #[error(transparent)]
+    Forbidden(#[from] PermissionDeniedError),
+    #[error("invalid cursor. Error: {0}")]
+    InvalidCursor(#[from] DecodeError),
+    #[error("page size should be positive and not greater than {max_page_size}, got {page_size}")]
+    InvalidPageSize {
+        page_size: usize,
+        max_page_size: usize,
+    },
+    #[error(transparent)]
+    InvalidRoleName(#[from] RoleNameError),
+    #[error(transparent)]
+    UserRepository(#[from] UserRepositoryError),
+}
@@ -0,0 +1,21 @@
+use nimbus_auth_shared::types::UserStatus;
+
+use crate::use_cases::{UserClaimsDto, UserDetailsDto};
+
+pub struct ListUsersRequest<'a> {
+    pub user: UserClaimsDto,
+    /// Case insensitive part of the user name
+    pub name_query: Option<&'a str>,
+    /// Only users holding the role are listed
+    pub role: Option<&'a str>,
+    pub status: Option<UserStatus>,
+    /// Cursor returned with the previous page, listing starts from the beginning if it is not set
+    pub cursor: Option<&'a str>,
+    pub page_size: Option<usize>,
+}
+
+pub struct ListUsersResponse {
+    pub users: Vec<UserDetailsDto>,
+    /// Is set only if there are more users
+    pub next_cursor: Option<String>,
+}
@@ -0,0 +1,91 @@
+use nimbus_auth_domain::{
+    entities::{
+        Entity,
+        authorization_code::{
+            AuthorizationCode, specifications::NewAuthorizationCodeSpecification,
+            value_objects::code_challenge::CodeChallenge,
+        },
+        oauth_client::value_objects::redirect_uri::RedirectUri,
+        session::SomeSession,
+    },
+    value_objects::identifier::Identifier,
+};
+use nimbus_auth_shared::constants::OAUTH_AUTHORIZATION_CODE_EXPIRATION_SECONDS;
+use ulid::Ulid;
+
+use crate::use_cases::{
+    OAuthAuthorizeError, OAuthAuthorizeRequest, OAuthAuthorizeResponse, UseCasesConfig,
+    UseCasesServices,
+};
+
+pub mod errors;
+pub mod schema;
+
+const RESPONSE_TYPE_CODE: &str = "code";
+
+pub async fn handle_oauth_authorize<'a>(
+    OAuthAuthorizeRequest {
+        session_id,
+        response_type,
+        client_id,
+        redirect_uri,
+        code_challenge,
+        code_challenge_method,
+    }: OAuthAuthorizeRequest<'a>,
+    UseCasesServices {
+        user_repository,
+        session_repository,
+        oauth_client_repository,
+        authorization_code_repository,
+        time_service,
+        ..
+    }: &UseCasesServices,
+    _: &UseCasesConfig,
+) -> Result<OAuthAuthorizeResponse, OAuthAuthorizeError> {
+    // client and redirect uri are checked first, errors after that are sent to the redirect uri
+    let client = oauth_client_repository
+        .get_by_id(&Identifier::from(Ulid::from_string(client_id)?))
+        .await?
+        .ok_or(OAuthAuthorizeError::ClientIsNotFound)?;
+    let redirect_uri = RedirectUri::from(redirect_uri)?;
+    if !client.has_redirect_uri(&redirect_uri) {
+        return Err(OAuthAuthorizeError::RedirectUriIsNotRegistered);
+    }
+
+    if response_type != RESPONSE_TYPE_CODE {
+        return Err(OAuthAuthorizeError::UnsupportedResponseType {
+            response_type: response_type.to_string(),
+        });
+    }
+    let code_challenge = CodeChallenge::from(code_challenge, code_challenge_method)?;
+
+    let session_id = session_id
+        .and_then(|session_id| Ulid::from_string(session_id).ok())
+        .ok_or(OAuthAuthorizeError::LoginRequired)?;
+    let session = match session_repository
+        .get_by_id(&Identifier::from(session_id))
+        .await?
+    {
+        Some(SomeSession::Active(session)) => session.into_owned(),
+        _ => return Err(OAuthAuthorizeError::LoginRequired),
+    };
+    let user = user_repository
+        .get_by_session(&session)
+        .await?
+        .ok_or(OAuthAuthorizeError::LoginRequired)?;
+
+    let code = AuthorizationCode::new(NewAuthorizationCodeSpecification {
+        client_id: client.id().clone(),
+        user_id: user.id().clone(),
+        redirect_uri,
+        code_challenge,
+        current_time: time_service.get_current_time().await?,
+        expiration_seconds: OAUTH_AUTHORIZATION_CODE_EXPIRATION_SECONDS,
+    });
+    authorization_code_repository.save(&code).await?;
+
+    Ok(OAuthAuthorizeResponse {
+        code: code.id().to_string(),
+        redirect_uri: code.redirect_uri().to_string(),
+    })
+}
@@ -0,0 +1,56 @@
+use nimbus_auth_domain::entities::{
+    authorization_code::value_objects::code_challenge::errors::CodeChallengeError,
+    oauth_client::value_objects::redirect_uri::errors::RedirectUriError,
+};
+use thiserror::Error;
+use ulid::DecodeError;
+
+use crate::services::{
+    authorization_code_repository::errors::AuthorizationCodeRepositoryError,
+    oauth_client_repository::errors::OAuthClientRepositoryError,
+    session_repository::errors::SessionRepositoryError, time_service::errors::TimeServiceError,
+    user_repository::errors::UserRepositoryError,
+};
+
+#[derive(Debug, Error)]
+pub enum OAuthAuthorizeError {
+    #[error("invalid client id. Error: {0}")]
+    InvalidClientId(#[from] DecodeError),
+    #[error("oauth client is not found")]
+    ClientIsNotFound,
+    #[error(transparent)]
+    InvalidRedirectUri(#[from] RedirectUriError),
+    #[error("redirect uri is not registered for the client")]
+    RedirectUriIsNotRegistered,
+    #[error("response type {response_type} is not supported, only code is")]
+    UnsupportedResponseType { response_type: String },
+    #[error(transparent)]
+    InvalidCodeChallenge(#[from] CodeChallengeError),
+    #[error("user has no active session")]
+    LoginRequired,
+    #[error(transparent)]
+    OAuthClientRepository(#[from] OAuthClientRepositoryError),
+    #[error(transparent)]
+    AuthorizationCodeRepository(#[from] AuthorizationCodeRepositoryError),
+    #[error(transparent)]
+    SessionRepository(#[from] SessionRepositoryError),
+    #[error(transparent)]
+    UserRepository(#[from] UserRepositoryError),
+    #[error(transparent)]
+    TimeService(#[from] TimeServiceError),
+}
+
+impl OAuthAuthorizeError {
+    /// Errors before the redirect uri is known to belong to the client are not sent to it,
+    /// otherwise the authorization server would redirect users to any uri
+    pub fn is_redirectable(&self) -> bool {
+        !matches!(
+            self,
+            OAuthAuthorizeError::InvalidClientId(_)
+                | OAuthAuthorizeError::ClientIsNotFound
+                | OAuthAuthorizeError::InvalidRedirectUri(_)
+                | OAuthAuthorizeError::RedirectUriIsNotRegistered
+                | OAuthAuthorizeError::OAuthClientRepository(_)
+        )
+    }
+}
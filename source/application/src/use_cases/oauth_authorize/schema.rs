@@ -0,0 +1,14 @@
+pub struct OAuthAuthorizeRequest<'a> {
+    /// Session of the user in the browser, taken from the session cookie
+    pub session_id: Option<&'a str>,
+    pub response_type: &'a str,
+    pub client_id: &'a str,
+    pub redirect_uri: &'a str,
+    pub code_challenge: &'a str,
+    pub code_challenge_method: &'a str,
+}
+
+pub struct OAuthAuthorizeResponse {
+    pub code: String,
+    pub redirect_uri: String,
+}
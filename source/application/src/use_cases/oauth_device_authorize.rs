@@ -0,0 +1,69 @@
+use nimbus_auth_domain::{
+    entities::{
+        Entity,
+        device_authorization::{
+            DeviceAuthorization, specifications::NewDeviceAuthorizationSpecification,
+        },
+    },
+    value_objects::identifier::Identifier,
+};
+use nimbus_auth_shared::constants::{
+    OAUTH_DEVICE_CODE_EXPIRATION_SECONDS, OAUTH_DEVICE_POLLING_INTERVAL_SECONDS,
+};
+use ulid::Ulid;
+
+use crate::use_cases::{
+    OAuthDeviceAuthorizeError, OAuthDeviceAuthorizeRequest, OAuthDeviceAuthorizeResponse,
+    UseCasesConfig, UseCasesServices,
+};
+
+pub mod errors;
+pub mod schema;
+
+/// Starts a device authorization grant, the device shows the user code and polls for tokens
+pub async fn handle_oauth_device_authorize<'a>(
+    OAuthDeviceAuthorizeRequest {
+        client_id,
+        client_secret,
+    }: OAuthDeviceAuthorizeRequest<'a>,
+    UseCasesServices {
+        oauth_client_repository,
+        device_authorization_repository,
+        time_service,
+        random_service,
+        ..
+    }: &UseCasesServices,
+    config: &UseCasesConfig,
+) -> Result<OAuthDeviceAuthorizeResponse, OAuthDeviceAuthorizeError> {
+    let verification_uri = config.oauth_device_verification_uri.as_deref();
+
+    let verification_uri =
+        verification_uri.ok_or(OAuthDeviceAuthorizeError::VerificationUriIsNotConfigured)?;
+
+    let client = oauth_client_repository
+        .get_by_id(&Identifier::from(Ulid::from_string(client_id)?))
+        .await?
+        .ok_or(OAuthDeviceAuthorizeError::ClientIsNotFound)?;
+    client
+        .authenticate(client_secret)
+        .map_err(OAuthDeviceAuthorizeError::ClientAuthentication)?;
+
+    let device_authorization = DeviceAuthorization::new(NewDeviceAuthorizationSpecification {
+        client_id: client.id().clone(),
+        user_code: random_service.get_random_user_code().await?,
+        current_time: time_service.get_current_time().await?,
+        expiration_seconds: OAUTH_DEVICE_CODE_EXPIRATION_SECONDS,
+        interval_seconds: OAUTH_DEVICE_POLLING_INTERVAL_SECONDS,
+    });
+    device_authorization_repository
+        .save(&device_authorization)
+        .await?;
+
+    Ok(OAuthDeviceAuthorizeResponse {
+        device_code: device_authorization.id().to_string(),
+        user_code: device_authorization.user_code().to_string(),
+        verification_uri: verification_uri.to_string(),
+        expires_at_unix_timestamp: device_authorization.expires_at().unix_timestamp(),
+        interval_seconds: device_authorization.interval_seconds(),
+    })
+}
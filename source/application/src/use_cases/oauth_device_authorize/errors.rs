@@ -0,0 +1,29 @@
+use nimbus_auth_domain::entities::oauth_client::errors::OAuthClientError;
+use thiserror::Error;
+use ulid::DecodeError;
+
+use crate::services::{
+    device_authorization_repository::errors::DeviceAuthorizationRepositoryError,
+    oauth_client_repository::errors::OAuthClientRepositoryError,
+    random_service::errors::RandomServiceError, time_service::errors::TimeServiceError,
+};
+
+#[derive(Debug, Error)]
+pub enum OAuthDeviceAuthorizeError {
+    #[error("device verification uri is not configured, device authorization grant is disabled")]
+    VerificationUriIsNotConfigured,
+    #[error("invalid client id. Error: {0}")]
+    InvalidClientId(#[from] DecodeError),
+    #[error("oauth client is not found")]
+    ClientIsNotFound,
+    #[error("oauth client authentication failed. Error: {0}")]
+    ClientAuthentication(#[source] OAuthClientError),
+    #[error(transparent)]
+    OAuthClientRepository(#[from] OAuthClientRepositoryError),
+    #[error(transparent)]
+    DeviceAuthorizationRepository(#[from] DeviceAuthorizationRepositoryError),
+    #[error(transparent)]
+    TimeService(#[from] TimeServiceError),
+    #[error(transparent)]
+    RandomService(#[from] RandomServiceError),
+}
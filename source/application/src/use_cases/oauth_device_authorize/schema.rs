@@ -0,0 +1,16 @@
+pub struct OAuthDeviceAuthorizeRequest<'a> {
+    pub client_id: &'a str,
+    /// Required for confidential clients
+    pub client_secret: Option<&'a str>,
+}
+
+pub struct OAuthDeviceAuthorizeResponse {
+    /// Secret of the device it polls the token endpoint with
+    pub device_code: String,
+    /// Shown to the user to be entered at the verification uri
+    pub user_code: String,
+    pub verification_uri: String,
+    pub expires_at_unix_timestamp: i64,
+    /// Minimal seconds between polls of the token endpoint
+    pub interval_seconds: usize,
+}
@@ -0,0 +1,408 @@
+use std::{borrow::Cow, collections::BTreeSet, sync::Arc};
+
+use nimbus_auth_domain::{
+    entities::{
+        Entity,
+        authorization_code::specifications::RedeemAuthorizationCodeSpecification,
+        device_authorization::{DevicePoll, specifications::PollDeviceAuthorizationSpecification},
+        oauth_client::{OAuthClient, value_objects::redirect_uri::RedirectUri},
+        role::value_objects::permission::Permission,
+        session::{SomeSession, specifications::NewSessionSpecification},
+        user::{SomeUser, User},
+    },
+    value_objects::{
+        actor::Actor, audiences::Audiences, client_access_token::ClientAccessToken,
+        identifier::Identifier,
+    },
+};
+use time::OffsetDateTime;
+use ulid::Ulid;
+
+use crate::{
+    services::{
+        authorization_code_repository::AuthorizationCodeRepository,
+        device_authorization_repository::DeviceAuthorizationRepository,
+        keypair_repository::KeyPairRepository, time_service::TimeService,
+    },
+    use_cases::{
+        AuthorizationError, ClientClaimsDto, OAuthGrant, OAuthTokenError, OAuthTokenRequest,
+        OAuthTokenResponse, PrincipalDto, RefreshRequest, UseCasesConfig, UseCasesServices,
+        UserClaimsDto,
+        authorize::{VerifiedAccessToken, verify_access_token},
+        dtos::{access_token::AccessTokenDto, session::SessionDto},
+        refresh::handle_client_refresh,
+    },
+};
+
+pub mod errors;
+pub mod schema;
+
+/// Refresh tokens are the ids of sessions the grants start, bound to the client they are issued to
+pub async fn handle_oauth_token<'a>(
+    OAuthTokenRequest {
+        client_id,
+        client_secret,
+        grant,
+    }: OAuthTokenRequest<'a>,
+    services: &UseCasesServices,
+    config: &UseCasesConfig,
+) -> Result<OAuthTokenResponse, OAuthTokenError> {
+    // refresh token grant hands the services over to the refresh use case
+    let UseCasesServices {
+        user_repository,
+        session_repository,
+        keypair_repository,
+        oauth_client_repository,
+        authorization_code_repository,
+        device_authorization_repository,
+        time_service,
+        ..
+    } = services;
+
+    let client = oauth_client_repository
+        .get_by_id(&Identifier::from(Ulid::from_string(client_id)?))
+        .await?
+        .ok_or(OAuthTokenError::ClientIsNotFound)?;
+    client
+        .authenticate(client_secret)
+        .map_err(OAuthTokenError::ClientAuthentication)?;
+
+    let (user_id, current_time) = match grant {
+        OAuthGrant::AuthorizationCode {
+            code,
+            redirect_uri,
+            code_verifier,
+        } => {
+            redeem_authorization_code(
+                &client,
+                code,
+                redirect_uri,
+                code_verifier,
+                authorization_code_repository.clone(),
+                time_service.clone(),
+            )
+            .await?
+        }
+        OAuthGrant::DeviceCode { device_code } => {
+            redeem_device_code(
+                &client,
+                device_code,
+                device_authorization_repository.clone(),
+                time_service.clone(),
+            )
+            .await?
+        }
+        OAuthGrant::RefreshToken { refresh_token } => {
+            let response = handle_client_refresh(
+                RefreshRequest {
+                    session_id: refresh_token,
+                    audiences: &[],
+                },
+                Some(client.id()),
+                services,
+                config,
+            )
+            .await?;
+            return Ok(OAuthTokenResponse {
+                principal: PrincipalDto::User(response.user),
+                session: Some(response.session),
+                access_token: response.access_token,
+            });
+        }
+        OAuthGrant::ClientCredentials { scopes } => {
+            return handle_client_credentials(
+                &client,
+                scopes,
+                keypair_repository.clone(),
+                time_service.clone(),
+                config,
+            )
+            .await;
+        }
+        OAuthGrant::TokenExchange {
+            subject_token,
+            actor_token,
+            audiences,
+            scopes,
+        } => {
+            // secret is checked already, public clients have none to check
+            if !client.is_confidential() {
+                return Err(OAuthTokenError::UnauthorizedClient);
+            }
+            return handle_token_exchange(
+                subject_token,
+                actor_token,
+                audiences,
+                scopes,
+                keypair_repository.clone(),
+                time_service.clone(),
+                config,
+            )
+            .await;
+        }
+    };
+
+    let user = match user_repository
+        .get_by_id(&user_id)
+        .await?
+        .ok_or(OAuthTokenError::UserIsNotFound)?
+    {
+        SomeUser::Active(user) => user.into_owned(),
+        _ => return Err(OAuthTokenError::UserIsNotActive),
+    };
+
+    let active_keypair = keypair_repository
+        .get_active()
+        .await?
+        .ok_or(OAuthTokenError::ActiveKeyPairNotFound)?;
+
+    let session = SomeSession::new(NewSessionSpecification {
+        user_claims: user.claims().clone(),
+        client_id: Some(client.id().clone()),
+        current_time,
+        expiration_seconds: config.session_expiration_seconds,
+    });
+
+    let transactional_session_repository = session_repository.start_transaction().await?;
+
+    let (transactional_session_repository, _) = transactional_session_repository
+        .save(SomeSession::Active(Cow::Borrowed(&session)))
+        .await?;
+
+    let access_token = &session.generate_access_token(
+        current_time,
+        config.access_token_expiration_seconds,
+        config.access_token_max_groups,
+        &config.access_token_attributes,
+    );
+    let signed_access_token = access_token.sign(&active_keypair, &config.realm)?;
+
+    transactional_session_repository.commit().await?;
+
+    Ok(OAuthTokenResponse {
+        principal: PrincipalDto::User(UserClaimsDto::from(access_token.user_claims())),
+        session: Some(SessionDto {
+            session_id: session.id().to_string(),
+            session_expires_at_unix_timestamp: session.expires_at().unix_timestamp(),
+        }),
+        access_token: AccessTokenDto {
+            signed_access_token,
+            signed_access_token_expires_at_unix_timestamp: access_token
+                .expires_at()
+                .unix_timestamp(),
+        },
+    })
+}
+
+/// Code is gone after the first exchange attempt, even a failed one
+async fn redeem_authorization_code(
+    client: &OAuthClient,
+    code: &str,
+    redirect_uri: &str,
+    code_verifier: &str,
+    authorization_code_repository: Arc<dyn AuthorizationCodeRepository>,
+    time_service: Arc<dyn TimeService>,
+) -> Result<(Identifier<Ulid, User>, OffsetDateTime), OAuthTokenError> {
+    let code_id = Ulid::from_string(code).map_err(|_| OAuthTokenError::CodeIsNotFound)?;
+    let code = authorization_code_repository
+        .take(&Identifier::from(code_id))
+        .await?
+        .ok_or(OAuthTokenError::CodeIsNotFound)?;
+
+    let current_time = time_service.get_current_time().await?;
+    let user_id = code.redeem(RedeemAuthorizationCodeSpecification {
+        client_id: client.id(),
+        redirect_uri: &RedirectUri::from(redirect_uri)?,
+        code_verifier,
+        current_time,
+    })?;
+
+    Ok((user_id, current_time))
+}
+
+/// Polls of a device are recorded until the user approves it, then the device code is gone
+async fn redeem_device_code(
+    client: &OAuthClient,
+    device_code: &str,
+    device_authorization_repository: Arc<dyn DeviceAuthorizationRepository>,
+    time_service: Arc<dyn TimeService>,
+) -> Result<(Identifier<Ulid, User>, OffsetDateTime), OAuthTokenError> {
+    let device_authorization_id = Identifier::from(
+        Ulid::from_string(device_code).map_err(|_| OAuthTokenError::DeviceCodeIsNotFound)?,
+    );
+    let device_authorization = device_authorization_repository
+        .get_by_id(&device_authorization_id)
+        .await?
+        .ok_or(OAuthTokenError::DeviceCodeIsNotFound)?;
+
+    let current_time = time_service.get_current_time().await?;
+    match device_authorization.poll(PollDeviceAuthorizationSpecification {
+        client_id: client.id(),
+        current_time,
+    })? {
+        DevicePoll::Pending(device_authorization) => {
+            device_authorization_repository
+                .save(&device_authorization)
+                .await?;
+            Err(OAuthTokenError::AuthorizationPending)
+        }
+        DevicePoll::SlowDown(device_authorization) => {
+            device_authorization_repository
+                .save(&device_authorization)
+                .await?;
+            Err(OAuthTokenError::SlowDown)
+        }
+        DevicePoll::Approved(user_id) => {
+            device_authorization_repository
+                .take(&device_authorization_id)
+                .await?
+                .ok_or(OAuthTokenError::DeviceCodeIsNotFound)?;
+            Ok((user_id, current_time))
+        }
+    }
+}
+
+async fn handle_client_credentials(
+    client: &OAuthClient,
+    scopes: Option<Vec<&str>>,
+    keypair_repository: Arc<dyn KeyPairRepository>,
+    time_service: Arc<dyn TimeService>,
+    config: &UseCasesConfig,
+) -> Result<OAuthTokenResponse, OAuthTokenError> {
+    // secret is checked already, public clients have none to check
+    if !client.is_confidential() {
+        return Err(OAuthTokenError::UnauthorizedClient);
+    }
+
+    let requested_scopes = scopes
+        .map(|scopes| {
+            scopes
+                .into_iter()
+                .map(Permission::from)
+                .collect::<Result<BTreeSet<_>, _>>()
+        })
+        .transpose()?;
+    let client_claims = client
+        .claims(requested_scopes)
+        .map_err(OAuthTokenError::ScopeIsNotAllowed)?;
+
+    let active_keypair = keypair_repository
+        .get_active()
+        .await?
+        .ok_or(OAuthTokenError::ActiveKeyPairNotFound)?;
+
+    let current_time = time_service.get_current_time().await?;
+    let access_token = ClientAccessToken::new(
+        client_claims,
+        current_time,
+        config.access_token_expiration_seconds,
+    );
+    let signed_access_token = access_token.sign(&active_keypair, &config.realm)?;
+
+    Ok(OAuthTokenResponse {
+        principal: PrincipalDto::Client(ClientClaimsDto::from(access_token.client_claims())),
+        session: None,
+        access_token: AccessTokenDto {
+            signed_access_token,
+            signed_access_token_expires_at_unix_timestamp: access_token
+                .expires_at()
+                .unix_timestamp(),
+        },
+    })
+}
+
+/// Subject and actor tokens are verified as on authorization, the actor becomes the current actor of the new token
+async fn handle_token_exchange(
+    subject_token: &str,
+    actor_token: Option<&str>,
+    audiences: &[String],
+    scopes: Option<Vec<&str>>,
+    keypair_repository: Arc<dyn KeyPairRepository>,
+    time_service: Arc<dyn TimeService>,
+    config: &UseCasesConfig,
+) -> Result<OAuthTokenResponse, OAuthTokenError> {
+    let realm = &config.realm;
+    let subject_token =
+        match verify_access_token(subject_token, keypair_repository.clone(), realm).await {
+            Ok(VerifiedAccessToken::User(access_token)) => access_token,
+            Ok(VerifiedAccessToken::Client(_)) => return Err(OAuthTokenError::SubjectIsNotUser),
+            Err(err) => {
+                return Err(map_verification_error(
+                    err,
+                    OAuthTokenError::InvalidSubjectToken,
+                ));
+            }
+        };
+
+    let actor = match actor_token {
+        Some(actor_token) => {
+            let actor_subject =
+                match verify_access_token(actor_token, keypair_repository.clone(), realm).await {
+                    Ok(VerifiedAccessToken::User(access_token)) => {
+                        access_token.user_claims().id().to_string()
+                    }
+                    Ok(VerifiedAccessToken::Client(access_token)) => {
+                        access_token.client_claims().id().to_string()
+                    }
+                    Err(err) => {
+                        return Err(map_verification_error(
+                            err,
+                            OAuthTokenError::InvalidActorToken,
+                        ));
+                    }
+                };
+            Some(Actor::new(&actor_subject, subject_token.actor().cloned()))
+        }
+        None => None,
+    };
+
+    let audiences = match audiences {
+        [] => subject_token.audiences().clone(),
+        audiences => Audiences::from(audiences, realm)?,
+    };
+    let requested_scopes = scopes
+        .map(|scopes| {
+            scopes
+                .into_iter()
+                .map(Permission::from)
+                .collect::<Result<BTreeSet<_>, _>>()
+        })
+        .transpose()?;
+
+    let active_keypair = keypair_repository
+        .get_active()
+        .await?
+        .ok_or(OAuthTokenError::ActiveKeyPairNotFound)?;
+
+    let current_time = time_service.get_current_time().await?;
+    let access_token = subject_token.exchange(
+        requested_scopes.as_ref(),
+        audiences,
+        actor,
+        current_time,
+        config.access_token_expiration_seconds,
+    )?;
+    let signed_access_token = access_token.sign(&active_keypair, realm)?;
+
+    Ok(OAuthTokenResponse {
+        principal: PrincipalDto::User(UserClaimsDto::from(access_token.user_claims())),
+        session: None,
+        access_token: AccessTokenDto {
+            signed_access_token,
+            signed_access_token_expires_at_unix_timestamp: access_token
+                .expires_at()
+                .unix_timestamp(),
+        },
+    })
+}
+
+/// Failures to reach the keypairs are not the fault of the token
+fn map_verification_error(
+    err: AuthorizationError,
+    invalid_token: fn(AuthorizationError) -> OAuthTokenError,
+) -> OAuthTokenError {
+    match err {
+        AuthorizationError::KeyPairRepository(err) => OAuthTokenError::KeyPairRepository(err),
+        err => invalid_token(err),
+    }
+}
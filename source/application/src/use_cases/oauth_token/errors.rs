@@ -0,0 +1,92 @@
+use nimbus_auth_domain::{
+    entities::{
+        authorization_code::errors::AuthorizationCodeError,
+        device_authorization::errors::DeviceAuthorizationError,
+        oauth_client::{
+            errors::OAuthClientError, value_objects::redirect_uri::errors::RedirectUriError,
+        },
+        role::value_objects::permission::errors::PermissionError,
+    },
+    value_objects::{
+        access_token::errors::{ExchangeAccessTokenError, SignAccessTokenError},
+        audiences::errors::AudiencesError,
+    },
+};
+use thiserror::Error;
+use ulid::DecodeError;
+
+use crate::{
+    services::{
+        authorization_code_repository::errors::AuthorizationCodeRepositoryError,
+        device_authorization_repository::errors::DeviceAuthorizationRepositoryError,
+        keypair_repository::errors::KeyPairRepositoryError,
+        oauth_client_repository::errors::OAuthClientRepositoryError,
+        session_repository::errors::SessionRepositoryError, time_service::errors::TimeServiceError,
+        user_repository::errors::UserRepositoryError,
+    },
+    use_cases::{AuthorizationError, RefreshError},
+};
+
+#[derive(Debug, Error)]
+pub enum OAuthTokenError {
+    #[error("invalid client id. Error: {0}")]
+    InvalidClientId(#[from] DecodeError),
+    #[error("oauth client is not found")]
+    ClientIsNotFound,
+    #[error("oauth client authentication failed. Error: {0}")]
+    ClientAuthentication(#[source] OAuthClientError),
+    #[error("public oauth client can not use the grant")]
+    UnauthorizedClient,
+    #[error("invalid scope. Error: {0}")]
+    InvalidScope(#[from] PermissionError),
+    #[error(transparent)]
+    ScopeIsNotAllowed(OAuthClientError),
+    #[error("authorization code is not found")]
+    CodeIsNotFound,
+    #[error(transparent)]
+    InvalidRedirectUri(#[from] RedirectUriError),
+    #[error(transparent)]
+    InvalidAuthorizationCode(#[from] AuthorizationCodeError),
+    #[error("device code is not found")]
+    DeviceCodeIsNotFound,
+    #[error(transparent)]
+    InvalidDeviceCode(#[from] DeviceAuthorizationError),
+    #[error("user has not approved the device yet")]
+    AuthorizationPending,
+    #[error("device polls too often")]
+    SlowDown,
+    #[error("invalid subject token. Error: {0}")]
+    InvalidSubjectToken(#[source] AuthorizationError),
+    #[error("subject token is issued to a client, not a user")]
+    SubjectIsNotUser,
+    #[error("invalid actor token. Error: {0}")]
+    InvalidActorToken(#[source] AuthorizationError),
+    #[error(transparent)]
+    InvalidAudience(#[from] AudiencesError),
+    #[error(transparent)]
+    ExchangeAccessToken(#[from] ExchangeAccessTokenError),
+    #[error("user of the grant is not found")]
+    UserIsNotFound,
+    #[error("user of the grant is not active")]
+    UserIsNotActive,
+    #[error(transparent)]
+    Refresh(#[from] RefreshError),
+    #[error(transparent)]
+    OAuthClientRepository(#[from] OAuthClientRepositoryError),
+    #[error(transparent)]
+    AuthorizationCodeRepository(#[from] AuthorizationCodeRepositoryError),
+    #[error(transparent)]
+    DeviceAuthorizationRepository(#[from] DeviceAuthorizationRepositoryError),
+    #[error(transparent)]
+    SessionRepository(#[from] SessionRepositoryError),
+    #[error(transparent)]
+    UserRepository(#[from] UserRepositoryError),
+    #[error(transparent)]
+    TimeService(#[from] TimeServiceError),
+    #[error(transparent)]
+    KeyPairRepository(#[from] KeyPairRepositoryError),
+    #[error("active key pair not found")]
+    ActiveKeyPairNotFound,
+    #[error(transparent)]
+    SignAccessToken(#[from] SignAccessTokenError),
+}
@@ -0,0 +1,47 @@
+use crate::use_cases::{
+    PrincipalDto,
+    dtos::{access_token::AccessTokenDto, session::SessionDto},
+};
+
+pub enum OAuthGrant<'a> {
+    AuthorizationCode {
+        code: &'a str,
+        redirect_uri: &'a str,
+        code_verifier: &'a str,
+    },
+    RefreshToken {
+        refresh_token: &'a str,
+    },
+    /// Device polls for the tokens until the user approves it
+    DeviceCode {
+        device_code: &'a str,
+    },
+    /// Client acts on its own behalf, no requested scopes grant all scopes of the client
+    ClientCredentials {
+        scopes: Option<Vec<&'a str>>,
+    },
+    /// Client swaps an access token of a user for a narrower one
+    ///
+    /// No requested audiences keep those of the subject token, no requested scopes keep all its permissions
+    TokenExchange {
+        subject_token: &'a str,
+        /// Token of the party acting for the user, it becomes the current actor of the new token
+        actor_token: Option<&'a str>,
+        audiences: &'a [String],
+        scopes: Option<Vec<&'a str>>,
+    },
+}
+
+pub struct OAuthTokenRequest<'a> {
+    pub client_id: &'a str,
+    /// Required for confidential clients
+    pub client_secret: Option<&'a str>,
+    pub grant: OAuthGrant<'a>,
+}
+
+pub struct OAuthTokenResponse {
+    pub principal: PrincipalDto,
+    /// Id of the session is the refresh token, client credentials and token exchange grants start no session
+    pub session: Option<SessionDto>,
+    pub access_token: AccessTokenDto,
+}
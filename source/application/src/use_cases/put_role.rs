@@ -0,0 +1,56 @@
+use std::collections::BTreeSet;
+
+use nimbus_auth_domain::entities::role::{
+    Role,
+    specifications::NewRoleSpecification,
+    value_objects::{permission::Permission, role_name::RoleName},
+};
+use nimbus_auth_shared::constants::PERMISSION_MANAGE_ROLES;
+
+use crate::use_cases::{
+    PutRoleError, PutRoleRequest, PutRoleResponse, RoleDto, UseCasesConfig, UseCasesServices,
+    guards::require_permission,
+};
+
+pub mod errors;
+pub mod schema;
+
+/// Creates the role or replaces its permissions, holders of the role get new permissions
+/// in access tokens issued after the change
+pub async fn handle_put_role<'a>(
+    PutRoleRequest {
+        user,
+        role_name,
+        permissions,
+    }: PutRoleRequest<'a>,
+    UseCasesServices {
+        role_repository, ..
+    }: &UseCasesServices,
+    _: &UseCasesConfig,
+) -> Result<PutRoleResponse, PutRoleError> {
+    require_permission(&user, PERMISSION_MANAGE_ROLES)?;
+
+    let role_name = RoleName::from(role_name)?;
+    let permissions = permissions
+        .iter()
+        .map(|permission| Permission::from(permission))
+        .collect::<Result<BTreeSet<_>, _>>()?;
+
+    let (role, is_created) = match role_repository.get_by_name(&role_name).await? {
+        Some(role) => (role.with_permissions(permissions), false),
+        None => (
+            Role::new(NewRoleSpecification {
+                name: role_name,
+                permissions,
+            }),
+            true,
+        ),
+    };
+
+    role_repository.save(&role).await?;
+
+    Ok(PutRoleResponse {
+        role: RoleDto::from(&role),
+        is_created,
+    })
+}
@@ -0,0 +1,20 @@
+use nimbus_auth_domain::entities::role::value_objects::{
+    permission::errors::PermissionError, role_name::errors::RoleNameError,
+};
+use thiserror::Error;
+
+use crate::{
+    services::role_repository::errors::RoleRepositoryError, use_cases::PermissionDeniedError,
+};
+
+#[derive(Debug, Error)]
+pub enum PutRoleError {
+    #[error(transparent)]
+    Forbidden(#[from] PermissionDeniedError),
+    #[error(transparent)]
+    InvalidRoleName(#[from] RoleNameError),
+    #[error(transparent)]
+    InvalidPermission(#[from] PermissionError),
+    #[error(transparent)]
+    RoleRepository(#[from] RoleRepositoryError),
+}
@@ -0,0 +1,12 @@
+use crate::use_cases::{RoleDto, UserClaimsDto};
+
+pub struct PutRoleRequest<'a> {
+    pub user: UserClaimsDto,
+    pub role_name: &'a str,
+    pub permissions: &'a [String],
+}
+
+pub struct PutRoleResponse {
+    pub role: RoleDto,
+    pub is_created: bool,
+}
@@ -1,37 +1,47 @@
-use std::{borrow::Cow, sync::Arc};
+use std::borrow::Cow;
 
 use nimbus_auth_domain::{
-    entities::{Entity, session::SomeSession},
-    value_objects::identifier::Identifier,
+    entities::{Entity, oauth_client::OAuthClient, session::SomeSession},
+    value_objects::{audiences::Audiences, identifier::Identifier},
 };
-use nimbus_auth_shared::types::{AccessTokenExpirationSeconds, SessionExpirationSeconds};
 use ulid::Ulid;
 use zeroize::Zeroizing;
 
-use crate::{
-    services::{
-        keypair_repository::KeyPairRepository, session_repository::SessionRepository,
-        time_service::TimeService, user_repository::UserRepository,
-    },
-    use_cases::{
-        RefreshRequest, RefreshResponse, UserClaimsDto,
-        dtos::{access_token::AccessTokenDto, session::SessionDto},
-        refresh::errors::RefreshError,
-    },
+use crate::use_cases::{
+    RefreshRequest, RefreshResponse, UseCasesConfig, UseCasesServices, UserClaimsDto,
+    dtos::{access_token::AccessTokenDto, session::SessionDto},
+    refresh::errors::RefreshError,
 };
 
 pub mod errors;
 pub mod schema;
 
 pub async fn handle_refresh<'a>(
-    RefreshRequest { session_id }: RefreshRequest<'a>,
-    user_repository: Arc<dyn UserRepository>,
-    session_repository: Arc<dyn SessionRepository>,
-    keypair_repository: Arc<dyn KeyPairRepository>,
-    time_service: Arc<dyn TimeService>,
-    session_exp_seconds: SessionExpirationSeconds,
-    access_token_exp_seconds: AccessTokenExpirationSeconds,
+    request: RefreshRequest<'a>,
+    services: &UseCasesServices,
+    config: &UseCasesConfig,
 ) -> Result<RefreshResponse, RefreshError> {
+    handle_client_refresh(request, None, services, config).await
+}
+
+/// Sessions issued to an oauth client are refreshed only with the id of that client
+pub(crate) async fn handle_client_refresh<'a>(
+    RefreshRequest {
+        session_id,
+        audiences,
+    }: RefreshRequest<'a>,
+    client_id: Option<&Identifier<Ulid, OAuthClient>>,
+    UseCasesServices {
+        user_repository,
+        session_repository,
+        keypair_repository,
+        time_service,
+        ..
+    }: &UseCasesServices,
+    config: &UseCasesConfig,
+) -> Result<RefreshResponse, RefreshError> {
+    let audiences = Audiences::from(audiences, &config.realm)?;
+
     let session = session_repository
         .get_by_id(&Identifier::from(Ulid::from_string(session_id)?))
         .await?
@@ -54,8 +64,13 @@ pub async fn handle_refresh<'a>(
         .await?
         .ok_or(RefreshError::ActiveKeyPairNotFound)?;
 
-    let (revoked_session, new_active_session) =
-        active_session.refresh(time_service.get_current_time().await?, session_exp_seconds);
+    let (revoked_session, new_active_session) = active_session.refresh(
+        client_id,
+        // claims are re-derived from the current user, so role changes apply on refresh
+        user.claims().clone(),
+        time_service.get_current_time().await?,
+        config.session_expiration_seconds,
+    )?;
 
     let transactional_session_repository = session_repository.start_transaction().await?;
 
@@ -67,15 +82,19 @@ pub async fn handle_refresh<'a>(
         .save(SomeSession::Active(Cow::Borrowed(&new_active_session)))
         .await?;
 
-    let access_token = &new_active_session.generate_access_token(
-        time_service.get_current_time().await?,
-        access_token_exp_seconds,
-    );
-    let signed_access_token = access_token.sign(&active_keypair)?;
+    let access_token = &new_active_session
+        .generate_access_token(
+            time_service.get_current_time().await?,
+            config.access_token_expiration_seconds,
+            config.access_token_max_groups,
+            &config.access_token_attributes,
+        )
+        .with_audiences(audiences);
+    let signed_access_token = access_token.sign(&active_keypair, &config.realm)?;
 
     transactional_session_repository.commit().await?;
 
-    let user_dto = UserClaimsDto::from(user.claims());
+    let user_dto = UserClaimsDto::from(access_token.user_claims());
 
     let session_dto = SessionDto {
         session_id: new_active_session.id().to_string(),
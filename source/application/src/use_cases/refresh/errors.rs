@@ -1,4 +1,9 @@
-use nimbus_auth_domain::value_objects::access_token::errors::SignAccessTokenError;
+use nimbus_auth_domain::{
+    entities::session::errors::SessionError,
+    value_objects::{
+        access_token::errors::SignAccessTokenError, audiences::errors::AudiencesError,
+    },
+};
 use thiserror::Error;
 use ulid::DecodeError;
 
@@ -10,6 +15,8 @@ use crate::services::{
 
 #[derive(Debug, Error)]
 pub enum RefreshError {
+    #[error(transparent)]
+    InvalidAudiences(#[from] AudiencesError),
     #[error(transparent)]
     IdDecode(#[from] DecodeError),
     #[error(transparent)]
@@ -21,6 +28,8 @@ pub enum RefreshError {
     #[error("session is revoked")]
     SessionIsRevoked,
     #[error(transparent)]
+    Session(#[from] SessionError),
+    #[error(transparent)]
     UserRepository(#[from] UserRepositoryError),
     #[error("user for this session is not found")]
     UserIsNotFound,
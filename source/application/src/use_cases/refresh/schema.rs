@@ -5,6 +5,8 @@ use crate::use_cases::{
 
 pub struct RefreshRequest<'a> {
     pub session_id: &'a str,
+    /// Audiences the access token should target, limited to the realm allowlist
+    pub audiences: &'a [String],
 }
 
 pub struct RefreshResponse {
@@ -0,0 +1,75 @@
+use nimbus_auth_domain::{
+    entities::{
+        oauth_client::{
+            OAuthClient,
+            specifications::NewOAuthClientSpecification,
+            value_objects::{oauth_client_name::OAuthClientName, redirect_uri::RedirectUri},
+        },
+        role::value_objects::permission::Permission,
+    },
+    value_objects::secret_hash::SecretHash,
+};
+use nimbus_auth_shared::constants::PERMISSION_MANAGE_OAUTH_CLIENTS;
+
+use crate::use_cases::{
+    OAuthClientDto, RegisterOAuthClientError, RegisterOAuthClientRequest,
+    RegisterOAuthClientResponse, UseCasesConfig, UseCasesServices, guards::require_permission,
+};
+
+pub mod errors;
+pub mod schema;
+
+pub async fn handle_register_oauth_client<'a>(
+    RegisterOAuthClientRequest {
+        user,
+        client_name,
+        redirect_uris,
+        confidential,
+        scopes,
+    }: RegisterOAuthClientRequest<'a>,
+    UseCasesServices {
+        oauth_client_repository,
+        random_service,
+        ..
+    }: &UseCasesServices,
+    _: &UseCasesConfig,
+) -> Result<RegisterOAuthClientResponse, RegisterOAuthClientError> {
+    require_permission(&user, PERMISSION_MANAGE_OAUTH_CLIENTS)?;
+
+    let name = OAuthClientName::from(client_name)?;
+    // confidential clients may only act on their own behalf, without redirects
+    if redirect_uris.is_empty() && !confidential {
+        return Err(RegisterOAuthClientError::NoRedirectUris);
+    }
+    if !scopes.is_empty() && !confidential {
+        return Err(RegisterOAuthClientError::PublicClientScopes);
+    }
+    let redirect_uris = redirect_uris
+        .iter()
+        .map(|redirect_uri| RedirectUri::from(redirect_uri))
+        .collect::<Result<Vec<_>, _>>()?;
+    let scopes = scopes
+        .iter()
+        .map(|scope| Permission::from(scope))
+        .collect::<Result<_, _>>()?;
+
+    let client_secret = match confidential {
+        true => Some(random_service.get_random_secret().await?),
+        false => None,
+    };
+
+    let client = OAuthClient::new(NewOAuthClientSpecification {
+        name,
+        redirect_uris,
+        secret_hash: client_secret
+            .as_ref()
+            .map(|client_secret| SecretHash::hash(client_secret)),
+        scopes,
+    });
+    oauth_client_repository.save(&client).await?;
+
+    Ok(RegisterOAuthClientResponse {
+        client: OAuthClientDto::from(&client),
+        client_secret,
+    })
+}
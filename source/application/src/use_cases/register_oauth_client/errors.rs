@@ -0,0 +1,35 @@
+use nimbus_auth_domain::entities::{
+    oauth_client::value_objects::{
+        oauth_client_name::errors::OAuthClientNameError, redirect_uri::errors::RedirectUriError,
+    },
+    role::value_objects::permission::errors::PermissionError,
+};
+use thiserror::Error;
+
+use crate::{
+    services::{
+        oauth_client_repository::errors::OAuthClientRepositoryError,
+        random_service::errors::RandomServiceError,
+    },
+    use_cases::PermissionDeniedError,
+};
+
+#[derive(Debug, Error)]
+pub enum RegisterOAuthClientError {
+    #[error(transparent)]
+    Forbidden(#[from] PermissionDeniedError),
+    #[error(transparent)]
+    InvalidClientName(#[from] OAuthClientNameError),
+    #[error(transparent)]
+    InvalidRedirectUri(#[from] RedirectUriError),
+    #[error("public oauth client should have at least one redirect uri")]
+    NoRedirectUris,
+    #[error("invalid scope. Error: {0}")]
+    InvalidScope(#[from] PermissionError),
+    #[error("public oauth client can not have scopes, they are granted through client credentials")]
+    PublicClientScopes,
+    #[error(transparent)]
+    OAuthClientRepository(#[from] OAuthClientRepositoryError),
+    #[error(transparent)]
+    RandomService(#[from] RandomServiceError),
+}
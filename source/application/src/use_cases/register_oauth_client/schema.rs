@@ -0,0 +1,19 @@
+use zeroize::Zeroizing;
+
+use crate::use_cases::{OAuthClientDto, UserClaimsDto};
+
+pub struct RegisterOAuthClientRequest<'a> {
+    pub user: UserClaimsDto,
+    pub client_name: &'a str,
+    pub redirect_uris: &'a [String],
+    /// Confidential clients get a secret, e.g. backend services
+    pub confidential: bool,
+    /// Permissions the client can be granted through the client credentials grant
+    pub scopes: &'a [String],
+}
+
+pub struct RegisterOAuthClientResponse {
+    pub client: OAuthClientDto,
+    /// Only confidential clients have a secret, it can not be retrieved later
+    pub client_secret: Option<Zeroizing<String>>,
+}
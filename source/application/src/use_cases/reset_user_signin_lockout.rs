@@ -0,0 +1,36 @@
+use nimbus_auth_domain::entities::user::value_objects::user_name::UserName;
+use nimbus_auth_shared::constants::PERMISSION_MANAGE_USERS;
+
+use crate::use_cases::{
+    ResetUserSigninLockoutError, ResetUserSigninLockoutRequest, ResetUserSigninLockoutResponse,
+    UseCasesConfig, UseCasesServices, guards::require_permission,
+};
+
+pub mod errors;
+pub mod schema;
+
+pub async fn handle_reset_user_signin_lockout<'a>(
+    ResetUserSigninLockoutRequest { user, user_name }: ResetUserSigninLockoutRequest<'a>,
+    UseCasesServices {
+        user_repository, ..
+    }: &UseCasesServices,
+    _: &UseCasesConfig,
+) -> Result<ResetUserSigninLockoutResponse, ResetUserSigninLockoutError> {
+    require_permission(&user, PERMISSION_MANAGE_USERS)?;
+
+    let user_name = UserName::from(user_name)?;
+
+    let target_user = user_repository.get_by_name(&user_name).await?.ok_or(
+        ResetUserSigninLockoutError::UserIsNotFound {
+            user_name: user_name.to_string(),
+        },
+    )?;
+
+    if !target_user.signin_lockout().is_reset() {
+        user_repository
+            .save(target_user.with_signin_lockout_reset())
+            .await?;
+    }
+
+    Ok(ResetUserSigninLockoutResponse {})
+}
@@ -0,0 +1,8 @@
+use crate::use_cases::UserClaimsDto;
+
+pub struct ResetUserSigninLockoutRequest<'a> {
+    pub user: UserClaimsDto,
+    pub user_name: &'a str,
+}
+
+pub struct ResetUserSigninLockoutResponse {}
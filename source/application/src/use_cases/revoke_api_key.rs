@@ -0,0 +1,39 @@
+use nimbus_auth_domain::value_objects::identifier::Identifier;
+use ulid::Ulid;
+
+use crate::use_cases::{
+    ApiKeyDto, RevokeApiKeyError, RevokeApiKeyRequest, RevokeApiKeyResponse, UseCasesConfig,
+    UseCasesServices,
+};
+
+pub mod errors;
+pub mod schema;
+
+/// Revokes an api key of the user, keys of other users are reported as not found
+pub async fn handle_revoke_api_key<'a>(
+    RevokeApiKeyRequest { user, api_key_id }: RevokeApiKeyRequest<'a>,
+    UseCasesServices {
+        api_key_repository,
+        time_service,
+        ..
+    }: &UseCasesServices,
+    _: &UseCasesConfig,
+) -> Result<RevokeApiKeyResponse, RevokeApiKeyError> {
+    let user_id = Ulid::from_string(&user.id)?;
+    let api_key_id =
+        Ulid::from_string(api_key_id).map_err(|_| RevokeApiKeyError::ApiKeyIsNotFound)?;
+
+    let api_key = api_key_repository
+        .get_by_id(&Identifier::from(api_key_id))
+        .await?
+        .filter(|api_key| api_key.user_id() == &Identifier::from(user_id))
+        .ok_or(RevokeApiKeyError::ApiKeyIsNotFound)?;
+
+    let current_time = time_service.get_current_time().await?;
+    let api_key = api_key.revoke(current_time);
+    api_key_repository.save(&api_key).await?;
+
+    Ok(RevokeApiKeyResponse {
+        api_key: ApiKeyDto::from(&api_key),
+    })
+}
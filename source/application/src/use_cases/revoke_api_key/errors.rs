@@ -0,0 +1,18 @@
+use thiserror::Error;
+use ulid::DecodeError;
+
+use crate::services::{
+    api_key_repository::errors::ApiKeyRepositoryError, time_service::errors::TimeServiceError,
+};
+
+#[derive(Debug, Error)]
+pub enum RevokeApiKeyError {
+    #[error("invalid user id. Error: {0}")]
+    InvalidUserId(#[from] DecodeError),
+    #[error("api key is not found")]
+    ApiKeyIsNotFound,
+    #[error(transparent)]
+    ApiKeyRepository(#[from] ApiKeyRepositoryError),
+    #[error(transparent)]
+    TimeService(#[from] TimeServiceError),
+}
@@ -0,0 +1,10 @@
+use crate::use_cases::{ApiKeyDto, UserClaimsDto};
+
+pub struct RevokeApiKeyRequest<'a> {
+    pub user: UserClaimsDto,
+    pub api_key_id: &'a str,
+}
+
+pub struct RevokeApiKeyResponse {
+    pub api_key: ApiKeyDto,
+}
@@ -0,0 +1,85 @@
+use std::{borrow::Cow, str::FromStr};
+
+use nimbus_auth_domain::{
+    entities::{
+        Entity,
+        keypair::{
+            SomeKeyPair, specifications::NewKeyPairSpecification, value_objects::KeyPairValue,
+        },
+    },
+    value_objects::identifier::Identifier,
+};
+use nimbus_auth_shared::constants::PERMISSION_MANAGE_KEYPAIRS;
+use ulid::Ulid;
+
+use crate::use_cases::{
+    RevokeKeyPairError, RevokeKeyPairRequest, RevokeKeyPairResponse, UseCasesConfig,
+    UseCasesServices, guards::require_permission,
+};
+
+pub mod errors;
+pub mod schema;
+
+/// Tokens signed by the revoked key pair stop being verified immediately
+///
+/// Revoked active key pair is replaced with a new one, so signin keeps working
+pub async fn handle_revoke_keypair<'a>(
+    RevokeKeyPairRequest { user, key_id }: RevokeKeyPairRequest<'a>,
+    UseCasesServices {
+        keypair_repository,
+        time_service,
+        random_service,
+        ..
+    }: &UseCasesServices,
+    _: &UseCasesConfig,
+) -> Result<RevokeKeyPairResponse, RevokeKeyPairError> {
+    require_permission(&user, PERMISSION_MANAGE_KEYPAIRS)?;
+
+    let id = Identifier::from(Ulid::from_str(key_id)?);
+
+    let current_time = time_service.get_current_time().await?;
+
+    let transactional_keypair_repository = keypair_repository.start_transaction().await?;
+
+    let (transactional_keypair_repository, keypair) =
+        transactional_keypair_repository.get_by_id(&id).await?;
+
+    let (transactional_keypair_repository, new_active_keypair) = match keypair {
+        Some(SomeKeyPair::Active(keypair)) => {
+            let revoked_keypair = keypair.into_owned().revoke(current_time);
+            let private_key_pem = random_service.get_random_private_key_pem().await?;
+            let new_active_keypair = SomeKeyPair::new(NewKeyPairSpecification {
+                value: KeyPairValue::from_pem(private_key_pem)?,
+            });
+            let (transactional_keypair_repository, _) = transactional_keypair_repository
+                .save(SomeKeyPair::Revoked(Cow::Borrowed(&revoked_keypair)))
+                .await?;
+            let (transactional_keypair_repository, _) = transactional_keypair_repository
+                .save(SomeKeyPair::Active(Cow::Borrowed(&new_active_keypair)))
+                .await?;
+            (transactional_keypair_repository, Some(new_active_keypair))
+        }
+        Some(SomeKeyPair::Expiring(keypair)) => {
+            let revoked_keypair = keypair.into_owned().revoke(current_time);
+            let (transactional_keypair_repository, _) = transactional_keypair_repository
+                .save(SomeKeyPair::Revoked(Cow::Borrowed(&revoked_keypair)))
+                .await?;
+            (transactional_keypair_repository, None)
+        }
+        keypair => {
+            transactional_keypair_repository.rollback().await?;
+            let key_id = key_id.to_string();
+            return Err(match keypair {
+                Some(SomeKeyPair::Revoked(_)) => RevokeKeyPairError::KeyPairIsRevoked { key_id },
+                Some(SomeKeyPair::Expired(_)) => RevokeKeyPairError::KeyPairIsExpired { key_id },
+                _ => RevokeKeyPairError::KeyPairNotFound { key_id },
+            });
+        }
+    };
+
+    transactional_keypair_repository.commit().await?;
+
+    Ok(RevokeKeyPairResponse {
+        new_active_key_id: new_active_keypair.map(|keypair| keypair.id().to_string()),
+    })
+}
@@ -0,0 +1,33 @@
+use nimbus_auth_domain::entities::keypair::value_objects::errors::KeyPairValueError;
+use thiserror::Error;
+use ulid::DecodeError;
+
+use crate::{
+    services::{
+        keypair_repository::errors::KeyPairRepositoryError,
+        random_service::errors::RandomServiceError, time_service::errors::TimeServiceError,
+    },
+    use_cases::PermissionDeniedError,
+};
+
+#[derive(Debug, Error)]
+pub enum RevokeKeyPairError {
+    #[error(transparent)]
+    Forbidden(#[from] PermissionDeniedError),
+    #[error("invalid key id. Error: {0}")]
+    InvalidKeyId(#[from] DecodeError),
+    #[error("key pair with id: {key_id} is not found")]
+    KeyPairNotFound { key_id: String },
+    #[error("key pair with id: {key_id} is already revoked")]
+    KeyPairIsRevoked { key_id: String },
+    #[error("key pair with id: {key_id} is expired")]
+    KeyPairIsExpired { key_id: String },
+    #[error(transparent)]
+    RandomService(#[from] RandomServiceError),
+    #[error(transparent)]
+    KeyPairValue(#[from] KeyPairValueError),
+    #[error(transparent)]
+    KeyPairRepository(#[from] KeyPairRepositoryError),
+    #[error(transparent)]
+    TimeService(#[from] TimeServiceError),
+}
@@ -0,0 +1,11 @@
+use crate::use_cases::UserClaimsDto;
+
+pub struct RevokeKeyPairRequest<'a> {
+    pub user: UserClaimsDto,
+    pub key_id: &'a str,
+}
+
+pub struct RevokeKeyPairResponse {
+    /// Present if the revoked key pair was active and has been replaced
+    pub new_active_key_id: Option<String>,
+}
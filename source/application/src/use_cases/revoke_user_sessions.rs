@@ -0,0 +1,84 @@
+use std::borrow::Cow;
+
+use nimbus_auth_domain::{
+    entities::{
+        Entity,
+        session::SomeSession,
+        user::{User, value_objects::user_name::UserName},
+    },
+    value_objects::identifier::Identifier,
+};
+use nimbus_auth_shared::constants::PERMISSION_MANAGE_USERS;
+use time::OffsetDateTime;
+use ulid::Ulid;
+
+use crate::{
+    services::session_repository::{
+        SessionRepositoryWithTransaction, errors::SessionRepositoryError,
+    },
+    use_cases::{
+        RevokeUserSessionsError, RevokeUserSessionsRequest, RevokeUserSessionsResponse,
+        UseCasesConfig, UseCasesServices, guards::require_permission,
+    },
+};
+
+pub mod errors;
+pub mod schema;
+
+pub async fn handle_revoke_user_sessions<'a>(
+    RevokeUserSessionsRequest { user, user_name }: RevokeUserSessionsRequest<'a>,
+    UseCasesServices {
+        user_repository,
+        session_repository,
+        time_service,
+        ..
+    }: &UseCasesServices,
+    _: &UseCasesConfig,
+) -> Result<RevokeUserSessionsResponse, RevokeUserSessionsError> {
+    require_permission(&user, PERMISSION_MANAGE_USERS)?;
+
+    let user_name = UserName::from(user_name)?;
+
+    let target_user = user_repository.get_by_name(&user_name).await?.ok_or(
+        RevokeUserSessionsError::UserIsNotFound {
+            user_name: user_name.to_string(),
+        },
+    )?;
+
+    let transactional_session_repository = session_repository.start_transaction().await?;
+
+    let (transactional_session_repository, revoked_sessions_count) = revoke_active_sessions(
+        transactional_session_repository,
+        target_user.id(),
+        time_service.get_current_time().await?,
+    )
+    .await?;
+
+    transactional_session_repository.commit().await?;
+
+    Ok(RevokeUserSessionsResponse {
+        revoked_sessions_count,
+    })
+}
+
+/// Revokes all active sessions of the user within the transaction, returns the number of revoked sessions
+pub(crate) async fn revoke_active_sessions(
+    transactional_session_repository: Box<dyn SessionRepositoryWithTransaction>,
+    user_id: &Identifier<Ulid, User>,
+    current_time: OffsetDateTime,
+) -> Result<(Box<dyn SessionRepositoryWithTransaction>, usize), SessionRepositoryError> {
+    let (mut transactional_session_repository, active_sessions) = transactional_session_repository
+        .get_active_by_user_id(user_id, current_time)
+        .await?;
+
+    let revoked_sessions_count = active_sessions.len();
+    for active_session in active_sessions {
+        let revoked_session = active_session.revoke(current_time);
+        let (repository, _) = transactional_session_repository
+            .save(SomeSession::Revoked(Cow::Borrowed(&revoked_session)))
+            .await?;
+        transactional_session_repository = repository;
+    }
+
+    Ok((transactional_session_repository, revoked_sessions_count))
+}
@@ -0,0 +1,10 @@
+use crate::use_cases::UserClaimsDto;
+
+pub struct RevokeUserSessionsRequest<'a> {
+    pub user: UserClaimsDto,
+    pub user_name: &'a str,
+}
+
+pub struct RevokeUserSessionsResponse {
+    pub revoked_sessions_count: usize,
+}
@@ -1,16 +1,13 @@
-use std::{borrow::Cow, sync::Arc};
+use std::borrow::Cow;
 
 use nimbus_auth_domain::entities::keypair::{
     SomeKeyPair, specifications::NewKeyPairSpecification, value_objects::KeyPairValue,
 };
-use nimbus_auth_shared::types::{AccessTokenExpirationSeconds, UserRole};
+use nimbus_auth_shared::constants::PERMISSION_MANAGE_KEYPAIRS;
 
-use crate::{
-    services::{
-        keypair_repository::KeyPairRepository, random_service::RandomService,
-        time_service::TimeService,
-    },
-    use_cases::{RotateKeyPairsError, RotateKeyPairsRequest, RotateKeyPairsResponse},
+use crate::use_cases::{
+    RotateKeyPairsError, RotateKeyPairsRequest, RotateKeyPairsResponse, UseCasesConfig,
+    UseCasesServices, guards::require_permission,
 };
 
 pub mod errors;
@@ -18,14 +15,17 @@ pub mod schema;
 
 pub async fn handle_rotate_keypairs(
     RotateKeyPairsRequest { user }: RotateKeyPairsRequest,
-    keypair_repository: Arc<dyn KeyPairRepository>,
-    time_service: Arc<dyn TimeService>,
-    random_service: Arc<dyn RandomService>,
-    expiration_seconds: AccessTokenExpirationSeconds,
+    UseCasesServices {
+        keypair_repository,
+        time_service,
+        random_service,
+        ..
+    }: &UseCasesServices,
+    config: &UseCasesConfig,
 ) -> Result<RotateKeyPairsResponse, RotateKeyPairsError> {
-    if user.role != UserRole::Admin {
-        return Err(RotateKeyPairsError::Forbidden(user.role));
-    }
+    let expiration_seconds = config.access_token_expiration_seconds;
+
+    require_permission(&user, PERMISSION_MANAGE_KEYPAIRS)?;
 
     let private_key_pem = random_service.get_random_private_key_pem().await?;
     let keypair_value = KeyPairValue::from_pem(private_key_pem)?;
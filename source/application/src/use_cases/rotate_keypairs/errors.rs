@@ -1,16 +1,18 @@
 use nimbus_auth_domain::entities::keypair::value_objects::errors::KeyPairValueError;
-use nimbus_auth_shared::types::UserRole;
 use thiserror::Error;
 
-use crate::services::{
-    keypair_repository::errors::KeyPairRepositoryError, random_service::errors::RandomServiceError,
-    time_service::errors::TimeServiceError,
+use crate::{
+    services::{
+        keypair_repository::errors::KeyPairRepositoryError,
+        random_service::errors::RandomServiceError, time_service::errors::TimeServiceError,
+    },
+    use_cases::PermissionDeniedError,
 };
 
 #[derive(Debug, Error)]
 pub enum RotateKeyPairsError {
-    #[error("operation forbiddden for a user with a role: {0}")]
-    Forbidden(UserRole),
+    #[error(transparent)]
+    Forbidden(#[from] PermissionDeniedError),
     #[error(transparent)]
     RandomService(#[from] RandomServiceError),
     #[error(transparent)]
@@ -1,20 +1,31 @@
 use std::{borrow::Cow, sync::Arc};
 
-use nimbus_auth_domain::entities::{
-    Entity,
-    session::{SomeSession, specifications::NewSessionSpecification},
-    user::value_objects::{password::Password, user_name::UserName},
+use nimbus_auth_domain::{
+    entities::{
+        Entity,
+        role::value_objects::role_name::RoleName,
+        session::{SomeSession, specifications::NewSessionSpecification},
+        user::{
+            SomeUser, User,
+            specifications::NewUserSpecification,
+            value_objects::{password::Password, password_hash::PasswordHash, user_name::UserName},
+        },
+    },
+    value_objects::audiences::Audiences,
 };
-use nimbus_auth_shared::types::{AccessTokenExpirationSeconds, SessionExpirationSeconds};
+use nimbus_auth_shared::types::SigninLockoutPolicy;
+use time::OffsetDateTime;
+use tokio::spawn;
+use tracing::warn;
 use zeroize::Zeroizing;
 
 use crate::{
     services::{
-        keypair_repository::KeyPairRepository, session_repository::SessionRepository,
-        time_service::TimeService, user_repository::UserRepository,
+        legacy_authenticator::LegacyAuthenticator, random_service::RandomService,
+        role_repository::RoleRepository, user_repository::UserRepository,
     },
     use_cases::{
-        UserClaimsDto,
+        UseCasesConfig, UseCasesServices, UserClaimsDto,
         dtos::{access_token::AccessTokenDto, session::SessionDto},
         signin::{
             errors::SignInError,
@@ -30,29 +41,145 @@ pub async fn handle_signin<'a>(
     SignInRequest {
         user_name,
         password,
+        audiences,
     }: SignInRequest<'a>,
-    user_repository: Arc<dyn UserRepository>,
-    session_repository: Arc<dyn SessionRepository>,
-    keypair_repository: Arc<dyn KeyPairRepository>,
-    time_service: Arc<dyn TimeService>,
-    session_exp_seconds: SessionExpirationSeconds,
-    access_token_exp_seconds: AccessTokenExpirationSeconds,
+    UseCasesServices {
+        user_repository,
+        role_repository,
+        session_repository,
+        keypair_repository,
+        time_service,
+        random_service,
+        legacy_authenticator,
+        ..
+    }: &UseCasesServices,
+    config: &UseCasesConfig,
 ) -> Result<SignInResponse, SignInError> {
     let user_name = UserName::from(user_name)?;
+    let audiences = Audiences::from(audiences, &config.realm)?;
+    let password_peppers = &config.password_peppers;
+    let dummy_password_hash = config.dummy_password_hash.as_deref();
+
+    let password = Password::from_unvalidated(password);
+
+    let user = match user_repository.get_by_name(&user_name).await? {
+        Some(user) => {
+            if let (Some(_), Some(legacy_authenticator)) =
+                (dummy_password_hash, legacy_authenticator)
+            {
+                // unknown users are checked by legacy backend, so known users pay for the same call
+                legacy_authenticator
+                    .authenticate(&user_name, &password)
+                    .await?;
+            }
+
+            let current_time = time_service.get_current_time().await?;
+            if let Some(locked_until) = user.signin_lockout().active_locked_until(current_time) {
+                // lockout is not revealed either, otherwise it would show that the user exists
+                if let Some(dummy_password_hash) = dummy_password_hash {
+                    dummy_password_hash.verify(&password, password_peppers)?;
+                    return Err(SignInError::PasswordDoesNotMatchWithHash);
+                }
+                return Err(SignInError::UserIsLockedOut {
+                    user_name: user_name.to_string(),
+                    locked_until,
+                });
+            }
 
-    let user =
-        user_repository
-            .get_by_name(&user_name)
+            if !user.password_hash().verify(&password, password_peppers)? {
+                // recorded in the background, so the response takes as long as for unknown users
+                let user_name = user_name.clone();
+                let user_repository = user_repository.clone();
+                let signin_lockout_policy = config.signin_lockout_policy;
+                spawn(async move {
+                    if let Err(err) = record_failed_signin_attempt(
+                        &user_name,
+                        user_repository,
+                        current_time,
+                        &signin_lockout_policy,
+                    )
+                    .await
+                    {
+                        warn!("failed signin attempt of user {user_name} was not recorded: {err}");
+                    }
+                });
+                return Err(SignInError::PasswordDoesNotMatchWithHash);
+            }
+
+            // status is checked only after the password, so it is not revealed to anyone else
+            let (user, is_unsuspended) = match user {
+                SomeUser::Active(user) => (user.into_owned(), false),
+                SomeUser::Suspended(user) => match user.is_suspension_over(current_time) {
+                    true => (user.into_owned().unsuspend(), true),
+                    false => {
+                        return Err(SignInError::UserIsSuspended {
+                            user_name: user_name.to_string(),
+                            suspended_until: user.suspended_until(),
+                        });
+                    }
+                },
+                SomeUser::Deleted(_) => {
+                    return Err(SignInError::UserIsDeleted {
+                        user_name: user_name.to_string(),
+                    });
+                }
+            };
+
+            let user = match user.signin_lockout().is_reset() && !is_unsuspended {
+                true => user,
+                false => {
+                    let user = user.with_signin_lockout_reset();
+                    user_repository.save(SomeUser::from(&user)).await?;
+                    user
+                }
+            };
+
+            match user
+                .password_hash()
+                .needs_rehash(&config.password_hashing_params, password_peppers)
+            {
+                true => {
+                    // signin should not fail because of the upgrade, it will be retried on the next signin
+                    match upgrade_password_hash(
+                        &user,
+                        password,
+                        user_repository.clone(),
+                        random_service.clone(),
+                        config,
+                    )
+                    .await
+                    {
+                        Ok(upgraded_user) => upgraded_user,
+                        Err(err) => {
+                            warn!("password hash upgrade failed for user {}: {err}", user.id());
+                            user
+                        }
+                    }
+                }
+                false => user,
+            }
+        }
+        None => {
+            if let Some(dummy_password_hash) = dummy_password_hash {
+                // same hashing work as for existing users, so the response time does not reveal them
+                dummy_password_hash.verify(&password, password_peppers)?;
+            }
+
+            migrate_legacy_user(
+                &user_name,
+                password,
+                legacy_authenticator.clone(),
+                user_repository.clone(),
+                role_repository.clone(),
+                random_service.clone(),
+                config,
+            )
             .await?
             .ok_or(SignInError::UserIsNotFound {
                 user_name: user_name.to_string(),
-            })?;
-
-    let password = Password::from(password)?;
-
-    if !user.password_hash().verify(&password) {
-        return Err(SignInError::PasswordDoesNotMatchWithHash);
-    }
+            })?
+        }
+    };
 
     let active_keypair = keypair_repository
         .get_active()
@@ -61,8 +188,9 @@ pub async fn handle_signin<'a>(
 
     let session = SomeSession::new(NewSessionSpecification {
         user_claims: user.claims().clone(),
+        client_id: None,
         current_time: time_service.get_current_time().await?,
-        expiration_seconds: session_exp_seconds,
+        expiration_seconds: config.session_expiration_seconds,
     });
 
     let transactional_session_repository = session_repository.start_transaction().await?;
@@ -71,15 +199,19 @@ pub async fn handle_signin<'a>(
         .save(SomeSession::Active(Cow::Borrowed(&session)))
         .await?;
 
-    let access_token = &session.generate_access_token(
-        time_service.get_current_time().await?,
-        access_token_exp_seconds,
-    );
-    let signed_access_token = access_token.sign(&active_keypair)?;
+    let access_token = &session
+        .generate_access_token(
+            time_service.get_current_time().await?,
+            config.access_token_expiration_seconds,
+            config.access_token_max_groups,
+            &config.access_token_attributes,
+        )
+        .with_audiences(audiences);
+    let signed_access_token = access_token.sign(&active_keypair, &config.realm)?;
 
     transactional_session_repository.commit().await?;
 
-    let user_dto = UserClaimsDto::from(user.claims());
+    let user_dto = UserClaimsDto::from(access_token.user_claims());
 
     let session_dto = SessionDto {
         session_id: session.id().to_string(),
@@ -97,3 +229,93 @@ pub async fn handle_signin<'a>(
         access_token: access_token_dto,
     })
 }
+
+/// User is read again in the transaction, so concurrent failed attempts are all counted
+async fn record_failed_signin_attempt(
+    user_name: &UserName,
+    user_repository: Arc<dyn UserRepository>,
+    current_time: OffsetDateTime,
+    signin_lockout_policy: &SigninLockoutPolicy,
+) -> Result<(), SignInError> {
+    let transactional_user_repository = user_repository.start_transaction().await?;
+
+    let (transactional_user_repository, user) =
+        transactional_user_repository.get_by_name(user_name).await?;
+
+    let Some(user) = user else {
+        transactional_user_repository.rollback().await?;
+        return Ok(());
+    };
+
+    let (transactional_user_repository, _) = transactional_user_repository
+        .save(user.with_failed_signin_attempt(current_time, signin_lockout_policy))
+        .await?;
+
+    transactional_user_repository.commit().await?;
+
+    Ok(())
+}
+
+async fn upgrade_password_hash(
+    user: &User,
+    password: Password,
+    user_repository: Arc<dyn UserRepository>,
+    random_service: Arc<dyn RandomService>,
+    config: &UseCasesConfig,
+) -> Result<User, SignInError> {
+    let salt_b64 = random_service.get_random_salt_b64().await?;
+    let password_hash = PasswordHash::hash(
+        password,
+        &salt_b64,
+        &config.password_hashing_params,
+        &config.password_peppers,
+    )?;
+    let upgraded_user = user.clone().with_new_password_hash(password_hash);
+
+    user_repository.save(SomeUser::from(&upgraded_user)).await?;
+
+    Ok(upgraded_user)
+}
+
+/// Creates user authenticated by legacy backend, returns `None` if there is no legacy backend or it rejects credentials
+async fn migrate_legacy_user(
+    user_name: &UserName,
+    password: Password,
+    legacy_authenticator: Option<Arc<dyn LegacyAuthenticator>>,
+    user_repository: Arc<dyn UserRepository>,
+    role_repository: Arc<dyn RoleRepository>,
+    random_service: Arc<dyn RandomService>,
+    config: &UseCasesConfig,
+) -> Result<Option<User>, SignInError> {
+    let Some(legacy_authenticator) = legacy_authenticator else {
+        return Ok(None);
+    };
+
+    if !legacy_authenticator
+        .authenticate(user_name, &password)
+        .await?
+    {
+        return Ok(None);
+    }
+
+    let salt_b64 = random_service.get_random_salt_b64().await?;
+    let password_hash = PasswordHash::hash(
+        password,
+        &salt_b64,
+        &config.password_hashing_params,
+        &config.password_peppers,
+    )?;
+    let default_role = role_repository
+        .get_by_name(&RoleName::default_role())
+        .await?
+        .ok_or(SignInError::DefaultRoleNotFound)?;
+    let user = User::new(NewUserSpecification {
+        user_name: user_name.clone(),
+        password_hash,
+        roles: vec![default_role],
+    });
+
+    user_repository.save(SomeUser::from(&user)).await?;
+
+    Ok(Some(user))
+}
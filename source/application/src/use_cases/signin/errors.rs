@@ -1,30 +1,53 @@
 use nimbus_auth_domain::{
     entities::user::value_objects::{
-        password::errors::PasswordError, user_name::errors::UserNameError,
+        password_hash::errors::PasswordHashError, user_name::errors::UserNameError,
+    },
+    value_objects::{
+        access_token::errors::SignAccessTokenError, audiences::errors::AudiencesError,
     },
-    value_objects::access_token::errors::SignAccessTokenError,
 };
 use thiserror::Error;
+use time::OffsetDateTime;
 
 use crate::services::{
     keypair_repository::errors::KeyPairRepositoryError,
+    legacy_authenticator::errors::LegacyAuthenticatorError,
+    random_service::errors::RandomServiceError, role_repository::errors::RoleRepositoryError,
     session_repository::errors::SessionRepositoryError, time_service::errors::TimeServiceError,
     user_repository::errors::UserRepositoryError,
 };
 
 #[derive(Debug, Error)]
 pub enum SignInError {
+    #[error(transparent)]
+    InvalidAudiences(#[from] AudiencesError),
     #[error(transparent)]
     InvalidUserName(#[from] UserNameError),
     #[error(transparent)]
     UserRepository(#[from] UserRepositoryError),
     #[error("user with name: {user_name} is not found")]
     UserIsNotFound { user_name: String },
-    #[error(transparent)]
-    InvalidPassword(#[from] PasswordError),
+    #[error("user with name: {user_name} is locked out until {locked_until}")]
+    UserIsLockedOut {
+        user_name: String,
+        locked_until: OffsetDateTime,
+    },
+    #[error("user with name: {user_name} is suspended until {suspended_until:?}")]
+    UserIsSuspended {
+        user_name: String,
+        suspended_until: Option<OffsetDateTime>,
+    },
+    #[error("user with name: {user_name} is deleted")]
+    UserIsDeleted { user_name: String },
     #[error("password does not match saved hash")]
     PasswordDoesNotMatchWithHash,
     #[error(transparent)]
+    LegacyAuthenticator(#[from] LegacyAuthenticatorError),
+    #[error(transparent)]
+    PasswordHash(#[from] PasswordHashError),
+    #[error(transparent)]
+    RandomService(#[from] RandomServiceError),
+    #[error(transparent)]
     SessionRepository(#[from] SessionRepositoryError),
     #[error(transparent)]
     TimeService(#[from] TimeServiceError),
@@ -33,5 +56,9 @@ pub enum SignInError {
     #[error("active key pair not found")]
     ActiveKeyPairNotFound,
     #[error(transparent)]
+    RoleRepository(#[from] RoleRepositoryError),
+    #[error("default role not found")]
+    DefaultRoleNotFound,
+    #[error(transparent)]
     SignAccessToken(#[from] SignAccessTokenError),
 }
@@ -8,6 +8,8 @@ use crate::use_cases::{
 pub struct SignInRequest<'a> {
     pub user_name: &'a str,
     pub password: &'a Zeroizing<String>,
+    /// Audiences the access token should target, limited to the realm allowlist
+    pub audiences: &'a [String],
 }
 
 pub struct SignInResponse {
@@ -1,25 +1,32 @@
 use std::{borrow::Cow, sync::Arc};
 
-use nimbus_auth_domain::entities::{
-    Entity,
-    session::{SomeSession, specifications::NewSessionSpecification},
-    user::{
-        User,
-        specifications::NewUserSpecification,
-        value_objects::{password::Password, password_hash::PasswordHash, user_name::UserName},
+use nimbus_auth_domain::{
+    entities::{
+        Entity,
+        role::value_objects::role_name::RoleName,
+        session::{SomeSession, specifications::NewSessionSpecification},
+        user::{
+            SomeUser, User,
+            specifications::NewUserSpecification,
+            value_objects::{password::Password, password_hash::PasswordHash, user_name::UserName},
+        },
     },
+    value_objects::audiences::Audiences,
 };
-use nimbus_auth_shared::types::{AccessTokenExpirationSeconds, SessionExpirationSeconds};
+use tokio::spawn;
+use tracing::warn;
 use zeroize::Zeroizing;
 
 use crate::{
     services::{
-        keypair_repository::KeyPairRepository, random_service::RandomService,
-        session_repository::SessionRepository, time_service::TimeService,
-        user_repository::UserRepository,
+        random_service::RandomService,
+        role_repository::RoleRepository,
+        signup_notifier::{SignUpNotification, SignUpNotifier},
+        user_repository::{UserRepository, errors::UserRepositoryError},
     },
     use_cases::{
-        SignUpRequest, SignUpResponse, UserClaimsDto,
+        SignUpRequest, SignUpResponse, SignedUpResponse, UseCasesConfig, UseCasesServices,
+        UserClaimsDto,
         dtos::{access_token::AccessTokenDto, session::SessionDto},
         signup::errors::SignUpError,
     },
@@ -32,16 +39,35 @@ pub async fn handle_signup<'a>(
     SignUpRequest {
         user_name,
         password,
+        audiences,
     }: SignUpRequest<'a>,
-    user_repository: Arc<dyn UserRepository>,
-    session_repository: Arc<dyn SessionRepository>,
-    keypair_repository: Arc<dyn KeyPairRepository>,
-    time_service: Arc<dyn TimeService>,
-    random_service: Arc<dyn RandomService>,
-    session_exp_seconds: SessionExpirationSeconds,
-    access_token_exp_seconds: AccessTokenExpirationSeconds,
+    UseCasesServices {
+        user_repository,
+        role_repository,
+        session_repository,
+        keypair_repository,
+        time_service,
+        random_service,
+        signup_notifier,
+        ..
+    }: &UseCasesServices,
+    config: &UseCasesConfig,
 ) -> Result<SignUpResponse, SignUpError> {
     let user_name = UserName::from(user_name)?;
+    let audiences = Audiences::from(audiences, &config.realm)?;
+
+    if config.dummy_password_hash.is_some() {
+        return handle_uniform_signup(
+            user_name,
+            password,
+            user_repository.clone(),
+            role_repository.clone(),
+            random_service.clone(),
+            signup_notifier.clone(),
+            config,
+        )
+        .await;
+    }
 
     let existing_user = user_repository.get_by_name(&user_name).await?;
 
@@ -51,29 +77,54 @@ pub async fn handle_signup<'a>(
         });
     }
 
-    let password = Password::from(password)?;
+    let password = Password::from(
+        password,
+        &config.password_policy,
+        &config.breached_passwords_filter,
+    )?;
     let salt_b64 = random_service.get_random_salt_b64().await?;
-    let password_hash = PasswordHash::hash(password, &salt_b64)?;
+    let password_hash = PasswordHash::hash(
+        password,
+        &salt_b64,
+        &config.password_hashing_params,
+        &config.password_peppers,
+    )?;
 
     let active_keypair = keypair_repository
         .get_active()
         .await?
         .ok_or(SignUpError::ActiveKeyPairNotFound)?;
 
+    let default_role = role_repository
+        .get_by_name(&RoleName::default_role())
+        .await?
+        .ok_or(SignUpError::DefaultRoleNotFound)?;
+
     let user = User::new(NewUserSpecification {
         user_name,
         password_hash,
+        roles: vec![default_role],
     });
 
     let session = SomeSession::new(NewSessionSpecification {
         user_claims: user.claims().clone(),
+        client_id: None,
         current_time: time_service.get_current_time().await?,
-        expiration_seconds: session_exp_seconds,
+        expiration_seconds: config.session_expiration_seconds,
     });
 
     let transactional_user_repository = user_repository.start_transaction().await?;
 
-    let (transactional_user_repository, _) = transactional_user_repository.save(&user).await?;
+    // user name may be taken by a concurrent signup since the check above
+    let (transactional_user_repository, _) = transactional_user_repository
+        .save(SomeUser::from(&user))
+        .await
+        .map_err(|err| match err {
+            UserRepositoryError::UserNameIsTaken => SignUpError::UserAlreadyExists {
+                user_name: user.name().to_string(),
+            },
+            err => SignUpError::from(err),
+        })?;
 
     let transactional_session_repository = session_repository.start_transaction().await?;
 
@@ -81,16 +132,20 @@ pub async fn handle_signup<'a>(
         .save(SomeSession::Active(Cow::Borrowed(&session)))
         .await?;
 
-    let access_token = &session.generate_access_token(
-        time_service.get_current_time().await?,
-        access_token_exp_seconds,
-    );
-    let signed_access_token = access_token.sign(&active_keypair)?;
+    let access_token = &session
+        .generate_access_token(
+            time_service.get_current_time().await?,
+            config.access_token_expiration_seconds,
+            config.access_token_max_groups,
+            &config.access_token_attributes,
+        )
+        .with_audiences(audiences);
+    let signed_access_token = access_token.sign(&active_keypair, &config.realm)?;
 
     transactional_session_repository.commit().await?;
     transactional_user_repository.commit().await?;
 
-    let user_dto = UserClaimsDto::from(user.claims());
+    let user_dto = UserClaimsDto::from(access_token.user_claims());
 
     let session_dto = SessionDto {
         session_id: session.id().to_string(),
@@ -102,9 +157,76 @@ pub async fn handle_signup<'a>(
         signed_access_token_expires_at_unix_timestamp: access_token.expires_at().unix_timestamp(),
     };
 
-    Ok(SignUpResponse {
+    Ok(SignUpResponse::SignedUp(Box::new(SignedUpResponse {
         user: user_dto,
         session: session_dto,
         access_token: access_token_dto,
-    })
+    })))
+}
+
+/// Responds the same way whether the user name is taken or not, the outcome is delivered by signup notifier
+async fn handle_uniform_signup(
+    user_name: UserName,
+    password: &Zeroizing<String>,
+    user_repository: Arc<dyn UserRepository>,
+    role_repository: Arc<dyn RoleRepository>,
+    random_service: Arc<dyn RandomService>,
+    signup_notifier: Option<Arc<dyn SignUpNotifier>>,
+    config: &UseCasesConfig,
+) -> Result<SignUpResponse, SignUpError> {
+    let password = Password::from(
+        password,
+        &config.password_policy,
+        &config.breached_passwords_filter,
+    )?;
+
+    // both outcomes do the same work up to the insert, so the response time does not reveal taken user names
+    let salt_b64 = random_service.get_random_salt_b64().await?;
+    let password_hash = PasswordHash::hash(
+        password,
+        &salt_b64,
+        &config.password_hashing_params,
+        &config.password_peppers,
+    )?;
+
+    let default_role = role_repository
+        .get_by_name(&RoleName::default_role())
+        .await?
+        .ok_or(SignUpError::DefaultRoleNotFound)?;
+
+    let user = User::new(NewUserSpecification {
+        user_name: user_name.clone(),
+        password_hash,
+        roles: vec![default_role],
+    });
+
+    // the unique key is the check, so concurrent signups of one user name get the same response too
+    let transactional_user_repository = user_repository.start_transaction().await?;
+    let notification = match transactional_user_repository
+        .save(SomeUser::from(user))
+        .await
+    {
+        Ok((transactional_user_repository, _)) => {
+            transactional_user_repository.commit().await?;
+            SignUpNotification::UserCreated { user_name }
+        }
+        Err(UserRepositoryError::UserNameIsTaken) => {
+            SignUpNotification::UserAlreadyExists { user_name }
+        }
+        Err(err) => return Err(SignUpError::from(err)),
+    };
+
+    match signup_notifier {
+        // delivered in the background, awaiting it would make the response time differ by the outcome
+        Some(signup_notifier) => {
+            spawn(async move {
+                if let Err(err) = signup_notifier.notify(notification).await {
+                    warn!("signup notification delivery failed: {err}");
+                }
+            });
+        }
+        None => warn!("signup notifier is not configured, signup outcome is not delivered"),
+    }
+
+    Ok(SignUpResponse::Accepted)
 }
@@ -3,19 +3,24 @@ use nimbus_auth_domain::{
         password::errors::PasswordError, password_hash::errors::PasswordHashError,
         user_name::errors::UserNameError,
     },
-    value_objects::access_token::errors::SignAccessTokenError,
+    value_objects::{
+        access_token::errors::SignAccessTokenError, audiences::errors::AudiencesError,
+    },
 };
 use nimbus_auth_shared::errors::ErrorBoxed;
 use thiserror::Error;
 
 use crate::services::{
     keypair_repository::errors::KeyPairRepositoryError, random_service::errors::RandomServiceError,
+    role_repository::errors::RoleRepositoryError,
     session_repository::errors::SessionRepositoryError, time_service::errors::TimeServiceError,
     user_repository::errors::UserRepositoryError,
 };
 
 #[derive(Debug, Error)]
 pub enum SignUpError {
+    #[error(transparent)]
+    InvalidAudiences(#[from] AudiencesError),
     #[error(transparent)]
     InvalidUserName(#[from] UserNameError),
     #[error(transparent)]
@@ -37,6 +42,10 @@ pub enum SignUpError {
     #[error("active key pair not found")]
     ActiveKeyPairNotFound,
     #[error(transparent)]
+    RoleRepository(#[from] RoleRepositoryError),
+    #[error("default role not found")]
+    DefaultRoleNotFound,
+    #[error(transparent)]
     SignAccessToken(#[from] SignAccessTokenError),
     #[error(transparent)]
     Other(#[from] ErrorBoxed),
@@ -8,9 +8,17 @@ use crate::use_cases::{
 pub struct SignUpRequest<'a> {
     pub user_name: &'a str,
     pub password: &'a Zeroizing<String>,
+    /// Audiences the access token should target, limited to the realm allowlist
+    pub audiences: &'a [String],
 }
 
-pub struct SignUpResponse {
+pub enum SignUpResponse {
+    SignedUp(Box<SignedUpResponse>),
+    /// Returned whether the user was created or not when user enumeration protection is enabled
+    Accepted,
+}
+
+pub struct SignedUpResponse {
     pub user: UserClaimsDto,
     pub session: SessionDto,
     pub access_token: AccessTokenDto,
@@ -0,0 +1,83 @@
+use nimbus_auth_domain::entities::{
+    Entity,
+    user::{SomeUser, value_objects::user_name::UserName},
+};
+use nimbus_auth_shared::constants::PERMISSION_MANAGE_USERS;
+use time::OffsetDateTime;
+
+use crate::use_cases::{
+    SuspendUserError, SuspendUserRequest, SuspendUserResponse, UseCasesConfig, UseCasesServices,
+    guards::require_permission, revoke_user_sessions::revoke_active_sessions,
+};
+
+pub mod errors;
+pub mod schema;
+
+pub async fn handle_suspend_user<'a>(
+    SuspendUserRequest {
+        user,
+        user_name,
+        reason,
+        suspended_until_unix_timestamp,
+    }: SuspendUserRequest<'a>,
+    UseCasesServices {
+        user_repository,
+        session_repository,
+        time_service,
+        ..
+    }: &UseCasesServices,
+    _: &UseCasesConfig,
+) -> Result<SuspendUserResponse, SuspendUserError> {
+    require_permission(&user, PERMISSION_MANAGE_USERS)?;
+
+    let user_name = UserName::from(user_name)?;
+    let suspended_until = suspended_until_unix_timestamp
+        .map(OffsetDateTime::from_unix_timestamp)
+        .transpose()?;
+
+    let target_user =
+        user_repository
+            .get_by_name(&user_name)
+            .await?
+            .ok_or(SuspendUserError::UserIsNotFound {
+                user_name: user_name.to_string(),
+            })?;
+
+    // suspending already suspended user replaces its reason and end of suspension
+    let suspended_user = match target_user {
+        SomeUser::Active(target_user) => target_user
+            .into_owned()
+            .suspend(reason.to_string(), suspended_until),
+        SomeUser::Suspended(target_user) => target_user
+            .into_owned()
+            .unsuspend()
+            .suspend(reason.to_string(), suspended_until),
+        SomeUser::Deleted(_) => {
+            return Err(SuspendUserError::UserIsDeleted {
+                user_name: user_name.to_string(),
+            });
+        }
+    };
+
+    let transactional_user_repository = user_repository.start_transaction().await?;
+
+    let (transactional_user_repository, _) = transactional_user_repository
+        .save(SomeUser::from(&suspended_user))
+        .await?;
+
+    let transactional_session_repository = session_repository.start_transaction().await?;
+
+    let (transactional_session_repository, revoked_sessions_count) = revoke_active_sessions(
+        transactional_session_repository,
+        suspended_user.id(),
+        time_service.get_current_time().await?,
+    )
+    .await?;
+
+    transactional_session_repository.commit().await?;
+    transactional_user_repository.commit().await?;
+
+    Ok(SuspendUserResponse {
+        revoked_sessions_count,
+    })
+}
@@ -0,0 +1,13 @@
+use crate::use_cases::UserClaimsDto;
+
+pub struct SuspendUserRequest<'a> {
+    pub user: UserClaimsDto,
+    pub user_name: &'a str,
+    pub reason: &'a str,
+    /// Suspension is indefinite if it is not set
+    pub suspended_until_unix_timestamp: Option<i64>,
+}
+
+pub struct SuspendUserResponse {
+    pub revoked_sessions_count: usize,
+}
@@ -0,0 +1,40 @@
+use nimbus_auth_domain::value_objects::identifier::Identifier;
+use ulid::Ulid;
+
+use crate::use_cases::{
+    UnlinkExternalIdentityError, UnlinkExternalIdentityRequest, UnlinkExternalIdentityResponse,
+    UseCasesConfig, UseCasesServices, guards::require_no_actor,
+};
+
+pub mod errors;
+pub mod schema;
+
+/// Unlinks the identity of the user at the provider, the provider does not have to be configured anymore
+///
+/// Identity the user was created with can not be unlinked
+pub async fn handle_unlink_external_identity<'a>(
+    UnlinkExternalIdentityRequest { user, provider }: UnlinkExternalIdentityRequest<'a>,
+    UseCasesServices {
+        external_identity_repository,
+        ..
+    }: &UseCasesServices,
+    _: &UseCasesConfig,
+) -> Result<UnlinkExternalIdentityResponse, UnlinkExternalIdentityError> {
+    require_no_actor(&user)?;
+
+    let user_id = Identifier::from(Ulid::from_string(&user.id)?);
+
+    let identity = external_identity_repository
+        .get_by_user_id(&user_id)
+        .await?
+        .into_iter()
+        .find(|identity| identity.provider() == provider)
+        .ok_or(UnlinkExternalIdentityError::IdentityIsNotFound {
+            provider: provider.to_string(),
+        })?;
+    identity.ensure_unlinkable()?;
+
+    external_identity_repository.delete(&identity).await?;
+
+    Ok(UnlinkExternalIdentityResponse {})
+}
@@ -0,0 +1,22 @@
+use nimbus_auth_domain::entities::external_identity::errors::ExternalIdentityError;
+use thiserror::Error;
+use ulid::DecodeError;
+
+use crate::{
+    services::external_identity_repository::errors::ExternalIdentityRepositoryError,
+    use_cases::ActorDeniedError,
+};
+
+#[derive(Debug, Error)]
+pub enum UnlinkExternalIdentityError {
+    #[error(transparent)]
+    Forbidden(#[from] ActorDeniedError),
+    #[error("invalid user id. Error: {0}")]
+    InvalidUserId(#[from] DecodeError),
+    #[error("user has no identity linked at provider: {provider}")]
+    IdentityIsNotFound { provider: String },
+    #[error(transparent)]
+    ExternalIdentity(#[from] ExternalIdentityError),
+    #[error(transparent)]
+    ExternalIdentityRepository(#[from] ExternalIdentityRepositoryError),
+}
@@ -0,0 +1,8 @@
+use crate::use_cases::UserClaimsDto;
+
+pub struct UnlinkExternalIdentityRequest<'a> {
+    pub user: UserClaimsDto,
+    pub provider: &'a str,
+}
+
+pub struct UnlinkExternalIdentityResponse {}
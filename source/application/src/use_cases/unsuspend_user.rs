@@ -0,0 +1,40 @@
+use nimbus_auth_domain::entities::user::{SomeUser, value_objects::user_name::UserName};
+use nimbus_auth_shared::constants::PERMISSION_MANAGE_USERS;
+
+use crate::use_cases::{
+    UnsuspendUserError, UnsuspendUserRequest, UnsuspendUserResponse, UseCasesConfig,
+    UseCasesServices, guards::require_permission,
+};
+
+pub mod errors;
+pub mod schema;
+
+pub async fn handle_unsuspend_user<'a>(
+    UnsuspendUserRequest { user, user_name }: UnsuspendUserRequest<'a>,
+    UseCasesServices {
+        user_repository, ..
+    }: &UseCasesServices,
+    _: &UseCasesConfig,
+) -> Result<UnsuspendUserResponse, UnsuspendUserError> {
+    require_permission(&user, PERMISSION_MANAGE_USERS)?;
+
+    let user_name = UserName::from(user_name)?;
+
+    let target_user = user_repository.get_by_name(&user_name).await?.ok_or(
+        UnsuspendUserError::UserIsNotFound {
+            user_name: user_name.to_string(),
+        },
+    )?;
+
+    let SomeUser::Suspended(target_user) = target_user else {
+        return Err(UnsuspendUserError::UserIsNotSuspended {
+            user_name: user_name.to_string(),
+        });
+    };
+
+    user_repository
+        .save(SomeUser::from(target_user.into_owned().unsuspend()))
+        .await?;
+
+    Ok(UnsuspendUserResponse {})
+}
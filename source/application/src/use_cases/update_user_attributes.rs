@@ -0,0 +1,50 @@
+use nimbus_auth_domain::entities::user::{
+    SomeUser,
+    value_objects::{user_attributes::UserAttributes, user_name::UserName},
+};
+use nimbus_auth_shared::constants::PERMISSION_MANAGE_USERS;
+
+use crate::use_cases::{
+    UpdateUserAttributesError, UpdateUserAttributesRequest, UpdateUserAttributesResponse,
+    UseCasesConfig, UseCasesServices, UserDetailsDto, guards::require_permission,
+};
+
+pub mod errors;
+pub mod schema;
+
+/// Attributes are replaced as a whole, new values get into access tokens issued after the change
+pub async fn handle_update_user_attributes<'a>(
+    UpdateUserAttributesRequest {
+        user,
+        user_name,
+        attributes_json,
+    }: UpdateUserAttributesRequest<'a>,
+    UseCasesServices {
+        user_repository, ..
+    }: &UseCasesServices,
+    _: &UseCasesConfig,
+) -> Result<UpdateUserAttributesResponse, UpdateUserAttributesError> {
+    require_permission(&user, PERMISSION_MANAGE_USERS)?;
+
+    let user_name = UserName::from(user_name)?;
+    let attributes = UserAttributes::from_json(attributes_json)?;
+
+    let target_user = user_repository.get_by_name(&user_name).await?.ok_or(
+        UpdateUserAttributesError::UserIsNotFound {
+            user_name: user_name.to_string(),
+        },
+    )?;
+
+    if let SomeUser::Deleted(_) = target_user {
+        return Err(UpdateUserAttributesError::UserIsDeleted {
+            user_name: user_name.to_string(),
+        });
+    }
+
+    let target_user = target_user.with_attributes(attributes);
+    user_repository.save(target_user.clone()).await?;
+
+    Ok(UpdateUserAttributesResponse {
+        user: UserDetailsDto::from(&target_user),
+    })
+}
@@ -0,0 +1,12 @@
+use crate::use_cases::{UserClaimsDto, UserDetailsDto};
+
+pub struct UpdateUserAttributesRequest<'a> {
+    pub user: UserClaimsDto,
+    pub user_name: &'a str,
+    /// JSON object with all attributes of the user
+    pub attributes_json: &'a str,
+}
+
+pub struct UpdateUserAttributesResponse {
+    pub user: UserDetailsDto,
+}
@@ -1,6 +1,15 @@
 use crate::value_objects::identifier::IdentifierOfType;
 
+pub mod api_key;
+pub mod authorization_code;
+pub mod device_authorization;
+pub mod external_identity;
+pub mod federated_authorization;
+pub mod group;
+pub mod impersonation;
 pub mod keypair;
+pub mod oauth_client;
+pub mod role;
 pub mod session;
 pub mod user;
 
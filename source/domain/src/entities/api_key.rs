@@ -0,0 +1,181 @@
+use std::collections::BTreeSet;
+
+use nimbus_auth_shared::constants::API_KEY_PREFIX;
+use time::{Duration, OffsetDateTime};
+use ulid::Ulid;
+use zeroize::Zeroizing;
+
+use crate::{
+    entities::{
+        Entity,
+        api_key::{
+            errors::ApiKeyError,
+            specifications::{NewApiKeySpecification, RestoreApiKeySpecification},
+            value_objects::api_key_name::ApiKeyName,
+        },
+        role::value_objects::permission::Permission,
+        user::User,
+    },
+    value_objects::{
+        identifier::{Identifier, IdentifierOfType},
+        secret_hash::SecretHash,
+        user_claims::UserClaims,
+    },
+};
+
+pub mod errors;
+pub mod specifications;
+#[cfg(test)]
+mod tests;
+pub mod value_objects;
+
+/// Long-lived credential of a user for scripts and CI, it grants only its scopes of the user's permissions
+///
+/// The key itself is `nak_<key id>_<secret>`, only the hash of the secret is stored
+#[derive(Debug, Clone)]
+pub struct ApiKey {
+    id: Identifier<Ulid, ApiKey>,
+    user_id: Identifier<Ulid, User>,
+    name: ApiKeyName,
+    secret_hash: SecretHash,
+    scopes: BTreeSet<Permission>,
+    created_at: OffsetDateTime,
+    expires_at: Option<OffsetDateTime>,
+    revoked_at: Option<OffsetDateTime>,
+}
+
+impl Entity<Ulid> for ApiKey {
+    type Id = Identifier<Ulid, ApiKey>;
+
+    fn id(&self) -> &Self::Id {
+        &self.id
+    }
+}
+
+impl ApiKey {
+    pub fn new(
+        NewApiKeySpecification {
+            user_id,
+            name,
+            secret_hash,
+            scopes,
+            current_time,
+            expiration_seconds,
+        }: NewApiKeySpecification,
+    ) -> Self {
+        Self {
+            id: Identifier::new(),
+            user_id,
+            name,
+            secret_hash,
+            scopes,
+            created_at: current_time,
+            expires_at: expiration_seconds.map(|expiration_seconds| {
+                current_time + Duration::seconds(expiration_seconds as i64)
+            }),
+            revoked_at: None,
+        }
+    }
+
+    pub fn restore(
+        RestoreApiKeySpecification {
+            id,
+            user_id,
+            name,
+            secret_hash,
+            scopes,
+            created_at,
+            expires_at,
+            revoked_at,
+        }: RestoreApiKeySpecification,
+    ) -> Self {
+        Self {
+            id,
+            user_id,
+            name,
+            secret_hash,
+            scopes,
+            created_at,
+            expires_at,
+            revoked_at,
+        }
+    }
+
+    /// Formats the key shown to its owner once
+    pub fn format_token(&self, secret: &str) -> Zeroizing<String> {
+        Zeroizing::new(format!("{API_KEY_PREFIX}{}_{secret}", self.id))
+    }
+
+    /// Splits the key into the id of the key and its secret, the secret itself may contain `_`
+    pub fn parse_token(token: &str) -> Result<(Identifier<Ulid, ApiKey>, &str), ApiKeyError> {
+        let (id, secret) = token
+            .strip_prefix(API_KEY_PREFIX)
+            .and_then(|token| token.split_once('_'))
+            .ok_or(ApiKeyError::Malformed)?;
+        let id = Ulid::from_string(id).map_err(|_| ApiKeyError::Malformed)?;
+        Ok((Identifier::from(id), secret))
+    }
+
+    pub fn authenticate(
+        &self,
+        secret: &str,
+        current_time: OffsetDateTime,
+    ) -> Result<(), ApiKeyError> {
+        if !self.secret_hash.verify(secret) {
+            return Err(ApiKeyError::SecretMismatch);
+        }
+        if self.revoked_at.is_some() {
+            return Err(ApiKeyError::Revoked);
+        }
+        if self.is_expired(current_time) {
+            return Err(ApiKeyError::Expired);
+        }
+        Ok(())
+    }
+
+    /// Limits the claims of the key's owner to the scopes of the key
+    pub fn restrict_claims(&self, user_claims: UserClaims) -> UserClaims {
+        user_claims.with_permissions_limit(&self.scopes)
+    }
+
+    /// Revoking an already revoked key keeps the time it was revoked first
+    pub fn revoke(self, current_time: OffsetDateTime) -> Self {
+        Self {
+            revoked_at: self.revoked_at.or(Some(current_time)),
+            ..self
+        }
+    }
+
+    pub fn is_expired(&self, current_time: OffsetDateTime) -> bool {
+        self.expires_at
+            .is_some_and(|expires_at| expires_at <= current_time)
+    }
+
+    pub fn user_id(&self) -> &Identifier<Ulid, User> {
+        &self.user_id
+    }
+
+    pub fn name(&self) -> &ApiKeyName {
+        &self.name
+    }
+
+    pub fn secret_hash(&self) -> &SecretHash {
+        &self.secret_hash
+    }
+
+    pub fn scopes(&self) -> &BTreeSet<Permission> {
+        &self.scopes
+    }
+
+    pub fn created_at(&self) -> &OffsetDateTime {
+        &self.created_at
+    }
+
+    pub fn expires_at(&self) -> Option<&OffsetDateTime> {
+        self.expires_at.as_ref()
+    }
+
+    pub fn revoked_at(&self) -> Option<&OffsetDateTime> {
+        self.revoked_at.as_ref()
+    }
+}
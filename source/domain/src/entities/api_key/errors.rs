@@ -0,0 +1,13 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ApiKeyError {
+    #[error("api key is malformed")]
+    Malformed,
+    #[error("api key secret does not match")]
+    SecretMismatch,
+    #[error("api key is expired")]
+    Expired,
+    #[error("api key is revoked")]
+    Revoked,
+}
@@ -0,0 +1,34 @@
+use std::collections::BTreeSet;
+
+use time::OffsetDateTime;
+use ulid::Ulid;
+
+use crate::{
+    entities::{
+        api_key::{ApiKey, value_objects::api_key_name::ApiKeyName},
+        role::value_objects::permission::Permission,
+        user::User,
+    },
+    value_objects::{identifier::Identifier, secret_hash::SecretHash},
+};
+
+pub struct NewApiKeySpecification {
+    pub user_id: Identifier<Ulid, User>,
+    pub name: ApiKeyName,
+    pub secret_hash: SecretHash,
+    pub scopes: BTreeSet<Permission>,
+    pub current_time: OffsetDateTime,
+    /// Key without expiration is valid until it is revoked
+    pub expiration_seconds: Option<u64>,
+}
+
+pub struct RestoreApiKeySpecification {
+    pub id: Identifier<Ulid, ApiKey>,
+    pub user_id: Identifier<Ulid, User>,
+    pub name: ApiKeyName,
+    pub secret_hash: SecretHash,
+    pub scopes: BTreeSet<Permission>,
+    pub created_at: OffsetDateTime,
+    pub expires_at: Option<OffsetDateTime>,
+    pub revoked_at: Option<OffsetDateTime>,
+}
@@ -0,0 +1,102 @@
+use std::collections::BTreeSet;
+
+use time::{Duration, OffsetDateTime};
+
+use crate::{
+    entities::{
+        Entity,
+        api_key::{
+            ApiKey, errors::ApiKeyError, specifications::NewApiKeySpecification,
+            value_objects::api_key_name::ApiKeyName,
+        },
+        role::value_objects::{permission::Permission, role_name::RoleName},
+        user::value_objects::user_name::UserName,
+    },
+    value_objects::{
+        identifier::{Identifier, IdentifierOfType},
+        secret_hash::SecretHash,
+        user_claims::UserClaims,
+    },
+};
+
+const API_KEY_NAME: &str = "ci-deploy";
+const SECRET: &str = "yD8lWq3h0C0vJf6Qb2sZpVtE9kN4mA7x_1uGcHiLoPw";
+const SCOPE: &str = "auth:users:read";
+const OTHER_PERMISSION: &str = "auth:users:manage";
+
+fn get_api_key(current_time: OffsetDateTime, expiration_seconds: Option<u64>) -> ApiKey {
+    ApiKey::new(NewApiKeySpecification {
+        user_id: Identifier::new(),
+        name: ApiKeyName::from(API_KEY_NAME).unwrap(),
+        secret_hash: SecretHash::hash(SECRET),
+        scopes: BTreeSet::from([Permission::from(SCOPE).unwrap()]),
+        current_time,
+        expiration_seconds,
+    })
+}
+
+#[test]
+fn token_is_parsed_back() {
+    let api_key = get_api_key(OffsetDateTime::now_utc(), None);
+    let token = api_key.format_token(SECRET);
+
+    let (id, secret) = ApiKey::parse_token(&token).unwrap();
+
+    assert_eq!(&id, api_key.id());
+    assert_eq!(secret, SECRET);
+}
+
+#[test]
+fn malformed_token() {
+    let result = ApiKey::parse_token("nak_not-an-id");
+    assert!(matches!(result, Err(ApiKeyError::Malformed)))
+}
+
+#[test]
+fn matching_secret() {
+    let current_time = OffsetDateTime::now_utc();
+    let api_key = get_api_key(current_time, Some(60));
+    assert!(api_key.authenticate(SECRET, current_time).is_ok())
+}
+
+#[test]
+fn other_secret() {
+    let current_time = OffsetDateTime::now_utc();
+    let api_key = get_api_key(current_time, None);
+    let result = api_key.authenticate("other", current_time);
+    assert!(matches!(result, Err(ApiKeyError::SecretMismatch)))
+}
+
+#[test]
+fn expired_key() {
+    let current_time = OffsetDateTime::now_utc();
+    let api_key = get_api_key(current_time, Some(60));
+    let result = api_key.authenticate(SECRET, current_time + Duration::seconds(61));
+    assert!(matches!(result, Err(ApiKeyError::Expired)))
+}
+
+#[test]
+fn revoked_key() {
+    let current_time = OffsetDateTime::now_utc();
+    let api_key = get_api_key(current_time, None).revoke(current_time);
+    let result = api_key.authenticate(SECRET, current_time);
+    assert!(matches!(result, Err(ApiKeyError::Revoked)))
+}
+
+#[test]
+fn claims_are_restricted_to_scopes() {
+    let api_key = get_api_key(OffsetDateTime::now_utc(), None);
+    let user_claims = UserClaims::new(
+        api_key.user_id().clone(),
+        UserName::from("developer").unwrap(),
+        BTreeSet::from([RoleName::from("admin").unwrap()]),
+        BTreeSet::from([
+            Permission::from(SCOPE).unwrap(),
+            Permission::from(OTHER_PERMISSION).unwrap(),
+        ]),
+    );
+
+    let user_claims = api_key.restrict_claims(user_claims);
+
+    assert_eq!(user_claims.permissions(), api_key.scopes());
+}
@@ -0,0 +1,49 @@
+use std::fmt::Display;
+
+use nimbus_auth_shared::constants::API_KEY_NAME_MAX_LENGTH_INCLUSIVE;
+
+use crate::entities::api_key::value_objects::api_key_name::errors::ApiKeyNameError;
+
+pub mod errors;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ApiKeyName {
+    value: String,
+}
+
+impl ApiKeyName {
+    pub fn from(value: &str) -> Result<Self, ApiKeyNameError> {
+        Self::validate(value)?;
+        Ok(Self {
+            value: value.to_string(),
+        })
+    }
+
+    fn validate(value: &str) -> Result<(), ApiKeyNameError> {
+        if value.is_empty() {
+            return Err(ApiKeyNameError::Empty);
+        }
+        if value.len() > API_KEY_NAME_MAX_LENGTH_INCLUSIVE {
+            return Err(ApiKeyNameError::TooLong {
+                max_length: API_KEY_NAME_MAX_LENGTH_INCLUSIVE,
+            });
+        }
+        match value
+            .chars()
+            .all(|ch| ch.is_ascii_lowercase() || ch.is_ascii_digit() || ch == '_' || ch == '-')
+        {
+            true => Ok(()),
+            false => Err(ApiKeyNameError::InvalidCharacters),
+        }
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl Display for ApiKeyName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
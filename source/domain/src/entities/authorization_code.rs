@@ -0,0 +1,130 @@
+use time::OffsetDateTime;
+use ulid::Ulid;
+
+use crate::{
+    entities::{
+        Entity,
+        authorization_code::{
+            errors::AuthorizationCodeError,
+            specifications::{
+                NewAuthorizationCodeSpecification, RedeemAuthorizationCodeSpecification,
+                RestoreAuthorizationCodeSpecification,
+            },
+            value_objects::code_challenge::CodeChallenge,
+        },
+        oauth_client::{OAuthClient, value_objects::redirect_uri::RedirectUri},
+        user::User,
+    },
+    value_objects::identifier::{Identifier, IdentifierOfType},
+};
+
+pub mod errors;
+pub mod specifications;
+#[cfg(test)]
+mod tests;
+pub mod value_objects;
+
+/// Single use grant a client exchanges for tokens of the user who approved it
+#[derive(Debug, Clone)]
+pub struct AuthorizationCode {
+    id: Identifier<Ulid, AuthorizationCode>,
+    client_id: Identifier<Ulid, OAuthClient>,
+    user_id: Identifier<Ulid, User>,
+    redirect_uri: RedirectUri,
+    code_challenge: CodeChallenge,
+    expires_at: OffsetDateTime,
+}
+
+impl Entity<Ulid> for AuthorizationCode {
+    type Id = Identifier<Ulid, AuthorizationCode>;
+
+    fn id(&self) -> &Self::Id {
+        &self.id
+    }
+}
+
+impl AuthorizationCode {
+    pub fn new(
+        NewAuthorizationCodeSpecification {
+            client_id,
+            user_id,
+            redirect_uri,
+            code_challenge,
+            current_time,
+            expiration_seconds,
+        }: NewAuthorizationCodeSpecification,
+    ) -> Self {
+        Self {
+            id: Identifier::new(),
+            client_id,
+            user_id,
+            redirect_uri,
+            code_challenge,
+            expires_at: current_time + time::Duration::seconds(expiration_seconds as i64),
+        }
+    }
+
+    pub fn restore(
+        RestoreAuthorizationCodeSpecification {
+            id,
+            client_id,
+            user_id,
+            redirect_uri,
+            code_challenge,
+            expires_at,
+        }: RestoreAuthorizationCodeSpecification,
+    ) -> Self {
+        Self {
+            id,
+            client_id,
+            user_id,
+            redirect_uri,
+            code_challenge,
+            expires_at,
+        }
+    }
+
+    /// Code is valid only for the client and redirect uri it was issued to,
+    /// and only for the holder of the verifier its challenge was derived from
+    pub fn redeem(
+        self,
+        RedeemAuthorizationCodeSpecification {
+            client_id,
+            redirect_uri,
+            code_verifier,
+            current_time,
+        }: RedeemAuthorizationCodeSpecification,
+    ) -> Result<Identifier<Ulid, User>, AuthorizationCodeError> {
+        if (self.expires_at - current_time).whole_seconds() <= 0 {
+            return Err(AuthorizationCodeError::Expired);
+        }
+        if &self.client_id != client_id {
+            return Err(AuthorizationCodeError::ClientMismatch);
+        }
+        if &self.redirect_uri != redirect_uri {
+            return Err(AuthorizationCodeError::RedirectUriMismatch);
+        }
+        self.code_challenge.verify(code_verifier)?;
+        Ok(self.user_id)
+    }
+
+    pub fn client_id(&self) -> &Identifier<Ulid, OAuthClient> {
+        &self.client_id
+    }
+
+    pub fn user_id(&self) -> &Identifier<Ulid, User> {
+        &self.user_id
+    }
+
+    pub fn redirect_uri(&self) -> &RedirectUri {
+        &self.redirect_uri
+    }
+
+    pub fn code_challenge(&self) -> &CodeChallenge {
+        &self.code_challenge
+    }
+
+    pub fn expires_at(&self) -> OffsetDateTime {
+        self.expires_at
+    }
+}
@@ -0,0 +1,15 @@
+use thiserror::Error;
+
+use crate::entities::authorization_code::value_objects::code_challenge::errors::CodeChallengeError;
+
+#[derive(Debug, Error)]
+pub enum AuthorizationCodeError {
+    #[error("authorization code is expired")]
+    Expired,
+    #[error("authorization code was issued to another client")]
+    ClientMismatch,
+    #[error("redirect uri does not match the one of the authorization request")]
+    RedirectUriMismatch,
+    #[error(transparent)]
+    CodeVerifier(#[from] CodeChallengeError),
+}
@@ -0,0 +1,36 @@
+use time::OffsetDateTime;
+use ulid::Ulid;
+
+use crate::{
+    entities::{
+        authorization_code::{AuthorizationCode, value_objects::code_challenge::CodeChallenge},
+        oauth_client::{OAuthClient, value_objects::redirect_uri::RedirectUri},
+        user::User,
+    },
+    value_objects::identifier::Identifier,
+};
+
+pub struct NewAuthorizationCodeSpecification {
+    pub client_id: Identifier<Ulid, OAuthClient>,
+    pub user_id: Identifier<Ulid, User>,
+    pub redirect_uri: RedirectUri,
+    pub code_challenge: CodeChallenge,
+    pub current_time: OffsetDateTime,
+    pub expiration_seconds: usize,
+}
+
+pub struct RestoreAuthorizationCodeSpecification {
+    pub id: Identifier<Ulid, AuthorizationCode>,
+    pub client_id: Identifier<Ulid, OAuthClient>,
+    pub user_id: Identifier<Ulid, User>,
+    pub redirect_uri: RedirectUri,
+    pub code_challenge: CodeChallenge,
+    pub expires_at: OffsetDateTime,
+}
+
+pub struct RedeemAuthorizationCodeSpecification<'a> {
+    pub client_id: &'a Identifier<Ulid, OAuthClient>,
+    pub redirect_uri: &'a RedirectUri,
+    pub code_verifier: &'a str,
+    pub current_time: OffsetDateTime,
+}
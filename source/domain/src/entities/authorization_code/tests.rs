@@ -0,0 +1,106 @@
+use nimbus_auth_shared::constants::{
+    OAUTH_AUTHORIZATION_CODE_EXPIRATION_SECONDS, OAUTH_CODE_CHALLENGE_METHOD_S256,
+};
+use time::OffsetDateTime;
+
+use crate::{
+    entities::{
+        authorization_code::{
+            AuthorizationCode,
+            errors::AuthorizationCodeError,
+            specifications::{
+                NewAuthorizationCodeSpecification, RedeemAuthorizationCodeSpecification,
+            },
+            value_objects::code_challenge::CodeChallenge,
+        },
+        oauth_client::value_objects::redirect_uri::RedirectUri,
+    },
+    value_objects::identifier::{Identifier, IdentifierOfType},
+};
+
+const CODE_VERIFIER: &str = "dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk";
+const CODE_CHALLENGE: &str = "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM";
+const REDIRECT_URI: &str = "https://app.example.com/callback";
+
+fn get_code(current_time: OffsetDateTime) -> AuthorizationCode {
+    AuthorizationCode::new(NewAuthorizationCodeSpecification {
+        client_id: Identifier::new(),
+        user_id: Identifier::new(),
+        redirect_uri: RedirectUri::from(REDIRECT_URI).unwrap(),
+        code_challenge: CodeChallenge::from(CODE_CHALLENGE, OAUTH_CODE_CHALLENGE_METHOD_S256)
+            .unwrap(),
+        current_time,
+        expiration_seconds: OAUTH_AUTHORIZATION_CODE_EXPIRATION_SECONDS,
+    })
+}
+
+#[test]
+fn redeem_code() {
+    let current_time = OffsetDateTime::now_utc();
+    let code = get_code(current_time);
+    let client_id = code.client_id().clone();
+    let user_id = code.user_id().clone();
+
+    let result = code.redeem(RedeemAuthorizationCodeSpecification {
+        client_id: &client_id,
+        redirect_uri: &RedirectUri::from(REDIRECT_URI).unwrap(),
+        code_verifier: CODE_VERIFIER,
+        current_time,
+    });
+
+    assert_eq!(result.unwrap(), user_id)
+}
+
+#[test]
+fn redeem_code_of_other_client() {
+    let current_time = OffsetDateTime::now_utc();
+    let code = get_code(current_time);
+
+    let result = code.redeem(RedeemAuthorizationCodeSpecification {
+        client_id: &Identifier::new(),
+        redirect_uri: &RedirectUri::from(REDIRECT_URI).unwrap(),
+        code_verifier: CODE_VERIFIER,
+        current_time,
+    });
+
+    assert!(matches!(
+        result,
+        Err(AuthorizationCodeError::ClientMismatch)
+    ))
+}
+
+#[test]
+fn redeem_code_with_other_redirect_uri() {
+    let current_time = OffsetDateTime::now_utc();
+    let code = get_code(current_time);
+    let client_id = code.client_id().clone();
+
+    let result = code.redeem(RedeemAuthorizationCodeSpecification {
+        client_id: &client_id,
+        redirect_uri: &RedirectUri::from("https://app.example.com/other").unwrap(),
+        code_verifier: CODE_VERIFIER,
+        current_time,
+    });
+
+    assert!(matches!(
+        result,
+        Err(AuthorizationCodeError::RedirectUriMismatch)
+    ))
+}
+
+#[test]
+fn redeem_expired_code() {
+    let current_time = OffsetDateTime::now_utc();
+    let code = get_code(current_time);
+    let client_id = code.client_id().clone();
+
+    let result = code.redeem(RedeemAuthorizationCodeSpecification {
+        client_id: &client_id,
+        redirect_uri: &RedirectUri::from(REDIRECT_URI).unwrap(),
+        code_verifier: CODE_VERIFIER,
+        current_time: current_time
+            + time::Duration::seconds(OAUTH_AUTHORIZATION_CODE_EXPIRATION_SECONDS as i64),
+    });
+
+    assert!(matches!(result, Err(AuthorizationCodeError::Expired)))
+}
@@ -0,0 +1,67 @@
+use base64::{Engine, prelude::BASE64_URL_SAFE_NO_PAD};
+use nimbus_auth_shared::constants::OAUTH_CODE_CHALLENGE_METHOD_S256;
+use sha2::{Digest, Sha256};
+
+use crate::entities::authorization_code::value_objects::code_challenge::errors::CodeChallengeError;
+
+pub mod errors;
+#[cfg(test)]
+mod tests;
+
+const CODE_VERIFIER_MIN_LENGTH_INCLUSIVE: usize = 43;
+const CODE_VERIFIER_MAX_LENGTH_INCLUSIVE: usize = 128;
+const SHA256_DIGEST_LENGTH: usize = 32;
+
+/// PKCE challenge of the authorization request, BASE64URL(SHA256(code verifier))
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CodeChallenge {
+    value: String,
+}
+
+impl CodeChallenge {
+    pub fn from(value: &str, method: &str) -> Result<Self, CodeChallengeError> {
+        if method != OAUTH_CODE_CHALLENGE_METHOD_S256 {
+            return Err(CodeChallengeError::UnsupportedMethod {
+                method: method.to_string(),
+            });
+        }
+        match BASE64_URL_SAFE_NO_PAD.decode(value) {
+            Ok(digest) if digest.len() == SHA256_DIGEST_LENGTH => Ok(Self {
+                value: value.to_string(),
+            }),
+            _ => Err(CodeChallengeError::InvalidChallenge),
+        }
+    }
+
+    /// Challenge of a verifier generated by this service, e.g. for its requests to identity providers
+    pub fn derive(code_verifier: &str) -> Self {
+        Self {
+            value: BASE64_URL_SAFE_NO_PAD.encode(Sha256::digest(code_verifier.as_bytes())),
+        }
+    }
+
+    pub fn verify(&self, code_verifier: &str) -> Result<(), CodeChallengeError> {
+        let is_valid_length = (CODE_VERIFIER_MIN_LENGTH_INCLUSIVE
+            ..=CODE_VERIFIER_MAX_LENGTH_INCLUSIVE)
+            .contains(&code_verifier.len());
+        let is_valid_charset = code_verifier
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '-' | '.' | '_' | '~'));
+        if !is_valid_length || !is_valid_charset {
+            return Err(CodeChallengeError::InvalidVerifier);
+        }
+
+        match Self::derive(code_verifier) == *self {
+            true => Ok(()),
+            false => Err(CodeChallengeError::VerifierMismatch),
+        }
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    pub fn method(&self) -> &'static str {
+        OAUTH_CODE_CHALLENGE_METHOD_S256
+    }
+}
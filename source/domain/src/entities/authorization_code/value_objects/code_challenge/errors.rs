@@ -0,0 +1,15 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum CodeChallengeError {
+    #[error("code challenge method {method} is not supported, only S256 is")]
+    UnsupportedMethod { method: String },
+    #[error("code challenge is not a base64url encoded sha256 digest")]
+    InvalidChallenge,
+    #[error(
+        "code verifier should be 43 to 128 characters long and contain only unreserved characters"
+    )]
+    InvalidVerifier,
+    #[error("code verifier does not match the code challenge")]
+    VerifierMismatch,
+}
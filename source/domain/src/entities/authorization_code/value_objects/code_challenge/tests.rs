@@ -0,0 +1,50 @@
+use nimbus_auth_shared::constants::OAUTH_CODE_CHALLENGE_METHOD_S256;
+
+use crate::entities::authorization_code::value_objects::code_challenge::{
+    CodeChallenge, errors::CodeChallengeError,
+};
+
+// example of RFC 7636, appendix B
+const CODE_VERIFIER: &str = "dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk";
+const CODE_CHALLENGE: &str = "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM";
+
+#[test]
+fn matching_code_verifier() {
+    let challenge = CodeChallenge::from(CODE_CHALLENGE, OAUTH_CODE_CHALLENGE_METHOD_S256).unwrap();
+    assert!(challenge.verify(CODE_VERIFIER).is_ok())
+}
+
+#[test]
+fn other_code_verifier() {
+    let challenge = CodeChallenge::from(CODE_CHALLENGE, OAUTH_CODE_CHALLENGE_METHOD_S256).unwrap();
+    let result = challenge.verify(&"a".repeat(43));
+    assert!(matches!(result, Err(CodeChallengeError::VerifierMismatch)))
+}
+
+#[test]
+fn short_code_verifier() {
+    let challenge = CodeChallenge::from(CODE_CHALLENGE, OAUTH_CODE_CHALLENGE_METHOD_S256).unwrap();
+    let result = challenge.verify("short");
+    assert!(matches!(result, Err(CodeChallengeError::InvalidVerifier)))
+}
+
+#[test]
+fn plain_code_challenge_method() {
+    let result = CodeChallenge::from(CODE_VERIFIER, "plain");
+    assert!(matches!(
+        result,
+        Err(CodeChallengeError::UnsupportedMethod { .. })
+    ))
+}
+
+#[test]
+fn code_challenge_of_wrong_length() {
+    let result = CodeChallenge::from("abc", OAUTH_CODE_CHALLENGE_METHOD_S256);
+    assert!(matches!(result, Err(CodeChallengeError::InvalidChallenge)))
+}
+
+#[test]
+fn derived_code_challenge() {
+    let challenge = CodeChallenge::derive(CODE_VERIFIER);
+    assert_eq!(challenge.value(), CODE_CHALLENGE)
+}
@@ -0,0 +1,180 @@
+use nimbus_auth_shared::constants::OAUTH_DEVICE_SLOW_DOWN_SECONDS;
+use time::{Duration, OffsetDateTime};
+use ulid::Ulid;
+
+use crate::{
+    entities::{
+        Entity,
+        device_authorization::{
+            errors::DeviceAuthorizationError,
+            specifications::{
+                NewDeviceAuthorizationSpecification, PollDeviceAuthorizationSpecification,
+                RestoreDeviceAuthorizationSpecification,
+            },
+            value_objects::user_code::UserCode,
+        },
+        oauth_client::OAuthClient,
+        user::User,
+    },
+    value_objects::identifier::{Identifier, IdentifierOfType},
+};
+
+pub mod errors;
+pub mod specifications;
+#[cfg(test)]
+mod tests;
+pub mod value_objects;
+
+/// Grant of a device which can not show a login form, a user approves it on another screen
+///
+/// The id is the device code the device polls the token endpoint with
+#[derive(Debug, Clone)]
+pub struct DeviceAuthorization {
+    id: Identifier<Ulid, DeviceAuthorization>,
+    client_id: Identifier<Ulid, OAuthClient>,
+    user_code: UserCode,
+    approved_by: Option<Identifier<Ulid, User>>,
+    expires_at: OffsetDateTime,
+    interval_seconds: usize,
+    last_polled_at: Option<OffsetDateTime>,
+}
+
+/// Outcome of a poll which did not fail
+pub enum DevicePoll {
+    /// User has not approved the device yet, the poll is recorded
+    Pending(DeviceAuthorization),
+    /// Device polls too often, its polling interval is increased
+    SlowDown(DeviceAuthorization),
+    Approved(Identifier<Ulid, User>),
+}
+
+impl Entity<Ulid> for DeviceAuthorization {
+    type Id = Identifier<Ulid, DeviceAuthorization>;
+
+    fn id(&self) -> &Self::Id {
+        &self.id
+    }
+}
+
+impl DeviceAuthorization {
+    pub fn new(
+        NewDeviceAuthorizationSpecification {
+            client_id,
+            user_code,
+            current_time,
+            expiration_seconds,
+            interval_seconds,
+        }: NewDeviceAuthorizationSpecification,
+    ) -> Self {
+        Self {
+            id: Identifier::new(),
+            client_id,
+            user_code,
+            approved_by: None,
+            expires_at: current_time + Duration::seconds(expiration_seconds as i64),
+            interval_seconds,
+            last_polled_at: None,
+        }
+    }
+
+    pub fn restore(
+        RestoreDeviceAuthorizationSpecification {
+            id,
+            client_id,
+            user_code,
+            approved_by,
+            expires_at,
+            interval_seconds,
+            last_polled_at,
+        }: RestoreDeviceAuthorizationSpecification,
+    ) -> Self {
+        Self {
+            id,
+            client_id,
+            user_code,
+            approved_by,
+            expires_at,
+            interval_seconds,
+            last_polled_at,
+        }
+    }
+
+    pub fn approve(
+        self,
+        user_id: Identifier<Ulid, User>,
+        current_time: OffsetDateTime,
+    ) -> Result<Self, DeviceAuthorizationError> {
+        if self.is_expired(current_time) {
+            return Err(DeviceAuthorizationError::Expired);
+        }
+        if self.approved_by.is_some() {
+            return Err(DeviceAuthorizationError::AlreadyApproved);
+        }
+        Ok(Self {
+            approved_by: Some(user_id),
+            ..self
+        })
+    }
+
+    /// Device code is valid only for the client it was issued to
+    pub fn poll(
+        self,
+        PollDeviceAuthorizationSpecification {
+            client_id,
+            current_time,
+        }: PollDeviceAuthorizationSpecification,
+    ) -> Result<DevicePoll, DeviceAuthorizationError> {
+        if &self.client_id != client_id {
+            return Err(DeviceAuthorizationError::ClientMismatch);
+        }
+        if self.is_expired(current_time) {
+            return Err(DeviceAuthorizationError::Expired);
+        }
+        if let Some(user_id) = self.approved_by {
+            return Ok(DevicePoll::Approved(user_id));
+        }
+
+        let polls_too_often = self.last_polled_at.is_some_and(|last_polled_at| {
+            current_time - last_polled_at < Duration::seconds(self.interval_seconds as i64)
+        });
+        match polls_too_often {
+            true => Ok(DevicePoll::SlowDown(Self {
+                interval_seconds: self.interval_seconds + OAUTH_DEVICE_SLOW_DOWN_SECONDS,
+                last_polled_at: Some(current_time),
+                ..self
+            })),
+            false => Ok(DevicePoll::Pending(Self {
+                last_polled_at: Some(current_time),
+                ..self
+            })),
+        }
+    }
+
+    pub fn is_expired(&self, current_time: OffsetDateTime) -> bool {
+        self.expires_at <= current_time
+    }
+
+    pub fn client_id(&self) -> &Identifier<Ulid, OAuthClient> {
+        &self.client_id
+    }
+
+    pub fn user_code(&self) -> &UserCode {
+        &self.user_code
+    }
+
+    pub fn approved_by(&self) -> Option<&Identifier<Ulid, User>> {
+        self.approved_by.as_ref()
+    }
+
+    pub fn expires_at(&self) -> OffsetDateTime {
+        self.expires_at
+    }
+
+    pub fn interval_seconds(&self) -> usize {
+        self.interval_seconds
+    }
+
+    pub fn last_polled_at(&self) -> Option<OffsetDateTime> {
+        self.last_polled_at
+    }
+}
@@ -0,0 +1,11 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum DeviceAuthorizationError {
+    #[error("device authorization is expired")]
+    Expired,
+    #[error("device authorization is already approved")]
+    AlreadyApproved,
+    #[error("device code was issued to another client")]
+    ClientMismatch,
+}
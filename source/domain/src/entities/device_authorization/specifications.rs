@@ -0,0 +1,34 @@
+use time::OffsetDateTime;
+use ulid::Ulid;
+
+use crate::{
+    entities::{
+        device_authorization::{DeviceAuthorization, value_objects::user_code::UserCode},
+        oauth_client::OAuthClient,
+        user::User,
+    },
+    value_objects::identifier::Identifier,
+};
+
+pub struct NewDeviceAuthorizationSpecification {
+    pub client_id: Identifier<Ulid, OAuthClient>,
+    pub user_code: UserCode,
+    pub current_time: OffsetDateTime,
+    pub expiration_seconds: usize,
+    pub interval_seconds: usize,
+}
+
+pub struct RestoreDeviceAuthorizationSpecification {
+    pub id: Identifier<Ulid, DeviceAuthorization>,
+    pub client_id: Identifier<Ulid, OAuthClient>,
+    pub user_code: UserCode,
+    pub approved_by: Option<Identifier<Ulid, User>>,
+    pub expires_at: OffsetDateTime,
+    pub interval_seconds: usize,
+    pub last_polled_at: Option<OffsetDateTime>,
+}
+
+pub struct PollDeviceAuthorizationSpecification<'a> {
+    pub client_id: &'a Identifier<Ulid, OAuthClient>,
+    pub current_time: OffsetDateTime,
+}
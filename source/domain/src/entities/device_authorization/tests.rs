@@ -0,0 +1,153 @@
+use time::{Duration, OffsetDateTime};
+
+use crate::{
+    entities::device_authorization::{
+        DeviceAuthorization, DevicePoll,
+        errors::DeviceAuthorizationError,
+        specifications::{
+            NewDeviceAuthorizationSpecification, PollDeviceAuthorizationSpecification,
+        },
+        value_objects::user_code::UserCode,
+    },
+    value_objects::identifier::{Identifier, IdentifierOfType},
+};
+
+const USER_CODE: &str = "BCDF-GHJK";
+const EXPIRATION_SECONDS: usize = 600;
+const INTERVAL_SECONDS: usize = 5;
+
+fn get_device_authorization(current_time: OffsetDateTime) -> DeviceAuthorization {
+    DeviceAuthorization::new(NewDeviceAuthorizationSpecification {
+        client_id: Identifier::new(),
+        user_code: UserCode::from(USER_CODE).unwrap(),
+        current_time,
+        expiration_seconds: EXPIRATION_SECONDS,
+        interval_seconds: INTERVAL_SECONDS,
+    })
+}
+
+#[test]
+fn pending_until_approved() {
+    let current_time = OffsetDateTime::now_utc();
+    let device_authorization = get_device_authorization(current_time);
+    let client_id = device_authorization.client_id().clone();
+
+    let result = device_authorization.poll(PollDeviceAuthorizationSpecification {
+        client_id: &client_id,
+        current_time,
+    });
+
+    assert!(matches!(result, Ok(DevicePoll::Pending(_))))
+}
+
+#[test]
+fn approved() {
+    let current_time = OffsetDateTime::now_utc();
+    let device_authorization = get_device_authorization(current_time);
+    let client_id = device_authorization.client_id().clone();
+    let user_id = Identifier::new();
+
+    let result = device_authorization
+        .approve(user_id.clone(), current_time)
+        .unwrap()
+        .poll(PollDeviceAuthorizationSpecification {
+            client_id: &client_id,
+            current_time,
+        });
+
+    assert!(matches!(result, Ok(DevicePoll::Approved(approved_by)) if approved_by == user_id))
+}
+
+#[test]
+fn polling_too_often_increases_interval() {
+    let current_time = OffsetDateTime::now_utc();
+    let device_authorization = get_device_authorization(current_time);
+    let client_id = device_authorization.client_id().clone();
+    let Ok(DevicePoll::Pending(device_authorization)) =
+        device_authorization.poll(PollDeviceAuthorizationSpecification {
+            client_id: &client_id,
+            current_time,
+        })
+    else {
+        panic!("expected first poll to be pending");
+    };
+
+    let result = device_authorization.poll(PollDeviceAuthorizationSpecification {
+        client_id: &client_id,
+        current_time: current_time + Duration::seconds(1),
+    });
+
+    assert!(matches!(
+        result,
+        Ok(DevicePoll::SlowDown(device_authorization))
+            if device_authorization.interval_seconds() > INTERVAL_SECONDS
+    ))
+}
+
+#[test]
+fn polling_after_interval_is_pending() {
+    let current_time = OffsetDateTime::now_utc();
+    let device_authorization = get_device_authorization(current_time);
+    let client_id = device_authorization.client_id().clone();
+    let Ok(DevicePoll::Pending(device_authorization)) =
+        device_authorization.poll(PollDeviceAuthorizationSpecification {
+            client_id: &client_id,
+            current_time,
+        })
+    else {
+        panic!("expected first poll to be pending");
+    };
+
+    let result = device_authorization.poll(PollDeviceAuthorizationSpecification {
+        client_id: &client_id,
+        current_time: current_time + Duration::seconds(INTERVAL_SECONDS as i64),
+    });
+
+    assert!(matches!(result, Ok(DevicePoll::Pending(_))))
+}
+
+#[test]
+fn expired() {
+    let current_time = OffsetDateTime::now_utc();
+    let device_authorization = get_device_authorization(current_time);
+    let client_id = device_authorization.client_id().clone();
+
+    let result = device_authorization.poll(PollDeviceAuthorizationSpecification {
+        client_id: &client_id,
+        current_time: current_time + Duration::seconds(EXPIRATION_SECONDS as i64),
+    });
+
+    assert!(matches!(result, Err(DeviceAuthorizationError::Expired)))
+}
+
+#[test]
+fn another_client() {
+    let current_time = OffsetDateTime::now_utc();
+    let device_authorization = get_device_authorization(current_time);
+
+    let result = device_authorization.poll(PollDeviceAuthorizationSpecification {
+        client_id: &Identifier::new(),
+        current_time,
+    });
+
+    assert!(matches!(
+        result,
+        Err(DeviceAuthorizationError::ClientMismatch)
+    ))
+}
+
+#[test]
+fn approving_twice() {
+    let current_time = OffsetDateTime::now_utc();
+    let device_authorization = get_device_authorization(current_time);
+
+    let result = device_authorization
+        .approve(Identifier::new(), current_time)
+        .unwrap()
+        .approve(Identifier::new(), current_time);
+
+    assert!(matches!(
+        result,
+        Err(DeviceAuthorizationError::AlreadyApproved)
+    ))
+}
@@ -0,0 +1,54 @@
+use std::fmt::Display;
+
+use nimbus_auth_shared::constants::{OAUTH_USER_CODE_ALPHABET, OAUTH_USER_CODE_LENGTH};
+
+use crate::entities::device_authorization::value_objects::user_code::errors::UserCodeError;
+
+pub mod errors;
+#[cfg(test)]
+mod tests;
+
+/// Code the user types to approve a device, it is shown as `XXXX-XXXX`
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct UserCode {
+    value: String,
+}
+
+impl UserCode {
+    /// Users may type the code in lowercase and with or without the separator
+    pub fn from(value: &str) -> Result<Self, UserCodeError> {
+        let value: String = value
+            .chars()
+            .filter(|ch| *ch != '-' && !ch.is_whitespace())
+            .map(|ch| ch.to_ascii_uppercase())
+            .collect();
+        Self::validate(&value)?;
+        Ok(Self { value })
+    }
+
+    fn validate(value: &str) -> Result<(), UserCodeError> {
+        if value.chars().count() != OAUTH_USER_CODE_LENGTH {
+            return Err(UserCodeError::WrongLength {
+                length: OAUTH_USER_CODE_LENGTH,
+            });
+        }
+        match value
+            .chars()
+            .all(|ch| OAUTH_USER_CODE_ALPHABET.contains(ch))
+        {
+            true => Ok(()),
+            false => Err(UserCodeError::InvalidCharacters),
+        }
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl Display for UserCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (first_half, second_half) = self.value.split_at(self.value.len() / 2);
+        write!(f, "{first_half}-{second_half}")
+    }
+}
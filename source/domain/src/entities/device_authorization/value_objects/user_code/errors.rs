@@ -0,0 +1,9 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum UserCodeError {
+    #[error("user code should be {length} characters long")]
+    WrongLength { length: usize },
+    #[error("user code contains invalid characters")]
+    InvalidCharacters,
+}
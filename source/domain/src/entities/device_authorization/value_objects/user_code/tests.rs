@@ -0,0 +1,23 @@
+use crate::entities::device_authorization::value_objects::user_code::{
+    UserCode, errors::UserCodeError,
+};
+
+#[test]
+fn typed_code_is_normalized() {
+    let user_code = UserCode::from("bcdf-ghjk").unwrap();
+
+    assert_eq!(user_code.value(), "BCDFGHJK");
+    assert_eq!(user_code.to_string(), "BCDF-GHJK");
+}
+
+#[test]
+fn wrong_length() {
+    let result = UserCode::from("BCDF-GHJ");
+    assert!(matches!(result, Err(UserCodeError::WrongLength { .. })))
+}
+
+#[test]
+fn ambiguous_characters() {
+    let result = UserCode::from("BCDF-GHJ0");
+    assert!(matches!(result, Err(UserCodeError::InvalidCharacters)))
+}
@@ -0,0 +1,121 @@
+use time::OffsetDateTime;
+use ulid::Ulid;
+
+use crate::{
+    entities::{
+        Entity,
+        external_identity::{
+            errors::ExternalIdentityError,
+            specifications::{
+                NewExternalIdentitySpecification, RestoreExternalIdentitySpecification,
+            },
+        },
+        user::User,
+    },
+    value_objects::identifier::{Identifier, IdentifierOfType},
+};
+
+pub mod errors;
+pub mod specifications;
+#[cfg(test)]
+mod tests;
+
+/// Account of a user at an upstream identity provider, the user can sign in with it
+#[derive(Debug, Clone)]
+pub struct ExternalIdentity {
+    id: Identifier<Ulid, ExternalIdentity>,
+    user_id: Identifier<Ulid, User>,
+    provider: String,
+    subject: String,
+    email: Option<String>,
+    provisioned: bool,
+    linked_at: OffsetDateTime,
+}
+
+impl Entity<Ulid> for ExternalIdentity {
+    type Id = Identifier<Ulid, ExternalIdentity>;
+
+    fn id(&self) -> &Self::Id {
+        &self.id
+    }
+}
+
+impl ExternalIdentity {
+    pub fn new(
+        NewExternalIdentitySpecification {
+            user_id,
+            provider,
+            subject,
+            email,
+            provisioned,
+            current_time,
+        }: NewExternalIdentitySpecification,
+    ) -> Self {
+        Self {
+            id: Identifier::new(),
+            user_id,
+            provider: provider.to_string(),
+            subject: subject.to_string(),
+            email: email.map(str::to_string),
+            provisioned,
+            linked_at: current_time,
+        }
+    }
+
+    pub fn restore(
+        RestoreExternalIdentitySpecification {
+            id,
+            user_id,
+            provider,
+            subject,
+            email,
+            provisioned,
+            linked_at,
+        }: RestoreExternalIdentitySpecification,
+    ) -> Self {
+        Self {
+            id,
+            user_id,
+            provider,
+            subject,
+            email,
+            provisioned,
+            linked_at,
+        }
+    }
+
+    /// Identity the user was created with can not be unlinked, nobody knows the password of such a user
+    pub fn ensure_unlinkable(&self) -> Result<(), ExternalIdentityError> {
+        match self.provisioned {
+            true => Err(ExternalIdentityError::IdentityIsProvisioned),
+            false => Ok(()),
+        }
+    }
+
+    pub fn user_id(&self) -> &Identifier<Ulid, User> {
+        &self.user_id
+    }
+
+    pub fn provider(&self) -> &str {
+        &self.provider
+    }
+
+    /// Identifier of the user at the provider
+    pub fn subject(&self) -> &str {
+        &self.subject
+    }
+
+    /// Email at the time of linking, it is informational and never used to match users
+    pub fn email(&self) -> Option<&str> {
+        self.email.as_deref()
+    }
+
+    /// The user was created on the first signin with this identity
+    pub fn provisioned(&self) -> bool {
+        self.provisioned
+    }
+
+    pub fn linked_at(&self) -> OffsetDateTime {
+        self.linked_at
+    }
+}
@@ -0,0 +1,7 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ExternalIdentityError {
+    #[error("user was created with this identity and has no other way to sign in")]
+    IdentityIsProvisioned,
+}
@@ -0,0 +1,26 @@
+use time::OffsetDateTime;
+use ulid::Ulid;
+
+use crate::{
+    entities::{external_identity::ExternalIdentity, user::User},
+    value_objects::identifier::Identifier,
+};
+
+pub struct NewExternalIdentitySpecification<'a> {
+    pub user_id: Identifier<Ulid, User>,
+    pub provider: &'a str,
+    pub subject: &'a str,
+    pub email: Option<&'a str>,
+    pub provisioned: bool,
+    pub current_time: OffsetDateTime,
+}
+
+pub struct RestoreExternalIdentitySpecification {
+    pub id: Identifier<Ulid, ExternalIdentity>,
+    pub user_id: Identifier<Ulid, User>,
+    pub provider: String,
+    pub subject: String,
+    pub email: Option<String>,
+    pub provisioned: bool,
+    pub linked_at: OffsetDateTime,
+}
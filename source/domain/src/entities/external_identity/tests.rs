@@ -0,0 +1,38 @@
+use time::OffsetDateTime;
+
+use crate::{
+    entities::external_identity::{
+        ExternalIdentity, errors::ExternalIdentityError,
+        specifications::NewExternalIdentitySpecification,
+    },
+    value_objects::identifier::{Identifier, IdentifierOfType},
+};
+
+const PROVIDER: &str = "acme";
+const SUBJECT: &str = "248289761001";
+
+fn get_identity(provisioned: bool) -> ExternalIdentity {
+    ExternalIdentity::new(NewExternalIdentitySpecification {
+        user_id: Identifier::new(),
+        provider: PROVIDER,
+        subject: SUBJECT,
+        email: Some("jane.doe@example.com"),
+        provisioned,
+        current_time: OffsetDateTime::now_utc(),
+    })
+}
+
+#[test]
+fn linked_identity_is_unlinkable() {
+    let identity = get_identity(false);
+    assert!(identity.ensure_unlinkable().is_ok())
+}
+
+#[test]
+fn provisioned_identity_is_not_unlinkable() {
+    let identity = get_identity(true);
+    assert!(matches!(
+        identity.ensure_unlinkable(),
+        Err(ExternalIdentityError::IdentityIsProvisioned)
+    ))
+}
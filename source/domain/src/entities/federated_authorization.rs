@@ -0,0 +1,133 @@
+use time::OffsetDateTime;
+use ulid::Ulid;
+
+use crate::{
+    entities::{
+        Entity,
+        authorization_code::value_objects::code_challenge::CodeChallenge,
+        federated_authorization::{
+            errors::FederatedAuthorizationError,
+            specifications::{
+                NewFederatedAuthorizationSpecification, RedeemFederatedAuthorizationSpecification,
+                RestoreFederatedAuthorizationSpecification,
+            },
+        },
+        user::User,
+    },
+    value_objects::identifier::{Identifier, IdentifierOfType},
+};
+
+pub mod errors;
+pub mod specifications;
+#[cfg(test)]
+mod tests;
+
+/// Authentication request sent to an identity provider, its id is the `state` the provider sends back
+#[derive(Debug, Clone)]
+pub struct FederatedAuthorization {
+    id: Identifier<Ulid, FederatedAuthorization>,
+    provider: String,
+    user_id: Option<Identifier<Ulid, User>>,
+    nonce: String,
+    code_verifier: String,
+    expires_at: OffsetDateTime,
+}
+
+impl Entity<Ulid> for FederatedAuthorization {
+    type Id = Identifier<Ulid, FederatedAuthorization>;
+
+    fn id(&self) -> &Self::Id {
+        &self.id
+    }
+}
+
+impl FederatedAuthorization {
+    pub fn new(
+        NewFederatedAuthorizationSpecification {
+            provider,
+            user_id,
+            nonce,
+            code_verifier,
+            current_time,
+            expiration_seconds,
+        }: NewFederatedAuthorizationSpecification,
+    ) -> Self {
+        Self {
+            id: Identifier::new(),
+            provider: provider.to_string(),
+            user_id,
+            nonce,
+            code_verifier,
+            expires_at: current_time + time::Duration::seconds(expiration_seconds as i64),
+        }
+    }
+
+    pub fn restore(
+        RestoreFederatedAuthorizationSpecification {
+            id,
+            provider,
+            user_id,
+            nonce,
+            code_verifier,
+            expires_at,
+        }: RestoreFederatedAuthorizationSpecification,
+    ) -> Self {
+        Self {
+            id,
+            provider,
+            user_id,
+            nonce,
+            code_verifier,
+            expires_at,
+        }
+    }
+
+    /// Request is valid only for the provider it was sent to and for what it was started for,
+    /// signin or linking an identity to the account of the user who started it
+    pub fn redeem(
+        &self,
+        RedeemFederatedAuthorizationSpecification {
+            provider,
+            user_id,
+            current_time,
+        }: RedeemFederatedAuthorizationSpecification,
+    ) -> Result<(), FederatedAuthorizationError> {
+        if (self.expires_at - current_time).whole_seconds() <= 0 {
+            return Err(FederatedAuthorizationError::Expired);
+        }
+        if self.provider != provider {
+            return Err(FederatedAuthorizationError::ProviderMismatch);
+        }
+        if self.user_id.as_ref() != user_id {
+            return Err(FederatedAuthorizationError::UserMismatch);
+        }
+        Ok(())
+    }
+
+    pub fn provider(&self) -> &str {
+        &self.provider
+    }
+
+    /// User linking an identity to their account, there is none on signin
+    pub fn user_id(&self) -> Option<&Identifier<Ulid, User>> {
+        self.user_id.as_ref()
+    }
+
+    /// ID token issued for this request has to carry the nonce
+    pub fn nonce(&self) -> &str {
+        &self.nonce
+    }
+
+    /// Sent with the authorization code to the provider, only its challenge is sent with the request
+    pub fn code_verifier(&self) -> &str {
+        &self.code_verifier
+    }
+
+    pub fn code_challenge(&self) -> CodeChallenge {
+        CodeChallenge::derive(&self.code_verifier)
+    }
+
+    pub fn expires_at(&self) -> OffsetDateTime {
+        self.expires_at
+    }
+}
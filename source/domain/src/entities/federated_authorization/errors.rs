@@ -0,0 +1,11 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum FederatedAuthorizationError {
+    #[error("federated authorization is expired")]
+    Expired,
+    #[error("federated authorization was sent to another identity provider")]
+    ProviderMismatch,
+    #[error("federated authorization was started for another user or purpose")]
+    UserMismatch,
+}
@@ -0,0 +1,31 @@
+use time::OffsetDateTime;
+use ulid::Ulid;
+
+use crate::{
+    entities::{federated_authorization::FederatedAuthorization, user::User},
+    value_objects::identifier::Identifier,
+};
+
+pub struct NewFederatedAuthorizationSpecification<'a> {
+    pub provider: &'a str,
+    pub user_id: Option<Identifier<Ulid, User>>,
+    pub nonce: String,
+    pub code_verifier: String,
+    pub current_time: OffsetDateTime,
+    pub expiration_seconds: usize,
+}
+
+pub struct RestoreFederatedAuthorizationSpecification {
+    pub id: Identifier<Ulid, FederatedAuthorization>,
+    pub provider: String,
+    pub user_id: Option<Identifier<Ulid, User>>,
+    pub nonce: String,
+    pub code_verifier: String,
+    pub expires_at: OffsetDateTime,
+}
+
+pub struct RedeemFederatedAuthorizationSpecification<'a> {
+    pub provider: &'a str,
+    pub user_id: Option<&'a Identifier<Ulid, User>>,
+    pub current_time: OffsetDateTime,
+}
@@ -0,0 +1,116 @@
+use nimbus_auth_shared::constants::FEDERATED_AUTHORIZATION_EXPIRATION_SECONDS;
+use time::{Duration, OffsetDateTime};
+use ulid::Ulid;
+
+use crate::{
+    entities::{
+        federated_authorization::{
+            FederatedAuthorization,
+            errors::FederatedAuthorizationError,
+            specifications::{
+                NewFederatedAuthorizationSpecification, RedeemFederatedAuthorizationSpecification,
+            },
+        },
+        user::User,
+    },
+    value_objects::identifier::{Identifier, IdentifierOfType},
+};
+
+const PROVIDER: &str = "acme";
+// example of RFC 7636, appendix B
+const CODE_VERIFIER: &str = "dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk";
+const CODE_CHALLENGE: &str = "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM";
+
+fn get_authorization(
+    user_id: Option<Identifier<Ulid, User>>,
+    current_time: OffsetDateTime,
+) -> FederatedAuthorization {
+    FederatedAuthorization::new(NewFederatedAuthorizationSpecification {
+        provider: PROVIDER,
+        user_id,
+        nonce: "nonce".to_string(),
+        code_verifier: CODE_VERIFIER.to_string(),
+        current_time,
+        expiration_seconds: FEDERATED_AUTHORIZATION_EXPIRATION_SECONDS,
+    })
+}
+
+#[test]
+fn signin_authorization_is_redeemed() {
+    let current_time = OffsetDateTime::now_utc();
+    let authorization = get_authorization(None, current_time);
+
+    let result = authorization.redeem(RedeemFederatedAuthorizationSpecification {
+        provider: PROVIDER,
+        user_id: None,
+        current_time,
+    });
+
+    assert!(result.is_ok());
+    assert_eq!(authorization.code_challenge().value(), CODE_CHALLENGE)
+}
+
+#[test]
+fn expired_authorization() {
+    let current_time = OffsetDateTime::now_utc();
+    let authorization = get_authorization(None, current_time);
+
+    let result = authorization.redeem(RedeemFederatedAuthorizationSpecification {
+        provider: PROVIDER,
+        user_id: None,
+        current_time: current_time
+            + Duration::seconds(FEDERATED_AUTHORIZATION_EXPIRATION_SECONDS as i64),
+    });
+
+    assert!(matches!(result, Err(FederatedAuthorizationError::Expired)))
+}
+
+#[test]
+fn authorization_of_another_provider() {
+    let current_time = OffsetDateTime::now_utc();
+    let authorization = get_authorization(None, current_time);
+
+    let result = authorization.redeem(RedeemFederatedAuthorizationSpecification {
+        provider: "other",
+        user_id: None,
+        current_time,
+    });
+
+    assert!(matches!(
+        result,
+        Err(FederatedAuthorizationError::ProviderMismatch)
+    ))
+}
+
+#[test]
+fn link_authorization_is_not_redeemed_for_signin_or_another_user() {
+    let current_time = OffsetDateTime::now_utc();
+    let user_id = Identifier::new();
+    let authorization = get_authorization(Some(user_id.clone()), current_time);
+
+    let signin_result = authorization.redeem(RedeemFederatedAuthorizationSpecification {
+        provider: PROVIDER,
+        user_id: None,
+        current_time,
+    });
+    let other_user_result = authorization.redeem(RedeemFederatedAuthorizationSpecification {
+        provider: PROVIDER,
+        user_id: Some(&Identifier::new()),
+        current_time,
+    });
+    let same_user_result = authorization.redeem(RedeemFederatedAuthorizationSpecification {
+        provider: PROVIDER,
+        user_id: Some(&user_id),
+        current_time,
+    });
+
+    assert!(matches!(
+        signin_result,
+        Err(FederatedAuthorizationError::UserMismatch)
+    ));
+    assert!(matches!(
+        other_user_result,
+        Err(FederatedAuthorizationError::UserMismatch)
+    ));
+    assert!(same_user_result.is_ok())
+}
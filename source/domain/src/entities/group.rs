@@ -0,0 +1,47 @@
+use ulid::Ulid;
+
+use crate::{
+    entities::{
+        Entity,
+        group::{
+            specifications::{NewGroupSpecification, RestoreGroupSpecification},
+            value_objects::group_name::GroupName,
+        },
+    },
+    value_objects::identifier::{Identifier, IdentifierOfType},
+};
+
+pub mod specifications;
+pub mod value_objects;
+
+/// Team of users, downstream services authorize by membership in it
+#[derive(Debug, Clone)]
+pub struct Group {
+    id: Identifier<Ulid, Group>,
+    name: GroupName,
+}
+
+impl Entity<Ulid> for Group {
+    type Id = Identifier<Ulid, Group>;
+
+    fn id(&self) -> &Self::Id {
+        &self.id
+    }
+}
+
+impl Group {
+    pub fn new(NewGroupSpecification { name }: NewGroupSpecification) -> Self {
+        Self {
+            id: Identifier::new(),
+            name,
+        }
+    }
+
+    pub fn restore(RestoreGroupSpecification { id, name }: RestoreGroupSpecification) -> Self {
+        Self { id, name }
+    }
+
+    pub fn name(&self) -> &GroupName {
+        &self.name
+    }
+}
@@ -0,0 +1,15 @@
+use ulid::Ulid;
+
+use crate::{
+    entities::group::{Group, value_objects::group_name::GroupName},
+    value_objects::identifier::Identifier,
+};
+
+pub struct NewGroupSpecification {
+    pub name: GroupName,
+}
+
+pub struct RestoreGroupSpecification {
+    pub id: Identifier<Ulid, Group>,
+    pub name: GroupName,
+}
@@ -0,0 +1,51 @@
+use std::fmt::Display;
+
+use nimbus_auth_shared::constants::GROUP_NAME_MAX_LENGTH_INCLUSIVE;
+
+use crate::entities::group::value_objects::group_name::errors::GroupNameError;
+
+pub mod errors;
+#[cfg(test)]
+mod tests;
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GroupName {
+    value: String,
+}
+
+impl GroupName {
+    pub fn from(value: &str) -> Result<Self, GroupNameError> {
+        Self::validate(value)?;
+        Ok(Self {
+            value: value.to_string(),
+        })
+    }
+
+    fn validate(value: &str) -> Result<(), GroupNameError> {
+        if value.is_empty() {
+            return Err(GroupNameError::Empty);
+        }
+        if value.len() > GROUP_NAME_MAX_LENGTH_INCLUSIVE {
+            return Err(GroupNameError::TooLong {
+                max_length: GROUP_NAME_MAX_LENGTH_INCLUSIVE,
+            });
+        }
+        match value
+            .chars()
+            .all(|ch| ch.is_ascii_lowercase() || ch.is_ascii_digit() || ch == '_' || ch == '-')
+        {
+            true => Ok(()),
+            false => Err(GroupNameError::InvalidCharacters),
+        }
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl Display for GroupName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
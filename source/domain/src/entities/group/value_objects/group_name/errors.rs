@@ -0,0 +1,13 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum GroupNameError {
+    #[error("group name is empty")]
+    Empty,
+    #[error("group name is too long, should be less than or equal to {max_length}")]
+    TooLong { max_length: usize },
+    #[error(
+        "group name contains invalid characters. it should contain only lowercase English alphanumeric characters, `_` and `-`"
+    )]
+    InvalidCharacters,
+}
@@ -0,0 +1,32 @@
+use nimbus_auth_shared::constants::GROUP_NAME_MAX_LENGTH_INCLUSIVE;
+
+use crate::entities::group::value_objects::group_name::{GroupName, errors::GroupNameError};
+
+#[test]
+fn valid_group_name() {
+    let result = GroupName::from("platform-team_2");
+    assert!(result.is_ok())
+}
+
+#[test]
+fn empty_group_name() {
+    let result = GroupName::from("");
+    assert!(matches!(result, Err(GroupNameError::Empty)))
+}
+
+#[test]
+fn long_group_name() {
+    let result = GroupName::from(&"a".repeat(GROUP_NAME_MAX_LENGTH_INCLUSIVE + 1));
+    assert!(matches!(
+        result,
+        Err(GroupNameError::TooLong {
+            max_length: GROUP_NAME_MAX_LENGTH_INCLUSIVE
+        })
+    ))
+}
+
+#[test]
+fn group_name_with_spaces() {
+    let result = GroupName::from("platform team");
+    assert!(matches!(result, Err(GroupNameError::InvalidCharacters)))
+}
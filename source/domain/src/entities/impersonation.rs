@@ -0,0 +1,115 @@
+use nimbus_auth_shared::types::{
+    AccessTokenAttributes, AccessTokenExpirationSeconds, AccessTokenMaxGroups,
+};
+use time::OffsetDateTime;
+use ulid::Ulid;
+
+use crate::{
+    entities::{
+        Entity,
+        impersonation::{
+            errors::ImpersonationError, specifications::NewImpersonationSpecification,
+        },
+        user::User,
+    },
+    value_objects::{
+        access_token::AccessToken,
+        actor::Actor,
+        identifier::{Identifier, IdentifierOfType},
+        user_claims::UserClaims,
+    },
+};
+
+pub mod errors;
+pub mod specifications;
+#[cfg(test)]
+mod tests;
+
+/// Admin acting as another user to reproduce their issues, every one is kept as the audit trail
+#[derive(Debug, Clone)]
+pub struct Impersonation {
+    id: Identifier<Ulid, Impersonation>,
+    admin_id: Identifier<Ulid, User>,
+    user_id: Identifier<Ulid, User>,
+    reason: String,
+    started_at: OffsetDateTime,
+    expires_at: OffsetDateTime,
+}
+
+impl Entity<Ulid> for Impersonation {
+    type Id = Identifier<Ulid, Impersonation>;
+
+    fn id(&self) -> &Self::Id {
+        &self.id
+    }
+}
+
+impl Impersonation {
+    pub fn new(
+        NewImpersonationSpecification {
+            admin_id,
+            user_id,
+            reason,
+            current_time,
+            expiration_seconds,
+        }: NewImpersonationSpecification,
+    ) -> Result<Self, ImpersonationError> {
+        if admin_id == user_id {
+            return Err(ImpersonationError::SelfImpersonation);
+        }
+        let reason = reason.trim();
+        if reason.is_empty() {
+            return Err(ImpersonationError::ReasonIsEmpty);
+        }
+
+        Ok(Self {
+            id: Identifier::new(),
+            admin_id,
+            user_id,
+            reason: reason.to_string(),
+            started_at: current_time,
+            expires_at: current_time + time::Duration::seconds(expiration_seconds as i64),
+        })
+    }
+
+    /// Token of the user naming the admin as its actor, it lives as long as the impersonation
+    ///
+    /// Claims lose the auth permissions and the admin role, so the token can not be used for admin operations
+    pub fn generate_access_token(
+        &self,
+        user_claims: UserClaims,
+        max_groups: AccessTokenMaxGroups,
+        attributes: &AccessTokenAttributes,
+    ) -> AccessToken {
+        AccessToken::new(
+            user_claims.without_auth_privileges(),
+            self.started_at,
+            AccessTokenExpirationSeconds(
+                (self.expires_at - self.started_at).whole_seconds().max(0) as usize,
+            ),
+            max_groups,
+            attributes,
+        )
+        .with_actor(Actor::new(&self.admin_id.to_string(), None))
+    }
+
+    pub fn admin_id(&self) -> &Identifier<Ulid, User> {
+        &self.admin_id
+    }
+
+    pub fn user_id(&self) -> &Identifier<Ulid, User> {
+        &self.user_id
+    }
+
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+
+    pub fn started_at(&self) -> OffsetDateTime {
+        self.started_at
+    }
+
+    pub fn expires_at(&self) -> OffsetDateTime {
+        self.expires_at
+    }
+}
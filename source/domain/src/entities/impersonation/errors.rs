@@ -0,0 +1,9 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ImpersonationError {
+    #[error("admin can not impersonate themselves")]
+    SelfImpersonation,
+    #[error("reason of impersonation is empty")]
+    ReasonIsEmpty,
+}
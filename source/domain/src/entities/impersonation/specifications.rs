@@ -0,0 +1,12 @@
+use time::OffsetDateTime;
+use ulid::Ulid;
+
+use crate::{entities::user::User, value_objects::identifier::Identifier};
+
+pub struct NewImpersonationSpecification<'a> {
+    pub admin_id: Identifier<Ulid, User>,
+    pub user_id: Identifier<Ulid, User>,
+    pub reason: &'a str,
+    pub current_time: OffsetDateTime,
+    pub expiration_seconds: usize,
+}
@@ -0,0 +1,111 @@
+use std::collections::BTreeSet;
+
+use nimbus_auth_shared::{
+    constants::{ACCESS_TOKEN_MAX_GROUPS_DEFAULT, DEFAULT_ROLE_NAME, PERMISSION_MANAGE_USERS},
+    types::{AccessTokenAttributes, AccessTokenMaxGroups},
+};
+use time::OffsetDateTime;
+
+use crate::{
+    entities::{
+        impersonation::{
+            Impersonation, errors::ImpersonationError,
+            specifications::NewImpersonationSpecification,
+        },
+        role::value_objects::{permission::Permission, role_name::RoleName},
+        user::value_objects::user_name::UserName,
+    },
+    value_objects::{
+        identifier::{Identifier, IdentifierOfType},
+        user_claims::UserClaims,
+    },
+};
+
+const USER_NAME: &str = "customer";
+const REASON: &str = "ticket 4211, checkout fails";
+const SERVICE_PERMISSION: &str = "invoices:read";
+const EXPIRATION_SECONDS: usize = 900;
+
+#[test]
+fn token_names_admin_as_actor_without_auth_privileges() {
+    let admin_id = Identifier::new();
+    let user_id = Identifier::new();
+    let current_time = OffsetDateTime::now_utc();
+    let user_claims = UserClaims::new(
+        user_id.clone(),
+        UserName::from(USER_NAME).unwrap(),
+        BTreeSet::from([RoleName::default_role(), RoleName::admin()]),
+        BTreeSet::from([
+            Permission::from(PERMISSION_MANAGE_USERS).unwrap(),
+            Permission::from(SERVICE_PERMISSION).unwrap(),
+        ]),
+    );
+    let impersonation = Impersonation::new(NewImpersonationSpecification {
+        admin_id: admin_id.clone(),
+        user_id,
+        reason: REASON,
+        current_time,
+        expiration_seconds: EXPIRATION_SECONDS,
+    })
+    .unwrap();
+
+    let access_token = impersonation.generate_access_token(
+        user_claims,
+        AccessTokenMaxGroups(ACCESS_TOKEN_MAX_GROUPS_DEFAULT),
+        &AccessTokenAttributes::default(),
+    );
+
+    assert_eq!(
+        access_token
+            .actor()
+            .map(|actor| actor.subject().to_string()),
+        Some(admin_id.to_string())
+    );
+    assert_eq!(
+        access_token
+            .user_claims()
+            .roles()
+            .iter()
+            .map(|role| role.value())
+            .collect::<Vec<_>>(),
+        [DEFAULT_ROLE_NAME]
+    );
+    assert_eq!(
+        access_token
+            .user_claims()
+            .permissions()
+            .iter()
+            .map(|permission| permission.value())
+            .collect::<Vec<_>>(),
+        [SERVICE_PERMISSION]
+    );
+    assert_eq!(access_token.expires_at(), &impersonation.expires_at());
+}
+
+#[test]
+fn self_impersonation() {
+    let admin_id = Identifier::new();
+
+    let result = Impersonation::new(NewImpersonationSpecification {
+        admin_id: admin_id.clone(),
+        user_id: admin_id,
+        reason: REASON,
+        current_time: OffsetDateTime::now_utc(),
+        expiration_seconds: EXPIRATION_SECONDS,
+    });
+
+    assert!(matches!(result, Err(ImpersonationError::SelfImpersonation)));
+}
+
+#[test]
+fn blank_reason() {
+    let result = Impersonation::new(NewImpersonationSpecification {
+        admin_id: Identifier::new(),
+        user_id: Identifier::new(),
+        reason: "  ",
+        current_time: OffsetDateTime::now_utc(),
+        expiration_seconds: EXPIRATION_SECONDS,
+    });
+
+    assert!(matches!(result, Err(ImpersonationError::ReasonIsEmpty)));
+}
@@ -188,6 +188,16 @@ impl KeyPair<Expiring> {
     pub fn expires_at(&self) -> OffsetDateTime {
         self.state.expires_at
     }
+
+    /// Stops the key from verifying tokens before it expires, e.g. when it is compromised
+    pub fn revoke(self, current_time: OffsetDateTime) -> KeyPair<Revoked> {
+        KeyPair {
+            id: self.id.as_other_entity(),
+            state: Revoked {
+                revoked_at: current_time,
+            },
+        }
+    }
 }
 
 impl KeyPair<Expired> {
@@ -196,6 +206,12 @@ impl KeyPair<Expired> {
     }
 }
 
+impl KeyPair<Revoked> {
+    pub fn revoked_at(&self) -> OffsetDateTime {
+        self.state.revoked_at
+    }
+}
+
 macro_rules! impl_keypair_froms {
     ($state:ty, $variant:ident) => {
         impl From<KeyPair<$state>> for SomeKeyPair<'static> {
@@ -0,0 +1,145 @@
+use std::collections::BTreeSet;
+
+use ulid::Ulid;
+
+use crate::{
+    entities::{
+        Entity,
+        oauth_client::{
+            errors::OAuthClientError,
+            specifications::{NewOAuthClientSpecification, RestoreOAuthClientSpecification},
+            value_objects::{oauth_client_name::OAuthClientName, redirect_uri::RedirectUri},
+        },
+        role::value_objects::permission::Permission,
+    },
+    value_objects::{
+        client_claims::ClientClaims,
+        identifier::{Identifier, IdentifierOfType},
+        secret_hash::SecretHash,
+    },
+};
+
+pub mod errors;
+pub mod specifications;
+#[cfg(test)]
+mod tests;
+pub mod value_objects;
+
+/// Application obtaining tokens on behalf of users through the authorization code flow,
+/// or on its own behalf through the client credentials grant if it has a secret
+#[derive(Debug, Clone)]
+pub struct OAuthClient {
+    id: Identifier<Ulid, OAuthClient>,
+    name: OAuthClientName,
+    redirect_uris: Vec<RedirectUri>,
+    secret_hash: Option<SecretHash>,
+    /// Permissions the client can be granted in its own access tokens
+    scopes: BTreeSet<Permission>,
+}
+
+impl Entity<Ulid> for OAuthClient {
+    type Id = Identifier<Ulid, OAuthClient>;
+
+    fn id(&self) -> &Self::Id {
+        &self.id
+    }
+}
+
+impl OAuthClient {
+    pub fn new(
+        NewOAuthClientSpecification {
+            name,
+            redirect_uris,
+            secret_hash,
+            scopes,
+        }: NewOAuthClientSpecification,
+    ) -> Self {
+        Self {
+            id: Identifier::new(),
+            name,
+            redirect_uris,
+            secret_hash,
+            scopes,
+        }
+    }
+
+    pub fn restore(
+        RestoreOAuthClientSpecification {
+            id,
+            name,
+            redirect_uris,
+            secret_hash,
+            scopes,
+        }: RestoreOAuthClientSpecification,
+    ) -> Self {
+        Self {
+            id,
+            name,
+            redirect_uris,
+            secret_hash,
+            scopes,
+        }
+    }
+
+    pub fn name(&self) -> &OAuthClientName {
+        &self.name
+    }
+
+    pub fn redirect_uris(&self) -> &[RedirectUri] {
+        &self.redirect_uris
+    }
+
+    pub fn secret_hash(&self) -> Option<&SecretHash> {
+        self.secret_hash.as_ref()
+    }
+
+    pub fn scopes(&self) -> &BTreeSet<Permission> {
+        &self.scopes
+    }
+
+    /// Confidential clients have a secret to authenticate with
+    pub fn is_confidential(&self) -> bool {
+        self.secret_hash.is_some()
+    }
+
+    /// Redirect uris are compared exactly, without any pattern matching
+    pub fn has_redirect_uri(&self, redirect_uri: &RedirectUri) -> bool {
+        self.redirect_uris.contains(redirect_uri)
+    }
+
+    /// Public clients have nothing to authenticate with, so any secret they send is ignored
+    pub fn authenticate(&self, secret: Option<&str>) -> Result<(), OAuthClientError> {
+        match (&self.secret_hash, secret) {
+            (None, _) => Ok(()),
+            (Some(_), None) => Err(OAuthClientError::SecretIsMissing),
+            (Some(secret_hash), Some(secret)) => match secret_hash.verify(secret) {
+                true => Ok(()),
+                false => Err(OAuthClientError::SecretMismatch),
+            },
+        }
+    }
+
+    /// No requested scopes grant all scopes of the client
+    pub fn claims(
+        &self,
+        requested_scopes: Option<BTreeSet<Permission>>,
+    ) -> Result<ClientClaims, OAuthClientError> {
+        let scopes = match requested_scopes {
+            Some(requested_scopes) => {
+                if let Some(scope) = requested_scopes.difference(&self.scopes).next() {
+                    return Err(OAuthClientError::ScopeIsNotAllowed {
+                        scope: scope.to_string(),
+                    });
+                }
+                requested_scopes
+            }
+            None => self.scopes.clone(),
+        };
+
+        Ok(ClientClaims::new(
+            self.id.clone(),
+            self.name.clone(),
+            scopes,
+        ))
+    }
+}
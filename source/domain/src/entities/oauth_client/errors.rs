@@ -0,0 +1,11 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum OAuthClientError {
+    #[error("client secret is required for confidential clients")]
+    SecretIsMissing,
+    #[error("client secret does not match")]
+    SecretMismatch,
+    #[error("scope `{scope}` is not allowed for the client")]
+    ScopeIsNotAllowed { scope: String },
+}
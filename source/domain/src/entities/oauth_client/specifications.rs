@@ -0,0 +1,29 @@
+use std::collections::BTreeSet;
+
+use ulid::Ulid;
+
+use crate::{
+    entities::{
+        oauth_client::{
+            OAuthClient,
+            value_objects::{oauth_client_name::OAuthClientName, redirect_uri::RedirectUri},
+        },
+        role::value_objects::permission::Permission,
+    },
+    value_objects::{identifier::Identifier, secret_hash::SecretHash},
+};
+
+pub struct NewOAuthClientSpecification {
+    pub name: OAuthClientName,
+    pub redirect_uris: Vec<RedirectUri>,
+    pub secret_hash: Option<SecretHash>,
+    pub scopes: BTreeSet<Permission>,
+}
+
+pub struct RestoreOAuthClientSpecification {
+    pub id: Identifier<Ulid, OAuthClient>,
+    pub name: OAuthClientName,
+    pub redirect_uris: Vec<RedirectUri>,
+    pub secret_hash: Option<SecretHash>,
+    pub scopes: BTreeSet<Permission>,
+}
@@ -0,0 +1,82 @@
+use std::collections::BTreeSet;
+
+use crate::{
+    entities::{
+        oauth_client::{
+            OAuthClient, errors::OAuthClientError, specifications::NewOAuthClientSpecification,
+            value_objects::oauth_client_name::OAuthClientName,
+        },
+        role::value_objects::permission::Permission,
+    },
+    value_objects::secret_hash::SecretHash,
+};
+
+const CLIENT_NAME: &str = "billing";
+const SECRET: &str = "yD8lWq3h0C0vJf6Qb2sZpVtE9kN4mA7xR1uGcHiLoPw";
+const SCOPES: [&str; 2] = ["orders:read", "invoices:write"];
+
+fn get_scopes(scopes: &[&str]) -> BTreeSet<Permission> {
+    scopes
+        .iter()
+        .map(|scope| Permission::from(scope).unwrap())
+        .collect()
+}
+
+fn get_client(secret: Option<&str>) -> OAuthClient {
+    OAuthClient::new(NewOAuthClientSpecification {
+        name: OAuthClientName::from(CLIENT_NAME).unwrap(),
+        redirect_uris: Vec::new(),
+        secret_hash: secret.map(SecretHash::hash),
+        scopes: get_scopes(&SCOPES),
+    })
+}
+
+#[test]
+fn confidential_client_with_matching_secret() {
+    let client = get_client(Some(SECRET));
+    assert!(client.authenticate(Some(SECRET)).is_ok())
+}
+
+#[test]
+fn confidential_client_without_secret() {
+    let client = get_client(Some(SECRET));
+    let result = client.authenticate(None);
+    assert!(matches!(result, Err(OAuthClientError::SecretIsMissing)))
+}
+
+#[test]
+fn confidential_client_with_other_secret() {
+    let client = get_client(Some(SECRET));
+    let result = client.authenticate(Some("other"));
+    assert!(matches!(result, Err(OAuthClientError::SecretMismatch)))
+}
+
+#[test]
+fn public_client_without_secret() {
+    let client = get_client(None);
+    assert!(client.authenticate(None).is_ok())
+}
+
+#[test]
+fn claims_with_all_scopes() {
+    let client = get_client(Some(SECRET));
+    let claims = client.claims(None).unwrap();
+    assert_eq!(claims.scopes(), client.scopes())
+}
+
+#[test]
+fn claims_with_requested_scopes() {
+    let client = get_client(Some(SECRET));
+    let claims = client.claims(Some(get_scopes(&SCOPES[..1]))).unwrap();
+    assert_eq!(claims.scopes(), &get_scopes(&SCOPES[..1]))
+}
+
+#[test]
+fn claims_with_not_allowed_scope() {
+    let client = get_client(Some(SECRET));
+    let result = client.claims(Some(get_scopes(&["auth:users:manage"])));
+    assert!(matches!(
+        result,
+        Err(OAuthClientError::ScopeIsNotAllowed { .. })
+    ))
+}
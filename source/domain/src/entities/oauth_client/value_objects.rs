@@ -0,0 +1,2 @@
+pub mod oauth_client_name;
+pub mod redirect_uri;
@@ -0,0 +1,49 @@
+use std::fmt::Display;
+
+use nimbus_auth_shared::constants::OAUTH_CLIENT_NAME_MAX_LENGTH_INCLUSIVE;
+
+use crate::entities::oauth_client::value_objects::oauth_client_name::errors::OAuthClientNameError;
+
+pub mod errors;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OAuthClientName {
+    value: String,
+}
+
+impl OAuthClientName {
+    pub fn from(value: &str) -> Result<Self, OAuthClientNameError> {
+        Self::validate(value)?;
+        Ok(Self {
+            value: value.to_string(),
+        })
+    }
+
+    fn validate(value: &str) -> Result<(), OAuthClientNameError> {
+        if value.is_empty() {
+            return Err(OAuthClientNameError::Empty);
+        }
+        if value.len() > OAUTH_CLIENT_NAME_MAX_LENGTH_INCLUSIVE {
+            return Err(OAuthClientNameError::TooLong {
+                max_length: OAUTH_CLIENT_NAME_MAX_LENGTH_INCLUSIVE,
+            });
+        }
+        match value
+            .chars()
+            .all(|ch| ch.is_ascii_lowercase() || ch.is_ascii_digit() || ch == '_' || ch == '-')
+        {
+            true => Ok(()),
+            false => Err(OAuthClientNameError::InvalidCharacters),
+        }
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl Display for OAuthClientName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
@@ -0,0 +1,60 @@
+use std::fmt::Display;
+
+use nimbus_auth_shared::constants::OAUTH_REDIRECT_URI_MAX_LENGTH_INCLUSIVE;
+use url::{Host, Url};
+
+use crate::entities::oauth_client::value_objects::redirect_uri::errors::RedirectUriError;
+
+pub mod errors;
+#[cfg(test)]
+mod tests;
+
+/// Absolute uri the authorization server sends the user back to, kept as it was registered
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RedirectUri {
+    value: String,
+}
+
+impl RedirectUri {
+    pub fn from(value: &str) -> Result<Self, RedirectUriError> {
+        Self::validate(value)?;
+        Ok(Self {
+            value: value.to_string(),
+        })
+    }
+
+    fn validate(value: &str) -> Result<(), RedirectUriError> {
+        if value.len() > OAUTH_REDIRECT_URI_MAX_LENGTH_INCLUSIVE {
+            return Err(RedirectUriError::TooLong {
+                max_length: OAUTH_REDIRECT_URI_MAX_LENGTH_INCLUSIVE,
+            });
+        }
+        let url = Url::parse(value).map_err(RedirectUriError::Parsing)?;
+        if url.fragment().is_some() {
+            return Err(RedirectUriError::HasFragment);
+        }
+        // plain http is left for native apps and development servers listening on loopback
+        let is_loopback = match url.host() {
+            Some(Host::Domain(domain)) => domain == "localhost",
+            Some(Host::Ipv4(ip)) => ip.is_loopback(),
+            Some(Host::Ipv6(ip)) => ip.is_loopback(),
+            None => false,
+        };
+        match (url.scheme(), is_loopback) {
+            ("https", _) | ("http", true) => Ok(()),
+            (scheme, _) => Err(RedirectUriError::InsecureScheme {
+                scheme: scheme.to_string(),
+            }),
+        }
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl Display for RedirectUri {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
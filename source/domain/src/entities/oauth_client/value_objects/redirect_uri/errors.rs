@@ -0,0 +1,13 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum RedirectUriError {
+    #[error("redirect uri is too long, should be less than or equal to {max_length}")]
+    TooLong { max_length: usize },
+    #[error("redirect uri is not a valid absolute uri. Error: {0}")]
+    Parsing(#[source] url::ParseError),
+    #[error("redirect uri should not contain a fragment")]
+    HasFragment,
+    #[error("redirect uri scheme {scheme} is not allowed, it should be https or http on loopback")]
+    InsecureScheme { scheme: String },
+}
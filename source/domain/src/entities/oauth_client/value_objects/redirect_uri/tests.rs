@@ -0,0 +1,36 @@
+use crate::entities::oauth_client::value_objects::redirect_uri::{
+    RedirectUri, errors::RedirectUriError,
+};
+
+#[test]
+fn https_redirect_uri() {
+    let result = RedirectUri::from("https://app.example.com/callback?tenant=1");
+    assert!(result.is_ok())
+}
+
+#[test]
+fn http_loopback_redirect_uri() {
+    assert!(RedirectUri::from("http://localhost:8080/callback").is_ok());
+    assert!(RedirectUri::from("http://127.0.0.1:8080/callback").is_ok());
+}
+
+#[test]
+fn http_redirect_uri() {
+    let result = RedirectUri::from("http://app.example.com/callback");
+    assert!(matches!(
+        result,
+        Err(RedirectUriError::InsecureScheme { .. })
+    ))
+}
+
+#[test]
+fn relative_redirect_uri() {
+    let result = RedirectUri::from("/callback");
+    assert!(matches!(result, Err(RedirectUriError::Parsing(_))))
+}
+
+#[test]
+fn redirect_uri_with_fragment() {
+    let result = RedirectUri::from("https://app.example.com/callback#token");
+    assert!(matches!(result, Err(RedirectUriError::HasFragment)))
+}
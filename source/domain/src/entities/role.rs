@@ -0,0 +1,78 @@
+use std::collections::BTreeSet;
+
+use nimbus_auth_shared::constants::{ADMIN_ROLE_NAME, DEFAULT_ROLE_NAME};
+use ulid::Ulid;
+
+use crate::{
+    entities::{
+        Entity,
+        role::{
+            specifications::{NewRoleSpecification, RestoreRoleSpecification},
+            value_objects::{permission::Permission, role_name::RoleName},
+        },
+    },
+    value_objects::identifier::{Identifier, IdentifierOfType},
+};
+
+pub mod specifications;
+pub mod value_objects;
+
+/// Named set of permissions, users get permissions of all their roles
+#[derive(Debug, Clone)]
+pub struct Role {
+    id: Identifier<Ulid, Role>,
+    name: RoleName,
+    permissions: BTreeSet<Permission>,
+}
+
+impl Entity<Ulid> for Role {
+    type Id = Identifier<Ulid, Role>;
+
+    fn id(&self) -> &Self::Id {
+        &self.id
+    }
+}
+
+impl Role {
+    pub fn new(NewRoleSpecification { name, permissions }: NewRoleSpecification) -> Self {
+        Self {
+            id: Identifier::new(),
+            name,
+            permissions,
+        }
+    }
+
+    pub fn restore(
+        RestoreRoleSpecification {
+            id,
+            name,
+            permissions,
+        }: RestoreRoleSpecification,
+    ) -> Self {
+        Self {
+            id,
+            name,
+            permissions,
+        }
+    }
+
+    pub fn name(&self) -> &RoleName {
+        &self.name
+    }
+
+    pub fn permissions(&self) -> &BTreeSet<Permission> {
+        &self.permissions
+    }
+
+    /// Built-in roles are relied on by signup and admin bootstrap
+    pub fn is_built_in(&self) -> bool {
+        matches!(self.name.value(), DEFAULT_ROLE_NAME | ADMIN_ROLE_NAME)
+    }
+
+    pub fn with_permissions(self, permissions: BTreeSet<Permission>) -> Self {
+        Self {
+            permissions,
+            ..self
+        }
+    }
+}
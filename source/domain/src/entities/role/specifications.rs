@@ -0,0 +1,22 @@
+use std::collections::BTreeSet;
+
+use ulid::Ulid;
+
+use crate::{
+    entities::role::{
+        Role,
+        value_objects::{permission::Permission, role_name::RoleName},
+    },
+    value_objects::identifier::Identifier,
+};
+
+pub struct NewRoleSpecification {
+    pub name: RoleName,
+    pub permissions: BTreeSet<Permission>,
+}
+
+pub struct RestoreRoleSpecification {
+    pub id: Identifier<Ulid, Role>,
+    pub name: RoleName,
+    pub permissions: BTreeSet<Permission>,
+}
@@ -0,0 +1,51 @@
+use std::fmt::Display;
+
+use nimbus_auth_shared::constants::PERMISSION_MAX_LENGTH_INCLUSIVE;
+
+use crate::entities::role::value_objects::permission::errors::PermissionError;
+
+pub mod errors;
+#[cfg(test)]
+mod tests;
+
+/// Permission is an opaque name, e.g. `auth:users:read`, which services check in access token claims
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Permission {
+    value: String,
+}
+
+impl Permission {
+    pub fn from(value: &str) -> Result<Self, PermissionError> {
+        Self::validate(value)?;
+        Ok(Self {
+            value: value.to_string(),
+        })
+    }
+
+    fn validate(value: &str) -> Result<(), PermissionError> {
+        if value.is_empty() {
+            return Err(PermissionError::Empty);
+        }
+        if value.len() > PERMISSION_MAX_LENGTH_INCLUSIVE {
+            return Err(PermissionError::TooLong {
+                max_length: PERMISSION_MAX_LENGTH_INCLUSIVE,
+            });
+        }
+        match value.chars().all(|ch| {
+            ch.is_ascii_lowercase() || ch.is_ascii_digit() || matches!(ch, '_' | '-' | '.' | ':')
+        }) {
+            true => Ok(()),
+            false => Err(PermissionError::InvalidCharacters),
+        }
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl Display for Permission {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
@@ -0,0 +1,13 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum PermissionError {
+    #[error("permission is empty")]
+    Empty,
+    #[error("permission is too long, should be less than or equal to {max_length}")]
+    TooLong { max_length: usize },
+    #[error(
+        "permission contains invalid characters. it should contain only lowercase English alphanumeric characters, `_`, `-`, `.` and `:`"
+    )]
+    InvalidCharacters,
+}
@@ -0,0 +1,27 @@
+use nimbus_auth_shared::constants::{AUTH_PERMISSIONS, PERMISSION_MAX_LENGTH_INCLUSIVE};
+
+use crate::entities::role::value_objects::permission::{Permission, errors::PermissionError};
+
+#[test]
+fn auth_permissions_are_valid() {
+    for permission in AUTH_PERMISSIONS {
+        assert!(Permission::from(permission).is_ok())
+    }
+}
+
+#[test]
+fn long_permission() {
+    let result = Permission::from(&"a".repeat(PERMISSION_MAX_LENGTH_INCLUSIVE + 1));
+    assert!(matches!(
+        result,
+        Err(PermissionError::TooLong {
+            max_length: PERMISSION_MAX_LENGTH_INCLUSIVE
+        })
+    ))
+}
+
+#[test]
+fn permission_with_spaces() {
+    let result = Permission::from("billing: read");
+    assert!(matches!(result, Err(PermissionError::InvalidCharacters)))
+}
@@ -0,0 +1,67 @@
+use std::fmt::Display;
+
+use nimbus_auth_shared::constants::{
+    ADMIN_ROLE_NAME, DEFAULT_ROLE_NAME, ROLE_NAME_MAX_LENGTH_INCLUSIVE,
+};
+
+use crate::entities::role::value_objects::role_name::errors::RoleNameError;
+
+pub mod errors;
+#[cfg(test)]
+mod tests;
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RoleName {
+    value: String,
+}
+
+impl RoleName {
+    pub fn from(value: &str) -> Result<Self, RoleNameError> {
+        Self::validate(value)?;
+        Ok(Self {
+            value: value.to_string(),
+        })
+    }
+
+    /// Role given to every new user
+    pub fn default_role() -> Self {
+        Self {
+            value: DEFAULT_ROLE_NAME.to_string(),
+        }
+    }
+
+    /// Role holding all permissions of the auth service itself
+    pub fn admin() -> Self {
+        Self {
+            value: ADMIN_ROLE_NAME.to_string(),
+        }
+    }
+
+    fn validate(value: &str) -> Result<(), RoleNameError> {
+        if value.is_empty() {
+            return Err(RoleNameError::Empty);
+        }
+        if value.len() > ROLE_NAME_MAX_LENGTH_INCLUSIVE {
+            return Err(RoleNameError::TooLong {
+                max_length: ROLE_NAME_MAX_LENGTH_INCLUSIVE,
+            });
+        }
+        match value
+            .chars()
+            .all(|ch| ch.is_ascii_lowercase() || ch.is_ascii_digit() || ch == '_' || ch == '-')
+        {
+            true => Ok(()),
+            false => Err(RoleNameError::InvalidCharacters),
+        }
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl Display for RoleName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
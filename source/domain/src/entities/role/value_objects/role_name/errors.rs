@@ -0,0 +1,13 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum RoleNameError {
+    #[error("role name is empty")]
+    Empty,
+    #[error("role name is too long, should be less than or equal to {max_length}")]
+    TooLong { max_length: usize },
+    #[error(
+        "role name contains invalid characters. it should contain only lowercase English alphanumeric characters, `_` and `-`"
+    )]
+    InvalidCharacters,
+}
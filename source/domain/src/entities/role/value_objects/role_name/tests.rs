@@ -0,0 +1,39 @@
+use nimbus_auth_shared::constants::ROLE_NAME_MAX_LENGTH_INCLUSIVE;
+
+use crate::entities::role::value_objects::role_name::{RoleName, errors::RoleNameError};
+
+#[test]
+fn valid_role_name() {
+    let result = RoleName::from("support-team_2");
+    assert!(result.is_ok())
+}
+
+#[test]
+fn empty_role_name() {
+    let result = RoleName::from("");
+    assert!(matches!(result, Err(RoleNameError::Empty)))
+}
+
+#[test]
+fn long_role_name() {
+    let result = RoleName::from(&"a".repeat(ROLE_NAME_MAX_LENGTH_INCLUSIVE + 1));
+    assert!(matches!(
+        result,
+        Err(RoleNameError::TooLong {
+            max_length: ROLE_NAME_MAX_LENGTH_INCLUSIVE
+        })
+    ))
+}
+
+#[test]
+fn uppercase_role_name() {
+    let result = RoleName::from("Admin");
+    assert!(matches!(result, Err(RoleNameError::InvalidCharacters)))
+}
+
+#[test]
+fn built_in_role_names_are_valid() {
+    for role_name in [RoleName::default_role(), RoleName::admin()] {
+        assert!(RoleName::from(role_name.value()).is_ok())
+    }
+}
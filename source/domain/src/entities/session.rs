@@ -1,13 +1,20 @@
 use std::{borrow::Cow, ops::Deref};
 
-use nimbus_auth_shared::types::{AccessTokenExpirationSeconds, SessionExpirationSeconds};
+use nimbus_auth_shared::types::{
+    AccessTokenAttributes, AccessTokenExpirationSeconds, AccessTokenMaxGroups,
+    SessionExpirationSeconds,
+};
 use time::OffsetDateTime;
 use ulid::Ulid;
 
 use crate::{
     entities::{
         Entity,
-        session::specifications::{NewSessionSpecification, RestoreSessionSpecification},
+        oauth_client::OAuthClient,
+        session::{
+            errors::SessionError,
+            specifications::{NewSessionSpecification, RestoreSessionSpecification},
+        },
     },
     value_objects::{
         access_token::AccessToken,
@@ -18,12 +25,16 @@ use crate::{
 
 pub mod errors;
 pub mod specifications;
+#[cfg(test)]
+mod tests;
 
 pub trait SessionState {}
 
 #[derive(Debug, Clone)]
 pub struct Active {
     user_claims: UserClaims,
+    /// Set for sessions started by oauth grants, only the client may refresh them
+    client_id: Option<Identifier<Ulid, OAuthClient>>,
     expires_at: OffsetDateTime,
 }
 
@@ -78,6 +89,7 @@ impl SomeSession<'_> {
     pub fn new(
         NewSessionSpecification {
             user_claims,
+            client_id,
             current_time,
             expiration_seconds: SessionExpirationSeconds(expiration_seconds),
         }: NewSessionSpecification,
@@ -86,6 +98,7 @@ impl SomeSession<'_> {
             id: Identifier::new(),
             state: Active {
                 user_claims,
+                client_id,
                 expires_at: current_time + time::Duration::seconds(expiration_seconds as i64),
             },
         }
@@ -95,6 +108,7 @@ impl SomeSession<'_> {
         RestoreSessionSpecification {
             id,
             user_claims,
+            client_id,
             revoked_at,
             expires_at,
             current_time,
@@ -110,6 +124,7 @@ impl SomeSession<'_> {
                     id: id.as_other_entity(),
                     state: Active {
                         user_claims,
+                        client_id,
                         expires_at,
                     },
                 }),
@@ -149,13 +164,19 @@ impl Session<Active> {
         }
     }
 
+    /// New session carries the given claims, so changes of the user since signin take effect.
+    /// Sessions of oauth clients are refreshed only by the same client, see RFC 6749 section 6
     pub fn refresh(
         self,
+        client_id: Option<&Identifier<Ulid, OAuthClient>>,
+        user_claims: UserClaims,
         current_time: OffsetDateTime,
         expiration_seconds: SessionExpirationSeconds,
-    ) -> (Session<Revoked>, Session<Active>) {
-        let user_claims = self.user_claims.clone();
-        (
+    ) -> Result<(Session<Revoked>, Session<Active>), SessionError> {
+        if self.client_id.as_ref() != client_id {
+            return Err(SessionError::ClientMismatch);
+        }
+        Ok((
             Session {
                 id: self.id.as_other_entity(),
                 state: Revoked {
@@ -164,18 +185,27 @@ impl Session<Active> {
             },
             SomeSession::new(NewSessionSpecification {
                 user_claims,
+                client_id: self.state.client_id,
                 current_time,
                 expiration_seconds,
             }),
-        )
+        ))
     }
 
     pub fn generate_access_token(
         &self,
         current_time: OffsetDateTime,
         expiration_seconds: AccessTokenExpirationSeconds,
+        max_groups: AccessTokenMaxGroups,
+        attributes: &AccessTokenAttributes,
     ) -> AccessToken {
-        AccessToken::new(self.user_claims.clone(), current_time, expiration_seconds)
+        AccessToken::new(
+            self.user_claims.clone(),
+            current_time,
+            expiration_seconds,
+            max_groups,
+            attributes,
+        )
     }
 
     pub fn expires_at(&self) -> OffsetDateTime {
@@ -185,6 +215,10 @@ impl Session<Active> {
     pub fn user_claims(&self) -> &UserClaims {
         &self.user_claims
     }
+
+    pub fn client_id(&self) -> Option<&Identifier<Ulid, OAuthClient>> {
+        self.client_id.as_ref()
+    }
 }
 
 impl Session<Revoked> {
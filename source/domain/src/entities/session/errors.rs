@@ -1,4 +1,7 @@
 use thiserror::Error;
 
 #[derive(Debug, Error)]
-pub enum SessionError {}
+pub enum SessionError {
+    #[error("session is issued to another client")]
+    ClientMismatch,
+}
@@ -3,12 +3,14 @@ use time::OffsetDateTime;
 use ulid::Ulid;
 
 use crate::{
-    entities::session::SomeSession,
+    entities::{oauth_client::OAuthClient, session::SomeSession},
     value_objects::{identifier::Identifier, user_claims::UserClaims},
 };
 
 pub struct NewSessionSpecification {
     pub user_claims: UserClaims,
+    /// Oauth client the session is issued to, none for first party signins
+    pub client_id: Option<Identifier<Ulid, OAuthClient>>,
     pub current_time: OffsetDateTime,
     pub expiration_seconds: SessionExpirationSeconds,
 }
@@ -16,6 +18,7 @@ pub struct NewSessionSpecification {
 pub struct RestoreSessionSpecification<'a> {
     pub id: Identifier<Ulid, SomeSession<'a>>,
     pub user_claims: UserClaims,
+    pub client_id: Option<Identifier<Ulid, OAuthClient>>,
     pub revoked_at: Option<OffsetDateTime>,
     pub expires_at: OffsetDateTime,
     pub current_time: OffsetDateTime,
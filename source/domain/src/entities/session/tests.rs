@@ -0,0 +1,110 @@
+use std::collections::BTreeSet;
+
+use nimbus_auth_shared::types::SessionExpirationSeconds;
+use time::OffsetDateTime;
+
+use crate::{
+    entities::{
+        role::value_objects::{permission::Permission, role_name::RoleName},
+        session::{SomeSession, errors::SessionError, specifications::NewSessionSpecification},
+        user::value_objects::user_name::UserName,
+    },
+    value_objects::{
+        identifier::{Identifier, IdentifierOfType},
+        user_claims::UserClaims,
+    },
+};
+
+const SESSION_EXPIRATION_SECONDS: usize = 60;
+
+fn get_claims(roles: &[&str], permissions: &[&str]) -> UserClaims {
+    UserClaims::new(
+        Identifier::new(),
+        UserName::from("validuser123").unwrap(),
+        roles
+            .iter()
+            .map(|role| RoleName::from(role).unwrap())
+            .collect::<BTreeSet<_>>(),
+        permissions
+            .iter()
+            .map(|permission| Permission::from(permission).unwrap())
+            .collect::<BTreeSet<_>>(),
+    )
+}
+
+#[test]
+fn refreshed_session_carries_new_claims() {
+    let current_time = OffsetDateTime::now_utc();
+    let session = SomeSession::new(NewSessionSpecification {
+        user_claims: get_claims(&["admin"], &["auth:users:manage"]),
+        client_id: None,
+        current_time,
+        expiration_seconds: SessionExpirationSeconds(SESSION_EXPIRATION_SECONDS),
+    });
+
+    let demoted_claims = get_claims(&["default"], &[]);
+    let (_, new_session) = session
+        .refresh(
+            None,
+            demoted_claims,
+            current_time,
+            SessionExpirationSeconds(SESSION_EXPIRATION_SECONDS),
+        )
+        .unwrap();
+
+    assert!(new_session.user_claims().permissions().is_empty());
+    assert_eq!(
+        new_session.user_claims().roles(),
+        &BTreeSet::from([RoleName::from("default").unwrap()])
+    );
+}
+
+#[test]
+fn refreshed_session_stays_with_its_client() {
+    let current_time = OffsetDateTime::now_utc();
+    let client_id = Identifier::new();
+    let session = SomeSession::new(NewSessionSpecification {
+        user_claims: get_claims(&["default"], &[]),
+        client_id: Some(client_id.clone()),
+        current_time,
+        expiration_seconds: SessionExpirationSeconds(SESSION_EXPIRATION_SECONDS),
+    });
+
+    let (_, new_session) = session
+        .refresh(
+            Some(&client_id),
+            get_claims(&["default"], &[]),
+            current_time,
+            SessionExpirationSeconds(SESSION_EXPIRATION_SECONDS),
+        )
+        .unwrap();
+
+    assert_eq!(new_session.client_id(), Some(&client_id));
+}
+
+#[test]
+fn session_of_client_is_not_refreshed_by_another_client() {
+    let current_time = OffsetDateTime::now_utc();
+    let session = SomeSession::new(NewSessionSpecification {
+        user_claims: get_claims(&["default"], &[]),
+        client_id: Some(Identifier::new()),
+        current_time,
+        expiration_seconds: SessionExpirationSeconds(SESSION_EXPIRATION_SECONDS),
+    });
+
+    let result = session.clone().refresh(
+        Some(&Identifier::new()),
+        get_claims(&["default"], &[]),
+        current_time,
+        SessionExpirationSeconds(SESSION_EXPIRATION_SECONDS),
+    );
+    assert!(matches!(result, Err(SessionError::ClientMismatch)));
+
+    let result = session.refresh(
+        None,
+        get_claims(&["default"], &[]),
+        current_time,
+        SessionExpirationSeconds(SESSION_EXPIRATION_SECONDS),
+    );
+    assert!(matches!(result, Err(SessionError::ClientMismatch)));
+}
@@ -1,12 +1,25 @@
-use nimbus_auth_shared::types::UserRole;
+use std::{borrow::Cow, collections::BTreeSet};
+
+use nimbus_auth_shared::types::{SigninLockoutPolicy, UserStatus};
+use time::OffsetDateTime;
 use ulid::Ulid;
 
 use crate::{
     entities::{
         Entity,
+        group::Group,
+        role::{
+            Role,
+            value_objects::{permission::Permission, role_name::RoleName},
+        },
         user::{
-            specifications::{NewUserSpecification, RestoreUserSpecification},
-            value_objects::{password_hash::PasswordHash, user_name::UserName},
+            specifications::{
+                NewUserSpecification, RestoreUserSpecification, RestoreUserStateSpecification,
+            },
+            value_objects::{
+                password_hash::PasswordHash, signin_lockout::SigninLockout,
+                user_attributes::UserAttributes, user_name::UserName,
+            },
         },
     },
     value_objects::{
@@ -17,15 +30,47 @@ use crate::{
 
 pub mod errors;
 pub mod specifications;
+#[cfg(test)]
+mod tests;
 pub mod value_objects;
 
+pub trait UserState {}
+
+#[derive(Debug, Clone)]
+pub struct Active {}
+
+#[derive(Debug, Clone)]
+pub struct Suspended {
+    reason: String,
+    suspended_until: Option<OffsetDateTime>,
+}
+
+/// Soft deleted user, its name stays taken
+#[derive(Debug, Clone)]
+pub struct Deleted {
+    deleted_at: OffsetDateTime,
+}
+
+impl UserState for Active {}
+impl UserState for Suspended {}
+impl UserState for Deleted {}
+
 #[derive(Debug, Clone)]
-pub struct User {
+pub struct User<State: UserState = Active> {
     claims: UserClaims,
     password_hash: PasswordHash,
+    signin_lockout: SigninLockout,
+    state: State,
 }
 
-impl Entity<Ulid> for User {
+#[derive(Debug, Clone)]
+pub enum SomeUser<'a> {
+    Active(Cow<'a, User<Active>>),
+    Suspended(Cow<'a, User<Suspended>>),
+    Deleted(Cow<'a, User<Deleted>>),
+}
+
+impl<State: UserState> Entity<Ulid> for User<State> {
     type Id = Identifier<Ulid, User>;
 
     fn id(&self) -> &Self::Id {
@@ -33,27 +78,192 @@ impl Entity<Ulid> for User {
     }
 }
 
-impl User {
-    pub fn new(specs: NewUserSpecification) -> Self {
-        Self {
-            claims: UserClaims::new(Identifier::new(), specs.user_name, UserRole::Default),
-            password_hash: specs.password_hash,
+impl Entity<Ulid> for SomeUser<'_> {
+    type Id = Identifier<Ulid, User>;
+
+    fn id(&self) -> &Self::Id {
+        self.claims().id()
+    }
+}
+
+impl SomeUser<'_> {
+    pub fn restore(
+        RestoreUserSpecification {
+            claims,
+            password_hash,
+            signin_lockout,
+            state,
+        }: RestoreUserSpecification,
+    ) -> SomeUser<'static> {
+        match state {
+            RestoreUserStateSpecification::Active => SomeUser::from(User {
+                claims,
+                password_hash,
+                signin_lockout,
+                state: Active {},
+            }),
+            RestoreUserStateSpecification::Suspended {
+                reason,
+                suspended_until,
+            } => SomeUser::from(User {
+                claims,
+                password_hash,
+                signin_lockout,
+                state: Suspended {
+                    reason,
+                    suspended_until,
+                },
+            }),
+            RestoreUserStateSpecification::Deleted { deleted_at } => SomeUser::from(User {
+                claims,
+                password_hash,
+                signin_lockout,
+                state: Deleted { deleted_at },
+            }),
         }
     }
 
-    pub fn restore(specs: RestoreUserSpecification) -> Self {
-        Self {
-            claims: specs.claims,
-            password_hash: specs.password_hash,
+    pub fn into_owned(self) -> SomeUser<'static> {
+        match self {
+            SomeUser::Active(cow) => SomeUser::Active(Cow::Owned(cow.into_owned())),
+            SomeUser::Suspended(cow) => SomeUser::Suspended(Cow::Owned(cow.into_owned())),
+            SomeUser::Deleted(cow) => SomeUser::Deleted(Cow::Owned(cow.into_owned())),
+        }
+    }
+
+    pub fn status(&self) -> UserStatus {
+        match self {
+            SomeUser::Active(_) => UserStatus::Active,
+            SomeUser::Suspended(_) => UserStatus::Suspended,
+            SomeUser::Deleted(_) => UserStatus::Deleted,
+        }
+    }
+
+    pub fn claims(&self) -> &UserClaims {
+        match self {
+            SomeUser::Active(user) => user.claims(),
+            SomeUser::Suspended(user) => user.claims(),
+            SomeUser::Deleted(user) => user.claims(),
         }
     }
 
+    pub fn name(&self) -> &UserName {
+        self.claims().name()
+    }
+
+    pub fn roles(&self) -> &BTreeSet<RoleName> {
+        self.claims().roles()
+    }
+
+    pub fn permissions(&self) -> &BTreeSet<Permission> {
+        self.claims().permissions()
+    }
+
+    pub fn groups(&self) -> &[Group] {
+        self.claims().groups()
+    }
+
+    pub fn attributes(&self) -> &UserAttributes {
+        self.claims().attributes()
+    }
+
+    pub fn password_hash(&self) -> &PasswordHash {
+        match self {
+            SomeUser::Active(user) => user.password_hash(),
+            SomeUser::Suspended(user) => user.password_hash(),
+            SomeUser::Deleted(user) => user.password_hash(),
+        }
+    }
+
+    pub fn signin_lockout(&self) -> &SigninLockout {
+        match self {
+            SomeUser::Active(user) => user.signin_lockout(),
+            SomeUser::Suspended(user) => user.signin_lockout(),
+            SomeUser::Deleted(user) => user.signin_lockout(),
+        }
+    }
+
+    pub fn with_roles(self, roles: &[Role]) -> SomeUser<'static> {
+        match self {
+            SomeUser::Active(user) => SomeUser::from(user.into_owned().with_roles(roles)),
+            SomeUser::Suspended(user) => SomeUser::from(user.into_owned().with_roles(roles)),
+            SomeUser::Deleted(user) => SomeUser::from(user.into_owned().with_roles(roles)),
+        }
+    }
+
+    pub fn with_groups(self, groups: &[Group]) -> SomeUser<'static> {
+        match self {
+            SomeUser::Active(user) => SomeUser::from(user.into_owned().with_groups(groups)),
+            SomeUser::Suspended(user) => SomeUser::from(user.into_owned().with_groups(groups)),
+            SomeUser::Deleted(user) => SomeUser::from(user.into_owned().with_groups(groups)),
+        }
+    }
+
+    pub fn with_attributes(self, attributes: UserAttributes) -> SomeUser<'static> {
+        match self {
+            SomeUser::Active(user) => SomeUser::from(user.into_owned().with_attributes(attributes)),
+            SomeUser::Suspended(user) => {
+                SomeUser::from(user.into_owned().with_attributes(attributes))
+            }
+            SomeUser::Deleted(user) => {
+                SomeUser::from(user.into_owned().with_attributes(attributes))
+            }
+        }
+    }
+
+    pub fn with_failed_signin_attempt(
+        self,
+        current_time: OffsetDateTime,
+        policy: &SigninLockoutPolicy,
+    ) -> SomeUser<'static> {
+        match self {
+            SomeUser::Active(user) => SomeUser::from(
+                user.into_owned()
+                    .with_failed_signin_attempt(current_time, policy),
+            ),
+            SomeUser::Suspended(user) => SomeUser::from(
+                user.into_owned()
+                    .with_failed_signin_attempt(current_time, policy),
+            ),
+            SomeUser::Deleted(user) => SomeUser::from(
+                user.into_owned()
+                    .with_failed_signin_attempt(current_time, policy),
+            ),
+        }
+    }
+
+    pub fn with_signin_lockout_reset(self) -> SomeUser<'static> {
+        match self {
+            SomeUser::Active(user) => SomeUser::from(user.into_owned().with_signin_lockout_reset()),
+            SomeUser::Suspended(user) => {
+                SomeUser::from(user.into_owned().with_signin_lockout_reset())
+            }
+            SomeUser::Deleted(user) => {
+                SomeUser::from(user.into_owned().with_signin_lockout_reset())
+            }
+        }
+    }
+}
+
+impl<State: UserState> User<State> {
     pub fn name(&self) -> &UserName {
         self.claims.name()
     }
 
-    pub fn role(&self) -> &UserRole {
-        self.claims.role()
+    pub fn roles(&self) -> &BTreeSet<RoleName> {
+        self.claims.roles()
+    }
+
+    pub fn permissions(&self) -> &BTreeSet<Permission> {
+        self.claims.permissions()
+    }
+
+    pub fn groups(&self) -> &[Group] {
+        self.claims.groups()
+    }
+
+    pub fn attributes(&self) -> &UserAttributes {
+        self.claims.attributes()
     }
 
     pub fn password_hash(&self) -> &PasswordHash {
@@ -64,10 +274,173 @@ impl User {
         &self.claims
     }
 
-    pub fn with_new_role(self, role: UserRole) -> Self {
-        Self::restore(RestoreUserSpecification {
-            claims: UserClaims::new(self.claims.id().clone(), self.claims.name().clone(), role),
+    pub fn signin_lockout(&self) -> &SigninLockout {
+        &self.signin_lockout
+    }
+
+    /// Replaces the roles, permissions are resolved from the given roles
+    pub fn with_roles(self, roles: &[Role]) -> Self {
+        Self {
+            claims: get_claims(self.claims.id().clone(), self.claims.name().clone(), roles)
+                .with_groups(self.claims.groups().to_vec())
+                .with_attributes(self.claims.attributes().clone()),
+            ..self
+        }
+    }
+
+    /// Replaces the group memberships
+    pub fn with_groups(self, groups: &[Group]) -> Self {
+        Self {
+            claims: self.claims.with_groups(groups.to_vec()),
+            ..self
+        }
+    }
+
+    /// Replaces all custom attributes
+    pub fn with_attributes(self, attributes: UserAttributes) -> Self {
+        Self {
+            claims: self.claims.with_attributes(attributes),
+            ..self
+        }
+    }
+
+    pub fn with_failed_signin_attempt(
+        self,
+        current_time: OffsetDateTime,
+        policy: &SigninLockoutPolicy,
+    ) -> Self {
+        Self {
+            signin_lockout: self
+                .signin_lockout
+                .with_failed_attempt(current_time, policy),
+            ..self
+        }
+    }
+
+    pub fn with_signin_lockout_reset(self) -> Self {
+        Self {
+            signin_lockout: SigninLockout::default(),
+            ..self
+        }
+    }
+}
+
+impl User<Active> {
+    pub fn new(specs: NewUserSpecification) -> Self {
+        Self {
+            claims: get_claims(Identifier::new(), specs.user_name, &specs.roles),
+            password_hash: specs.password_hash,
+            signin_lockout: SigninLockout::default(),
+            state: Active {},
+        }
+    }
+
+    pub fn with_new_password_hash(self, password_hash: PasswordHash) -> Self {
+        Self {
+            password_hash,
+            ..self
+        }
+    }
+
+    /// Suspends until the given time or indefinitely if it is not set
+    pub fn suspend(
+        self,
+        reason: String,
+        suspended_until: Option<OffsetDateTime>,
+    ) -> User<Suspended> {
+        User {
+            claims: self.claims,
+            password_hash: self.password_hash,
+            signin_lockout: self.signin_lockout,
+            state: Suspended {
+                reason,
+                suspended_until,
+            },
+        }
+    }
+
+    pub fn delete(self, current_time: OffsetDateTime) -> User<Deleted> {
+        User {
+            claims: self.claims,
+            password_hash: self.password_hash,
+            signin_lockout: self.signin_lockout,
+            state: Deleted {
+                deleted_at: current_time,
+            },
+        }
+    }
+}
+
+impl User<Suspended> {
+    pub fn reason(&self) -> &str {
+        &self.state.reason
+    }
+
+    pub fn suspended_until(&self) -> Option<OffsetDateTime> {
+        self.state.suspended_until
+    }
+
+    pub fn is_suspension_over(&self, current_time: OffsetDateTime) -> bool {
+        self.state
+            .suspended_until
+            .is_some_and(|suspended_until| suspended_until <= current_time)
+    }
+
+    pub fn unsuspend(self) -> User<Active> {
+        User {
+            claims: self.claims,
+            password_hash: self.password_hash,
+            signin_lockout: self.signin_lockout,
+            state: Active {},
+        }
+    }
+
+    pub fn delete(self, current_time: OffsetDateTime) -> User<Deleted> {
+        User {
+            claims: self.claims,
             password_hash: self.password_hash,
-        })
+            signin_lockout: self.signin_lockout,
+            state: Deleted {
+                deleted_at: current_time,
+            },
+        }
+    }
+}
+
+impl User<Deleted> {
+    pub fn deleted_at(&self) -> OffsetDateTime {
+        self.state.deleted_at
     }
 }
+
+fn get_claims(id: Identifier<Ulid, User>, name: UserName, roles: &[Role]) -> UserClaims {
+    UserClaims::new(
+        id,
+        name,
+        roles.iter().map(|role| role.name().clone()).collect(),
+        roles
+            .iter()
+            .flat_map(|role| role.permissions().iter().cloned())
+            .collect(),
+    )
+}
+
+macro_rules! impl_user_froms {
+    ($state:ty, $variant:ident) => {
+        impl From<User<$state>> for SomeUser<'static> {
+            fn from(user: User<$state>) -> Self {
+                SomeUser::$variant(Cow::Owned(user))
+            }
+        }
+
+        impl<'a> From<&'a User<$state>> for SomeUser<'a> {
+            fn from(user: &'a User<$state>) -> Self {
+                SomeUser::$variant(Cow::Borrowed(user))
+            }
+        }
+    };
+}
+
+impl_user_froms!(Active, Active);
+impl_user_froms!(Suspended, Suspended);
+impl_user_froms!(Deleted, Deleted);
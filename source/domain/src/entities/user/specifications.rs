@@ -1,14 +1,35 @@
+use time::OffsetDateTime;
+
 use crate::{
-    entities::user::value_objects::{password_hash::PasswordHash, user_name::UserName},
+    entities::{
+        role::Role,
+        user::value_objects::{
+            password_hash::PasswordHash, signin_lockout::SigninLockout, user_name::UserName,
+        },
+    },
     value_objects::user_claims::UserClaims,
 };
 
 pub struct NewUserSpecification {
     pub user_name: UserName,
     pub password_hash: PasswordHash,
+    pub roles: Vec<Role>,
 }
 
 pub struct RestoreUserSpecification {
     pub claims: UserClaims,
     pub password_hash: PasswordHash,
+    pub signin_lockout: SigninLockout,
+    pub state: RestoreUserStateSpecification,
+}
+
+pub enum RestoreUserStateSpecification {
+    Active,
+    Suspended {
+        reason: String,
+        suspended_until: Option<OffsetDateTime>,
+    },
+    Deleted {
+        deleted_at: OffsetDateTime,
+    },
 }
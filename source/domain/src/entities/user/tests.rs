@@ -0,0 +1,119 @@
+use nimbus_auth_shared::types::UserStatus;
+use time::{Duration, OffsetDateTime};
+
+use crate::entities::{
+    Entity,
+    group::{Group, specifications::NewGroupSpecification, value_objects::group_name::GroupName},
+    role::{
+        Role,
+        specifications::NewRoleSpecification,
+        value_objects::{permission::Permission, role_name::RoleName},
+    },
+    user::{
+        SomeUser, User,
+        specifications::NewUserSpecification,
+        value_objects::{password_hash::PasswordHash, user_name::UserName},
+    },
+};
+
+fn get_user() -> User {
+    User::new(NewUserSpecification {
+        user_name: UserName::from("validuser123").unwrap(),
+        password_hash: PasswordHash::from(
+            "$argon2id$v=19$m=19456,t=2,p=1$c29tZXNhbHRzb21lc2FsdA$wGQuMV0mwhdsBYZ2yz4Wlk0eaEWqS7Ev3ut5FTWxi3U",
+        )
+        .unwrap(),
+        roles: Vec::new(),
+    })
+}
+
+fn get_role(name: &str, permissions: &[&str]) -> Role {
+    Role::new(NewRoleSpecification {
+        name: RoleName::from(name).unwrap(),
+        permissions: permissions
+            .iter()
+            .map(|permission| Permission::from(permission).unwrap())
+            .collect(),
+    })
+}
+
+fn get_group(name: &str) -> Group {
+    Group::new(NewGroupSpecification {
+        name: GroupName::from(name).unwrap(),
+    })
+}
+
+#[test]
+fn suspension_keeps_identity() {
+    let user = get_user();
+    let id = user.id().clone();
+    let suspended_user = user.suspend("abuse".to_string(), None);
+    assert_eq!(suspended_user.id(), &id);
+    assert_eq!(suspended_user.reason(), "abuse");
+    assert_eq!(
+        SomeUser::from(&suspended_user).status(),
+        UserStatus::Suspended
+    );
+}
+
+#[test]
+fn indefinite_suspension_is_never_over() {
+    let suspended_user = get_user().suspend("abuse".to_string(), None);
+    assert!(!suspended_user.is_suspension_over(OffsetDateTime::now_utc() + Duration::days(3650)));
+}
+
+#[test]
+fn temporary_suspension_is_over_after_its_end() {
+    let now = OffsetDateTime::now_utc();
+    let suspended_user = get_user().suspend("abuse".to_string(), Some(now + Duration::hours(1)));
+    assert!(!suspended_user.is_suspension_over(now));
+    assert!(suspended_user.is_suspension_over(now + Duration::hours(1)));
+}
+
+#[test]
+fn deleted_user_keeps_identity_and_password_hash() {
+    let now = OffsetDateTime::now_utc();
+    let user = get_user();
+    let id = user.id().clone();
+    let password_hash = user.password_hash().value().to_string();
+    let deleted_user = user.suspend("abuse".to_string(), None).delete(now);
+    assert_eq!(deleted_user.id(), &id);
+    assert_eq!(deleted_user.deleted_at(), now);
+    assert_eq!(deleted_user.password_hash().value(), password_hash);
+}
+
+#[test]
+fn permissions_are_resolved_from_roles() {
+    let user = get_user().with_roles(&[
+        get_role("support", &["auth:users:read", "billing:read"]),
+        get_role("billing", &["billing:read", "billing:refund"]),
+    ]);
+    let roles: Vec<&str> = user.roles().iter().map(RoleName::value).collect();
+    let permissions: Vec<&str> = user.permissions().iter().map(Permission::value).collect();
+    assert_eq!(roles, vec!["billing", "support"]);
+    assert_eq!(
+        permissions,
+        vec!["auth:users:read", "billing:read", "billing:refund"]
+    );
+}
+
+#[test]
+fn replacing_roles_drops_their_permissions() {
+    let user = get_user()
+        .with_roles(&[get_role("admin", &["auth:users:manage"])])
+        .with_roles(&[get_role("default", &[])]);
+    assert!(user.permissions().is_empty());
+}
+
+#[test]
+fn replacing_roles_keeps_groups() {
+    let user = get_user()
+        .with_groups(&[get_group("platform"), get_group("backend")])
+        .with_roles(&[get_role("default", &[])]);
+    let group_names: Vec<String> = user
+        .groups()
+        .iter()
+        .map(|group| group.name().to_string())
+        .collect();
+    assert_eq!(group_names, ["backend", "platform"]);
+}
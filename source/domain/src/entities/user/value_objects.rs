@@ -1,3 +1,5 @@
 pub mod password;
 pub mod password_hash;
+pub mod signin_lockout;
+pub mod user_attributes;
 pub mod user_name;
@@ -1,7 +1,11 @@
-use nimbus_auth_shared::constants::{PASSWORD_MAX_LENGTH_INCLUSIVE, PASSWORD_MIN_LENGTH_INCLUSIVE};
+use nimbus_auth_shared::types::{PasswordCharacterClass, PasswordPolicy};
 use zeroize::Zeroizing;
+use zxcvbn::zxcvbn;
 
-use crate::entities::user::value_objects::password::errors::PasswordError;
+use crate::{
+    entities::user::value_objects::password::errors::{PasswordError, PasswordPolicyViolation},
+    value_objects::breached_passwords_filter::BreachedPasswordsFilter,
+};
 
 pub mod errors;
 #[cfg(test)]
@@ -13,6 +17,7 @@ pub struct Password {
 }
 
 struct PasswordCharsValidation {
+    length: usize,
     has_ascii_uppercase: bool,
     has_ascii_lowercase: bool,
     has_ascii_digit: bool,
@@ -24,6 +29,7 @@ struct PasswordCharsValidation {
 impl PasswordCharsValidation {
     pub fn new() -> Self {
         Self {
+            length: 0,
             has_ascii_uppercase: false,
             has_ascii_lowercase: false,
             has_ascii_digit: false,
@@ -34,6 +40,7 @@ impl PasswordCharsValidation {
     }
 
     pub fn validate_next_char(mut self, ch: char) -> Self {
+        self.length += 1;
         self.has_ascii_uppercase = self.has_ascii_uppercase || ch.is_ascii_uppercase();
         self.has_ascii_lowercase = self.has_ascii_lowercase || ch.is_ascii_lowercase();
         self.has_ascii_digit = self.has_ascii_digit || ch.is_ascii_digit();
@@ -43,49 +50,102 @@ impl PasswordCharsValidation {
         self
     }
 
-    pub fn validate(self) -> Result<(), PasswordError> {
-        if !(self.has_ascii_uppercase
-            && self.has_ascii_lowercase
-            && self.has_ascii_digit
-            && self.has_ascii_punctuation)
-        {
-            return Err(PasswordError::TooWeak);
+    fn has_character_class(&self, character_class: PasswordCharacterClass) -> bool {
+        match character_class {
+            PasswordCharacterClass::Uppercase => self.has_ascii_uppercase,
+            PasswordCharacterClass::Lowercase => self.has_ascii_lowercase,
+            PasswordCharacterClass::Digit => self.has_ascii_digit,
+            PasswordCharacterClass::Punctuation => self.has_ascii_punctuation,
         }
+    }
+
+    pub fn validate(self, policy: &PasswordPolicy) -> Vec<PasswordPolicyViolation> {
+        let mut violations = Vec::new();
 
-        if self.has_non_ascii || self.has_spaces {
-            return Err(PasswordError::InvalidCharacters);
+        if self.length < policy.min_length {
+            violations.push(PasswordPolicyViolation::TooShort {
+                min_length: policy.min_length,
+            });
         }
+        if self.length > policy.max_length {
+            violations.push(PasswordPolicyViolation::TooLong {
+                max_length: policy.max_length,
+            });
+        }
+
+        violations.extend(
+            policy
+                .required_character_classes
+                .iter()
+                .filter(|character_class| !self.has_character_class(**character_class))
+                .map(|character_class| {
+                    PasswordPolicyViolation::MissingCharacterClass(*character_class)
+                }),
+        );
 
-        Ok(())
+        if self.has_non_ascii && !policy.allow_unicode {
+            violations.push(PasswordPolicyViolation::NonAsciiCharacters);
+        }
+        if self.has_spaces && !policy.allow_spaces {
+            violations.push(PasswordPolicyViolation::Spaces);
+        }
+
+        violations
     }
 }
 
 impl Password {
-    pub fn from(value: &Zeroizing<String>) -> Result<Self, PasswordError> {
-        Self::validate(value)?;
+    pub fn from(
+        value: &Zeroizing<String>,
+        policy: &PasswordPolicy,
+        breached_passwords_filter: &BreachedPasswordsFilter,
+    ) -> Result<Self, PasswordError> {
+        Self::validate(value, policy, breached_passwords_filter)?;
         Ok(Self {
             value: value.clone(),
         })
     }
 
-    fn validate(value: &str) -> Result<(), PasswordError> {
-        let length = value.len();
-        if length < PASSWORD_MIN_LENGTH_INCLUSIVE {
-            return Err(PasswordError::TooShort {
-                min_length: PASSWORD_MIN_LENGTH_INCLUSIVE,
-            });
-        }
-        if length > PASSWORD_MAX_LENGTH_INCLUSIVE {
-            return Err(PasswordError::TooLong {
-                max_length: PASSWORD_MAX_LENGTH_INCLUSIVE,
-            });
+    /// Wraps a password without checking it against a policy
+    ///
+    /// Should be used only to verify a password against an existing hash, because the policy could have been changed since the hash was created
+    pub fn from_unvalidated(value: &Zeroizing<String>) -> Self {
+        Self {
+            value: value.clone(),
         }
-        value
+    }
+
+    fn validate(
+        value: &str,
+        policy: &PasswordPolicy,
+        breached_passwords_filter: &BreachedPasswordsFilter,
+    ) -> Result<(), PasswordError> {
+        let mut violations = value
             .chars()
             .fold(PasswordCharsValidation::new(), |checks, ch| {
                 checks.validate_next_char(ch)
             })
-            .validate()
+            .validate(policy);
+
+        if breached_passwords_filter.contains(value) {
+            violations.push(PasswordPolicyViolation::Breached);
+        }
+
+        // strength estimation is relatively expensive so it runs only for passwords passing other checks
+        if policy.min_strength_score > 0 && violations.is_empty() {
+            let score = u8::from(zxcvbn(value, &[]).score());
+            if score < policy.min_strength_score {
+                violations.push(PasswordPolicyViolation::TooWeak {
+                    score,
+                    min_score: policy.min_strength_score,
+                });
+            }
+        }
+
+        match violations.is_empty() {
+            true => Ok(()),
+            false => Err(PasswordError::PolicyViolation { violations }),
+        }
     }
 
     pub fn value(&self) -> &str {
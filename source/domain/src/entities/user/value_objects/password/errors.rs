@@ -1,17 +1,30 @@
+use nimbus_auth_shared::types::PasswordCharacterClass;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
 pub enum PasswordError {
+    #[error("password does not satisfy password policy: {}", violations.iter().map(|violation| violation.to_string()).collect::<Vec<_>>().join("; "))]
+    PolicyViolation {
+        violations: Vec<PasswordPolicyViolation>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum PasswordPolicyViolation {
     #[error("password is too short, should be more than or equal to {min_length}")]
     TooShort { min_length: usize },
     #[error("password is too long, should be less than or equal to {max_length}")]
     TooLong { max_length: usize },
+    #[error("password should contain at least one character of class: {0}")]
+    MissingCharacterClass(PasswordCharacterClass),
+    #[error("password contains non ASCII characters")]
+    NonAsciiCharacters,
+    #[error("password contains spaces")]
+    Spaces,
     #[error(
-        "password is too weak, it should contain at least one uppercase, one lowercase, one digit and one punctuation characters"
-    )]
-    TooWeak,
-    #[error(
-        "password contains invalid characters. it should contain only English alphanumeric and punctuation characters"
+        "password is too weak, its strength score is {score} and should be at least {min_score}"
     )]
-    InvalidCharacters,
+    TooWeak { score: u8, min_score: u8 },
+    #[error("password appears in a known data breach")]
+    Breached,
 }
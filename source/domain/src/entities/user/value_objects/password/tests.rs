@@ -1,40 +1,156 @@
-use nimbus_auth_shared::constants::{PASSWORD_MAX_LENGTH_INCLUSIVE, PASSWORD_MIN_LENGTH_INCLUSIVE};
+use nimbus_auth_shared::{
+    constants::{PASSWORD_MAX_LENGTH_DEFAULT, PASSWORD_MIN_LENGTH_DEFAULT},
+    types::{PasswordCharacterClass, PasswordPolicy},
+};
 use zeroize::Zeroizing;
 
-use crate::entities::user::value_objects::password::{Password, errors::PasswordError};
+use crate::{
+    entities::user::value_objects::password::{
+        Password,
+        errors::{PasswordError, PasswordPolicyViolation},
+    },
+    value_objects::breached_passwords_filter::BreachedPasswordsFilter,
+};
+
+fn get_violations(result: Result<Password, PasswordError>) -> Vec<PasswordPolicyViolation> {
+    match result {
+        Ok(_) => Vec::new(),
+        Err(PasswordError::PolicyViolation { violations }) => violations,
+    }
+}
 
 #[test]
 fn valid_password() {
-    let result = Password::from(&Zeroizing::new("StrongPassword123!".to_string()));
+    let result = Password::from(
+        &Zeroizing::new("StrongPassword123!".to_string()),
+        &PasswordPolicy::default(),
+        &BreachedPasswordsFilter::empty(),
+    );
     assert!(result.is_ok())
 }
 
 #[test]
 fn short_password() {
-    let short_password_str = "A".repeat(PASSWORD_MIN_LENGTH_INCLUSIVE - 1);
-    let result = Password::from(&Zeroizing::new(short_password_str.to_string()));
-    assert!(matches!(
-        result,
-        Err(PasswordError::TooShort {
-            min_length: PASSWORD_MIN_LENGTH_INCLUSIVE
+    let short_password_str = "A".repeat(PASSWORD_MIN_LENGTH_DEFAULT - 1);
+    let result = Password::from(
+        &Zeroizing::new(short_password_str.to_string()),
+        &PasswordPolicy::default(),
+        &BreachedPasswordsFilter::empty(),
+    );
+    assert!(
+        get_violations(result).contains(&PasswordPolicyViolation::TooShort {
+            min_length: PASSWORD_MIN_LENGTH_DEFAULT
         })
-    ))
+    )
 }
 
 #[test]
 fn long_password() {
-    let long_password_str = "A".repeat(PASSWORD_MAX_LENGTH_INCLUSIVE + 1);
-    let result = Password::from(&Zeroizing::new(long_password_str.to_string()));
-    assert!(matches!(
-        result,
-        Err(PasswordError::TooLong {
-            max_length: PASSWORD_MAX_LENGTH_INCLUSIVE
+    let long_password_str = "A".repeat(PASSWORD_MAX_LENGTH_DEFAULT + 1);
+    let result = Password::from(
+        &Zeroizing::new(long_password_str.to_string()),
+        &PasswordPolicy::default(),
+        &BreachedPasswordsFilter::empty(),
+    );
+    assert!(
+        get_violations(result).contains(&PasswordPolicyViolation::TooLong {
+            max_length: PASSWORD_MAX_LENGTH_DEFAULT
         })
-    ))
+    )
 }
 
 #[test]
 fn weak_password() {
-    let result = Password::from(&Zeroizing::new("SimplePassword".to_string()));
-    assert!(matches!(result, Err(PasswordError::TooWeak)))
+    let result = Password::from(
+        &Zeroizing::new("SimplePassword".to_string()),
+        &PasswordPolicy::default(),
+        &BreachedPasswordsFilter::empty(),
+    );
+    assert_eq!(
+        get_violations(result),
+        vec![
+            PasswordPolicyViolation::MissingCharacterClass(PasswordCharacterClass::Digit),
+            PasswordPolicyViolation::MissingCharacterClass(PasswordCharacterClass::Punctuation),
+        ]
+    )
+}
+
+#[test]
+fn passphrase_rejected_by_default_policy() {
+    let result = Password::from(
+        &Zeroizing::new("correct horse battery stäple".to_string()),
+        &PasswordPolicy::default(),
+        &BreachedPasswordsFilter::empty(),
+    );
+    let violations = get_violations(result);
+    assert!(violations.contains(&PasswordPolicyViolation::Spaces));
+    assert!(violations.contains(&PasswordPolicyViolation::NonAsciiCharacters));
+}
+
+#[test]
+fn passphrase_allowed_by_relaxed_policy() {
+    let policy = PasswordPolicy {
+        max_length: 64,
+        required_character_classes: Vec::new(),
+        allow_unicode: true,
+        allow_spaces: true,
+        ..PasswordPolicy::default()
+    };
+    let result = Password::from(
+        &Zeroizing::new("correct horse battery stäple".to_string()),
+        &policy,
+        &BreachedPasswordsFilter::empty(),
+    );
+    assert!(result.is_ok())
+}
+
+#[test]
+fn length_counts_characters_not_bytes() {
+    let policy = PasswordPolicy {
+        min_length: 4,
+        max_length: 4,
+        required_character_classes: Vec::new(),
+        allow_unicode: true,
+        ..PasswordPolicy::default()
+    };
+    let result = Password::from(
+        &Zeroizing::new("äöüß".to_string()),
+        &policy,
+        &BreachedPasswordsFilter::empty(),
+    );
+    assert!(result.is_ok())
+}
+
+#[test]
+fn password_below_min_strength_score() {
+    let policy = PasswordPolicy {
+        min_strength_score: 3,
+        ..PasswordPolicy::default()
+    };
+    let result = Password::from(
+        &Zeroizing::new("Password123!".to_string()),
+        &policy,
+        &BreachedPasswordsFilter::empty(),
+    );
+    assert!(matches!(
+        get_violations(result).as_slice(),
+        [PasswordPolicyViolation::TooWeak { min_score: 3, .. }]
+    ))
+}
+
+#[test]
+fn breached_password() {
+    let breached_passwords_filter =
+        BreachedPasswordsFilter::from_sha1_digests([BreachedPasswordsFilter::get_sha1_digest(
+            "StrongPassword123!",
+        )]);
+    let result = Password::from(
+        &Zeroizing::new("StrongPassword123!".to_string()),
+        &PasswordPolicy::default(),
+        &breached_passwords_filter,
+    );
+    assert_eq!(
+        get_violations(result),
+        vec![PasswordPolicyViolation::Breached]
+    )
 }
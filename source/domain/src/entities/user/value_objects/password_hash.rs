@@ -1,27 +1,77 @@
-use std::fmt::Display;
+use std::{fmt::Display, str::FromStr};
 
 use argon2::{
-    Argon2, PasswordVerifier,
+    Algorithm, Argon2, KeyId, Params, ParamsBuilder, PasswordVerifier, Version,
     password_hash::{PasswordHasher, SaltString},
 };
+use bcrypt::HashParts;
+use nimbus_auth_shared::types::PasswordHashingParams;
+use pbkdf2::Pbkdf2;
+use scrypt::Scrypt;
+use zeroize::Zeroizing;
 
-use crate::entities::user::value_objects::{
-    password::Password, password_hash::errors::PasswordHashError,
+use crate::{
+    entities::user::value_objects::{password::Password, password_hash::errors::PasswordHashError},
+    value_objects::password_peppers::PasswordPeppers,
 };
 
 pub mod errors;
 #[cfg(test)]
 mod tests;
 
+const DUMMY_PASSWORD: &str = "nimbus-auth-dummy-password";
+
 #[derive(Clone, Debug)]
 pub struct PasswordHash {
     value: String,
 }
 
+/// PHC hash schemes accepted for verification, only Argon2id is used for new hashes
+///
+/// PBKDF2, scrypt and bcrypt (which is not a PHC string) are supported for users imported from legacy systems,
+/// their hashes are upgraded on signin
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum PhcScheme {
+    Argon2,
+    Pbkdf2,
+    Scrypt,
+}
+
 impl PasswordHash {
-    pub fn hash(password: Password, salt_b64: &str) -> Result<Self, PasswordHashError> {
+    /// Hashes password with the current pepper if any, its version is stored in the `keyid` parameter of the hash
+    pub fn hash(
+        password: Password,
+        salt_b64: &str,
+        params: &PasswordHashingParams,
+        peppers: &PasswordPeppers,
+    ) -> Result<Self, PasswordHashError> {
         let salt = SaltString::from_b64(salt_b64).map_err(|_| PasswordHashError::Salt)?;
-        let hash = Argon2::default()
+        let mut argon2_params = ParamsBuilder::new();
+        argon2_params
+            .m_cost(params.memory_cost_kib)
+            .t_cost(params.time_cost)
+            .p_cost(params.parallelism);
+
+        let argon2 = match peppers.current() {
+            Some((version, pepper)) => {
+                argon2_params
+                    .keyid(KeyId::new(&version.to_be_bytes()).map_err(PasswordHashError::Params)?);
+                Argon2::new_with_secret(
+                    pepper,
+                    Algorithm::Argon2id,
+                    Version::V0x13,
+                    argon2_params.build().map_err(PasswordHashError::Params)?,
+                )
+                .map_err(PasswordHashError::Params)?
+            }
+            None => Argon2::new(
+                Algorithm::Argon2id,
+                Version::V0x13,
+                argon2_params.build().map_err(PasswordHashError::Params)?,
+            ),
+        };
+
+        let hash = argon2
             .hash_password(password.value().as_bytes(), &salt)
             .map_err(|err| PasswordHashError::Hash(err))?;
         Ok(Self {
@@ -29,24 +79,150 @@ impl PasswordHash {
         })
     }
 
+    /// Hash of a fixed password for verifying credentials of unknown users,
+    /// so the signin of an unknown user takes as long as of an existing one
+    pub fn dummy(
+        salt_b64: &str,
+        params: &PasswordHashingParams,
+        peppers: &PasswordPeppers,
+    ) -> Result<Self, PasswordHashError> {
+        let password = Password::from_unvalidated(&Zeroizing::new(DUMMY_PASSWORD.to_string()));
+        Self::hash(password, salt_b64, params, peppers)
+    }
+
+    /// Accepts Argon2, PBKDF2-SHA256/SHA512 and scrypt PHC strings and bcrypt modular crypt strings
     pub fn from(value: &str) -> Result<Self, PasswordHashError> {
+        if Self::is_bcrypt(value) {
+            HashParts::from_str(value).map_err(PasswordHashError::Bcrypt)?;
+            return Ok(Self {
+                value: value.to_string(),
+            });
+        }
+
         let hash = argon2::password_hash::PasswordHash::new(value)
             .map_err(|err| PasswordHashError::Hash(err))?;
+        Self::get_phc_scheme(&hash)?;
         Ok(Self {
             value: hash.to_string(),
         })
     }
 
-    pub fn verify(&self, password: &Password) -> bool {
-        let hash = argon2::password_hash::PasswordHash::new(&self.value).unwrap();
-        Argon2::default()
+    pub fn verify(
+        &self,
+        password: &Password,
+        peppers: &PasswordPeppers,
+    ) -> Result<bool, PasswordHashError> {
+        if Self::is_bcrypt(&self.value) {
+            return bcrypt::verify(password.value().as_bytes(), &self.value)
+                .map_err(PasswordHashError::Bcrypt);
+        }
+
+        let hash = argon2::password_hash::PasswordHash::new(&self.value)
+            .map_err(PasswordHashError::Hash)?;
+
+        match Self::get_phc_scheme(&hash)? {
+            PhcScheme::Argon2 => {}
+            PhcScheme::Pbkdf2 => {
+                return Ok(Pbkdf2
+                    .verify_password(password.value().as_bytes(), &hash)
+                    .is_ok());
+            }
+            PhcScheme::Scrypt => {
+                return Ok(Scrypt
+                    .verify_password(password.value().as_bytes(), &hash)
+                    .is_ok());
+            }
+        }
+
+        let argon2 = match Self::get_pepper_version(&hash)? {
+            Some(version) => {
+                let pepper = peppers
+                    .get(version)
+                    .ok_or(PasswordHashError::UnknownPepperVersion { version })?;
+                Argon2::new_with_secret(
+                    pepper,
+                    Algorithm::default(),
+                    Version::default(),
+                    Params::default(),
+                )
+                .map_err(PasswordHashError::Params)?
+            }
+            None => Argon2::default(),
+        };
+
+        Ok(argon2
             .verify_password(password.value().as_bytes(), &hash)
-            .is_ok()
+            .is_ok())
+    }
+
+    /// Checks whether the hash was created with other algorithm, version, cost parameters or pepper than the current ones
+    pub fn needs_rehash(&self, params: &PasswordHashingParams, peppers: &PasswordPeppers) -> bool {
+        if Self::is_bcrypt(&self.value) {
+            return true;
+        }
+        let Ok(hash) = argon2::password_hash::PasswordHash::new(&self.value) else {
+            return true;
+        };
+        let Ok(hash_params) = Params::try_from(&hash) else {
+            return true;
+        };
+        let Ok(pepper_version) = Self::get_pepper_version(&hash) else {
+            return true;
+        };
+
+        hash.algorithm != Algorithm::Argon2id.ident()
+            || hash.version != Some(Version::V0x13.into())
+            || hash_params.m_cost() != params.memory_cost_kib
+            || hash_params.t_cost() != params.time_cost
+            || hash_params.p_cost() != params.parallelism
+            || pepper_version != peppers.current().map(|(version, _)| version)
     }
 
     pub fn value(&self) -> &str {
         &self.value
     }
+
+    fn is_bcrypt(value: &str) -> bool {
+        ["$2a$", "$2b$", "$2x$", "$2y$"]
+            .iter()
+            .any(|prefix| value.starts_with(prefix))
+    }
+
+    fn get_phc_scheme(
+        hash: &argon2::password_hash::PasswordHash,
+    ) -> Result<PhcScheme, PasswordHashError> {
+        if hash.algorithm == Algorithm::Argon2id.ident()
+            || hash.algorithm == Algorithm::Argon2i.ident()
+            || hash.algorithm == Algorithm::Argon2d.ident()
+        {
+            return Ok(PhcScheme::Argon2);
+        }
+        if hash.algorithm == pbkdf2::Algorithm::PBKDF2_SHA256_IDENT
+            || hash.algorithm == pbkdf2::Algorithm::PBKDF2_SHA512_IDENT
+        {
+            return Ok(PhcScheme::Pbkdf2);
+        }
+        if hash.algorithm == scrypt::ALG_ID {
+            return Ok(PhcScheme::Scrypt);
+        }
+        Err(PasswordHashError::UnsupportedAlgorithm {
+            algorithm: hash.algorithm.to_string(),
+        })
+    }
+
+    fn get_pepper_version(
+        hash: &argon2::password_hash::PasswordHash,
+    ) -> Result<Option<u32>, PasswordHashError> {
+        let hash_params = Params::try_from(hash).map_err(PasswordHashError::Hash)?;
+        match hash_params.keyid() {
+            [] => Ok(None),
+            keyid => Ok(Some(u32::from_be_bytes(
+                keyid
+                    .try_into()
+                    .map_err(|_| PasswordHashError::InvalidPepperVersion)?,
+            ))),
+        }
+    }
 }
 
 impl Display for PasswordHash {
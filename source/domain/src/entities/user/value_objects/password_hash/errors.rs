@@ -7,4 +7,14 @@ pub enum PasswordHashError {
     Salt,
     #[error("error while hashing")]
     Hash(password_hash::Error),
+    #[error("invalid hashing params")]
+    Params(argon2::Error),
+    #[error("unsupported password hash algorithm: {algorithm}")]
+    UnsupportedAlgorithm { algorithm: String },
+    #[error("invalid bcrypt hash. Error: {0}")]
+    Bcrypt(#[source] bcrypt::BcryptError),
+    #[error("invalid pepper version stored in hash")]
+    InvalidPepperVersion,
+    #[error("pepper with version {version} is not configured")]
+    UnknownPepperVersion { version: u32 },
 }
@@ -1,9 +1,17 @@
-use argon2::password_hash::{SaltString, rand_core::OsRng};
+use argon2::password_hash::{PasswordHasher, SaltString, rand_core::OsRng};
+use nimbus_auth_shared::types::{PasswordHashingParams, PasswordPolicy};
+use pbkdf2::Pbkdf2;
+use scrypt::Scrypt;
 use zeroize::Zeroizing;
 
-use crate::entities::user::value_objects::{
-    password::Password,
-    password_hash::{PasswordHash, errors::PasswordHashError},
+use crate::{
+    entities::user::value_objects::{
+        password::Password,
+        password_hash::{PasswordHash, errors::PasswordHashError},
+    },
+    value_objects::{
+        breached_passwords_filter::BreachedPasswordsFilter, password_peppers::PasswordPeppers,
+    },
 };
 
 const VALID_PASSWORD: &str = "StrongPassword123!";
@@ -11,17 +19,46 @@ const VALID_PASSWORD: &str = "StrongPassword123!";
 #[test]
 fn same_password_valid_salt() {
     let salt = SaltString::generate(&mut OsRng);
-    let password_to_hash = Password::from(&Zeroizing::new(VALID_PASSWORD.to_string())).unwrap();
-    let hash = PasswordHash::hash(password_to_hash, salt.as_str()).unwrap();
-    let password_to_verify = Password::from(&Zeroizing::new(VALID_PASSWORD.to_string())).unwrap();
-    assert!(hash.verify(&password_to_verify))
+    let password_to_hash = Password::from(
+        &Zeroizing::new(VALID_PASSWORD.to_string()),
+        &PasswordPolicy::default(),
+        &BreachedPasswordsFilter::empty(),
+    )
+    .unwrap();
+    let hash = PasswordHash::hash(
+        password_to_hash,
+        salt.as_str(),
+        &PasswordHashingParams::default(),
+        &PasswordPeppers::empty(),
+    )
+    .unwrap();
+    let password_to_verify = Password::from(
+        &Zeroizing::new(VALID_PASSWORD.to_string()),
+        &PasswordPolicy::default(),
+        &BreachedPasswordsFilter::empty(),
+    )
+    .unwrap();
+    assert!(
+        hash.verify(&password_to_verify, &PasswordPeppers::empty())
+            .unwrap()
+    )
 }
 
 #[test]
 fn invalid_salt() {
     let invalid_salt = "invalid_salt";
-    let password_to_hash = Password::from(&Zeroizing::new(VALID_PASSWORD.to_string())).unwrap();
-    let result = PasswordHash::hash(password_to_hash, invalid_salt);
+    let password_to_hash = Password::from(
+        &Zeroizing::new(VALID_PASSWORD.to_string()),
+        &PasswordPolicy::default(),
+        &BreachedPasswordsFilter::empty(),
+    )
+    .unwrap();
+    let result = PasswordHash::hash(
+        password_to_hash,
+        invalid_salt,
+        &PasswordHashingParams::default(),
+        &PasswordPeppers::empty(),
+    );
     assert!(matches!(result, Err(PasswordHashError::Salt)))
 }
 
@@ -29,8 +66,196 @@ fn invalid_salt() {
 fn wrong_password() {
     let wrong_password = "WrongPassword123!";
     let salt = SaltString::generate(&mut OsRng);
-    let password_to_hash = Password::from(&Zeroizing::new(VALID_PASSWORD.to_string())).unwrap();
-    let hash = PasswordHash::hash(password_to_hash, salt.as_str()).unwrap();
-    let password_to_verify = Password::from(&Zeroizing::new(wrong_password.to_string())).unwrap();
-    assert!(!hash.verify(&password_to_verify))
+    let password_to_hash = Password::from(
+        &Zeroizing::new(VALID_PASSWORD.to_string()),
+        &PasswordPolicy::default(),
+        &BreachedPasswordsFilter::empty(),
+    )
+    .unwrap();
+    let hash = PasswordHash::hash(
+        password_to_hash,
+        salt.as_str(),
+        &PasswordHashingParams::default(),
+        &PasswordPeppers::empty(),
+    )
+    .unwrap();
+    let password_to_verify = Password::from(
+        &Zeroizing::new(wrong_password.to_string()),
+        &PasswordPolicy::default(),
+        &BreachedPasswordsFilter::empty(),
+    )
+    .unwrap();
+    assert!(
+        !hash
+            .verify(&password_to_verify, &PasswordPeppers::empty())
+            .unwrap()
+    )
+}
+
+#[test]
+fn outdated_params_need_rehash() {
+    let salt = SaltString::generate(&mut OsRng);
+    let old_params = PasswordHashingParams {
+        memory_cost_kib: 8 * 1024,
+        time_cost: 1,
+        parallelism: 1,
+    };
+    let password_to_hash = Password::from_unvalidated(&Zeroizing::new(VALID_PASSWORD.to_string()));
+    let hash = PasswordHash::hash(
+        password_to_hash,
+        salt.as_str(),
+        &old_params,
+        &PasswordPeppers::empty(),
+    )
+    .unwrap();
+    assert!(!hash.needs_rehash(&old_params, &PasswordPeppers::empty()));
+    assert!(hash.needs_rehash(&PasswordHashingParams::default(), &PasswordPeppers::empty()));
+    assert!(
+        hash.verify(
+            &Password::from_unvalidated(&Zeroizing::new(VALID_PASSWORD.to_string())),
+            &PasswordPeppers::empty(),
+        )
+        .unwrap()
+    );
+}
+
+#[test]
+fn other_algorithm_needs_rehash() {
+    let argon2i_hash = PasswordHash::from(
+        "$argon2i$v=19$m=19456,t=2,p=1$c29tZXNhbHQ$o4fXmVGbLVaPPhEfvv6d1YjrUIJe5QUNKWzNyB/DAIk",
+    )
+    .unwrap();
+    assert!(
+        argon2i_hash.needs_rehash(&PasswordHashingParams::default(), &PasswordPeppers::empty())
+    );
+}
+
+#[test]
+fn peppered_hash_requires_pepper() {
+    let salt = SaltString::generate(&mut OsRng);
+    let peppers = PasswordPeppers::from("1:first-pepper-0123456789").unwrap();
+    let password = Zeroizing::new(VALID_PASSWORD.to_string());
+    let hash = PasswordHash::hash(
+        Password::from_unvalidated(&password),
+        salt.as_str(),
+        &PasswordHashingParams::default(),
+        &peppers,
+    )
+    .unwrap();
+    assert!(
+        hash.verify(&Password::from_unvalidated(&password), &peppers)
+            .unwrap()
+    );
+    assert!(matches!(
+        hash.verify(
+            &Password::from_unvalidated(&password),
+            &PasswordPeppers::empty()
+        ),
+        Err(PasswordHashError::UnknownPepperVersion { version: 1 })
+    ));
+}
+
+#[test]
+fn rotated_pepper_needs_rehash() {
+    let salt = SaltString::generate(&mut OsRng);
+    let old_peppers = PasswordPeppers::from("1:first-pepper-0123456789").unwrap();
+    let rotated_peppers =
+        PasswordPeppers::from("1:first-pepper-0123456789\n2:second-pepper-0123456789").unwrap();
+    let password = Zeroizing::new(VALID_PASSWORD.to_string());
+    let hash = PasswordHash::hash(
+        Password::from_unvalidated(&password),
+        salt.as_str(),
+        &PasswordHashingParams::default(),
+        &old_peppers,
+    )
+    .unwrap();
+    assert!(!hash.needs_rehash(&PasswordHashingParams::default(), &old_peppers));
+    assert!(hash.needs_rehash(&PasswordHashingParams::default(), &rotated_peppers));
+    assert!(
+        hash.verify(&Password::from_unvalidated(&password), &rotated_peppers)
+            .unwrap()
+    );
+}
+
+fn assert_legacy_hash_verifies_and_needs_rehash(legacy_hash: &str) {
+    let hash = PasswordHash::from(legacy_hash).unwrap();
+    let password = Zeroizing::new(VALID_PASSWORD.to_string());
+    let wrong_password = Zeroizing::new("WrongPassword123!".to_string());
+    assert!(
+        hash.verify(
+            &Password::from_unvalidated(&password),
+            &PasswordPeppers::empty()
+        )
+        .unwrap()
+    );
+    assert!(
+        !hash
+            .verify(
+                &Password::from_unvalidated(&wrong_password),
+                &PasswordPeppers::empty()
+            )
+            .unwrap()
+    );
+    assert!(hash.needs_rehash(&PasswordHashingParams::default(), &PasswordPeppers::empty()));
+}
+
+#[test]
+fn legacy_bcrypt_hash() {
+    let legacy_hash = bcrypt::hash(VALID_PASSWORD, 4).unwrap();
+    assert_legacy_hash_verifies_and_needs_rehash(&legacy_hash);
+}
+
+#[test]
+fn legacy_pbkdf2_sha256_hash() {
+    let salt = SaltString::generate(&mut OsRng);
+    let legacy_hash = Pbkdf2
+        .hash_password_customized(
+            VALID_PASSWORD.as_bytes(),
+            Some(pbkdf2::Algorithm::PBKDF2_SHA256_IDENT),
+            None,
+            pbkdf2::Params {
+                rounds: 1000,
+                output_length: 32,
+            },
+            &salt,
+        )
+        .unwrap()
+        .to_string();
+    assert_legacy_hash_verifies_and_needs_rehash(&legacy_hash);
+}
+
+#[test]
+fn legacy_scrypt_hash() {
+    let salt = SaltString::generate(&mut OsRng);
+    let legacy_hash = Scrypt
+        .hash_password_customized(
+            VALID_PASSWORD.as_bytes(),
+            None,
+            None,
+            scrypt::Params::new(10, 8, 1, 32).unwrap(),
+            &salt,
+        )
+        .unwrap()
+        .to_string();
+    assert_legacy_hash_verifies_and_needs_rehash(&legacy_hash);
+}
+
+#[test]
+fn unsupported_algorithm() {
+    let result = PasswordHash::from("$md5$c29tZXNhbHQ$aGFzaGhhc2hoYXNoaGFzaGhhc2g");
+    assert!(matches!(
+        result,
+        Err(PasswordHashError::UnsupportedAlgorithm { .. })
+    ));
+}
+
+#[test]
+fn dummy_hash_uses_current_params() {
+    let salt = SaltString::generate(&mut OsRng);
+    let params = PasswordHashingParams::default();
+    let peppers = PasswordPeppers::empty();
+    let dummy_hash = PasswordHash::dummy(salt.as_str(), &params, &peppers).unwrap();
+    let password = Password::from_unvalidated(&Zeroizing::new(VALID_PASSWORD.to_string()));
+    assert!(!dummy_hash.verify(&password, &peppers).unwrap());
+    assert!(!dummy_hash.needs_rehash(&params, &peppers));
 }
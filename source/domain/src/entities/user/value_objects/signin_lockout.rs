@@ -0,0 +1,70 @@
+use nimbus_auth_shared::types::SigninLockoutPolicy;
+use time::{Duration, OffsetDateTime};
+
+#[cfg(test)]
+mod tests;
+
+/// Failed signin attempts since the last successful signin and the temporary lockout they caused
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SigninLockout {
+    failed_attempts: u32,
+    locked_until: Option<OffsetDateTime>,
+}
+
+impl SigninLockout {
+    pub fn restore(failed_attempts: u32, locked_until: Option<OffsetDateTime>) -> Self {
+        Self {
+            failed_attempts,
+            locked_until,
+        }
+    }
+
+    pub fn failed_attempts(&self) -> u32 {
+        self.failed_attempts
+    }
+
+    pub fn locked_until(&self) -> Option<OffsetDateTime> {
+        self.locked_until
+    }
+
+    pub fn is_locked(&self, current_time: OffsetDateTime) -> bool {
+        self.active_locked_until(current_time).is_some()
+    }
+
+    /// End of the lockout if it has not expired at `current_time`
+    pub fn active_locked_until(&self, current_time: OffsetDateTime) -> Option<OffsetDateTime> {
+        self.locked_until
+            .filter(|locked_until| *locked_until > current_time)
+    }
+
+    pub fn is_reset(&self) -> bool {
+        self.failed_attempts == 0 && self.locked_until.is_none()
+    }
+
+    /// Records a failed attempt, locks for `base * 2^(attempts - max_failed_attempts)` seconds
+    /// once the threshold is reached
+    pub fn with_failed_attempt(
+        self,
+        current_time: OffsetDateTime,
+        policy: &SigninLockoutPolicy,
+    ) -> Self {
+        let failed_attempts = self.failed_attempts.saturating_add(1);
+        if policy.max_failed_attempts == 0 || failed_attempts < policy.max_failed_attempts {
+            return Self {
+                failed_attempts,
+                locked_until: self.locked_until,
+            };
+        }
+        let exponent = failed_attempts - policy.max_failed_attempts;
+        let lockout_seconds = 2usize
+            .checked_pow(exponent)
+            .and_then(|multiplier| policy.base_lockout_seconds.checked_mul(multiplier))
+            .map_or(policy.max_lockout_seconds, |seconds| {
+                seconds.min(policy.max_lockout_seconds)
+            });
+        Self {
+            failed_attempts,
+            locked_until: Some(current_time + Duration::seconds(lockout_seconds as i64)),
+        }
+    }
+}
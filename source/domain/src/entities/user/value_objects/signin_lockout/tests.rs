@@ -0,0 +1,66 @@
+use nimbus_auth_shared::types::SigninLockoutPolicy;
+use time::{Duration, OffsetDateTime};
+
+use crate::entities::user::value_objects::signin_lockout::SigninLockout;
+
+const POLICY: SigninLockoutPolicy = SigninLockoutPolicy {
+    max_failed_attempts: 3,
+    base_lockout_seconds: 30,
+    max_lockout_seconds: 100,
+};
+
+fn fail_times(
+    times: u32,
+    current_time: OffsetDateTime,
+    policy: &SigninLockoutPolicy,
+) -> SigninLockout {
+    (0..times).fold(SigninLockout::default(), |lockout, _| {
+        lockout.with_failed_attempt(current_time, policy)
+    })
+}
+
+#[test]
+fn not_locked_below_threshold() {
+    let now = OffsetDateTime::now_utc();
+    let lockout = fail_times(2, now, &POLICY);
+    assert_eq!(lockout.failed_attempts(), 2);
+    assert!(!lockout.is_locked(now));
+}
+
+#[test]
+fn locked_at_threshold() {
+    let now = OffsetDateTime::now_utc();
+    let lockout = fail_times(3, now, &POLICY);
+    assert_eq!(lockout.locked_until(), Some(now + Duration::seconds(30)));
+    assert!(lockout.is_locked(now));
+    assert!(!lockout.is_locked(now + Duration::seconds(30)));
+}
+
+#[test]
+fn lockout_doubles_and_is_capped() {
+    let now = OffsetDateTime::now_utc();
+    assert_eq!(
+        fail_times(4, now, &POLICY).locked_until(),
+        Some(now + Duration::seconds(60))
+    );
+    assert_eq!(
+        fail_times(5, now, &POLICY).locked_until(),
+        Some(now + Duration::seconds(100))
+    );
+    assert_eq!(
+        fail_times(200, now, &POLICY).locked_until(),
+        Some(now + Duration::seconds(100))
+    );
+}
+
+#[test]
+fn disabled_policy_never_locks() {
+    let now = OffsetDateTime::now_utc();
+    let policy = SigninLockoutPolicy {
+        max_failed_attempts: 0,
+        ..POLICY
+    };
+    let lockout = fail_times(50, now, &policy);
+    assert_eq!(lockout.failed_attempts(), 50);
+    assert!(!lockout.is_locked(now));
+}
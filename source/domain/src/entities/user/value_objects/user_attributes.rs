@@ -0,0 +1,93 @@
+use std::fmt::Display;
+
+use nimbus_auth_shared::constants::{
+    USER_ATTRIBUTE_NAME_MAX_LENGTH_INCLUSIVE, USER_ATTRIBUTES_MAX_SIZE_BYTES_INCLUSIVE,
+};
+use serde_json::{Map, Value};
+
+use crate::entities::user::value_objects::user_attributes::errors::UserAttributesError;
+
+pub mod errors;
+#[cfg(test)]
+mod tests;
+
+/// Arbitrary metadata of the user kept as a JSON object, like tenant id, display name or locale
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct UserAttributes {
+    value: Map<String, Value>,
+}
+
+impl UserAttributes {
+    pub fn from_json(json: &str) -> Result<Self, UserAttributesError> {
+        if json.len() > USER_ATTRIBUTES_MAX_SIZE_BYTES_INCLUSIVE {
+            return Err(UserAttributesError::TooLarge {
+                max_size_bytes: USER_ATTRIBUTES_MAX_SIZE_BYTES_INCLUSIVE,
+            });
+        }
+        match serde_json::from_str(json).map_err(UserAttributesError::InvalidJson)? {
+            Value::Object(value) => Self::from_map(value),
+            _ => Err(UserAttributesError::NotAnObject),
+        }
+    }
+
+    pub fn from_map(value: Map<String, Value>) -> Result<Self, UserAttributesError> {
+        value
+            .keys()
+            .try_for_each(|name| Self::validate_name(name))?;
+        let attributes = Self { value };
+        if attributes.to_json().len() > USER_ATTRIBUTES_MAX_SIZE_BYTES_INCLUSIVE {
+            return Err(UserAttributesError::TooLarge {
+                max_size_bytes: USER_ATTRIBUTES_MAX_SIZE_BYTES_INCLUSIVE,
+            });
+        }
+        Ok(attributes)
+    }
+
+    fn validate_name(name: &str) -> Result<(), UserAttributesError> {
+        if name.is_empty() || name.len() > USER_ATTRIBUTE_NAME_MAX_LENGTH_INCLUSIVE {
+            return Err(UserAttributesError::InvalidNameLength {
+                name: name.to_string(),
+                max_length: USER_ATTRIBUTE_NAME_MAX_LENGTH_INCLUSIVE,
+            });
+        }
+        match name
+            .chars()
+            .all(|ch| ch.is_ascii_lowercase() || ch.is_ascii_digit() || ch == '_')
+        {
+            true => Ok(()),
+            false => Err(UserAttributesError::InvalidNameCharacters {
+                name: name.to_string(),
+            }),
+        }
+    }
+
+    /// Keeps only attributes with the given names
+    pub fn project(&self, names: &[String]) -> Self {
+        Self {
+            value: self
+                .value
+                .iter()
+                .filter(|(name, _)| names.contains(name))
+                .map(|(name, value)| (name.clone(), value.clone()))
+                .collect(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.value.is_empty()
+    }
+
+    pub fn value(&self) -> &Map<String, Value> {
+        &self.value
+    }
+
+    pub fn to_json(&self) -> String {
+        Value::Object(self.value.clone()).to_string()
+    }
+}
+
+impl Display for UserAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_json())
+    }
+}
@@ -0,0 +1,21 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum UserAttributesError {
+    #[error("user attributes are not valid JSON. Error: {0}")]
+    InvalidJson(#[source] serde_json::Error),
+    #[error("user attributes should be a JSON object")]
+    NotAnObject,
+    #[error(
+        "user attributes are too large, should be less than or equal to {max_size_bytes} bytes"
+    )]
+    TooLarge { max_size_bytes: usize },
+    #[error(
+        "user attribute name: {name} should be non empty and less than or equal to {max_length}"
+    )]
+    InvalidNameLength { name: String, max_length: usize },
+    #[error(
+        "user attribute name: {name} contains invalid characters. it should contain only lowercase English alphanumeric characters and `_`"
+    )]
+    InvalidNameCharacters { name: String },
+}
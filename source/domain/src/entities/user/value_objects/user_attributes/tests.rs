@@ -0,0 +1,58 @@
+use nimbus_auth_shared::constants::USER_ATTRIBUTES_MAX_SIZE_BYTES_INCLUSIVE;
+
+use crate::entities::user::value_objects::user_attributes::{
+    UserAttributes, errors::UserAttributesError,
+};
+
+#[test]
+fn valid_attributes() {
+    let attributes = UserAttributes::from_json(
+        r#"{"tenant_id": "acme", "locale": "en-US", "entitlements": ["reports"]}"#,
+    );
+    assert!(attributes.is_ok());
+}
+
+#[test]
+fn not_an_object() {
+    let attributes = UserAttributes::from_json(r#"["tenant_id"]"#);
+    assert!(matches!(attributes, Err(UserAttributesError::NotAnObject)));
+}
+
+#[test]
+fn invalid_json() {
+    let attributes = UserAttributes::from_json(r#"{"tenant_id": "#);
+    assert!(matches!(
+        attributes,
+        Err(UserAttributesError::InvalidJson(_))
+    ));
+}
+
+#[test]
+fn invalid_name() {
+    let attributes = UserAttributes::from_json(r#"{"Tenant-Id": "acme"}"#);
+    assert!(matches!(
+        attributes,
+        Err(UserAttributesError::InvalidNameCharacters { .. })
+    ));
+}
+
+#[test]
+fn too_large() {
+    let json = format!(
+        r#"{{"display_name": "{}"}}"#,
+        "a".repeat(USER_ATTRIBUTES_MAX_SIZE_BYTES_INCLUSIVE)
+    );
+    let attributes = UserAttributes::from_json(&json);
+    assert!(matches!(
+        attributes,
+        Err(UserAttributesError::TooLarge { .. })
+    ));
+}
+
+#[test]
+fn projection_keeps_only_allowed_names() {
+    let attributes =
+        UserAttributes::from_json(r#"{"tenant_id": "acme", "display_name": "Jane"}"#).unwrap();
+    let projected = attributes.project(&["tenant_id".to_string(), "locale".to_string()]);
+    assert_eq!(projected.to_json(), r#"{"tenant_id":"acme"}"#);
+}
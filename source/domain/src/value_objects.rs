@@ -1,3 +1,11 @@
 pub mod access_token;
+pub mod actor;
+pub mod audiences;
+pub mod breached_passwords_filter;
+pub mod client_access_token;
+pub mod client_claims;
+pub mod id_token;
 pub mod identifier;
+pub mod password_peppers;
+pub mod secret_hash;
 pub mod user_claims;
@@ -1,13 +1,17 @@
-use std::collections::HashSet;
+use std::{
+    collections::{BTreeSet, HashSet},
+    iter::once,
+};
 
 use jsonwebtoken::{
     Algorithm, DecodingKey, EncodingKey, Header, Validation, decode, decode_header, encode,
 };
 use nimbus_auth_shared::{
-    constants::{ACCESS_TOKEN_AUDIENCE, ACCESS_TOKEN_ISSUER},
-    types::{AccessTokenExpirationSeconds, UserRole},
+    constants::CLIENT_ACCESS_TOKEN_TYPE,
+    types::{AccessTokenAttributes, AccessTokenExpirationSeconds, AccessTokenMaxGroups, Realm},
 };
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use serde_json::{Map, Value};
 use time::OffsetDateTime;
 use ulid::Ulid;
 use zeroize::Zeroizing;
@@ -15,11 +19,19 @@ use zeroize::Zeroizing;
 use crate::{
     entities::{
         Entity,
+        group::{
+            Group, specifications::RestoreGroupSpecification, value_objects::group_name::GroupName,
+        },
         keypair::{Active, Expiring, KeyPair, SomeKeyPair},
-        user::value_objects::user_name::UserName,
+        role::value_objects::{permission::Permission, role_name::RoleName},
+        user::value_objects::{user_attributes::UserAttributes, user_name::UserName},
     },
     value_objects::{
-        access_token::errors::{ExtractKeyIdError, SignAccessTokenError, VerificationError},
+        access_token::errors::{
+            ExchangeAccessTokenError, ExtractKeyIdError, SignAccessTokenError, VerificationError,
+        },
+        actor::Actor,
+        audiences::Audiences,
         identifier::{Identifier, IdentifierOfType},
         user_claims::UserClaims,
     },
@@ -32,17 +44,100 @@ mod tests;
 #[derive(Debug, Clone)]
 pub struct AccessToken {
     user_claims: UserClaims,
+    audiences: Audiences,
     expires_at: OffsetDateTime,
+    actor: Option<Actor>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 struct Claims {
-    aud: String,
+    aud: AudienceClaim,
     exp: usize,
     iss: String,
     sub: String,
     name: String,
-    role: String,
+    roles: Vec<String>,
+    permissions: Vec<String>,
+    groups: Vec<GroupClaim>,
+    groups_overflow: bool,
+    /// Only attributes from the configured allowlist
+    attributes: Map<String, Value>,
+    /// Tokens issued before delegation have none
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    act: Option<ActorClaim>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ActorClaim {
+    sub: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    act: Option<Box<ActorClaim>>,
+}
+
+impl From<&Actor> for ActorClaim {
+    fn from(value: &Actor) -> Self {
+        Self {
+            sub: value.subject().to_string(),
+            act: value
+                .prior_actor()
+                .map(|prior_actor| Box::new(ActorClaim::from(prior_actor))),
+        }
+    }
+}
+
+impl From<ActorClaim> for Actor {
+    fn from(value: ActorClaim) -> Self {
+        Actor::new(
+            &value.sub,
+            value.act.map(|prior_actor| Actor::from(*prior_actor)),
+        )
+    }
+}
+
+/// Single audience is kept as a string, as in tokens issued before multiple audiences
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub(crate) enum AudienceClaim {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl AudienceClaim {
+    /// Token without audiences targets the default audience of the realm it is signed in
+    pub(crate) fn new(audiences: &Audiences, realm: &Realm) -> Self {
+        let audiences = match audiences.value() {
+            [] => realm
+                .default_audience()
+                .into_iter()
+                .map(String::from)
+                .collect(),
+            audiences => audiences.to_vec(),
+        };
+        match <[String; 1]>::try_from(audiences) {
+            Ok([audience]) => AudienceClaim::One(audience),
+            Err(audiences) => AudienceClaim::Many(audiences),
+        }
+    }
+
+    pub(crate) fn into_audiences(self) -> Audiences {
+        Audiences::restore(match self {
+            AudienceClaim::One(audience) => vec![audience],
+            AudienceClaim::Many(audiences) => audiences,
+        })
+    }
+}
+
+/// Kind of principal an access token is issued to, told apart by the `typ` header
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccessTokenType {
+    User,
+    Client,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct GroupClaim {
+    id: String,
+    name: String,
 }
 
 impl AccessToken {
@@ -50,43 +145,169 @@ impl AccessToken {
         user_claims: UserClaims,
         current_time: OffsetDateTime,
         AccessTokenExpirationSeconds(expiration_seconds): AccessTokenExpirationSeconds,
+        AccessTokenMaxGroups(max_groups): AccessTokenMaxGroups,
+        AccessTokenAttributes(attributes): &AccessTokenAttributes,
     ) -> AccessToken {
         AccessToken {
-            user_claims,
+            user_claims: user_claims
+                .with_groups_limit(max_groups)
+                .with_attributes_projection(attributes),
+            audiences: Audiences::default(),
             expires_at: current_time + time::Duration::seconds(expiration_seconds as i64),
+            actor: None,
         }
     }
 
+    /// Narrower token of the same user for token exchange
+    ///
+    /// Scopes must be granted by this token, no scopes keep all of its permissions.
+    /// Audiences must be targeted by this token, no audiences keep all of its audiences.
+    /// The new token never outlives this one and keeps its delegation chain unless a new actor is given
+    pub fn exchange(
+        &self,
+        scopes: Option<&BTreeSet<Permission>>,
+        audiences: Audiences,
+        actor: Option<Actor>,
+        current_time: OffsetDateTime,
+        AccessTokenExpirationSeconds(expiration_seconds): AccessTokenExpirationSeconds,
+    ) -> Result<AccessToken, ExchangeAccessTokenError> {
+        let user_claims = match scopes {
+            Some(scopes) => {
+                if let Some(scope) = scopes
+                    .iter()
+                    .find(|scope| !self.user_claims.permissions().contains(scope))
+                {
+                    return Err(ExchangeAccessTokenError::ScopeIsNotGranted {
+                        scope: scope.to_string(),
+                    });
+                }
+                self.user_claims.clone().with_permissions_limit(scopes)
+            }
+            None => self.user_claims.clone(),
+        };
+
+        let audiences = match audiences.value() {
+            [] => self.audiences.clone(),
+            requested => {
+                if let Some(audience) = requested
+                    .iter()
+                    .find(|audience| !self.audiences.value().contains(audience))
+                {
+                    return Err(ExchangeAccessTokenError::AudienceIsNotGranted {
+                        audience: audience.to_string(),
+                    });
+                }
+                audiences
+            }
+        };
+
+        Ok(AccessToken {
+            user_claims,
+            audiences,
+            expires_at: self
+                .expires_at
+                .min(current_time + time::Duration::seconds(expiration_seconds as i64)),
+            actor: actor.or_else(|| self.actor.clone()),
+        })
+    }
+
+    /// Token without audiences targets the default audience of the realm it is signed in
+    pub fn with_audiences(mut self, audiences: Audiences) -> Self {
+        self.audiences = audiences;
+        self
+    }
+
+    pub fn with_actor(mut self, actor: Actor) -> Self {
+        self.actor = Some(actor);
+        self
+    }
+
     pub fn user_claims(&self) -> &UserClaims {
         &self.user_claims
     }
 
+    pub fn audiences(&self) -> &Audiences {
+        &self.audiences
+    }
+
     pub fn expires_at(&self) -> &OffsetDateTime {
         &self.expires_at
     }
 
-    pub fn sign(&self, keypair: &KeyPair<Active>) -> Result<String, SignAccessTokenError> {
-        let mut header = Header::new(jsonwebtoken::Algorithm::EdDSA);
-        header.kid = Some(keypair.id().to_string());
+    /// Current actor of a delegated token
+    pub fn actor(&self) -> Option<&Actor> {
+        self.actor.as_ref()
+    }
 
-        let expiration_timestamp = self.expires_at.unix_timestamp() as usize;
+    /// Issuer is taken from the realm the token is issued in
+    pub fn sign(
+        &self,
+        keypair: &KeyPair<Active>,
+        realm: &Realm,
+    ) -> Result<String, SignAccessTokenError> {
         let claims = Claims {
-            aud: ACCESS_TOKEN_AUDIENCE.to_string(),
-            exp: expiration_timestamp,
-            iss: ACCESS_TOKEN_ISSUER.to_string(),
+            aud: AudienceClaim::new(&self.audiences, realm),
+            exp: self.expires_at.unix_timestamp() as usize,
+            iss: realm.issuer.clone(),
             sub: self.user_claims.id().to_string(),
             name: self.user_claims.name().to_string(),
-            role: self.user_claims.role().to_string(),
+            roles: self
+                .user_claims
+                .roles()
+                .iter()
+                .map(|role| role.to_string())
+                .collect(),
+            permissions: self
+                .user_claims
+                .permissions()
+                .iter()
+                .map(|permission| permission.to_string())
+                .collect(),
+            groups: self
+                .user_claims
+                .groups()
+                .iter()
+                .map(|group| GroupClaim {
+                    id: group.id().to_string(),
+                    name: group.name().to_string(),
+                })
+                .collect(),
+            groups_overflow: self.user_claims.groups_overflow(),
+            attributes: self.user_claims.attributes().value().clone(),
+            act: self.actor.as_ref().map(ActorClaim::from),
         };
 
+        Self::encode_claims(&claims, AccessTokenType::User, keypair)
+    }
+
+    pub(crate) fn encode_claims<C: Serialize>(
+        claims: &C,
+        token_type: AccessTokenType,
+        keypair: &KeyPair<Active>,
+    ) -> Result<String, SignAccessTokenError> {
+        let mut header = Header::new(jsonwebtoken::Algorithm::EdDSA);
+        header.kid = Some(keypair.id().to_string());
+        if token_type == AccessTokenType::Client {
+            header.typ = Some(CLIENT_ACCESS_TOKEN_TYPE.to_string());
+        }
+
         let key = EncodingKey::from_ed_pem(keypair.value().private_key_pem().as_bytes())
             .map_err(SignAccessTokenError::InvalidPrivateKeyFormat)?;
 
-        let token = encode(&header, &claims, &key).map_err(SignAccessTokenError::Encoding)?;
+        let token = encode(&header, claims, &key).map_err(SignAccessTokenError::Encoding)?;
 
         Ok(token)
     }
 
+    /// Tokens without the client `typ` header are issued to users
+    pub fn extract_type(signed_token: &str) -> Result<AccessTokenType, ExtractKeyIdError> {
+        let header = decode_header(signed_token).map_err(ExtractKeyIdError::HeaderDecoding)?;
+        match header.typ.as_deref() {
+            Some(CLIENT_ACCESS_TOKEN_TYPE) => Ok(AccessTokenType::Client),
+            _ => Ok(AccessTokenType::User),
+        }
+    }
+
     pub fn extract_keypair_id(
         signed_token: &str,
     ) -> Result<Identifier<Ulid, SomeKeyPair>, ExtractKeyIdError> {
@@ -101,22 +322,26 @@ impl AccessToken {
     pub fn verify_with_active(
         signed_token: &str,
         keypair: &KeyPair<Active>,
+        realm: &Realm,
     ) -> Result<AccessToken, VerificationError> {
         AccessToken::verify(
             signed_token,
             keypair.id().clone().as_other_entity(),
             &keypair.value().public_key_pem(),
+            realm,
         )
     }
 
     pub fn verify_with_expiring(
         signed_token: &str,
         keypair: &KeyPair<Expiring>,
+        realm: &Realm,
     ) -> Result<AccessToken, VerificationError> {
         AccessToken::verify(
             signed_token,
             keypair.id().clone().as_other_entity(),
             &keypair.value().public_key_pem(),
+            realm,
         )
     }
 
@@ -124,24 +349,15 @@ impl AccessToken {
         signed_token: &str,
         expected_keypair_id: Identifier<Ulid, SomeKeyPair>,
         public_key_pem: &str,
+        realm: &Realm,
     ) -> Result<AccessToken, VerificationError> {
-        let actual_key_id = Self::extract_keypair_id(signed_token)?;
-        if actual_key_id != expected_keypair_id {
-            return Err(VerificationError::KeyPairIdsDoNotMatch);
-        }
-
-        let mut validation = Validation::new(Algorithm::EdDSA);
-        validation.set_audience(&[ACCESS_TOKEN_AUDIENCE]);
-        let mut issuer = HashSet::with_capacity(1);
-        issuer.insert(ACCESS_TOKEN_ISSUER.to_string());
-        validation.iss = Some(issuer);
-
-        let decoding_key = DecodingKey::from_ed_pem(public_key_pem.as_bytes())
-            .map_err(|err| VerificationError::InvalidDecodingKey(err))?;
-
-        let claims = decode::<Claims>(signed_token, &decoding_key, &validation)
-            .map_err(|err| VerificationError::Decoding(err))?
-            .claims;
+        let claims = Self::decode_claims::<Claims>(
+            signed_token,
+            AccessTokenType::User,
+            expected_keypair_id,
+            public_key_pem,
+            realm,
+        )?;
 
         let user_id = Identifier::from(
             Ulid::from_string(claims.sub.as_str())
@@ -149,14 +365,79 @@ impl AccessToken {
         );
         let user_name = UserName::from(claims.name.as_str())
             .map_err(|err| VerificationError::InvalidClaims(err.to_string()))?;
-        let user_role = UserRole::try_from(claims.role.as_str())
-            .map_err(|err| VerificationError::InvalidClaims(err))?;
+        let roles = claims
+            .roles
+            .iter()
+            .map(|role| RoleName::from(role))
+            .collect::<Result<_, _>>()
+            .map_err(|err| VerificationError::InvalidClaims(err.to_string()))?;
+        let permissions = claims
+            .permissions
+            .iter()
+            .map(|permission| Permission::from(permission))
+            .collect::<Result<_, _>>()
+            .map_err(|err| VerificationError::InvalidClaims(err.to_string()))?;
+        let groups = claims
+            .groups
+            .iter()
+            .map(|group| {
+                let id = Ulid::from_string(&group.id)
+                    .map_err(|err| VerificationError::InvalidClaims(err.to_string()))?;
+                let name = GroupName::from(&group.name)
+                    .map_err(|err| VerificationError::InvalidClaims(err.to_string()))?;
+                Ok(Group::restore(RestoreGroupSpecification {
+                    id: Identifier::from(id),
+                    name,
+                }))
+            })
+            .collect::<Result<_, VerificationError>>()?;
+        let attributes = UserAttributes::from_map(claims.attributes)
+            .map_err(|err| VerificationError::InvalidClaims(err.to_string()))?;
         let expires_at = OffsetDateTime::from_unix_timestamp(claims.exp as i64)
             .map_err(|err| VerificationError::InvalidClaims(err.to_string()))?;
 
         Ok(AccessToken {
-            user_claims: UserClaims::new(user_id, user_name, user_role),
+            user_claims: UserClaims::new(user_id, user_name, roles, permissions)
+                .with_groups(groups)
+                .with_groups_overflow(claims.groups_overflow)
+                .with_attributes(attributes),
+            audiences: claims.aud.into_audiences(),
             expires_at,
+            actor: claims.act.map(Actor::from),
         })
     }
+
+    /// Checks the key id, `typ` header, signature, issuer, audiences and expiration of the token
+    pub(crate) fn decode_claims<C: DeserializeOwned>(
+        signed_token: &str,
+        expected_type: AccessTokenType,
+        expected_keypair_id: Identifier<Ulid, SomeKeyPair>,
+        public_key_pem: &str,
+        realm: &Realm,
+    ) -> Result<C, VerificationError> {
+        let actual_key_id = Self::extract_keypair_id(signed_token)?;
+        if actual_key_id != expected_keypair_id {
+            return Err(VerificationError::KeyPairIdsDoNotMatch);
+        }
+        if Self::extract_type(signed_token)? != expected_type {
+            return Err(VerificationError::WrongTokenType);
+        }
+
+        let mut validation = Validation::new(Algorithm::EdDSA);
+        // token is accepted if it targets any of the realm audiences
+        validation.set_audience(&realm.audiences);
+        validation.iss = Some(
+            once(&realm.issuer)
+                .chain(realm.trusted_issuers.iter())
+                .cloned()
+                .collect::<HashSet<_>>(),
+        );
+
+        let decoding_key = DecodingKey::from_ed_pem(public_key_pem.as_bytes())
+            .map_err(|err| VerificationError::InvalidDecodingKey(err))?;
+
+        Ok(decode::<C>(signed_token, &decoding_key, &validation)
+            .map_err(|err| VerificationError::Decoding(err))?
+            .claims)
+    }
 }
@@ -26,6 +26,8 @@ pub enum VerificationError {
     ExtractKeyId(#[from] ExtractKeyIdError),
     #[error("keypair ids do not match")]
     KeyPairIdsDoNotMatch,
+    #[error("access token is issued to another type of principal")]
+    WrongTokenType,
     #[error("invalid decoding key. Error: {0}")]
     InvalidDecodingKey(#[source] Error),
     #[error("decoding error: {0}")]
@@ -33,3 +35,11 @@ pub enum VerificationError {
     #[error("invalid claims. Error: {0}")]
     InvalidClaims(String),
 }
+
+#[derive(Debug, Error)]
+pub enum ExchangeAccessTokenError {
+    #[error("scope: {scope} is not granted by the subject token")]
+    ScopeIsNotGranted { scope: String },
+    #[error("audience: {audience} is not targeted by the subject token")]
+    AudienceIsNotGranted { audience: String },
+}
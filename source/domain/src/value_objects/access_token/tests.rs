@@ -1,7 +1,13 @@
+use std::collections::BTreeSet;
+
 use argon2::password_hash::SaltString;
 use ed25519_dalek::{SigningKey, pkcs8::EncodePrivateKey, pkcs8::spki::der::pem::LineEnding};
 use nimbus_auth_shared::{
-    constants::ACCESS_TOKEN_EXPIRATION_SECONDS_DEFAULT, types::AccessTokenExpirationSeconds,
+    constants::{ACCESS_TOKEN_EXPIRATION_SECONDS_DEFAULT, ACCESS_TOKEN_MAX_GROUPS_DEFAULT},
+    types::{
+        AccessTokenAttributes, AccessTokenExpirationSeconds, AccessTokenMaxGroups,
+        PasswordHashingParams, PasswordPolicy, Realm,
+    },
 };
 use rand::rngs::OsRng;
 use time::OffsetDateTime;
@@ -10,37 +16,73 @@ use zeroize::Zeroizing;
 use crate::{
     entities::{
         Entity,
+        group::{
+            Group, specifications::NewGroupSpecification, value_objects::group_name::GroupName,
+        },
         keypair::{
             Active, KeyPair, SomeKeyPair, specifications::NewKeyPairSpecification,
             value_objects::KeyPairValue,
         },
+        role::{
+            Role,
+            specifications::NewRoleSpecification,
+            value_objects::{permission::Permission, role_name::RoleName},
+        },
         user::{
             User,
             specifications::NewUserSpecification,
-            value_objects::{password::Password, password_hash::PasswordHash, user_name::UserName},
+            value_objects::{
+                password::Password, password_hash::PasswordHash, user_attributes::UserAttributes,
+                user_name::UserName,
+            },
         },
     },
     value_objects::{
-        access_token::{AccessToken, errors::VerificationError},
+        access_token::{
+            AccessToken,
+            errors::{ExchangeAccessTokenError, VerificationError},
+        },
+        actor::Actor,
+        audiences::Audiences,
+        breached_passwords_filter::BreachedPasswordsFilter,
         identifier::Identifier,
+        password_peppers::PasswordPeppers,
     },
 };
 
 const VALID_USER_NAME: &str = "validuser123";
 const VALID_PASSWORD: &str = "StrongPassword123!";
+const VALID_ROLE_NAME: &str = "support";
+const VALID_PERMISSION: &str = "auth:users:read";
+const VALID_GROUP_NAMES: [&str; 3] = ["backend", "frontend", "platform"];
 
 fn get_user() -> User {
     let user_name = UserName::from(VALID_USER_NAME)
         .expect("username should have been constructed successfully");
-    let password = Password::from(&Zeroizing::new(VALID_PASSWORD.to_string()))
-        .expect("password should have been constructed successfully");
+    let password = Password::from(
+        &Zeroizing::new(VALID_PASSWORD.to_string()),
+        &PasswordPolicy::default(),
+        &BreachedPasswordsFilter::empty(),
+    )
+    .expect("password should have been constructed successfully");
     let salt = SaltString::generate(&mut OsRng);
-    let password_hash = PasswordHash::hash(password, salt.as_str())
-        .expect("password should have been cached successfully");
+    let password_hash = PasswordHash::hash(
+        password,
+        salt.as_str(),
+        &PasswordHashingParams::default(),
+        &PasswordPeppers::empty(),
+    )
+    .expect("password should have been cached successfully");
 
     User::new(NewUserSpecification {
         user_name: user_name,
         password_hash: password_hash,
+        roles: vec![Role::new(NewRoleSpecification {
+            name: RoleName::from(VALID_ROLE_NAME)
+                .expect("role name should have been constructed successfully"),
+            permissions: BTreeSet::from([Permission::from(VALID_PERMISSION)
+                .expect("permission should have been constructed successfully")]),
+        })],
     })
 }
 
@@ -62,13 +104,88 @@ fn encode_decode() {
         user.claims().clone(),
         OffsetDateTime::now_utc(),
         AccessTokenExpirationSeconds(ACCESS_TOKEN_EXPIRATION_SECONDS_DEFAULT),
+        AccessTokenMaxGroups(ACCESS_TOKEN_MAX_GROUPS_DEFAULT),
+        &AccessTokenAttributes::default(),
+    );
+    let signed_token = access_token
+        .sign(&keypair, &Realm::default())
+        .expect("token should have been signed successfully");
+
+    let access_token = AccessToken::verify_with_active(&signed_token, &keypair, &Realm::default())
+        .expect("token should have been verified successfully");
+    let user_claims = access_token.user_claims();
+    assert_eq!(user_claims.id(), user.id());
+    assert_eq!(user_claims.roles(), user.roles());
+    assert_eq!(user_claims.permissions(), user.permissions());
+}
+
+fn get_groups() -> Vec<Group> {
+    VALID_GROUP_NAMES
+        .iter()
+        .map(|name| {
+            Group::new(NewGroupSpecification {
+                name: GroupName::from(name)
+                    .expect("group name should have been constructed successfully"),
+            })
+        })
+        .collect()
+}
+
+#[test]
+fn encode_decode_groups() {
+    let user = get_user().with_groups(&get_groups());
+    let keypair = get_keypair();
+
+    let access_token = AccessToken::new(
+        user.claims().clone(),
+        OffsetDateTime::now_utc(),
+        AccessTokenExpirationSeconds(ACCESS_TOKEN_EXPIRATION_SECONDS_DEFAULT),
+        AccessTokenMaxGroups(ACCESS_TOKEN_MAX_GROUPS_DEFAULT),
+        &AccessTokenAttributes::default(),
+    );
+    let signed_token = access_token
+        .sign(&keypair, &Realm::default())
+        .expect("token should have been signed successfully");
+
+    let access_token = AccessToken::verify_with_active(&signed_token, &keypair, &Realm::default())
+        .expect("token should have been verified successfully");
+    let user_claims = access_token.user_claims();
+    let group_ids: Vec<_> = user_claims
+        .groups()
+        .iter()
+        .map(|group| group.id())
+        .collect();
+    let expected_group_ids: Vec<_> = user.groups().iter().map(|group| group.id()).collect();
+    assert_eq!(group_ids, expected_group_ids);
+    assert!(!user_claims.groups_overflow());
+}
+
+#[test]
+fn encode_decode_groups_over_limit() {
+    let user = get_user().with_groups(&get_groups());
+    let keypair = get_keypair();
+
+    let access_token = AccessToken::new(
+        user.claims().clone(),
+        OffsetDateTime::now_utc(),
+        AccessTokenExpirationSeconds(ACCESS_TOKEN_EXPIRATION_SECONDS_DEFAULT),
+        AccessTokenMaxGroups(2),
+        &AccessTokenAttributes::default(),
     );
     let signed_token = access_token
-        .sign(&keypair)
+        .sign(&keypair, &Realm::default())
         .expect("token should have been signed successfully");
 
-    let result = AccessToken::verify_with_active(&signed_token, &keypair);
-    assert!(matches!(result, Ok(..)));
+    let access_token = AccessToken::verify_with_active(&signed_token, &keypair, &Realm::default())
+        .expect("token should have been verified successfully");
+    let user_claims = access_token.user_claims();
+    let group_names: Vec<_> = user_claims
+        .groups()
+        .iter()
+        .map(|group| group.name().to_string())
+        .collect();
+    assert_eq!(group_names, VALID_GROUP_NAMES[..2]);
+    assert!(user_claims.groups_overflow());
 }
 
 #[test]
@@ -80,9 +197,11 @@ fn verify_key_extraction() {
         user.claims().clone(),
         OffsetDateTime::now_utc(),
         AccessTokenExpirationSeconds(ACCESS_TOKEN_EXPIRATION_SECONDS_DEFAULT),
+        AccessTokenMaxGroups(ACCESS_TOKEN_MAX_GROUPS_DEFAULT),
+        &AccessTokenAttributes::default(),
     );
     let signed_token = access_token
-        .sign(&keypair)
+        .sign(&keypair, &Realm::default())
         .expect("token should have been signed successfully");
 
     let keypair_id: Identifier<ulid::Ulid, KeyPair<Active>> =
@@ -103,13 +222,18 @@ fn encode_decode_with_wrong_key() {
         user.claims().clone(),
         OffsetDateTime::now_utc(),
         AccessTokenExpirationSeconds(ACCESS_TOKEN_EXPIRATION_SECONDS_DEFAULT),
+        AccessTokenMaxGroups(ACCESS_TOKEN_MAX_GROUPS_DEFAULT),
+        &AccessTokenAttributes::default(),
     );
     let signed_token = access_token
-        .sign(&keypair)
+        .sign(&keypair, &Realm::default())
         .expect("token should have been signed successfully");
 
-    let result = AccessToken::verify_with_active(&signed_token, &wrong_keypair);
-    assert!(matches!(result, Err(VerificationError::KeyPairIdsDoNotMatch)));
+    let result = AccessToken::verify_with_active(&signed_token, &wrong_keypair, &Realm::default());
+    assert!(matches!(
+        result,
+        Err(VerificationError::KeyPairIdsDoNotMatch)
+    ));
 }
 
 #[test]
@@ -121,9 +245,11 @@ fn modified_token() {
         user.claims().clone(),
         OffsetDateTime::now_utc(),
         AccessTokenExpirationSeconds(ACCESS_TOKEN_EXPIRATION_SECONDS_DEFAULT),
+        AccessTokenMaxGroups(ACCESS_TOKEN_MAX_GROUPS_DEFAULT),
+        &AccessTokenAttributes::default(),
     );
     let signed_token = access_token
-        .sign(&keypair)
+        .sign(&keypair, &Realm::default())
         .expect("token should have been signed successfully");
 
     let mut token_parts: Vec<String> = signed_token
@@ -141,6 +267,248 @@ fn modified_token() {
         String::from_utf8(payload_bytes).expect("payload bytes should still be valid utf8 string");
     let tampered_token = token_parts.join(".");
 
-    let result = AccessToken::verify_with_active(&tampered_token, &keypair);
+    let result = AccessToken::verify_with_active(&tampered_token, &keypair, &Realm::default());
+    assert!(matches!(result, Err(VerificationError::Decoding(..))));
+}
+
+#[test]
+fn encode_decode_allowed_attributes() {
+    let user = get_user().with_attributes(
+        UserAttributes::from_json(r#"{"tenant_id": "acme", "display_name": "Jane"}"#)
+            .expect("attributes should have been constructed successfully"),
+    );
+    let keypair = get_keypair();
+
+    let access_token = AccessToken::new(
+        user.claims().clone(),
+        OffsetDateTime::now_utc(),
+        AccessTokenExpirationSeconds(ACCESS_TOKEN_EXPIRATION_SECONDS_DEFAULT),
+        AccessTokenMaxGroups(ACCESS_TOKEN_MAX_GROUPS_DEFAULT),
+        &AccessTokenAttributes(vec!["tenant_id".to_string()]),
+    );
+    let signed_token = access_token
+        .sign(&keypair, &Realm::default())
+        .expect("token should have been signed successfully");
+
+    let access_token = AccessToken::verify_with_active(&signed_token, &keypair, &Realm::default())
+        .expect("token should have been verified successfully");
+    assert_eq!(
+        access_token.user_claims().attributes().to_json(),
+        r#"{"tenant_id":"acme"}"#
+    );
+}
+
+#[test]
+fn token_of_other_realm() {
+    let user = get_user();
+    let keypair = get_keypair();
+
+    let access_token = AccessToken::new(
+        user.claims().clone(),
+        OffsetDateTime::now_utc(),
+        AccessTokenExpirationSeconds(ACCESS_TOKEN_EXPIRATION_SECONDS_DEFAULT),
+        AccessTokenMaxGroups(ACCESS_TOKEN_MAX_GROUPS_DEFAULT),
+        &AccessTokenAttributes::default(),
+    );
+    let signed_token = access_token
+        .sign(&keypair, &Realm::default().derive("shop", None))
+        .expect("token should have been signed successfully");
+
+    let result = AccessToken::verify_with_active(&signed_token, &keypair, &Realm::default());
     assert!(matches!(result, Err(VerificationError::Decoding(..))));
 }
+
+#[test]
+fn encode_decode_multiple_audiences() {
+    let user = get_user();
+    let keypair = get_keypair();
+    let realm = Realm {
+        audiences: vec!["nimbus".to_string(), "billing".to_string()],
+        ..Realm::default()
+    };
+    let audiences = Audiences::from(&["billing".to_string(), "nimbus".to_string()], &realm)
+        .expect("audiences should have been constructed successfully");
+
+    let access_token = AccessToken::new(
+        user.claims().clone(),
+        OffsetDateTime::now_utc(),
+        AccessTokenExpirationSeconds(ACCESS_TOKEN_EXPIRATION_SECONDS_DEFAULT),
+        AccessTokenMaxGroups(ACCESS_TOKEN_MAX_GROUPS_DEFAULT),
+        &AccessTokenAttributes::default(),
+    )
+    .with_audiences(audiences.clone());
+    let signed_token = access_token
+        .sign(&keypair, &realm)
+        .expect("token should have been signed successfully");
+
+    let access_token = AccessToken::verify_with_active(&signed_token, &keypair, &realm)
+        .expect("token should have been verified successfully");
+    assert_eq!(access_token.audiences(), &audiences);
+}
+
+#[test]
+fn token_of_trusted_issuer() {
+    let user = get_user();
+    let keypair = get_keypair();
+    let old_realm = Realm::default();
+    let new_realm = Realm {
+        issuer: "nimbus-auth-v2".to_string(),
+        trusted_issuers: vec![old_realm.issuer.clone()],
+        ..Realm::default()
+    };
+
+    let access_token = AccessToken::new(
+        user.claims().clone(),
+        OffsetDateTime::now_utc(),
+        AccessTokenExpirationSeconds(ACCESS_TOKEN_EXPIRATION_SECONDS_DEFAULT),
+        AccessTokenMaxGroups(ACCESS_TOKEN_MAX_GROUPS_DEFAULT),
+        &AccessTokenAttributes::default(),
+    );
+    let signed_token = access_token
+        .sign(&keypair, &old_realm)
+        .expect("token should have been signed successfully");
+
+    let result = AccessToken::verify_with_active(&signed_token, &keypair, &new_realm);
+    assert!(result.is_ok());
+
+    let untrusting_realm = Realm {
+        trusted_issuers: vec![],
+        ..new_realm
+    };
+    let result = AccessToken::verify_with_active(&signed_token, &keypair, &untrusting_realm);
+    assert!(matches!(result, Err(VerificationError::Decoding(..))));
+}
+
+#[test]
+fn exchange_narrows_scopes_and_lifetime() {
+    let user = get_user();
+    let current_time = OffsetDateTime::now_utc();
+    let access_token = AccessToken::new(
+        user.claims().clone(),
+        current_time,
+        AccessTokenExpirationSeconds(ACCESS_TOKEN_EXPIRATION_SECONDS_DEFAULT),
+        AccessTokenMaxGroups(ACCESS_TOKEN_MAX_GROUPS_DEFAULT),
+        &AccessTokenAttributes::default(),
+    )
+    .with_audiences(Audiences::restore(vec![
+        "nimbus".to_string(),
+        "billing".to_string(),
+    ]));
+    let audiences = Audiences::restore(vec!["billing".to_string()]);
+
+    let exchanged = access_token
+        .exchange(
+            Some(&BTreeSet::new()),
+            audiences.clone(),
+            None,
+            current_time,
+            AccessTokenExpirationSeconds(ACCESS_TOKEN_EXPIRATION_SECONDS_DEFAULT * 2),
+        )
+        .expect("token should have been exchanged successfully");
+
+    assert_eq!(exchanged.user_claims().id(), user.id());
+    assert!(exchanged.user_claims().permissions().is_empty());
+    assert_eq!(exchanged.audiences(), &audiences);
+    assert_eq!(exchanged.expires_at(), access_token.expires_at());
+}
+
+#[test]
+fn exchange_with_not_granted_scope() {
+    let user = get_user();
+    let access_token = AccessToken::new(
+        user.claims().clone(),
+        OffsetDateTime::now_utc(),
+        AccessTokenExpirationSeconds(ACCESS_TOKEN_EXPIRATION_SECONDS_DEFAULT),
+        AccessTokenMaxGroups(ACCESS_TOKEN_MAX_GROUPS_DEFAULT),
+        &AccessTokenAttributes::default(),
+    );
+    let scopes = BTreeSet::from([Permission::from("auth:users:write")
+        .expect("permission should have been constructed successfully")]);
+
+    let result = access_token.exchange(
+        Some(&scopes),
+        Audiences::default(),
+        None,
+        OffsetDateTime::now_utc(),
+        AccessTokenExpirationSeconds(ACCESS_TOKEN_EXPIRATION_SECONDS_DEFAULT),
+    );
+
+    assert!(matches!(
+        result,
+        Err(ExchangeAccessTokenError::ScopeIsNotGranted { scope }) if scope == "auth:users:write"
+    ));
+}
+
+#[test]
+fn exchange_with_not_granted_audience() {
+    let user = get_user();
+    let access_token = AccessToken::new(
+        user.claims().clone(),
+        OffsetDateTime::now_utc(),
+        AccessTokenExpirationSeconds(ACCESS_TOKEN_EXPIRATION_SECONDS_DEFAULT),
+        AccessTokenMaxGroups(ACCESS_TOKEN_MAX_GROUPS_DEFAULT),
+        &AccessTokenAttributes::default(),
+    )
+    .with_audiences(Audiences::restore(vec!["billing".to_string()]));
+
+    let result = access_token.exchange(
+        None,
+        Audiences::restore(vec!["payments".to_string()]),
+        None,
+        OffsetDateTime::now_utc(),
+        AccessTokenExpirationSeconds(ACCESS_TOKEN_EXPIRATION_SECONDS_DEFAULT),
+    );
+
+    assert!(matches!(
+        result,
+        Err(ExchangeAccessTokenError::AudienceIsNotGranted { audience }) if audience == "payments"
+    ));
+}
+
+#[test]
+fn encode_decode_delegation_chain() {
+    let user = get_user();
+    let keypair = get_keypair();
+    let current_time = OffsetDateTime::now_utc();
+    let access_token = AccessToken::new(
+        user.claims().clone(),
+        current_time,
+        AccessTokenExpirationSeconds(ACCESS_TOKEN_EXPIRATION_SECONDS_DEFAULT),
+        AccessTokenMaxGroups(ACCESS_TOKEN_MAX_GROUPS_DEFAULT),
+        &AccessTokenAttributes::default(),
+    );
+    let delegated = access_token
+        .exchange(
+            None,
+            Audiences::default(),
+            Some(Actor::new("gateway", None)),
+            current_time,
+            AccessTokenExpirationSeconds(ACCESS_TOKEN_EXPIRATION_SECONDS_DEFAULT),
+        )
+        .expect("token should have been exchanged successfully");
+    let actor = Actor::new("billing", delegated.actor().cloned());
+    let chained = delegated
+        .exchange(
+            None,
+            Audiences::default(),
+            Some(actor.clone()),
+            current_time,
+            AccessTokenExpirationSeconds(ACCESS_TOKEN_EXPIRATION_SECONDS_DEFAULT),
+        )
+        .expect("token should have been exchanged successfully");
+    let signed_token = chained
+        .sign(&keypair, &Realm::default())
+        .expect("token should have been signed successfully");
+
+    let verified = AccessToken::verify_with_active(&signed_token, &keypair, &Realm::default())
+        .expect("token should have been verified successfully");
+
+    assert_eq!(verified.actor(), Some(&actor));
+    assert_eq!(
+        verified
+            .actor()
+            .and_then(Actor::prior_actor)
+            .map(Actor::subject),
+        Some("gateway")
+    );
+}
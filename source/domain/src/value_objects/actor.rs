@@ -0,0 +1,26 @@
+/// Party acting on behalf of the subject of an access token, as in the `act` claim
+///
+/// Nested actors are the prior parties of a delegation chain, the outermost one is the current actor
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Actor {
+    subject: String,
+    actor: Option<Box<Actor>>,
+}
+
+impl Actor {
+    /// Subject is the id of the user or the client that acts
+    pub fn new(subject: &str, prior_actor: Option<Actor>) -> Self {
+        Self {
+            subject: subject.to_string(),
+            actor: prior_actor.map(Box::new),
+        }
+    }
+
+    pub fn subject(&self) -> &str {
+        &self.subject
+    }
+
+    pub fn prior_actor(&self) -> Option<&Actor> {
+        self.actor.as_deref()
+    }
+}
@@ -0,0 +1,48 @@
+use nimbus_auth_shared::types::Realm;
+
+use crate::value_objects::audiences::errors::AudiencesError;
+
+pub mod errors;
+#[cfg(test)]
+mod tests;
+
+/// Audiences an access token targets, limited to the audiences allowed in its realm
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Audiences(Vec<String>);
+
+impl Audiences {
+    /// No requested audiences fall back to the default audience of the realm
+    pub fn from(requested: &[String], realm: &Realm) -> Result<Self, AudiencesError> {
+        if requested.is_empty() {
+            return Ok(Self(
+                realm
+                    .default_audience()
+                    .map(|audience| vec![audience.to_string()])
+                    .unwrap_or_default(),
+            ));
+        }
+
+        let mut audiences = Vec::with_capacity(requested.len());
+        for audience in requested {
+            if !realm.audiences.contains(audience) {
+                return Err(AudiencesError::NotAllowed {
+                    audience: audience.to_string(),
+                });
+            }
+            if !audiences.contains(audience) {
+                audiences.push(audience.to_string());
+            }
+        }
+
+        Ok(Self(audiences))
+    }
+
+    /// Restores audiences of an already verified token
+    pub fn restore(audiences: Vec<String>) -> Self {
+        Self(audiences)
+    }
+
+    pub fn value(&self) -> &[String] {
+        &self.0
+    }
+}
@@ -0,0 +1,7 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum AudiencesError {
+    #[error("audience: {audience} is not allowed")]
+    NotAllowed { audience: String },
+}
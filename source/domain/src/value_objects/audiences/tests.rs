@@ -0,0 +1,44 @@
+use nimbus_auth_shared::types::Realm;
+
+use crate::value_objects::audiences::{Audiences, errors::AudiencesError};
+
+fn get_realm() -> Realm {
+    Realm {
+        audiences: vec!["nimbus".to_string(), "billing".to_string()],
+        ..Realm::default()
+    }
+}
+
+#[test]
+fn no_requested_audiences() {
+    let audiences = Audiences::from(&[], &get_realm())
+        .expect("audiences should have been constructed successfully");
+
+    assert_eq!(audiences.value(), ["nimbus"]);
+}
+
+#[test]
+fn allowed_audiences() {
+    let requested = [
+        "billing".to_string(),
+        "nimbus".to_string(),
+        "billing".to_string(),
+    ];
+
+    let audiences = Audiences::from(&requested, &get_realm())
+        .expect("audiences should have been constructed successfully");
+
+    assert_eq!(audiences.value(), ["billing", "nimbus"]);
+}
+
+#[test]
+fn not_allowed_audience() {
+    let requested = ["nimbus".to_string(), "payments".to_string()];
+
+    let result = Audiences::from(&requested, &get_realm());
+
+    assert!(matches!(
+        result,
+        Err(AudiencesError::NotAllowed { audience }) if audience == "payments"
+    ));
+}
@@ -0,0 +1,95 @@
+use sha1::{Digest, Sha1};
+
+use crate::value_objects::breached_passwords_filter::errors::BreachedPasswordsFilterError;
+
+pub mod errors;
+#[cfg(test)]
+mod tests;
+
+const FILE_MAGIC: &[u8; 8] = b"NBPF\0\0\0\x01";
+const PREFIX_SIZE: usize = size_of::<u64>();
+
+/// Set of known breached passwords stored as sorted 64 bit prefixes of their SHA-1 digests
+///
+/// Prefixes keep the filter compact (8 bytes per password) while false positives stay negligible for corpora of billions of passwords
+#[derive(Debug, Default)]
+pub struct BreachedPasswordsFilter {
+    sha1_prefixes: Vec<u64>,
+}
+
+impl BreachedPasswordsFilter {
+    pub fn empty() -> Self {
+        Self::default()
+    }
+
+    pub fn from_sha1_digests<I: IntoIterator<Item = [u8; 20]>>(digests: I) -> Self {
+        let mut sha1_prefixes: Vec<u64> = digests
+            .into_iter()
+            .map(|digest| Self::get_prefix(&digest))
+            .collect();
+        sha1_prefixes.sort_unstable();
+        sha1_prefixes.dedup();
+        Self { sha1_prefixes }
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, BreachedPasswordsFilterError> {
+        let prefixes_bytes = bytes
+            .strip_prefix(FILE_MAGIC)
+            .ok_or(BreachedPasswordsFilterError::InvalidHeader)?;
+
+        if prefixes_bytes.len() % PREFIX_SIZE != 0 {
+            return Err(BreachedPasswordsFilterError::InvalidLength {
+                length: prefixes_bytes.len(),
+            });
+        }
+
+        let sha1_prefixes: Vec<u64> = prefixes_bytes
+            .chunks_exact(PREFIX_SIZE)
+            .map(|chunk| u64::from_be_bytes(chunk.try_into().expect("chunk has prefix size")))
+            .collect();
+
+        if let Some(position) = sha1_prefixes
+            .windows(2)
+            .position(|window| window[0] >= window[1])
+        {
+            return Err(BreachedPasswordsFilterError::NotSorted {
+                position: position + 1,
+            });
+        }
+
+        Ok(Self { sha1_prefixes })
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes =
+            Vec::with_capacity(FILE_MAGIC.len() + self.sha1_prefixes.len() * PREFIX_SIZE);
+        bytes.extend_from_slice(FILE_MAGIC);
+        for prefix in &self.sha1_prefixes {
+            bytes.extend_from_slice(&prefix.to_be_bytes());
+        }
+        bytes
+    }
+
+    pub fn get_sha1_digest(password: &str) -> [u8; 20] {
+        Sha1::digest(password.as_bytes()).into()
+    }
+
+    pub fn contains(&self, password: &str) -> bool {
+        let prefix = Self::get_prefix(&Self::get_sha1_digest(password));
+        self.sha1_prefixes.binary_search(&prefix).is_ok()
+    }
+
+    pub fn len(&self) -> usize {
+        self.sha1_prefixes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sha1_prefixes.is_empty()
+    }
+
+    fn get_prefix(digest: &[u8; 20]) -> u64 {
+        let mut prefix = [0u8; PREFIX_SIZE];
+        prefix.copy_from_slice(&digest[..PREFIX_SIZE]);
+        u64::from_be_bytes(prefix)
+    }
+}
@@ -0,0 +1,11 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum BreachedPasswordsFilterError {
+    #[error("invalid breached passwords filter header")]
+    InvalidHeader,
+    #[error("invalid breached passwords filter length: {length}, should be a multiple of 8")]
+    InvalidLength { length: usize },
+    #[error("breached passwords filter is not sorted at position: {position}")]
+    NotSorted { position: usize },
+}
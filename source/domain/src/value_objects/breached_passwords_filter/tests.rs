@@ -0,0 +1,48 @@
+use crate::value_objects::breached_passwords_filter::{
+    BreachedPasswordsFilter, errors::BreachedPasswordsFilterError,
+};
+
+fn get_filter(passwords: &[&str]) -> BreachedPasswordsFilter {
+    BreachedPasswordsFilter::from_sha1_digests(
+        passwords
+            .iter()
+            .map(|password| BreachedPasswordsFilter::get_sha1_digest(password)),
+    )
+}
+
+#[test]
+fn contains_breached_password() {
+    let filter = get_filter(&["Password123!", "qwerty", "qwerty"]);
+    assert_eq!(filter.len(), 2);
+    assert!(filter.contains("Password123!"));
+    assert!(!filter.contains("StrongPassword123!"));
+}
+
+#[test]
+fn roundtrip_through_bytes() {
+    let filter = get_filter(&["Password123!", "qwerty", "letmein"]);
+    let restored = BreachedPasswordsFilter::from_bytes(&filter.to_bytes()).unwrap();
+    assert_eq!(restored.len(), 3);
+    assert!(restored.contains("letmein"));
+}
+
+#[test]
+fn invalid_header() {
+    let result = BreachedPasswordsFilter::from_bytes(b"not a filter");
+    assert!(matches!(
+        result,
+        Err(BreachedPasswordsFilterError::InvalidHeader)
+    ));
+}
+
+#[test]
+fn unsorted_prefixes() {
+    let mut bytes = BreachedPasswordsFilter::empty().to_bytes();
+    bytes.extend_from_slice(&2u64.to_be_bytes());
+    bytes.extend_from_slice(&1u64.to_be_bytes());
+    let result = BreachedPasswordsFilter::from_bytes(&bytes);
+    assert!(matches!(
+        result,
+        Err(BreachedPasswordsFilterError::NotSorted { position: 1 })
+    ));
+}
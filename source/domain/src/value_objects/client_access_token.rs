@@ -0,0 +1,163 @@
+use nimbus_auth_shared::types::{AccessTokenExpirationSeconds, Realm};
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use ulid::Ulid;
+
+use crate::{
+    entities::{
+        Entity,
+        keypair::{Active, Expiring, KeyPair, SomeKeyPair},
+        oauth_client::value_objects::oauth_client_name::OAuthClientName,
+        role::value_objects::permission::Permission,
+    },
+    value_objects::{
+        access_token::{
+            AccessToken, AccessTokenType, AudienceClaim,
+            errors::{SignAccessTokenError, VerificationError},
+        },
+        audiences::Audiences,
+        client_claims::ClientClaims,
+        identifier::Identifier,
+    },
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Access token of a client acting on its own behalf, without any user involved
+#[derive(Debug, Clone)]
+pub struct ClientAccessToken {
+    client_claims: ClientClaims,
+    audiences: Audiences,
+    expires_at: OffsetDateTime,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Claims {
+    aud: AudienceClaim,
+    exp: usize,
+    iss: String,
+    /// Id of the client
+    sub: String,
+    name: String,
+    /// Space separated scopes, as in OAuth
+    scope: String,
+}
+
+impl ClientAccessToken {
+    pub fn new(
+        client_claims: ClientClaims,
+        current_time: OffsetDateTime,
+        AccessTokenExpirationSeconds(expiration_seconds): AccessTokenExpirationSeconds,
+    ) -> ClientAccessToken {
+        ClientAccessToken {
+            client_claims,
+            audiences: Audiences::default(),
+            expires_at: current_time + time::Duration::seconds(expiration_seconds as i64),
+        }
+    }
+
+    /// Token without audiences targets the default audience of the realm it is signed in
+    pub fn with_audiences(mut self, audiences: Audiences) -> Self {
+        self.audiences = audiences;
+        self
+    }
+
+    pub fn client_claims(&self) -> &ClientClaims {
+        &self.client_claims
+    }
+
+    pub fn audiences(&self) -> &Audiences {
+        &self.audiences
+    }
+
+    pub fn expires_at(&self) -> &OffsetDateTime {
+        &self.expires_at
+    }
+
+    /// Issuer is taken from the realm the token is issued in
+    pub fn sign(
+        &self,
+        keypair: &KeyPair<Active>,
+        realm: &Realm,
+    ) -> Result<String, SignAccessTokenError> {
+        let claims = Claims {
+            aud: AudienceClaim::new(&self.audiences, realm),
+            exp: self.expires_at.unix_timestamp() as usize,
+            iss: realm.issuer.clone(),
+            sub: self.client_claims.id().to_string(),
+            name: self.client_claims.name().to_string(),
+            scope: self
+                .client_claims
+                .scopes()
+                .iter()
+                .map(|scope| scope.value())
+                .collect::<Vec<_>>()
+                .join(" "),
+        };
+
+        AccessToken::encode_claims(&claims, AccessTokenType::Client, keypair)
+    }
+
+    pub fn verify_with_active(
+        signed_token: &str,
+        keypair: &KeyPair<Active>,
+        realm: &Realm,
+    ) -> Result<ClientAccessToken, VerificationError> {
+        ClientAccessToken::verify(
+            signed_token,
+            keypair.id().clone().as_other_entity(),
+            &keypair.value().public_key_pem(),
+            realm,
+        )
+    }
+
+    pub fn verify_with_expiring(
+        signed_token: &str,
+        keypair: &KeyPair<Expiring>,
+        realm: &Realm,
+    ) -> Result<ClientAccessToken, VerificationError> {
+        ClientAccessToken::verify(
+            signed_token,
+            keypair.id().clone().as_other_entity(),
+            &keypair.value().public_key_pem(),
+            realm,
+        )
+    }
+
+    fn verify(
+        signed_token: &str,
+        expected_keypair_id: Identifier<Ulid, SomeKeyPair>,
+        public_key_pem: &str,
+        realm: &Realm,
+    ) -> Result<ClientAccessToken, VerificationError> {
+        let claims = AccessToken::decode_claims::<Claims>(
+            signed_token,
+            AccessTokenType::Client,
+            expected_keypair_id,
+            public_key_pem,
+            realm,
+        )?;
+
+        let client_id = Identifier::from(
+            Ulid::from_string(claims.sub.as_str())
+                .map_err(|err| VerificationError::InvalidClaims(err.to_string()))?,
+        );
+        let client_name = OAuthClientName::from(claims.name.as_str())
+            .map_err(|err| VerificationError::InvalidClaims(err.to_string()))?;
+        let scopes = claims
+            .scope
+            .split_whitespace()
+            .map(Permission::from)
+            .collect::<Result<_, _>>()
+            .map_err(|err| VerificationError::InvalidClaims(err.to_string()))?;
+        let expires_at = OffsetDateTime::from_unix_timestamp(claims.exp as i64)
+            .map_err(|err| VerificationError::InvalidClaims(err.to_string()))?;
+
+        Ok(ClientAccessToken {
+            client_claims: ClientClaims::new(client_id, client_name, scopes),
+            audiences: claims.aud.into_audiences(),
+            expires_at,
+        })
+    }
+}
@@ -0,0 +1,118 @@
+use std::collections::BTreeSet;
+
+use ed25519_dalek::{SigningKey, pkcs8::EncodePrivateKey, pkcs8::spki::der::pem::LineEnding};
+use nimbus_auth_shared::{
+    constants::ACCESS_TOKEN_EXPIRATION_SECONDS_DEFAULT,
+    types::{AccessTokenExpirationSeconds, Realm},
+};
+use rand::rngs::OsRng;
+use time::OffsetDateTime;
+
+use crate::{
+    entities::{
+        keypair::{
+            Active, KeyPair, SomeKeyPair, specifications::NewKeyPairSpecification,
+            value_objects::KeyPairValue,
+        },
+        oauth_client::value_objects::oauth_client_name::OAuthClientName,
+        role::value_objects::permission::Permission,
+    },
+    value_objects::{
+        access_token::{AccessToken, AccessTokenType, errors::VerificationError},
+        client_access_token::ClientAccessToken,
+        client_claims::ClientClaims,
+        identifier::{Identifier, IdentifierOfType},
+    },
+};
+
+const VALID_CLIENT_NAME: &str = "billing";
+const VALID_SCOPES: [&str; 2] = ["orders:read", "invoices:write"];
+
+fn get_client_claims() -> ClientClaims {
+    ClientClaims::new(
+        Identifier::new(),
+        OAuthClientName::from(VALID_CLIENT_NAME)
+            .expect("client name should have been constructed successfully"),
+        VALID_SCOPES
+            .iter()
+            .map(|scope| {
+                Permission::from(scope).expect("scope should have been constructed successfully")
+            })
+            .collect(),
+    )
+}
+
+fn get_keypair() -> KeyPair<Active> {
+    let mut rng = OsRng;
+    let signing_key = SigningKey::generate(&mut rng);
+    let pem = signing_key.to_pkcs8_pem(LineEnding::LF).unwrap();
+    SomeKeyPair::new(NewKeyPairSpecification {
+        value: KeyPairValue::from_pem(pem).expect("key pair value should have been constructed"),
+    })
+}
+
+fn get_signed_token(client_claims: ClientClaims, keypair: &KeyPair<Active>) -> String {
+    ClientAccessToken::new(
+        client_claims,
+        OffsetDateTime::now_utc(),
+        AccessTokenExpirationSeconds(ACCESS_TOKEN_EXPIRATION_SECONDS_DEFAULT),
+    )
+    .sign(keypair, &Realm::default())
+    .expect("token should have been signed successfully")
+}
+
+#[test]
+fn encode_decode() {
+    let client_claims = get_client_claims();
+    let keypair = get_keypair();
+
+    let signed_token = get_signed_token(client_claims.clone(), &keypair);
+
+    let access_token =
+        ClientAccessToken::verify_with_active(&signed_token, &keypair, &Realm::default())
+            .expect("token should have been verified successfully");
+    assert_eq!(access_token.client_claims().id(), client_claims.id());
+    assert_eq!(
+        access_token.client_claims().scopes(),
+        client_claims.scopes()
+    );
+}
+
+#[test]
+fn encode_decode_no_scopes() {
+    let client_claims = get_client_claims();
+    let client_claims = ClientClaims::new(
+        client_claims.id().clone(),
+        client_claims.name().clone(),
+        BTreeSet::new(),
+    );
+    let keypair = get_keypair();
+
+    let signed_token = get_signed_token(client_claims, &keypair);
+
+    let access_token =
+        ClientAccessToken::verify_with_active(&signed_token, &keypair, &Realm::default())
+            .expect("token should have been verified successfully");
+    assert!(access_token.client_claims().scopes().is_empty());
+}
+
+#[test]
+fn client_token_type() {
+    let keypair = get_keypair();
+
+    let signed_token = get_signed_token(get_client_claims(), &keypair);
+
+    let token_type = AccessToken::extract_type(&signed_token)
+        .expect("token type should have been extracted successfully");
+    assert_eq!(token_type, AccessTokenType::Client);
+}
+
+#[test]
+fn client_token_is_not_user_token() {
+    let keypair = get_keypair();
+
+    let signed_token = get_signed_token(get_client_claims(), &keypair);
+
+    let result = AccessToken::verify_with_active(&signed_token, &keypair, &Realm::default());
+    assert!(matches!(result, Err(VerificationError::WrongTokenType)));
+}
@@ -0,0 +1,41 @@
+use std::collections::BTreeSet;
+
+use ulid::Ulid;
+
+use crate::{
+    entities::{
+        oauth_client::{OAuthClient, value_objects::oauth_client_name::OAuthClientName},
+        role::value_objects::permission::Permission,
+    },
+    value_objects::identifier::Identifier,
+};
+
+/// Claims of a client acting on its own behalf, scopes are checked as permissions by services
+#[derive(Debug, Clone)]
+pub struct ClientClaims {
+    id: Identifier<Ulid, OAuthClient>,
+    name: OAuthClientName,
+    scopes: BTreeSet<Permission>,
+}
+
+impl ClientClaims {
+    pub fn new(
+        id: Identifier<Ulid, OAuthClient>,
+        name: OAuthClientName,
+        scopes: BTreeSet<Permission>,
+    ) -> Self {
+        Self { id, name, scopes }
+    }
+
+    pub fn id(&self) -> &Identifier<Ulid, OAuthClient> {
+        &self.id
+    }
+
+    pub fn name(&self) -> &OAuthClientName {
+        &self.name
+    }
+
+    pub fn scopes(&self) -> &BTreeSet<Permission> {
+        &self.scopes
+    }
+}
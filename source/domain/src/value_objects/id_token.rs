@@ -0,0 +1,135 @@
+use jsonwebtoken::{
+    Algorithm, DecodingKey, Validation, decode, decode_header,
+    jwk::{Jwk, JwkSet},
+};
+use serde::Deserialize;
+
+use crate::value_objects::id_token::errors::{IdTokenError, IdTokenKeysError};
+
+pub mod errors;
+#[cfg(test)]
+mod tests;
+
+/// Symmetric algorithms are left out, otherwise anyone knowing the client secret could forge tokens
+const ALLOWED_ALGORITHMS: [Algorithm; 9] = [
+    Algorithm::RS256,
+    Algorithm::RS384,
+    Algorithm::RS512,
+    Algorithm::PS256,
+    Algorithm::PS384,
+    Algorithm::PS512,
+    Algorithm::ES256,
+    Algorithm::ES384,
+    Algorithm::EdDSA,
+];
+
+/// Identity of a user at an upstream OpenID Connect provider, taken from a verified ID token
+#[derive(Debug, Clone)]
+pub struct IdToken {
+    issuer: String,
+    subject: String,
+    email: Option<String>,
+    preferred_username: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Claims {
+    iss: String,
+    sub: String,
+    #[serde(default)]
+    nonce: Option<String>,
+    #[serde(default)]
+    email: Option<String>,
+    #[serde(default)]
+    preferred_username: Option<String>,
+}
+
+/// Public keys of an identity provider as published at its JWKS uri
+#[derive(Debug, Clone)]
+pub struct IdTokenKeys {
+    keys: JwkSet,
+}
+
+impl IdTokenKeys {
+    pub fn from_json(value: &[u8]) -> Result<Self, IdTokenKeysError> {
+        Ok(Self {
+            keys: serde_json::from_slice(value).map_err(IdTokenKeysError::Decoding)?,
+        })
+    }
+
+    /// Providers with a single key may leave the key id out of the token header
+    fn find(&self, key_id: Option<&str>) -> Option<&Jwk> {
+        match key_id {
+            Some(key_id) => self.keys.find(key_id),
+            None => match self.keys.keys.as_slice() {
+                [key] => Some(key),
+                _ => None,
+            },
+        }
+    }
+}
+
+impl IdToken {
+    /// Checks the signature against the provider keys, the issuer, the audience, the expiration
+    /// and the nonce of the authorization request the token was issued for
+    pub fn verify(
+        signed_token: &str,
+        keys: &IdTokenKeys,
+        issuer: &str,
+        client_id: &str,
+        nonce: &str,
+    ) -> Result<IdToken, IdTokenError> {
+        let header = decode_header(signed_token).map_err(IdTokenError::HeaderDecoding)?;
+        if !ALLOWED_ALGORITHMS.contains(&header.alg) {
+            return Err(IdTokenError::UnsupportedAlgorithm {
+                algorithm: format!("{:?}", header.alg),
+            });
+        }
+        let key = keys
+            .find(header.kid.as_deref())
+            .ok_or(IdTokenError::KeyIsNotFound)?;
+        let decoding_key = DecodingKey::from_jwk(key).map_err(IdTokenError::InvalidDecodingKey)?;
+
+        let mut validation = Validation::new(header.alg);
+        validation.set_audience(&[client_id]);
+        validation.set_issuer(&[issuer]);
+        validation.set_required_spec_claims(&["exp", "iss", "aud", "sub"]);
+
+        let claims = decode::<Claims>(signed_token, &decoding_key, &validation)
+            .map_err(IdTokenError::Decoding)?
+            .claims;
+        if claims.nonce.as_deref() != Some(nonce) {
+            return Err(IdTokenError::NonceMismatch);
+        }
+
+        Ok(IdToken {
+            issuer: claims.iss,
+            subject: claims.sub,
+            email: claims.email,
+            preferred_username: claims.preferred_username,
+        })
+    }
+
+    pub fn issuer(&self) -> &str {
+        &self.issuer
+    }
+
+    /// Stable identifier of the user at the provider, unlike the email or the user name
+    pub fn subject(&self) -> &str {
+        &self.subject
+    }
+
+    pub fn email(&self) -> Option<&str> {
+        self.email.as_deref()
+    }
+
+    /// Name for a user created on the first signin, the preferred user name or the local part of the email
+    pub fn suggested_user_name(&self) -> Option<&str> {
+        self.preferred_username.as_deref().or_else(|| {
+            self.email
+                .as_deref()
+                .and_then(|email| email.split_once('@'))
+                .map(|(local_part, _)| local_part)
+        })
+    }
+}
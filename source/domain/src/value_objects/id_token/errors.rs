@@ -0,0 +1,24 @@
+use jsonwebtoken::errors::Error;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum IdTokenKeysError {
+    #[error("keys should be a JWK set. Error: {0}")]
+    Decoding(#[source] serde_json::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum IdTokenError {
+    #[error("header decoding error: {0}")]
+    HeaderDecoding(#[source] Error),
+    #[error("algorithm: {algorithm} is not supported for ID tokens")]
+    UnsupportedAlgorithm { algorithm: String },
+    #[error("key the ID token is signed with is not found")]
+    KeyIsNotFound,
+    #[error("invalid decoding key. Error: {0}")]
+    InvalidDecodingKey(#[source] Error),
+    #[error("decoding error: {0}")]
+    Decoding(#[source] Error),
+    #[error("nonce does not match the one of the authorization request")]
+    NonceMismatch,
+}
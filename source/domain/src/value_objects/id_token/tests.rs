@@ -0,0 +1,166 @@
+use base64::{Engine, prelude::BASE64_URL_SAFE_NO_PAD};
+use ed25519_dalek::{SigningKey, pkcs8::EncodePrivateKey, pkcs8::spki::der::pem::LineEnding};
+use jsonwebtoken::{Algorithm, EncodingKey, Header, encode};
+use rand::rngs::OsRng;
+use serde_json::{Value, json};
+use time::OffsetDateTime;
+
+use crate::value_objects::id_token::{
+    IdToken, IdTokenKeys,
+    errors::{IdTokenError, IdTokenKeysError},
+};
+
+const ISSUER: &str = "https://idp.example.com";
+const CLIENT_ID: &str = "nimbus";
+const KEY_ID: &str = "idp-key";
+const NONCE: &str = "nonce-of-the-authorization-request";
+const SUBJECT: &str = "248289761001";
+
+fn get_signing_key() -> SigningKey {
+    SigningKey::generate(&mut OsRng)
+}
+
+fn get_keys(signing_key: &SigningKey) -> IdTokenKeys {
+    let jwks = json!({
+        "keys": [{
+            "kty": "OKP",
+            "crv": "Ed25519",
+            "kid": KEY_ID,
+            "x": BASE64_URL_SAFE_NO_PAD.encode(signing_key.verifying_key().to_bytes()),
+        }]
+    });
+    IdTokenKeys::from_json(jwks.to_string().as_bytes()).unwrap()
+}
+
+fn get_claims(audience: &str, nonce: &str) -> Value {
+    json!({
+        "iss": ISSUER,
+        "sub": SUBJECT,
+        "aud": audience,
+        "exp": OffsetDateTime::now_utc().unix_timestamp() + 300,
+        "nonce": nonce,
+        "email": "jane.doe@example.com",
+    })
+}
+
+fn sign(signing_key: &SigningKey, key_id: &str, claims: &Value) -> String {
+    let pem = signing_key.to_pkcs8_pem(LineEnding::LF).unwrap();
+    let mut header = Header::new(Algorithm::EdDSA);
+    header.kid = Some(key_id.to_string());
+    encode(
+        &header,
+        claims,
+        &EncodingKey::from_ed_pem(pem.as_bytes()).unwrap(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn valid_id_token() {
+    let signing_key = get_signing_key();
+    let signed_token = sign(&signing_key, KEY_ID, &get_claims(CLIENT_ID, NONCE));
+
+    let id_token = IdToken::verify(
+        &signed_token,
+        &get_keys(&signing_key),
+        ISSUER,
+        CLIENT_ID,
+        NONCE,
+    )
+    .unwrap();
+
+    assert_eq!(id_token.issuer(), ISSUER);
+    assert_eq!(id_token.subject(), SUBJECT);
+    assert_eq!(id_token.email(), Some("jane.doe@example.com"));
+    assert_eq!(id_token.suggested_user_name(), Some("jane.doe"));
+}
+
+#[test]
+fn id_token_of_another_authorization_request() {
+    let signing_key = get_signing_key();
+    let signed_token = sign(&signing_key, KEY_ID, &get_claims(CLIENT_ID, "other-nonce"));
+
+    let result = IdToken::verify(
+        &signed_token,
+        &get_keys(&signing_key),
+        ISSUER,
+        CLIENT_ID,
+        NONCE,
+    );
+
+    assert!(matches!(result, Err(IdTokenError::NonceMismatch)))
+}
+
+#[test]
+fn id_token_issued_to_another_client() {
+    let signing_key = get_signing_key();
+    let signed_token = sign(&signing_key, KEY_ID, &get_claims("other-client", NONCE));
+
+    let result = IdToken::verify(
+        &signed_token,
+        &get_keys(&signing_key),
+        ISSUER,
+        CLIENT_ID,
+        NONCE,
+    );
+
+    assert!(matches!(result, Err(IdTokenError::Decoding(_))))
+}
+
+#[test]
+fn id_token_signed_with_unknown_key() {
+    let signing_key = get_signing_key();
+    let signed_token = sign(&get_signing_key(), KEY_ID, &get_claims(CLIENT_ID, NONCE));
+    let unknown_key_id_token = sign(&signing_key, "other-key", &get_claims(CLIENT_ID, NONCE));
+
+    let forged_result = IdToken::verify(
+        &signed_token,
+        &get_keys(&signing_key),
+        ISSUER,
+        CLIENT_ID,
+        NONCE,
+    );
+    let unknown_key_id_result = IdToken::verify(
+        &unknown_key_id_token,
+        &get_keys(&signing_key),
+        ISSUER,
+        CLIENT_ID,
+        NONCE,
+    );
+
+    assert!(matches!(forged_result, Err(IdTokenError::Decoding(_))));
+    assert!(matches!(
+        unknown_key_id_result,
+        Err(IdTokenError::KeyIsNotFound)
+    ))
+}
+
+#[test]
+fn id_token_signed_with_client_secret() {
+    let signing_key = get_signing_key();
+    let signed_token = encode(
+        &Header::new(Algorithm::HS256),
+        &get_claims(CLIENT_ID, NONCE),
+        &EncodingKey::from_secret(b"client-secret"),
+    )
+    .unwrap();
+
+    let result = IdToken::verify(
+        &signed_token,
+        &get_keys(&signing_key),
+        ISSUER,
+        CLIENT_ID,
+        NONCE,
+    );
+
+    assert!(matches!(
+        result,
+        Err(IdTokenError::UnsupportedAlgorithm { .. })
+    ))
+}
+
+#[test]
+fn keys_not_in_jwk_set_format() {
+    let result = IdTokenKeys::from_json(b"{\"keys\": \"none\"}");
+    assert!(matches!(result, Err(IdTokenKeysError::Decoding(_))))
+}
@@ -0,0 +1,82 @@
+use std::collections::BTreeMap;
+
+use zeroize::Zeroizing;
+
+use crate::value_objects::password_peppers::errors::PasswordPeppersError;
+
+pub mod errors;
+#[cfg(test)]
+mod tests;
+
+const PEPPER_MIN_LENGTH: usize = 16;
+
+/// Versioned server side secrets applied as Argon2 secret on top of password hashes
+///
+/// New hashes are created with the pepper of the highest version, older versions are kept to verify not yet upgraded hashes
+#[derive(Default)]
+pub struct PasswordPeppers {
+    peppers: BTreeMap<u32, Zeroizing<Vec<u8>>>,
+}
+
+impl PasswordPeppers {
+    pub fn empty() -> Self {
+        Self::default()
+    }
+
+    /// Parses secret file content with one `<version>:<pepper>` entry per line, empty lines and lines starting with `#` are skipped
+    pub fn from(content: &str) -> Result<Self, PasswordPeppersError> {
+        let mut peppers = BTreeMap::new();
+
+        for (index, line) in content.lines().enumerate() {
+            let line_number = index + 1;
+            let line = line.trim_end_matches('\r');
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (version, pepper) = line
+                .split_once(':')
+                .ok_or(PasswordPeppersError::InvalidEntry { line_number })?;
+            let version = version
+                .trim()
+                .parse::<u32>()
+                .ok()
+                .filter(|version| *version > 0)
+                .ok_or(PasswordPeppersError::InvalidVersion { line_number })?;
+
+            if pepper.len() < PEPPER_MIN_LENGTH {
+                return Err(PasswordPeppersError::TooShort {
+                    version,
+                    min_length: PEPPER_MIN_LENGTH,
+                });
+            }
+
+            if peppers
+                .insert(version, Zeroizing::new(pepper.as_bytes().to_vec()))
+                .is_some()
+            {
+                return Err(PasswordPeppersError::DuplicateVersion { version });
+            }
+        }
+
+        Ok(Self { peppers })
+    }
+
+    pub fn current(&self) -> Option<(u32, &[u8])> {
+        self.peppers
+            .last_key_value()
+            .map(|(version, pepper)| (*version, pepper.as_slice()))
+    }
+
+    pub fn get(&self, version: u32) -> Option<&[u8]> {
+        self.peppers.get(&version).map(|pepper| pepper.as_slice())
+    }
+
+    pub fn len(&self) -> usize {
+        self.peppers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.peppers.is_empty()
+    }
+}
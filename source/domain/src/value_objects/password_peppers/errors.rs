@@ -0,0 +1,13 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum PasswordPeppersError {
+    #[error("invalid pepper entry at line {line_number}, should be `<version>:<pepper>`")]
+    InvalidEntry { line_number: usize },
+    #[error("invalid pepper version at line {line_number}, should be positive integer")]
+    InvalidVersion { line_number: usize },
+    #[error("pepper with version {version} is too short, should be at least {min_length} bytes")]
+    TooShort { version: u32, min_length: usize },
+    #[error("pepper version {version} is duplicated")]
+    DuplicateVersion { version: u32 },
+}
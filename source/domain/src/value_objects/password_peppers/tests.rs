@@ -0,0 +1,42 @@
+use crate::value_objects::password_peppers::{PasswordPeppers, errors::PasswordPeppersError};
+
+#[test]
+fn highest_version_is_current() {
+    let peppers = PasswordPeppers::from(
+        "# rotated on 2026-10-01\n1:first-pepper-0123456789\n\n2:second-pepper-0123456789\n",
+    )
+    .unwrap();
+    assert_eq!(peppers.len(), 2);
+    assert_eq!(
+        peppers.current(),
+        Some((2, "second-pepper-0123456789".as_bytes()))
+    );
+    assert_eq!(peppers.get(1), Some("first-pepper-0123456789".as_bytes()));
+}
+
+#[test]
+fn short_pepper() {
+    let result = PasswordPeppers::from("1:short");
+    assert!(matches!(
+        result,
+        Err(PasswordPeppersError::TooShort { version: 1, .. })
+    ));
+}
+
+#[test]
+fn duplicate_version() {
+    let result = PasswordPeppers::from("1:first-pepper-0123456789\n1:second-pepper-0123456789");
+    assert!(matches!(
+        result,
+        Err(PasswordPeppersError::DuplicateVersion { version: 1 })
+    ));
+}
+
+#[test]
+fn invalid_version() {
+    let result = PasswordPeppers::from("0:first-pepper-0123456789");
+    assert!(matches!(
+        result,
+        Err(PasswordPeppersError::InvalidVersion { line_number: 1 })
+    ));
+}
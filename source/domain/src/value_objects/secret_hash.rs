@@ -0,0 +1,47 @@
+use base64::{Engine, prelude::BASE64_URL_SAFE_NO_PAD};
+use sha2::{Digest, Sha256};
+use subtle::ConstantTimeEq;
+
+use crate::value_objects::secret_hash::errors::SecretHashError;
+
+pub mod errors;
+#[cfg(test)]
+mod tests;
+
+const SHA256_DIGEST_LENGTH: usize = 32;
+
+/// BASE64URL(SHA256(secret)) of a client secret or an API key, secrets are random so a slow hash is not needed
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SecretHash {
+    value: String,
+}
+
+impl SecretHash {
+    pub fn hash(secret: &str) -> Self {
+        Self {
+            value: BASE64_URL_SAFE_NO_PAD.encode(Sha256::digest(secret.as_bytes())),
+        }
+    }
+
+    pub fn from(value: &str) -> Result<Self, SecretHashError> {
+        match BASE64_URL_SAFE_NO_PAD.decode(value) {
+            Ok(digest) if digest.len() == SHA256_DIGEST_LENGTH => Ok(Self {
+                value: value.to_string(),
+            }),
+            _ => Err(SecretHashError::InvalidFormat),
+        }
+    }
+
+    /// Digests are compared in constant time
+    pub fn verify(&self, secret: &str) -> bool {
+        let digest = Sha256::digest(secret.as_bytes());
+        match BASE64_URL_SAFE_NO_PAD.decode(&self.value) {
+            Ok(expected) => bool::from(expected.ct_eq(digest.as_slice())),
+            Err(_) => false,
+        }
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
@@ -0,0 +1,7 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum SecretHashError {
+    #[error("secret hash should be base64url encoded sha256 digest")]
+    InvalidFormat,
+}
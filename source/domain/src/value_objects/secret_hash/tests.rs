@@ -0,0 +1,27 @@
+use crate::value_objects::secret_hash::{SecretHash, errors::SecretHashError};
+
+const SECRET: &str = "yD8lWq3h0C0vJf6Qb2sZpVtE9kN4mA7xR1uGcHiLoPw";
+
+#[test]
+fn matching_secret() {
+    let hash = SecretHash::hash(SECRET);
+    assert!(hash.verify(SECRET))
+}
+
+#[test]
+fn other_secret() {
+    let hash = SecretHash::hash(SECRET);
+    assert!(!hash.verify(&SECRET.to_uppercase()))
+}
+
+#[test]
+fn restored_hash() {
+    let hash = SecretHash::from(SecretHash::hash(SECRET).value()).unwrap();
+    assert!(hash.verify(SECRET))
+}
+
+#[test]
+fn hash_of_wrong_length() {
+    let result = SecretHash::from("c2hvcnQ");
+    assert!(matches!(result, Err(SecretHashError::InvalidFormat)))
+}
@@ -1,21 +1,116 @@
-use nimbus_auth_shared::types::UserRole;
+use std::collections::BTreeSet;
+
+use nimbus_auth_shared::constants::{ADMIN_ROLE_NAME, AUTH_PERMISSIONS};
 use ulid::Ulid;
 
 use crate::{
-    entities::user::{User, value_objects::user_name::UserName},
+    entities::{
+        group::Group,
+        role::value_objects::{permission::Permission, role_name::RoleName},
+        user::{
+            User,
+            value_objects::{user_attributes::UserAttributes, user_name::UserName},
+        },
+    },
     value_objects::identifier::Identifier,
 };
 
+/// Permissions are resolved from the roles when the claims are built, so they are not looked up per request
 #[derive(Debug, Clone)]
 pub struct UserClaims {
     id: Identifier<Ulid, User>,
     name: UserName,
-    role: UserRole,
+    roles: BTreeSet<RoleName>,
+    permissions: BTreeSet<Permission>,
+    groups: Vec<Group>,
+    groups_overflow: bool,
+    attributes: UserAttributes,
 }
 
 impl UserClaims {
-    pub fn new(id: Identifier<Ulid, User>, name: UserName, role: UserRole) -> Self {
-        Self { id, name, role }
+    pub fn new(
+        id: Identifier<Ulid, User>,
+        name: UserName,
+        roles: BTreeSet<RoleName>,
+        permissions: BTreeSet<Permission>,
+    ) -> Self {
+        Self {
+            id,
+            name,
+            roles,
+            permissions,
+            groups: Vec::new(),
+            groups_overflow: false,
+            attributes: UserAttributes::default(),
+        }
+    }
+
+    pub fn with_attributes(self, attributes: UserAttributes) -> Self {
+        Self { attributes, ..self }
+    }
+
+    /// Keeps only attributes with the given names, as they are exposed in access tokens
+    pub fn with_attributes_projection(self, names: &[String]) -> Self {
+        Self {
+            attributes: self.attributes.project(names),
+            ..self
+        }
+    }
+
+    /// Groups are kept ordered by name
+    pub fn with_groups(self, mut groups: Vec<Group>) -> Self {
+        groups.sort_by(|a, b| a.name().cmp(b.name()));
+        groups.dedup_by(|a, b| a.name() == b.name());
+        Self { groups, ..self }
+    }
+
+    /// Leaves out groups over the limit and marks the claims as overflowed
+    pub fn with_groups_limit(self, max_groups: usize) -> Self {
+        match self.groups.len() > max_groups {
+            true => {
+                let mut groups = self.groups;
+                groups.truncate(max_groups);
+                Self {
+                    groups,
+                    groups_overflow: true,
+                    ..self
+                }
+            }
+            false => self,
+        }
+    }
+
+    /// Keeps only permissions in the given set, e.g. scopes of an API key
+    pub fn with_permissions_limit(self, allowed: &BTreeSet<Permission>) -> Self {
+        Self {
+            permissions: self.permissions.intersection(allowed).cloned().collect(),
+            ..self
+        }
+    }
+
+    /// Leaves out permissions of the auth service itself and the admin role
+    pub fn without_auth_privileges(self) -> Self {
+        Self {
+            roles: self
+                .roles
+                .into_iter()
+                .filter(|role| role.value() != ADMIN_ROLE_NAME)
+                .collect(),
+            permissions: self
+                .permissions
+                .into_iter()
+                .filter(|permission| !AUTH_PERMISSIONS.contains(&permission.value()))
+                .collect(),
+            ..self
+        }
+    }
+
+    /// Restores the overflow mark of claims carried by an access token
+    pub fn with_groups_overflow(self, groups_overflow: bool) -> Self {
+        Self {
+            groups_overflow,
+            ..self
+        }
     }
 
     pub fn id(&self) -> &Identifier<Ulid, User> {
@@ -26,7 +121,24 @@ impl UserClaims {
         &self.name
     }
 
-    pub fn role(&self) -> &UserRole {
-        &self.role
+    pub fn roles(&self) -> &BTreeSet<RoleName> {
+        &self.roles
+    }
+
+    pub fn permissions(&self) -> &BTreeSet<Permission> {
+        &self.permissions
+    }
+
+    pub fn groups(&self) -> &[Group] {
+        &self.groups
+    }
+
+    /// Some groups of the user are left out of these claims
+    pub fn groups_overflow(&self) -> bool {
+        self.groups_overflow
+    }
+
+    pub fn attributes(&self) -> &UserAttributes {
+        &self.attributes
     }
 }
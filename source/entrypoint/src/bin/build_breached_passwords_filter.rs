@@ -0,0 +1,81 @@
+//! Builds the breached passwords filter loaded by the service at startup
+//!
+//! Usage: build_breached_passwords_filter <plain|sha1> <input path> <output path>
+//!
+//! `plain` input contains one password per line, `sha1` input contains one hex encoded SHA-1 digest per line,
+//! optionally followed by `:<count>` as in Have I Been Pwned downloads
+
+use std::{
+    env,
+    fs::{self, File},
+    io::{BufRead, BufReader},
+};
+
+use nimbus_auth_domain::value_objects::breached_passwords_filter::BreachedPasswordsFilter;
+use nimbus_auth_shared::errors::{ErrorBoxed, ErrorContextExt};
+
+const USAGE: &str =
+    "usage: build_breached_passwords_filter <plain|sha1> <input path> <output path>";
+
+enum InputFormat {
+    Plain,
+    Sha1,
+}
+
+fn main() -> Result<(), ErrorBoxed> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let [format, input_path, output_path] = args.as_slice() else {
+        return Err(ErrorBoxed::from_str(USAGE));
+    };
+
+    let format = match format.as_str() {
+        "plain" => InputFormat::Plain,
+        "sha1" => InputFormat::Sha1,
+        _ => return Err(ErrorBoxed::from_str(USAGE)),
+    };
+
+    let input = File::open(input_path)
+        .map_err(|err| err.with_context(format!("can not open input file: {input_path}")))?;
+
+    let mut digests = Vec::new();
+    for (index, line) in BufReader::new(input).lines().enumerate() {
+        let line = line?;
+        let line = line.trim_end_matches('\r');
+        if line.is_empty() {
+            continue;
+        }
+        let digest = match format {
+            InputFormat::Plain => BreachedPasswordsFilter::get_sha1_digest(line),
+            InputFormat::Sha1 => parse_sha1_hex(line).ok_or(ErrorBoxed::from_str(format!(
+                "invalid SHA-1 digest at line {}",
+                index + 1
+            )))?,
+        };
+        digests.push(digest);
+    }
+
+    let filter = BreachedPasswordsFilter::from_sha1_digests(digests);
+
+    fs::write(output_path, filter.to_bytes())
+        .map_err(|err| err.with_context(format!("can not write output file: {output_path}")))?;
+
+    println!(
+        "breached passwords filter with {} entries written to {output_path}",
+        filter.len()
+    );
+
+    Ok(())
+}
+
+fn parse_sha1_hex(line: &str) -> Option<[u8; 20]> {
+    let hex = line.split(':').next()?.trim();
+    if hex.len() != 40 {
+        return None;
+    }
+
+    let mut digest = [0u8; 20];
+    for (index, byte) in digest.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(hex.get(index * 2..index * 2 + 2)?, 16).ok()?;
+    }
+    Some(digest)
+}
@@ -0,0 +1,119 @@
+//! Picks Argon2id parameters whose hashing time is close to a target on the current machine
+//!
+//! Usage: calibrate_password_hashing <target milliseconds> [parallelism] [max memory cost KiB]
+//!
+//! Memory cost is doubled first until the target or max memory cost is reached, then time cost is increased.
+//! The result is printed as env variables for the service config
+
+use std::{
+    env,
+    time::{Duration, Instant},
+};
+
+use nimbus_auth_domain::{
+    entities::user::value_objects::{password::Password, password_hash::PasswordHash},
+    value_objects::password_peppers::PasswordPeppers,
+};
+use nimbus_auth_shared::{
+    constants::{
+        PASSWORD_HASH_MEMORY_COST_KIB_ENV_VAR_NAME, PASSWORD_HASH_PARALLELISM_DEFAULT,
+        PASSWORD_HASH_PARALLELISM_ENV_VAR_NAME, PASSWORD_HASH_TIME_COST_ENV_VAR_NAME,
+    },
+    errors::{ErrorBoxed, ErrorContextExt},
+    types::PasswordHashingParams,
+};
+use zeroize::Zeroizing;
+
+const USAGE: &str =
+    "usage: calibrate_password_hashing <target milliseconds> [parallelism] [max memory cost KiB]";
+const MAX_MEMORY_COST_KIB_DEFAULT: u32 = 1024 * 1024;
+const MAX_TIME_COST: u32 = 64;
+const MEASUREMENTS_COUNT: usize = 3;
+const CALIBRATION_PASSWORD: &str = "CalibrationPassword123!";
+const CALIBRATION_SALT_B64: &str = "Y2FsaWJyYXRpb25zYWx0";
+
+fn main() -> Result<(), ErrorBoxed> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let target = match args.first() {
+        Some(value) => Duration::from_millis(
+            value
+                .parse()
+                .map_err(|err: std::num::ParseIntError| err.with_context(USAGE))?,
+        ),
+        None => return Err(ErrorBoxed::from_str(USAGE)),
+    };
+    let parallelism = match args.get(1) {
+        Some(value) => value
+            .parse()
+            .map_err(|err: std::num::ParseIntError| err.with_context(USAGE))?,
+        None => PASSWORD_HASH_PARALLELISM_DEFAULT,
+    };
+    let max_memory_cost_kib = match args.get(2) {
+        Some(value) => value
+            .parse()
+            .map_err(|err: std::num::ParseIntError| err.with_context(USAGE))?,
+        None => MAX_MEMORY_COST_KIB_DEFAULT,
+    };
+
+    let mut params = PasswordHashingParams {
+        parallelism,
+        ..PasswordHashingParams::default()
+    };
+    let mut elapsed = measure(&params)?;
+
+    while elapsed < target && params.memory_cost_kib.saturating_mul(2) <= max_memory_cost_kib {
+        let next_params = PasswordHashingParams {
+            memory_cost_kib: params.memory_cost_kib * 2,
+            ..params
+        };
+        let next_elapsed = measure(&next_params)?;
+        if next_elapsed > target {
+            break;
+        }
+        (params, elapsed) = (next_params, next_elapsed);
+    }
+
+    while elapsed < target && params.time_cost < MAX_TIME_COST {
+        let next_params = PasswordHashingParams {
+            time_cost: params.time_cost + 1,
+            ..params
+        };
+        let next_elapsed = measure(&next_params)?;
+        if next_elapsed > target {
+            break;
+        }
+        (params, elapsed) = (next_params, next_elapsed);
+    }
+
+    println!("# hashing takes {} ms", elapsed.as_millis());
+    println!(
+        "{PASSWORD_HASH_MEMORY_COST_KIB_ENV_VAR_NAME}={}",
+        params.memory_cost_kib
+    );
+    println!(
+        "{PASSWORD_HASH_TIME_COST_ENV_VAR_NAME}={}",
+        params.time_cost
+    );
+    println!(
+        "{PASSWORD_HASH_PARALLELISM_ENV_VAR_NAME}={}",
+        params.parallelism
+    );
+
+    Ok(())
+}
+
+fn measure(params: &PasswordHashingParams) -> Result<Duration, ErrorBoxed> {
+    let password = Zeroizing::new(CALIBRATION_PASSWORD.to_string());
+    let mut fastest = Duration::MAX;
+    for _ in 0..MEASUREMENTS_COUNT {
+        let started_at = Instant::now();
+        PasswordHash::hash(
+            Password::from_unvalidated(&password),
+            CALIBRATION_SALT_B64,
+            params,
+            &PasswordPeppers::empty(),
+        )?;
+        fastest = fastest.min(started_at.elapsed());
+    }
+    Ok(fastest)
+}
@@ -0,0 +1,124 @@
+//! Runs admin operations directly against the storage, without a running server or an admin token
+//!
+//! Usage: nimbus-auth-admin [--realm <realm name>] <command> [args]
+//!
+//! Commands:
+//! - migrate
+//! - create-user <user name> [--admin] < password
+//! - promote <user name>
+//! - demote <user name>
+//! - grant-role <user name> <role name>
+//! - revoke-role <user name> <role name>
+//! - bootstrap-admin <user name> [password hash]
+//! - import-users <jsonl|csv> <input path>
+//! - list-sessions <user name>
+//! - revoke-sessions <user name>
+//! - rotate-keypairs
+//! - revoke-keypair <key id>
+//! - export-public-keys
+//! - register-oauth-client <client name> <redirect uri> [redirect uri ...]
+//! - register-service-client <client name> [scope ...]
+//!
+//! Config is read from the same env variables as the server's. Logs go to stderr,
+//! so listings printed to stdout can be piped. Commands run in the default realm
+//! unless another one is given
+
+use std::{env, io};
+
+use nimbus_auth_entrypoint::{
+    commands::{
+        bootstrap_admin::{BOOTSTRAP_ADMIN_COMMAND, run_bootstrap_admin},
+        change_user_roles::{
+            DEMOTE_USER_COMMAND, GRANT_ROLE_COMMAND, PROMOTE_USER_COMMAND, REVOKE_ROLE_COMMAND,
+            run_change_user_roles,
+        },
+        create_user::{CREATE_USER_COMMAND, run_create_user},
+        export_public_keys::{EXPORT_PUBLIC_KEYS_COMMAND, run_export_public_keys},
+        import_users::{IMPORT_USERS_COMMAND, run_import_users},
+        list_user_sessions::{LIST_USER_SESSIONS_COMMAND, run_list_user_sessions},
+        migrate::{MIGRATE_COMMAND, run_migrate},
+        register_oauth_client::{REGISTER_OAUTH_CLIENT_COMMAND, run_register_oauth_client},
+        register_service_client::{REGISTER_SERVICE_CLIENT_COMMAND, run_register_service_client},
+        revoke_keypair::{REVOKE_KEYPAIR_COMMAND, run_revoke_keypair},
+        revoke_user_sessions::{REVOKE_USER_SESSIONS_COMMAND, run_revoke_user_sessions},
+        rotate_keypairs::{ROTATE_KEYPAIRS_COMMAND, run_rotate_keypairs},
+    },
+    errors::EntryPointError,
+    setup::{build_shared_services, build_use_cases, connect_postgres_db, get_config_from_env},
+};
+use nimbus_auth_shared::errors::ErrorBoxed;
+use tracing::subscriber;
+use tracing_subscriber::{EnvFilter, Registry, fmt, layer::SubscriberExt};
+
+const USAGE: &str = "usage: nimbus-auth-admin [--realm <realm name>] <command> [args], \
+    see the binary docs for commands";
+const REALM_OPTION: &str = "--realm";
+
+#[tokio::main]
+async fn main() -> Result<(), EntryPointError> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let (realm_name, args) = match args.as_slice() {
+        [option, realm_name, args @ ..] if option == REALM_OPTION => {
+            (Some(realm_name.as_str()), args)
+        }
+        args => (None, args),
+    };
+    let Some((command, args)) = args.split_first() else {
+        return Err(EntryPointError::Usage(USAGE));
+    };
+
+    let config = get_config_from_env()?;
+
+    configure_tracing()?;
+
+    let postgres_db = connect_postgres_db(&config).await?;
+
+    // migrations are run before anything else touches the database
+    if command == MIGRATE_COMMAND {
+        return run_migrate(&postgres_db, args).await;
+    }
+
+    let realm = match realm_name {
+        Some(realm_name) => config
+            .realms()
+            .iter()
+            .find(|realm| realm.name == realm_name)
+            .ok_or(EntryPointError::UnknownRealm {
+                name: realm_name.to_string(),
+            })?
+            .clone(),
+        None => config.default_realm().clone(),
+    };
+
+    let shared_services = build_shared_services(&config).await?;
+    let use_cases = build_use_cases(&config, postgres_db, &shared_services, &realm).await?;
+
+    match command.as_str() {
+        CREATE_USER_COMMAND => run_create_user(&use_cases, args).await,
+        PROMOTE_USER_COMMAND | DEMOTE_USER_COMMAND | GRANT_ROLE_COMMAND | REVOKE_ROLE_COMMAND => {
+            run_change_user_roles(&use_cases, command, args).await
+        }
+        BOOTSTRAP_ADMIN_COMMAND => run_bootstrap_admin(&use_cases, args).await,
+        IMPORT_USERS_COMMAND => run_import_users(&use_cases, args).await,
+        LIST_USER_SESSIONS_COMMAND => run_list_user_sessions(&use_cases, args).await,
+        REVOKE_USER_SESSIONS_COMMAND => run_revoke_user_sessions(&use_cases, args).await,
+        ROTATE_KEYPAIRS_COMMAND => run_rotate_keypairs(&use_cases, args).await,
+        REVOKE_KEYPAIR_COMMAND => run_revoke_keypair(&use_cases, args).await,
+        EXPORT_PUBLIC_KEYS_COMMAND => run_export_public_keys(&use_cases, args).await,
+        REGISTER_OAUTH_CLIENT_COMMAND => run_register_oauth_client(&use_cases, args).await,
+        REGISTER_SERVICE_CLIENT_COMMAND => run_register_service_client(&use_cases, args).await,
+        command => Err(EntryPointError::UnknownCommand {
+            command: command.to_string(),
+        }),
+    }
+}
+
+fn configure_tracing() -> Result<(), ErrorBoxed> {
+    let subscriber = Registry::default()
+        .with(fmt::Layer::default().with_writer(io::stderr))
+        .with(EnvFilter::new("info"));
+
+    subscriber::set_global_default(subscriber)?;
+
+    Ok(())
+}
@@ -0,0 +1,12 @@
+pub mod bootstrap_admin;
+pub mod change_user_roles;
+pub mod create_user;
+pub mod export_public_keys;
+pub mod import_users;
+pub mod list_user_sessions;
+pub mod migrate;
+pub mod register_oauth_client;
+pub mod register_service_client;
+pub mod revoke_keypair;
+pub mod revoke_user_sessions;
+pub mod rotate_keypairs;
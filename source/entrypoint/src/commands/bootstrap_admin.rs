@@ -0,0 +1,41 @@
+use nimbus_auth_application::use_cases::{BootstrapAdminRequest, UseCases};
+use nimbus_auth_shared::errors::ErrorBoxed;
+use tracing::info;
+
+use crate::errors::EntryPointError;
+
+pub const BOOTSTRAP_ADMIN_COMMAND: &str = "bootstrap-admin";
+const USAGE: &str = "usage: nimbus-auth-admin bootstrap-admin <user name> [password hash]";
+
+/// Password hash is a PHC string, it is needed only if the user does not exist yet
+pub async fn run_bootstrap_admin(
+    use_cases: &UseCases,
+    args: &[String],
+) -> Result<(), EntryPointError> {
+    let (user_name, password_hash) = match args {
+        [user_name] => (user_name, None),
+        [user_name, password_hash] => (user_name, Some(password_hash.as_str())),
+        _ => return Err(EntryPointError::Usage(USAGE)),
+    };
+
+    let response = use_cases
+        .bootstrap_admin(BootstrapAdminRequest {
+            user_name,
+            password_hash,
+        })
+        .await
+        .map_err(ErrorBoxed::from)?;
+
+    match response.is_created {
+        true => info!(
+            "admin {} is created with id {}",
+            response.user.name, response.user.id
+        ),
+        false => info!(
+            "user {} is promoted to admin, its id is {}",
+            response.user.name, response.user.id
+        ),
+    }
+
+    Ok(())
+}
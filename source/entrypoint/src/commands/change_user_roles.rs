@@ -0,0 +1,57 @@
+use nimbus_auth_application::use_cases::{ChangeUserRolesRequest, UseCases, UserClaimsDto};
+use nimbus_auth_shared::{constants::ADMIN_ROLE_NAME, errors::ErrorBoxed};
+use tracing::info;
+
+use crate::errors::EntryPointError;
+
+pub const PROMOTE_USER_COMMAND: &str = "promote";
+pub const DEMOTE_USER_COMMAND: &str = "demote";
+pub const GRANT_ROLE_COMMAND: &str = "grant-role";
+pub const REVOKE_ROLE_COMMAND: &str = "revoke-role";
+const USAGE: &str = "usage: nimbus-auth-admin <promote|demote> <user name> \
+    or nimbus-auth-admin <grant-role|revoke-role> <user name> <role name>";
+
+/// Promote and demote grant and revoke the admin role,
+/// taking roles away also revokes sessions of the user
+pub async fn run_change_user_roles(
+    use_cases: &UseCases,
+    command: &str,
+    args: &[String],
+) -> Result<(), EntryPointError> {
+    let (user_name, granted_roles, revoked_roles): (_, Vec<String>, Vec<String>) =
+        match (command, args) {
+            (PROMOTE_USER_COMMAND, [user_name]) => {
+                (user_name, vec![ADMIN_ROLE_NAME.to_string()], vec![])
+            }
+            (DEMOTE_USER_COMMAND, [user_name]) => {
+                (user_name, vec![], vec![ADMIN_ROLE_NAME.to_string()])
+            }
+            (GRANT_ROLE_COMMAND, [user_name, role_name]) => {
+                (user_name, vec![role_name.clone()], vec![])
+            }
+            (REVOKE_ROLE_COMMAND, [user_name, role_name]) => {
+                (user_name, vec![], vec![role_name.clone()])
+            }
+            _ => return Err(EntryPointError::Usage(USAGE)),
+        };
+
+    let response = use_cases
+        .change_user_roles(ChangeUserRolesRequest {
+            user: UserClaimsDto::operator(),
+            user_name,
+            granted_roles: &granted_roles,
+            revoked_roles: &revoked_roles,
+            revoke_sessions: !revoked_roles.is_empty(),
+        })
+        .await
+        .map_err(ErrorBoxed::from)?;
+
+    info!(
+        "user {} has roles {}, revoked {} sessions",
+        response.user.name,
+        response.user.roles.join(", "),
+        response.revoked_sessions_count
+    );
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use nimbus_auth_application::use_cases::{CreateUserRequest, UseCases, UserClaimsDto};
+use nimbus_auth_shared::{constants::ADMIN_ROLE_NAME, errors::ErrorBoxed};
+use tokio::io::{self, AsyncBufReadExt, BufReader};
+use tracing::info;
+use zeroize::Zeroizing;
+
+use crate::errors::EntryPointError;
+
+pub const CREATE_USER_COMMAND: &str = "create-user";
+const USAGE: &str = "usage: nimbus-auth-admin create-user <user name> [--admin] < password";
+const ADMIN_FLAG: &str = "--admin";
+
+/// Password is read from the first line of stdin, so it does not get into shell history
+pub async fn run_create_user(use_cases: &UseCases, args: &[String]) -> Result<(), EntryPointError> {
+    let (user_name, roles) = match args {
+        [user_name] => (user_name, vec![]),
+        [user_name, flag] if flag == ADMIN_FLAG => (user_name, vec![ADMIN_ROLE_NAME.to_string()]),
+        _ => return Err(EntryPointError::Usage(USAGE)),
+    };
+
+    let mut password = Zeroizing::new(String::new());
+    BufReader::new(io::stdin()).read_line(&mut password).await?;
+    let password = Zeroizing::new(password.trim_end_matches(['\r', '\n']).to_string());
+    if password.is_empty() {
+        return Err(EntryPointError::Usage(USAGE));
+    }
+
+    let response = use_cases
+        .create_user(CreateUserRequest {
+            user: UserClaimsDto::operator(),
+            user_name,
+            password: &password,
+            roles: &roles,
+        })
+        .await
+        .map_err(ErrorBoxed::from)?;
+
+    info!(
+        "user {} is created with id {} and roles {}",
+        response.user.name,
+        response.user.id,
+        response.user.roles.join(", ")
+    );
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use nimbus_auth_application::use_cases::{ListPublicKeysRequest, UseCases};
+use nimbus_auth_shared::errors::ErrorBoxed;
+use serde::Serialize;
+
+use crate::errors::EntryPointError;
+
+pub const EXPORT_PUBLIC_KEYS_COMMAND: &str = "export-public-keys";
+const USAGE: &str = "usage: nimbus-auth-admin export-public-keys";
+
+#[derive(Serialize)]
+struct PublicKeyRow {
+    key_id: String,
+    public_key_pem: String,
+    expires_at_unix_timestamp: Option<i64>,
+}
+
+/// Prints public keys of active and expiring key pairs as json lines to stdout
+pub async fn run_export_public_keys(
+    use_cases: &UseCases,
+    args: &[String],
+) -> Result<(), EntryPointError> {
+    let [] = args else {
+        return Err(EntryPointError::Usage(USAGE));
+    };
+
+    let response = use_cases
+        .list_public_keys(ListPublicKeysRequest {})
+        .await
+        .map_err(ErrorBoxed::from)?;
+
+    for public_key in response.public_keys {
+        let row = PublicKeyRow {
+            key_id: public_key.key_id,
+            public_key_pem: public_key.public_key_pem,
+            expires_at_unix_timestamp: public_key.expires_at_unix_timestamp,
+        };
+        println!("{}", serde_json::to_string(&row).map_err(ErrorBoxed::from)?);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use nimbus_auth_application::use_cases::{ImportUserRecord, ImportUsersRequest, UseCases};
+use nimbus_auth_shared::errors::{ErrorBoxed, ErrorContextExt};
+use serde::Deserialize;
+use tokio::fs;
+use tracing::{info, warn};
+
+use crate::errors::EntryPointError;
+
+pub const IMPORT_USERS_COMMAND: &str = "import-users";
+const USAGE: &str = "usage: nimbus-auth-admin import-users <jsonl|csv> <input path>";
+
+/// Row of import file, csv files should have `user_name,password_hash` header
+#[derive(Deserialize)]
+struct ImportedUserRow {
+    user_name: String,
+    password_hash: String,
+}
+
+impl From<ImportedUserRow> for ImportUserRecord {
+    fn from(value: ImportedUserRow) -> Self {
+        Self {
+            user_name: value.user_name,
+            password_hash: value.password_hash,
+        }
+    }
+}
+
+pub async fn run_import_users(
+    use_cases: &UseCases,
+    args: &[String],
+) -> Result<(), EntryPointError> {
+    let [format, input_path] = args else {
+        return Err(EntryPointError::Usage(USAGE));
+    };
+
+    let content = fs::read_to_string(input_path)
+        .await
+        .map_err(|err| err.with_context(format!("can not read import file: {input_path}")))?;
+
+    let users = match format.as_str() {
+        "jsonl" => parse_jsonl(&content)?,
+        "csv" => parse_csv(&content)?,
+        _ => return Err(EntryPointError::Usage(USAGE)),
+    };
+
+    let response = use_cases
+        .import_users(ImportUsersRequest { users: &users })
+        .await
+        .map_err(ErrorBoxed::from)?;
+
+    for rejected_user in &response.rejected {
+        warn!(
+            "user {} is not imported: {}",
+            rejected_user.user_name, rejected_user.reason
+        );
+    }
+    info!(
+        "imported {} users, rejected {}",
+        response.imported_count,
+        response.rejected.len()
+    );
+
+    Ok(())
+}
+
+fn parse_jsonl(content: &str) -> Result<Vec<ImportUserRecord>, ErrorBoxed> {
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            serde_json::from_str::<ImportedUserRow>(line)
+                .map(ImportUserRecord::from)
+                .map_err(|err| err.with_context(format!("invalid record at line {}", index + 1)))
+        })
+        .collect()
+}
+
+fn parse_csv(content: &str) -> Result<Vec<ImportUserRecord>, ErrorBoxed> {
+    csv::Reader::from_reader(content.as_bytes())
+        .deserialize::<ImportedUserRow>()
+        .enumerate()
+        .map(|(index, row)| {
+            row.map(ImportUserRecord::from)
+                .map_err(|err| err.with_context(format!("invalid record at row {}", index + 1)))
+        })
+        .collect()
+}
@@ -0,0 +1,42 @@
+use nimbus_auth_application::use_cases::{ListUserSessionsRequest, UseCases, UserClaimsDto};
+use nimbus_auth_shared::errors::ErrorBoxed;
+use serde::Serialize;
+
+use crate::errors::EntryPointError;
+
+pub const LIST_USER_SESSIONS_COMMAND: &str = "list-sessions";
+const USAGE: &str = "usage: nimbus-auth-admin list-sessions <user name>";
+
+#[derive(Serialize)]
+struct SessionRow {
+    session_id: String,
+    expires_at_unix_timestamp: i64,
+}
+
+/// Prints active sessions of the user as json lines to stdout
+pub async fn run_list_user_sessions(
+    use_cases: &UseCases,
+    args: &[String],
+) -> Result<(), EntryPointError> {
+    let [user_name] = args else {
+        return Err(EntryPointError::Usage(USAGE));
+    };
+
+    let response = use_cases
+        .list_user_sessions(ListUserSessionsRequest {
+            user: UserClaimsDto::operator(),
+            user_name,
+        })
+        .await
+        .map_err(ErrorBoxed::from)?;
+
+    for session in response.sessions {
+        let row = SessionRow {
+            session_id: session.session_id,
+            expires_at_unix_timestamp: session.session_expires_at_unix_timestamp,
+        };
+        println!("{}", serde_json::to_string(&row).map_err(ErrorBoxed::from)?);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,23 @@
+use nimbus_auth_infrastructure::postgres_db::PostgresDatabase;
+use nimbus_auth_shared::errors::ErrorBoxed;
+use tracing::info;
+
+use crate::errors::EntryPointError;
+
+pub const MIGRATE_COMMAND: &str = "migrate";
+const USAGE: &str = "usage: nimbus-auth-admin migrate";
+
+pub async fn run_migrate(
+    postgres_db: &PostgresDatabase,
+    args: &[String],
+) -> Result<(), EntryPointError> {
+    let [] = args else {
+        return Err(EntryPointError::Usage(USAGE));
+    };
+
+    postgres_db.migrate().await.map_err(ErrorBoxed::from)?;
+
+    info!("migrations are applied");
+
+    Ok(())
+}
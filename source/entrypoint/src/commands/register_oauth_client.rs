@@ -0,0 +1,41 @@
+use nimbus_auth_application::use_cases::{RegisterOAuthClientRequest, UseCases, UserClaimsDto};
+use nimbus_auth_shared::errors::ErrorBoxed;
+use tracing::info;
+
+use crate::errors::EntryPointError;
+
+pub const REGISTER_OAUTH_CLIENT_COMMAND: &str = "register-oauth-client";
+const USAGE: &str = "usage: nimbus-auth-admin register-oauth-client <client name> <redirect uri> [redirect uri ...]";
+
+/// Client id is printed to stdout, so it can be piped into the client's configuration
+pub async fn run_register_oauth_client(
+    use_cases: &UseCases,
+    args: &[String],
+) -> Result<(), EntryPointError> {
+    let [client_name, redirect_uris @ ..] = args else {
+        return Err(EntryPointError::Usage(USAGE));
+    };
+    if redirect_uris.is_empty() {
+        return Err(EntryPointError::Usage(USAGE));
+    }
+
+    let response = use_cases
+        .register_oauth_client(RegisterOAuthClientRequest {
+            user: UserClaimsDto::operator(),
+            client_name,
+            redirect_uris,
+            confidential: false,
+            scopes: &[],
+        })
+        .await
+        .map_err(ErrorBoxed::from)?;
+
+    info!(
+        "oauth client {} is registered with redirect uris {}",
+        response.client.name,
+        response.client.redirect_uris.join(", ")
+    );
+    println!("{}", response.client.id);
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use nimbus_auth_application::use_cases::{RegisterOAuthClientRequest, UseCases, UserClaimsDto};
+use nimbus_auth_shared::errors::ErrorBoxed;
+use tracing::info;
+
+use crate::errors::EntryPointError;
+
+pub const REGISTER_SERVICE_CLIENT_COMMAND: &str = "register-service-client";
+const USAGE: &str = "usage: nimbus-auth-admin register-service-client <client name> [scope ...]";
+
+/// Client id and secret are printed to stdout on separate lines, the secret can not be retrieved later
+pub async fn run_register_service_client(
+    use_cases: &UseCases,
+    args: &[String],
+) -> Result<(), EntryPointError> {
+    let [client_name, scopes @ ..] = args else {
+        return Err(EntryPointError::Usage(USAGE));
+    };
+
+    let response = use_cases
+        .register_oauth_client(RegisterOAuthClientRequest {
+            user: UserClaimsDto::operator(),
+            client_name,
+            redirect_uris: &[],
+            confidential: true,
+            scopes,
+        })
+        .await
+        .map_err(ErrorBoxed::from)?;
+
+    info!(
+        "service client {} is registered with scopes {}",
+        response.client.name,
+        response.client.scopes.join(", ")
+    );
+    println!("{}", response.client.id);
+    if let Some(client_secret) = response.client_secret {
+        println!("{}", client_secret.as_str());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use nimbus_auth_application::use_cases::{RevokeKeyPairRequest, UseCases, UserClaimsDto};
+use nimbus_auth_shared::errors::ErrorBoxed;
+use tracing::info;
+
+use crate::errors::EntryPointError;
+
+pub const REVOKE_KEYPAIR_COMMAND: &str = "revoke-keypair";
+const USAGE: &str = "usage: nimbus-auth-admin revoke-keypair <key id>";
+
+pub async fn run_revoke_keypair(
+    use_cases: &UseCases,
+    args: &[String],
+) -> Result<(), EntryPointError> {
+    let [key_id] = args else {
+        return Err(EntryPointError::Usage(USAGE));
+    };
+
+    let response = use_cases
+        .revoke_keypair(RevokeKeyPairRequest {
+            user: UserClaimsDto::operator(),
+            key_id,
+        })
+        .await
+        .map_err(ErrorBoxed::from)?;
+
+    match response.new_active_key_id {
+        Some(new_active_key_id) => {
+            info!("active key pair {key_id} is revoked and replaced with {new_active_key_id}")
+        }
+        None => info!("key pair {key_id} is revoked"),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use nimbus_auth_application::use_cases::{RevokeUserSessionsRequest, UseCases, UserClaimsDto};
+use nimbus_auth_shared::errors::ErrorBoxed;
+use tracing::info;
+
+use crate::errors::EntryPointError;
+
+pub const REVOKE_USER_SESSIONS_COMMAND: &str = "revoke-sessions";
+const USAGE: &str = "usage: nimbus-auth-admin revoke-sessions <user name>";
+
+pub async fn run_revoke_user_sessions(
+    use_cases: &UseCases,
+    args: &[String],
+) -> Result<(), EntryPointError> {
+    let [user_name] = args else {
+        return Err(EntryPointError::Usage(USAGE));
+    };
+
+    let response = use_cases
+        .revoke_user_sessions(RevokeUserSessionsRequest {
+            user: UserClaimsDto::operator(),
+            user_name,
+        })
+        .await
+        .map_err(ErrorBoxed::from)?;
+
+    info!(
+        "revoked {} sessions of user {user_name}",
+        response.revoked_sessions_count
+    );
+
+    Ok(())
+}
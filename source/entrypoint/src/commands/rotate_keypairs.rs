@@ -0,0 +1,28 @@
+use nimbus_auth_application::use_cases::{RotateKeyPairsRequest, UseCases, UserClaimsDto};
+use nimbus_auth_shared::errors::ErrorBoxed;
+use tracing::info;
+
+use crate::errors::EntryPointError;
+
+pub const ROTATE_KEYPAIRS_COMMAND: &str = "rotate-keypairs";
+const USAGE: &str = "usage: nimbus-auth-admin rotate-keypairs";
+
+pub async fn run_rotate_keypairs(
+    use_cases: &UseCases,
+    args: &[String],
+) -> Result<(), EntryPointError> {
+    let [] = args else {
+        return Err(EntryPointError::Usage(USAGE));
+    };
+
+    use_cases
+        .rotate_keypairs(RotateKeyPairsRequest {
+            user: UserClaimsDto::operator(),
+        })
+        .await
+        .map_err(ErrorBoxed::from)?;
+
+    info!("key pairs are rotated");
+
+    Ok(())
+}
@@ -7,6 +7,12 @@ use tokio::io;
 pub enum EntryPointError {
     #[error("error sending shutdown signal")]
     ShutdownSignalSending,
+    #[error("unknown command: {command}")]
+    UnknownCommand { command: String },
+    #[error("{0}")]
+    Usage(&'static str),
+    #[error("realm with name: {name} is not configured")]
+    UnknownRealm { name: String },
     #[error(transparent)]
     WebApi(#[from] WebApiError),
     #[error(transparent)]
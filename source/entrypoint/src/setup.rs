@@ -0,0 +1,602 @@
+use std::{collections::HashMap, env, sync::Arc};
+
+use nimbus_auth_application::{
+    services::{
+        identity_provider::IdentityProvider, legacy_authenticator::LegacyAuthenticator,
+        random_service::RandomService, signup_notifier::SignUpNotifier,
+    },
+    use_cases::{UseCases, UseCasesConfig, UseCasesServices},
+};
+use nimbus_auth_domain::{
+    entities::user::value_objects::password_hash::PasswordHash,
+    value_objects::{
+        breached_passwords_filter::BreachedPasswordsFilter, password_peppers::PasswordPeppers,
+    },
+};
+use nimbus_auth_infrastructure::{
+    postgres_db::PostgresDatabase,
+    services_implementations::{
+        filesystem_inmemory_cached_keypair_repository::FileSystemInMemoryCachedKeyPairRepository,
+        oidc_identity_provider::OidcIdentityProvider, os_random_service::OsRandomService,
+        os_time_service::OsTimeService, postgres_api_key_repository::PostgresApiKeyRepository,
+        postgres_authorization_code_repository::PostgresAuthorizationCodeRepository,
+        postgres_device_authorization_repository::PostgresDeviceAuthorizationRepository,
+        postgres_external_identity_repository::PostgresExternalIdentityRepository,
+        postgres_federated_authorization_repository::PostgresFederatedAuthorizationRepository,
+        postgres_group_repository::PostgresGroupRepository,
+        postgres_impersonation_repository::PostgresImpersonationRepository,
+        postgres_legacy_authenticator::PostgresLegacyAuthenticator,
+        postgres_oauth_client_repository::PostgresOAuthClientRepository,
+        postgres_role_repository::PostgresRoleRepository,
+        postgres_session_repository::PostgresSessionRepository,
+        postgres_user_repository::PostgresUserRepository,
+        webhook_signup_notifier::WebhookSignUpNotifier,
+    },
+};
+use nimbus_auth_shared::{
+    config::{AppConfig, AppConfigBuilder, AppConfigRequiredOptions},
+    constants::{
+        ACCESS_TOKEN_ATTRIBUTES_COMMA_SEPARATED_ENV_VAR_NAME,
+        ACCESS_TOKEN_AUDIENCES_COMMA_SEPARATED_ENV_VAR_NAME,
+        ACCESS_TOKEN_EXPIRATION_SECONDS_ENV_VAR_NAME, ACCESS_TOKEN_ISSUER_ENV_VAR_NAME,
+        ACCESS_TOKEN_MAX_GROUPS_ENV_VAR_NAME,
+        ACCESS_TOKEN_TRUSTED_ISSUERS_COMMA_SEPARATED_ENV_VAR_NAME,
+        BREACHED_PASSWORDS_FILTER_PATH_ENV_VAR_NAME, CORS_ORIGINS_COMMA_SEPARATED_ENV_VAR_NAME,
+        KEYPAIRS_STORE_PATH_ENV_VAR_NAME, LEGACY_AUTH_POSTGRES_SCHEMA_ENV_VAR_NAME,
+        LEGACY_AUTH_POSTGRES_TABLE_ENV_VAR_NAME, OAUTH_DEVICE_VERIFICATION_URI_ENV_VAR_NAME,
+        OIDC_PROVIDER_CLIENT_ID_ENV_VAR_SUFFIX, OIDC_PROVIDER_CLIENT_SECRET_ENV_VAR_SUFFIX,
+        OIDC_PROVIDER_ENV_VAR_PREFIX, OIDC_PROVIDER_ISSUER_ENV_VAR_SUFFIX,
+        OIDC_PROVIDER_REDIRECT_URI_ENV_VAR_SUFFIX, OIDC_PROVIDER_SCOPES_COMMA_SEPARATED_DEFAULT,
+        OIDC_PROVIDER_SCOPES_COMMA_SEPARATED_ENV_VAR_SUFFIX,
+        OIDC_PROVIDERS_COMMA_SEPARATED_ENV_VAR_NAME, PASSWORD_ALLOW_SPACES_ENV_VAR_NAME,
+        PASSWORD_ALLOW_UNICODE_ENV_VAR_NAME, PASSWORD_HASH_MEMORY_COST_KIB_ENV_VAR_NAME,
+        PASSWORD_HASH_PARALLELISM_ENV_VAR_NAME, PASSWORD_HASH_TIME_COST_ENV_VAR_NAME,
+        PASSWORD_MAX_LENGTH_ENV_VAR_NAME, PASSWORD_MIN_LENGTH_ENV_VAR_NAME,
+        PASSWORD_MIN_STRENGTH_SCORE_ENV_VAR_NAME, PASSWORD_PEPPERS_PATH_ENV_VAR_NAME,
+        PASSWORD_REQUIRED_CHARACTER_CLASSES_COMMA_SEPARATED_ENV_VAR_NAME,
+        POSTGRESDB_MAX_CONNECTIONS_ENV_VAR_NAME, POSTGRESQL_URL_ENV_VAR_NAME,
+        REALMS_COMMA_SEPARATED_ENV_VAR_NAME, SERVER_ADDR_ENV_VAR_NAME,
+        SESSION_EXPIRATION_SECONDS_ENV_VAR_NAME, SIGNIN_LOCKOUT_BASE_SECONDS_ENV_VAR_NAME,
+        SIGNIN_LOCKOUT_MAX_FAILED_ATTEMPTS_ENV_VAR_NAME, SIGNIN_LOCKOUT_MAX_SECONDS_ENV_VAR_NAME,
+        SIGNUP_NOTIFICATION_WEBHOOK_URL_ENV_VAR_NAME, USE_HSTS_ENV_VAR_NAME,
+        USER_ENUMERATION_PROTECTION_ENV_VAR_NAME,
+    },
+    errors::{ErrorBoxed, ErrorContextExt},
+    types::{OidcProvider, PasswordCharacterClass, Realm},
+};
+use tokio::fs;
+use tracing::{info, subscriber, warn};
+use tracing_subscriber::{EnvFilter, Registry, fmt, layer::SubscriberExt};
+use zeroize::Zeroizing;
+
+pub fn get_config_from_env() -> Result<AppConfig, ErrorBoxed> {
+    dotenvy::dotenv()?;
+
+    let mut config_builder = AppConfigBuilder::new(AppConfigRequiredOptions {
+        server_addr: env::var(SERVER_ADDR_ENV_VAR_NAME).map_err(|err| {
+            err.with_context(format!(
+                "env variable ({SERVER_ADDR_ENV_VAR_NAME}) is required and not presented"
+            ))
+        })?,
+        keypairs_store_path: env::var(KEYPAIRS_STORE_PATH_ENV_VAR_NAME)
+            .map_err(|err| {
+                err.with_context(format!(
+                    "env variable ({KEYPAIRS_STORE_PATH_ENV_VAR_NAME}) is required and not presented"
+                ))
+            })?
+            .parse()?,
+        postgres_db_url: env::var(POSTGRESQL_URL_ENV_VAR_NAME)
+            .map_err(|err| {
+                err.with_context(format!(
+                    "env variable ({POSTGRESQL_URL_ENV_VAR_NAME}) is required and not presented"
+                ))
+            })?
+            .parse()?,
+    });
+
+    if let Ok(value) = env::var(SESSION_EXPIRATION_SECONDS_ENV_VAR_NAME) {
+        let parsed = value.parse().map_err(|err: std::num::ParseIntError| {
+            err.with_context(format!(
+                "env variable ({SESSION_EXPIRATION_SECONDS_ENV_VAR_NAME}) has wrong format, it should be integer"
+            ))
+        })?;
+        config_builder.with_session_expiration_seconds(parsed);
+    };
+
+    if let Ok(value) = env::var(ACCESS_TOKEN_EXPIRATION_SECONDS_ENV_VAR_NAME) {
+        let parsed = value.parse().map_err(|err: std::num::ParseIntError| {
+            err.with_context(format!(
+                "env variable ({ACCESS_TOKEN_EXPIRATION_SECONDS_ENV_VAR_NAME}) has wrong format, it should be integer"
+            ))
+        })?;
+        config_builder.with_access_token_expiration_seconds(parsed);
+    }
+
+    if let Ok(value) = env::var(ACCESS_TOKEN_MAX_GROUPS_ENV_VAR_NAME) {
+        let parsed = value.parse().map_err(|err: std::num::ParseIntError| {
+            err.with_context(format!(
+                "env variable ({ACCESS_TOKEN_MAX_GROUPS_ENV_VAR_NAME}) has wrong format, it should be integer"
+            ))
+        })?;
+        config_builder.with_access_token_max_groups(parsed);
+    }
+
+    if let Ok(value) = env::var(ACCESS_TOKEN_ATTRIBUTES_COMMA_SEPARATED_ENV_VAR_NAME) {
+        config_builder.with_access_token_attributes_comma_separated(&value);
+    }
+
+    if let Ok(value) = env::var(POSTGRESDB_MAX_CONNECTIONS_ENV_VAR_NAME) {
+        let parsed = value.parse().map_err(|err: std::num::ParseIntError| {
+            err.with_context(format!(
+                "env variable ({POSTGRESDB_MAX_CONNECTIONS_ENV_VAR_NAME}) has wrong format, it should be integer"
+            ))
+        })?;
+        config_builder.with_postgres_db_max_connections(parsed);
+    }
+
+    if let Ok(value) = env::var(USE_HSTS_ENV_VAR_NAME)
+        && value.parse::<bool>().map_err(|err| {
+            err.with_context(format!(
+                "env variable ({USE_HSTS_ENV_VAR_NAME}) has wrong format, it should be `true` or `false`"
+            ))
+        })?
+    {
+        config_builder.with_hsts();
+    }
+
+    if let Ok(value) = env::var(CORS_ORIGINS_COMMA_SEPARATED_ENV_VAR_NAME) {
+        config_builder.with_cors_origins_comma_separated(&value);
+    }
+
+    if let Ok(value) = env::var(PASSWORD_MIN_LENGTH_ENV_VAR_NAME) {
+        let parsed = value.parse().map_err(|err: std::num::ParseIntError| {
+            err.with_context(format!(
+                "env variable ({PASSWORD_MIN_LENGTH_ENV_VAR_NAME}) has wrong format, it should be integer"
+            ))
+        })?;
+        config_builder.with_password_min_length(parsed);
+    }
+
+    if let Ok(value) = env::var(PASSWORD_MAX_LENGTH_ENV_VAR_NAME) {
+        let parsed = value.parse().map_err(|err: std::num::ParseIntError| {
+            err.with_context(format!(
+                "env variable ({PASSWORD_MAX_LENGTH_ENV_VAR_NAME}) has wrong format, it should be integer"
+            ))
+        })?;
+        config_builder.with_password_max_length(parsed);
+    }
+
+    if let Ok(value) = env::var(PASSWORD_REQUIRED_CHARACTER_CLASSES_COMMA_SEPARATED_ENV_VAR_NAME) {
+        let parsed = value
+            .split(",")
+            .filter(|character_class| !character_class.trim().is_empty())
+            .map(|character_class| PasswordCharacterClass::try_from(character_class.trim()))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|err| {
+                ErrorBoxed::from_str(format!(
+                    "env variable ({PASSWORD_REQUIRED_CHARACTER_CLASSES_COMMA_SEPARATED_ENV_VAR_NAME}) has wrong format: {err}"
+                ))
+            })?;
+        config_builder.with_password_required_character_classes(parsed);
+    }
+
+    if let Ok(value) = env::var(PASSWORD_ALLOW_UNICODE_ENV_VAR_NAME)
+        && value.parse::<bool>().map_err(|err| {
+            err.with_context(format!(
+                "env variable ({PASSWORD_ALLOW_UNICODE_ENV_VAR_NAME}) has wrong format, it should be `true` or `false`"
+            ))
+        })?
+    {
+        config_builder.with_password_unicode_allowed();
+    }
+
+    if let Ok(value) = env::var(PASSWORD_ALLOW_SPACES_ENV_VAR_NAME)
+        && value.parse::<bool>().map_err(|err| {
+            err.with_context(format!(
+                "env variable ({PASSWORD_ALLOW_SPACES_ENV_VAR_NAME}) has wrong format, it should be `true` or `false`"
+            ))
+        })?
+    {
+        config_builder.with_password_spaces_allowed();
+    }
+
+    if let Ok(value) = env::var(PASSWORD_MIN_STRENGTH_SCORE_ENV_VAR_NAME) {
+        let parsed = value.parse().map_err(|err: std::num::ParseIntError| {
+            err.with_context(format!(
+                "env variable ({PASSWORD_MIN_STRENGTH_SCORE_ENV_VAR_NAME}) has wrong format, it should be integer"
+            ))
+        })?;
+        config_builder.with_password_min_strength_score(parsed);
+    }
+
+    if let Ok(value) = env::var(PASSWORD_HASH_MEMORY_COST_KIB_ENV_VAR_NAME) {
+        let parsed = value.parse().map_err(|err: std::num::ParseIntError| {
+            err.with_context(format!(
+                "env variable ({PASSWORD_HASH_MEMORY_COST_KIB_ENV_VAR_NAME}) has wrong format, it should be integer"
+            ))
+        })?;
+        config_builder.with_password_hash_memory_cost_kib(parsed);
+    }
+
+    if let Ok(value) = env::var(PASSWORD_HASH_TIME_COST_ENV_VAR_NAME) {
+        let parsed = value.parse().map_err(|err: std::num::ParseIntError| {
+            err.with_context(format!(
+                "env variable ({PASSWORD_HASH_TIME_COST_ENV_VAR_NAME}) has wrong format, it should be integer"
+            ))
+        })?;
+        config_builder.with_password_hash_time_cost(parsed);
+    }
+
+    if let Ok(value) = env::var(PASSWORD_HASH_PARALLELISM_ENV_VAR_NAME) {
+        let parsed = value.parse().map_err(|err: std::num::ParseIntError| {
+            err.with_context(format!(
+                "env variable ({PASSWORD_HASH_PARALLELISM_ENV_VAR_NAME}) has wrong format, it should be integer"
+            ))
+        })?;
+        config_builder.with_password_hash_parallelism(parsed);
+    }
+
+    if let Ok(value) = env::var(PASSWORD_PEPPERS_PATH_ENV_VAR_NAME) {
+        config_builder.with_password_peppers_path(value.parse()?);
+    }
+
+    if let Ok(value) = env::var(BREACHED_PASSWORDS_FILTER_PATH_ENV_VAR_NAME) {
+        config_builder.with_breached_passwords_filter_path(value.parse()?);
+    }
+
+    if let Ok(value) = env::var(LEGACY_AUTH_POSTGRES_SCHEMA_ENV_VAR_NAME) {
+        config_builder.with_legacy_auth_postgres_schema(&value);
+    }
+
+    if let Ok(value) = env::var(LEGACY_AUTH_POSTGRES_TABLE_ENV_VAR_NAME) {
+        config_builder.with_legacy_auth_postgres_table(&value);
+    }
+
+    if let Ok(value) = env::var(SIGNIN_LOCKOUT_MAX_FAILED_ATTEMPTS_ENV_VAR_NAME) {
+        let parsed = value.parse().map_err(|err: std::num::ParseIntError| {
+            err.with_context(format!(
+                "env variable ({SIGNIN_LOCKOUT_MAX_FAILED_ATTEMPTS_ENV_VAR_NAME}) has wrong format, it should be integer"
+            ))
+        })?;
+        config_builder.with_signin_lockout_max_failed_attempts(parsed);
+    }
+
+    if let Ok(value) = env::var(SIGNIN_LOCKOUT_BASE_SECONDS_ENV_VAR_NAME) {
+        let parsed = value.parse().map_err(|err: std::num::ParseIntError| {
+            err.with_context(format!(
+                "env variable ({SIGNIN_LOCKOUT_BASE_SECONDS_ENV_VAR_NAME}) has wrong format, it should be integer"
+            ))
+        })?;
+        config_builder.with_signin_lockout_base_seconds(parsed);
+    }
+
+    if let Ok(value) = env::var(SIGNIN_LOCKOUT_MAX_SECONDS_ENV_VAR_NAME) {
+        let parsed = value.parse().map_err(|err: std::num::ParseIntError| {
+            err.with_context(format!(
+                "env variable ({SIGNIN_LOCKOUT_MAX_SECONDS_ENV_VAR_NAME}) has wrong format, it should be integer"
+            ))
+        })?;
+        config_builder.with_signin_lockout_max_seconds(parsed);
+    }
+
+    if let Ok(value) = env::var(USER_ENUMERATION_PROTECTION_ENV_VAR_NAME)
+        && value.parse::<bool>().map_err(|err| {
+            err.with_context(format!(
+                "env variable ({USER_ENUMERATION_PROTECTION_ENV_VAR_NAME}) has wrong format, it should be `true` or `false`"
+            ))
+        })?
+    {
+        config_builder.with_user_enumeration_protection();
+    }
+
+    if let Ok(value) = env::var(SIGNUP_NOTIFICATION_WEBHOOK_URL_ENV_VAR_NAME) {
+        config_builder.with_signup_notification_webhook_url(&value);
+    }
+
+    if let Ok(value) = env::var(OAUTH_DEVICE_VERIFICATION_URI_ENV_VAR_NAME) {
+        config_builder.with_oauth_device_verification_uri(&value);
+    }
+
+    if let Ok(value) = env::var(ACCESS_TOKEN_ISSUER_ENV_VAR_NAME) {
+        config_builder.with_access_token_issuer(&value);
+    }
+
+    if let Ok(value) = env::var(ACCESS_TOKEN_TRUSTED_ISSUERS_COMMA_SEPARATED_ENV_VAR_NAME) {
+        config_builder.with_access_token_trusted_issuers_comma_separated(&value);
+    }
+
+    if let Ok(value) = env::var(ACCESS_TOKEN_AUDIENCES_COMMA_SEPARATED_ENV_VAR_NAME) {
+        config_builder.with_access_token_audiences_comma_separated(&value);
+    }
+
+    if let Ok(value) = env::var(REALMS_COMMA_SEPARATED_ENV_VAR_NAME) {
+        config_builder.with_realms_comma_separated(&value);
+    }
+
+    if let Ok(value) = env::var(OIDC_PROVIDERS_COMMA_SEPARATED_ENV_VAR_NAME) {
+        for name in value.split(",").map(|name| name.trim()) {
+            if name.is_empty() {
+                continue;
+            }
+            config_builder.with_oidc_provider(get_oidc_provider_from_env(name)?);
+        }
+    }
+
+    Ok(config_builder.build()?)
+}
+
+/// Reads settings of the provider from `OIDC_PROVIDER_<NAME>_<SETTING>` env variables
+fn get_oidc_provider_from_env(name: &str) -> Result<OidcProvider, ErrorBoxed> {
+    let prefix = format!(
+        "{OIDC_PROVIDER_ENV_VAR_PREFIX}{}",
+        name.to_ascii_uppercase().replace('-', "_")
+    );
+    let get_required = |suffix: &str| {
+        let env_var_name = format!("{prefix}{suffix}");
+        env::var(&env_var_name).map_err(|err| {
+            err.with_context(format!(
+                "env variable ({env_var_name}) is required and not presented"
+            ))
+        })
+    };
+
+    let scopes = env::var(format!(
+        "{prefix}{OIDC_PROVIDER_SCOPES_COMMA_SEPARATED_ENV_VAR_SUFFIX}"
+    ))
+    .unwrap_or(OIDC_PROVIDER_SCOPES_COMMA_SEPARATED_DEFAULT.to_string());
+
+    Ok(OidcProvider {
+        name: name.to_string(),
+        issuer: get_required(OIDC_PROVIDER_ISSUER_ENV_VAR_SUFFIX)?,
+        client_id: get_required(OIDC_PROVIDER_CLIENT_ID_ENV_VAR_SUFFIX)?,
+        client_secret: get_required(OIDC_PROVIDER_CLIENT_SECRET_ENV_VAR_SUFFIX)?,
+        redirect_uri: get_required(OIDC_PROVIDER_REDIRECT_URI_ENV_VAR_SUFFIX)?,
+        scopes: scopes
+            .split(",")
+            .map(|scope| scope.trim().to_string())
+            .filter(|scope| !scope.is_empty())
+            .collect(),
+    })
+}
+
+pub fn configure_tracing(_: &AppConfig) -> Result<(), ErrorBoxed> {
+    let subscriber = Registry::default()
+        .with(fmt::Layer::default())
+        .with(EnvFilter::new("debug"));
+
+    subscriber::set_global_default(subscriber)?;
+
+    Ok(())
+}
+
+pub async fn connect_postgres_db(
+    app_config: &AppConfig,
+) -> Result<Arc<PostgresDatabase>, ErrorBoxed> {
+    Ok(Arc::new(PostgresDatabase::new(app_config).await?))
+}
+
+/// Services which do not depend on a realm, loaded once and shared by use cases of every realm
+pub struct SharedServices {
+    password_peppers: Arc<PasswordPeppers>,
+    breached_passwords_filter: Arc<BreachedPasswordsFilter>,
+    dummy_password_hash: Option<Arc<PasswordHash>>,
+    identity_providers: HashMap<String, Arc<dyn IdentityProvider>>,
+    signup_notifier: Option<Arc<dyn SignUpNotifier>>,
+}
+
+pub async fn build_shared_services(app_config: &AppConfig) -> Result<SharedServices, ErrorBoxed> {
+    let password_peppers = Arc::new(load_password_peppers(app_config).await?);
+    let breached_passwords_filter = Arc::new(load_breached_passwords_filter(app_config).await?);
+
+    let identity_providers = app_config
+        .oidc_providers()
+        .iter()
+        .map(|provider| {
+            OidcIdentityProvider::new(provider).map(|identity_provider| {
+                (
+                    provider.name.clone(),
+                    Arc::new(identity_provider) as Arc<dyn IdentityProvider>,
+                )
+            })
+        })
+        .collect::<Result<HashMap<_, _>, _>>()?;
+    let signup_notifier = app_config
+        .signup_notification_webhook_url()
+        .map(|url| {
+            WebhookSignUpNotifier::new(url)
+                .map(|notifier| Arc::new(notifier) as Arc<dyn SignUpNotifier>)
+        })
+        .transpose()?;
+
+    let dummy_password_hash = match app_config.user_enumeration_protection() {
+        true => {
+            if signup_notifier.is_none() {
+                warn!(
+                    "signup notification webhook is not configured, signup outcomes are not delivered"
+                );
+            }
+            let salt_b64 = OsRandomService::new().get_random_salt_b64().await?;
+            Some(Arc::new(PasswordHash::dummy(
+                &salt_b64,
+                &app_config.password_hashing_params(),
+                &password_peppers,
+            )?))
+        }
+        false => None,
+    };
+
+    Ok(SharedServices {
+        password_peppers,
+        breached_passwords_filter,
+        dummy_password_hash,
+        identity_providers,
+        signup_notifier,
+    })
+}
+
+/// Use cases of every configured realm, the default realm goes first
+pub async fn build_realms_use_cases(
+    app_config: &AppConfig,
+    postgres_db: Arc<PostgresDatabase>,
+) -> Result<Vec<UseCases>, ErrorBoxed> {
+    let shared_services = build_shared_services(app_config).await?;
+    let mut realms_use_cases = Vec::with_capacity(app_config.realms().len());
+    for realm in app_config.realms() {
+        realms_use_cases
+            .push(build_use_cases(app_config, postgres_db.clone(), &shared_services, realm).await?);
+    }
+    Ok(realms_use_cases)
+}
+
+pub async fn build_use_cases(
+    app_config: &AppConfig,
+    postgres_db: Arc<PostgresDatabase>,
+    shared_services: &SharedServices,
+    realm: &Realm,
+) -> Result<UseCases, ErrorBoxed> {
+    let session_repository = Arc::new(PostgresSessionRepository::new(
+        postgres_db.clone(),
+        &realm.name,
+    ));
+    let user_repository = Arc::new(PostgresUserRepository::new(
+        postgres_db.clone(),
+        &realm.name,
+    ));
+    let role_repository =
+        Arc::new(PostgresRoleRepository::init(postgres_db.clone(), &realm.name).await?);
+    let group_repository = Arc::new(PostgresGroupRepository::new(
+        postgres_db.clone(),
+        &realm.name,
+    ));
+    let oauth_client_repository = Arc::new(PostgresOAuthClientRepository::new(
+        postgres_db.clone(),
+        &realm.name,
+    ));
+    let authorization_code_repository = Arc::new(PostgresAuthorizationCodeRepository::new(
+        postgres_db.clone(),
+        &realm.name,
+    ));
+    let device_authorization_repository = Arc::new(PostgresDeviceAuthorizationRepository::new(
+        postgres_db.clone(),
+        &realm.name,
+    ));
+    let api_key_repository = Arc::new(PostgresApiKeyRepository::new(
+        postgres_db.clone(),
+        &realm.name,
+    ));
+    let impersonation_repository = Arc::new(PostgresImpersonationRepository::new(
+        postgres_db.clone(),
+        &realm.name,
+    ));
+    let external_identity_repository = Arc::new(PostgresExternalIdentityRepository::new(
+        postgres_db.clone(),
+        &realm.name,
+    ));
+    let federated_authorization_repository = Arc::new(
+        PostgresFederatedAuthorizationRepository::new(postgres_db.clone(), &realm.name),
+    );
+    // keypairs of the default realm stay where they were before realms were introduced
+    let keypairs_store_path = match realm.is_default() {
+        true => app_config.keypairs_store_path().clone(),
+        false => app_config
+            .keypairs_store_path()
+            .join("realms")
+            .join(&realm.name),
+    };
+    let keypair_repository =
+        Arc::new(FileSystemInMemoryCachedKeyPairRepository::init(&keypairs_store_path).await?);
+    let time_service = Arc::new(OsTimeService::new());
+    let random_service = Arc::new(OsRandomService::new());
+    // legacy users are migrated into the default realm only
+    let legacy_authenticator = app_config
+        .legacy_auth_postgres_schema()
+        .filter(|_| realm.is_default())
+        .map(|schema| {
+            Arc::new(PostgresLegacyAuthenticator::new(
+                postgres_db.clone(),
+                schema,
+                app_config.legacy_auth_postgres_table(),
+            )) as Arc<dyn LegacyAuthenticator>
+        });
+    let use_cases_config = UseCasesConfig {
+        session_expiration_seconds: app_config.session_expiration_seconds(),
+        access_token_expiration_seconds: app_config.access_token_expiration_seconds(),
+        access_token_max_groups: app_config.access_token_max_groups(),
+        access_token_attributes: app_config.access_token_attributes().clone(),
+        realm: realm.clone(),
+        password_policy: app_config.password_policy().clone(),
+        password_hashing_params: app_config.password_hashing_params(),
+        password_peppers: shared_services.password_peppers.clone(),
+        breached_passwords_filter: shared_services.breached_passwords_filter.clone(),
+        signin_lockout_policy: app_config.signin_lockout_policy(),
+        dummy_password_hash: shared_services.dummy_password_hash.clone(),
+        oauth_device_verification_uri: app_config
+            .oauth_device_verification_uri()
+            .map(|uri| uri.to_string()),
+    };
+
+    let use_cases_services = UseCasesServices {
+        session_repository,
+        user_repository,
+        role_repository,
+        group_repository,
+        keypair_repository,
+        oauth_client_repository,
+        authorization_code_repository,
+        device_authorization_repository,
+        api_key_repository,
+        impersonation_repository,
+        identity_providers: shared_services.identity_providers.clone(),
+        external_identity_repository,
+        federated_authorization_repository,
+        time_service,
+        random_service,
+        legacy_authenticator,
+        signup_notifier: shared_services.signup_notifier.clone(),
+    };
+
+    Ok(UseCases::new(use_cases_config, use_cases_services))
+}
+
+async fn load_password_peppers(app_config: &AppConfig) -> Result<PasswordPeppers, ErrorBoxed> {
+    let Some(path) = app_config.password_peppers_path() else {
+        warn!("password peppers path is not configured, password hashes are not peppered");
+        return Ok(PasswordPeppers::empty());
+    };
+
+    let content = Zeroizing::new(fs::read_to_string(path).await.map_err(|err| {
+        err.with_context(format!(
+            "can not read password peppers from: {}",
+            path.display()
+        ))
+    })?);
+    let peppers = PasswordPeppers::from(&content)?;
+
+    info!("loaded {} password peppers", peppers.len());
+
+    Ok(peppers)
+}
+
+async fn load_breached_passwords_filter(
+    app_config: &AppConfig,
+) -> Result<BreachedPasswordsFilter, ErrorBoxed> {
+    let Some(path) = app_config.breached_passwords_filter_path() else {
+        warn!("breached passwords filter path is not configured, check is disabled");
+        return Ok(BreachedPasswordsFilter::empty());
+    };
+
+    let bytes = fs::read(path).await.map_err(|err| {
+        err.with_context(format!(
+            "can not read breached passwords filter from: {}",
+            path.display()
+        ))
+    })?;
+    let filter = BreachedPasswordsFilter::from_bytes(&bytes)?;
+
+    info!(
+        "loaded breached passwords filter with {} entries",
+        filter.len()
+    );
+
+    Ok(filter)
+}
@@ -0,0 +1,22 @@
+-- schema of deployments that predate migrations, which already have these tables
+DO $$ BEGIN
+    CREATE TYPE user_role AS ENUM ('default', 'admin');
+EXCEPTION
+    WHEN duplicate_object THEN NULL;
+END $$;
+
+CREATE TABLE IF NOT EXISTS users (
+    id TEXT PRIMARY KEY,
+    user_name TEXT NOT NULL UNIQUE,
+    role user_role NOT NULL DEFAULT 'default',
+    password_hash TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS sessions (
+    id TEXT PRIMARY KEY,
+    user_id TEXT NOT NULL REFERENCES users (id),
+    expires_at TIMESTAMPTZ NOT NULL,
+    revoked_at TIMESTAMPTZ
+);
+
+CREATE INDEX IF NOT EXISTS sessions_user_id_idx ON sessions (user_id);
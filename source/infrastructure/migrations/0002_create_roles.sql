@@ -0,0 +1,39 @@
+CREATE TABLE roles (
+    id TEXT PRIMARY KEY,
+    name TEXT NOT NULL UNIQUE
+);
+
+CREATE TABLE role_permissions (
+    role_id TEXT NOT NULL REFERENCES roles (id) ON DELETE CASCADE,
+    permission TEXT NOT NULL,
+    PRIMARY KEY (role_id, permission)
+);
+
+CREATE TABLE user_roles (
+    user_id TEXT NOT NULL REFERENCES users (id),
+    role_id TEXT NOT NULL REFERENCES roles (id) ON DELETE CASCADE,
+    PRIMARY KEY (user_id, role_id)
+);
+
+CREATE INDEX user_roles_role_id_idx ON user_roles (role_id);
+
+-- built-in roles, admin holds all permissions of the auth service itself
+INSERT INTO roles (id, name) VALUES
+    ('00000000000000000000000001', 'default'),
+    ('00000000000000000000000002', 'admin');
+
+INSERT INTO role_permissions (role_id, permission) VALUES
+    ('00000000000000000000000002', 'auth:keypairs:manage'),
+    ('00000000000000000000000002', 'auth:users:read'),
+    ('00000000000000000000000002', 'auth:users:manage'),
+    ('00000000000000000000000002', 'auth:roles:manage');
+
+INSERT INTO user_roles (user_id, role_id)
+    SELECT id, '00000000000000000000000001' FROM users;
+
+INSERT INTO user_roles (user_id, role_id)
+    SELECT id, '00000000000000000000000002' FROM users WHERE role = 'admin';
+
+ALTER TABLE users DROP COLUMN role;
+
+DROP TYPE user_role;
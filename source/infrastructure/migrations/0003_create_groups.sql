@@ -0,0 +1,15 @@
+CREATE TABLE groups (
+    id TEXT PRIMARY KEY,
+    name TEXT NOT NULL UNIQUE
+);
+
+CREATE TABLE user_groups (
+    user_id TEXT NOT NULL REFERENCES users (id),
+    group_id TEXT NOT NULL REFERENCES groups (id) ON DELETE CASCADE,
+    PRIMARY KEY (user_id, group_id)
+);
+
+CREATE INDEX user_groups_group_id_idx ON user_groups (group_id);
+
+INSERT INTO role_permissions (role_id, permission) VALUES
+    ('00000000000000000000000002', 'auth:groups:manage');
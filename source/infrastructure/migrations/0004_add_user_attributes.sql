@@ -0,0 +1,4 @@
+ALTER TABLE users ADD COLUMN attributes JSONB NOT NULL DEFAULT '{}'::JSONB;
+
+ALTER TABLE users ADD CONSTRAINT users_attributes_is_object
+    CHECK (jsonb_typeof(attributes) = 'object');
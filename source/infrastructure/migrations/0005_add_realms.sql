@@ -0,0 +1,4 @@
+ALTER TABLE users ADD COLUMN realm TEXT NOT NULL DEFAULT 'default';
+
+ALTER TABLE users DROP CONSTRAINT users_user_name_key;
+ALTER TABLE users ADD CONSTRAINT users_realm_user_name_key UNIQUE (realm, user_name);
@@ -0,0 +1,19 @@
+CREATE TABLE oauth_clients (
+    id TEXT PRIMARY KEY,
+    realm TEXT NOT NULL DEFAULT 'default',
+    name TEXT NOT NULL,
+    redirect_uris TEXT[] NOT NULL
+);
+
+CREATE TABLE oauth_authorization_codes (
+    id TEXT PRIMARY KEY,
+    realm TEXT NOT NULL DEFAULT 'default',
+    client_id TEXT NOT NULL REFERENCES oauth_clients (id) ON DELETE CASCADE,
+    user_id TEXT NOT NULL REFERENCES users (id),
+    redirect_uri TEXT NOT NULL,
+    code_challenge TEXT NOT NULL,
+    expires_at TIMESTAMPTZ NOT NULL
+);
+
+INSERT INTO role_permissions (role_id, permission) VALUES
+    ('00000000000000000000000002', 'auth:oauth_clients:manage');
@@ -0,0 +1,13 @@
+CREATE TABLE oauth_device_authorizations (
+    id TEXT PRIMARY KEY,
+    realm TEXT NOT NULL DEFAULT 'default',
+    client_id TEXT NOT NULL REFERENCES oauth_clients (id) ON DELETE CASCADE,
+    user_code TEXT NOT NULL,
+    approved_by TEXT REFERENCES users (id),
+    expires_at TIMESTAMPTZ NOT NULL,
+    interval_seconds INTEGER NOT NULL,
+    last_polled_at TIMESTAMPTZ
+);
+
+CREATE INDEX oauth_device_authorizations_user_code_idx
+    ON oauth_device_authorizations (realm, user_code);
@@ -0,0 +1,15 @@
+-- audit trail of admins acting as other users, kept when users are deleted
+CREATE TABLE impersonations (
+    id TEXT PRIMARY KEY,
+    realm TEXT NOT NULL DEFAULT 'default',
+    admin_id TEXT NOT NULL,
+    user_id TEXT NOT NULL,
+    reason TEXT NOT NULL,
+    started_at TIMESTAMPTZ NOT NULL,
+    expires_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX impersonations_user_id_idx ON impersonations (realm, user_id);
+
+INSERT INTO role_permissions (role_id, permission) VALUES
+    ('00000000000000000000000002', 'auth:users:impersonate');
@@ -0,0 +1,23 @@
+CREATE TABLE external_identities (
+    id TEXT PRIMARY KEY,
+    realm TEXT NOT NULL DEFAULT 'default',
+    user_id TEXT NOT NULL REFERENCES users (id),
+    provider TEXT NOT NULL,
+    subject TEXT NOT NULL,
+    email TEXT,
+    provisioned BOOLEAN NOT NULL,
+    linked_at TIMESTAMPTZ NOT NULL,
+    UNIQUE (realm, provider, subject),
+    UNIQUE (realm, user_id, provider)
+);
+
+-- pending authorization code flows with upstream providers, the id is the state
+CREATE TABLE federated_authorizations (
+    id TEXT PRIMARY KEY,
+    realm TEXT NOT NULL DEFAULT 'default',
+    provider TEXT NOT NULL,
+    user_id TEXT REFERENCES users (id),
+    nonce TEXT NOT NULL,
+    code_verifier TEXT NOT NULL,
+    expires_at TIMESTAMPTZ NOT NULL
+);
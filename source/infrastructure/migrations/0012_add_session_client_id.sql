@@ -0,0 +1,2 @@
+-- sessions started by oauth grants are refreshed only by the client they are issued to
+ALTER TABLE sessions ADD COLUMN client_id TEXT;
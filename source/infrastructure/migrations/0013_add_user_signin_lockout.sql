@@ -0,0 +1,2 @@
+ALTER TABLE users ADD COLUMN failed_signin_attempts INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE users ADD COLUMN locked_until TIMESTAMPTZ;
@@ -0,0 +1,6 @@
+CREATE TYPE user_status AS ENUM ('active', 'suspended', 'deleted');
+
+ALTER TABLE users ADD COLUMN status user_status NOT NULL DEFAULT 'active';
+ALTER TABLE users ADD COLUMN suspension_reason TEXT;
+ALTER TABLE users ADD COLUMN suspended_until TIMESTAMPTZ;
+ALTER TABLE users ADD COLUMN deleted_at TIMESTAMPTZ;
@@ -0,0 +1,9 @@
+ALTER TABLE roles ADD COLUMN realm TEXT NOT NULL DEFAULT 'default';
+
+ALTER TABLE roles DROP CONSTRAINT roles_name_key;
+ALTER TABLE roles ADD CONSTRAINT roles_realm_name_key UNIQUE (realm, name);
+
+ALTER TABLE groups ADD COLUMN realm TEXT NOT NULL DEFAULT 'default';
+
+ALTER TABLE groups DROP CONSTRAINT groups_name_key;
+ALTER TABLE groups ADD CONSTRAINT groups_realm_name_key UNIQUE (realm, name);
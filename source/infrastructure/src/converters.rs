@@ -1,12 +1,104 @@
-use nimbus_auth_application::use_cases::{AccessTokenDto, UserClaimsDto};
+use nimbus_auth_application::use_cases::{
+    AccessTokenDto, ApiKeyDto, ExternalIdentityDto, GroupDto, RoleDto, UserClaimsDto,
+    UserDetailsDto,
+};
+use nimbus_auth_domain::entities::user::value_objects::password::errors::PasswordPolicyViolation;
 use nimbus_auth_proto::proto::nimbus::{
-    auth::entities::v1::AccessTokenProto, entities::user::v1::UserProto,
+    admin::{
+        groups::v1::GroupProto,
+        roles::v1::RoleProto,
+        users::v1::{UserDetailsProto, UserStatusProto},
+    },
+    auth::api_keys::v1::ApiKeyProto,
+    auth::entities::v1::{
+        AccessTokenProto, PasswordPolicyViolationCodeProto, PasswordPolicyViolationProto,
+        PasswordPolicyViolationsProto,
+    },
+    auth::federation::v1::ExternalIdentityProto,
+    entities::user::v1::UserProto,
 };
+use nimbus_auth_shared::types::{PasswordCharacterClass, UserStatus};
 
 pub fn convert_user_into_proto(user: UserClaimsDto) -> UserProto {
     UserProto {
         id: user.id,
         user_name: user.name,
+        attributes_json: user.attributes,
+    }
+}
+
+pub fn convert_user_details_into_proto(user: UserDetailsDto) -> UserDetailsProto {
+    UserDetailsProto {
+        id: user.id,
+        user_name: user.name,
+        roles: user.roles,
+        permissions: user.permissions,
+        groups: user
+            .groups
+            .into_iter()
+            .map(convert_group_into_proto)
+            .collect(),
+        attributes_json: user.attributes,
+        status: convert_user_status_into_proto(user.status).into(),
+        suspension_reason: user.suspension_reason,
+        suspended_until_unix_timestamp: user.suspended_until_unix_timestamp,
+        deleted_at_unix_timestamp: user.deleted_at_unix_timestamp,
+        failed_signin_attempts: user.signin_lockout.failed_attempts,
+        locked_until_unix_timestamp: user.signin_lockout.locked_until_unix_timestamp,
+    }
+}
+
+pub fn convert_role_into_proto(role: RoleDto) -> RoleProto {
+    RoleProto {
+        name: role.name,
+        permissions: role.permissions,
+        is_built_in: role.is_built_in,
+    }
+}
+
+pub fn convert_group_into_proto(group: GroupDto) -> GroupProto {
+    GroupProto {
+        id: group.id,
+        name: group.name,
+    }
+}
+
+pub fn convert_api_key_into_proto(api_key: ApiKeyDto) -> ApiKeyProto {
+    ApiKeyProto {
+        id: api_key.id,
+        name: api_key.name,
+        scopes: api_key.scopes,
+        created_at_unix_timestamp: api_key.created_at_unix_timestamp,
+        expires_at_unix_timestamp: api_key.expires_at_unix_timestamp,
+        revoked_at_unix_timestamp: api_key.revoked_at_unix_timestamp,
+    }
+}
+
+pub fn convert_external_identity_into_proto(
+    identity: ExternalIdentityDto,
+) -> ExternalIdentityProto {
+    ExternalIdentityProto {
+        provider: identity.provider,
+        subject: identity.subject,
+        email: identity.email,
+        provisioned: identity.provisioned,
+        linked_at_unix_timestamp: identity.linked_at_unix_timestamp,
+    }
+}
+
+pub fn convert_user_status_into_proto(status: UserStatus) -> UserStatusProto {
+    match status {
+        UserStatus::Active => UserStatusProto::Active,
+        UserStatus::Suspended => UserStatusProto::Suspended,
+        UserStatus::Deleted => UserStatusProto::Deleted,
+    }
+}
+
+pub fn convert_user_status_from_proto(status: UserStatusProto) -> UserStatus {
+    match status {
+        UserStatusProto::Active => UserStatus::Active,
+        UserStatusProto::Suspended => UserStatus::Suspended,
+        UserStatusProto::Deleted => UserStatus::Deleted,
     }
 }
 
@@ -16,3 +108,40 @@ pub fn convert_access_token_into_proto(access_token: AccessTokenDto) -> AccessTo
         expires_at_unix_timestamp: access_token.signed_access_token_expires_at_unix_timestamp,
     }
 }
+
+pub fn convert_password_policy_violations_into_proto(
+    violations: &[PasswordPolicyViolation],
+) -> PasswordPolicyViolationsProto {
+    PasswordPolicyViolationsProto {
+        violations: violations
+            .iter()
+            .map(|violation| PasswordPolicyViolationProto {
+                code: convert_password_policy_violation_code_into_proto(violation).into(),
+                message: violation.to_string(),
+            })
+            .collect(),
+    }
+}
+
+fn convert_password_policy_violation_code_into_proto(
+    violation: &PasswordPolicyViolation,
+) -> PasswordPolicyViolationCodeProto {
+    match violation {
+        PasswordPolicyViolation::TooShort { .. } => PasswordPolicyViolationCodeProto::TooShort,
+        PasswordPolicyViolation::TooLong { .. } => PasswordPolicyViolationCodeProto::TooLong,
+        PasswordPolicyViolation::MissingCharacterClass(character_class) => match character_class {
+            PasswordCharacterClass::Uppercase => PasswordPolicyViolationCodeProto::MissingUppercase,
+            PasswordCharacterClass::Lowercase => PasswordPolicyViolationCodeProto::MissingLowercase,
+            PasswordCharacterClass::Digit => PasswordPolicyViolationCodeProto::MissingDigit,
+            PasswordCharacterClass::Punctuation => {
+                PasswordPolicyViolationCodeProto::MissingPunctuation
+            }
+        },
+        PasswordPolicyViolation::NonAsciiCharacters => {
+            PasswordPolicyViolationCodeProto::NonAsciiCharacters
+        }
+        PasswordPolicyViolation::Spaces => PasswordPolicyViolationCodeProto::Spaces,
+        PasswordPolicyViolation::TooWeak { .. } => PasswordPolicyViolationCodeProto::TooWeak,
+        PasswordPolicyViolation::Breached => PasswordPolicyViolationCodeProto::Breached,
+    }
+}
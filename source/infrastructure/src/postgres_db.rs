@@ -17,6 +17,7 @@ use tokio::{
 use crate::postgres_db::errors::PostgresDatabaseError;
 
 pub mod errors;
+pub mod queries;
 pub mod types;
 
 pub struct PostgresDatabase {
@@ -60,6 +61,12 @@ impl PostgresDatabase {
         Ok(Self { pool })
     }
 
+    /// Applies migrations embedded from the `migrations` directory which are not applied yet
+    pub async fn migrate(&self) -> Result<(), PostgresDatabaseError> {
+        sqlx::migrate!("./migrations").run(&self.pool).await?;
+        Ok(())
+    }
+
     pub fn pool(&self) -> &PgPool {
         &self.pool
     }
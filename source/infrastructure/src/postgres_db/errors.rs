@@ -6,5 +6,7 @@ pub enum PostgresDatabaseError {
     #[error(transparent)]
     Sqlx(#[from] sqlx::Error),
     #[error(transparent)]
+    Migrate(#[from] sqlx::migrate::MigrateError),
+    #[error(transparent)]
     Other(#[from] ErrorBoxed),
 }
@@ -0,0 +1,23 @@
+/// Names of roles held by the user from the `users` table in scope
+pub const USER_ROLES_COLUMN: &str = "ARRAY(\
+    SELECT roles.name FROM user_roles \
+    INNER JOIN roles ON roles.id = user_roles.role_id \
+    WHERE user_roles.user_id = users.id ORDER BY roles.name)";
+
+/// Permissions of all roles held by the user from the `users` table in scope
+pub const USER_PERMISSIONS_COLUMN: &str = "ARRAY(\
+    SELECT DISTINCT role_permissions.permission FROM user_roles \
+    INNER JOIN role_permissions ON role_permissions.role_id = user_roles.role_id \
+    WHERE user_roles.user_id = users.id ORDER BY role_permissions.permission)";
+
+/// Ids of groups the user from the `users` table in scope is a member of, ordered by group name
+pub const USER_GROUP_IDS_COLUMN: &str = "ARRAY(\
+    SELECT groups.id FROM user_groups \
+    INNER JOIN groups ON groups.id = user_groups.group_id \
+    WHERE user_groups.user_id = users.id ORDER BY groups.name)";
+
+/// Names of groups the user from the `users` table in scope is a member of, ordered by group name
+pub const USER_GROUP_NAMES_COLUMN: &str = "ARRAY(\
+    SELECT groups.name FROM user_groups \
+    INNER JOIN groups ON groups.id = user_groups.group_id \
+    WHERE user_groups.user_id = users.id ORDER BY groups.name)";
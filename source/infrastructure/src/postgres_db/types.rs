@@ -1 +1 @@
-pub mod user_role;
+pub mod user_status;
@@ -0,0 +1,29 @@
+use nimbus_auth_shared::types::UserStatus;
+
+#[derive(sqlx::Type, Debug)]
+#[sqlx(type_name = "user_status", rename_all = "lowercase")]
+pub enum UserStatusDb {
+    Active,
+    Suspended,
+    Deleted,
+}
+
+impl From<&UserStatus> for UserStatusDb {
+    fn from(value: &UserStatus) -> Self {
+        match value {
+            UserStatus::Active => Self::Active,
+            UserStatus::Suspended => Self::Suspended,
+            UserStatus::Deleted => Self::Deleted,
+        }
+    }
+}
+
+impl From<&UserStatusDb> for UserStatus {
+    fn from(value: &UserStatusDb) -> Self {
+        match value {
+            UserStatusDb::Active => Self::Active,
+            UserStatusDb::Suspended => Self::Suspended,
+            UserStatusDb::Deleted => Self::Deleted,
+        }
+    }
+}
@@ -1,5 +1,17 @@
 pub mod filesystem_inmemory_cached_keypair_repository;
+pub mod oidc_identity_provider;
 pub mod os_random_service;
 pub mod os_time_service;
+pub mod postgres_api_key_repository;
+pub mod postgres_authorization_code_repository;
+pub mod postgres_device_authorization_repository;
+pub mod postgres_external_identity_repository;
+pub mod postgres_federated_authorization_repository;
+pub mod postgres_group_repository;
+pub mod postgres_impersonation_repository;
+pub mod postgres_legacy_authenticator;
+pub mod postgres_oauth_client_repository;
+pub mod postgres_role_repository;
 pub mod postgres_session_repository;
 pub mod postgres_user_repository;
+pub mod webhook_signup_notifier;
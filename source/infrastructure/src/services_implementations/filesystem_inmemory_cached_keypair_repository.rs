@@ -1,28 +1,78 @@
-use std::path::PathBuf;
+use std::{
+    collections::HashMap,
+    fs::File,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::SystemTime,
+};
 
 use nimbus_auth_application::services::keypair_repository::{
     KeyPairRepository, KeyPairRepositoryWithTransaction, errors::KeyPairRepositoryError,
 };
 use nimbus_auth_domain::{
-    entities::keypair::{Active, KeyPair, SomeKeyPair},
-    value_objects::identifier::Identifier,
+    entities::{
+        Entity,
+        keypair::{Active, KeyPair, SomeKeyPair},
+    },
+    value_objects::identifier::{Identifier, IdentifierOfType},
+};
+use nimbus_auth_shared::{
+    errors::ErrorBoxed,
+    futures::{StaticPinnedFuture, pin_static_future},
+};
+use time::OffsetDateTime;
+use tokio::{
+    fs,
+    io::AsyncWriteExt,
+    sync::{OwnedRwLockReadGuard, OwnedRwLockWriteGuard, RwLock},
+    task::spawn_blocking,
 };
-use nimbus_auth_shared::futures::StaticPinnedFuture;
 use ulid::Ulid;
+use zeroize::Zeroizing;
+
+use crate::services_implementations::filesystem_inmemory_cached_keypair_repository::schema::{
+    KeyPairFileDb, StoredKeyPair,
+};
+
+mod schema;
 
+const KEYPAIR_FILE_EXTENSION: &str = "json";
+const KEYPAIR_TEMP_FILE_EXTENSION: &str = "tmp";
+const LOCK_FILE_NAME: &str = ".lock";
+
+/// Each key pair is stored in a file of its own named by its id
 pub struct FileSystemInMemoryCachedKeyPairRepository {
     keypairs_location: PathBuf,
+    cache: Arc<RwLock<KeyPairsCache>>,
+}
+
+/// Key pairs read from the location, lookups do not touch the file system unless
+/// the location is changed since, e.g. by the admin tool
+#[derive(Default)]
+struct KeyPairsCache {
+    keypairs: HashMap<Ulid, StoredKeyPair>,
+    location_modified_at: Option<SystemTime>,
 }
 
+/// Holds the cache and the lock file of the location, so transactions of all processes
+/// sharing the location run one at a time. Saves are written to files on commit
 pub struct FileSystemInMemoryCachedKeyPairRepositoryWithTransaction {
     keypairs_location: PathBuf,
+    cache: OwnedRwLockWriteGuard<KeyPairsCache>,
+    _lock_file: File,
+    keypair_saves: HashMap<Ulid, StoredKeyPair>,
 }
 
 impl FileSystemInMemoryCachedKeyPairRepository {
     pub async fn init(keypairs_location: &PathBuf) -> Result<Self, KeyPairRepositoryError> {
-        todo!();
+        fs::create_dir_all(keypairs_location)
+            .await
+            .map_err(ErrorBoxed::from)?;
+        let mut cache = KeyPairsCache::default();
+        cache.reload(keypairs_location).await?;
         Ok(Self {
             keypairs_location: keypairs_location.clone(),
+            cache: Arc::new(RwLock::new(cache)),
         })
     }
 }
@@ -31,32 +81,95 @@ impl KeyPairRepository for FileSystemInMemoryCachedKeyPairRepository {
     fn start_transaction(
         &self,
     ) -> StaticPinnedFuture<Box<dyn KeyPairRepositoryWithTransaction>, KeyPairRepositoryError> {
-        todo!()
+        let cache = self.cache.clone();
+        let keypairs_location = self.keypairs_location.clone();
+        pin_static_future(async move {
+            let mut cache = cache.write_owned().await;
+            let lock_file = lock_location(&keypairs_location).await?;
+            // another process could have changed key pairs before the lock is taken
+            cache.reload(&keypairs_location).await?;
+            Ok(
+                Box::new(FileSystemInMemoryCachedKeyPairRepositoryWithTransaction {
+                    keypairs_location,
+                    cache,
+                    _lock_file: lock_file,
+                    keypair_saves: HashMap::new(),
+                }) as Box<dyn KeyPairRepositoryWithTransaction>,
+            )
+        })
     }
 
     fn get_by_id(
         &self,
         id: &Identifier<Ulid, SomeKeyPair<'static>>,
     ) -> StaticPinnedFuture<Option<SomeKeyPair<'static>>, KeyPairRepositoryError> {
-        todo!()
+        let cache = self.cache.clone();
+        let keypairs_location = self.keypairs_location.clone();
+        let id = *id.value();
+        pin_static_future(async move {
+            let cache = read_cache(cache, &keypairs_location).await?;
+            let current_time = OffsetDateTime::now_utc();
+            Ok(cache
+                .keypairs
+                .get(&id)
+                .map(|keypair| keypair.restore(id, current_time)))
+        })
     }
 
     fn get_active(&self) -> StaticPinnedFuture<Option<KeyPair<Active>>, KeyPairRepositoryError> {
-        todo!()
+        let cache = self.cache.clone();
+        let keypairs_location = self.keypairs_location.clone();
+        pin_static_future(async move {
+            let cache = read_cache(cache, &keypairs_location).await?;
+            Ok(active_keypair(cache.keypairs.iter()))
+        })
+    }
+
+    fn get_verifying(
+        &self,
+    ) -> StaticPinnedFuture<Vec<SomeKeyPair<'static>>, KeyPairRepositoryError> {
+        let cache = self.cache.clone();
+        let keypairs_location = self.keypairs_location.clone();
+        pin_static_future(async move {
+            let cache = read_cache(cache, &keypairs_location).await?;
+            let current_time = OffsetDateTime::now_utc();
+            Ok(cache
+                .keypairs
+                .iter()
+                .map(|(id, keypair)| keypair.restore(*id, current_time))
+                .filter(|keypair| {
+                    matches!(keypair, SomeKeyPair::Active(_) | SomeKeyPair::Expiring(_))
+                })
+                .collect())
+        })
     }
 
     fn save(&self, keypair: SomeKeyPair) -> StaticPinnedFuture<(), KeyPairRepositoryError> {
-        todo!()
+        let transaction = self.start_transaction();
+        let keypair = keypair.into_owned();
+        pin_static_future(async move {
+            let (transaction, _) = transaction.await?.save(keypair).await?;
+            transaction.commit().await
+        })
     }
 }
 
 impl KeyPairRepositoryWithTransaction for FileSystemInMemoryCachedKeyPairRepositoryWithTransaction {
-    fn commit(self: Box<Self>) -> StaticPinnedFuture<(), KeyPairRepositoryError> {
-        todo!()
+    fn commit(mut self: Box<Self>) -> StaticPinnedFuture<(), KeyPairRepositoryError> {
+        pin_static_future(async move {
+            for (id, keypair) in self.keypair_saves.drain() {
+                write_keypair_file(&self.keypairs_location, id, &keypair).await?;
+                self.cache.keypairs.insert(id, keypair);
+            }
+            // nobody else writes while the lock is held, so the cache matches the location
+            self.cache.location_modified_at =
+                Some(location_modified_at(&self.keypairs_location).await?);
+            Ok(())
+        })
     }
 
     fn rollback(self: Box<Self>) -> StaticPinnedFuture<(), KeyPairRepositoryError> {
-        todo!()
+        pin_static_future(async { Ok(()) })
     }
 
     fn get_by_id(
@@ -69,7 +182,13 @@ impl KeyPairRepositoryWithTransaction for FileSystemInMemoryCachedKeyPairReposit
         ),
         KeyPairRepositoryError,
     > {
-        todo!()
+        let id = *id.value();
+        pin_static_future(async move {
+            let keypair = self
+                .stored_keypair(&id)
+                .map(|keypair| keypair.restore(id, OffsetDateTime::now_utc()));
+            Ok((self as Box<dyn KeyPairRepositoryWithTransaction>, keypair))
+        })
     }
 
     fn get_active(
@@ -81,14 +200,153 @@ impl KeyPairRepositoryWithTransaction for FileSystemInMemoryCachedKeyPairReposit
         ),
         KeyPairRepositoryError,
     > {
-        todo!()
+        pin_static_future(async move {
+            let keypair = active_keypair(
+                self.keypair_saves.iter().chain(
+                    self.cache
+                        .keypairs
+                        .iter()
+                        .filter(|(id, _)| !self.keypair_saves.contains_key(id)),
+                ),
+            );
+            Ok((self as Box<dyn KeyPairRepositoryWithTransaction>, keypair))
+        })
     }
 
     fn save(
-        self: Box<Self>,
+        mut self: Box<Self>,
         keypair: SomeKeyPair,
     ) -> StaticPinnedFuture<(Box<dyn KeyPairRepositoryWithTransaction>, ()), KeyPairRepositoryError>
     {
-        todo!()
+        let keypair = keypair.into_owned();
+        pin_static_future(async move {
+            let id = *keypair.id().value();
+            let stored_keypair = StoredKeyPair::with_state(self.stored_keypair(&id), &keypair)?;
+            self.keypair_saves.insert(id, stored_keypair);
+            Ok((self as Box<dyn KeyPairRepositoryWithTransaction>, ()))
+        })
     }
 }
+
+impl FileSystemInMemoryCachedKeyPairRepositoryWithTransaction {
+    /// Saves of the transaction are seen before they are committed
+    fn stored_keypair(&self, id: &Ulid) -> Option<&StoredKeyPair> {
+        self.keypair_saves
+            .get(id)
+            .or_else(|| self.cache.keypairs.get(id))
+    }
+}
+
+impl KeyPairsCache {
+    async fn reload(&mut self, keypairs_location: &Path) -> Result<(), KeyPairRepositoryError> {
+        // modification time is taken first, so changes made while reading cause another reload
+        let location_modified_at = location_modified_at(keypairs_location).await?;
+        let mut keypairs = HashMap::new();
+        let mut entries = fs::read_dir(keypairs_location)
+            .await
+            .map_err(ErrorBoxed::from)?;
+        while let Some(entry) = entries.next_entry().await.map_err(ErrorBoxed::from)? {
+            let path = entry.path();
+            if path
+                .extension()
+                .is_none_or(|extension| extension != KEYPAIR_FILE_EXTENSION)
+            {
+                continue;
+            }
+            let (id, keypair) = read_keypair_file(&path).await.map_err(|err| {
+                KeyPairRepositoryError::KeyPairRestoration(ErrorBoxed::from_str(format!(
+                    "{}: {err}",
+                    path.display()
+                )))
+            })?;
+            keypairs.insert(id, keypair);
+        }
+        self.keypairs = keypairs;
+        self.location_modified_at = Some(location_modified_at);
+        Ok(())
+    }
+}
+
+/// Cache is read again if the location is changed since it was read
+async fn read_cache(
+    cache: Arc<RwLock<KeyPairsCache>>,
+    keypairs_location: &Path,
+) -> Result<OwnedRwLockReadGuard<KeyPairsCache>, KeyPairRepositoryError> {
+    let location_modified_at = Some(location_modified_at(keypairs_location).await?);
+    let cache_guard = cache.clone().read_owned().await;
+    if cache_guard.location_modified_at == location_modified_at {
+        return Ok(cache_guard);
+    }
+    drop(cache_guard);
+    let mut cache_guard = cache.write_owned().await;
+    if cache_guard.location_modified_at != location_modified_at {
+        cache_guard.reload(keypairs_location).await?;
+    }
+    Ok(cache_guard.downgrade())
+}
+
+/// Key pair which is active now, the latest one if several are
+fn active_keypair<'a>(
+    keypairs: impl Iterator<Item = (&'a Ulid, &'a StoredKeyPair)>,
+) -> Option<KeyPair<Active>> {
+    let current_time = OffsetDateTime::now_utc();
+    keypairs
+        .filter_map(|(id, keypair)| match keypair.restore(*id, current_time) {
+            SomeKeyPair::Active(keypair) => Some(keypair.into_owned()),
+            _ => None,
+        })
+        .max_by_key(|keypair| *keypair.id().value())
+}
+
+async fn location_modified_at(keypairs_location: &Path) -> Result<SystemTime, ErrorBoxed> {
+    Ok(fs::metadata(keypairs_location).await?.modified()?)
+}
+
+/// Lock is released once the returned file is dropped
+async fn lock_location(keypairs_location: &Path) -> Result<File, ErrorBoxed> {
+    let lock_file_path = keypairs_location.join(LOCK_FILE_NAME);
+    Ok(spawn_blocking(move || {
+        let lock_file = File::options()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(lock_file_path)?;
+        lock_file.lock()?;
+        Ok::<_, std::io::Error>(lock_file)
+    })
+    .await??)
+}
+
+async fn read_keypair_file(path: &Path) -> Result<(Ulid, StoredKeyPair), ErrorBoxed> {
+    let id = path
+        .file_stem()
+        .and_then(|file_stem| file_stem.to_str())
+        .ok_or_else(|| ErrorBoxed::from_str("file is not named by key pair id"))?;
+    let id = Ulid::from_string(id)?;
+    let content = Zeroizing::new(fs::read_to_string(path).await?);
+    let keypair_file: KeyPairFileDb = serde_json::from_str(&content)?;
+    Ok((id, StoredKeyPair::try_from(&keypair_file)?))
+}
+
+/// File is replaced at once, so it is never read half written
+async fn write_keypair_file(
+    keypairs_location: &Path,
+    id: Ulid,
+    keypair: &StoredKeyPair,
+) -> Result<(), ErrorBoxed> {
+    let content = Zeroizing::new(serde_json::to_string(&KeyPairFileDb::from(keypair))?);
+    let path = keypairs_location
+        .join(id.to_string())
+        .with_extension(KEYPAIR_FILE_EXTENSION);
+    let temp_path = path.with_extension(KEYPAIR_TEMP_FILE_EXTENSION);
+    let mut options = fs::OpenOptions::new();
+    options.create(true).truncate(true).write(true);
+    // private keys are readable by their owner only
+    #[cfg(unix)]
+    options.mode(0o600);
+    let mut file = options.open(&temp_path).await?;
+    file.write_all(content.as_bytes()).await?;
+    file.sync_all().await?;
+    fs::rename(&temp_path, &path).await?;
+    Ok(())
+}
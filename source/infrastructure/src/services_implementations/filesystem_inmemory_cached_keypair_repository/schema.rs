@@ -0,0 +1,105 @@
+use nimbus_auth_application::services::keypair_repository::errors::KeyPairRepositoryError;
+use nimbus_auth_domain::{
+    entities::{
+        Entity,
+        keypair::{
+            SomeKeyPair, specifications::RestoreKeyPairSpecification, value_objects::KeyPairValue,
+        },
+    },
+    value_objects::identifier::Identifier,
+};
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use ulid::Ulid;
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
+
+use crate::services_implementations::filesystem_inmemory_cached_keypair_repository::schema::errors::TryFromKeyPairFileDbError;
+
+pub mod errors;
+
+/// Content of the file of a key pair, revoked and expired key pairs keep their keys
+/// as long as their files are kept
+#[derive(Serialize, Deserialize, Zeroize, ZeroizeOnDrop)]
+pub struct KeyPairFileDb {
+    pub private_key_pem: String,
+    pub expires_at_unix_timestamp: Option<i64>,
+    pub revoked_at_unix_timestamp: Option<i64>,
+}
+
+/// Key pair as it is stored, its state depends on the time it is restored at
+#[derive(Clone)]
+pub struct StoredKeyPair {
+    pub value: KeyPairValue,
+    pub expires_at: Option<OffsetDateTime>,
+    pub revoked_at: Option<OffsetDateTime>,
+}
+
+impl StoredKeyPair {
+    pub fn restore(&self, id: Ulid, current_time: OffsetDateTime) -> SomeKeyPair<'static> {
+        SomeKeyPair::restore(RestoreKeyPairSpecification {
+            id: Identifier::from(id),
+            value: self.value.clone(),
+            expires_at: self.expires_at,
+            revoked_at: self.revoked_at,
+            current_time,
+        })
+    }
+
+    /// Expired and revoked key pairs have no keys, so they are kept from the stored ones
+    pub fn with_state(
+        stored: Option<&StoredKeyPair>,
+        keypair: &SomeKeyPair,
+    ) -> Result<StoredKeyPair, KeyPairRepositoryError> {
+        let not_stored = || KeyPairRepositoryError::KeyPairIsNotStored {
+            key_id: keypair.id().to_string(),
+        };
+        Ok(match keypair {
+            SomeKeyPair::Active(keypair) => StoredKeyPair {
+                value: keypair.value().clone(),
+                expires_at: None,
+                revoked_at: None,
+            },
+            SomeKeyPair::Expiring(keypair) => StoredKeyPair {
+                value: keypair.value().clone(),
+                expires_at: Some(keypair.expires_at()),
+                revoked_at: None,
+            },
+            SomeKeyPair::Expired(keypair) => StoredKeyPair {
+                expires_at: Some(keypair.expired_at()),
+                ..stored.ok_or_else(not_stored)?.clone()
+            },
+            SomeKeyPair::Revoked(keypair) => StoredKeyPair {
+                revoked_at: Some(keypair.revoked_at()),
+                ..stored.ok_or_else(not_stored)?.clone()
+            },
+        })
+    }
+}
+
+impl TryFrom<&KeyPairFileDb> for StoredKeyPair {
+    type Error = TryFromKeyPairFileDbError;
+
+    fn try_from(value: &KeyPairFileDb) -> Result<Self, Self::Error> {
+        Ok(StoredKeyPair {
+            value: KeyPairValue::from_pem(Zeroizing::new(value.private_key_pem.clone()))?,
+            expires_at: value
+                .expires_at_unix_timestamp
+                .map(OffsetDateTime::from_unix_timestamp)
+                .transpose()?,
+            revoked_at: value
+                .revoked_at_unix_timestamp
+                .map(OffsetDateTime::from_unix_timestamp)
+                .transpose()?,
+        })
+    }
+}
+
+impl From<&StoredKeyPair> for KeyPairFileDb {
+    fn from(value: &StoredKeyPair) -> Self {
+        KeyPairFileDb {
+            private_key_pem: value.value.private_key_pem().as_str().to_string(),
+            expires_at_unix_timestamp: value.expires_at.map(OffsetDateTime::unix_timestamp),
+            revoked_at_unix_timestamp: value.revoked_at.map(OffsetDateTime::unix_timestamp),
+        }
+    }
+}
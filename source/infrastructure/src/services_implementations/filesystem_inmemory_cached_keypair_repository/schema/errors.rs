@@ -0,0 +1,11 @@
+use nimbus_auth_domain::entities::keypair::value_objects::errors::KeyPairValueError;
+use thiserror::Error;
+use time::error::ComponentRange;
+
+#[derive(Error, Debug)]
+pub enum TryFromKeyPairFileDbError {
+    #[error(transparent)]
+    Value(#[from] KeyPairValueError),
+    #[error("invalid timestamp. Error: {0}")]
+    InvalidTimestamp(#[from] ComponentRange),
+}
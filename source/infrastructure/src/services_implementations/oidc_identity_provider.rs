@@ -0,0 +1,182 @@
+use std::{sync::Arc, time::Duration};
+
+use nimbus_auth_application::services::identity_provider::{
+    IdentityProvider, errors::IdentityProviderError,
+};
+use nimbus_auth_domain::{
+    entities::authorization_code::value_objects::code_challenge::CodeChallenge,
+    value_objects::id_token::IdTokenKeys,
+};
+use nimbus_auth_shared::{
+    constants::OUTGOING_REQUEST_TIMEOUT_SECONDS,
+    errors::ErrorBoxed,
+    futures::{StaticPinnedFuture, pin_static_future},
+    types::OidcProvider,
+};
+use reqwest::{Client, StatusCode};
+use tokio::sync::OnceCell;
+use url::Url;
+
+use crate::services_implementations::oidc_identity_provider::schema::{
+    OidcDiscoveryJson, OidcTokenRequestForm, OidcTokenResponseJson,
+};
+
+mod schema;
+
+const DISCOVERY_PATH: &str = "/.well-known/openid-configuration";
+
+/// OpenID Connect provider whose endpoints are discovered from its issuer on first use
+pub struct OidcIdentityProvider {
+    client: Client,
+    provider: OidcProvider,
+    discovery: Arc<OnceCell<OidcDiscoveryJson>>,
+}
+
+impl OidcIdentityProvider {
+    pub fn new(provider: &OidcProvider) -> Result<Self, ErrorBoxed> {
+        let client = Client::builder()
+            .timeout(Duration::from_secs(OUTGOING_REQUEST_TIMEOUT_SECONDS))
+            .build()?;
+        Ok(Self {
+            client,
+            provider: provider.clone(),
+            discovery: Arc::new(OnceCell::new()),
+        })
+    }
+}
+
+impl IdentityProvider for OidcIdentityProvider {
+    fn name(&self) -> &str {
+        &self.provider.name
+    }
+
+    fn issuer(&self) -> &str {
+        &self.provider.issuer
+    }
+
+    fn client_id(&self) -> &str {
+        &self.provider.client_id
+    }
+
+    fn get_authorization_url(
+        &self,
+        state: &str,
+        nonce: &str,
+        code_challenge: &CodeChallenge,
+    ) -> StaticPinnedFuture<String, IdentityProviderError> {
+        let client = self.client.clone();
+        let discovery = self.discovery.clone();
+        let provider = self.provider.clone();
+        let state = state.to_string();
+        let nonce = nonce.to_string();
+        let code_challenge = code_challenge.clone();
+        pin_static_future(async move {
+            let discovery = discover(&client, &discovery, &provider.issuer).await?;
+            let mut authorization_url =
+                Url::parse(&discovery.authorization_endpoint).map_err(ErrorBoxed::from)?;
+            authorization_url
+                .query_pairs_mut()
+                .append_pair("response_type", "code")
+                .append_pair("client_id", &provider.client_id)
+                .append_pair("redirect_uri", &provider.redirect_uri)
+                .append_pair("scope", &provider.scopes.join(" "))
+                .append_pair("state", &state)
+                .append_pair("nonce", &nonce)
+                .append_pair("code_challenge", code_challenge.value())
+                .append_pair("code_challenge_method", code_challenge.method());
+            Ok(authorization_url.to_string())
+        })
+    }
+
+    fn exchange_code(
+        &self,
+        code: &str,
+        code_verifier: &str,
+    ) -> StaticPinnedFuture<String, IdentityProviderError> {
+        let client = self.client.clone();
+        let discovery = self.discovery.clone();
+        let provider = self.provider.clone();
+        let code = code.to_string();
+        let code_verifier = code_verifier.to_string();
+        pin_static_future(async move {
+            let discovery = discover(&client, &discovery, &provider.issuer).await?;
+            let response = client
+                .post(&discovery.token_endpoint)
+                .form(&OidcTokenRequestForm {
+                    grant_type: "authorization_code",
+                    code: &code,
+                    redirect_uri: &provider.redirect_uri,
+                    client_id: &provider.client_id,
+                    client_secret: &provider.client_secret,
+                    code_verifier: &code_verifier,
+                })
+                .send()
+                .await
+                .map_err(ErrorBoxed::from)?;
+            // invalid, expired or reused codes are rejected with invalid_grant
+            if response.status() == StatusCode::BAD_REQUEST {
+                return Err(IdentityProviderError::CodeIsRejected);
+            }
+            let body = response
+                .error_for_status()
+                .map_err(ErrorBoxed::from)?
+                .bytes()
+                .await
+                .map_err(ErrorBoxed::from)?;
+            let token_response: OidcTokenResponseJson =
+                serde_json::from_slice(&body).map_err(ErrorBoxed::from)?;
+            Ok(token_response.id_token)
+        })
+    }
+
+    fn get_signing_keys(&self) -> StaticPinnedFuture<IdTokenKeys, IdentityProviderError> {
+        let client = self.client.clone();
+        let discovery = self.discovery.clone();
+        let provider = self.provider.clone();
+        pin_static_future(async move {
+            let discovery = discover(&client, &discovery, &provider.issuer).await?;
+            // keys are fetched on every signin, so rotation at the provider is picked up right away
+            let body = client
+                .get(&discovery.jwks_uri)
+                .send()
+                .await
+                .and_then(|response| response.error_for_status())
+                .map_err(ErrorBoxed::from)?
+                .bytes()
+                .await
+                .map_err(ErrorBoxed::from)?;
+            Ok(IdTokenKeys::from_json(&body).map_err(ErrorBoxed::from)?)
+        })
+    }
+}
+
+/// Fetches discovery document once, failed attempts are retried on the next call.
+/// Documents of another issuer are rejected, endpoints must not be redirected elsewhere
+async fn discover<'a>(
+    client: &Client,
+    discovery: &'a OnceCell<OidcDiscoveryJson>,
+    issuer: &str,
+) -> Result<&'a OidcDiscoveryJson, IdentityProviderError> {
+    discovery
+        .get_or_try_init(|| async {
+            let body = client
+                .get(format!("{}{DISCOVERY_PATH}", issuer.trim_end_matches('/')))
+                .send()
+                .await
+                .and_then(|response| response.error_for_status())
+                .map_err(ErrorBoxed::from)?
+                .bytes()
+                .await
+                .map_err(ErrorBoxed::from)?;
+            let discovery: OidcDiscoveryJson =
+                serde_json::from_slice(&body).map_err(ErrorBoxed::from)?;
+            if discovery.issuer != issuer {
+                return Err(IdentityProviderError::IssuerMismatch {
+                    expected: issuer.to_string(),
+                    actual: discovery.issuer,
+                });
+            }
+            Ok(discovery)
+        })
+        .await
+}
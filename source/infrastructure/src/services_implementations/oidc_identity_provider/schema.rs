@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+/// Part of the provider metadata the authorization code flow needs
+#[derive(Deserialize)]
+pub struct OidcDiscoveryJson {
+    pub issuer: String,
+    pub authorization_endpoint: String,
+    pub token_endpoint: String,
+    pub jwks_uri: String,
+}
+
+#[derive(Serialize)]
+pub struct OidcTokenRequestForm<'a> {
+    pub grant_type: &'a str,
+    pub code: &'a str,
+    pub redirect_uri: &'a str,
+    pub client_id: &'a str,
+    pub client_secret: &'a str,
+    pub code_verifier: &'a str,
+}
+
+#[derive(Deserialize)]
+pub struct OidcTokenResponseJson {
+    pub id_token: String,
+}
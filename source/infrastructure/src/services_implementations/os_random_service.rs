@@ -1,4 +1,5 @@
 use argon2::password_hash::SaltString;
+use base64::{Engine, prelude::BASE64_URL_SAFE_NO_PAD};
 use ed25519_dalek::{
     SigningKey,
     pkcs8::{EncodePrivateKey, spki::der::pem::LineEnding},
@@ -6,8 +7,12 @@ use ed25519_dalek::{
 use nimbus_auth_application::services::random_service::{
     RandomService, errors::RandomServiceError,
 };
-use nimbus_auth_shared::futures::{StaticPinnedFuture, pin_static_future};
-use rand::rngs::OsRng;
+use nimbus_auth_domain::entities::device_authorization::value_objects::user_code::UserCode;
+use nimbus_auth_shared::{
+    constants::{OAUTH_USER_CODE_ALPHABET, OAUTH_USER_CODE_LENGTH, RANDOM_SECRET_LENGTH_BYTES},
+    futures::{StaticPinnedFuture, pin_static_future},
+};
+use rand::{Rng, RngCore, rngs::OsRng};
 use zeroize::Zeroizing;
 
 pub struct OsRandomService {}
@@ -32,4 +37,25 @@ impl RandomService for OsRandomService {
     fn get_random_salt_b64(&self) -> StaticPinnedFuture<String, RandomServiceError> {
         pin_static_future(async { Ok(SaltString::generate(&mut OsRng).as_str().to_string()) })
     }
+
+    fn get_random_secret(&self) -> StaticPinnedFuture<Zeroizing<String>, RandomServiceError> {
+        pin_static_future(async {
+            let mut bytes = Zeroizing::new([0u8; RANDOM_SECRET_LENGTH_BYTES]);
+            OsRng.fill_bytes(bytes.as_mut());
+            Ok(Zeroizing::new(
+                BASE64_URL_SAFE_NO_PAD.encode(bytes.as_ref()),
+            ))
+        })
+    }
+
+    fn get_random_user_code(&self) -> StaticPinnedFuture<UserCode, RandomServiceError> {
+        pin_static_future(async {
+            let alphabet = OAUTH_USER_CODE_ALPHABET.as_bytes();
+            let value: String = (0..OAUTH_USER_CODE_LENGTH)
+                .map(|_| alphabet[OsRng.gen_range(0..alphabet.len())] as char)
+                .collect();
+            // characters are taken from the alphabet the code is validated against
+            Ok(UserCode::from(&value).unwrap())
+        })
+    }
 }
@@ -0,0 +1,91 @@
+use std::sync::Arc;
+
+use nimbus_auth_application::services::api_key_repository::{
+    ApiKeyRepository, errors::ApiKeyRepositoryError,
+};
+use nimbus_auth_domain::{
+    entities::{api_key::ApiKey, user::User},
+    value_objects::identifier::Identifier,
+};
+use nimbus_auth_shared::{
+    errors::ErrorBoxed,
+    futures::{StaticPinnedFuture, pin_static_future},
+};
+use ulid::Ulid;
+
+use crate::{
+    postgres_db::PostgresDatabase,
+    services_implementations::postgres_api_key_repository::{
+        queries::{get_api_key_by_id, get_api_keys_by_user_id, save_api_key},
+        schema::{GetApiKeyDb, SaveApiKeyDb},
+    },
+};
+
+mod queries;
+mod schema;
+
+/// Keys are stored within the realm the repository is scoped to
+pub struct PostgresApiKeyRepository {
+    database: Arc<PostgresDatabase>,
+    realm: String,
+}
+
+impl PostgresApiKeyRepository {
+    pub fn new(database: Arc<PostgresDatabase>, realm: &str) -> Self {
+        Self {
+            database,
+            realm: realm.to_string(),
+        }
+    }
+}
+
+impl ApiKeyRepository for PostgresApiKeyRepository {
+    fn get_by_id(
+        &self,
+        id: &Identifier<Ulid, ApiKey>,
+    ) -> StaticPinnedFuture<Option<ApiKey>, ApiKeyRepositoryError> {
+        let db_clone = self.database.clone();
+        let realm = self.realm.clone();
+        let id = id.to_string();
+        pin_static_future(async move {
+            let mut connection = db_clone.pool().acquire().await.map_err(ErrorBoxed::from)?;
+            get_api_key_by_id(&mut *connection, &realm, &id)
+                .await?
+                .as_ref()
+                .map(restore_api_key)
+                .transpose()
+        })
+    }
+
+    fn get_by_user_id(
+        &self,
+        user_id: &Identifier<Ulid, User>,
+    ) -> StaticPinnedFuture<Vec<ApiKey>, ApiKeyRepositoryError> {
+        let db_clone = self.database.clone();
+        let realm = self.realm.clone();
+        let user_id = user_id.to_string();
+        pin_static_future(async move {
+            let mut connection = db_clone.pool().acquire().await.map_err(ErrorBoxed::from)?;
+            get_api_keys_by_user_id(&mut *connection, &realm, &user_id)
+                .await?
+                .iter()
+                .map(restore_api_key)
+                .collect()
+        })
+    }
+
+    fn save(&self, api_key: &ApiKey) -> StaticPinnedFuture<(), ApiKeyRepositoryError> {
+        let db_clone = self.database.clone();
+        let realm = self.realm.clone();
+        let api_key = SaveApiKeyDb::from(api_key);
+        pin_static_future(async move {
+            let mut connection = db_clone.pool().acquire().await.map_err(ErrorBoxed::from)?;
+            save_api_key(&mut *connection, &realm, &api_key).await
+        })
+    }
+}
+
+fn restore_api_key(api_key_db: &GetApiKeyDb) -> Result<ApiKey, ApiKeyRepositoryError> {
+    ApiKey::try_from(api_key_db)
+        .map_err(|err| ApiKeyRepositoryError::ApiKeyRestoration(ErrorBoxed::from(err)))
+}
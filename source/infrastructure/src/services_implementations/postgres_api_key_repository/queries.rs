@@ -0,0 +1,72 @@
+use nimbus_auth_application::services::api_key_repository::errors::ApiKeyRepositoryError;
+use nimbus_auth_shared::errors::ErrorBoxed;
+
+use crate::services_implementations::postgres_api_key_repository::schema::{
+    GetApiKeyDb, SaveApiKeyDb,
+};
+
+pub async fn get_api_key_by_id<'a, E>(
+    executor: &'a mut E,
+    realm: &str,
+    id: &str,
+) -> Result<Option<GetApiKeyDb>, ApiKeyRepositoryError>
+where
+    &'a mut E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+{
+    Ok(sqlx::query_as::<_, GetApiKeyDb>(
+        "SELECT id, user_id, name, secret_hash, scopes, created_at, expires_at, revoked_at \
+        FROM api_keys WHERE realm = $1 AND id = $2",
+    )
+    .bind(realm)
+    .bind(id)
+    .fetch_optional(executor)
+    .await
+    .map_err(ErrorBoxed::from)?)
+}
+
+pub async fn get_api_keys_by_user_id<'a, E>(
+    executor: &'a mut E,
+    realm: &str,
+    user_id: &str,
+) -> Result<Vec<GetApiKeyDb>, ApiKeyRepositoryError>
+where
+    &'a mut E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+{
+    Ok(sqlx::query_as::<_, GetApiKeyDb>(
+        "SELECT id, user_id, name, secret_hash, scopes, created_at, expires_at, revoked_at \
+        FROM api_keys WHERE realm = $1 AND user_id = $2 ORDER BY created_at, id",
+    )
+    .bind(realm)
+    .bind(user_id)
+    .fetch_all(executor)
+    .await
+    .map_err(ErrorBoxed::from)?)
+}
+
+pub async fn save_api_key<'a, E>(
+    executor: &'a mut E,
+    realm: &str,
+    api_key: &SaveApiKeyDb,
+) -> Result<(), ApiKeyRepositoryError>
+where
+    &'a mut E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+{
+    sqlx::query(
+        "INSERT INTO api_keys (id, realm, user_id, name, secret_hash, scopes, created_at, expires_at, revoked_at) \
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9) \
+        ON CONFLICT (id) DO UPDATE SET revoked_at = EXCLUDED.revoked_at",
+    )
+    .bind(&api_key.id)
+    .bind(realm)
+    .bind(&api_key.user_id)
+    .bind(&api_key.name)
+    .bind(&api_key.secret_hash)
+    .bind(&api_key.scopes)
+    .bind(api_key.created_at)
+    .bind(api_key.expires_at)
+    .bind(api_key.revoked_at)
+    .execute(executor)
+    .await
+    .map_err(ErrorBoxed::from)?;
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use nimbus_auth_domain::{
+    entities::{
+        Entity,
+        api_key::{
+            ApiKey, specifications::RestoreApiKeySpecification,
+            value_objects::api_key_name::ApiKeyName,
+        },
+        role::value_objects::permission::Permission,
+    },
+    value_objects::{identifier::Identifier, secret_hash::SecretHash},
+};
+use sqlx::prelude::FromRow;
+use time::OffsetDateTime;
+use ulid::Ulid;
+
+use crate::services_implementations::postgres_api_key_repository::schema::errors::TryFromApiKeyDbError;
+
+pub mod errors;
+
+#[derive(FromRow)]
+pub struct GetApiKeyDb {
+    pub id: String,
+    pub user_id: String,
+    pub name: String,
+    pub secret_hash: String,
+    pub scopes: Vec<String>,
+    pub created_at: OffsetDateTime,
+    pub expires_at: Option<OffsetDateTime>,
+    pub revoked_at: Option<OffsetDateTime>,
+}
+
+pub struct SaveApiKeyDb {
+    pub id: String,
+    pub user_id: String,
+    pub name: String,
+    pub secret_hash: String,
+    pub scopes: Vec<String>,
+    pub created_at: OffsetDateTime,
+    pub expires_at: Option<OffsetDateTime>,
+    pub revoked_at: Option<OffsetDateTime>,
+}
+
+impl TryFrom<&GetApiKeyDb> for ApiKey {
+    type Error = TryFromApiKeyDbError;
+
+    fn try_from(value: &GetApiKeyDb) -> Result<Self, Self::Error> {
+        Ok(ApiKey::restore(RestoreApiKeySpecification {
+            id: Identifier::from(Ulid::from_string(&value.id)?),
+            user_id: Identifier::from(Ulid::from_string(&value.user_id)?),
+            name: ApiKeyName::from(&value.name)?,
+            secret_hash: SecretHash::from(&value.secret_hash)?,
+            scopes: value
+                .scopes
+                .iter()
+                .map(|scope| Permission::from(scope))
+                .collect::<Result<_, _>>()?,
+            created_at: value.created_at,
+            expires_at: value.expires_at,
+            revoked_at: value.revoked_at,
+        }))
+    }
+}
+
+impl From<&ApiKey> for SaveApiKeyDb {
+    fn from(value: &ApiKey) -> Self {
+        SaveApiKeyDb {
+            id: value.id().to_string(),
+            user_id: value.user_id().to_string(),
+            name: value.name().to_string(),
+            secret_hash: value.secret_hash().value().to_string(),
+            scopes: value
+                .scopes()
+                .iter()
+                .map(|scope| scope.to_string())
+                .collect(),
+            created_at: *value.created_at(),
+            expires_at: value.expires_at().copied(),
+            revoked_at: value.revoked_at().copied(),
+        }
+    }
+}
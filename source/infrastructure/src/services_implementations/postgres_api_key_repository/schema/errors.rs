@@ -0,0 +1,21 @@
+use nimbus_auth_domain::{
+    entities::{
+        api_key::value_objects::api_key_name::errors::ApiKeyNameError,
+        role::value_objects::permission::errors::PermissionError,
+    },
+    value_objects::secret_hash::errors::SecretHashError,
+};
+use thiserror::Error;
+use ulid::DecodeError;
+
+#[derive(Error, Debug)]
+pub enum TryFromApiKeyDbError {
+    #[error("invalid identifier. Error: {0}")]
+    InvalidIdentifier(#[from] DecodeError),
+    #[error(transparent)]
+    ApiKeyName(#[from] ApiKeyNameError),
+    #[error(transparent)]
+    SecretHash(#[from] SecretHashError),
+    #[error(transparent)]
+    Permission(#[from] PermissionError),
+}
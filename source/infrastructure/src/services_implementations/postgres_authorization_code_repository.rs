@@ -0,0 +1,78 @@
+use std::sync::Arc;
+
+use nimbus_auth_application::services::authorization_code_repository::{
+    AuthorizationCodeRepository, errors::AuthorizationCodeRepositoryError,
+};
+use nimbus_auth_domain::{
+    entities::authorization_code::AuthorizationCode, value_objects::identifier::Identifier,
+};
+use nimbus_auth_shared::{
+    errors::ErrorBoxed,
+    futures::{StaticPinnedFuture, pin_static_future},
+};
+use ulid::Ulid;
+
+use crate::{
+    postgres_db::PostgresDatabase,
+    services_implementations::postgres_authorization_code_repository::{
+        queries::{save_authorization_code, take_authorization_code},
+        schema::{GetAuthorizationCodeDb, SaveAuthorizationCodeDb},
+    },
+};
+
+mod queries;
+mod schema;
+
+pub struct PostgresAuthorizationCodeRepository {
+    database: Arc<PostgresDatabase>,
+    realm: String,
+}
+
+impl PostgresAuthorizationCodeRepository {
+    pub fn new(database: Arc<PostgresDatabase>, realm: &str) -> Self {
+        Self {
+            database,
+            realm: realm.to_string(),
+        }
+    }
+}
+
+impl AuthorizationCodeRepository for PostgresAuthorizationCodeRepository {
+    fn save(
+        &self,
+        code: &AuthorizationCode,
+    ) -> StaticPinnedFuture<(), AuthorizationCodeRepositoryError> {
+        let db_clone = self.database.clone();
+        let realm = self.realm.clone();
+        let code = SaveAuthorizationCodeDb::from(code);
+        pin_static_future(async move {
+            let mut connection = db_clone.pool().acquire().await.map_err(ErrorBoxed::from)?;
+            save_authorization_code(&mut *connection, &realm, &code).await
+        })
+    }
+
+    fn take(
+        &self,
+        id: &Identifier<Ulid, AuthorizationCode>,
+    ) -> StaticPinnedFuture<Option<AuthorizationCode>, AuthorizationCodeRepositoryError> {
+        let db_clone = self.database.clone();
+        let realm = self.realm.clone();
+        let id = id.to_string();
+        pin_static_future(async move {
+            let mut connection = db_clone.pool().acquire().await.map_err(ErrorBoxed::from)?;
+            take_authorization_code(&mut *connection, &realm, &id)
+                .await?
+                .as_ref()
+                .map(restore_authorization_code)
+                .transpose()
+        })
+    }
+}
+
+fn restore_authorization_code(
+    code_db: &GetAuthorizationCodeDb,
+) -> Result<AuthorizationCode, AuthorizationCodeRepositoryError> {
+    AuthorizationCode::try_from(code_db).map_err(|err| {
+        AuthorizationCodeRepositoryError::AuthorizationCodeRestoration(ErrorBoxed::from(err))
+    })
+}
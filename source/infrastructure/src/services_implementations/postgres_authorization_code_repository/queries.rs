@@ -0,0 +1,52 @@
+use nimbus_auth_application::services::authorization_code_repository::errors::AuthorizationCodeRepositoryError;
+use nimbus_auth_shared::errors::ErrorBoxed;
+
+use crate::services_implementations::postgres_authorization_code_repository::schema::{
+    GetAuthorizationCodeDb, SaveAuthorizationCodeDb,
+};
+
+pub async fn save_authorization_code<'a, E>(
+    executor: &'a mut E,
+    realm: &str,
+    code: &SaveAuthorizationCodeDb,
+) -> Result<(), AuthorizationCodeRepositoryError>
+where
+    &'a mut E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+{
+    sqlx::query(
+        "INSERT INTO oauth_authorization_codes \
+        (id, realm, client_id, user_id, redirect_uri, code_challenge, expires_at) \
+        VALUES ($1, $2, $3, $4, $5, $6, $7)",
+    )
+    .bind(&code.id)
+    .bind(realm)
+    .bind(&code.client_id)
+    .bind(&code.user_id)
+    .bind(&code.redirect_uri)
+    .bind(&code.code_challenge)
+    .bind(code.expires_at)
+    .execute(executor)
+    .await
+    .map_err(ErrorBoxed::from)?;
+    Ok(())
+}
+
+/// Deleting with returning makes the code single use even under concurrent exchanges
+pub async fn take_authorization_code<'a, E>(
+    executor: &'a mut E,
+    realm: &str,
+    id: &str,
+) -> Result<Option<GetAuthorizationCodeDb>, AuthorizationCodeRepositoryError>
+where
+    &'a mut E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+{
+    Ok(sqlx::query_as::<_, GetAuthorizationCodeDb>(
+        "DELETE FROM oauth_authorization_codes WHERE realm = $1 AND id = $2 \
+        RETURNING id, client_id, user_id, redirect_uri, code_challenge, expires_at",
+    )
+    .bind(realm)
+    .bind(id)
+    .fetch_optional(executor)
+    .await
+    .map_err(ErrorBoxed::from)?)
+}
@@ -0,0 +1,72 @@
+use nimbus_auth_domain::{
+    entities::{
+        Entity,
+        authorization_code::{
+            AuthorizationCode, specifications::RestoreAuthorizationCodeSpecification,
+            value_objects::code_challenge::CodeChallenge,
+        },
+        oauth_client::value_objects::redirect_uri::RedirectUri,
+    },
+    value_objects::identifier::Identifier,
+};
+use nimbus_auth_shared::constants::OAUTH_CODE_CHALLENGE_METHOD_S256;
+use sqlx::prelude::FromRow;
+use time::OffsetDateTime;
+use ulid::Ulid;
+
+use crate::services_implementations::postgres_authorization_code_repository::schema::errors::TryFromAuthorizationCodeDbError;
+
+pub mod errors;
+
+#[derive(FromRow)]
+pub struct GetAuthorizationCodeDb {
+    pub id: String,
+    pub client_id: String,
+    pub user_id: String,
+    pub redirect_uri: String,
+    pub code_challenge: String,
+    pub expires_at: OffsetDateTime,
+}
+
+pub struct SaveAuthorizationCodeDb {
+    pub id: String,
+    pub client_id: String,
+    pub user_id: String,
+    pub redirect_uri: String,
+    pub code_challenge: String,
+    pub expires_at: OffsetDateTime,
+}
+
+impl TryFrom<&GetAuthorizationCodeDb> for AuthorizationCode {
+    type Error = TryFromAuthorizationCodeDbError;
+
+    fn try_from(value: &GetAuthorizationCodeDb) -> Result<Self, Self::Error> {
+        Ok(AuthorizationCode::restore(
+            RestoreAuthorizationCodeSpecification {
+                id: Identifier::from(Ulid::from_string(&value.id)?),
+                client_id: Identifier::from(Ulid::from_string(&value.client_id)?),
+                user_id: Identifier::from(Ulid::from_string(&value.user_id)?),
+                redirect_uri: RedirectUri::from(&value.redirect_uri)?,
+                // only S256 challenges are ever stored
+                code_challenge: CodeChallenge::from(
+                    &value.code_challenge,
+                    OAUTH_CODE_CHALLENGE_METHOD_S256,
+                )?,
+                expires_at: value.expires_at,
+            },
+        ))
+    }
+}
+
+impl From<&AuthorizationCode> for SaveAuthorizationCodeDb {
+    fn from(value: &AuthorizationCode) -> Self {
+        SaveAuthorizationCodeDb {
+            id: value.id().to_string(),
+            client_id: value.client_id().to_string(),
+            user_id: value.user_id().to_string(),
+            redirect_uri: value.redirect_uri().to_string(),
+            code_challenge: value.code_challenge().value().to_string(),
+            expires_at: value.expires_at(),
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use nimbus_auth_domain::entities::{
+    authorization_code::value_objects::code_challenge::errors::CodeChallengeError,
+    oauth_client::value_objects::redirect_uri::errors::RedirectUriError,
+};
+use thiserror::Error;
+use ulid::DecodeError;
+
+#[derive(Error, Debug)]
+pub enum TryFromAuthorizationCodeDbError {
+    #[error("invalid identifier. Error: {0}")]
+    InvalidIdentifier(#[from] DecodeError),
+    #[error(transparent)]
+    RedirectUri(#[from] RedirectUriError),
+    #[error(transparent)]
+    CodeChallenge(#[from] CodeChallengeError),
+}
@@ -0,0 +1,116 @@
+use std::sync::Arc;
+
+use nimbus_auth_application::services::device_authorization_repository::{
+    DeviceAuthorizationRepository, errors::DeviceAuthorizationRepositoryError,
+};
+use nimbus_auth_domain::{
+    entities::device_authorization::{DeviceAuthorization, value_objects::user_code::UserCode},
+    value_objects::identifier::Identifier,
+};
+use nimbus_auth_shared::{
+    errors::ErrorBoxed,
+    futures::{StaticPinnedFuture, pin_static_future},
+};
+use ulid::Ulid;
+
+use crate::{
+    postgres_db::PostgresDatabase,
+    services_implementations::postgres_device_authorization_repository::{
+        queries::{
+            get_device_authorization_by_id, get_device_authorization_by_user_code,
+            save_device_authorization, take_device_authorization,
+        },
+        schema::{GetDeviceAuthorizationDb, SaveDeviceAuthorizationDb},
+    },
+};
+
+mod queries;
+mod schema;
+
+pub struct PostgresDeviceAuthorizationRepository {
+    database: Arc<PostgresDatabase>,
+    realm: String,
+}
+
+impl PostgresDeviceAuthorizationRepository {
+    pub fn new(database: Arc<PostgresDatabase>, realm: &str) -> Self {
+        Self {
+            database,
+            realm: realm.to_string(),
+        }
+    }
+}
+
+impl DeviceAuthorizationRepository for PostgresDeviceAuthorizationRepository {
+    fn get_by_id(
+        &self,
+        id: &Identifier<Ulid, DeviceAuthorization>,
+    ) -> StaticPinnedFuture<Option<DeviceAuthorization>, DeviceAuthorizationRepositoryError> {
+        let db_clone = self.database.clone();
+        let realm = self.realm.clone();
+        let id = id.to_string();
+        pin_static_future(async move {
+            let mut connection = db_clone.pool().acquire().await.map_err(ErrorBoxed::from)?;
+            get_device_authorization_by_id(&mut *connection, &realm, &id)
+                .await?
+                .as_ref()
+                .map(restore_device_authorization)
+                .transpose()
+        })
+    }
+
+    fn get_by_user_code(
+        &self,
+        user_code: &UserCode,
+    ) -> StaticPinnedFuture<Option<DeviceAuthorization>, DeviceAuthorizationRepositoryError> {
+        let db_clone = self.database.clone();
+        let realm = self.realm.clone();
+        let user_code = user_code.value().to_string();
+        pin_static_future(async move {
+            let mut connection = db_clone.pool().acquire().await.map_err(ErrorBoxed::from)?;
+            get_device_authorization_by_user_code(&mut *connection, &realm, &user_code)
+                .await?
+                .as_ref()
+                .map(restore_device_authorization)
+                .transpose()
+        })
+    }
+
+    fn save(
+        &self,
+        device_authorization: &DeviceAuthorization,
+    ) -> StaticPinnedFuture<(), DeviceAuthorizationRepositoryError> {
+        let db_clone = self.database.clone();
+        let realm = self.realm.clone();
+        let device_authorization = SaveDeviceAuthorizationDb::from(device_authorization);
+        pin_static_future(async move {
+            let mut connection = db_clone.pool().acquire().await.map_err(ErrorBoxed::from)?;
+            save_device_authorization(&mut *connection, &realm, &device_authorization).await
+        })
+    }
+
+    fn take(
+        &self,
+        id: &Identifier<Ulid, DeviceAuthorization>,
+    ) -> StaticPinnedFuture<Option<DeviceAuthorization>, DeviceAuthorizationRepositoryError> {
+        let db_clone = self.database.clone();
+        let realm = self.realm.clone();
+        let id = id.to_string();
+        pin_static_future(async move {
+            let mut connection = db_clone.pool().acquire().await.map_err(ErrorBoxed::from)?;
+            take_device_authorization(&mut *connection, &realm, &id)
+                .await?
+                .as_ref()
+                .map(restore_device_authorization)
+                .transpose()
+        })
+    }
+}
+
+fn restore_device_authorization(
+    device_authorization_db: &GetDeviceAuthorizationDb,
+) -> Result<DeviceAuthorization, DeviceAuthorizationRepositoryError> {
+    DeviceAuthorization::try_from(device_authorization_db).map_err(|err| {
+        DeviceAuthorizationRepositoryError::DeviceAuthorizationRestoration(ErrorBoxed::from(err))
+    })
+}
@@ -0,0 +1,94 @@
+use nimbus_auth_application::services::device_authorization_repository::errors::DeviceAuthorizationRepositoryError;
+use nimbus_auth_shared::errors::ErrorBoxed;
+
+use crate::services_implementations::postgres_device_authorization_repository::schema::{
+    GetDeviceAuthorizationDb, SaveDeviceAuthorizationDb,
+};
+
+pub async fn get_device_authorization_by_id<'a, E>(
+    executor: &'a mut E,
+    realm: &str,
+    id: &str,
+) -> Result<Option<GetDeviceAuthorizationDb>, DeviceAuthorizationRepositoryError>
+where
+    &'a mut E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+{
+    Ok(sqlx::query_as::<_, GetDeviceAuthorizationDb>(
+        "SELECT id, client_id, user_code, approved_by, expires_at, interval_seconds, last_polled_at \
+        FROM oauth_device_authorizations WHERE realm = $1 AND id = $2",
+    )
+    .bind(realm)
+    .bind(id)
+    .fetch_optional(executor)
+    .await
+    .map_err(ErrorBoxed::from)?)
+}
+
+pub async fn get_device_authorization_by_user_code<'a, E>(
+    executor: &'a mut E,
+    realm: &str,
+    user_code: &str,
+) -> Result<Option<GetDeviceAuthorizationDb>, DeviceAuthorizationRepositoryError>
+where
+    &'a mut E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+{
+    Ok(sqlx::query_as::<_, GetDeviceAuthorizationDb>(
+        "SELECT id, client_id, user_code, approved_by, expires_at, interval_seconds, last_polled_at \
+        FROM oauth_device_authorizations WHERE realm = $1 AND user_code = $2 \
+        ORDER BY expires_at DESC LIMIT 1",
+    )
+    .bind(realm)
+    .bind(user_code)
+    .fetch_optional(executor)
+    .await
+    .map_err(ErrorBoxed::from)?)
+}
+
+pub async fn save_device_authorization<'a, E>(
+    executor: &'a mut E,
+    realm: &str,
+    device_authorization: &SaveDeviceAuthorizationDb,
+) -> Result<(), DeviceAuthorizationRepositoryError>
+where
+    &'a mut E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+{
+    sqlx::query(
+        "INSERT INTO oauth_device_authorizations \
+        (id, realm, client_id, user_code, approved_by, expires_at, interval_seconds, last_polled_at) \
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8) \
+        ON CONFLICT (id) DO UPDATE SET approved_by = EXCLUDED.approved_by, \
+        interval_seconds = EXCLUDED.interval_seconds, last_polled_at = EXCLUDED.last_polled_at",
+    )
+    .bind(&device_authorization.id)
+    .bind(realm)
+    .bind(&device_authorization.client_id)
+    .bind(&device_authorization.user_code)
+    .bind(&device_authorization.approved_by)
+    .bind(device_authorization.expires_at)
+    .bind(device_authorization.interval_seconds)
+    .bind(device_authorization.last_polled_at)
+    .execute(executor)
+    .await
+    .map_err(ErrorBoxed::from)?;
+    Ok(())
+}
+
+/// Deleting with returning makes the device code single use even under concurrent polls
+pub async fn take_device_authorization<'a, E>(
+    executor: &'a mut E,
+    realm: &str,
+    id: &str,
+) -> Result<Option<GetDeviceAuthorizationDb>, DeviceAuthorizationRepositoryError>
+where
+    &'a mut E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+{
+    Ok(sqlx::query_as::<_, GetDeviceAuthorizationDb>(
+        "DELETE FROM oauth_device_authorizations WHERE realm = $1 AND id = $2 \
+        RETURNING id, client_id, user_code, approved_by, expires_at, interval_seconds, last_polled_at",
+    )
+    .bind(realm)
+    .bind(id)
+    .fetch_optional(executor)
+    .await
+    .map_err(ErrorBoxed::from)?)
+}
@@ -0,0 +1,76 @@
+use nimbus_auth_domain::{
+    entities::{
+        Entity,
+        device_authorization::{
+            DeviceAuthorization, specifications::RestoreDeviceAuthorizationSpecification,
+            value_objects::user_code::UserCode,
+        },
+    },
+    value_objects::identifier::Identifier,
+};
+use sqlx::prelude::FromRow;
+use time::OffsetDateTime;
+use ulid::Ulid;
+
+use crate::services_implementations::postgres_device_authorization_repository::schema::errors::TryFromDeviceAuthorizationDbError;
+
+pub mod errors;
+
+#[derive(FromRow)]
+pub struct GetDeviceAuthorizationDb {
+    pub id: String,
+    pub client_id: String,
+    pub user_code: String,
+    pub approved_by: Option<String>,
+    pub expires_at: OffsetDateTime,
+    pub interval_seconds: i32,
+    pub last_polled_at: Option<OffsetDateTime>,
+}
+
+pub struct SaveDeviceAuthorizationDb {
+    pub id: String,
+    pub client_id: String,
+    pub user_code: String,
+    pub approved_by: Option<String>,
+    pub expires_at: OffsetDateTime,
+    pub interval_seconds: i32,
+    pub last_polled_at: Option<OffsetDateTime>,
+}
+
+impl TryFrom<&GetDeviceAuthorizationDb> for DeviceAuthorization {
+    type Error = TryFromDeviceAuthorizationDbError;
+
+    fn try_from(value: &GetDeviceAuthorizationDb) -> Result<Self, Self::Error> {
+        Ok(DeviceAuthorization::restore(
+            RestoreDeviceAuthorizationSpecification {
+                id: Identifier::from(Ulid::from_string(&value.id)?),
+                client_id: Identifier::from(Ulid::from_string(&value.client_id)?),
+                user_code: UserCode::from(&value.user_code)?,
+                approved_by: value
+                    .approved_by
+                    .as_deref()
+                    .map(|approved_by| Ulid::from_string(approved_by).map(Identifier::from))
+                    .transpose()?,
+                expires_at: value.expires_at,
+                interval_seconds: value.interval_seconds.max(0) as usize,
+                last_polled_at: value.last_polled_at,
+            },
+        ))
+    }
+}
+
+impl From<&DeviceAuthorization> for SaveDeviceAuthorizationDb {
+    fn from(value: &DeviceAuthorization) -> Self {
+        SaveDeviceAuthorizationDb {
+            id: value.id().to_string(),
+            client_id: value.client_id().to_string(),
+            user_code: value.user_code().value().to_string(),
+            approved_by: value
+                .approved_by()
+                .map(|approved_by| approved_by.to_string()),
+            expires_at: value.expires_at(),
+            interval_seconds: value.interval_seconds().min(i32::MAX as usize) as i32,
+            last_polled_at: value.last_polled_at(),
+        }
+    }
+}
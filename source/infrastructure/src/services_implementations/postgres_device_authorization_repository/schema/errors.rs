@@ -0,0 +1,11 @@
+use nimbus_auth_domain::entities::device_authorization::value_objects::user_code::errors::UserCodeError;
+use thiserror::Error;
+use ulid::DecodeError;
+
+#[derive(Error, Debug)]
+pub enum TryFromDeviceAuthorizationDbError {
+    #[error("invalid identifier. Error: {0}")]
+    InvalidIdentifier(#[from] DecodeError),
+    #[error(transparent)]
+    UserCode(#[from] UserCodeError),
+}
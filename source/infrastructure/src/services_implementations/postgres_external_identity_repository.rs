@@ -0,0 +1,114 @@
+use std::sync::Arc;
+
+use nimbus_auth_application::services::external_identity_repository::{
+    ExternalIdentityRepository, errors::ExternalIdentityRepositoryError,
+};
+use nimbus_auth_domain::{
+    entities::{Entity, external_identity::ExternalIdentity, user::User},
+    value_objects::identifier::Identifier,
+};
+use nimbus_auth_shared::{
+    errors::ErrorBoxed,
+    futures::{StaticPinnedFuture, pin_static_future},
+};
+use ulid::Ulid;
+
+use crate::{
+    postgres_db::PostgresDatabase,
+    services_implementations::postgres_external_identity_repository::{
+        queries::{
+            delete_external_identity, get_external_identities_by_user_id,
+            get_external_identity_by_subject, save_external_identity,
+        },
+        schema::{GetExternalIdentityDb, SaveExternalIdentityDb},
+    },
+};
+
+pub(crate) mod queries;
+pub(crate) mod schema;
+
+pub struct PostgresExternalIdentityRepository {
+    database: Arc<PostgresDatabase>,
+    realm: String,
+}
+
+impl PostgresExternalIdentityRepository {
+    pub fn new(database: Arc<PostgresDatabase>, realm: &str) -> Self {
+        Self {
+            database,
+            realm: realm.to_string(),
+        }
+    }
+}
+
+impl ExternalIdentityRepository for PostgresExternalIdentityRepository {
+    fn get_by_subject(
+        &self,
+        provider: &str,
+        subject: &str,
+    ) -> StaticPinnedFuture<Option<ExternalIdentity>, ExternalIdentityRepositoryError> {
+        let db_clone = self.database.clone();
+        let realm = self.realm.clone();
+        let provider = provider.to_string();
+        let subject = subject.to_string();
+        pin_static_future(async move {
+            let mut connection = db_clone.pool().acquire().await.map_err(ErrorBoxed::from)?;
+            get_external_identity_by_subject(&mut *connection, &realm, &provider, &subject)
+                .await?
+                .as_ref()
+                .map(restore_external_identity)
+                .transpose()
+        })
+    }
+
+    fn get_by_user_id(
+        &self,
+        user_id: &Identifier<Ulid, User>,
+    ) -> StaticPinnedFuture<Vec<ExternalIdentity>, ExternalIdentityRepositoryError> {
+        let db_clone = self.database.clone();
+        let realm = self.realm.clone();
+        let user_id = user_id.to_string();
+        pin_static_future(async move {
+            let mut connection = db_clone.pool().acquire().await.map_err(ErrorBoxed::from)?;
+            get_external_identities_by_user_id(&mut *connection, &realm, &user_id)
+                .await?
+                .iter()
+                .map(restore_external_identity)
+                .collect()
+        })
+    }
+
+    fn save(
+        &self,
+        identity: &ExternalIdentity,
+    ) -> StaticPinnedFuture<(), ExternalIdentityRepositoryError> {
+        let db_clone = self.database.clone();
+        let realm = self.realm.clone();
+        let identity = SaveExternalIdentityDb::from(identity);
+        pin_static_future(async move {
+            let mut connection = db_clone.pool().acquire().await.map_err(ErrorBoxed::from)?;
+            save_external_identity(&mut *connection, &realm, &identity).await
+        })
+    }
+
+    fn delete(
+        &self,
+        identity: &ExternalIdentity,
+    ) -> StaticPinnedFuture<(), ExternalIdentityRepositoryError> {
+        let db_clone = self.database.clone();
+        let realm = self.realm.clone();
+        let id = identity.id().to_string();
+        pin_static_future(async move {
+            let mut connection = db_clone.pool().acquire().await.map_err(ErrorBoxed::from)?;
+            delete_external_identity(&mut *connection, &realm, &id).await
+        })
+    }
+}
+
+fn restore_external_identity(
+    identity_db: &GetExternalIdentityDb,
+) -> Result<ExternalIdentity, ExternalIdentityRepositoryError> {
+    ExternalIdentity::try_from(identity_db).map_err(|err| {
+        ExternalIdentityRepositoryError::ExternalIdentityRestoration(ErrorBoxed::from(err))
+    })
+}
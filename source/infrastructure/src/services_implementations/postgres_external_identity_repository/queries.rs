@@ -0,0 +1,90 @@
+use nimbus_auth_application::services::external_identity_repository::errors::ExternalIdentityRepositoryError;
+use nimbus_auth_shared::errors::ErrorBoxed;
+
+use crate::services_implementations::postgres_external_identity_repository::schema::{
+    GetExternalIdentityDb, SaveExternalIdentityDb,
+};
+
+pub async fn get_external_identity_by_subject<'a, E>(
+    executor: &'a mut E,
+    realm: &str,
+    provider: &str,
+    subject: &str,
+) -> Result<Option<GetExternalIdentityDb>, ExternalIdentityRepositoryError>
+where
+    &'a mut E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+{
+    Ok(sqlx::query_as::<_, GetExternalIdentityDb>(
+        "SELECT id, user_id, provider, subject, email, provisioned, linked_at \
+        FROM external_identities WHERE realm = $1 AND provider = $2 AND subject = $3",
+    )
+    .bind(realm)
+    .bind(provider)
+    .bind(subject)
+    .fetch_optional(executor)
+    .await
+    .map_err(ErrorBoxed::from)?)
+}
+
+pub async fn get_external_identities_by_user_id<'a, E>(
+    executor: &'a mut E,
+    realm: &str,
+    user_id: &str,
+) -> Result<Vec<GetExternalIdentityDb>, ExternalIdentityRepositoryError>
+where
+    &'a mut E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+{
+    Ok(sqlx::query_as::<_, GetExternalIdentityDb>(
+        "SELECT id, user_id, provider, subject, email, provisioned, linked_at \
+        FROM external_identities WHERE realm = $1 AND user_id = $2 ORDER BY linked_at, id",
+    )
+    .bind(realm)
+    .bind(user_id)
+    .fetch_all(executor)
+    .await
+    .map_err(ErrorBoxed::from)?)
+}
+
+pub async fn save_external_identity<'a, E>(
+    executor: &'a mut E,
+    realm: &str,
+    identity: &SaveExternalIdentityDb,
+) -> Result<(), ExternalIdentityRepositoryError>
+where
+    &'a mut E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+{
+    sqlx::query(
+        "INSERT INTO external_identities \
+        (id, realm, user_id, provider, subject, email, provisioned, linked_at) \
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8)",
+    )
+    .bind(&identity.id)
+    .bind(realm)
+    .bind(&identity.user_id)
+    .bind(&identity.provider)
+    .bind(&identity.subject)
+    .bind(&identity.email)
+    .bind(identity.provisioned)
+    .bind(identity.linked_at)
+    .execute(executor)
+    .await
+    .map_err(ErrorBoxed::from)?;
+    Ok(())
+}
+
+pub async fn delete_external_identity<'a, E>(
+    executor: &'a mut E,
+    realm: &str,
+    id: &str,
+) -> Result<(), ExternalIdentityRepositoryError>
+where
+    &'a mut E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+{
+    sqlx::query("DELETE FROM external_identities WHERE realm = $1 AND id = $2")
+        .bind(realm)
+        .bind(id)
+        .execute(executor)
+        .await
+        .map_err(ErrorBoxed::from)?;
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use nimbus_auth_domain::{
+    entities::{
+        Entity,
+        external_identity::{
+            ExternalIdentity, specifications::RestoreExternalIdentitySpecification,
+        },
+    },
+    value_objects::identifier::Identifier,
+};
+use sqlx::prelude::FromRow;
+use time::OffsetDateTime;
+use ulid::Ulid;
+
+use crate::services_implementations::postgres_external_identity_repository::schema::errors::TryFromExternalIdentityDbError;
+
+pub mod errors;
+
+#[derive(FromRow)]
+pub struct GetExternalIdentityDb {
+    pub id: String,
+    pub user_id: String,
+    pub provider: String,
+    pub subject: String,
+    pub email: Option<String>,
+    pub provisioned: bool,
+    pub linked_at: OffsetDateTime,
+}
+
+pub struct SaveExternalIdentityDb {
+    pub id: String,
+    pub user_id: String,
+    pub provider: String,
+    pub subject: String,
+    pub email: Option<String>,
+    pub provisioned: bool,
+    pub linked_at: OffsetDateTime,
+}
+
+impl TryFrom<&GetExternalIdentityDb> for ExternalIdentity {
+    type Error = TryFromExternalIdentityDbError;
+
+    fn try_from(value: &GetExternalIdentityDb) -> Result<Self, Self::Error> {
+        Ok(ExternalIdentity::restore(
+            RestoreExternalIdentitySpecification {
+                id: Identifier::from(Ulid::from_string(&value.id)?),
+                user_id: Identifier::from(Ulid::from_string(&value.user_id)?),
+                provider: value.provider.clone(),
+                subject: value.subject.clone(),
+                email: value.email.clone(),
+                provisioned: value.provisioned,
+                linked_at: value.linked_at,
+            },
+        ))
+    }
+}
+
+impl From<&ExternalIdentity> for SaveExternalIdentityDb {
+    fn from(value: &ExternalIdentity) -> Self {
+        SaveExternalIdentityDb {
+            id: value.id().to_string(),
+            user_id: value.user_id().to_string(),
+            provider: value.provider().to_string(),
+            subject: value.subject().to_string(),
+            email: value.email().map(str::to_string),
+            provisioned: value.provisioned(),
+            linked_at: value.linked_at(),
+        }
+    }
+}
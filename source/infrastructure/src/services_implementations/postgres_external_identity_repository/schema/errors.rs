@@ -0,0 +1,8 @@
+use thiserror::Error;
+use ulid::DecodeError;
+
+#[derive(Error, Debug)]
+pub enum TryFromExternalIdentityDbError {
+    #[error("invalid identifier. Error: {0}")]
+    InvalidIdentifier(#[from] DecodeError),
+}
@@ -0,0 +1,82 @@
+use std::sync::Arc;
+
+use nimbus_auth_application::services::federated_authorization_repository::{
+    FederatedAuthorizationRepository, errors::FederatedAuthorizationRepositoryError,
+};
+use nimbus_auth_domain::{
+    entities::federated_authorization::FederatedAuthorization,
+    value_objects::identifier::Identifier,
+};
+use nimbus_auth_shared::{
+    errors::ErrorBoxed,
+    futures::{StaticPinnedFuture, pin_static_future},
+};
+use ulid::Ulid;
+
+use crate::{
+    postgres_db::PostgresDatabase,
+    services_implementations::postgres_federated_authorization_repository::{
+        queries::{save_federated_authorization, take_federated_authorization},
+        schema::{GetFederatedAuthorizationDb, SaveFederatedAuthorizationDb},
+    },
+};
+
+mod queries;
+mod schema;
+
+pub struct PostgresFederatedAuthorizationRepository {
+    database: Arc<PostgresDatabase>,
+    realm: String,
+}
+
+impl PostgresFederatedAuthorizationRepository {
+    pub fn new(database: Arc<PostgresDatabase>, realm: &str) -> Self {
+        Self {
+            database,
+            realm: realm.to_string(),
+        }
+    }
+}
+
+impl FederatedAuthorizationRepository for PostgresFederatedAuthorizationRepository {
+    fn save(
+        &self,
+        authorization: &FederatedAuthorization,
+    ) -> StaticPinnedFuture<(), FederatedAuthorizationRepositoryError> {
+        let db_clone = self.database.clone();
+        let realm = self.realm.clone();
+        let authorization = SaveFederatedAuthorizationDb::from(authorization);
+        pin_static_future(async move {
+            let mut connection = db_clone.pool().acquire().await.map_err(ErrorBoxed::from)?;
+            save_federated_authorization(&mut *connection, &realm, &authorization).await
+        })
+    }
+
+    fn take(
+        &self,
+        id: &Identifier<Ulid, FederatedAuthorization>,
+    ) -> StaticPinnedFuture<Option<FederatedAuthorization>, FederatedAuthorizationRepositoryError>
+    {
+        let db_clone = self.database.clone();
+        let realm = self.realm.clone();
+        let id = id.to_string();
+        pin_static_future(async move {
+            let mut connection = db_clone.pool().acquire().await.map_err(ErrorBoxed::from)?;
+            take_federated_authorization(&mut *connection, &realm, &id)
+                .await?
+                .as_ref()
+                .map(restore_federated_authorization)
+                .transpose()
+        })
+    }
+}
+
+fn restore_federated_authorization(
+    authorization_db: &GetFederatedAuthorizationDb,
+) -> Result<FederatedAuthorization, FederatedAuthorizationRepositoryError> {
+    FederatedAuthorization::try_from(authorization_db).map_err(|err| {
+        FederatedAuthorizationRepositoryError::FederatedAuthorizationRestoration(ErrorBoxed::from(
+            err,
+        ))
+    })
+}
@@ -0,0 +1,52 @@
+use nimbus_auth_application::services::federated_authorization_repository::errors::FederatedAuthorizationRepositoryError;
+use nimbus_auth_shared::errors::ErrorBoxed;
+
+use crate::services_implementations::postgres_federated_authorization_repository::schema::{
+    GetFederatedAuthorizationDb, SaveFederatedAuthorizationDb,
+};
+
+pub async fn save_federated_authorization<'a, E>(
+    executor: &'a mut E,
+    realm: &str,
+    authorization: &SaveFederatedAuthorizationDb,
+) -> Result<(), FederatedAuthorizationRepositoryError>
+where
+    &'a mut E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+{
+    sqlx::query(
+        "INSERT INTO federated_authorizations \
+        (id, realm, provider, user_id, nonce, code_verifier, expires_at) \
+        VALUES ($1, $2, $3, $4, $5, $6, $7)",
+    )
+    .bind(&authorization.id)
+    .bind(realm)
+    .bind(&authorization.provider)
+    .bind(&authorization.user_id)
+    .bind(&authorization.nonce)
+    .bind(&authorization.code_verifier)
+    .bind(authorization.expires_at)
+    .execute(executor)
+    .await
+    .map_err(ErrorBoxed::from)?;
+    Ok(())
+}
+
+/// Deleting with returning makes the state single use even under concurrent callbacks
+pub async fn take_federated_authorization<'a, E>(
+    executor: &'a mut E,
+    realm: &str,
+    id: &str,
+) -> Result<Option<GetFederatedAuthorizationDb>, FederatedAuthorizationRepositoryError>
+where
+    &'a mut E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+{
+    Ok(sqlx::query_as::<_, GetFederatedAuthorizationDb>(
+        "DELETE FROM federated_authorizations WHERE realm = $1 AND id = $2 \
+        RETURNING id, provider, user_id, nonce, code_verifier, expires_at",
+    )
+    .bind(realm)
+    .bind(id)
+    .fetch_optional(executor)
+    .await
+    .map_err(ErrorBoxed::from)?)
+}
@@ -0,0 +1,70 @@
+use nimbus_auth_domain::{
+    entities::{
+        Entity,
+        federated_authorization::{
+            FederatedAuthorization, specifications::RestoreFederatedAuthorizationSpecification,
+        },
+    },
+    value_objects::identifier::Identifier,
+};
+use sqlx::prelude::FromRow;
+use time::OffsetDateTime;
+use ulid::Ulid;
+
+use crate::services_implementations::postgres_federated_authorization_repository::schema::errors::TryFromFederatedAuthorizationDbError;
+
+pub mod errors;
+
+#[derive(FromRow)]
+pub struct GetFederatedAuthorizationDb {
+    pub id: String,
+    pub provider: String,
+    pub user_id: Option<String>,
+    pub nonce: String,
+    pub code_verifier: String,
+    pub expires_at: OffsetDateTime,
+}
+
+pub struct SaveFederatedAuthorizationDb {
+    pub id: String,
+    pub provider: String,
+    pub user_id: Option<String>,
+    pub nonce: String,
+    pub code_verifier: String,
+    pub expires_at: OffsetDateTime,
+}
+
+impl TryFrom<&GetFederatedAuthorizationDb> for FederatedAuthorization {
+    type Error = TryFromFederatedAuthorizationDbError;
+
+    fn try_from(value: &GetFederatedAuthorizationDb) -> Result<Self, Self::Error> {
+        Ok(FederatedAuthorization::restore(
+            RestoreFederatedAuthorizationSpecification {
+                id: Identifier::from(Ulid::from_string(&value.id)?),
+                provider: value.provider.clone(),
+                user_id: value
+                    .user_id
+                    .as_deref()
+                    .map(Ulid::from_string)
+                    .transpose()?
+                    .map(Identifier::from),
+                nonce: value.nonce.clone(),
+                code_verifier: value.code_verifier.clone(),
+                expires_at: value.expires_at,
+            },
+        ))
+    }
+}
+
+impl From<&FederatedAuthorization> for SaveFederatedAuthorizationDb {
+    fn from(value: &FederatedAuthorization) -> Self {
+        SaveFederatedAuthorizationDb {
+            id: value.id().to_string(),
+            provider: value.provider().to_string(),
+            user_id: value.user_id().map(|user_id| user_id.to_string()),
+            nonce: value.nonce().to_string(),
+            code_verifier: value.code_verifier().to_string(),
+            expires_at: value.expires_at(),
+        }
+    }
+}
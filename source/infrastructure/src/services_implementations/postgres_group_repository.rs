@@ -0,0 +1,115 @@
+use std::sync::Arc;
+
+use nimbus_auth_application::services::group_repository::{
+    GroupRepository, errors::GroupRepositoryError,
+};
+use nimbus_auth_domain::entities::{
+    Entity,
+    group::{Group, value_objects::group_name::GroupName},
+};
+use nimbus_auth_shared::{
+    errors::ErrorBoxed,
+    futures::{StaticPinnedFuture, pin_static_future},
+};
+
+use crate::{
+    postgres_db::PostgresDatabase,
+    services_implementations::postgres_group_repository::{
+        queries::{delete_group, get_groups_by_names, list_groups, save_group},
+        schema::{GetGroupDb, SaveGroupDb},
+    },
+};
+
+mod queries;
+mod schema;
+
+pub struct PostgresGroupRepository {
+    database: Arc<PostgresDatabase>,
+    realm: String,
+}
+
+impl PostgresGroupRepository {
+    pub fn new(database: Arc<PostgresDatabase>, realm: &str) -> Self {
+        Self {
+            database,
+            realm: realm.to_string(),
+        }
+    }
+}
+
+impl GroupRepository for PostgresGroupRepository {
+    fn get_by_name(
+        &self,
+        name: &GroupName,
+    ) -> StaticPinnedFuture<Option<Group>, GroupRepositoryError> {
+        let db_clone = self.database.clone();
+        let realm = self.realm.clone();
+        let names = vec![name.to_string()];
+        pin_static_future(async move {
+            let mut connection = db_clone.pool().acquire().await.map_err(ErrorBoxed::from)?;
+            get_groups_by_names(&mut *connection, &realm, &names)
+                .await?
+                .first()
+                .map(restore_group)
+                .transpose()
+        })
+    }
+
+    fn get_by_names(
+        &self,
+        names: &[GroupName],
+    ) -> StaticPinnedFuture<Vec<Group>, GroupRepositoryError> {
+        let db_clone = self.database.clone();
+        let realm = self.realm.clone();
+        let names = names
+            .iter()
+            .map(|name| name.to_string())
+            .collect::<Vec<_>>();
+        pin_static_future(async move {
+            let mut connection = db_clone.pool().acquire().await.map_err(ErrorBoxed::from)?;
+            get_groups_by_names(&mut *connection, &realm, &names)
+                .await?
+                .iter()
+                .map(restore_group)
+                .collect()
+        })
+    }
+
+    fn list(&self) -> StaticPinnedFuture<Vec<Group>, GroupRepositoryError> {
+        let db_clone = self.database.clone();
+        let realm = self.realm.clone();
+        pin_static_future(async move {
+            let mut connection = db_clone.pool().acquire().await.map_err(ErrorBoxed::from)?;
+            list_groups(&mut *connection, &realm)
+                .await?
+                .iter()
+                .map(restore_group)
+                .collect()
+        })
+    }
+
+    fn save(&self, group: &Group) -> StaticPinnedFuture<(), GroupRepositoryError> {
+        let db_clone = self.database.clone();
+        let realm = self.realm.clone();
+        let group = SaveGroupDb::from(group);
+        pin_static_future(async move {
+            let mut connection = db_clone.pool().acquire().await.map_err(ErrorBoxed::from)?;
+            save_group(&mut *connection, &realm, &group).await
+        })
+    }
+
+    fn delete(&self, group: &Group) -> StaticPinnedFuture<(), GroupRepositoryError> {
+        let db_clone = self.database.clone();
+        let realm = self.realm.clone();
+        let id = group.id().to_string();
+        pin_static_future(async move {
+            let mut connection = db_clone.pool().acquire().await.map_err(ErrorBoxed::from)?;
+            delete_group(&mut *connection, &realm, &id).await
+        })
+    }
+}
+
+fn restore_group(group_db: &GetGroupDb) -> Result<Group, GroupRepositoryError> {
+    Group::try_from(group_db)
+        .map_err(|err| GroupRepositoryError::GroupRestoration(ErrorBoxed::from(err)))
+}
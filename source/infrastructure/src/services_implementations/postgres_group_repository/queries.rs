@@ -0,0 +1,78 @@
+use nimbus_auth_application::services::group_repository::errors::GroupRepositoryError;
+use nimbus_auth_shared::errors::ErrorBoxed;
+
+use crate::services_implementations::postgres_group_repository::schema::{GetGroupDb, SaveGroupDb};
+
+pub async fn get_groups_by_names<'a, E>(
+    executor: &'a mut E,
+    realm: &str,
+    names: &[String],
+) -> Result<Vec<GetGroupDb>, GroupRepositoryError>
+where
+    &'a mut E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+{
+    Ok(sqlx::query_as::<_, GetGroupDb>(
+        "SELECT id, name FROM groups WHERE realm = $1 AND name = ANY($2) ORDER BY name",
+    )
+    .bind(realm)
+    .bind(names)
+    .fetch_all(executor)
+    .await
+    .map_err(ErrorBoxed::from)?)
+}
+
+pub async fn list_groups<'a, E>(
+    executor: &'a mut E,
+    realm: &str,
+) -> Result<Vec<GetGroupDb>, GroupRepositoryError>
+where
+    &'a mut E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+{
+    Ok(sqlx::query_as::<_, GetGroupDb>(
+        "SELECT id, name FROM groups WHERE realm = $1 ORDER BY name",
+    )
+    .bind(realm)
+    .fetch_all(executor)
+    .await
+    .map_err(ErrorBoxed::from)?)
+}
+
+pub async fn save_group<'a, E>(
+    executor: &'a mut E,
+    realm: &str,
+    group: &SaveGroupDb,
+) -> Result<(), GroupRepositoryError>
+where
+    &'a mut E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+{
+    sqlx::query(
+        "INSERT INTO groups (id, realm, name) VALUES ($1, $2, $3) \
+        ON CONFLICT (id) DO UPDATE SET name = EXCLUDED.name \
+        WHERE groups.realm = EXCLUDED.realm",
+    )
+    .bind(&group.id)
+    .bind(realm)
+    .bind(&group.name)
+    .execute(executor)
+    .await
+    .map_err(ErrorBoxed::from)?;
+    Ok(())
+}
+
+/// Memberships of the group are deleted with it
+pub async fn delete_group<'a, E>(
+    executor: &'a mut E,
+    realm: &str,
+    id: &str,
+) -> Result<(), GroupRepositoryError>
+where
+    &'a mut E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+{
+    sqlx::query("DELETE FROM groups WHERE realm = $1 AND id = $2")
+        .bind(realm)
+        .bind(id)
+        .execute(executor)
+        .await
+        .map_err(ErrorBoxed::from)?;
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use nimbus_auth_domain::{
+    entities::{
+        Entity,
+        group::{
+            Group, specifications::RestoreGroupSpecification, value_objects::group_name::GroupName,
+        },
+    },
+    value_objects::identifier::Identifier,
+};
+use sqlx::prelude::FromRow;
+use ulid::Ulid;
+
+use crate::services_implementations::postgres_group_repository::schema::errors::TryFromGroupDbError;
+
+pub mod errors;
+
+#[derive(FromRow)]
+pub struct GetGroupDb {
+    pub id: String,
+    pub name: String,
+}
+
+pub struct SaveGroupDb {
+    pub id: String,
+    pub name: String,
+}
+
+impl TryFrom<&GetGroupDb> for Group {
+    type Error = TryFromGroupDbError;
+
+    fn try_from(value: &GetGroupDb) -> Result<Self, Self::Error> {
+        Ok(Group::restore(RestoreGroupSpecification {
+            id: Identifier::from(Ulid::from_string(&value.id)?),
+            name: GroupName::from(&value.name)?,
+        }))
+    }
+}
+
+impl From<&Group> for SaveGroupDb {
+    fn from(value: &Group) -> Self {
+        SaveGroupDb {
+            id: value.id().to_string(),
+            name: value.name().to_string(),
+        }
+    }
+}
@@ -0,0 +1,11 @@
+use nimbus_auth_domain::entities::group::value_objects::group_name::errors::GroupNameError;
+use thiserror::Error;
+use ulid::DecodeError;
+
+#[derive(Error, Debug)]
+pub enum TryFromGroupDbError {
+    #[error("invalid identifier. Error: {0}")]
+    InvalidIdentifier(#[from] DecodeError),
+    #[error(transparent)]
+    GroupName(#[from] GroupNameError),
+}
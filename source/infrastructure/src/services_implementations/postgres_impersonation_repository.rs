@@ -0,0 +1,49 @@
+use std::sync::Arc;
+
+use nimbus_auth_application::services::impersonation_repository::{
+    ImpersonationRepository, errors::ImpersonationRepositoryError,
+};
+use nimbus_auth_domain::entities::impersonation::Impersonation;
+use nimbus_auth_shared::{
+    errors::ErrorBoxed,
+    futures::{StaticPinnedFuture, pin_static_future},
+};
+
+use crate::{
+    postgres_db::PostgresDatabase,
+    services_implementations::postgres_impersonation_repository::{
+        queries::save_impersonation, schema::SaveImpersonationDb,
+    },
+};
+
+mod queries;
+mod schema;
+
+pub struct PostgresImpersonationRepository {
+    database: Arc<PostgresDatabase>,
+    realm: String,
+}
+
+impl PostgresImpersonationRepository {
+    pub fn new(database: Arc<PostgresDatabase>, realm: &str) -> Self {
+        Self {
+            database,
+            realm: realm.to_string(),
+        }
+    }
+}
+
+impl ImpersonationRepository for PostgresImpersonationRepository {
+    fn save(
+        &self,
+        impersonation: &Impersonation,
+    ) -> StaticPinnedFuture<(), ImpersonationRepositoryError> {
+        let db_clone = self.database.clone();
+        let realm = self.realm.clone();
+        let impersonation = SaveImpersonationDb::from(impersonation);
+        pin_static_future(async move {
+            let mut connection = db_clone.pool().acquire().await.map_err(ErrorBoxed::from)?;
+            save_impersonation(&mut *connection, &realm, &impersonation).await
+        })
+    }
+}
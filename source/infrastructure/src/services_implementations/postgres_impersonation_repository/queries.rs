@@ -0,0 +1,30 @@
+use nimbus_auth_application::services::impersonation_repository::errors::ImpersonationRepositoryError;
+use nimbus_auth_shared::errors::ErrorBoxed;
+
+use crate::services_implementations::postgres_impersonation_repository::schema::SaveImpersonationDb;
+
+pub async fn save_impersonation<'a, E>(
+    executor: &'a mut E,
+    realm: &str,
+    impersonation: &SaveImpersonationDb,
+) -> Result<(), ImpersonationRepositoryError>
+where
+    &'a mut E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+{
+    sqlx::query(
+        "INSERT INTO impersonations \
+        (id, realm, admin_id, user_id, reason, started_at, expires_at) \
+        VALUES ($1, $2, $3, $4, $5, $6, $7)",
+    )
+    .bind(&impersonation.id)
+    .bind(realm)
+    .bind(&impersonation.admin_id)
+    .bind(&impersonation.user_id)
+    .bind(&impersonation.reason)
+    .bind(impersonation.started_at)
+    .bind(impersonation.expires_at)
+    .execute(executor)
+    .await
+    .map_err(ErrorBoxed::from)?;
+    Ok(())
+}
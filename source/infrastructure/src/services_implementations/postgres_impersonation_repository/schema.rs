@@ -0,0 +1,24 @@
+use nimbus_auth_domain::entities::{Entity, impersonation::Impersonation};
+use time::OffsetDateTime;
+
+pub struct SaveImpersonationDb {
+    pub id: String,
+    pub admin_id: String,
+    pub user_id: String,
+    pub reason: String,
+    pub started_at: OffsetDateTime,
+    pub expires_at: OffsetDateTime,
+}
+
+impl From<&Impersonation> for SaveImpersonationDb {
+    fn from(value: &Impersonation) -> Self {
+        SaveImpersonationDb {
+            id: value.id().to_string(),
+            admin_id: value.admin_id().to_string(),
+            user_id: value.user_id().to_string(),
+            reason: value.reason().to_string(),
+            started_at: value.started_at(),
+            expires_at: value.expires_at(),
+        }
+    }
+}
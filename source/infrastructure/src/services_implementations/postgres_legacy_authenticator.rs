@@ -0,0 +1,72 @@
+use std::sync::Arc;
+
+use nimbus_auth_application::services::legacy_authenticator::{
+    LegacyAuthenticator, errors::LegacyAuthenticatorError,
+};
+use nimbus_auth_domain::{
+    entities::user::value_objects::{
+        password::Password, password_hash::PasswordHash, user_name::UserName,
+    },
+    value_objects::password_peppers::PasswordPeppers,
+};
+use nimbus_auth_shared::{
+    errors::ErrorBoxed,
+    futures::{StaticPinnedFuture, pin_static_future},
+};
+
+use crate::{
+    postgres_db::PostgresDatabase,
+    services_implementations::postgres_legacy_authenticator::queries::get_legacy_user_by_name,
+};
+
+mod queries;
+mod schema;
+
+/// Reads users of legacy system from a table in another schema of the same database
+///
+/// The table should have `user_name` and `password_hash` columns, hashes can be in any format supported by [`PasswordHash`]
+pub struct PostgresLegacyAuthenticator {
+    database: Arc<PostgresDatabase>,
+    schema: String,
+    table: String,
+}
+
+impl PostgresLegacyAuthenticator {
+    pub fn new(database: Arc<PostgresDatabase>, schema: &str, table: &str) -> Self {
+        Self {
+            database,
+            schema: schema.to_string(),
+            table: table.to_string(),
+        }
+    }
+}
+
+impl LegacyAuthenticator for PostgresLegacyAuthenticator {
+    fn authenticate(
+        &self,
+        user_name: &UserName,
+        password: &Password,
+    ) -> StaticPinnedFuture<bool, LegacyAuthenticatorError> {
+        let db_clone = self.database.clone();
+        let schema = self.schema.clone();
+        let table = self.table.clone();
+        let user_name = user_name.to_string();
+        let password = password.clone();
+        pin_static_future(async move {
+            let mut connection = db_clone.pool().acquire().await.map_err(ErrorBoxed::from)?;
+            let Some(legacy_user) =
+                get_legacy_user_by_name(&mut *connection, &schema, &table, &user_name).await?
+            else {
+                return Ok(false);
+            };
+
+            let password_hash =
+                PasswordHash::from(&legacy_user.password_hash).map_err(ErrorBoxed::from)?;
+
+            // legacy system hashes are never peppered
+            Ok(password_hash
+                .verify(&password, &PasswordPeppers::empty())
+                .map_err(ErrorBoxed::from)?)
+        })
+    }
+}
@@ -0,0 +1,30 @@
+use nimbus_auth_application::services::legacy_authenticator::errors::LegacyAuthenticatorError;
+use nimbus_auth_shared::errors::ErrorBoxed;
+
+use crate::services_implementations::postgres_legacy_authenticator::schema::GetLegacyUserDb;
+
+pub async fn get_legacy_user_by_name<'a, E>(
+    executor: &'a mut E,
+    schema: &str,
+    table: &str,
+    user_name: &str,
+) -> Result<Option<GetLegacyUserDb>, LegacyAuthenticatorError>
+where
+    &'a mut E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+{
+    let query = format!(
+        "SELECT password_hash FROM {}.{} WHERE user_name = $1",
+        quote_identifier(schema),
+        quote_identifier(table)
+    );
+    Ok(sqlx::query_as::<_, GetLegacyUserDb>(&query)
+        .bind(user_name)
+        .fetch_optional(executor)
+        .await
+        .map_err(ErrorBoxed::from)?)
+}
+
+/// Identifiers can not be bound as query parameters, so they are quoted with embedded quotes escaped
+fn quote_identifier(identifier: &str) -> String {
+    format!("\"{}\"", identifier.replace('"', "\"\""))
+}
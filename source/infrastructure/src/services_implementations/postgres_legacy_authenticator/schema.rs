@@ -0,0 +1,6 @@
+use sqlx::prelude::FromRow;
+
+#[derive(FromRow)]
+pub struct GetLegacyUserDb {
+    pub password_hash: String,
+}
@@ -0,0 +1,75 @@
+use std::sync::Arc;
+
+use nimbus_auth_application::services::oauth_client_repository::{
+    OAuthClientRepository, errors::OAuthClientRepositoryError,
+};
+use nimbus_auth_domain::{
+    entities::oauth_client::OAuthClient, value_objects::identifier::Identifier,
+};
+use nimbus_auth_shared::{
+    errors::ErrorBoxed,
+    futures::{StaticPinnedFuture, pin_static_future},
+};
+use ulid::Ulid;
+
+use crate::{
+    postgres_db::PostgresDatabase,
+    services_implementations::postgres_oauth_client_repository::{
+        queries::{get_oauth_client_by_id, save_oauth_client},
+        schema::{GetOAuthClientDb, SaveOAuthClientDb},
+    },
+};
+
+mod queries;
+mod schema;
+
+/// Clients are registered within the realm the repository is scoped to
+pub struct PostgresOAuthClientRepository {
+    database: Arc<PostgresDatabase>,
+    realm: String,
+}
+
+impl PostgresOAuthClientRepository {
+    pub fn new(database: Arc<PostgresDatabase>, realm: &str) -> Self {
+        Self {
+            database,
+            realm: realm.to_string(),
+        }
+    }
+}
+
+impl OAuthClientRepository for PostgresOAuthClientRepository {
+    fn get_by_id(
+        &self,
+        id: &Identifier<Ulid, OAuthClient>,
+    ) -> StaticPinnedFuture<Option<OAuthClient>, OAuthClientRepositoryError> {
+        let db_clone = self.database.clone();
+        let realm = self.realm.clone();
+        let id = id.to_string();
+        pin_static_future(async move {
+            let mut connection = db_clone.pool().acquire().await.map_err(ErrorBoxed::from)?;
+            get_oauth_client_by_id(&mut *connection, &realm, &id)
+                .await?
+                .as_ref()
+                .map(restore_oauth_client)
+                .transpose()
+        })
+    }
+
+    fn save(&self, client: &OAuthClient) -> StaticPinnedFuture<(), OAuthClientRepositoryError> {
+        let db_clone = self.database.clone();
+        let realm = self.realm.clone();
+        let client = SaveOAuthClientDb::from(client);
+        pin_static_future(async move {
+            let mut connection = db_clone.pool().acquire().await.map_err(ErrorBoxed::from)?;
+            save_oauth_client(&mut *connection, &realm, &client).await
+        })
+    }
+}
+
+fn restore_oauth_client(
+    client_db: &GetOAuthClientDb,
+) -> Result<OAuthClient, OAuthClientRepositoryError> {
+    OAuthClient::try_from(client_db)
+        .map_err(|err| OAuthClientRepositoryError::OAuthClientRestoration(ErrorBoxed::from(err)))
+}
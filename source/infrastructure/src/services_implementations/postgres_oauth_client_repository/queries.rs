@@ -0,0 +1,50 @@
+use nimbus_auth_application::services::oauth_client_repository::errors::OAuthClientRepositoryError;
+use nimbus_auth_shared::errors::ErrorBoxed;
+
+use crate::services_implementations::postgres_oauth_client_repository::schema::{
+    GetOAuthClientDb, SaveOAuthClientDb,
+};
+
+pub async fn get_oauth_client_by_id<'a, E>(
+    executor: &'a mut E,
+    realm: &str,
+    id: &str,
+) -> Result<Option<GetOAuthClientDb>, OAuthClientRepositoryError>
+where
+    &'a mut E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+{
+    Ok(sqlx::query_as::<_, GetOAuthClientDb>(
+        "SELECT id, name, redirect_uris, secret_hash, scopes FROM oauth_clients WHERE realm = $1 AND id = $2",
+    )
+    .bind(realm)
+    .bind(id)
+    .fetch_optional(executor)
+    .await
+    .map_err(ErrorBoxed::from)?)
+}
+
+pub async fn save_oauth_client<'a, E>(
+    executor: &'a mut E,
+    realm: &str,
+    client: &SaveOAuthClientDb,
+) -> Result<(), OAuthClientRepositoryError>
+where
+    &'a mut E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+{
+    sqlx::query(
+        "INSERT INTO oauth_clients (id, realm, name, redirect_uris, secret_hash, scopes) \
+        VALUES ($1, $2, $3, $4, $5, $6) \
+        ON CONFLICT (id) DO UPDATE SET name = EXCLUDED.name, redirect_uris = EXCLUDED.redirect_uris, \
+        secret_hash = EXCLUDED.secret_hash, scopes = EXCLUDED.scopes",
+    )
+    .bind(&client.id)
+    .bind(realm)
+    .bind(&client.name)
+    .bind(&client.redirect_uris)
+    .bind(&client.secret_hash)
+    .bind(&client.scopes)
+    .execute(executor)
+    .await
+    .map_err(ErrorBoxed::from)?;
+    Ok(())
+}
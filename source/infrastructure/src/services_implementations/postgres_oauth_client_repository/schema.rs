@@ -0,0 +1,83 @@
+use nimbus_auth_domain::{
+    entities::{
+        Entity,
+        oauth_client::{
+            OAuthClient,
+            specifications::RestoreOAuthClientSpecification,
+            value_objects::{oauth_client_name::OAuthClientName, redirect_uri::RedirectUri},
+        },
+        role::value_objects::permission::Permission,
+    },
+    value_objects::{identifier::Identifier, secret_hash::SecretHash},
+};
+use sqlx::prelude::FromRow;
+use ulid::Ulid;
+
+use crate::services_implementations::postgres_oauth_client_repository::schema::errors::TryFromOAuthClientDbError;
+
+pub mod errors;
+
+#[derive(FromRow)]
+pub struct GetOAuthClientDb {
+    pub id: String,
+    pub name: String,
+    pub redirect_uris: Vec<String>,
+    pub secret_hash: Option<String>,
+    pub scopes: Vec<String>,
+}
+
+pub struct SaveOAuthClientDb {
+    pub id: String,
+    pub name: String,
+    pub redirect_uris: Vec<String>,
+    pub secret_hash: Option<String>,
+    pub scopes: Vec<String>,
+}
+
+impl TryFrom<&GetOAuthClientDb> for OAuthClient {
+    type Error = TryFromOAuthClientDbError;
+
+    fn try_from(value: &GetOAuthClientDb) -> Result<Self, Self::Error> {
+        Ok(OAuthClient::restore(RestoreOAuthClientSpecification {
+            id: Identifier::from(Ulid::from_string(&value.id)?),
+            name: OAuthClientName::from(&value.name)?,
+            redirect_uris: value
+                .redirect_uris
+                .iter()
+                .map(|redirect_uri| RedirectUri::from(redirect_uri))
+                .collect::<Result<_, _>>()?,
+            secret_hash: value
+                .secret_hash
+                .as_deref()
+                .map(SecretHash::from)
+                .transpose()?,
+            scopes: value
+                .scopes
+                .iter()
+                .map(|scope| Permission::from(scope))
+                .collect::<Result<_, _>>()?,
+        }))
+    }
+}
+
+impl From<&OAuthClient> for SaveOAuthClientDb {
+    fn from(value: &OAuthClient) -> Self {
+        SaveOAuthClientDb {
+            id: value.id().to_string(),
+            name: value.name().to_string(),
+            redirect_uris: value
+                .redirect_uris()
+                .iter()
+                .map(|redirect_uri| redirect_uri.to_string())
+                .collect(),
+            secret_hash: value
+                .secret_hash()
+                .map(|secret_hash| secret_hash.value().to_string()),
+            scopes: value
+                .scopes()
+                .iter()
+                .map(|scope| scope.to_string())
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use nimbus_auth_domain::{
+    entities::{
+        oauth_client::value_objects::{
+            oauth_client_name::errors::OAuthClientNameError, redirect_uri::errors::RedirectUriError,
+        },
+        role::value_objects::permission::errors::PermissionError,
+    },
+    value_objects::secret_hash::errors::SecretHashError,
+};
+use thiserror::Error;
+use ulid::DecodeError;
+
+#[derive(Error, Debug)]
+pub enum TryFromOAuthClientDbError {
+    #[error("invalid identifier. Error: {0}")]
+    InvalidIdentifier(#[from] DecodeError),
+    #[error(transparent)]
+    OAuthClientName(#[from] OAuthClientNameError),
+    #[error(transparent)]
+    RedirectUri(#[from] RedirectUriError),
+    #[error(transparent)]
+    SecretHash(#[from] SecretHashError),
+    #[error(transparent)]
+    Permission(#[from] PermissionError),
+}
@@ -0,0 +1,143 @@
+use std::sync::Arc;
+
+use nimbus_auth_application::services::role_repository::{
+    RoleRepository, errors::RoleRepositoryError,
+};
+use nimbus_auth_domain::entities::{
+    Entity,
+    role::{Role, value_objects::role_name::RoleName},
+};
+use nimbus_auth_shared::{
+    constants::{ADMIN_ROLE_NAME, AUTH_PERMISSIONS, DEFAULT_ROLE_NAME},
+    errors::ErrorBoxed,
+    futures::{StaticPinnedFuture, pin_static_future},
+};
+use ulid::Ulid;
+
+use crate::{
+    postgres_db::PostgresDatabase,
+    services_implementations::postgres_role_repository::{
+        queries::{create_role_if_absent, delete_role, get_roles_by_names, list_roles, save_role},
+        schema::{GetRoleDb, SaveRoleDb},
+    },
+};
+
+mod queries;
+mod schema;
+
+pub struct PostgresRoleRepository {
+    database: Arc<PostgresDatabase>,
+    realm: String,
+}
+
+impl PostgresRoleRepository {
+    /// Creates built-in roles of the realm, migrations create them only for the default realm
+    pub async fn init(
+        database: Arc<PostgresDatabase>,
+        realm: &str,
+    ) -> Result<Self, RoleRepositoryError> {
+        let built_in_roles = [
+            SaveRoleDb {
+                id: Ulid::new().to_string(),
+                name: DEFAULT_ROLE_NAME.to_string(),
+                permissions: Vec::new(),
+            },
+            SaveRoleDb {
+                id: Ulid::new().to_string(),
+                name: ADMIN_ROLE_NAME.to_string(),
+                permissions: AUTH_PERMISSIONS
+                    .iter()
+                    .map(|permission| permission.to_string())
+                    .collect(),
+            },
+        ];
+        let mut connection = database.pool().acquire().await.map_err(ErrorBoxed::from)?;
+        for role in &built_in_roles {
+            create_role_if_absent(&mut *connection, realm, role).await?;
+        }
+        Ok(Self {
+            database,
+            realm: realm.to_string(),
+        })
+    }
+}
+
+impl RoleRepository for PostgresRoleRepository {
+    fn get_by_name(
+        &self,
+        name: &RoleName,
+    ) -> StaticPinnedFuture<Option<Role>, RoleRepositoryError> {
+        let db_clone = self.database.clone();
+        let realm = self.realm.clone();
+        let names = vec![name.to_string()];
+        pin_static_future(async move {
+            let mut connection = db_clone.pool().acquire().await.map_err(ErrorBoxed::from)?;
+            get_roles_by_names(&mut *connection, &realm, &names)
+                .await?
+                .first()
+                .map(restore_role)
+                .transpose()
+        })
+    }
+
+    fn get_by_names(
+        &self,
+        names: &[RoleName],
+    ) -> StaticPinnedFuture<Vec<Role>, RoleRepositoryError> {
+        let db_clone = self.database.clone();
+        let realm = self.realm.clone();
+        let names = names
+            .iter()
+            .map(|name| name.to_string())
+            .collect::<Vec<_>>();
+        pin_static_future(async move {
+            let mut connection = db_clone.pool().acquire().await.map_err(ErrorBoxed::from)?;
+            get_roles_by_names(&mut *connection, &realm, &names)
+                .await?
+                .iter()
+                .map(restore_role)
+                .collect()
+        })
+    }
+
+    fn list(&self) -> StaticPinnedFuture<Vec<Role>, RoleRepositoryError> {
+        let db_clone = self.database.clone();
+        let realm = self.realm.clone();
+        pin_static_future(async move {
+            let mut connection = db_clone.pool().acquire().await.map_err(ErrorBoxed::from)?;
+            list_roles(&mut *connection, &realm)
+                .await?
+                .iter()
+                .map(restore_role)
+                .collect()
+        })
+    }
+
+    fn save(&self, role: &Role) -> StaticPinnedFuture<(), RoleRepositoryError> {
+        let db_clone = self.database.clone();
+        let realm = self.realm.clone();
+        let role = SaveRoleDb::from(role);
+        pin_static_future(async move {
+            // role and its permissions are replaced together
+            let mut transaction = db_clone.pool().begin().await.map_err(ErrorBoxed::from)?;
+            save_role(&mut transaction, &realm, &role).await?;
+            transaction.commit().await.map_err(ErrorBoxed::from)?;
+            Ok(())
+        })
+    }
+
+    fn delete(&self, role: &Role) -> StaticPinnedFuture<(), RoleRepositoryError> {
+        let db_clone = self.database.clone();
+        let realm = self.realm.clone();
+        let id = role.id().to_string();
+        pin_static_future(async move {
+            let mut connection = db_clone.pool().acquire().await.map_err(ErrorBoxed::from)?;
+            delete_role(&mut *connection, &realm, &id).await
+        })
+    }
+}
+
+fn restore_role(role_db: &GetRoleDb) -> Result<Role, RoleRepositoryError> {
+    Role::try_from(role_db)
+        .map_err(|err| RoleRepositoryError::RoleRestoration(ErrorBoxed::from(err)))
+}
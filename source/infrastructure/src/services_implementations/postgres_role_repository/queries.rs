@@ -0,0 +1,125 @@
+use nimbus_auth_application::services::role_repository::errors::RoleRepositoryError;
+use nimbus_auth_shared::errors::ErrorBoxed;
+
+use crate::services_implementations::postgres_role_repository::schema::{GetRoleDb, SaveRoleDb};
+
+const SELECT_ROLES: &str = "SELECT roles.id, roles.name, ARRAY(\
+    SELECT permission FROM role_permissions \
+    WHERE role_permissions.role_id = roles.id ORDER BY permission) AS permissions \
+    FROM roles";
+
+pub async fn get_roles_by_names<'a, E>(
+    executor: &'a mut E,
+    realm: &str,
+    names: &[String],
+) -> Result<Vec<GetRoleDb>, RoleRepositoryError>
+where
+    &'a mut E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+{
+    Ok(sqlx::query_as::<_, GetRoleDb>(&format!(
+        "{SELECT_ROLES} WHERE roles.realm = $1 AND roles.name = ANY($2) ORDER BY roles.name"
+    ))
+    .bind(realm)
+    .bind(names)
+    .fetch_all(executor)
+    .await
+    .map_err(ErrorBoxed::from)?)
+}
+
+pub async fn list_roles<'a, E>(
+    executor: &'a mut E,
+    realm: &str,
+) -> Result<Vec<GetRoleDb>, RoleRepositoryError>
+where
+    &'a mut E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+{
+    Ok(sqlx::query_as::<_, GetRoleDb>(&format!(
+        "{SELECT_ROLES} WHERE roles.realm = $1 ORDER BY roles.name"
+    ))
+    .bind(realm)
+    .fetch_all(executor)
+    .await
+    .map_err(ErrorBoxed::from)?)
+}
+
+/// Creates the role with its permissions unless the realm already has a role with the same name
+pub async fn create_role_if_absent<'a, E>(
+    executor: &'a mut E,
+    realm: &str,
+    role: &SaveRoleDb,
+) -> Result<(), RoleRepositoryError>
+where
+    &'a mut E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+{
+    sqlx::query(
+        "WITH created AS (\
+        INSERT INTO roles (id, realm, name) VALUES ($1, $2, $3) \
+        ON CONFLICT (realm, name) DO NOTHING RETURNING id) \
+        INSERT INTO role_permissions (role_id, permission) \
+        SELECT created.id, permission FROM created, UNNEST($4::TEXT[]) AS permission",
+    )
+    .bind(&role.id)
+    .bind(realm)
+    .bind(&role.name)
+    .bind(&role.permissions)
+    .execute(executor)
+    .await
+    .map_err(ErrorBoxed::from)?;
+    Ok(())
+}
+
+/// Should run in a transaction, permissions of the role are deleted and inserted again
+pub async fn save_role(
+    connection: &mut sqlx::PgConnection,
+    realm: &str,
+    role: &SaveRoleDb,
+) -> Result<(), RoleRepositoryError> {
+    sqlx::query(
+        "INSERT INTO roles (id, realm, name) VALUES ($1, $2, $3) ON CONFLICT (id) DO NOTHING",
+    )
+    .bind(&role.id)
+    .bind(realm)
+    .bind(&role.name)
+    .execute(&mut *connection)
+    .await
+    .map_err(ErrorBoxed::from)?;
+    sqlx::query(
+        "DELETE FROM role_permissions USING roles \
+        WHERE role_permissions.role_id = roles.id AND roles.realm = $1 AND roles.id = $2",
+    )
+    .bind(realm)
+    .bind(&role.id)
+    .execute(&mut *connection)
+    .await
+    .map_err(ErrorBoxed::from)?;
+    sqlx::query(
+        "INSERT INTO role_permissions (role_id, permission) \
+        SELECT roles.id, permission FROM roles, UNNEST($3::TEXT[]) AS permission \
+        WHERE roles.realm = $1 AND roles.id = $2",
+    )
+    .bind(realm)
+    .bind(&role.id)
+    .bind(&role.permissions)
+    .execute(&mut *connection)
+    .await
+    .map_err(ErrorBoxed::from)?;
+    Ok(())
+}
+
+/// Role permissions and assignments to users are deleted with it
+pub async fn delete_role<'a, E>(
+    executor: &'a mut E,
+    realm: &str,
+    id: &str,
+) -> Result<(), RoleRepositoryError>
+where
+    &'a mut E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+{
+    sqlx::query("DELETE FROM roles WHERE realm = $1 AND id = $2")
+        .bind(realm)
+        .bind(id)
+        .execute(executor)
+        .await
+        .map_err(ErrorBoxed::from)?;
+    Ok(())
+}
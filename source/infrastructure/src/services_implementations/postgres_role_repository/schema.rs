@@ -0,0 +1,60 @@
+use nimbus_auth_domain::{
+    entities::{
+        Entity,
+        role::{
+            Role,
+            specifications::RestoreRoleSpecification,
+            value_objects::{permission::Permission, role_name::RoleName},
+        },
+    },
+    value_objects::identifier::Identifier,
+};
+use sqlx::prelude::FromRow;
+use ulid::Ulid;
+
+use crate::services_implementations::postgres_role_repository::schema::errors::TryFromRoleDbError;
+
+pub mod errors;
+
+#[derive(FromRow)]
+pub struct GetRoleDb {
+    pub id: String,
+    pub name: String,
+    pub permissions: Vec<String>,
+}
+
+pub struct SaveRoleDb {
+    pub id: String,
+    pub name: String,
+    pub permissions: Vec<String>,
+}
+
+impl TryFrom<&GetRoleDb> for Role {
+    type Error = TryFromRoleDbError;
+
+    fn try_from(value: &GetRoleDb) -> Result<Self, Self::Error> {
+        Ok(Role::restore(RestoreRoleSpecification {
+            id: Identifier::from(Ulid::from_string(&value.id)?),
+            name: RoleName::from(&value.name)?,
+            permissions: value
+                .permissions
+                .iter()
+                .map(|permission| Permission::from(permission))
+                .collect::<Result<_, _>>()?,
+        }))
+    }
+}
+
+impl From<&Role> for SaveRoleDb {
+    fn from(value: &Role) -> Self {
+        SaveRoleDb {
+            id: value.id().to_string(),
+            name: value.name().to_string(),
+            permissions: value
+                .permissions()
+                .iter()
+                .map(|permission| permission.to_string())
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use nimbus_auth_domain::entities::role::value_objects::{
+    permission::errors::PermissionError, role_name::errors::RoleNameError,
+};
+use thiserror::Error;
+use ulid::DecodeError;
+
+#[derive(Error, Debug)]
+pub enum TryFromRoleDbError {
+    #[error("invalid identifier. Error: {0}")]
+    InvalidIdentifier(#[from] DecodeError),
+    #[error(transparent)]
+    RoleName(#[from] RoleNameError),
+    #[error(transparent)]
+    Permission(#[from] PermissionError),
+}
@@ -1,32 +1,57 @@
 use std::sync::Arc;
 
 use nimbus_auth_application::services::session_repository::{
-    SessionRepository, SessionRepositoryWithTransaction, errors::SessionRepositoryError,
+    SessionRepository, SessionRepositoryWithTransaction, SessionTransactionFuture,
+    errors::SessionRepositoryError,
+};
+use nimbus_auth_domain::{
+    entities::{
+        session::{Active, Session, SomeSession},
+        user::User,
+    },
+    value_objects::identifier::Identifier,
+};
+use nimbus_auth_shared::{
+    errors::ErrorBoxed,
+    futures::{StaticPinnedFuture, pin_future, pin_static_future},
 };
-use nimbus_auth_domain::{entities::session::SomeSession, value_objects::identifier::Identifier};
-use nimbus_auth_shared::futures::{StaticPinnedFuture, pin_future, pin_static_future};
 use sqlx::PgConnection;
+use time::OffsetDateTime;
 use ulid::Ulid;
 
 use crate::{
     postgres_db::{PostgresDatabase, PostgresTransaction},
-    services_implementations::postgres_session_repository::schema::GetSessionDb,
+    services_implementations::postgres_session_repository::{
+        queries::{get_active_sessions_by_user_id, get_session_by_id, save_session},
+        schema::{GetSessionDb, SaveSessionDb},
+    },
 };
 
 mod queries;
 mod schema;
 
+/// Sessions belong to the realm of their user
 pub struct PostgresSessionRepository {
     database: Arc<PostgresDatabase>,
+    realm: String,
 }
 
 enum SessionRepositoryWithTransactionQueryRequest {
-    GetById { id: String },
-    Save { session: GetSessionDb },
+    GetById {
+        id: String,
+    },
+    GetActiveByUserId {
+        user_id: String,
+        current_time: OffsetDateTime,
+    },
+    Save {
+        session: SaveSessionDb,
+    },
 }
 
 enum SessionRepositoryWithTransactionQueryResponse {
-    OptionalSession { session: Option<GetSessionDb> },
+    OptionalSession { session: Option<Box<GetSessionDb>> },
+    Sessions { sessions: Vec<GetSessionDb> },
     SessionSaved,
 }
 
@@ -39,8 +64,11 @@ pub struct PostgresSessionRepositoryWithTransaction {
 }
 
 impl PostgresSessionRepository {
-    pub fn new(database: Arc<PostgresDatabase>) -> Self {
-        Self { database }
+    pub fn new(database: Arc<PostgresDatabase>, realm: &str) -> Self {
+        Self {
+            database,
+            realm: realm.to_string(),
+        }
     }
 }
 
@@ -49,9 +77,10 @@ impl SessionRepository for PostgresSessionRepository {
         &self,
     ) -> StaticPinnedFuture<Box<dyn SessionRepositoryWithTransaction>, SessionRepositoryError> {
         let db_cloned = self.database.clone();
+        let realm = self.realm.clone();
         pin_static_future(async move {
             let transactional_repo =
-                PostgresSessionRepositoryWithTransaction::init(db_cloned).await?;
+                PostgresSessionRepositoryWithTransaction::init(db_cloned, realm).await?;
             Ok(Box::new(transactional_repo) as Box<dyn SessionRepositoryWithTransaction>)
         })
     }
@@ -60,60 +89,209 @@ impl SessionRepository for PostgresSessionRepository {
         &self,
         id: &Identifier<Ulid, SomeSession>,
     ) -> StaticPinnedFuture<Option<SomeSession<'static>>, SessionRepositoryError> {
-        todo!()
+        let db_clone = self.database.clone();
+        let realm = self.realm.clone();
+        let id = id.to_string();
+        pin_static_future(async move {
+            let mut connection = db_clone.pool().acquire().await.map_err(ErrorBoxed::from)?;
+            get_session_by_id(&mut *connection, &realm, &id)
+                .await?
+                .map(restore_session)
+                .transpose()
+        })
+    }
+
+    fn get_active_by_user_id(
+        &self,
+        user_id: &Identifier<Ulid, User>,
+        current_time: OffsetDateTime,
+    ) -> StaticPinnedFuture<Vec<Session<Active>>, SessionRepositoryError> {
+        let db_clone = self.database.clone();
+        let realm = self.realm.clone();
+        let user_id = user_id.to_string();
+        pin_static_future(async move {
+            let mut connection = db_clone.pool().acquire().await.map_err(ErrorBoxed::from)?;
+            let sessions =
+                get_active_sessions_by_user_id(&mut *connection, &realm, &user_id, current_time)
+                    .await?;
+            active_sessions(sessions, current_time)
+        })
     }
 
     fn save(&self, session: SomeSession) -> StaticPinnedFuture<(), SessionRepositoryError> {
-        todo!()
+        let db_clone = self.database.clone();
+        let realm = self.realm.clone();
+        let session = SaveSessionDb::from(session);
+        pin_static_future(async move {
+            let mut connection = db_clone.pool().acquire().await.map_err(ErrorBoxed::from)?;
+            save_session(&mut *connection, &realm, &session).await
+        })
     }
 }
 
 impl PostgresSessionRepositoryWithTransaction {
-    pub async fn init(database: Arc<PostgresDatabase>) -> Result<Self, SessionRepositoryError> {
+    pub async fn init(
+        database: Arc<PostgresDatabase>,
+        realm: String,
+    ) -> Result<Self, SessionRepositoryError> {
         let transaction = database
-            .start_transaction(|conn, req| pin_future(Self::handle_request(conn, req)))
+            .start_transaction(move |conn, req| {
+                pin_future(Self::handle_request(conn, realm.clone(), req))
+            })
             .await?;
         Ok(Self { transaction })
     }
 
     async fn handle_request(
         connection: &mut PgConnection,
+        realm: String,
         request: SessionRepositoryWithTransactionQueryRequest,
     ) -> Result<SessionRepositoryWithTransactionQueryResponse, SessionRepositoryError> {
         match request {
-            SessionRepositoryWithTransactionQueryRequest::GetById { id } => todo!(),
-            SessionRepositoryWithTransactionQueryRequest::Save { session } => todo!(),
+            SessionRepositoryWithTransactionQueryRequest::GetById { id } => Ok(
+                SessionRepositoryWithTransactionQueryResponse::OptionalSession {
+                    session: get_session_by_id(connection, &realm, &id)
+                        .await?
+                        .map(Box::new),
+                },
+            ),
+            SessionRepositoryWithTransactionQueryRequest::GetActiveByUserId {
+                user_id,
+                current_time,
+            } => Ok(SessionRepositoryWithTransactionQueryResponse::Sessions {
+                sessions: get_active_sessions_by_user_id(
+                    connection,
+                    &realm,
+                    &user_id,
+                    current_time,
+                )
+                .await?,
+            }),
+            SessionRepositoryWithTransactionQueryRequest::Save { session } => {
+                save_session(connection, &realm, &session).await?;
+                Ok(SessionRepositoryWithTransactionQueryResponse::SessionSaved)
+            }
         }
     }
 }
 
 impl SessionRepositoryWithTransaction for PostgresSessionRepositoryWithTransaction {
     fn commit(self: Box<Self>) -> StaticPinnedFuture<(), SessionRepositoryError> {
-        todo!()
+        pin_static_future(async move { self.transaction.commit().await })
     }
 
     fn rollback(self: Box<Self>) -> StaticPinnedFuture<(), SessionRepositoryError> {
-        todo!()
+        pin_static_future(async move { self.transaction.rollback().await })
     }
 
     fn get_by_id(
         self: Box<Self>,
         id: &Identifier<Ulid, SomeSession>,
-    ) -> StaticPinnedFuture<
-        (
-            Box<dyn SessionRepositoryWithTransaction>,
-            Option<SomeSession<'static>>,
-        ),
-        SessionRepositoryError,
-    > {
-        todo!()
+    ) -> SessionTransactionFuture<Option<SomeSession<'static>>> {
+        let id = id.to_string();
+        pin_static_future(async move {
+            let result = self
+                .transaction
+                .execute(SessionRepositoryWithTransactionQueryRequest::GetById { id })
+                .await?;
+
+            match result.1 {
+                SessionRepositoryWithTransactionQueryResponse::OptionalSession { session } => Ok((
+                    Box::new(Self {
+                        transaction: result.0,
+                    }) as Box<dyn SessionRepositoryWithTransaction>,
+                    session
+                        .map(|session_db| restore_session(*session_db))
+                        .transpose()?,
+                )),
+                _ => Err(SessionRepositoryError::from(ErrorBoxed::from_str(
+                    "got invalid response for query",
+                ))),
+            }
+        })
     }
 
-    fn save(
+    fn get_active_by_user_id(
         self: Box<Self>,
-        session: SomeSession,
-    ) -> StaticPinnedFuture<(Box<dyn SessionRepositoryWithTransaction>, ()), SessionRepositoryError>
-    {
-        todo!()
+        user_id: &Identifier<Ulid, User>,
+        current_time: OffsetDateTime,
+    ) -> SessionTransactionFuture<Vec<Session<Active>>> {
+        let user_id = user_id.to_string();
+        pin_static_future(async move {
+            let result = self
+                .transaction
+                .execute(
+                    SessionRepositoryWithTransactionQueryRequest::GetActiveByUserId {
+                        user_id,
+                        current_time,
+                    },
+                )
+                .await?;
+
+            match result.1 {
+                SessionRepositoryWithTransactionQueryResponse::Sessions { sessions } => Ok((
+                    Box::new(Self {
+                        transaction: result.0,
+                    }) as Box<dyn SessionRepositoryWithTransaction>,
+                    active_sessions(sessions, current_time)?,
+                )),
+                _ => Err(SessionRepositoryError::from(ErrorBoxed::from_str(
+                    "got invalid response for query",
+                ))),
+            }
+        })
     }
+
+    fn save(self: Box<Self>, session: SomeSession) -> SessionTransactionFuture<()> {
+        let session = SaveSessionDb::from(session);
+        pin_static_future(async move {
+            let result = self
+                .transaction
+                .execute(SessionRepositoryWithTransactionQueryRequest::Save { session })
+                .await?;
+
+            match result.1 {
+                SessionRepositoryWithTransactionQueryResponse::SessionSaved => Ok((
+                    Box::new(Self {
+                        transaction: result.0,
+                    }) as Box<dyn SessionRepositoryWithTransaction>,
+                    (),
+                )),
+                _ => Err(SessionRepositoryError::from(ErrorBoxed::from_str(
+                    "got invalid response for query",
+                ))),
+            }
+        })
+    }
+}
+
+/// Session is restored as of now, so it comes back expired once its time is over
+fn restore_session(
+    session_db: GetSessionDb,
+) -> Result<SomeSession<'static>, SessionRepositoryError> {
+    session_db
+        .into_domain(OffsetDateTime::now_utc())
+        .map_err(|err| SessionRepositoryError::SessionRestoration(ErrorBoxed::from(err)))
+}
+
+/// Sessions are restored as of `current_time`, the ones expired meanwhile are left out
+fn active_sessions(
+    sessions: Vec<GetSessionDb>,
+    current_time: OffsetDateTime,
+) -> Result<Vec<Session<Active>>, SessionRepositoryError> {
+    let sessions = sessions
+        .into_iter()
+        .map(|session_db| {
+            session_db
+                .into_domain(current_time)
+                .map_err(|err| SessionRepositoryError::SessionRestoration(ErrorBoxed::from(err)))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(sessions
+        .into_iter()
+        .filter_map(|session| match session {
+            SomeSession::Active(session) => Some(session.into_owned()),
+            _ => None,
+        })
+        .collect())
 }
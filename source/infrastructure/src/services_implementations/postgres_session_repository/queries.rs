@@ -1,20 +1,95 @@
 use nimbus_auth_application::services::session_repository::errors::SessionRepositoryError;
 use nimbus_auth_shared::errors::ErrorBoxed;
+use time::OffsetDateTime;
 
-use crate::services_implementations::postgres_session_repository::schema::GetSessionDb;
+use crate::{
+    postgres_db::queries::{
+        USER_GROUP_IDS_COLUMN, USER_GROUP_NAMES_COLUMN, USER_PERMISSIONS_COLUMN, USER_ROLES_COLUMN,
+    },
+    services_implementations::postgres_session_repository::schema::{GetSessionDb, SaveSessionDb},
+};
+
+/// Sessions store only the user id, user claims and realm are taken from the users, roles and groups tables
+fn select_sessions() -> String {
+    format!(
+        "SELECT sessions.*, users.user_name, {USER_ROLES_COLUMN} AS user_roles, \
+        {USER_PERMISSIONS_COLUMN} AS user_permissions, {USER_GROUP_IDS_COLUMN} AS user_group_ids, \
+        {USER_GROUP_NAMES_COLUMN} AS user_group_names, users.attributes::TEXT AS user_attributes_json \
+        FROM sessions INNER JOIN users ON users.id = sessions.user_id"
+    )
+}
 
 pub async fn get_session_by_id<'a, E>(
     executor: &'a mut E,
+    realm: &str,
     id: &str,
 ) -> Result<Option<GetSessionDb>, SessionRepositoryError>
 where
     &'a mut E: sqlx::Executor<'a, Database = sqlx::Postgres>,
 {
-    Ok(
-        sqlx::query_as::<_, GetSessionDb>("SELECT * FROM sessions WHERE id = $1")
-            .bind(id)
-            .fetch_optional(executor)
-            .await
-            .map_err(ErrorBoxed::from)?,
-    )
+    Ok(sqlx::query_as::<_, GetSessionDb>(&format!(
+        "{} WHERE users.realm = $1 AND sessions.id = $2",
+        select_sessions()
+    ))
+    .bind(realm)
+    .bind(id)
+    .fetch_optional(executor)
+    .await
+    .map_err(ErrorBoxed::from)?)
+}
+
+pub async fn get_active_sessions_by_user_id<'a, E>(
+    executor: &'a mut E,
+    realm: &str,
+    user_id: &str,
+    current_time: OffsetDateTime,
+) -> Result<Vec<GetSessionDb>, SessionRepositoryError>
+where
+    &'a mut E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+{
+    Ok(sqlx::query_as::<_, GetSessionDb>(&format!(
+        "{} WHERE users.realm = $1 AND sessions.user_id = $2 \
+        AND sessions.revoked_at IS NULL AND sessions.expires_at > $3",
+        select_sessions()
+    ))
+    .bind(realm)
+    .bind(user_id)
+    .bind(current_time)
+    .fetch_all(executor)
+    .await
+    .map_err(ErrorBoxed::from)?)
+}
+
+/// Active sessions are saved only for users of the realm, others only change the state of the stored ones
+pub async fn save_session<'a, E>(
+    executor: &'a mut E,
+    realm: &str,
+    session: &SaveSessionDb,
+) -> Result<(), SessionRepositoryError>
+where
+    &'a mut E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+{
+    match (&session.user_id, session.expires_at) {
+        (Some(user_id), Some(expires_at)) => sqlx::query(
+            "INSERT INTO sessions (id, user_id, client_id, expires_at, revoked_at) \
+            SELECT $1, users.id, $2, $3, NULL FROM users WHERE users.realm = $4 AND users.id = $5 \
+            ON CONFLICT (id) DO UPDATE SET expires_at = EXCLUDED.expires_at",
+        )
+        .bind(&session.id)
+        .bind(&session.client_id)
+        .bind(expires_at)
+        .bind(realm)
+        .bind(user_id),
+        _ => sqlx::query(
+            "UPDATE sessions SET revoked_at = COALESCE($1, sessions.revoked_at) \
+            FROM users WHERE users.id = sessions.user_id AND users.realm = $2 AND sessions.id = $3",
+        )
+        .bind(session.revoked_at)
+        .bind(realm)
+        .bind(&session.id),
+    }
+    .execute(executor)
+    .await
+    .map_err(ErrorBoxed::from)?;
+    Ok(())
 }
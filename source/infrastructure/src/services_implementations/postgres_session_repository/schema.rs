@@ -1,20 +1,20 @@
 use nimbus_auth_domain::{
     entities::{
         Entity,
+        group::{
+            Group, specifications::RestoreGroupSpecification, value_objects::group_name::GroupName,
+        },
+        role::value_objects::{permission::Permission, role_name::RoleName},
         session::{SomeSession, specifications::RestoreSessionSpecification},
-        user::value_objects::user_name::UserName,
+        user::value_objects::{user_attributes::UserAttributes, user_name::UserName},
     },
     value_objects::{identifier::Identifier, user_claims::UserClaims},
 };
-use nimbus_auth_shared::types::UserRole;
 use sqlx::prelude::FromRow;
 use time::OffsetDateTime;
 use ulid::Ulid;
 
-use crate::{
-    postgres_db::types::user_role::UserRoleDb,
-    services_implementations::postgres_session_repository::schema::errors::SessionDbIntoDomainError,
-};
+use crate::services_implementations::postgres_session_repository::schema::errors::SessionDbIntoDomainError;
 
 pub mod errors;
 
@@ -23,17 +23,24 @@ pub struct GetSessionDb {
     id: String,
     user_id: String,
     user_name: String,
-    user_role: UserRoleDb,
+    user_roles: Vec<String>,
+    user_permissions: Vec<String>,
+    user_group_ids: Vec<String>,
+    user_group_names: Vec<String>,
+    user_attributes_json: String,
+    client_id: Option<String>,
     expires_at: OffsetDateTime,
     revoked_at: Option<OffsetDateTime>,
 }
 
+/// Only active sessions carry their user, others are already stored and only change their state
 #[derive(FromRow)]
 pub struct SaveSessionDb {
-    id: String,
-    user_id: Option<String>,
-    expires_at: Option<OffsetDateTime>,
-    revoked_at: Option<OffsetDateTime>,
+    pub id: String,
+    pub user_id: Option<String>,
+    pub client_id: Option<String>,
+    pub expires_at: Option<OffsetDateTime>,
+    pub revoked_at: Option<OffsetDateTime>,
 }
 
 impl GetSessionDb {
@@ -43,10 +50,37 @@ impl GetSessionDb {
     ) -> Result<SomeSession<'static>, SessionDbIntoDomainError> {
         let user_id = Identifier::from(Ulid::from_string(&self.user_id)?);
         let user_name = UserName::from(&self.user_name)?;
-        let user_role = UserRole::from(&self.user_role);
+        let user_roles = self
+            .user_roles
+            .iter()
+            .map(|role_name| RoleName::from(role_name))
+            .collect::<Result<_, _>>()?;
+        let user_permissions = self
+            .user_permissions
+            .iter()
+            .map(|permission| Permission::from(permission))
+            .collect::<Result<_, _>>()?;
+        let user_groups = self
+            .user_group_ids
+            .iter()
+            .zip(self.user_group_names.iter())
+            .map(|(group_id, group_name)| {
+                Ok(Group::restore(RestoreGroupSpecification {
+                    id: Identifier::from(Ulid::from_string(group_id)?),
+                    name: GroupName::from(group_name)?,
+                }))
+            })
+            .collect::<Result<_, SessionDbIntoDomainError>>()?;
+        let client_id = self
+            .client_id
+            .map(|client_id| Ulid::from_string(&client_id).map(Identifier::from))
+            .transpose()?;
         Ok(SomeSession::restore(RestoreSessionSpecification {
             id: Identifier::from(Ulid::from_string(&self.id)?),
-            user_claims: UserClaims::new(user_id, user_name, user_role),
+            user_claims: UserClaims::new(user_id, user_name, user_roles, user_permissions)
+                .with_groups(user_groups)
+                .with_attributes(UserAttributes::from_json(&self.user_attributes_json)?),
+            client_id,
             expires_at: self.expires_at,
             revoked_at: self.revoked_at,
             current_time,
@@ -60,18 +94,21 @@ impl<'a> From<SomeSession<'a>> for SaveSessionDb {
             SomeSession::Active(session) => SaveSessionDb {
                 id: session.id().to_string(),
                 user_id: Some(session.user_claims().id().to_string()),
+                client_id: session.client_id().map(|client_id| client_id.to_string()),
                 expires_at: Some(session.expires_at()),
                 revoked_at: None,
             },
             SomeSession::Expired(session) => SaveSessionDb {
                 id: session.id().to_string(),
                 user_id: None,
+                client_id: None,
                 expires_at: None,
                 revoked_at: None,
             },
             SomeSession::Revoked(session) => SaveSessionDb {
                 id: session.id().to_string(),
                 user_id: None,
+                client_id: None,
                 expires_at: None,
                 revoked_at: Some(session.revoked_at()),
             },
@@ -1,4 +1,10 @@
-use nimbus_auth_domain::entities::user::value_objects::user_name::errors::UserNameError;
+use nimbus_auth_domain::entities::{
+    group::value_objects::group_name::errors::GroupNameError,
+    role::value_objects::{permission::errors::PermissionError, role_name::errors::RoleNameError},
+    user::value_objects::{
+        user_attributes::errors::UserAttributesError, user_name::errors::UserNameError,
+    },
+};
 use thiserror::Error;
 use ulid::DecodeError;
 
@@ -8,6 +14,12 @@ pub enum SessionDbIntoDomainError {
     InvalidIdentifier(#[from] DecodeError),
     #[error(transparent)]
     InvalidUserName(#[from] UserNameError),
-    #[error("invalid user role value: {0}")]
-    InvalidUserRole(String),
+    #[error(transparent)]
+    InvalidUserRole(#[from] RoleNameError),
+    #[error(transparent)]
+    InvalidUserPermission(#[from] PermissionError),
+    #[error(transparent)]
+    InvalidUserGroup(#[from] GroupNameError),
+    #[error(transparent)]
+    InvalidUserAttributes(#[from] UserAttributesError),
 }
@@ -1,13 +1,14 @@
 use std::sync::Arc;
 
 use nimbus_auth_application::services::user_repository::{
-    UserRepository, UserRepositoryWithTransaction, errors::UserRepositoryError,
+    UserListFilter, UserRepository, UserRepositoryWithTransaction, errors::UserRepositoryError,
 };
 use nimbus_auth_domain::{
     entities::{
         Entity,
+        external_identity::ExternalIdentity,
         session::{Active, Session},
-        user::{User, value_objects::user_name::UserName},
+        user::{SomeUser, User, value_objects::user_name::UserName},
     },
     value_objects::identifier::Identifier,
 };
@@ -20,29 +21,43 @@ use ulid::Ulid;
 
 use crate::{
     postgres_db::{PostgresDatabase, PostgresTransaction},
-    services_implementations::postgres_user_repository::{
-        queries::{get_user_by_id, get_user_by_name, get_user_by_session, save_user},
-        schema::{GetUserDb, SaveUserDb},
+    services_implementations::{
+        postgres_external_identity_repository::{
+            queries::save_external_identity, schema::SaveExternalIdentityDb,
+        },
+        postgres_user_repository::{
+            queries::{
+                get_user_by_id, get_user_by_name, get_user_by_session, list_users,
+                lock_role_by_name, lock_user_by_name, save_user,
+            },
+            schema::{GetUserDb, ListUsersDb, SaveUserDb},
+        },
     },
 };
 
 mod queries;
 mod schema;
 
+/// User names are unique within the realm the repository is scoped to
 pub struct PostgresUserRepository {
     database: Arc<PostgresDatabase>,
+    realm: String,
 }
 
 enum UserRepositoryTransactionQueryRequest {
     GetById { id: String },
     GetByName { user_name: String },
     GetBySession { session_id: String },
+    List { filter: ListUsersDb },
     Save { user: SaveUserDb },
+    SaveExternalIdentity { identity: SaveExternalIdentityDb },
 }
 
 enum UserRepositoryTransactionQueryResponse {
-    OptionalUser { user: Option<GetUserDb> },
+    OptionalUser { user: Option<Box<GetUserDb>> },
+    Users { users: Vec<GetUserDb> },
     UserSaved,
+    ExternalIdentitySaved,
 }
 
 pub struct PostgresUserRepositoryWithTransaction {
@@ -54,8 +69,11 @@ pub struct PostgresUserRepositoryWithTransaction {
 }
 
 impl PostgresUserRepository {
-    pub fn new(database: Arc<PostgresDatabase>) -> Self {
-        Self { database }
+    pub fn new(database: Arc<PostgresDatabase>, realm: &str) -> Self {
+        Self {
+            database,
+            realm: realm.to_string(),
+        }
     }
 }
 
@@ -64,8 +82,10 @@ impl UserRepository for PostgresUserRepository {
         &self,
     ) -> StaticPinnedFuture<Box<dyn UserRepositoryWithTransaction>, UserRepositoryError> {
         let db_cloned = self.database.clone();
+        let realm = self.realm.clone();
         pin_static_future(async move {
-            let transactional_repo = PostgresUserRepositoryWithTransaction::init(db_cloned).await?;
+            let transactional_repo =
+                PostgresUserRepositoryWithTransaction::init(db_cloned, realm).await?;
             Ok(Box::new(transactional_repo) as Box<dyn UserRepositoryWithTransaction>)
         })
     }
@@ -73,15 +93,16 @@ impl UserRepository for PostgresUserRepository {
     fn get_by_id(
         &self,
         id: &Identifier<Ulid, User>,
-    ) -> StaticPinnedFuture<Option<User>, UserRepositoryError> {
+    ) -> StaticPinnedFuture<Option<SomeUser<'static>>, UserRepositoryError> {
         let db_clone = self.database.clone();
+        let realm = self.realm.clone();
         let id = id.to_string();
         pin_static_future(async move {
             let mut connection = db_clone.pool().acquire().await.map_err(ErrorBoxed::from)?;
-            get_user_by_id(&mut *connection, &id)
+            get_user_by_id(&mut *connection, &realm, &id)
                 .await?
                 .map(|user_db| {
-                    User::try_from(&user_db)
+                    SomeUser::try_from(&user_db)
                         .map_err(|err| UserRepositoryError::UserRestoration(ErrorBoxed::from(err)))
                 })
                 .transpose()
@@ -91,15 +112,16 @@ impl UserRepository for PostgresUserRepository {
     fn get_by_name(
         &self,
         user_name: &UserName,
-    ) -> StaticPinnedFuture<Option<User>, UserRepositoryError> {
+    ) -> StaticPinnedFuture<Option<SomeUser<'static>>, UserRepositoryError> {
         let db_clone = self.database.clone();
+        let realm = self.realm.clone();
         let user_name = user_name.to_string();
         pin_static_future(async move {
             let mut connection = db_clone.pool().acquire().await.map_err(ErrorBoxed::from)?;
-            get_user_by_name(&mut *connection, &user_name)
+            get_user_by_name(&mut *connection, &realm, &user_name)
                 .await?
                 .map(|user_db| {
-                    User::try_from(&user_db)
+                    SomeUser::try_from(&user_db)
                         .map_err(|err| UserRepositoryError::UserRestoration(ErrorBoxed::from(err)))
                 })
                 .transpose()
@@ -111,61 +133,112 @@ impl UserRepository for PostgresUserRepository {
         session: &Session<Active>,
     ) -> StaticPinnedFuture<Option<User>, UserRepositoryError> {
         let db_clone = self.database.clone();
+        let realm = self.realm.clone();
         let session_id = session.id().to_string();
         pin_static_future(async move {
             let mut connection = db_clone.pool().acquire().await.map_err(ErrorBoxed::from)?;
-            get_user_by_id(&mut *connection, &session_id)
+            let user = get_user_by_session(&mut *connection, &realm, &session_id)
                 .await?
                 .map(|user_db| {
-                    User::try_from(&user_db)
+                    SomeUser::try_from(&user_db)
                         .map_err(|err| UserRepositoryError::UserRestoration(ErrorBoxed::from(err)))
                 })
-                .transpose()
+                .transpose()?;
+            Ok(user.and_then(active_user))
         })
     }
 
-    fn save(&self, user: &User) -> StaticPinnedFuture<(), UserRepositoryError> {
+    fn list(
+        &self,
+        filter: &UserListFilter,
+    ) -> StaticPinnedFuture<Vec<SomeUser<'static>>, UserRepositoryError> {
         let db_clone = self.database.clone();
-        let user = SaveUserDb::from(user);
+        let realm = self.realm.clone();
+        let filter = ListUsersDb::from(filter);
         pin_static_future(async move {
             let mut connection = db_clone.pool().acquire().await.map_err(ErrorBoxed::from)?;
-            save_user(&mut *connection, &user).await
+            list_users(&mut *connection, &realm, filter)
+                .await?
+                .iter()
+                .map(|user_db| {
+                    SomeUser::try_from(user_db)
+                        .map_err(|err| UserRepositoryError::UserRestoration(ErrorBoxed::from(err)))
+                })
+                .collect()
+        })
+    }
+
+    fn save(&self, user: SomeUser) -> StaticPinnedFuture<(), UserRepositoryError> {
+        let db_clone = self.database.clone();
+        let realm = self.realm.clone();
+        let user = SaveUserDb::from(&user);
+        pin_static_future(async move {
+            // user and its roles and groups are replaced together
+            let mut transaction = db_clone.pool().begin().await.map_err(ErrorBoxed::from)?;
+            save_user(&mut transaction, &realm, &user).await?;
+            transaction.commit().await.map_err(ErrorBoxed::from)?;
+            Ok(())
         })
     }
 }
 
 impl PostgresUserRepositoryWithTransaction {
-    pub async fn init(database: Arc<PostgresDatabase>) -> Result<Self, UserRepositoryError> {
+    pub async fn init(
+        database: Arc<PostgresDatabase>,
+        realm: String,
+    ) -> Result<Self, UserRepositoryError> {
         let transaction = database
-            .start_transaction(|conn, req| pin_future(Self::handle_request(conn, req)))
+            .start_transaction(move |conn, req| {
+                pin_future(Self::handle_request(conn, realm.clone(), req))
+            })
             .await?;
         Ok(Self { transaction })
     }
 
     async fn handle_request(
         connection: &mut PgConnection,
+        realm: String,
         request: UserRepositoryTransactionQueryRequest,
     ) -> Result<UserRepositoryTransactionQueryResponse, UserRepositoryError> {
         match request {
             UserRepositoryTransactionQueryRequest::GetById { id } => {
                 Ok(UserRepositoryTransactionQueryResponse::OptionalUser {
-                    user: get_user_by_id(connection, &id).await?,
+                    user: get_user_by_id(connection, &realm, &id).await?.map(Box::new),
                 })
             }
             UserRepositoryTransactionQueryRequest::GetByName { user_name } => {
+                lock_user_by_name(&mut *connection, &realm, &user_name).await?;
                 Ok(UserRepositoryTransactionQueryResponse::OptionalUser {
-                    user: get_user_by_name(connection, &user_name).await?,
+                    user: get_user_by_name(connection, &realm, &user_name)
+                        .await?
+                        .map(Box::new),
                 })
             }
             UserRepositoryTransactionQueryRequest::GetBySession { session_id } => {
                 Ok(UserRepositoryTransactionQueryResponse::OptionalUser {
-                    user: get_user_by_session(connection, &session_id).await?,
+                    user: get_user_by_session(connection, &realm, &session_id)
+                        .await?
+                        .map(Box::new),
+                })
+            }
+            UserRepositoryTransactionQueryRequest::List { filter } => {
+                if let Some(role_name) = &filter.role_name {
+                    lock_role_by_name(&mut *connection, &realm, role_name).await?;
+                }
+                Ok(UserRepositoryTransactionQueryResponse::Users {
+                    users: list_users(connection, &realm, filter).await?,
                 })
             }
             UserRepositoryTransactionQueryRequest::Save { user } => {
-                save_user(connection, &user).await?;
+                save_user(connection, &realm, &user).await?;
                 Ok(UserRepositoryTransactionQueryResponse::UserSaved)
             }
+            UserRepositoryTransactionQueryRequest::SaveExternalIdentity { identity } => {
+                save_external_identity(connection, &realm, &identity)
+                    .await
+                    .map_err(ErrorBoxed::from)?;
+                Ok(UserRepositoryTransactionQueryResponse::ExternalIdentitySaved)
+            }
         }
     }
 }
@@ -183,7 +256,10 @@ impl UserRepositoryWithTransaction for PostgresUserRepositoryWithTransaction {
         self: Box<Self>,
         id: &Identifier<Ulid, User>,
     ) -> StaticPinnedFuture<
-        (Box<dyn UserRepositoryWithTransaction>, Option<User>),
+        (
+            Box<dyn UserRepositoryWithTransaction>,
+            Option<SomeUser<'static>>,
+        ),
         UserRepositoryError,
     > {
         let id = id.to_string();
@@ -200,7 +276,7 @@ impl UserRepositoryWithTransaction for PostgresUserRepositoryWithTransaction {
                         transaction: result.0,
                     }) as Box<dyn UserRepositoryWithTransaction>,
                     user.map(|db| {
-                        User::try_from(&db).map_err(|err| {
+                        SomeUser::try_from(&*db).map_err(|err| {
                             UserRepositoryError::UserRestoration(ErrorBoxed::from(err))
                         })
                     })
@@ -217,7 +293,10 @@ impl UserRepositoryWithTransaction for PostgresUserRepositoryWithTransaction {
         self: Box<Self>,
         user_name: &UserName,
     ) -> StaticPinnedFuture<
-        (Box<dyn UserRepositoryWithTransaction>, Option<User>),
+        (
+            Box<dyn UserRepositoryWithTransaction>,
+            Option<SomeUser<'static>>,
+        ),
         UserRepositoryError,
     > {
         let user_name = user_name.to_string();
@@ -234,7 +313,7 @@ impl UserRepositoryWithTransaction for PostgresUserRepositoryWithTransaction {
                         transaction: result.0,
                     }) as Box<dyn UserRepositoryWithTransaction>,
                     user.map(|db| {
-                        User::try_from(&db).map_err(|err| {
+                        SomeUser::try_from(&*db).map_err(|err| {
                             UserRepositoryError::UserRestoration(ErrorBoxed::from(err))
                         })
                     })
@@ -268,11 +347,50 @@ impl UserRepositoryWithTransaction for PostgresUserRepositoryWithTransaction {
                         transaction: result.0,
                     }) as Box<dyn UserRepositoryWithTransaction>,
                     user.map(|db| {
-                        User::try_from(&db).map_err(|err| {
+                        SomeUser::try_from(&*db).map_err(|err| {
                             UserRepositoryError::UserRestoration(ErrorBoxed::from(err))
                         })
                     })
-                    .transpose()?,
+                    .transpose()?
+                    .and_then(active_user),
+                )),
+                _ => Err(UserRepositoryError::from(ErrorBoxed::from_str(
+                    "got invalid response for query",
+                ))),
+            }
+        })
+    }
+
+    fn list(
+        self: Box<Self>,
+        filter: &UserListFilter,
+    ) -> StaticPinnedFuture<
+        (
+            Box<dyn UserRepositoryWithTransaction>,
+            Vec<SomeUser<'static>>,
+        ),
+        UserRepositoryError,
+    > {
+        let filter = ListUsersDb::from(filter);
+        pin_static_future(async move {
+            let result = self
+                .transaction
+                .execute(UserRepositoryTransactionQueryRequest::List { filter })
+                .await?;
+
+            match result.1 {
+                UserRepositoryTransactionQueryResponse::Users { users } => Ok((
+                    Box::new(Self {
+                        transaction: result.0,
+                    }) as Box<dyn UserRepositoryWithTransaction>,
+                    users
+                        .iter()
+                        .map(|user_db| {
+                            SomeUser::try_from(user_db).map_err(|err| {
+                                UserRepositoryError::UserRestoration(ErrorBoxed::from(err))
+                            })
+                        })
+                        .collect::<Result<_, _>>()?,
                 )),
                 _ => Err(UserRepositoryError::from(ErrorBoxed::from_str(
                     "got invalid response for query",
@@ -283,9 +401,9 @@ impl UserRepositoryWithTransaction for PostgresUserRepositoryWithTransaction {
 
     fn save(
         self: Box<Self>,
-        user: &User,
+        user: SomeUser,
     ) -> StaticPinnedFuture<(Box<dyn UserRepositoryWithTransaction>, ()), UserRepositoryError> {
-        let user = SaveUserDb::from(user);
+        let user = SaveUserDb::from(&user);
         pin_static_future(async move {
             let result = self
                 .transaction
@@ -306,4 +424,37 @@ impl UserRepositoryWithTransaction for PostgresUserRepositoryWithTransaction {
             }
         })
     }
+
+    fn save_external_identity(
+        self: Box<Self>,
+        identity: &ExternalIdentity,
+    ) -> StaticPinnedFuture<(Box<dyn UserRepositoryWithTransaction>, ()), UserRepositoryError> {
+        let identity = SaveExternalIdentityDb::from(identity);
+        pin_static_future(async move {
+            let result = self
+                .transaction
+                .execute(UserRepositoryTransactionQueryRequest::SaveExternalIdentity { identity })
+                .await?;
+
+            match result.1 {
+                UserRepositoryTransactionQueryResponse::ExternalIdentitySaved => Ok((
+                    Box::new(Self {
+                        transaction: result.0,
+                    }) as Box<dyn UserRepositoryWithTransaction>,
+                    (),
+                )),
+                _ => Err(UserRepositoryError::from(ErrorBoxed::from_str(
+                    "got invalid response for query",
+                ))),
+            }
+        })
+    }
+}
+
+/// Users restored by session lookup are returned only if they are active
+fn active_user(user: SomeUser<'static>) -> Option<User> {
+    match user {
+        SomeUser::Active(user) => Some(user.into_owned()),
+        _ => None,
+    }
 }
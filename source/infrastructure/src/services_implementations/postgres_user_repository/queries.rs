@@ -1,50 +1,237 @@
 use nimbus_auth_application::services::user_repository::errors::UserRepositoryError;
 use nimbus_auth_shared::errors::ErrorBoxed;
 
-use crate::services_implementations::postgres_user_repository::schema::{GetUserDb, SaveUserDb};
+use sqlx::{Postgres, QueryBuilder};
+
+use crate::{
+    postgres_db::queries::{
+        USER_GROUP_IDS_COLUMN, USER_GROUP_NAMES_COLUMN, USER_PERMISSIONS_COLUMN, USER_ROLES_COLUMN,
+    },
+    services_implementations::postgres_user_repository::schema::{
+        GetUserDb, ListUsersDb, SaveUserDb,
+    },
+};
+
+/// Unique key of user names within a realm
+const USERS_REALM_USER_NAME_KEY: &str = "users_realm_user_name_key";
+
+/// Roles, permissions and groups are resolved through the roles and groups tables
+fn select_users() -> String {
+    format!(
+        "SELECT users.*, {USER_ROLES_COLUMN} AS roles, {USER_PERMISSIONS_COLUMN} AS permissions, \
+        {USER_GROUP_IDS_COLUMN} AS group_ids, {USER_GROUP_NAMES_COLUMN} AS group_names, \
+        users.attributes::TEXT AS attributes_json \
+        FROM users"
+    )
+}
 
 pub async fn get_user_by_id<'a, E>(
     executor: &'a mut E,
+    realm: &str,
     id: &str,
 ) -> Result<Option<GetUserDb>, UserRepositoryError>
 where
     &'a mut E: sqlx::Executor<'a, Database = sqlx::Postgres>,
 {
-    Ok(
-        sqlx::query_as::<_, GetUserDb>("SELECT * FROM users WHERE id = $1")
-            .bind(id)
-            .fetch_optional(executor)
-            .await
-            .map_err(ErrorBoxed::from)?,
-    )
+    Ok(sqlx::query_as::<_, GetUserDb>(&format!(
+        "{} WHERE users.realm = $1 AND users.id = $2",
+        select_users()
+    ))
+    .bind(realm)
+    .bind(id)
+    .fetch_optional(executor)
+    .await
+    .map_err(ErrorBoxed::from)?)
 }
 
 pub async fn get_user_by_name<'a, E>(
     executor: &'a mut E,
+    realm: &str,
     name: &str,
 ) -> Result<Option<GetUserDb>, UserRepositoryError>
 where
     &'a mut E: sqlx::Executor<'a, Database = sqlx::Postgres>,
 {
-    todo!()
+    Ok(sqlx::query_as::<_, GetUserDb>(&format!(
+        "{} WHERE users.realm = $1 AND users.user_name = $2",
+        select_users()
+    ))
+    .bind(realm)
+    .bind(name)
+    .fetch_optional(executor)
+    .await
+    .map_err(ErrorBoxed::from)?)
+}
+
+/// Locks the user row until the transaction ends, so concurrent updates of the user wait for it
+pub async fn lock_user_by_name<'a, E>(
+    executor: &'a mut E,
+    realm: &str,
+    name: &str,
+) -> Result<(), UserRepositoryError>
+where
+    &'a mut E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+{
+    sqlx::query("SELECT 1 FROM users WHERE realm = $1 AND user_name = $2 FOR UPDATE")
+        .bind(realm)
+        .bind(name)
+        .fetch_optional(executor)
+        .await
+        .map_err(ErrorBoxed::from)?;
+    Ok(())
+}
+
+/// Locks the role row until the transaction ends, so concurrent checks of its holders wait for it.
+/// Roles are only read while users are saved, so the lock does not block them
+pub async fn lock_role_by_name<'a, E>(
+    executor: &'a mut E,
+    realm: &str,
+    name: &str,
+) -> Result<(), UserRepositoryError>
+where
+    &'a mut E: sqlx::Executor<'a, Database = sqlx::Postgres>,
+{
+    sqlx::query("SELECT 1 FROM roles WHERE realm = $1 AND name = $2 FOR NO KEY UPDATE")
+        .bind(realm)
+        .bind(name)
+        .fetch_optional(executor)
+        .await
+        .map_err(ErrorBoxed::from)?;
+    Ok(())
 }
 
 pub async fn get_user_by_session<'a, E>(
     executor: &'a mut E,
+    realm: &str,
     session_id: &str,
 ) -> Result<Option<GetUserDb>, UserRepositoryError>
 where
     &'a mut E: sqlx::Executor<'a, Database = sqlx::Postgres>,
 {
-    todo!()
+    Ok(sqlx::query_as::<_, GetUserDb>(&format!(
+        "{} INNER JOIN sessions ON sessions.user_id = users.id \
+        WHERE users.realm = $1 AND sessions.id = $2 AND users.status = 'active'",
+        select_users()
+    ))
+    .bind(realm)
+    .bind(session_id)
+    .fetch_optional(executor)
+    .await
+    .map_err(ErrorBoxed::from)?)
 }
 
-pub async fn save_user<'a, E>(
+pub async fn list_users<'a, E>(
     executor: &'a mut E,
-    user: &SaveUserDb,
-) -> Result<(), UserRepositoryError>
+    realm: &str,
+    filter: ListUsersDb,
+) -> Result<Vec<GetUserDb>, UserRepositoryError>
 where
     &'a mut E: sqlx::Executor<'a, Database = sqlx::Postgres>,
 {
-    todo!()
+    let mut query =
+        QueryBuilder::<Postgres>::new(format!("{} WHERE users.realm = ", select_users()));
+    query.push_bind(realm);
+    if let Some(name_pattern) = filter.name_pattern {
+        query
+            .push(" AND users.user_name ILIKE ")
+            .push_bind(name_pattern);
+    }
+    if let Some(role_name) = filter.role_name {
+        query
+            .push(
+                " AND EXISTS (SELECT 1 FROM user_roles \
+                INNER JOIN roles ON roles.id = user_roles.role_id \
+                WHERE user_roles.user_id = users.id AND roles.realm = users.realm \
+                AND roles.name = ",
+            )
+            .push_bind(role_name)
+            .push(")");
+    }
+    if let Some(status) = filter.status {
+        query.push(" AND users.status = ").push_bind(status);
+    }
+    if let Some(after_id) = filter.after_id {
+        query.push(" AND users.id > ").push_bind(after_id);
+    }
+    query
+        .push(" ORDER BY users.id LIMIT ")
+        .push_bind(filter.limit);
+
+    Ok(query
+        .build_query_as::<GetUserDb>()
+        .fetch_all(executor)
+        .await
+        .map_err(ErrorBoxed::from)?)
+}
+
+/// Roles and group memberships of the user are replaced, so it should run in a transaction
+pub async fn save_user(
+    connection: &mut sqlx::PgConnection,
+    realm: &str,
+    user: &SaveUserDb,
+) -> Result<(), UserRepositoryError> {
+    sqlx::query(
+        "INSERT INTO users \
+        (id, realm, user_name, password_hash, failed_signin_attempts, locked_until, \
+        status, suspension_reason, suspended_until, deleted_at, attributes) \
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11::JSONB) \
+        ON CONFLICT (id) DO UPDATE SET \
+        user_name = EXCLUDED.user_name, password_hash = EXCLUDED.password_hash, \
+        failed_signin_attempts = EXCLUDED.failed_signin_attempts, \
+        locked_until = EXCLUDED.locked_until, status = EXCLUDED.status, \
+        suspension_reason = EXCLUDED.suspension_reason, \
+        suspended_until = EXCLUDED.suspended_until, deleted_at = EXCLUDED.deleted_at, \
+        attributes = EXCLUDED.attributes \
+        WHERE users.realm = EXCLUDED.realm",
+    )
+    .bind(&user.id)
+    .bind(realm)
+    .bind(&user.user_name)
+    .bind(&user.password_hash)
+    .bind(user.failed_signin_attempts)
+    .bind(user.locked_until)
+    .bind(&user.status)
+    .bind(&user.suspension_reason)
+    .bind(user.suspended_until)
+    .bind(user.deleted_at)
+    .bind(&user.attributes_json)
+    .execute(&mut *connection)
+    .await
+    .map_err(
+        |err| match err.as_database_error().and_then(|err| err.constraint()) {
+            Some(USERS_REALM_USER_NAME_KEY) => UserRepositoryError::UserNameIsTaken,
+            _ => UserRepositoryError::from(ErrorBoxed::from(err)),
+        },
+    )?;
+    sqlx::query("DELETE FROM user_roles WHERE user_id = $1")
+        .bind(&user.id)
+        .execute(&mut *connection)
+        .await
+        .map_err(ErrorBoxed::from)?;
+    sqlx::query(
+        "INSERT INTO user_roles (user_id, role_id) \
+        SELECT $1, roles.id FROM roles WHERE roles.realm = $2 AND roles.name = ANY($3::TEXT[])",
+    )
+    .bind(&user.id)
+    .bind(realm)
+    .bind(&user.roles)
+    .execute(&mut *connection)
+    .await
+    .map_err(ErrorBoxed::from)?;
+    sqlx::query("DELETE FROM user_groups WHERE user_id = $1")
+        .bind(&user.id)
+        .execute(&mut *connection)
+        .await
+        .map_err(ErrorBoxed::from)?;
+    sqlx::query(
+        "INSERT INTO user_groups (user_id, group_id) \
+        SELECT $1, groups.id FROM groups WHERE groups.realm = $2 AND groups.id = ANY($3::TEXT[])",
+    )
+    .bind(&user.id)
+    .bind(realm)
+    .bind(&user.group_ids)
+    .execute(&mut *connection)
+    .await
+    .map_err(ErrorBoxed::from)?;
+    Ok(())
 }
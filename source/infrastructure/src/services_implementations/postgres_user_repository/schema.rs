@@ -1,20 +1,29 @@
+use nimbus_auth_application::services::user_repository::UserListFilter;
 use nimbus_auth_domain::{
     entities::{
         Entity,
+        group::{
+            Group, specifications::RestoreGroupSpecification, value_objects::group_name::GroupName,
+        },
+        role::value_objects::{permission::Permission, role_name::RoleName},
         user::{
-            User,
-            specifications::RestoreUserSpecification,
-            value_objects::{password_hash::PasswordHash, user_name::UserName},
+            SomeUser,
+            specifications::{RestoreUserSpecification, RestoreUserStateSpecification},
+            value_objects::{
+                password_hash::PasswordHash, signin_lockout::SigninLockout,
+                user_attributes::UserAttributes, user_name::UserName,
+            },
         },
     },
     value_objects::{identifier::Identifier, user_claims::UserClaims},
 };
-use nimbus_auth_shared::types::UserRole;
+use nimbus_auth_shared::types::UserStatus;
 use sqlx::prelude::FromRow;
+use time::OffsetDateTime;
 use ulid::Ulid;
 
 use crate::{
-    postgres_db::types::user_role::UserRoleDb,
+    postgres_db::types::user_status::UserStatusDb,
     services_implementations::postgres_user_repository::schema::errors::TryFromUserDbError,
 };
 
@@ -24,41 +33,155 @@ pub mod errors;
 pub struct GetUserDb {
     pub id: String,
     pub user_name: String,
-    pub role: UserRoleDb,
     pub password_hash: String,
+    pub failed_signin_attempts: i32,
+    pub locked_until: Option<OffsetDateTime>,
+    pub status: UserStatusDb,
+    pub suspension_reason: Option<String>,
+    pub suspended_until: Option<OffsetDateTime>,
+    pub deleted_at: Option<OffsetDateTime>,
+    pub roles: Vec<String>,
+    pub permissions: Vec<String>,
+    pub group_ids: Vec<String>,
+    pub group_names: Vec<String>,
+    pub attributes_json: String,
 }
 
 #[derive(FromRow)]
 pub struct SaveUserDb {
     pub id: String,
     pub user_name: String,
-    pub role: UserRoleDb,
     pub password_hash: String,
+    pub failed_signin_attempts: i32,
+    pub locked_until: Option<OffsetDateTime>,
+    pub status: UserStatusDb,
+    pub suspension_reason: Option<String>,
+    pub suspended_until: Option<OffsetDateTime>,
+    pub deleted_at: Option<OffsetDateTime>,
+    /// User roles are replaced with these
+    pub roles: Vec<String>,
+    /// User group memberships are replaced with these
+    pub group_ids: Vec<String>,
+    pub attributes_json: String,
+}
+
+pub struct ListUsersDb {
+    pub name_pattern: Option<String>,
+    pub role_name: Option<String>,
+    pub status: Option<UserStatusDb>,
+    pub after_id: Option<String>,
+    pub limit: i64,
+}
+
+impl From<&UserListFilter> for ListUsersDb {
+    fn from(value: &UserListFilter) -> Self {
+        ListUsersDb {
+            name_pattern: value.name_query.as_ref().map(|name_query| {
+                // wildcards are escaped, so the query matches literally
+                let escaped_name_query = name_query
+                    .replace('\\', "\\\\")
+                    .replace('%', "\\%")
+                    .replace('_', "\\_");
+                format!("%{escaped_name_query}%")
+            }),
+            role_name: value.role.as_ref().map(|role_name| role_name.to_string()),
+            status: value.status.as_ref().map(UserStatusDb::from),
+            after_id: value.after_id.as_ref().map(|after_id| after_id.to_string()),
+            limit: value.limit.min(i64::MAX as usize) as i64,
+        }
+    }
 }
 
-impl TryFrom<&GetUserDb> for User {
+impl TryFrom<&GetUserDb> for SomeUser<'static> {
     type Error = TryFromUserDbError;
 
     fn try_from(value: &GetUserDb) -> Result<Self, Self::Error> {
         let claims = UserClaims::new(
             Identifier::from(Ulid::from_string(&value.id)?),
             UserName::from(&value.user_name)?,
-            UserRole::from(&value.role),
-        );
-        Ok(User::restore(RestoreUserSpecification {
+            value
+                .roles
+                .iter()
+                .map(|role_name| RoleName::from(role_name))
+                .collect::<Result<_, _>>()?,
+            value
+                .permissions
+                .iter()
+                .map(|permission| Permission::from(permission))
+                .collect::<Result<_, _>>()?,
+        )
+        .with_groups(
+            value
+                .group_ids
+                .iter()
+                .zip(value.group_names.iter())
+                .map(|(group_id, group_name)| {
+                    Ok(Group::restore(RestoreGroupSpecification {
+                        id: Identifier::from(Ulid::from_string(group_id)?),
+                        name: GroupName::from(group_name)?,
+                    }))
+                })
+                .collect::<Result<_, TryFromUserDbError>>()?,
+        )
+        .with_attributes(UserAttributes::from_json(&value.attributes_json)?);
+        let state = match value.status {
+            UserStatusDb::Active => RestoreUserStateSpecification::Active,
+            UserStatusDb::Suspended => RestoreUserStateSpecification::Suspended {
+                reason: value.suspension_reason.clone().unwrap_or_default(),
+                suspended_until: value.suspended_until,
+            },
+            UserStatusDb::Deleted => RestoreUserStateSpecification::Deleted {
+                deleted_at: value
+                    .deleted_at
+                    .ok_or(TryFromUserDbError::MissingStatusField {
+                        status: UserStatus::Deleted,
+                        field: "deleted_at",
+                    })?,
+            },
+        };
+        Ok(SomeUser::restore(RestoreUserSpecification {
             claims,
             password_hash: PasswordHash::from(&value.password_hash)?,
+            signin_lockout: SigninLockout::restore(
+                value.failed_signin_attempts.max(0) as u32,
+                value.locked_until,
+            ),
+            state,
         }))
     }
 }
 
-impl From<&User> for SaveUserDb {
-    fn from(value: &User) -> Self {
+impl From<&SomeUser<'_>> for SaveUserDb {
+    fn from(value: &SomeUser<'_>) -> Self {
+        let (suspension_reason, suspended_until, deleted_at) = match value {
+            SomeUser::Active(_) => (None, None, None),
+            SomeUser::Suspended(user) => (
+                Some(user.reason().to_string()),
+                user.suspended_until(),
+                None,
+            ),
+            SomeUser::Deleted(user) => (None, None, Some(user.deleted_at())),
+        };
         SaveUserDb {
             id: value.id().to_string(),
             user_name: value.name().to_string(),
-            role: UserRoleDb::from(value.role()),
-            password_hash: value.password_hash().to_string(),
+            password_hash: value.password_hash().value().to_string(),
+            failed_signin_attempts: value
+                .signin_lockout()
+                .failed_attempts()
+                .min(i32::MAX as u32) as i32,
+            locked_until: value.signin_lockout().locked_until(),
+            status: UserStatusDb::from(&value.status()),
+            suspension_reason,
+            suspended_until,
+            deleted_at,
+            roles: value.roles().iter().map(|role| role.to_string()).collect(),
+            group_ids: value
+                .groups()
+                .iter()
+                .map(|group| group.id().to_string())
+                .collect(),
+            attributes_json: value.attributes().to_json(),
         }
     }
 }
@@ -1,6 +1,12 @@
-use nimbus_auth_domain::entities::user::value_objects::{
-    password_hash::errors::PasswordHashError, user_name::errors::UserNameError,
+use nimbus_auth_domain::entities::{
+    group::value_objects::group_name::errors::GroupNameError,
+    role::value_objects::{permission::errors::PermissionError, role_name::errors::RoleNameError},
+    user::value_objects::{
+        password_hash::errors::PasswordHashError, user_attributes::errors::UserAttributesError,
+        user_name::errors::UserNameError,
+    },
 };
+use nimbus_auth_shared::types::UserStatus;
 use thiserror::Error;
 use ulid::DecodeError;
 
@@ -12,4 +18,17 @@ pub enum TryFromUserDbError {
     UserName(#[from] UserNameError),
     #[error(transparent)]
     PasswordHash(#[from] PasswordHashError),
+    #[error(transparent)]
+    RoleName(#[from] RoleNameError),
+    #[error(transparent)]
+    Permission(#[from] PermissionError),
+    #[error(transparent)]
+    GroupName(#[from] GroupNameError),
+    #[error(transparent)]
+    Attributes(#[from] UserAttributesError),
+    #[error("user with status: {status} has no {field}")]
+    MissingStatusField {
+        status: UserStatus,
+        field: &'static str,
+    },
 }
@@ -0,0 +1,56 @@
+use std::time::Duration;
+
+use nimbus_auth_application::services::signup_notifier::{
+    SignUpNotification, SignUpNotifier, errors::SignUpNotifierError,
+};
+use nimbus_auth_shared::{
+    constants::OUTGOING_REQUEST_TIMEOUT_SECONDS,
+    errors::ErrorBoxed,
+    futures::{StaticPinnedFuture, pin_static_future},
+};
+use reqwest::{Client, header::CONTENT_TYPE};
+
+use crate::services_implementations::webhook_signup_notifier::schema::SignUpNotificationJson;
+
+mod schema;
+
+/// Posts signup outcomes as JSON to a webhook, e.g. of a service sending emails
+pub struct WebhookSignUpNotifier {
+    client: Client,
+    url: String,
+}
+
+impl WebhookSignUpNotifier {
+    pub fn new(url: &str) -> Result<Self, ErrorBoxed> {
+        let client = Client::builder()
+            .timeout(Duration::from_secs(OUTGOING_REQUEST_TIMEOUT_SECONDS))
+            .build()?;
+        Ok(Self {
+            client,
+            url: url.to_string(),
+        })
+    }
+}
+
+impl SignUpNotifier for WebhookSignUpNotifier {
+    fn notify(
+        &self,
+        notification: SignUpNotification,
+    ) -> StaticPinnedFuture<(), SignUpNotifierError> {
+        let client = self.client.clone();
+        let url = self.url.clone();
+        pin_static_future(async move {
+            let body = serde_json::to_vec(&SignUpNotificationJson::from(&notification))
+                .map_err(ErrorBoxed::from)?;
+            client
+                .post(url)
+                .header(CONTENT_TYPE, "application/json")
+                .body(body)
+                .send()
+                .await
+                .and_then(|response| response.error_for_status())
+                .map_err(ErrorBoxed::from)?;
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,30 @@
+use nimbus_auth_application::services::signup_notifier::SignUpNotification;
+use serde::Serialize;
+
+#[derive(Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SignUpNotificationEventJson {
+    UserCreated,
+    UserAlreadyExists,
+}
+
+#[derive(Serialize)]
+pub struct SignUpNotificationJson {
+    pub event: SignUpNotificationEventJson,
+    pub user_name: String,
+}
+
+impl From<&SignUpNotification> for SignUpNotificationJson {
+    fn from(value: &SignUpNotification) -> Self {
+        match value {
+            SignUpNotification::UserCreated { user_name } => Self {
+                event: SignUpNotificationEventJson::UserCreated,
+                user_name: user_name.to_string(),
+            },
+            SignUpNotification::UserAlreadyExists { user_name } => Self {
+                event: SignUpNotificationEventJson::UserAlreadyExists,
+                user_name: user_name.to_string(),
+            },
+        }
+    }
+}
@@ -1,6 +1,8 @@
+use std::sync::Arc;
+
 use axum::{
     Router,
-    routing::{get, post},
+    routing::{get, post, put},
 };
 use nimbus_auth_application::use_cases::UseCases;
 use nimbus_auth_shared::config::AppConfig;
@@ -9,19 +11,41 @@ use tokio::{net::TcpListener, sync::oneshot};
 use crate::web_api::{
     errors::WebApiError,
     handlers::{
+        admin_groups::{handle_create_group, handle_delete_group, handle_list_groups},
+        admin_roles::{handle_delete_role, handle_list_roles, handle_put_role},
+        admin_users::{
+            handle_change_user_groups, handle_change_user_roles, handle_delete_user,
+            handle_get_user, handle_impersonate_user, handle_list_users,
+            handle_revoke_user_sessions, handle_suspend_user, handle_unsuspend_user,
+            handle_update_user_attributes,
+        },
+        api_keys::{handle_create_api_key, handle_list_api_keys, handle_revoke_api_key},
+        federation::{
+            handle_authorize_external_identity, handle_federated_authorize,
+            handle_federated_signin, handle_link_external_identity,
+            handle_list_external_identities, handle_unlink_external_identity,
+        },
         get_public_key::{handle_get_active_public_key, handle_get_public_key_by_id},
+        jwks::handle_get_jwks,
+        oauth::{
+            handle_approve_device_authorization, handle_oauth_authorize,
+            handle_oauth_device_authorize, handle_oauth_token,
+        },
         refresh::handle_refresh,
         rotate_keypairs::handle_rotate_keypairs,
         signin::handle_signin,
         signup::handle_signup,
+        user_signin_lockout::{handle_get_user_signin_lockout, handle_reset_user_signin_lockout},
     },
     middleware::apply_middleware,
+    realms::{RealmRouters, route_by_host},
 };
 
 pub mod errors;
 mod extractors;
 mod handlers;
 mod middleware;
+mod realms;
 mod responses;
 
 pub struct WebApi {}
@@ -29,23 +53,22 @@ pub struct WebApi {}
 impl WebApi {
     pub async fn serve(
         config: &AppConfig,
-        use_cases: UseCases,
+        realms_use_cases: Vec<UseCases>,
         shutdown_signal_receiver: oneshot::Receiver<()>,
     ) -> Result<(), WebApiError> {
         let (shutdown_result_sender, shutdown_result_receiver) =
             oneshot::channel::<Result<(), WebApiError>>();
 
-        let mut router = Router::new()
-            .route("/keypairs/rotate", post(handle_rotate_keypairs))
-            .route("/public_keys/active", get(handle_get_active_public_key))
-            .route(
-                "/public_keys/by_id/{key_id}",
-                get(handle_get_public_key_by_id),
-            )
-            .route("/auth/signup", post(handle_signup))
-            .route("/auth/signin", post(handle_signin))
-            .route("/auth/refresh", post(handle_refresh))
-            .with_state(use_cases);
+        let mut router = Router::new();
+        let mut realm_routers = RealmRouters::default();
+        for use_cases in realms_use_cases {
+            let realm = use_cases.realm().clone();
+            let realm_router = Self::realm_router(use_cases);
+            router = router.nest(&format!("/realms/{}", realm.name), realm_router.clone());
+            realm_routers.add(&realm, realm_router);
+        }
+        let realm_routers = Arc::new(realm_routers);
+        router = router.fallback(move |request| route_by_host(realm_routers.clone(), request));
 
         router = apply_middleware(router, config)?;
 
@@ -69,4 +92,103 @@ impl WebApi {
         shutdown_result_receiver.await.unwrap()?;
         Ok(())
     }
+
+    /// Each realm is served by the same routes bound to its own use cases
+    fn realm_router(use_cases: UseCases) -> Router {
+        Router::new()
+            .route("/keypairs/rotate", post(handle_rotate_keypairs))
+            .route("/.well-known/jwks.json", get(handle_get_jwks))
+            .route("/public_keys/active", get(handle_get_active_public_key))
+            .route(
+                "/public_keys/by_id/{key_id}",
+                get(handle_get_public_key_by_id),
+            )
+            .route("/auth/signup", post(handle_signup))
+            .route("/auth/signin", post(handle_signin))
+            .route("/auth/refresh", post(handle_refresh))
+            .route(
+                "/auth/federation/{provider}/authorize",
+                post(handle_federated_authorize),
+            )
+            .route(
+                "/auth/federation/{provider}/signin",
+                post(handle_federated_signin),
+            )
+            .route("/oauth/authorize", get(handle_oauth_authorize))
+            .route("/oauth/token", post(handle_oauth_token))
+            .route(
+                "/oauth/device_authorization",
+                post(handle_oauth_device_authorize),
+            )
+            .route(
+                "/oauth/device/approve",
+                post(handle_approve_device_authorization),
+            )
+            .route(
+                "/api_keys",
+                get(handle_list_api_keys).post(handle_create_api_key),
+            )
+            .route("/api_keys/{api_key_id}/revoke", post(handle_revoke_api_key))
+            .route("/external_identities", get(handle_list_external_identities))
+            .route(
+                "/external_identities/{provider}",
+                post(handle_link_external_identity).delete(handle_unlink_external_identity),
+            )
+            .route(
+                "/external_identities/{provider}/authorize",
+                post(handle_authorize_external_identity),
+            )
+            .route(
+                "/admin/users/{user_name}/signin_lockout",
+                get(handle_get_user_signin_lockout),
+            )
+            .route(
+                "/admin/users/{user_name}/signin_lockout/reset",
+                post(handle_reset_user_signin_lockout),
+            )
+            .route("/admin/roles", get(handle_list_roles))
+            .route(
+                "/admin/roles/{role_name}",
+                put(handle_put_role).delete(handle_delete_role),
+            )
+            .route("/admin/groups", get(handle_list_groups))
+            .route(
+                "/admin/groups/{group_name}",
+                post(handle_create_group).delete(handle_delete_group),
+            )
+            .route("/admin/users/search", post(handle_list_users))
+            .route(
+                "/admin/users/{user_name}",
+                get(handle_get_user).delete(handle_delete_user),
+            )
+            .route(
+                "/admin/users/{user_name}/roles",
+                post(handle_change_user_roles),
+            )
+            .route(
+                "/admin/users/{user_name}/groups",
+                post(handle_change_user_groups),
+            )
+            .route(
+                "/admin/users/{user_name}/attributes",
+                put(handle_update_user_attributes),
+            )
+            .route(
+                "/admin/users/{user_name}/suspend",
+                post(handle_suspend_user),
+            )
+            .route(
+                "/admin/users/{user_name}/unsuspend",
+                post(handle_unsuspend_user),
+            )
+            .route(
+                "/admin/users/{user_name}/sessions/revoke",
+                post(handle_revoke_user_sessions),
+            )
+            .route(
+                "/admin/users/{user_name}/impersonate",
+                post(handle_impersonate_user),
+            )
+            .with_state(use_cases)
+    }
 }
@@ -3,58 +3,106 @@ use axum::{
     http::{StatusCode, header::AUTHORIZATION, request::Parts},
 };
 use nimbus_auth_application::use_cases::{
-    AuthorizationError, AuthorizationRequest, UseCases, UserClaimsDto,
+    AuthorizationError, AuthorizationRequest, AuthorizeApiKeyError, AuthorizeApiKeyRequest,
+    PrincipalDto, UseCases, UserClaimsDto,
 };
+use nimbus_auth_shared::constants::API_KEY_PREFIX;
 use tracing::error;
 
+/// Access token or api key of a user, tokens of clients acting on their own behalf are rejected
 pub struct Authorization(pub UserClaimsDto);
 
+/// Access token of either a user or a client acting on its own behalf
+pub struct PrincipalAuthorization(pub PrincipalDto);
+
 impl FromRequestParts<UseCases> for Authorization {
     type Rejection = (StatusCode, &'static str);
 
-    fn from_request_parts(
+    async fn from_request_parts(
         parts: &mut Parts,
         state: &UseCases,
-    ) -> impl Future<Output = Result<Self, Self::Rejection>> + Send {
-        async {
-            let signed_token = parts
-                .headers
-                .get(AUTHORIZATION)
-                .ok_or((
-                    StatusCode::UNAUTHORIZED,
-                    "authorization header is not found",
-                ))?
-                .to_str()
-                .map_err(|_| (StatusCode::BAD_REQUEST, "authorization header is invalid"))?
-                .strip_prefix("Bearer ")
-                .ok_or((
-                    StatusCode::BAD_REQUEST,
-                    "authorization header has wrong schema",
-                ))?;
-
-            let auth_response = state
-                .authorize(AuthorizationRequest { signed_token })
-                .await
-                .map_err(|err| match err {
-                    AuthorizationError::ExtractKeyId(_) => (
-                        StatusCode::BAD_REQUEST,
-                        "access token does not contain valid key id",
-                    ),
-                    AuthorizationError::AccessTokenVerification(_) => {
-                        (StatusCode::BAD_REQUEST, "access token is invalid")
-                    }
-                    AuthorizationError::KeyPairNotFound
-                    | AuthorizationError::KeyPairExpired
-                    | AuthorizationError::KeyPairRevoked => {
-                        (StatusCode::BAD_REQUEST, "access token key is invalid")
-                    }
-                    err => {
-                        error!("error in authorization extractor: {err}");
-                        (StatusCode::INTERNAL_SERVER_ERROR, "server error")
-                    }
-                })?;
-
-            Ok(Authorization(auth_response.user))
+    ) -> Result<Self, Self::Rejection> {
+        match PrincipalAuthorization::from_request_parts(parts, state).await? {
+            PrincipalAuthorization(PrincipalDto::User(user)) => Ok(Authorization(user)),
+            PrincipalAuthorization(PrincipalDto::Client(_)) => Err((
+                StatusCode::FORBIDDEN,
+                "access token is issued to a client, not a user",
+            )),
         }
     }
 }
+
+impl FromRequestParts<UseCases> for PrincipalAuthorization {
+    type Rejection = (StatusCode, &'static str);
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &UseCases,
+    ) -> Result<Self, Self::Rejection> {
+        let signed_token = parts
+            .headers
+            .get(AUTHORIZATION)
+            .ok_or((
+                StatusCode::UNAUTHORIZED,
+                "authorization header is not found",
+            ))?
+            .to_str()
+            .map_err(|_| (StatusCode::BAD_REQUEST, "authorization header is invalid"))?
+            .strip_prefix("Bearer ")
+            .ok_or((
+                StatusCode::BAD_REQUEST,
+                "authorization header has wrong schema",
+            ))?;
+
+        if signed_token.starts_with(API_KEY_PREFIX) {
+            return authorize_api_key(signed_token, state).await;
+        }
+
+        let auth_response = state
+            .authorize(AuthorizationRequest { signed_token })
+            .await
+            .map_err(|err| match err {
+                AuthorizationError::ExtractKeyId(_) => (
+                    StatusCode::BAD_REQUEST,
+                    "access token does not contain valid key id",
+                ),
+                AuthorizationError::AccessTokenVerification(_) => {
+                    (StatusCode::BAD_REQUEST, "access token is invalid")
+                }
+                AuthorizationError::KeyPairNotFound
+                | AuthorizationError::KeyPairExpired
+                | AuthorizationError::KeyPairRevoked => {
+                    (StatusCode::BAD_REQUEST, "access token key is invalid")
+                }
+                err => {
+                    error!("error in authorization extractor: {err}");
+                    (StatusCode::INTERNAL_SERVER_ERROR, "server error")
+                }
+            })?;
+
+        Ok(PrincipalAuthorization(auth_response.principal))
+    }
+}
+
+async fn authorize_api_key(
+    api_key: &str,
+    state: &UseCases,
+) -> Result<PrincipalAuthorization, (StatusCode, &'static str)> {
+    let auth_response = state
+        .authorize_api_key(AuthorizeApiKeyRequest { api_key })
+        .await
+        .map_err(|err| match err {
+            AuthorizeApiKeyError::ApiKey(_)
+            | AuthorizeApiKeyError::ApiKeyIsNotFound
+            | AuthorizeApiKeyError::UserIsNotFound
+            | AuthorizeApiKeyError::UserIsNotActive => {
+                (StatusCode::UNAUTHORIZED, "api key is invalid")
+            }
+            err => {
+                error!("error in authorization extractor: {err}");
+                (StatusCode::INTERNAL_SERVER_ERROR, "server error")
+            }
+        })?;
+
+    Ok(PrincipalAuthorization(auth_response.principal))
+}
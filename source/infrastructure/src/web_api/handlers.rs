@@ -1,5 +1,13 @@
+pub mod admin_groups;
+pub mod admin_roles;
+pub mod admin_users;
+pub mod api_keys;
+pub mod federation;
 pub mod get_public_key;
+pub mod jwks;
+pub mod oauth;
 pub mod refresh;
 pub mod rotate_keypairs;
 pub mod signin;
 pub mod signup;
+pub mod user_signin_lockout;
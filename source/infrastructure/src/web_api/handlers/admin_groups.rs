@@ -0,0 +1,176 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use nimbus_auth_application::use_cases::{
+    CreateGroupError, CreateGroupRequest, DeleteGroupError, DeleteGroupRequest, ListGroupsError,
+    ListGroupsRequest, UseCases,
+};
+use nimbus_auth_proto::proto::nimbus::admin::groups::v1::{
+    AdminGroupsErrorCodeProto, CreateGroupResponseProto, CreateGroupSuccessResponseProto,
+    DeleteGroupResponseProto, DeleteGroupSuccessResponseProto, ListGroupsResponseProto,
+    ListGroupsSuccessResponseProto, create_group_response_proto, delete_group_response_proto,
+    list_groups_response_proto,
+};
+use tracing::error;
+
+use crate::{
+    converters::convert_group_into_proto,
+    web_api::{
+        extractors::authorization_extractor::Authorization, responses::proto::ProtoResponse,
+    },
+};
+
+pub async fn handle_list_groups(
+    State(use_cases): State<UseCases>,
+    Authorization(user): Authorization,
+) -> impl IntoResponse {
+    let result = use_cases.list_groups(ListGroupsRequest { user }).await;
+
+    let (status_code, result) = match result {
+        Ok(response) => (
+            StatusCode::OK,
+            list_groups_response_proto::Result::Success(ListGroupsSuccessResponseProto {
+                groups: response
+                    .groups
+                    .into_iter()
+                    .map(convert_group_into_proto)
+                    .collect(),
+            }),
+        ),
+        Err(err) => {
+            let (status_code, error_code) = match err {
+                ListGroupsError::Forbidden(_) => {
+                    (StatusCode::FORBIDDEN, AdminGroupsErrorCodeProto::Forbidden)
+                }
+                err => {
+                    error!("error in handle_list_groups handler: {err}");
+                    (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        AdminGroupsErrorCodeProto::Undefined,
+                    )
+                }
+            };
+            (
+                status_code,
+                list_groups_response_proto::Result::Error(error_code.into()),
+            )
+        }
+    };
+
+    ProtoResponse::new(
+        status_code,
+        ListGroupsResponseProto {
+            result: Some(result),
+        },
+    )
+}
+
+pub async fn handle_create_group(
+    State(use_cases): State<UseCases>,
+    Authorization(user): Authorization,
+    Path(group_name): Path<String>,
+) -> impl IntoResponse {
+    let result = use_cases
+        .create_group(CreateGroupRequest {
+            user,
+            group_name: &group_name,
+        })
+        .await;
+
+    let (status_code, result) = match result {
+        Ok(response) => (
+            StatusCode::CREATED,
+            create_group_response_proto::Result::Success(CreateGroupSuccessResponseProto {
+                group: Some(convert_group_into_proto(response.group)),
+            }),
+        ),
+        Err(err) => {
+            let (status_code, error_code) = match err {
+                CreateGroupError::Forbidden(_) => {
+                    (StatusCode::FORBIDDEN, AdminGroupsErrorCodeProto::Forbidden)
+                }
+                CreateGroupError::InvalidGroupName(_) => (
+                    StatusCode::BAD_REQUEST,
+                    AdminGroupsErrorCodeProto::ValidationError,
+                ),
+                CreateGroupError::GroupAlreadyExists { .. } => (
+                    StatusCode::CONFLICT,
+                    AdminGroupsErrorCodeProto::GroupAlreadyExists,
+                ),
+                err => {
+                    error!("error in handle_create_group handler: {err}");
+                    (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        AdminGroupsErrorCodeProto::Undefined,
+                    )
+                }
+            };
+            (
+                status_code,
+                create_group_response_proto::Result::Error(error_code.into()),
+            )
+        }
+    };
+
+    ProtoResponse::new(
+        status_code,
+        CreateGroupResponseProto {
+            result: Some(result),
+        },
+    )
+}
+
+pub async fn handle_delete_group(
+    State(use_cases): State<UseCases>,
+    Authorization(user): Authorization,
+    Path(group_name): Path<String>,
+) -> impl IntoResponse {
+    let result = use_cases
+        .delete_group(DeleteGroupRequest {
+            user,
+            group_name: &group_name,
+        })
+        .await;
+
+    let (status_code, result) = match result {
+        Ok(_) => (
+            StatusCode::OK,
+            delete_group_response_proto::Result::Success(DeleteGroupSuccessResponseProto {}),
+        ),
+        Err(err) => {
+            let (status_code, error_code) = match err {
+                DeleteGroupError::Forbidden(_) => {
+                    (StatusCode::FORBIDDEN, AdminGroupsErrorCodeProto::Forbidden)
+                }
+                DeleteGroupError::InvalidGroupName(_) => (
+                    StatusCode::BAD_REQUEST,
+                    AdminGroupsErrorCodeProto::ValidationError,
+                ),
+                DeleteGroupError::GroupIsNotFound { .. } => (
+                    StatusCode::NOT_FOUND,
+                    AdminGroupsErrorCodeProto::GroupNotFound,
+                ),
+                err => {
+                    error!("error in handle_delete_group handler: {err}");
+                    (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        AdminGroupsErrorCodeProto::Undefined,
+                    )
+                }
+            };
+            (
+                status_code,
+                delete_group_response_proto::Result::Error(error_code.into()),
+            )
+        }
+    };
+
+    ProtoResponse::new(
+        status_code,
+        DeleteGroupResponseProto {
+            result: Some(result),
+        },
+    )
+}
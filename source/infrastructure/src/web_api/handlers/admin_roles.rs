@@ -0,0 +1,197 @@
+use axum::{
+    body::Bytes,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use nimbus_auth_application::use_cases::{
+    DeleteRoleError, DeleteRoleRequest, ListRolesError, ListRolesRequest, PutRoleError,
+    PutRoleRequest, UseCases,
+};
+use nimbus_auth_proto::proto::nimbus::admin::roles::v1::{
+    AdminRolesErrorCodeProto, DeleteRoleResponseProto, DeleteRoleSuccessResponseProto,
+    ListRolesResponseProto, ListRolesSuccessResponseProto, PutRoleRequestProto,
+    PutRoleResponseProto, PutRoleSuccessResponseProto, delete_role_response_proto,
+    list_roles_response_proto, put_role_response_proto,
+};
+use prost::Message;
+use tracing::error;
+
+use crate::{
+    converters::convert_role_into_proto,
+    web_api::{
+        extractors::authorization_extractor::Authorization, responses::proto::ProtoResponse,
+    },
+};
+
+pub async fn handle_list_roles(
+    State(use_cases): State<UseCases>,
+    Authorization(user): Authorization,
+) -> impl IntoResponse {
+    let result = use_cases.list_roles(ListRolesRequest { user }).await;
+
+    let (status_code, result) = match result {
+        Ok(response) => (
+            StatusCode::OK,
+            list_roles_response_proto::Result::Success(ListRolesSuccessResponseProto {
+                roles: response
+                    .roles
+                    .into_iter()
+                    .map(convert_role_into_proto)
+                    .collect(),
+            }),
+        ),
+        Err(err) => {
+            let (status_code, error_code) = match err {
+                ListRolesError::Forbidden(_) => {
+                    (StatusCode::FORBIDDEN, AdminRolesErrorCodeProto::Forbidden)
+                }
+                err => {
+                    error!("error in handle_list_roles handler: {err}");
+                    (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        AdminRolesErrorCodeProto::Undefined,
+                    )
+                }
+            };
+            (
+                status_code,
+                list_roles_response_proto::Result::Error(error_code.into()),
+            )
+        }
+    };
+
+    ProtoResponse::new(
+        status_code,
+        ListRolesResponseProto {
+            result: Some(result),
+        },
+    )
+}
+
+pub async fn handle_put_role(
+    State(use_cases): State<UseCases>,
+    Authorization(user): Authorization,
+    Path(role_name): Path<String>,
+    body: Bytes,
+) -> impl IntoResponse {
+    let PutRoleRequestProto { permissions } = match PutRoleRequestProto::decode(body) {
+        Ok(request) => request,
+        Err(_) => {
+            return ProtoResponse::new(
+                StatusCode::BAD_REQUEST,
+                PutRoleResponseProto {
+                    result: Some(put_role_response_proto::Result::Error(
+                        AdminRolesErrorCodeProto::WrongBodyFormat.into(),
+                    )),
+                },
+            );
+        }
+    };
+
+    let result = use_cases
+        .put_role(PutRoleRequest {
+            user,
+            role_name: &role_name,
+            permissions: &permissions,
+        })
+        .await;
+
+    let (status_code, result) = match result {
+        Ok(response) => (
+            match response.is_created {
+                true => StatusCode::CREATED,
+                false => StatusCode::OK,
+            },
+            put_role_response_proto::Result::Success(PutRoleSuccessResponseProto {
+                role: Some(convert_role_into_proto(response.role)),
+                is_created: response.is_created,
+            }),
+        ),
+        Err(err) => {
+            let (status_code, error_code) = match err {
+                PutRoleError::Forbidden(_) => {
+                    (StatusCode::FORBIDDEN, AdminRolesErrorCodeProto::Forbidden)
+                }
+                PutRoleError::InvalidRoleName(_) | PutRoleError::InvalidPermission(_) => (
+                    StatusCode::BAD_REQUEST,
+                    AdminRolesErrorCodeProto::ValidationError,
+                ),
+                err => {
+                    error!("error in handle_put_role handler: {err}");
+                    (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        AdminRolesErrorCodeProto::Undefined,
+                    )
+                }
+            };
+            (
+                status_code,
+                put_role_response_proto::Result::Error(error_code.into()),
+            )
+        }
+    };
+
+    ProtoResponse::new(
+        status_code,
+        PutRoleResponseProto {
+            result: Some(result),
+        },
+    )
+}
+
+pub async fn handle_delete_role(
+    State(use_cases): State<UseCases>,
+    Authorization(user): Authorization,
+    Path(role_name): Path<String>,
+) -> impl IntoResponse {
+    let result = use_cases
+        .delete_role(DeleteRoleRequest {
+            user,
+            role_name: &role_name,
+        })
+        .await;
+
+    let (status_code, result) = match result {
+        Ok(_) => (
+            StatusCode::OK,
+            delete_role_response_proto::Result::Success(DeleteRoleSuccessResponseProto {}),
+        ),
+        Err(err) => {
+            let (status_code, error_code) = match err {
+                DeleteRoleError::Forbidden(_) => {
+                    (StatusCode::FORBIDDEN, AdminRolesErrorCodeProto::Forbidden)
+                }
+                DeleteRoleError::InvalidRoleName(_) => (
+                    StatusCode::BAD_REQUEST,
+                    AdminRolesErrorCodeProto::ValidationError,
+                ),
+                DeleteRoleError::RoleIsNotFound { .. } => (
+                    StatusCode::NOT_FOUND,
+                    AdminRolesErrorCodeProto::RoleNotFound,
+                ),
+                DeleteRoleError::RoleIsBuiltIn { .. } => {
+                    (StatusCode::CONFLICT, AdminRolesErrorCodeProto::RoleBuiltIn)
+                }
+                err => {
+                    error!("error in handle_delete_role handler: {err}");
+                    (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        AdminRolesErrorCodeProto::Undefined,
+                    )
+                }
+            };
+            (
+                status_code,
+                delete_role_response_proto::Result::Error(error_code.into()),
+            )
+        }
+    };
+
+    ProtoResponse::new(
+        status_code,
+        DeleteRoleResponseProto {
+            result: Some(result),
+        },
+    )
+}
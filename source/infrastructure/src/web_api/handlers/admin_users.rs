@@ -0,0 +1,762 @@
+use axum::{
+    body::Bytes,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use nimbus_auth_application::use_cases::{
+    ChangeUserGroupsError, ChangeUserGroupsRequest, ChangeUserRolesError, ChangeUserRolesRequest,
+    DeleteUserError, DeleteUserRequest, GetUserError, GetUserRequest, ImpersonateUserError,
+    ImpersonateUserRequest, ListUsersError, ListUsersRequest, RevokeUserSessionsError,
+    RevokeUserSessionsRequest, SuspendUserError, SuspendUserRequest, UnsuspendUserError,
+    UnsuspendUserRequest, UpdateUserAttributesError, UpdateUserAttributesRequest, UseCases,
+};
+use nimbus_auth_proto::proto::nimbus::admin::users::v1::{
+    AdminUsersErrorCodeProto, ChangeUserGroupsRequestProto, ChangeUserGroupsResponseProto,
+    ChangeUserGroupsSuccessResponseProto, ChangeUserRolesRequestProto,
+    ChangeUserRolesResponseProto, ChangeUserRolesSuccessResponseProto, DeleteUserResponseProto,
+    DeleteUserSuccessResponseProto, GetUserResponseProto, GetUserSuccessResponseProto,
+    ImpersonateUserRequestProto, ImpersonateUserResponseProto, ImpersonateUserSuccessResponseProto,
+    ListUsersRequestProto, ListUsersResponseProto, ListUsersSuccessResponseProto,
+    RevokeUserSessionsResponseProto, RevokeUserSessionsSuccessResponseProto,
+    SuspendUserRequestProto, SuspendUserResponseProto, SuspendUserSuccessResponseProto,
+    UnsuspendUserResponseProto, UnsuspendUserSuccessResponseProto,
+    UpdateUserAttributesRequestProto, UpdateUserAttributesResponseProto,
+    UpdateUserAttributesSuccessResponseProto, UserStatusProto, change_user_groups_response_proto,
+    change_user_roles_response_proto, delete_user_response_proto, get_user_response_proto,
+    impersonate_user_response_proto, list_users_response_proto,
+    revoke_user_sessions_response_proto, suspend_user_response_proto,
+    unsuspend_user_response_proto, update_user_attributes_response_proto,
+};
+use prost::Message;
+use tracing::error;
+
+use crate::{
+    converters::{convert_user_details_into_proto, convert_user_status_from_proto},
+    web_api::{
+        extractors::authorization_extractor::Authorization, responses::proto::ProtoResponse,
+    },
+};
+
+pub async fn handle_list_users(
+    State(use_cases): State<UseCases>,
+    Authorization(user): Authorization,
+    body: Bytes,
+) -> impl IntoResponse {
+    let wrong_body_format = || {
+        ProtoResponse::new(
+            StatusCode::BAD_REQUEST,
+            ListUsersResponseProto {
+                result: Some(list_users_response_proto::Result::Error(
+                    AdminUsersErrorCodeProto::WrongBodyFormat.into(),
+                )),
+            },
+        )
+    };
+    let ListUsersRequestProto {
+        name_query,
+        role,
+        status,
+        cursor,
+        page_size,
+    } = match ListUsersRequestProto::decode(body) {
+        Ok(request) => request,
+        Err(_) => return wrong_body_format(),
+    };
+    let Ok(status) = status.map(UserStatusProto::try_from).transpose() else {
+        return wrong_body_format();
+    };
+
+    let result = use_cases
+        .list_users(ListUsersRequest {
+            user,
+            name_query: name_query.as_deref(),
+            role: role.as_deref(),
+            status: status.map(convert_user_status_from_proto),
+            cursor: cursor.as_deref(),
+            page_size: page_size.map(|page_size| page_size as usize),
+        })
+        .await;
+
+    let (status_code, result) = match result {
+        Ok(response) => (
+            StatusCode::OK,
+            list_users_response_proto::Result::Success(ListUsersSuccessResponseProto {
+                users: response
+                    .users
+                    .into_iter()
+                    .map(convert_user_details_into_proto)
+                    .collect(),
+                next_cursor: response.next_cursor,
+            }),
+        ),
+        Err(err) => {
+            let (status_code, error_code) = match err {
+                ListUsersError::Forbidden(_) => {
+                    (StatusCode::FORBIDDEN, AdminUsersErrorCodeProto::Forbidden)
+                }
+                ListUsersError::InvalidRoleName(_)
+                | ListUsersError::InvalidCursor(_)
+                | ListUsersError::InvalidPageSize { .. } => (
+                    StatusCode::BAD_REQUEST,
+                    AdminUsersErrorCodeProto::ValidationError,
+                ),
+                err => {
+                    error!("error in handle_list_users handler: {err}");
+                    (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        AdminUsersErrorCodeProto::Undefined,
+                    )
+                }
+            };
+            (
+                status_code,
+                list_users_response_proto::Result::Error(error_code.into()),
+            )
+        }
+    };
+
+    ProtoResponse::new(
+        status_code,
+        ListUsersResponseProto {
+            result: Some(result),
+        },
+    )
+}
+
+pub async fn handle_get_user(
+    State(use_cases): State<UseCases>,
+    Authorization(user): Authorization,
+    Path(user_name): Path<String>,
+) -> impl IntoResponse {
+    let result = use_cases
+        .get_user(GetUserRequest {
+            user,
+            user_name: &user_name,
+        })
+        .await;
+
+    let (status_code, result) = match result {
+        Ok(response) => (
+            StatusCode::OK,
+            get_user_response_proto::Result::Success(GetUserSuccessResponseProto {
+                user: Some(convert_user_details_into_proto(response.user)),
+            }),
+        ),
+        Err(err) => {
+            let (status_code, error_code) = match err {
+                GetUserError::Forbidden(_) => {
+                    (StatusCode::FORBIDDEN, AdminUsersErrorCodeProto::Forbidden)
+                }
+                GetUserError::InvalidUserName(_) => (
+                    StatusCode::BAD_REQUEST,
+                    AdminUsersErrorCodeProto::ValidationError,
+                ),
+                GetUserError::UserIsNotFound { .. } => (
+                    StatusCode::NOT_FOUND,
+                    AdminUsersErrorCodeProto::UserNotFound,
+                ),
+                err => {
+                    error!("error in handle_get_user handler: {err}");
+                    (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        AdminUsersErrorCodeProto::Undefined,
+                    )
+                }
+            };
+            (
+                status_code,
+                get_user_response_proto::Result::Error(error_code.into()),
+            )
+        }
+    };
+
+    ProtoResponse::new(
+        status_code,
+        GetUserResponseProto {
+            result: Some(result),
+        },
+    )
+}
+
+pub async fn handle_change_user_roles(
+    State(use_cases): State<UseCases>,
+    Authorization(user): Authorization,
+    Path(user_name): Path<String>,
+    body: Bytes,
+) -> impl IntoResponse {
+    let ChangeUserRolesRequestProto {
+        granted_roles,
+        revoked_roles,
+        revoke_sessions,
+    } = match ChangeUserRolesRequestProto::decode(body) {
+        Ok(request) => request,
+        Err(_) => {
+            return ProtoResponse::new(
+                StatusCode::BAD_REQUEST,
+                ChangeUserRolesResponseProto {
+                    result: Some(change_user_roles_response_proto::Result::Error(
+                        AdminUsersErrorCodeProto::WrongBodyFormat.into(),
+                    )),
+                },
+            );
+        }
+    };
+
+    let result = use_cases
+        .change_user_roles(ChangeUserRolesRequest {
+            user,
+            user_name: &user_name,
+            granted_roles: &granted_roles,
+            revoked_roles: &revoked_roles,
+            revoke_sessions,
+        })
+        .await;
+
+    let (status_code, result) = match result {
+        Ok(response) => (
+            StatusCode::OK,
+            change_user_roles_response_proto::Result::Success(
+                ChangeUserRolesSuccessResponseProto {
+                    user: Some(convert_user_details_into_proto(response.user)),
+                    revoked_sessions_count: response.revoked_sessions_count as u64,
+                },
+            ),
+        ),
+        Err(err) => {
+            let (status_code, error_code) = match err {
+                ChangeUserRolesError::Forbidden(_) => {
+                    (StatusCode::FORBIDDEN, AdminUsersErrorCodeProto::Forbidden)
+                }
+                ChangeUserRolesError::InvalidUserName(_)
+                | ChangeUserRolesError::InvalidRoleName(_) => (
+                    StatusCode::BAD_REQUEST,
+                    AdminUsersErrorCodeProto::ValidationError,
+                ),
+                ChangeUserRolesError::UserIsNotFound { .. } => (
+                    StatusCode::NOT_FOUND,
+                    AdminUsersErrorCodeProto::UserNotFound,
+                ),
+                ChangeUserRolesError::RoleIsNotFound { .. } => (
+                    StatusCode::NOT_FOUND,
+                    AdminUsersErrorCodeProto::RoleNotFound,
+                ),
+                ChangeUserRolesError::UserIsDeleted { .. } => {
+                    (StatusCode::CONFLICT, AdminUsersErrorCodeProto::UserDeleted)
+                }
+                err => {
+                    error!("error in handle_change_user_roles handler: {err}");
+                    (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        AdminUsersErrorCodeProto::Undefined,
+                    )
+                }
+            };
+            (
+                status_code,
+                change_user_roles_response_proto::Result::Error(error_code.into()),
+            )
+        }
+    };
+
+    ProtoResponse::new(
+        status_code,
+        ChangeUserRolesResponseProto {
+            result: Some(result),
+        },
+    )
+}
+
+pub async fn handle_change_user_groups(
+    State(use_cases): State<UseCases>,
+    Authorization(user): Authorization,
+    Path(user_name): Path<String>,
+    body: Bytes,
+) -> impl IntoResponse {
+    let ChangeUserGroupsRequestProto {
+        joined_groups,
+        left_groups,
+    } = match ChangeUserGroupsRequestProto::decode(body) {
+        Ok(request) => request,
+        Err(_) => {
+            return ProtoResponse::new(
+                StatusCode::BAD_REQUEST,
+                ChangeUserGroupsResponseProto {
+                    result: Some(change_user_groups_response_proto::Result::Error(
+                        AdminUsersErrorCodeProto::WrongBodyFormat.into(),
+                    )),
+                },
+            );
+        }
+    };
+
+    let result = use_cases
+        .change_user_groups(ChangeUserGroupsRequest {
+            user,
+            user_name: &user_name,
+            joined_groups: &joined_groups,
+            left_groups: &left_groups,
+        })
+        .await;
+
+    let (status_code, result) = match result {
+        Ok(response) => (
+            StatusCode::OK,
+            change_user_groups_response_proto::Result::Success(
+                ChangeUserGroupsSuccessResponseProto {
+                    user: Some(convert_user_details_into_proto(response.user)),
+                },
+            ),
+        ),
+        Err(err) => {
+            let (status_code, error_code) = match err {
+                ChangeUserGroupsError::Forbidden(_) => {
+                    (StatusCode::FORBIDDEN, AdminUsersErrorCodeProto::Forbidden)
+                }
+                ChangeUserGroupsError::InvalidUserName(_)
+                | ChangeUserGroupsError::InvalidGroupName(_) => (
+                    StatusCode::BAD_REQUEST,
+                    AdminUsersErrorCodeProto::ValidationError,
+                ),
+                ChangeUserGroupsError::UserIsNotFound { .. } => (
+                    StatusCode::NOT_FOUND,
+                    AdminUsersErrorCodeProto::UserNotFound,
+                ),
+                ChangeUserGroupsError::GroupIsNotFound { .. } => (
+                    StatusCode::NOT_FOUND,
+                    AdminUsersErrorCodeProto::GroupNotFound,
+                ),
+                ChangeUserGroupsError::UserIsDeleted { .. } => {
+                    (StatusCode::CONFLICT, AdminUsersErrorCodeProto::UserDeleted)
+                }
+                err => {
+                    error!("error in handle_change_user_groups handler: {err}");
+                    (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        AdminUsersErrorCodeProto::Undefined,
+                    )
+                }
+            };
+            (
+                status_code,
+                change_user_groups_response_proto::Result::Error(error_code.into()),
+            )
+        }
+    };
+
+    ProtoResponse::new(
+        status_code,
+        ChangeUserGroupsResponseProto {
+            result: Some(result),
+        },
+    )
+}
+
+pub async fn handle_update_user_attributes(
+    State(use_cases): State<UseCases>,
+    Authorization(user): Authorization,
+    Path(user_name): Path<String>,
+    body: Bytes,
+) -> impl IntoResponse {
+    let UpdateUserAttributesRequestProto { attributes_json } =
+        match UpdateUserAttributesRequestProto::decode(body) {
+            Ok(request) => request,
+            Err(_) => {
+                return ProtoResponse::new(
+                    StatusCode::BAD_REQUEST,
+                    UpdateUserAttributesResponseProto {
+                        result: Some(update_user_attributes_response_proto::Result::Error(
+                            AdminUsersErrorCodeProto::WrongBodyFormat.into(),
+                        )),
+                    },
+                );
+            }
+        };
+
+    let result = use_cases
+        .update_user_attributes(UpdateUserAttributesRequest {
+            user,
+            user_name: &user_name,
+            attributes_json: &attributes_json,
+        })
+        .await;
+
+    let (status_code, result) = match result {
+        Ok(response) => (
+            StatusCode::OK,
+            update_user_attributes_response_proto::Result::Success(
+                UpdateUserAttributesSuccessResponseProto {
+                    user: Some(convert_user_details_into_proto(response.user)),
+                },
+            ),
+        ),
+        Err(err) => {
+            let (status_code, error_code) = match err {
+                UpdateUserAttributesError::Forbidden(_) => {
+                    (StatusCode::FORBIDDEN, AdminUsersErrorCodeProto::Forbidden)
+                }
+                UpdateUserAttributesError::InvalidUserName(_)
+                | UpdateUserAttributesError::InvalidAttributes(_) => (
+                    StatusCode::BAD_REQUEST,
+                    AdminUsersErrorCodeProto::ValidationError,
+                ),
+                UpdateUserAttributesError::UserIsNotFound { .. } => (
+                    StatusCode::NOT_FOUND,
+                    AdminUsersErrorCodeProto::UserNotFound,
+                ),
+                UpdateUserAttributesError::UserIsDeleted { .. } => {
+                    (StatusCode::CONFLICT, AdminUsersErrorCodeProto::UserDeleted)
+                }
+                err => {
+                    error!("error in handle_update_user_attributes handler: {err}");
+                    (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        AdminUsersErrorCodeProto::Undefined,
+                    )
+                }
+            };
+            (
+                status_code,
+                update_user_attributes_response_proto::Result::Error(error_code.into()),
+            )
+        }
+    };
+
+    ProtoResponse::new(
+        status_code,
+        UpdateUserAttributesResponseProto {
+            result: Some(result),
+        },
+    )
+}
+
+pub async fn handle_suspend_user(
+    State(use_cases): State<UseCases>,
+    Authorization(user): Authorization,
+    Path(user_name): Path<String>,
+    body: Bytes,
+) -> impl IntoResponse {
+    let SuspendUserRequestProto {
+        reason,
+        suspended_until_unix_timestamp,
+    } = match SuspendUserRequestProto::decode(body) {
+        Ok(request) => request,
+        Err(_) => {
+            return ProtoResponse::new(
+                StatusCode::BAD_REQUEST,
+                SuspendUserResponseProto {
+                    result: Some(suspend_user_response_proto::Result::Error(
+                        AdminUsersErrorCodeProto::WrongBodyFormat.into(),
+                    )),
+                },
+            );
+        }
+    };
+
+    let result = use_cases
+        .suspend_user(SuspendUserRequest {
+            user,
+            user_name: &user_name,
+            reason: &reason,
+            suspended_until_unix_timestamp,
+        })
+        .await;
+
+    let (status_code, result) = match result {
+        Ok(response) => (
+            StatusCode::OK,
+            suspend_user_response_proto::Result::Success(SuspendUserSuccessResponseProto {
+                revoked_sessions_count: response.revoked_sessions_count as u64,
+            }),
+        ),
+        Err(err) => {
+            let (status_code, error_code) = match err {
+                SuspendUserError::Forbidden(_) => {
+                    (StatusCode::FORBIDDEN, AdminUsersErrorCodeProto::Forbidden)
+                }
+                SuspendUserError::InvalidUserName(_)
+                | SuspendUserError::InvalidSuspendedUntil(_) => (
+                    StatusCode::BAD_REQUEST,
+                    AdminUsersErrorCodeProto::ValidationError,
+                ),
+                SuspendUserError::UserIsNotFound { .. } => (
+                    StatusCode::NOT_FOUND,
+                    AdminUsersErrorCodeProto::UserNotFound,
+                ),
+                SuspendUserError::UserIsDeleted { .. } => {
+                    (StatusCode::CONFLICT, AdminUsersErrorCodeProto::UserDeleted)
+                }
+                err => {
+                    error!("error in handle_suspend_user handler: {err}");
+                    (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        AdminUsersErrorCodeProto::Undefined,
+                    )
+                }
+            };
+            (
+                status_code,
+                suspend_user_response_proto::Result::Error(error_code.into()),
+            )
+        }
+    };
+
+    ProtoResponse::new(
+        status_code,
+        SuspendUserResponseProto {
+            result: Some(result),
+        },
+    )
+}
+
+pub async fn handle_delete_user(
+    State(use_cases): State<UseCases>,
+    Authorization(user): Authorization,
+    Path(user_name): Path<String>,
+) -> impl IntoResponse {
+    let result = use_cases
+        .delete_user(DeleteUserRequest {
+            user,
+            user_name: &user_name,
+        })
+        .await;
+
+    let (status_code, result) = match result {
+        Ok(response) => (
+            StatusCode::OK,
+            delete_user_response_proto::Result::Success(DeleteUserSuccessResponseProto {
+                revoked_sessions_count: response.revoked_sessions_count as u64,
+            }),
+        ),
+        Err(err) => {
+            let (status_code, error_code) = match err {
+                DeleteUserError::Forbidden(_) => {
+                    (StatusCode::FORBIDDEN, AdminUsersErrorCodeProto::Forbidden)
+                }
+                DeleteUserError::InvalidUserName(_) => (
+                    StatusCode::BAD_REQUEST,
+                    AdminUsersErrorCodeProto::ValidationError,
+                ),
+                DeleteUserError::UserIsNotFound { .. } => (
+                    StatusCode::NOT_FOUND,
+                    AdminUsersErrorCodeProto::UserNotFound,
+                ),
+                DeleteUserError::UserIsAlreadyDeleted { .. } => {
+                    (StatusCode::CONFLICT, AdminUsersErrorCodeProto::UserDeleted)
+                }
+                err => {
+                    error!("error in handle_delete_user handler: {err}");
+                    (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        AdminUsersErrorCodeProto::Undefined,
+                    )
+                }
+            };
+            (
+                status_code,
+                delete_user_response_proto::Result::Error(error_code.into()),
+            )
+        }
+    };
+
+    ProtoResponse::new(
+        status_code,
+        DeleteUserResponseProto {
+            result: Some(result),
+        },
+    )
+}
+
+pub async fn handle_unsuspend_user(
+    State(use_cases): State<UseCases>,
+    Authorization(user): Authorization,
+    Path(user_name): Path<String>,
+) -> impl IntoResponse {
+    let result = use_cases
+        .unsuspend_user(UnsuspendUserRequest {
+            user,
+            user_name: &user_name,
+        })
+        .await;
+
+    let (status_code, result) = match result {
+        Ok(_) => (
+            StatusCode::OK,
+            unsuspend_user_response_proto::Result::Success(UnsuspendUserSuccessResponseProto {}),
+        ),
+        Err(err) => {
+            let (status_code, error_code) = match err {
+                UnsuspendUserError::Forbidden(_) => {
+                    (StatusCode::FORBIDDEN, AdminUsersErrorCodeProto::Forbidden)
+                }
+                UnsuspendUserError::InvalidUserName(_) => (
+                    StatusCode::BAD_REQUEST,
+                    AdminUsersErrorCodeProto::ValidationError,
+                ),
+                UnsuspendUserError::UserIsNotFound { .. } => (
+                    StatusCode::NOT_FOUND,
+                    AdminUsersErrorCodeProto::UserNotFound,
+                ),
+                UnsuspendUserError::UserIsNotSuspended { .. } => (
+                    StatusCode::CONFLICT,
+                    AdminUsersErrorCodeProto::UserNotSuspended,
+                ),
+                err => {
+                    error!("error in handle_unsuspend_user handler: {err}");
+                    (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        AdminUsersErrorCodeProto::Undefined,
+                    )
+                }
+            };
+            (
+                status_code,
+                unsuspend_user_response_proto::Result::Error(error_code.into()),
+            )
+        }
+    };
+
+    ProtoResponse::new(
+        status_code,
+        UnsuspendUserResponseProto {
+            result: Some(result),
+        },
+    )
+}
+
+pub async fn handle_revoke_user_sessions(
+    State(use_cases): State<UseCases>,
+    Authorization(user): Authorization,
+    Path(user_name): Path<String>,
+) -> impl IntoResponse {
+    let result = use_cases
+        .revoke_user_sessions(RevokeUserSessionsRequest {
+            user,
+            user_name: &user_name,
+        })
+        .await;
+
+    let (status_code, result) = match result {
+        Ok(response) => (
+            StatusCode::OK,
+            revoke_user_sessions_response_proto::Result::Success(
+                RevokeUserSessionsSuccessResponseProto {
+                    revoked_sessions_count: response.revoked_sessions_count as u64,
+                },
+            ),
+        ),
+        Err(err) => {
+            let (status_code, error_code) = match err {
+                RevokeUserSessionsError::Forbidden(_) => {
+                    (StatusCode::FORBIDDEN, AdminUsersErrorCodeProto::Forbidden)
+                }
+                RevokeUserSessionsError::InvalidUserName(_) => (
+                    StatusCode::BAD_REQUEST,
+                    AdminUsersErrorCodeProto::ValidationError,
+                ),
+                RevokeUserSessionsError::UserIsNotFound { .. } => (
+                    StatusCode::NOT_FOUND,
+                    AdminUsersErrorCodeProto::UserNotFound,
+                ),
+                err => {
+                    error!("error in handle_revoke_user_sessions handler: {err}");
+                    (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        AdminUsersErrorCodeProto::Undefined,
+                    )
+                }
+            };
+            (
+                status_code,
+                revoke_user_sessions_response_proto::Result::Error(error_code.into()),
+            )
+        }
+    };
+
+    ProtoResponse::new(
+        status_code,
+        RevokeUserSessionsResponseProto {
+            result: Some(result),
+        },
+    )
+}
+
+pub async fn handle_impersonate_user(
+    State(use_cases): State<UseCases>,
+    Authorization(user): Authorization,
+    Path(user_name): Path<String>,
+    body: Bytes,
+) -> impl IntoResponse {
+    let ImpersonateUserRequestProto { reason } = match ImpersonateUserRequestProto::decode(body) {
+        Ok(request) => request,
+        Err(_) => {
+            return ProtoResponse::new(
+                StatusCode::BAD_REQUEST,
+                ImpersonateUserResponseProto {
+                    result: Some(impersonate_user_response_proto::Result::Error(
+                        AdminUsersErrorCodeProto::WrongBodyFormat.into(),
+                    )),
+                },
+            );
+        }
+    };
+
+    let result = use_cases
+        .impersonate_user(ImpersonateUserRequest {
+            user,
+            user_name: &user_name,
+            reason: &reason,
+        })
+        .await;
+
+    let (status_code, result) = match result {
+        Ok(response) => (
+            StatusCode::OK,
+            impersonate_user_response_proto::Result::Success(ImpersonateUserSuccessResponseProto {
+                impersonation_id: response.impersonation_id,
+                access_token: response.access_token.signed_access_token,
+                access_token_expires_at_unix_timestamp: response
+                    .access_token
+                    .signed_access_token_expires_at_unix_timestamp,
+            }),
+        ),
+        Err(err) => {
+            let (status_code, error_code) = match err {
+                ImpersonateUserError::Forbidden(_) => {
+                    (StatusCode::FORBIDDEN, AdminUsersErrorCodeProto::Forbidden)
+                }
+                ImpersonateUserError::InvalidUserName(_)
+                | ImpersonateUserError::Impersonation(_) => (
+                    StatusCode::BAD_REQUEST,
+                    AdminUsersErrorCodeProto::ValidationError,
+                ),
+                ImpersonateUserError::UserIsNotFound { .. } => (
+                    StatusCode::NOT_FOUND,
+                    AdminUsersErrorCodeProto::UserNotFound,
+                ),
+                ImpersonateUserError::UserIsNotActive { .. } => (
+                    StatusCode::CONFLICT,
+                    AdminUsersErrorCodeProto::UserNotActive,
+                ),
+                err => {
+                    error!("error in handle_impersonate_user handler: {err}");
+                    (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        AdminUsersErrorCodeProto::Undefined,
+                    )
+                }
+            };
+            (
+                status_code,
+                impersonate_user_response_proto::Result::Error(error_code.into()),
+            )
+        }
+    };
+
+    ProtoResponse::new(
+        status_code,
+        ImpersonateUserResponseProto {
+            result: Some(result),
+        },
+    )
+}
@@ -0,0 +1,184 @@
+use axum::{
+    body::Bytes,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use nimbus_auth_application::use_cases::{
+    CreateApiKeyError, CreateApiKeyRequest, ListApiKeysRequest, RevokeApiKeyError,
+    RevokeApiKeyRequest, UseCases,
+};
+use nimbus_auth_proto::proto::nimbus::auth::api_keys::v1::{
+    ApiKeysErrorCodeProto, CreateApiKeyRequestProto, CreateApiKeyResponseProto,
+    CreateApiKeySuccessResponseProto, ListApiKeysResponseProto, ListApiKeysSuccessResponseProto,
+    RevokeApiKeyResponseProto, RevokeApiKeySuccessResponseProto, create_api_key_response_proto,
+    list_api_keys_response_proto, revoke_api_key_response_proto,
+};
+use prost::Message;
+use tracing::error;
+
+use crate::{
+    converters::convert_api_key_into_proto,
+    web_api::{
+        extractors::authorization_extractor::Authorization, responses::proto::ProtoResponse,
+    },
+};
+
+pub async fn handle_create_api_key(
+    State(use_cases): State<UseCases>,
+    Authorization(user): Authorization,
+    body: Bytes,
+) -> impl IntoResponse {
+    let CreateApiKeyRequestProto {
+        name,
+        scopes,
+        expires_in_seconds,
+    } = match CreateApiKeyRequestProto::decode(body) {
+        Ok(request) => request,
+        Err(_) => {
+            return ProtoResponse::new(
+                StatusCode::BAD_REQUEST,
+                CreateApiKeyResponseProto {
+                    result: Some(create_api_key_response_proto::Result::Error(
+                        ApiKeysErrorCodeProto::WrongBodyFormat.into(),
+                    )),
+                },
+            );
+        }
+    };
+
+    let result = use_cases
+        .create_api_key(CreateApiKeyRequest {
+            user,
+            name: &name,
+            scopes: &scopes,
+            expires_in_seconds,
+        })
+        .await;
+
+    let (status_code, result) = match result {
+        Ok(response) => (
+            StatusCode::CREATED,
+            create_api_key_response_proto::Result::Success(CreateApiKeySuccessResponseProto {
+                api_key: Some(convert_api_key_into_proto(response.api_key)),
+                token: response.token.to_string(),
+            }),
+        ),
+        Err(err) => {
+            let (status_code, error_code) = match err {
+                CreateApiKeyError::InvalidName(_) | CreateApiKeyError::InvalidScope(_) => (
+                    StatusCode::BAD_REQUEST,
+                    ApiKeysErrorCodeProto::ValidationError,
+                ),
+                CreateApiKeyError::Forbidden(_) | CreateApiKeyError::ApiKeyForbidden(_) => {
+                    (StatusCode::FORBIDDEN, ApiKeysErrorCodeProto::Forbidden)
+                }
+                CreateApiKeyError::ScopeIsNotGranted { .. } => (
+                    StatusCode::FORBIDDEN,
+                    ApiKeysErrorCodeProto::ScopeNotGranted,
+                ),
+                err => {
+                    error!("error in handle_create_api_key handler: {err}");
+                    (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        ApiKeysErrorCodeProto::Undefined,
+                    )
+                }
+            };
+            (
+                status_code,
+                create_api_key_response_proto::Result::Error(error_code.into()),
+            )
+        }
+    };
+
+    ProtoResponse::new(
+        status_code,
+        CreateApiKeyResponseProto {
+            result: Some(result),
+        },
+    )
+}
+
+pub async fn handle_list_api_keys(
+    State(use_cases): State<UseCases>,
+    Authorization(user): Authorization,
+) -> impl IntoResponse {
+    let result = use_cases.list_api_keys(ListApiKeysRequest { user }).await;
+
+    let (status_code, result) = match result {
+        Ok(response) => (
+            StatusCode::OK,
+            list_api_keys_response_proto::Result::Success(ListApiKeysSuccessResponseProto {
+                api_keys: response
+                    .api_keys
+                    .into_iter()
+                    .map(convert_api_key_into_proto)
+                    .collect(),
+            }),
+        ),
+        Err(err) => {
+            error!("error in handle_list_api_keys handler: {err}");
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                list_api_keys_response_proto::Result::Error(
+                    ApiKeysErrorCodeProto::Undefined.into(),
+                ),
+            )
+        }
+    };
+
+    ProtoResponse::new(
+        status_code,
+        ListApiKeysResponseProto {
+            result: Some(result),
+        },
+    )
+}
+
+pub async fn handle_revoke_api_key(
+    State(use_cases): State<UseCases>,
+    Authorization(user): Authorization,
+    Path(api_key_id): Path<String>,
+) -> impl IntoResponse {
+    let result = use_cases
+        .revoke_api_key(RevokeApiKeyRequest {
+            user,
+            api_key_id: &api_key_id,
+        })
+        .await;
+
+    let (status_code, result) = match result {
+        Ok(response) => (
+            StatusCode::OK,
+            revoke_api_key_response_proto::Result::Success(RevokeApiKeySuccessResponseProto {
+                api_key: Some(convert_api_key_into_proto(response.api_key)),
+            }),
+        ),
+        Err(err) => {
+            let (status_code, error_code) = match err {
+                RevokeApiKeyError::ApiKeyIsNotFound => {
+                    (StatusCode::NOT_FOUND, ApiKeysErrorCodeProto::ApiKeyNotFound)
+                }
+                err => {
+                    error!("error in handle_revoke_api_key handler: {err}");
+                    (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        ApiKeysErrorCodeProto::Undefined,
+                    )
+                }
+            };
+            (
+                status_code,
+                revoke_api_key_response_proto::Result::Error(error_code.into()),
+            )
+        }
+    };
+
+    ProtoResponse::new(
+        status_code,
+        RevokeApiKeyResponseProto {
+            result: Some(result),
+        },
+    )
+}
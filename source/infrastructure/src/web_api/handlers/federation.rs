@@ -0,0 +1,478 @@
+use axum::{
+    body::Bytes,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use axum_extra::extract::CookieJar;
+use nimbus_auth_application::{
+    services::identity_provider::errors::IdentityProviderError,
+    use_cases::{
+        FederatedAuthorizeError, FederatedAuthorizeRequest, FederatedAuthorizeResponse,
+        FederatedSignInError, FederatedSignInRequest, LinkExternalIdentityError,
+        LinkExternalIdentityRequest, ListExternalIdentitiesRequest,
+        RedeemFederatedAuthorizationError, UnlinkExternalIdentityError,
+        UnlinkExternalIdentityRequest, UseCases,
+    },
+};
+use nimbus_auth_proto::proto::nimbus::auth::federation::v1::{
+    FederatedAuthorizeResponseProto, FederatedAuthorizeSuccessResponseProto,
+    FederatedSignInRequestProto, FederatedSignInResponseProto, FederatedSignInSuccessResponseProto,
+    FederationErrorCodeProto, LinkExternalIdentityRequestProto, LinkExternalIdentityResponseProto,
+    LinkExternalIdentitySuccessResponseProto, ListExternalIdentitiesResponseProto,
+    ListExternalIdentitiesSuccessResponseProto, UnlinkExternalIdentityResponseProto,
+    UnlinkExternalIdentitySuccessResponseProto, federated_authorize_response_proto,
+    federated_sign_in_response_proto, link_external_identity_response_proto,
+    list_external_identities_response_proto, unlink_external_identity_response_proto,
+};
+use nimbus_auth_shared::constants::FEDERATED_STATE_COOKIE_NAME;
+use prost::Message;
+use tracing::error;
+
+use crate::{
+    converters::{
+        convert_access_token_into_proto, convert_external_identity_into_proto,
+        convert_user_into_proto,
+    },
+    web_api::{
+        extractors::{authorization_extractor::Authorization, client_extractor::Client},
+        responses::proto::ProtoResponse,
+    },
+};
+
+pub async fn handle_federated_authorize(
+    State(use_cases): State<UseCases>,
+    Path(provider): Path<String>,
+) -> impl IntoResponse {
+    let result = use_cases
+        .federated_authorize(FederatedAuthorizeRequest {
+            provider: &provider,
+            user: None,
+        })
+        .await;
+
+    convert_federated_authorize_result(result, "handle_federated_authorize")
+}
+
+pub async fn handle_authorize_external_identity(
+    State(use_cases): State<UseCases>,
+    Authorization(user): Authorization,
+    Path(provider): Path<String>,
+) -> impl IntoResponse {
+    let result = use_cases
+        .federated_authorize(FederatedAuthorizeRequest {
+            provider: &provider,
+            user: Some(user),
+        })
+        .await;
+
+    convert_federated_authorize_result(result, "handle_authorize_external_identity")
+}
+
+fn convert_federated_authorize_result(
+    result: Result<FederatedAuthorizeResponse, FederatedAuthorizeError>,
+    handler: &str,
+) -> ProtoResponse<FederatedAuthorizeResponseProto> {
+    let (status_code, result, state) = match result {
+        Ok(response) => (
+            StatusCode::OK,
+            federated_authorize_response_proto::Result::Success(
+                FederatedAuthorizeSuccessResponseProto {
+                    authorization_url: response.authorization_url,
+                },
+            ),
+            Some(response.state),
+        ),
+        Err(err) => {
+            let (status_code, error_code) = match err {
+                FederatedAuthorizeError::ProviderIsNotFound { .. } => (
+                    StatusCode::NOT_FOUND,
+                    FederationErrorCodeProto::ProviderNotFound,
+                ),
+                FederatedAuthorizeError::Forbidden(_) => {
+                    (StatusCode::FORBIDDEN, FederationErrorCodeProto::Forbidden)
+                }
+                FederatedAuthorizeError::IdentityProvider(err) => {
+                    error!("identity provider error in {handler} handler: {err}");
+                    (
+                        StatusCode::BAD_GATEWAY,
+                        FederationErrorCodeProto::IdentityProviderUnavailable,
+                    )
+                }
+                err => {
+                    error!("error in {handler} handler: {err}");
+                    (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        FederationErrorCodeProto::Undefined,
+                    )
+                }
+            };
+            (
+                status_code,
+                federated_authorize_response_proto::Result::Error(error_code.into()),
+                None,
+            )
+        }
+    };
+
+    let response = ProtoResponse::new(
+        status_code,
+        FederatedAuthorizeResponseProto {
+            result: Some(result),
+        },
+    );
+    match state {
+        Some(state) => response.with_federated_state_cookie(&state),
+        None => response,
+    }
+}
+
+pub async fn handle_federated_signin(
+    State(use_cases): State<UseCases>,
+    Client(client_type): Client,
+    Path(provider): Path<String>,
+    cookie_jar: CookieJar,
+    body: Bytes,
+) -> impl IntoResponse {
+    let FederatedSignInRequestProto {
+        code,
+        state,
+        audiences,
+    } = match FederatedSignInRequestProto::decode(body) {
+        Ok(request) => request,
+        Err(_) => {
+            return ProtoResponse::new(
+                StatusCode::BAD_REQUEST,
+                FederatedSignInResponseProto {
+                    result: Some(federated_sign_in_response_proto::Result::Error(
+                        FederationErrorCodeProto::WrongBodyFormat.into(),
+                    )),
+                },
+            );
+        }
+    };
+
+    if !is_state_of_client(&cookie_jar, &state) {
+        return ProtoResponse::new(
+            StatusCode::BAD_REQUEST,
+            FederatedSignInResponseProto {
+                result: Some(federated_sign_in_response_proto::Result::Error(
+                    FederationErrorCodeProto::InvalidState.into(),
+                )),
+            },
+        );
+    }
+
+    let result = use_cases
+        .federated_signin(FederatedSignInRequest {
+            provider: &provider,
+            code: &code,
+            state: &state,
+            audiences: &audiences,
+        })
+        .await;
+
+    let error_code = match result {
+        Ok(response) => {
+            match ProtoResponse::new(
+                StatusCode::OK,
+                FederatedSignInResponseProto {
+                    result: Some(federated_sign_in_response_proto::Result::Success(
+                        FederatedSignInSuccessResponseProto {
+                            user: Some(convert_user_into_proto(response.user)),
+                            access_token: Some(convert_access_token_into_proto(
+                                response.access_token,
+                            )),
+                        },
+                    )),
+                },
+            )
+            .with_session_headers(client_type, &response.session)
+            {
+                Ok(response_with_session_headers) => return response_with_session_headers,
+                Err(err) => {
+                    error!("internal error in handle_federated_signin: {err}");
+                    (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        FederationErrorCodeProto::Undefined,
+                    )
+                }
+            }
+        }
+        Err(err) => match err {
+            FederatedSignInError::ProviderIsNotFound { .. } => (
+                StatusCode::NOT_FOUND,
+                FederationErrorCodeProto::ProviderNotFound,
+            ),
+            FederatedSignInError::InvalidAudiences(_)
+            | FederatedSignInError::UserNameIsMissing
+            | FederatedSignInError::InvalidUserName(_) => (
+                StatusCode::BAD_REQUEST,
+                FederationErrorCodeProto::ValidationError,
+            ),
+            FederatedSignInError::UserNameIsTaken { .. } => (
+                StatusCode::CONFLICT,
+                FederationErrorCodeProto::UserNameTaken,
+            ),
+            FederatedSignInError::UserIsSuspended { .. } => (
+                StatusCode::FORBIDDEN,
+                FederationErrorCodeProto::UserSuspended,
+            ),
+            FederatedSignInError::UserIsDeleted { .. } => {
+                (StatusCode::FORBIDDEN, FederationErrorCodeProto::UserDeleted)
+            }
+            FederatedSignInError::RedeemFederatedAuthorization(err) => {
+                convert_redeem_error(err, "handle_federated_signin")
+            }
+            err => {
+                error!("internal error in handle_federated_signin: {err}");
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    FederationErrorCodeProto::Undefined,
+                )
+            }
+        },
+    };
+
+    let (status_code, error_code) = error_code;
+    ProtoResponse::new(
+        status_code,
+        FederatedSignInResponseProto {
+            result: Some(federated_sign_in_response_proto::Result::Error(
+                error_code.into(),
+            )),
+        },
+    )
+}
+
+pub async fn handle_list_external_identities(
+    State(use_cases): State<UseCases>,
+    Authorization(user): Authorization,
+) -> impl IntoResponse {
+    let result = use_cases
+        .list_external_identities(ListExternalIdentitiesRequest { user })
+        .await;
+
+    let (status_code, result) = match result {
+        Ok(response) => (
+            StatusCode::OK,
+            list_external_identities_response_proto::Result::Success(
+                ListExternalIdentitiesSuccessResponseProto {
+                    identities: response
+                        .identities
+                        .into_iter()
+                        .map(convert_external_identity_into_proto)
+                        .collect(),
+                },
+            ),
+        ),
+        Err(err) => {
+            error!("error in handle_list_external_identities handler: {err}");
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                list_external_identities_response_proto::Result::Error(
+                    FederationErrorCodeProto::Undefined.into(),
+                ),
+            )
+        }
+    };
+
+    ProtoResponse::new(
+        status_code,
+        ListExternalIdentitiesResponseProto {
+            result: Some(result),
+        },
+    )
+}
+
+pub async fn handle_link_external_identity(
+    State(use_cases): State<UseCases>,
+    Authorization(user): Authorization,
+    Path(provider): Path<String>,
+    cookie_jar: CookieJar,
+    body: Bytes,
+) -> impl IntoResponse {
+    let LinkExternalIdentityRequestProto { code, state } =
+        match LinkExternalIdentityRequestProto::decode(body) {
+            Ok(request) => request,
+            Err(_) => {
+                return ProtoResponse::new(
+                    StatusCode::BAD_REQUEST,
+                    LinkExternalIdentityResponseProto {
+                        result: Some(link_external_identity_response_proto::Result::Error(
+                            FederationErrorCodeProto::WrongBodyFormat.into(),
+                        )),
+                    },
+                );
+            }
+        };
+
+    if !is_state_of_client(&cookie_jar, &state) {
+        return ProtoResponse::new(
+            StatusCode::BAD_REQUEST,
+            LinkExternalIdentityResponseProto {
+                result: Some(link_external_identity_response_proto::Result::Error(
+                    FederationErrorCodeProto::InvalidState.into(),
+                )),
+            },
+        );
+    }
+
+    let result = use_cases
+        .link_external_identity(LinkExternalIdentityRequest {
+            user,
+            provider: &provider,
+            code: &code,
+            state: &state,
+        })
+        .await;
+
+    let (status_code, result) = match result {
+        Ok(response) => (
+            StatusCode::OK,
+            link_external_identity_response_proto::Result::Success(
+                LinkExternalIdentitySuccessResponseProto {
+                    identity: Some(convert_external_identity_into_proto(response.identity)),
+                },
+            ),
+        ),
+        Err(err) => {
+            let (status_code, error_code) = match err {
+                LinkExternalIdentityError::Forbidden(_) => {
+                    (StatusCode::FORBIDDEN, FederationErrorCodeProto::Forbidden)
+                }
+                LinkExternalIdentityError::ProviderIsNotFound { .. } => (
+                    StatusCode::NOT_FOUND,
+                    FederationErrorCodeProto::ProviderNotFound,
+                ),
+                LinkExternalIdentityError::IdentityIsLinkedToAnotherUser
+                | LinkExternalIdentityError::ProviderIsAlreadyLinked { .. } => (
+                    StatusCode::CONFLICT,
+                    FederationErrorCodeProto::IdentityAlreadyLinked,
+                ),
+                LinkExternalIdentityError::RedeemFederatedAuthorization(err) => {
+                    convert_redeem_error(err, "handle_link_external_identity")
+                }
+                err => {
+                    error!("error in handle_link_external_identity handler: {err}");
+                    (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        FederationErrorCodeProto::Undefined,
+                    )
+                }
+            };
+            (
+                status_code,
+                link_external_identity_response_proto::Result::Error(error_code.into()),
+            )
+        }
+    };
+
+    ProtoResponse::new(
+        status_code,
+        LinkExternalIdentityResponseProto {
+            result: Some(result),
+        },
+    )
+}
+
+pub async fn handle_unlink_external_identity(
+    State(use_cases): State<UseCases>,
+    Authorization(user): Authorization,
+    Path(provider): Path<String>,
+) -> impl IntoResponse {
+    let result = use_cases
+        .unlink_external_identity(UnlinkExternalIdentityRequest {
+            user,
+            provider: &provider,
+        })
+        .await;
+
+    let (status_code, result) = match result {
+        Ok(_) => (
+            StatusCode::OK,
+            unlink_external_identity_response_proto::Result::Success(
+                UnlinkExternalIdentitySuccessResponseProto {},
+            ),
+        ),
+        Err(err) => {
+            let (status_code, error_code) = match err {
+                UnlinkExternalIdentityError::Forbidden(_) => {
+                    (StatusCode::FORBIDDEN, FederationErrorCodeProto::Forbidden)
+                }
+                UnlinkExternalIdentityError::IdentityIsNotFound { .. } => (
+                    StatusCode::NOT_FOUND,
+                    FederationErrorCodeProto::IdentityNotFound,
+                ),
+                UnlinkExternalIdentityError::ExternalIdentity(_) => (
+                    StatusCode::CONFLICT,
+                    FederationErrorCodeProto::IdentityProvisioned,
+                ),
+                err => {
+                    error!("error in handle_unlink_external_identity handler: {err}");
+                    (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        FederationErrorCodeProto::Undefined,
+                    )
+                }
+            };
+            (
+                status_code,
+                unlink_external_identity_response_proto::Result::Error(error_code.into()),
+            )
+        }
+    };
+
+    ProtoResponse::new(
+        status_code,
+        UnlinkExternalIdentityResponseProto {
+            result: Some(result),
+        },
+    )
+}
+
+/// Callbacks with unknown, expired or reused state and rejected codes are all treated as invalid state
+/// Guards against login CSRF, a state redeemed by another client than the one which started the authorization
+/// would sign that client in to the account of whoever completed the authorization
+fn is_state_of_client(cookie_jar: &CookieJar, state: &str) -> bool {
+    cookie_jar
+        .get(FEDERATED_STATE_COOKIE_NAME)
+        .is_some_and(|cookie| cookie.value() == state)
+}
+
+fn convert_redeem_error(
+    err: RedeemFederatedAuthorizationError,
+    handler: &str,
+) -> (StatusCode, FederationErrorCodeProto) {
+    match err {
+        RedeemFederatedAuthorizationError::StateIsNotFound
+        | RedeemFederatedAuthorizationError::FederatedAuthorization(_)
+        | RedeemFederatedAuthorizationError::IdentityProvider(
+            IdentityProviderError::CodeIsRejected,
+        ) => (
+            StatusCode::BAD_REQUEST,
+            FederationErrorCodeProto::InvalidState,
+        ),
+        RedeemFederatedAuthorizationError::InvalidIdToken(err) => {
+            error!("invalid ID token in {handler} handler: {err}");
+            (
+                StatusCode::BAD_GATEWAY,
+                FederationErrorCodeProto::InvalidIdToken,
+            )
+        }
+        RedeemFederatedAuthorizationError::IdentityProvider(err) => {
+            error!("identity provider error in {handler} handler: {err}");
+            (
+                StatusCode::BAD_GATEWAY,
+                FederationErrorCodeProto::IdentityProviderUnavailable,
+            )
+        }
+        err => {
+            error!("error in {handler} handler: {err}");
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                FederationErrorCodeProto::Undefined,
+            )
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use axum::{
+    Json,
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use base64::{Engine, prelude::BASE64_URL_SAFE_NO_PAD};
+use ed25519_dalek::{VerifyingKey, pkcs8::DecodePublicKey};
+use nimbus_auth_application::use_cases::{ListPublicKeysRequest, UseCases};
+use serde::Serialize;
+use tracing::error;
+
+#[derive(Serialize)]
+struct JwkSet {
+    keys: Vec<Jwk>,
+}
+
+/// Ed25519 public key as an octet key pair, see RFC 8037
+#[derive(Serialize)]
+struct Jwk {
+    kty: &'static str,
+    crv: &'static str,
+    alg: &'static str,
+    #[serde(rename = "use")]
+    key_use: &'static str,
+    kid: String,
+    x: String,
+}
+
+/// Serves public keys which verify access tokens of the realm
+pub async fn handle_get_jwks(State(use_cases): State<UseCases>) -> Response {
+    let result = use_cases.list_public_keys(ListPublicKeysRequest {}).await;
+
+    let public_keys = match result {
+        Ok(response) => response.public_keys,
+        Err(err) => {
+            error!("error in handle_get_jwks handler: {}", err);
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+
+    let mut keys = Vec::with_capacity(public_keys.len());
+    for public_key in public_keys {
+        let verifying_key = match VerifyingKey::from_public_key_pem(&public_key.public_key_pem) {
+            Ok(verifying_key) => verifying_key,
+            Err(err) => {
+                error!("error in handle_get_jwks handler: {}", err);
+                return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+            }
+        };
+        keys.push(Jwk {
+            kty: "OKP",
+            crv: "Ed25519",
+            alg: "EdDSA",
+            key_use: "sig",
+            kid: public_key.key_id,
+            x: BASE64_URL_SAFE_NO_PAD.encode(verifying_key.as_bytes()),
+        });
+    }
+
+    (StatusCode::OK, Json(JwkSet { keys })).into_response()
+}
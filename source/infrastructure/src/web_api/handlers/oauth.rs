@@ -0,0 +1,437 @@
+use axum::{
+    Form, Json,
+    extract::{Query, State},
+    http::{
+        HeaderMap, HeaderValue, StatusCode,
+        header::{AUTHORIZATION, CACHE_CONTROL, PRAGMA},
+    },
+    response::{IntoResponse, Redirect, Response},
+};
+use axum_extra::extract::CookieJar;
+use base64::{Engine, prelude::BASE64_STANDARD};
+use nimbus_auth_application::use_cases::{
+    ApproveDeviceAuthorizationError, ApproveDeviceAuthorizationRequest, OAuthAuthorizeError,
+    OAuthAuthorizeRequest, OAuthDeviceAuthorizeError, OAuthDeviceAuthorizeRequest, OAuthGrant,
+    OAuthTokenError, OAuthTokenRequest, PrincipalDto, RefreshError, UseCases,
+};
+use nimbus_auth_domain::{
+    entities::device_authorization::errors::DeviceAuthorizationError,
+    value_objects::access_token::errors::ExchangeAccessTokenError,
+};
+use nimbus_auth_shared::constants::SESSION_COOKIE_NAME;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use tracing::error;
+use url::Url;
+
+use crate::web_api::extractors::authorization_extractor::Authorization;
+
+const GRANT_TYPE_AUTHORIZATION_CODE: &str = "authorization_code";
+const GRANT_TYPE_REFRESH_TOKEN: &str = "refresh_token";
+const GRANT_TYPE_CLIENT_CREDENTIALS: &str = "client_credentials";
+const GRANT_TYPE_DEVICE_CODE: &str = "urn:ietf:params:oauth:grant-type:device_code";
+const GRANT_TYPE_TOKEN_EXCHANGE: &str = "urn:ietf:params:oauth:grant-type:token-exchange";
+const TOKEN_TYPE_ACCESS_TOKEN: &str = "urn:ietf:params:oauth:token-type:access_token";
+const TOKEN_TYPE_BEARER: &str = "Bearer";
+
+/// Missing parameters are left empty and rejected by the use case
+#[derive(Deserialize)]
+pub struct OAuthAuthorizeQuery {
+    #[serde(default)]
+    response_type: String,
+    #[serde(default)]
+    client_id: String,
+    #[serde(default)]
+    redirect_uri: String,
+    #[serde(default)]
+    code_challenge: String,
+    #[serde(default)]
+    code_challenge_method: String,
+    state: Option<String>,
+}
+
+/// Client may authenticate with the form instead of the basic authorization header
+#[derive(Deserialize)]
+pub struct OAuthTokenForm {
+    #[serde(default)]
+    grant_type: String,
+    #[serde(default)]
+    client_id: String,
+    client_secret: Option<String>,
+    #[serde(default)]
+    code: String,
+    #[serde(default)]
+    redirect_uri: String,
+    #[serde(default)]
+    code_verifier: String,
+    #[serde(default)]
+    refresh_token: String,
+    #[serde(default)]
+    device_code: String,
+    #[serde(default)]
+    subject_token: String,
+    #[serde(default)]
+    subject_token_type: String,
+    actor_token: Option<String>,
+    actor_token_type: Option<String>,
+    /// Single downstream service the exchanged token targets
+    audience: Option<String>,
+    /// Space separated
+    scope: Option<String>,
+}
+
+/// Client may authenticate with the form instead of the basic authorization header
+#[derive(Deserialize)]
+pub struct OAuthDeviceAuthorizeForm {
+    #[serde(default)]
+    client_id: String,
+    client_secret: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct ApproveDeviceAuthorizationForm {
+    #[serde(default)]
+    user_code: String,
+}
+
+#[derive(Serialize)]
+struct OAuthDeviceAuthorizeSuccessResponse {
+    device_code: String,
+    user_code: String,
+    verification_uri: String,
+    expires_in: i64,
+    interval: usize,
+}
+
+#[derive(Serialize)]
+struct ApproveDeviceAuthorizationSuccessResponse {
+    client_id: String,
+    client_name: String,
+}
+
+#[derive(Serialize)]
+struct OAuthTokenSuccessResponse {
+    access_token: String,
+    token_type: &'static str,
+    expires_in: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    refresh_token: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scope: Option<String>,
+    /// Only in responses of token exchange
+    #[serde(skip_serializing_if = "Option::is_none")]
+    issued_token_type: Option<&'static str>,
+}
+
+#[derive(Serialize)]
+struct OAuthErrorResponse {
+    error: &'static str,
+}
+
+/// Users are authenticated by the session cookie of their browser
+pub async fn handle_oauth_authorize(
+    State(use_cases): State<UseCases>,
+    cookies: CookieJar,
+    Query(query): Query<OAuthAuthorizeQuery>,
+) -> Response {
+    let result = use_cases
+        .oauth_authorize(OAuthAuthorizeRequest {
+            session_id: cookies
+                .get(SESSION_COOKIE_NAME)
+                .map(|cookie| cookie.value()),
+            response_type: &query.response_type,
+            client_id: &query.client_id,
+            redirect_uri: &query.redirect_uri,
+            code_challenge: &query.code_challenge,
+            code_challenge_method: &query.code_challenge_method,
+        })
+        .await;
+
+    let (redirect_uri, params) = match result {
+        Ok(response) => (response.redirect_uri, vec![("code", response.code)]),
+        Err(err) if !err.is_redirectable() => {
+            return match err {
+                OAuthAuthorizeError::OAuthClientRepository(err) => {
+                    error!("internal error in handle_oauth_authorize: {err}");
+                    (StatusCode::INTERNAL_SERVER_ERROR, "server error").into_response()
+                }
+                _ => (
+                    StatusCode::BAD_REQUEST,
+                    "client id or redirect uri is invalid",
+                )
+                    .into_response(),
+            };
+        }
+        Err(err) => {
+            let error_code = match err {
+                OAuthAuthorizeError::UnsupportedResponseType { .. } => "unsupported_response_type",
+                OAuthAuthorizeError::InvalidCodeChallenge(_) => "invalid_request",
+                OAuthAuthorizeError::LoginRequired => "login_required",
+                err => {
+                    error!("internal error in handle_oauth_authorize: {err}");
+                    "server_error"
+                }
+            };
+            (query.redirect_uri, vec![("error", error_code.to_string())])
+        }
+    };
+
+    // redirect uri is known to be registered for the client at this point
+    let Ok(mut redirect_url) = Url::parse(&redirect_uri) else {
+        return (StatusCode::BAD_REQUEST, "redirect uri is invalid").into_response();
+    };
+    redirect_url
+        .query_pairs_mut()
+        .extend_pairs(params)
+        .extend_pairs(query.state.map(|state| ("state", state)));
+
+    Redirect::to(redirect_url.as_str()).into_response()
+}
+
+pub async fn handle_oauth_token(
+    State(use_cases): State<UseCases>,
+    headers: HeaderMap,
+    Form(form): Form<OAuthTokenForm>,
+) -> Response {
+    let (client_id, client_secret) = match headers.get(AUTHORIZATION) {
+        Some(header_value) => match parse_basic_credentials(header_value) {
+            Some((client_id, client_secret)) => (client_id, Some(client_secret)),
+            None => return oauth_token_error(StatusCode::UNAUTHORIZED, "invalid_client"),
+        },
+        None => (form.client_id, form.client_secret),
+    };
+
+    let is_token_exchange = form.grant_type == GRANT_TYPE_TOKEN_EXCHANGE;
+    let audiences = Vec::from_iter(form.audience);
+    let grant = match form.grant_type.as_str() {
+        GRANT_TYPE_AUTHORIZATION_CODE => OAuthGrant::AuthorizationCode {
+            code: &form.code,
+            redirect_uri: &form.redirect_uri,
+            code_verifier: &form.code_verifier,
+        },
+        GRANT_TYPE_REFRESH_TOKEN => OAuthGrant::RefreshToken {
+            refresh_token: &form.refresh_token,
+        },
+        GRANT_TYPE_CLIENT_CREDENTIALS => OAuthGrant::ClientCredentials {
+            scopes: form
+                .scope
+                .as_deref()
+                .map(|scope| scope.split_whitespace().collect()),
+        },
+        GRANT_TYPE_DEVICE_CODE => OAuthGrant::DeviceCode {
+            device_code: &form.device_code,
+        },
+        GRANT_TYPE_TOKEN_EXCHANGE => {
+            // only access tokens of this server are exchanged
+            if form.subject_token_type != TOKEN_TYPE_ACCESS_TOKEN
+                || (form.actor_token.is_some()
+                    && form.actor_token_type.as_deref() != Some(TOKEN_TYPE_ACCESS_TOKEN))
+            {
+                return oauth_token_error(StatusCode::BAD_REQUEST, "invalid_request");
+            }
+            OAuthGrant::TokenExchange {
+                subject_token: &form.subject_token,
+                actor_token: form.actor_token.as_deref(),
+                audiences: &audiences,
+                scopes: form
+                    .scope
+                    .as_deref()
+                    .map(|scope| scope.split_whitespace().collect()),
+            }
+        }
+        _ => return oauth_token_error(StatusCode::BAD_REQUEST, "unsupported_grant_type"),
+    };
+
+    let result = use_cases
+        .oauth_token(OAuthTokenRequest {
+            client_id: &client_id,
+            client_secret: client_secret.as_deref(),
+            grant,
+        })
+        .await;
+
+    match result {
+        Ok(response) => no_store(
+            Json(OAuthTokenSuccessResponse {
+                access_token: response.access_token.signed_access_token,
+                token_type: TOKEN_TYPE_BEARER,
+                expires_in: (response
+                    .access_token
+                    .signed_access_token_expires_at_unix_timestamp
+                    - OffsetDateTime::now_utc().unix_timestamp())
+                .max(0),
+                refresh_token: response.session.map(|session| session.session_id),
+                scope: match response.principal {
+                    PrincipalDto::Client(client) => Some(client.scopes.join(" ")),
+                    PrincipalDto::User(user) if is_token_exchange => {
+                        Some(user.permissions.join(" "))
+                    }
+                    PrincipalDto::User(_) => None,
+                },
+                issued_token_type: is_token_exchange.then_some(TOKEN_TYPE_ACCESS_TOKEN),
+            })
+            .into_response(),
+        ),
+        Err(err) => match err {
+            OAuthTokenError::InvalidClientId(_)
+            | OAuthTokenError::ClientIsNotFound
+            | OAuthTokenError::ClientAuthentication(_) => {
+                oauth_token_error(StatusCode::UNAUTHORIZED, "invalid_client")
+            }
+            OAuthTokenError::UnauthorizedClient => {
+                oauth_token_error(StatusCode::BAD_REQUEST, "unauthorized_client")
+            }
+            OAuthTokenError::InvalidScope(_)
+            | OAuthTokenError::ScopeIsNotAllowed(_)
+            | OAuthTokenError::ExchangeAccessToken(ExchangeAccessTokenError::ScopeIsNotGranted {
+                ..
+            }) => oauth_token_error(StatusCode::BAD_REQUEST, "invalid_scope"),
+            OAuthTokenError::InvalidAudience(_)
+            | OAuthTokenError::ExchangeAccessToken(
+                ExchangeAccessTokenError::AudienceIsNotGranted { .. },
+            ) => oauth_token_error(StatusCode::BAD_REQUEST, "invalid_target"),
+            OAuthTokenError::AuthorizationPending => {
+                oauth_token_error(StatusCode::BAD_REQUEST, "authorization_pending")
+            }
+            OAuthTokenError::SlowDown => oauth_token_error(StatusCode::BAD_REQUEST, "slow_down"),
+            OAuthTokenError::InvalidDeviceCode(DeviceAuthorizationError::Expired) => {
+                oauth_token_error(StatusCode::BAD_REQUEST, "expired_token")
+            }
+            OAuthTokenError::CodeIsNotFound
+            | OAuthTokenError::InvalidRedirectUri(_)
+            | OAuthTokenError::InvalidAuthorizationCode(_)
+            | OAuthTokenError::DeviceCodeIsNotFound
+            | OAuthTokenError::InvalidDeviceCode(_)
+            | OAuthTokenError::InvalidSubjectToken(_)
+            | OAuthTokenError::SubjectIsNotUser
+            | OAuthTokenError::InvalidActorToken(_)
+            | OAuthTokenError::UserIsNotFound
+            | OAuthTokenError::UserIsNotActive
+            | OAuthTokenError::Refresh(
+                RefreshError::IdDecode(_)
+                | RefreshError::SessionIsNotFound
+                | RefreshError::SessionIsExpired
+                | RefreshError::SessionIsRevoked
+                | RefreshError::Session(_)
+                | RefreshError::UserIsNotFound,
+            ) => oauth_token_error(StatusCode::BAD_REQUEST, "invalid_grant"),
+            err => {
+                error!("internal error in handle_oauth_token: {err}");
+                oauth_token_error(StatusCode::INTERNAL_SERVER_ERROR, "server_error")
+            }
+        },
+    }
+}
+
+pub async fn handle_oauth_device_authorize(
+    State(use_cases): State<UseCases>,
+    headers: HeaderMap,
+    Form(form): Form<OAuthDeviceAuthorizeForm>,
+) -> Response {
+    let (client_id, client_secret) = match headers.get(AUTHORIZATION) {
+        Some(header_value) => match parse_basic_credentials(header_value) {
+            Some((client_id, client_secret)) => (client_id, Some(client_secret)),
+            None => return oauth_token_error(StatusCode::UNAUTHORIZED, "invalid_client"),
+        },
+        None => (form.client_id, form.client_secret),
+    };
+
+    let result = use_cases
+        .oauth_device_authorize(OAuthDeviceAuthorizeRequest {
+            client_id: &client_id,
+            client_secret: client_secret.as_deref(),
+        })
+        .await;
+
+    match result {
+        Ok(response) => no_store(
+            Json(OAuthDeviceAuthorizeSuccessResponse {
+                device_code: response.device_code,
+                user_code: response.user_code,
+                verification_uri: response.verification_uri,
+                expires_in: (response.expires_at_unix_timestamp
+                    - OffsetDateTime::now_utc().unix_timestamp())
+                .max(0),
+                interval: response.interval_seconds,
+            })
+            .into_response(),
+        ),
+        Err(err) => match err {
+            OAuthDeviceAuthorizeError::InvalidClientId(_)
+            | OAuthDeviceAuthorizeError::ClientIsNotFound
+            | OAuthDeviceAuthorizeError::ClientAuthentication(_) => {
+                oauth_token_error(StatusCode::UNAUTHORIZED, "invalid_client")
+            }
+            OAuthDeviceAuthorizeError::VerificationUriIsNotConfigured => {
+                oauth_token_error(StatusCode::BAD_REQUEST, "unauthorized_client")
+            }
+            err => {
+                error!("internal error in handle_oauth_device_authorize: {err}");
+                oauth_token_error(StatusCode::INTERNAL_SERVER_ERROR, "server_error")
+            }
+        },
+    }
+}
+
+/// Signed in user approves the code shown by the device on the verification page
+pub async fn handle_approve_device_authorization(
+    State(use_cases): State<UseCases>,
+    Authorization(user): Authorization,
+    Form(form): Form<ApproveDeviceAuthorizationForm>,
+) -> Response {
+    let result = use_cases
+        .approve_device_authorization(ApproveDeviceAuthorizationRequest {
+            user,
+            user_code: &form.user_code,
+        })
+        .await;
+
+    match result {
+        Ok(response) => Json(ApproveDeviceAuthorizationSuccessResponse {
+            client_id: response.client.id,
+            client_name: response.client.name,
+        })
+        .into_response(),
+        Err(err) => match err {
+            ApproveDeviceAuthorizationError::Forbidden(_) => (
+                StatusCode::FORBIDDEN,
+                "token used by an actor can not approve devices",
+            )
+                .into_response(),
+            ApproveDeviceAuthorizationError::InvalidUserCode(_)
+            | ApproveDeviceAuthorizationError::DeviceAuthorizationIsNotFound => {
+                (StatusCode::NOT_FOUND, "user code is not found").into_response()
+            }
+            ApproveDeviceAuthorizationError::DeviceAuthorization(
+                DeviceAuthorizationError::Expired,
+            ) => (StatusCode::GONE, "user code is expired").into_response(),
+            ApproveDeviceAuthorizationError::DeviceAuthorization(
+                DeviceAuthorizationError::AlreadyApproved,
+            ) => (StatusCode::CONFLICT, "user code is already approved").into_response(),
+            err => {
+                error!("internal error in handle_approve_device_authorization: {err}");
+                (StatusCode::INTERNAL_SERVER_ERROR, "server error").into_response()
+            }
+        },
+    }
+}
+
+/// Ids and secrets issued here never contain characters that would be url encoded
+fn parse_basic_credentials(header_value: &HeaderValue) -> Option<(String, String)> {
+    let encoded = header_value.to_str().ok()?.strip_prefix("Basic ")?;
+    let decoded = String::from_utf8(BASE64_STANDARD.decode(encoded).ok()?).ok()?;
+    let (client_id, client_secret) = decoded.split_once(':')?;
+    Some((client_id.to_string(), client_secret.to_string()))
+}
+
+fn oauth_token_error(status_code: StatusCode, error: &'static str) -> Response {
+    no_store((status_code, Json(OAuthErrorResponse { error })).into_response())
+}
+
+/// Token responses carry credentials, so they must not be cached
+fn no_store(mut response: Response) -> Response {
+    let headers = response.headers_mut();
+    headers.insert(CACHE_CONTROL, HeaderValue::from_static("no-store"));
+    headers.insert(PRAGMA, HeaderValue::from_static("no-cache"));
+    response
+}
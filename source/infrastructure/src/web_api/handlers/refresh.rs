@@ -1,9 +1,10 @@
-use axum::{extract::State, http::StatusCode, response::IntoResponse};
+use axum::{body::Bytes, extract::State, http::StatusCode, response::IntoResponse};
 use nimbus_auth_application::use_cases::{RefreshError, RefreshRequest, UseCases};
 use nimbus_auth_proto::proto::nimbus::auth::refresh::v1::{
-    RefreshErrorCodeProto, RefreshResponseProto, RefreshSuccessResponseProto,
+    RefreshErrorCodeProto, RefreshRequestProto, RefreshResponseProto, RefreshSuccessResponseProto,
     refresh_response_proto,
 };
+use prost::Message;
 use tracing::error;
 
 use crate::{
@@ -18,10 +19,27 @@ pub async fn handle_refresh(
     State(use_cases): State<UseCases>,
     Client(client_type): Client,
     Session { session_id }: Session,
+    body: Bytes,
 ) -> impl IntoResponse {
+    // empty body keeps the default audience of the realm
+    let RefreshRequestProto { audiences } = match RefreshRequestProto::decode(body) {
+        Ok(request) => request,
+        Err(_) => {
+            return ProtoResponse::new(
+                StatusCode::BAD_REQUEST,
+                RefreshResponseProto {
+                    result: Some(refresh_response_proto::Result::Error(
+                        RefreshErrorCodeProto::ValidationError.into(),
+                    )),
+                },
+            );
+        }
+    };
+
     let result = use_cases
         .refresh(RefreshRequest {
             session_id: &session_id,
+            audiences: &audiences,
         })
         .await;
 
@@ -53,7 +71,7 @@ pub async fn handle_refresh(
             }
         },
         Err(err) => match err {
-            RefreshError::IdDecode(_) => ProtoResponse::new(
+            RefreshError::IdDecode(_) | RefreshError::InvalidAudiences(_) => ProtoResponse::new(
                 StatusCode::BAD_REQUEST,
                 RefreshResponseProto {
                     result: Some(refresh_response_proto::Result::Error(
@@ -71,7 +89,8 @@ pub async fn handle_refresh(
             ),
             RefreshError::UserIsNotFound
             | RefreshError::SessionIsExpired
-            | RefreshError::SessionIsRevoked => ProtoResponse::new(
+            | RefreshError::SessionIsRevoked
+            | RefreshError::Session(_) => ProtoResponse::new(
                 StatusCode::BAD_REQUEST,
                 RefreshResponseProto {
                     result: Some(refresh_response_proto::Result::Error(
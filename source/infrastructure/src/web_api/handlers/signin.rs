@@ -26,6 +26,7 @@ pub async fn handle_signin(
     let SignInRequestProto {
         user_name,
         password,
+        audiences,
     } = match SignInRequestProto::decode(body) {
         Ok(request) => request,
         Err(_) => {
@@ -45,6 +46,7 @@ pub async fn handle_signin(
         .signin(SignInRequest {
             user_name: &user_name,
             password: &password,
+            audiences: &audiences,
         })
         .await;
 
@@ -76,7 +78,7 @@ pub async fn handle_signin(
             }
         },
         Err(err) => match err {
-            SignInError::InvalidUserName(_) | SignInError::InvalidPassword(_) => {
+            SignInError::InvalidUserName(_) | SignInError::InvalidAudiences(_) => {
                 ProtoResponse::new(
                     StatusCode::BAD_REQUEST,
                     SignInResponseProto {
@@ -96,6 +98,30 @@ pub async fn handle_signin(
                     },
                 )
             }
+            SignInError::UserIsSuspended { .. } => ProtoResponse::new(
+                StatusCode::FORBIDDEN,
+                SignInResponseProto {
+                    result: Some(sign_in_response_proto::Result::Error(
+                        SignInErrorCodeProto::UserSuspended.into(),
+                    )),
+                },
+            ),
+            SignInError::UserIsDeleted { .. } => ProtoResponse::new(
+                StatusCode::FORBIDDEN,
+                SignInResponseProto {
+                    result: Some(sign_in_response_proto::Result::Error(
+                        SignInErrorCodeProto::UserDeleted.into(),
+                    )),
+                },
+            ),
+            SignInError::UserIsLockedOut { .. } => ProtoResponse::new(
+                StatusCode::TOO_MANY_REQUESTS,
+                SignInResponseProto {
+                    result: Some(sign_in_response_proto::Result::Error(
+                        SignInErrorCodeProto::UserLockedOut.into(),
+                    )),
+                },
+            ),
             err => {
                 error!("internal error in handle_signin: {err}");
                 ProtoResponse::new(
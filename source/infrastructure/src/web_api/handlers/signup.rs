@@ -1,7 +1,11 @@
 use axum::{body::Bytes, extract::State, http::StatusCode, response::IntoResponse};
-use nimbus_auth_application::use_cases::{SignUpError, SignUpRequest, UseCases};
+use nimbus_auth_application::use_cases::{
+    SignUpError, SignUpRequest, SignUpResponse, SignedUpResponse, UseCases,
+};
+use nimbus_auth_domain::entities::user::value_objects::password::errors::PasswordError;
 use nimbus_auth_proto::proto::nimbus::auth::signup::v1::{
-    SignUpErrorCodeProto, SignUpRequestProto, SignUpResponseProto, SignUpSuccessResponseProto,
+    SignUpAcceptedResponseProto, SignUpErrorCodeProto, SignUpRequestProto, SignUpResponseProto,
+    SignUpSuccessResponseProto,
     sign_up_response_proto::{self},
 };
 use prost::Message;
@@ -9,7 +13,10 @@ use tracing::error;
 use zeroize::Zeroizing;
 
 use crate::{
-    converters::{convert_access_token_into_proto, convert_user_into_proto},
+    converters::{
+        convert_access_token_into_proto, convert_password_policy_violations_into_proto,
+        convert_user_into_proto,
+    },
     web_api::{extractors::client_extractor::Client, responses::proto::ProtoResponse},
 };
 
@@ -21,6 +28,7 @@ pub async fn handle_signup(
     let SignUpRequestProto {
         user_name,
         password,
+        audiences,
     } = match SignUpRequestProto::decode(body) {
         Ok(request) => request,
         Err(_) => {
@@ -40,43 +48,69 @@ pub async fn handle_signup(
         .signup(SignUpRequest {
             user_name: &user_name,
             password: &password,
+            audiences: &audiences,
         })
         .await;
 
     match result {
-        Ok(response) => match ProtoResponse::new(
-            StatusCode::CREATED,
+        Ok(SignUpResponse::Accepted) => ProtoResponse::new(
+            StatusCode::ACCEPTED,
             SignUpResponseProto {
-                result: Some(sign_up_response_proto::Result::Success(
-                    SignUpSuccessResponseProto {
-                        user: Some(convert_user_into_proto(response.user)),
-                        access_token: Some(convert_access_token_into_proto(response.access_token)),
-                    },
+                result: Some(sign_up_response_proto::Result::Accepted(
+                    SignUpAcceptedResponseProto {},
                 )),
             },
-        )
-        .with_session_headers(client_type, &response.session)
-        {
-            Ok(response_with_session_headers) => response_with_session_headers,
-            Err(err) => {
-                error!("internal error in handle_signup: {err}");
+        ),
+        Ok(SignUpResponse::SignedUp(signed_up)) => {
+            let SignedUpResponse {
+                user,
+                session,
+                access_token,
+            } = *signed_up;
+            match ProtoResponse::new(
+                StatusCode::CREATED,
+                SignUpResponseProto {
+                    result: Some(sign_up_response_proto::Result::Success(
+                        SignUpSuccessResponseProto {
+                            user: Some(convert_user_into_proto(user)),
+                            access_token: Some(convert_access_token_into_proto(access_token)),
+                        },
+                    )),
+                },
+            )
+            .with_session_headers(client_type, &session)
+            {
+                Ok(response_with_session_headers) => response_with_session_headers,
+                Err(err) => {
+                    error!("internal error in handle_signup: {err}");
+                    ProtoResponse::new(
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        SignUpResponseProto {
+                            result: Some(sign_up_response_proto::Result::Error(
+                                SignUpErrorCodeProto::Undefined.into(),
+                            )),
+                        },
+                    )
+                }
+            }
+        }
+        Err(err) => match err {
+            SignUpError::InvalidUserName(_) | SignUpError::InvalidAudiences(_) => {
                 ProtoResponse::new(
-                    StatusCode::INTERNAL_SERVER_ERROR,
+                    StatusCode::BAD_REQUEST,
                     SignUpResponseProto {
                         result: Some(sign_up_response_proto::Result::Error(
-                            SignUpErrorCodeProto::Undefined.into(),
+                            SignUpErrorCodeProto::ValidationError.into(),
                         )),
                     },
                 )
             }
-        },
-        Err(err) => match err {
-            SignUpError::InvalidUserName(_) | SignUpError::InvalidPassword(_) => {
+            SignUpError::InvalidPassword(PasswordError::PolicyViolation { violations }) => {
                 ProtoResponse::new(
                     StatusCode::BAD_REQUEST,
                     SignUpResponseProto {
-                        result: Some(sign_up_response_proto::Result::Error(
-                            SignUpErrorCodeProto::ValidationError.into(),
+                        result: Some(sign_up_response_proto::Result::PasswordPolicyViolations(
+                            convert_password_policy_violations_into_proto(&violations),
                         )),
                     },
                 )
@@ -0,0 +1,137 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use nimbus_auth_application::use_cases::{
+    GetUserSigninLockoutError, GetUserSigninLockoutRequest, ResetUserSigninLockoutError,
+    ResetUserSigninLockoutRequest, UseCases,
+};
+use nimbus_auth_proto::proto::nimbus::auth::user_signin_lockout::v1::{
+    GetUserSigninLockoutResponseProto, GetUserSigninLockoutSuccessResponseProto,
+    ResetUserSigninLockoutResponseProto, ResetUserSigninLockoutSuccessResponseProto,
+    UserSigninLockoutErrorCodeProto, get_user_signin_lockout_response_proto,
+    reset_user_signin_lockout_response_proto,
+};
+use tracing::error;
+
+use crate::web_api::{
+    extractors::authorization_extractor::Authorization, responses::proto::ProtoResponse,
+};
+
+pub async fn handle_get_user_signin_lockout(
+    State(use_cases): State<UseCases>,
+    Authorization(user): Authorization,
+    Path(user_name): Path<String>,
+) -> impl IntoResponse {
+    let result = use_cases
+        .get_user_signin_lockout(GetUserSigninLockoutRequest {
+            user,
+            user_name: &user_name,
+        })
+        .await;
+
+    let (status_code, result) = match result {
+        Ok(response) => (
+            StatusCode::OK,
+            get_user_signin_lockout_response_proto::Result::Success(
+                GetUserSigninLockoutSuccessResponseProto {
+                    failed_attempts: response.signin_lockout.failed_attempts,
+                    locked_until_unix_timestamp: response
+                        .signin_lockout
+                        .locked_until_unix_timestamp,
+                },
+            ),
+        ),
+        Err(err) => {
+            let (status_code, error_code) = match err {
+                GetUserSigninLockoutError::Forbidden(_) => (
+                    StatusCode::FORBIDDEN,
+                    UserSigninLockoutErrorCodeProto::Forbidden,
+                ),
+                GetUserSigninLockoutError::InvalidUserName(_) => (
+                    StatusCode::BAD_REQUEST,
+                    UserSigninLockoutErrorCodeProto::ValidationError,
+                ),
+                GetUserSigninLockoutError::UserIsNotFound { .. } => (
+                    StatusCode::NOT_FOUND,
+                    UserSigninLockoutErrorCodeProto::UserNotFound,
+                ),
+                err => {
+                    error!("error in handle_get_user_signin_lockout handler: {err}");
+                    (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        UserSigninLockoutErrorCodeProto::Undefined,
+                    )
+                }
+            };
+            (
+                status_code,
+                get_user_signin_lockout_response_proto::Result::Error(error_code.into()),
+            )
+        }
+    };
+
+    ProtoResponse::new(
+        status_code,
+        GetUserSigninLockoutResponseProto {
+            result: Some(result),
+        },
+    )
+}
+
+pub async fn handle_reset_user_signin_lockout(
+    State(use_cases): State<UseCases>,
+    Authorization(user): Authorization,
+    Path(user_name): Path<String>,
+) -> impl IntoResponse {
+    let result = use_cases
+        .reset_user_signin_lockout(ResetUserSigninLockoutRequest {
+            user,
+            user_name: &user_name,
+        })
+        .await;
+
+    let (status_code, result) = match result {
+        Ok(_) => (
+            StatusCode::OK,
+            reset_user_signin_lockout_response_proto::Result::Success(
+                ResetUserSigninLockoutSuccessResponseProto {},
+            ),
+        ),
+        Err(err) => {
+            let (status_code, error_code) = match err {
+                ResetUserSigninLockoutError::Forbidden(_) => (
+                    StatusCode::FORBIDDEN,
+                    UserSigninLockoutErrorCodeProto::Forbidden,
+                ),
+                ResetUserSigninLockoutError::InvalidUserName(_) => (
+                    StatusCode::BAD_REQUEST,
+                    UserSigninLockoutErrorCodeProto::ValidationError,
+                ),
+                ResetUserSigninLockoutError::UserIsNotFound { .. } => (
+                    StatusCode::NOT_FOUND,
+                    UserSigninLockoutErrorCodeProto::UserNotFound,
+                ),
+                err => {
+                    error!("error in handle_reset_user_signin_lockout handler: {err}");
+                    (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        UserSigninLockoutErrorCodeProto::Undefined,
+                    )
+                }
+            };
+            (
+                status_code,
+                reset_user_signin_lockout_response_proto::Result::Error(error_code.into()),
+            )
+        }
+    };
+
+    ProtoResponse::new(
+        status_code,
+        ResetUserSigninLockoutResponseProto {
+            result: Some(result),
+        },
+    )
+}
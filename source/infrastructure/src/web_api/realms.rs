@@ -0,0 +1,50 @@
+use std::{collections::HashMap, convert::Infallible, sync::Arc};
+
+use axum::{Router, extract::Request, http::header::HOST, response::Response};
+use nimbus_auth_shared::types::Realm;
+use tower::ServiceExt;
+
+#[derive(Default)]
+pub struct RealmRouters {
+    by_host: HashMap<String, Router>,
+    default: Router,
+}
+
+impl RealmRouters {
+    pub fn add(&mut self, realm: &Realm, router: Router) {
+        if let Some(host) = &realm.host {
+            self.by_host.insert(host.clone(), router.clone());
+        }
+        if realm.is_default() {
+            self.default = router;
+        }
+    }
+
+    fn get(&self, host: Option<&str>) -> Router {
+        host.and_then(|host| self.by_host.get(host))
+            .unwrap_or(&self.default)
+            .clone()
+    }
+}
+
+/// Requests outside of `/realms/{name}` prefixes are routed by host, unknown hosts go to the default realm
+pub async fn route_by_host(realm_routers: Arc<RealmRouters>, request: Request) -> Response {
+    let host = request
+        .uri()
+        .host()
+        .or_else(|| {
+            request
+                .headers()
+                .get(HOST)
+                .and_then(|host| host.to_str().ok())
+        })
+        .map(|host| host.split(':').next().unwrap_or(host).to_string());
+
+    let result: Result<Response, Infallible> =
+        realm_routers.get(host.as_deref()).oneshot(request).await;
+
+    match result {
+        Ok(response) => response,
+        Err(err) => match err {},
+    }
+}
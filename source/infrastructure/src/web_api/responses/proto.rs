@@ -12,6 +12,7 @@ use axum_extra::extract::{
 };
 use nimbus_auth_application::use_cases::SessionDto;
 use nimbus_auth_shared::constants::{
+    FEDERATED_AUTHORIZATION_EXPIRATION_SECONDS, FEDERATED_STATE_COOKIE_NAME,
     SESSION_COOKIE_EXP_TIMESTAMP_NAME, SESSION_COOKIE_NAME, SESSION_HEADER_EXP_TIMESTAMP_NAME,
     SESSION_HEADER_NAME,
 };
@@ -87,6 +88,21 @@ impl<T: Message> ProtoResponse<T> {
         }
         Ok(self)
     }
+
+    /// Lax, as the provider redirects the user back from another site
+    pub fn with_federated_state_cookie(mut self, state: &str) -> Self {
+        self.set_cookie(
+            Cookie::build((FEDERATED_STATE_COOKIE_NAME, state.to_string()))
+                .http_only(true)
+                .secure(true)
+                .same_site(SameSite::Lax)
+                .path("/")
+                .max_age(time::Duration::seconds(
+                    FEDERATED_AUTHORIZATION_EXPIRATION_SECONDS as i64,
+                )),
+        );
+        self
+    }
 }
 
 impl<T: Message> IntoResponse for ProtoResponse<T> {
@@ -1,5 +1,10 @@
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut config = prost_build::Config::new();
+    // results of admin users requests carry whole users next to bare error codes
+    config.enum_attribute(
+        ".nimbus.admin.users.v1",
+        "#[allow(clippy::large_enum_variant)]",
+    );
     config.include_file("proto.rs").compile_protos(
         &[
             "../../proto/v1/auth/get_public_key.proto",
@@ -7,6 +12,12 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             "../../proto/v1/auth/signup.proto",
             "../../proto/v1/auth/signin.proto",
             "../../proto/v1/auth/refresh.proto",
+            "../../proto/v1/auth/user_signin_lockout.proto",
+            "../../proto/v1/auth/api_keys.proto",
+            "../../proto/v1/auth/federation.proto",
+            "../../proto/v1/admin/users.proto",
+            "../../proto/v1/admin/roles.proto",
+            "../../proto/v1/admin/groups.proto",
         ],
         &["../../proto"],
     )?;
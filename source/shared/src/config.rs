@@ -4,11 +4,21 @@ use url::{ParseError, Url};
 
 use crate::{
     constants::{
-        ACCESS_TOKEN_EXPIRATION_SECONDS_DEFAULT, CORS_ORIGINS_COMMA_SEPARATED_DEFAULT,
-        POSTGRESDB_MAX_CONNECTIONS_DEFAULT, SESSION_EXPIRATION_SECONDS_DEFAULT, USE_HSTS_DEFAULT,
+        ACCESS_TOKEN_ATTRIBUTES_COMMA_SEPARATED_DEFAULT,
+        ACCESS_TOKEN_AUDIENCES_COMMA_SEPARATED_DEFAULT, ACCESS_TOKEN_EXPIRATION_SECONDS_DEFAULT,
+        ACCESS_TOKEN_ISSUER_DEFAULT, ACCESS_TOKEN_MAX_GROUPS_DEFAULT,
+        ACCESS_TOKEN_TRUSTED_ISSUERS_COMMA_SEPARATED_DEFAULT, CORS_ORIGINS_COMMA_SEPARATED_DEFAULT,
+        LEGACY_AUTH_POSTGRES_TABLE_DEFAULT, OIDC_PROVIDER_NAME_MAX_LENGTH_INCLUSIVE,
+        OIDC_SCOPE_OPENID, PASSWORD_MAX_STRENGTH_SCORE, POSTGRESDB_MAX_CONNECTIONS_DEFAULT,
+        REALM_NAME_MAX_LENGTH_INCLUSIVE, REALMS_COMMA_SEPARATED_DEFAULT,
+        SESSION_EXPIRATION_SECONDS_DEFAULT, USE_HSTS_DEFAULT, USER_ENUMERATION_PROTECTION_DEFAULT,
     },
     errors::AppConfigBuilderError,
-    types::{AccessTokenExpirationSeconds, PostgresDbMaxConnections, SessionExpirationSeconds},
+    types::{
+        AccessTokenAttributes, AccessTokenExpirationSeconds, AccessTokenMaxGroups, OidcProvider,
+        PasswordCharacterClass, PasswordHashingParams, PasswordPolicy, PostgresDbMaxConnections,
+        Realm, SessionExpirationSeconds, SigninLockoutPolicy,
+    },
 };
 
 pub struct AppConfigBuilder {
@@ -17,9 +27,26 @@ pub struct AppConfigBuilder {
     postgres_db_url: String,
     session_expiration_seconds: usize,
     access_token_expiration_seconds: usize,
+    access_token_max_groups: usize,
+    access_token_attributes_comma_separated: String,
+    access_token_issuer: String,
+    access_token_trusted_issuers_comma_separated: String,
+    access_token_audiences_comma_separated: String,
     postgres_db_max_connections: usize,
     use_hsts: bool,
     cors_origins_comma_separated: String,
+    password_policy: PasswordPolicy,
+    password_hashing_params: PasswordHashingParams,
+    password_peppers_path: Option<PathBuf>,
+    breached_passwords_filter_path: Option<PathBuf>,
+    legacy_auth_postgres_schema: Option<String>,
+    legacy_auth_postgres_table: String,
+    signin_lockout_policy: SigninLockoutPolicy,
+    user_enumeration_protection: bool,
+    signup_notification_webhook_url: Option<String>,
+    oauth_device_verification_uri: Option<String>,
+    oidc_providers: Vec<OidcProvider>,
+    realms_comma_separated: String,
 }
 
 #[derive(Clone)]
@@ -29,9 +56,23 @@ pub struct AppConfig {
     postgres_db_url: String,
     session_expiration_seconds: SessionExpirationSeconds,
     access_token_expiration_seconds: AccessTokenExpirationSeconds,
+    access_token_max_groups: AccessTokenMaxGroups,
+    access_token_attributes: AccessTokenAttributes,
     postgres_db_max_connections: PostgresDbMaxConnections,
     use_hsts: bool,
     cors_origins: Vec<String>,
+    password_policy: PasswordPolicy,
+    password_hashing_params: PasswordHashingParams,
+    password_peppers_path: Option<PathBuf>,
+    breached_passwords_filter_path: Option<PathBuf>,
+    legacy_auth_postgres_schema: Option<String>,
+    legacy_auth_postgres_table: String,
+    signin_lockout_policy: SigninLockoutPolicy,
+    user_enumeration_protection: bool,
+    signup_notification_webhook_url: Option<String>,
+    oauth_device_verification_uri: Option<String>,
+    oidc_providers: Vec<OidcProvider>,
+    realms: Vec<Realm>,
 }
 
 pub struct AppConfigRequiredOptions {
@@ -54,9 +95,29 @@ impl AppConfigBuilder {
             postgres_db_url,
             session_expiration_seconds: SESSION_EXPIRATION_SECONDS_DEFAULT,
             access_token_expiration_seconds: ACCESS_TOKEN_EXPIRATION_SECONDS_DEFAULT,
+            access_token_max_groups: ACCESS_TOKEN_MAX_GROUPS_DEFAULT,
+            access_token_attributes_comma_separated:
+                ACCESS_TOKEN_ATTRIBUTES_COMMA_SEPARATED_DEFAULT.to_string(),
+            access_token_issuer: ACCESS_TOKEN_ISSUER_DEFAULT.to_string(),
+            access_token_trusted_issuers_comma_separated:
+                ACCESS_TOKEN_TRUSTED_ISSUERS_COMMA_SEPARATED_DEFAULT.to_string(),
+            access_token_audiences_comma_separated: ACCESS_TOKEN_AUDIENCES_COMMA_SEPARATED_DEFAULT
+                .to_string(),
             postgres_db_max_connections: POSTGRESDB_MAX_CONNECTIONS_DEFAULT,
             use_hsts: USE_HSTS_DEFAULT,
             cors_origins_comma_separated: CORS_ORIGINS_COMMA_SEPARATED_DEFAULT.to_string(),
+            password_policy: PasswordPolicy::default(),
+            password_hashing_params: PasswordHashingParams::default(),
+            password_peppers_path: None,
+            breached_passwords_filter_path: None,
+            legacy_auth_postgres_schema: None,
+            legacy_auth_postgres_table: LEGACY_AUTH_POSTGRES_TABLE_DEFAULT.to_string(),
+            signin_lockout_policy: SigninLockoutPolicy::default(),
+            user_enumeration_protection: USER_ENUMERATION_PROTECTION_DEFAULT,
+            signup_notification_webhook_url: None,
+            oauth_device_verification_uri: None,
+            oidc_providers: Vec::new(),
+            realms_comma_separated: REALMS_COMMA_SEPARATED_DEFAULT.to_string(),
         }
     }
 
@@ -70,6 +131,41 @@ impl AppConfigBuilder {
         self
     }
 
+    pub fn with_access_token_max_groups(&mut self, max_groups: usize) -> &mut Self {
+        self.access_token_max_groups = max_groups;
+        self
+    }
+
+    pub fn with_access_token_attributes_comma_separated(
+        &mut self,
+        attributes_comma_separated: &str,
+    ) -> &mut Self {
+        self.access_token_attributes_comma_separated = attributes_comma_separated.to_string();
+        self
+    }
+
+    pub fn with_access_token_issuer(&mut self, issuer: &str) -> &mut Self {
+        self.access_token_issuer = issuer.to_string();
+        self
+    }
+
+    pub fn with_access_token_trusted_issuers_comma_separated(
+        &mut self,
+        trusted_issuers_comma_separated: &str,
+    ) -> &mut Self {
+        self.access_token_trusted_issuers_comma_separated =
+            trusted_issuers_comma_separated.to_string();
+        self
+    }
+
+    pub fn with_access_token_audiences_comma_separated(
+        &mut self,
+        audiences_comma_separated: &str,
+    ) -> &mut Self {
+        self.access_token_audiences_comma_separated = audiences_comma_separated.to_string();
+        self
+    }
+
     pub fn with_postgres_db_max_connections(&mut self, connections: usize) -> &mut Self {
         self.postgres_db_max_connections = connections;
         self
@@ -88,7 +184,118 @@ impl AppConfigBuilder {
         self
     }
 
+    pub fn with_password_min_length(&mut self, min_length: usize) -> &mut Self {
+        self.password_policy.min_length = min_length;
+        self
+    }
+
+    pub fn with_password_max_length(&mut self, max_length: usize) -> &mut Self {
+        self.password_policy.max_length = max_length;
+        self
+    }
+
+    pub fn with_password_required_character_classes(
+        &mut self,
+        character_classes: Vec<PasswordCharacterClass>,
+    ) -> &mut Self {
+        self.password_policy.required_character_classes = character_classes;
+        self
+    }
+
+    pub fn with_password_unicode_allowed(&mut self) -> &mut Self {
+        self.password_policy.allow_unicode = true;
+        self
+    }
+
+    pub fn with_password_spaces_allowed(&mut self) -> &mut Self {
+        self.password_policy.allow_spaces = true;
+        self
+    }
+
+    pub fn with_password_min_strength_score(&mut self, score: u8) -> &mut Self {
+        self.password_policy.min_strength_score = score;
+        self
+    }
+
+    pub fn with_password_hash_memory_cost_kib(&mut self, memory_cost_kib: u32) -> &mut Self {
+        self.password_hashing_params.memory_cost_kib = memory_cost_kib;
+        self
+    }
+
+    pub fn with_password_hash_time_cost(&mut self, time_cost: u32) -> &mut Self {
+        self.password_hashing_params.time_cost = time_cost;
+        self
+    }
+
+    pub fn with_password_hash_parallelism(&mut self, parallelism: u32) -> &mut Self {
+        self.password_hashing_params.parallelism = parallelism;
+        self
+    }
+
+    pub fn with_password_peppers_path(&mut self, path: PathBuf) -> &mut Self {
+        self.password_peppers_path = Some(path);
+        self
+    }
+
+    pub fn with_breached_passwords_filter_path(&mut self, path: PathBuf) -> &mut Self {
+        self.breached_passwords_filter_path = Some(path);
+        self
+    }
+
+    pub fn with_legacy_auth_postgres_schema(&mut self, schema: &str) -> &mut Self {
+        self.legacy_auth_postgres_schema = Some(schema.to_string());
+        self
+    }
+
+    pub fn with_legacy_auth_postgres_table(&mut self, table: &str) -> &mut Self {
+        self.legacy_auth_postgres_table = table.to_string();
+        self
+    }
+
+    pub fn with_signin_lockout_max_failed_attempts(&mut self, attempts: u32) -> &mut Self {
+        self.signin_lockout_policy.max_failed_attempts = attempts;
+        self
+    }
+
+    pub fn with_signin_lockout_base_seconds(&mut self, seconds: usize) -> &mut Self {
+        self.signin_lockout_policy.base_lockout_seconds = seconds;
+        self
+    }
+
+    pub fn with_signin_lockout_max_seconds(&mut self, seconds: usize) -> &mut Self {
+        self.signin_lockout_policy.max_lockout_seconds = seconds;
+        self
+    }
+
+    pub fn with_user_enumeration_protection(&mut self) -> &mut Self {
+        self.user_enumeration_protection = true;
+        self
+    }
+
+    pub fn with_signup_notification_webhook_url(&mut self, url: &str) -> &mut Self {
+        self.signup_notification_webhook_url = Some(url.to_string());
+        self
+    }
+
+    pub fn with_oauth_device_verification_uri(&mut self, uri: &str) -> &mut Self {
+        self.oauth_device_verification_uri = Some(uri.to_string());
+        self
+    }
+
+    pub fn with_oidc_provider(&mut self, provider: OidcProvider) -> &mut Self {
+        self.oidc_providers.push(provider);
+        self
+    }
+
+    pub fn with_realms_comma_separated(&mut self, realms_comma_separated: &str) -> &mut Self {
+        self.realms_comma_separated = realms_comma_separated.to_string();
+        self
+    }
+
     pub fn build(self) -> Result<AppConfig, AppConfigBuilderError> {
+        Self::validate_password_policy(&self.password_policy)?;
+        Self::validate_password_hashing_params(&self.password_hashing_params)?;
+        Self::validate_signin_lockout_policy(&self.signin_lockout_policy)?;
         Ok(AppConfig {
             server_addr: self.server_addr,
             keypairs_store_path: self.keypairs_store_path,
@@ -97,14 +304,95 @@ impl AppConfigBuilder {
             access_token_expiration_seconds: AccessTokenExpirationSeconds(
                 self.access_token_expiration_seconds,
             ),
+            access_token_max_groups: AccessTokenMaxGroups(self.access_token_max_groups),
+            access_token_attributes: AccessTokenAttributes(
+                self.access_token_attributes_comma_separated
+                    .split(",")
+                    .map(|attribute| attribute.trim())
+                    .filter(|attribute| !attribute.is_empty())
+                    .map(|attribute| attribute.to_string())
+                    .collect(),
+            ),
             postgres_db_max_connections: PostgresDbMaxConnections(self.postgres_db_max_connections),
             use_hsts: self.use_hsts,
             cors_origins: Self::parse_cors_origins_comma_separated(
                 &self.cors_origins_comma_separated,
             )?,
+            password_policy: self.password_policy,
+            password_hashing_params: self.password_hashing_params,
+            password_peppers_path: self.password_peppers_path,
+            breached_passwords_filter_path: self.breached_passwords_filter_path,
+            legacy_auth_postgres_schema: self.legacy_auth_postgres_schema,
+            legacy_auth_postgres_table: self.legacy_auth_postgres_table,
+            signin_lockout_policy: self.signin_lockout_policy,
+            user_enumeration_protection: self.user_enumeration_protection,
+            signup_notification_webhook_url: self
+                .signup_notification_webhook_url
+                .map(|url| Url::parse(url.trim()).map(|url| url.to_string()))
+                .transpose()?,
+            oauth_device_verification_uri: self
+                .oauth_device_verification_uri
+                .map(|uri| Url::parse(uri.trim()).map(|uri| uri.to_string()))
+                .transpose()?,
+            oidc_providers: Self::validate_oidc_providers(self.oidc_providers)?,
+            realms: Self::parse_realms_comma_separated(
+                &self.realms_comma_separated,
+                Self::build_default_realm(
+                    &self.access_token_issuer,
+                    &self.access_token_trusted_issuers_comma_separated,
+                    &self.access_token_audiences_comma_separated,
+                )?,
+            )?,
         })
     }
 
+    fn validate_password_policy(policy: &PasswordPolicy) -> Result<(), AppConfigBuilderError> {
+        if policy.min_length == 0 || policy.min_length > policy.max_length {
+            return Err(AppConfigBuilderError::PasswordLengthBounds {
+                min_length: policy.min_length,
+                max_length: policy.max_length,
+            });
+        }
+        if policy.min_strength_score > PASSWORD_MAX_STRENGTH_SCORE {
+            return Err(AppConfigBuilderError::PasswordMinStrengthScore {
+                score: policy.min_strength_score,
+                max_score: PASSWORD_MAX_STRENGTH_SCORE,
+            });
+        }
+        Ok(())
+    }
+
+    fn validate_password_hashing_params(
+        params: &PasswordHashingParams,
+    ) -> Result<(), AppConfigBuilderError> {
+        if params.time_cost == 0
+            || params.parallelism == 0
+            || params.memory_cost_kib < params.parallelism.saturating_mul(8)
+        {
+            return Err(AppConfigBuilderError::PasswordHashingParams {
+                memory_cost_kib: params.memory_cost_kib,
+                time_cost: params.time_cost,
+                parallelism: params.parallelism,
+            });
+        }
+        Ok(())
+    }
+
+    fn validate_signin_lockout_policy(
+        policy: &SigninLockoutPolicy,
+    ) -> Result<(), AppConfigBuilderError> {
+        if policy.max_failed_attempts != 0
+            && (policy.base_lockout_seconds == 0
+                || policy.base_lockout_seconds > policy.max_lockout_seconds)
+        {
+            return Err(AppConfigBuilderError::SigninLockoutDurations {
+                base_lockout_seconds: policy.base_lockout_seconds,
+                max_lockout_seconds: policy.max_lockout_seconds,
+            });
+        }
+        Ok(())
+    }
+
     fn parse_cors_origins_comma_separated(
         cors_origins_comma_separated: &str,
     ) -> Result<Vec<String>, ParseError> {
@@ -114,6 +402,110 @@ impl AppConfigBuilder {
             .map(|origin| Url::parse(origin.trim()).map(|url| url.to_string()))
             .collect()
     }
+
+    fn build_default_realm(
+        issuer: &str,
+        trusted_issuers_comma_separated: &str,
+        audiences_comma_separated: &str,
+    ) -> Result<Realm, AppConfigBuilderError> {
+        let issuer = issuer.trim();
+        if issuer.is_empty() {
+            return Err(AppConfigBuilderError::EmptyAccessTokenIssuer);
+        }
+        let audiences = Self::split_comma_separated(audiences_comma_separated);
+        if audiences.is_empty() {
+            return Err(AppConfigBuilderError::EmptyAccessTokenAudiences);
+        }
+        Ok(Realm {
+            issuer: issuer.to_string(),
+            trusted_issuers: Self::split_comma_separated(trusted_issuers_comma_separated),
+            audiences,
+            ..Realm::default()
+        })
+    }
+
+    fn split_comma_separated(values_comma_separated: &str) -> Vec<String> {
+        values_comma_separated
+            .split(",")
+            .map(|value| value.trim())
+            .filter(|value| !value.is_empty())
+            .map(|value| value.to_string())
+            .collect()
+    }
+
+    /// Named realms are derived from the default one
+    fn validate_oidc_providers(
+        providers: Vec<OidcProvider>,
+    ) -> Result<Vec<OidcProvider>, AppConfigBuilderError> {
+        let mut validated: Vec<OidcProvider> = Vec::with_capacity(providers.len());
+        for provider in providers {
+            let is_valid = !provider.name.is_empty()
+                && provider.name.len() <= OIDC_PROVIDER_NAME_MAX_LENGTH_INCLUSIVE
+                && provider
+                    .name
+                    .chars()
+                    .all(|char| char.is_ascii_lowercase() || char.is_ascii_digit() || char == '-')
+                && !provider.client_id.trim().is_empty()
+                && provider
+                    .scopes
+                    .iter()
+                    .any(|scope| scope == OIDC_SCOPE_OPENID);
+            if !is_valid {
+                return Err(AppConfigBuilderError::InvalidOidcProvider {
+                    name: provider.name,
+                    max_length: OIDC_PROVIDER_NAME_MAX_LENGTH_INCLUSIVE,
+                });
+            }
+            if validated.iter().any(|other| other.name == provider.name) {
+                return Err(AppConfigBuilderError::DuplicateOidcProvider {
+                    name: provider.name,
+                });
+            }
+            // issuer is compared with the `iss` claim of ID tokens as is, so only the redirect uri is normalized
+            Url::parse(&provider.issuer)?;
+            let redirect_uri = Url::parse(provider.redirect_uri.trim())?.to_string();
+            validated.push(OidcProvider {
+                redirect_uri,
+                ..provider
+            });
+        }
+        Ok(validated)
+    }
+
+    fn parse_realms_comma_separated(
+        realms_comma_separated: &str,
+        default_realm: Realm,
+    ) -> Result<Vec<Realm>, AppConfigBuilderError> {
+        let mut realms = vec![default_realm];
+        for realm in realms_comma_separated
+            .split(",")
+            .map(|realm| realm.trim())
+            .filter(|realm| !realm.is_empty())
+        {
+            let (name, host) = match realm.split_once("@") {
+                Some((name, host)) => (name, Some(host)),
+                None => (realm, None),
+            };
+            let is_valid_name = !name.is_empty()
+                && name.len() <= REALM_NAME_MAX_LENGTH_INCLUSIVE
+                && name
+                    .chars()
+                    .all(|char| char.is_ascii_lowercase() || char.is_ascii_digit() || char == '-');
+            if !is_valid_name || host.is_some_and(|host| host.is_empty()) {
+                return Err(AppConfigBuilderError::InvalidRealm {
+                    realm: realm.to_string(),
+                    max_length: REALM_NAME_MAX_LENGTH_INCLUSIVE,
+                });
+            }
+            if realms.iter().any(|realm| realm.name == name) {
+                return Err(AppConfigBuilderError::DuplicateRealm {
+                    name: name.to_string(),
+                });
+            }
+            realms.push(realms[0].derive(name, host));
+        }
+        Ok(realms)
+    }
 }
 
 impl AppConfig {
@@ -137,6 +529,14 @@ impl AppConfig {
         self.access_token_expiration_seconds
     }
 
+    pub fn access_token_max_groups(&self) -> AccessTokenMaxGroups {
+        self.access_token_max_groups
+    }
+
+    pub fn access_token_attributes(&self) -> &AccessTokenAttributes {
+        &self.access_token_attributes
+    }
+
     pub fn postgres_db_max_connections(&self) -> PostgresDbMaxConnections {
         self.postgres_db_max_connections
     }
@@ -148,4 +548,63 @@ impl AppConfig {
     pub fn cors_origins(&self) -> &Vec<String> {
         &self.cors_origins
     }
+
+    pub fn password_policy(&self) -> &PasswordPolicy {
+        &self.password_policy
+    }
+
+    pub fn password_hashing_params(&self) -> PasswordHashingParams {
+        self.password_hashing_params
+    }
+
+    pub fn password_peppers_path(&self) -> Option<&PathBuf> {
+        self.password_peppers_path.as_ref()
+    }
+
+    pub fn breached_passwords_filter_path(&self) -> Option<&PathBuf> {
+        self.breached_passwords_filter_path.as_ref()
+    }
+
+    /// Schema with legacy users table, just in time migration from legacy backend is disabled if not set
+    pub fn legacy_auth_postgres_schema(&self) -> Option<&str> {
+        self.legacy_auth_postgres_schema.as_deref()
+    }
+
+    pub fn legacy_auth_postgres_table(&self) -> &str {
+        &self.legacy_auth_postgres_table
+    }
+
+    pub fn signin_lockout_policy(&self) -> SigninLockoutPolicy {
+        self.signin_lockout_policy
+    }
+
+    /// Hides whether a user name is registered: signin verifies unknown users against a dummy hash
+    /// and signup responds the same way whether the user was created or not
+    pub fn user_enumeration_protection(&self) -> bool {
+        self.user_enumeration_protection
+    }
+
+    /// Receives signup outcomes which are not returned to the client when user enumeration protection is enabled
+    pub fn signup_notification_webhook_url(&self) -> Option<&str> {
+        self.signup_notification_webhook_url.as_deref()
+    }
+
+    /// Page where users enter the codes shown by devices, device authorization grant is disabled if not set
+    pub fn oauth_device_verification_uri(&self) -> Option<&str> {
+        self.oauth_device_verification_uri.as_deref()
+    }
+
+    /// Upstream OpenID Connect providers, federated signin is disabled if there are none
+    pub fn oidc_providers(&self) -> &[OidcProvider] {
+        &self.oidc_providers
+    }
+
+    /// Served realms, the default realm goes first
+    pub fn realms(&self) -> &[Realm] {
+        &self.realms
+    }
+
+    pub fn default_realm(&self) -> &Realm {
+        &self.realms[0]
+    }
 }
@@ -7,8 +7,27 @@ pub const SESSION_EXPIRATION_SECONDS_DEFAULT: usize = 7 * 24 * 60 * 60;
 
 pub const ACCESS_TOKEN_EXPIRATION_SECONDS_ENV_VAR_NAME: &str = "ACCESS_TOKEN_EXPIRATION_SECONDS";
 pub const ACCESS_TOKEN_EXPIRATION_SECONDS_DEFAULT: usize = 15 * 60;
-pub const ACCESS_TOKEN_AUDIENCE: &str = "nimbus";
-pub const ACCESS_TOKEN_ISSUER: &str = "nimbus-auth";
+pub const ACCESS_TOKEN_MAX_GROUPS_ENV_VAR_NAME: &str = "ACCESS_TOKEN_MAX_GROUPS";
+pub const ACCESS_TOKEN_MAX_GROUPS_DEFAULT: usize = 50;
+pub const ACCESS_TOKEN_ATTRIBUTES_COMMA_SEPARATED_ENV_VAR_NAME: &str =
+    "ACCESS_TOKEN_ATTRIBUTES_COMMA_SEPARATED";
+pub const ACCESS_TOKEN_ATTRIBUTES_COMMA_SEPARATED_DEFAULT: &str = "";
+pub const ACCESS_TOKEN_ISSUER_ENV_VAR_NAME: &str = "ACCESS_TOKEN_ISSUER";
+pub const ACCESS_TOKEN_ISSUER_DEFAULT: &str = "nimbus-auth";
+/// Issuers accepted on verification besides the current one, e.g. an issuer being renamed
+pub const ACCESS_TOKEN_TRUSTED_ISSUERS_COMMA_SEPARATED_ENV_VAR_NAME: &str =
+    "ACCESS_TOKEN_TRUSTED_ISSUERS_COMMA_SEPARATED";
+pub const ACCESS_TOKEN_TRUSTED_ISSUERS_COMMA_SEPARATED_DEFAULT: &str = "";
+/// Audiences clients may request, the first one is used when none is requested
+pub const ACCESS_TOKEN_AUDIENCES_COMMA_SEPARATED_ENV_VAR_NAME: &str =
+    "ACCESS_TOKEN_AUDIENCES_COMMA_SEPARATED";
+pub const ACCESS_TOKEN_AUDIENCES_COMMA_SEPARATED_DEFAULT: &str = "nimbus";
+
+/// Entries are `<realm name>` or `<realm name>@<host>`, the default realm is always served
+pub const REALMS_COMMA_SEPARATED_ENV_VAR_NAME: &str = "REALMS_COMMA_SEPARATED";
+pub const REALMS_COMMA_SEPARATED_DEFAULT: &str = "";
+pub const DEFAULT_REALM_NAME: &str = "default";
+pub const REALM_NAME_MAX_LENGTH_INCLUSIVE: usize = 32;
 
 pub const POSTGRESDB_MAX_CONNECTIONS_ENV_VAR_NAME: &str = "POSTGRESDB_MAX_CONNECTIONS";
 pub const POSTGRESDB_MAX_CONNECTIONS_DEFAULT: usize = 24;
@@ -21,12 +40,138 @@ pub const CORS_ORIGINS_COMMA_SEPARATED_DEFAULT: &str = "";
 
 pub const CHANNEL_BUFFER_SIZE_DEFAULT: usize = 4096;
 
-pub const PASSWORD_MIN_LENGTH_INCLUSIVE: usize = 8;
-pub const PASSWORD_MAX_LENGTH_INCLUSIVE: usize = 32;
+pub const PASSWORD_MIN_LENGTH_ENV_VAR_NAME: &str = "PASSWORD_MIN_LENGTH";
+pub const PASSWORD_MIN_LENGTH_DEFAULT: usize = 8;
+
+pub const PASSWORD_MAX_LENGTH_ENV_VAR_NAME: &str = "PASSWORD_MAX_LENGTH";
+pub const PASSWORD_MAX_LENGTH_DEFAULT: usize = 32;
+
+pub const PASSWORD_REQUIRED_CHARACTER_CLASSES_COMMA_SEPARATED_ENV_VAR_NAME: &str =
+    "PASSWORD_REQUIRED_CHARACTER_CLASSES_COMMA_SEPARATED";
+
+pub const PASSWORD_ALLOW_UNICODE_ENV_VAR_NAME: &str = "PASSWORD_ALLOW_UNICODE";
+pub const PASSWORD_ALLOW_UNICODE_DEFAULT: bool = false;
+
+pub const PASSWORD_ALLOW_SPACES_ENV_VAR_NAME: &str = "PASSWORD_ALLOW_SPACES";
+pub const PASSWORD_ALLOW_SPACES_DEFAULT: bool = false;
+
+pub const PASSWORD_MIN_STRENGTH_SCORE_ENV_VAR_NAME: &str = "PASSWORD_MIN_STRENGTH_SCORE";
+pub const PASSWORD_MIN_STRENGTH_SCORE_DEFAULT: u8 = 0;
+pub const PASSWORD_MAX_STRENGTH_SCORE: u8 = 4;
+
+pub const PASSWORD_HASH_MEMORY_COST_KIB_ENV_VAR_NAME: &str = "PASSWORD_HASH_MEMORY_COST_KIB";
+pub const PASSWORD_HASH_MEMORY_COST_KIB_DEFAULT: u32 = 19 * 1024;
+
+pub const PASSWORD_HASH_TIME_COST_ENV_VAR_NAME: &str = "PASSWORD_HASH_TIME_COST";
+pub const PASSWORD_HASH_TIME_COST_DEFAULT: u32 = 2;
+
+pub const PASSWORD_HASH_PARALLELISM_ENV_VAR_NAME: &str = "PASSWORD_HASH_PARALLELISM";
+pub const PASSWORD_HASH_PARALLELISM_DEFAULT: u32 = 1;
+
+pub const PASSWORD_PEPPERS_PATH_ENV_VAR_NAME: &str = "PASSWORD_PEPPERS_PATH";
+
+pub const BREACHED_PASSWORDS_FILTER_PATH_ENV_VAR_NAME: &str = "BREACHED_PASSWORDS_FILTER_PATH";
+
+pub const LEGACY_AUTH_POSTGRES_SCHEMA_ENV_VAR_NAME: &str = "LEGACY_AUTH_POSTGRES_SCHEMA";
+
+pub const LEGACY_AUTH_POSTGRES_TABLE_ENV_VAR_NAME: &str = "LEGACY_AUTH_POSTGRES_TABLE";
+pub const LEGACY_AUTH_POSTGRES_TABLE_DEFAULT: &str = "users";
+
+pub const SIGNIN_LOCKOUT_MAX_FAILED_ATTEMPTS_ENV_VAR_NAME: &str =
+    "SIGNIN_LOCKOUT_MAX_FAILED_ATTEMPTS";
+pub const SIGNIN_LOCKOUT_MAX_FAILED_ATTEMPTS_DEFAULT: u32 = 5;
+
+pub const SIGNIN_LOCKOUT_BASE_SECONDS_ENV_VAR_NAME: &str = "SIGNIN_LOCKOUT_BASE_SECONDS";
+pub const SIGNIN_LOCKOUT_BASE_SECONDS_DEFAULT: usize = 30;
+
+pub const SIGNIN_LOCKOUT_MAX_SECONDS_ENV_VAR_NAME: &str = "SIGNIN_LOCKOUT_MAX_SECONDS";
+pub const SIGNIN_LOCKOUT_MAX_SECONDS_DEFAULT: usize = 60 * 60;
+
+pub const USER_ENUMERATION_PROTECTION_ENV_VAR_NAME: &str = "USER_ENUMERATION_PROTECTION";
+pub const USER_ENUMERATION_PROTECTION_DEFAULT: bool = false;
+
+pub const SIGNUP_NOTIFICATION_WEBHOOK_URL_ENV_VAR_NAME: &str = "SIGNUP_NOTIFICATION_WEBHOOK_URL";
+
+pub const OAUTH_DEVICE_VERIFICATION_URI_ENV_VAR_NAME: &str = "OAUTH_DEVICE_VERIFICATION_URI";
+
+/// Names of upstream OpenID Connect providers, settings of each one are read from
+/// `OIDC_PROVIDER_<NAME>_<SETTING>` with the name uppercased and `-` replaced by `_`
+pub const OIDC_PROVIDERS_COMMA_SEPARATED_ENV_VAR_NAME: &str = "OIDC_PROVIDERS_COMMA_SEPARATED";
+pub const OIDC_PROVIDER_ENV_VAR_PREFIX: &str = "OIDC_PROVIDER_";
+pub const OIDC_PROVIDER_ISSUER_ENV_VAR_SUFFIX: &str = "_ISSUER";
+pub const OIDC_PROVIDER_CLIENT_ID_ENV_VAR_SUFFIX: &str = "_CLIENT_ID";
+pub const OIDC_PROVIDER_CLIENT_SECRET_ENV_VAR_SUFFIX: &str = "_CLIENT_SECRET";
+pub const OIDC_PROVIDER_REDIRECT_URI_ENV_VAR_SUFFIX: &str = "_REDIRECT_URI";
+pub const OIDC_PROVIDER_SCOPES_COMMA_SEPARATED_ENV_VAR_SUFFIX: &str = "_SCOPES_COMMA_SEPARATED";
+pub const OIDC_PROVIDER_SCOPES_COMMA_SEPARATED_DEFAULT: &str = "openid,email,profile";
+pub const OIDC_PROVIDER_NAME_MAX_LENGTH_INCLUSIVE: usize = 32;
+/// Scope every authentication request to a provider has to include
+pub const OIDC_SCOPE_OPENID: &str = "openid";
+
+pub const LIST_USERS_PAGE_SIZE_DEFAULT: usize = 50;
+pub const LIST_USERS_PAGE_SIZE_MAX: usize = 500;
 
 pub const USERNAME_MIN_LENGTH_INCLUSIVE: usize = 4;
 pub const USERNAME_MAX_LENGTH_INCLUSIVE: usize = 32;
 
+pub const ROLE_NAME_MAX_LENGTH_INCLUSIVE: usize = 64;
+pub const PERMISSION_MAX_LENGTH_INCLUSIVE: usize = 128;
+pub const GROUP_NAME_MAX_LENGTH_INCLUSIVE: usize = 64;
+pub const OAUTH_CLIENT_NAME_MAX_LENGTH_INCLUSIVE: usize = 64;
+pub const OAUTH_REDIRECT_URI_MAX_LENGTH_INCLUSIVE: usize = 2048;
+pub const API_KEY_NAME_MAX_LENGTH_INCLUSIVE: usize = 64;
+
+/// Authorization codes are exchanged right after the redirect, so they live shortly
+pub const OAUTH_AUTHORIZATION_CODE_EXPIRATION_SECONDS: usize = 60;
+/// Only S256 is supported, plain challenges do not protect leaked codes
+pub const OAUTH_CODE_CHALLENGE_METHOD_S256: &str = "S256";
+/// Client secrets and API keys are random bytes encoded as base64url
+pub const RANDOM_SECRET_LENGTH_BYTES: usize = 32;
+/// `typ` header of access tokens issued to clients, tokens of users keep the default `JWT`
+pub const CLIENT_ACCESS_TOKEN_TYPE: &str = "client+jwt";
+/// Users approve a device on another screen, so device codes live longer than authorization codes
+pub const OAUTH_DEVICE_CODE_EXPIRATION_SECONDS: usize = 600;
+/// Devices have to wait this long between polls of the token endpoint
+pub const OAUTH_DEVICE_POLLING_INTERVAL_SECONDS: usize = 5;
+/// Polling interval of a device grows by this each time it polls too often
+pub const OAUTH_DEVICE_SLOW_DOWN_SECONDS: usize = 5;
+/// User codes are typed by hand, consonants only avoid ambiguous characters and words
+pub const OAUTH_USER_CODE_ALPHABET: &str = "BCDFGHJKLMNPQRSTVWXZ";
+pub const OAUTH_USER_CODE_LENGTH: usize = 8;
+/// API keys are told apart from JWTs in the authorization header by this prefix
+pub const API_KEY_PREFIX: &str = "nak_";
+/// Impersonation tokens are only for reproducing issues and have no session to refresh them
+pub const IMPERSONATION_ACCESS_TOKEN_EXPIRATION_SECONDS: usize = 900;
+/// Users may take a while to sign in at the identity provider before it redirects them back
+pub const FEDERATED_AUTHORIZATION_EXPIRATION_SECONDS: usize = 600;
+/// Requests to webhooks and identity providers are given up after this, they are awaited by clients
+pub const OUTGOING_REQUEST_TIMEOUT_SECONDS: u64 = 10;
+
+pub const USER_ATTRIBUTES_MAX_SIZE_BYTES_INCLUSIVE: usize = 4096;
+pub const USER_ATTRIBUTE_NAME_MAX_LENGTH_INCLUSIVE: usize = 64;
+
+/// Built-in roles are created in every realm and can not be deleted
+pub const DEFAULT_ROLE_NAME: &str = "default";
+pub const ADMIN_ROLE_NAME: &str = "admin";
+
+/// Permissions checked by this module, other services may check their own permissions
+pub const PERMISSION_MANAGE_KEYPAIRS: &str = "auth:keypairs:manage";
+pub const PERMISSION_READ_USERS: &str = "auth:users:read";
+pub const PERMISSION_MANAGE_USERS: &str = "auth:users:manage";
+pub const PERMISSION_MANAGE_ROLES: &str = "auth:roles:manage";
+pub const PERMISSION_MANAGE_GROUPS: &str = "auth:groups:manage";
+pub const PERMISSION_MANAGE_OAUTH_CLIENTS: &str = "auth:oauth_clients:manage";
+pub const PERMISSION_IMPERSONATE_USERS: &str = "auth:users:impersonate";
+pub const AUTH_PERMISSIONS: [&str; 7] = [
+    PERMISSION_MANAGE_KEYPAIRS,
+    PERMISSION_READ_USERS,
+    PERMISSION_MANAGE_USERS,
+    PERMISSION_MANAGE_ROLES,
+    PERMISSION_MANAGE_GROUPS,
+    PERMISSION_MANAGE_OAUTH_CLIENTS,
+    PERMISSION_IMPERSONATE_USERS,
+];
+
 pub const CLIENT_TYPE_HEADER_NAME: &str = "x-client-type";
 pub const CLIENT_TYPE_BROWSER_HEADER_VALUE: &str = "browser";
 pub const CLIENT_TYPE_MOBILE_HEADER_VALUE: &str = "mobile";
@@ -34,6 +179,8 @@ pub const CLIENT_TYPE_PC_HEADER_VALUE: &str = "pc";
 
 pub const SESSION_COOKIE_NAME: &str = "session_id";
 pub const SESSION_COOKIE_EXP_TIMESTAMP_NAME: &str = "session_exp_timestamp";
+/// Holds the state of a federated authorization, so only the client which started it can redeem it
+pub const FEDERATED_STATE_COOKIE_NAME: &str = "federated_state";
 
 pub const SESSION_HEADER_NAME: &str = "x-session-id";
 pub const SESSION_HEADER_EXP_TIMESTAMP_NAME: &str = "x-session-exp-timestamp";
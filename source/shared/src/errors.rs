@@ -84,4 +84,44 @@ impl Error for ErrorWithContext {
 pub enum AppConfigBuilderError {
     #[error(transparent)]
     OriginParsingError(#[from] ParseError),
+    #[error(
+        "password min length ({min_length}) should be positive and not greater than max length ({max_length})"
+    )]
+    PasswordLengthBounds {
+        min_length: usize,
+        max_length: usize,
+    },
+    #[error("password min strength score should be less than or equal to {max_score}, got {score}")]
+    PasswordMinStrengthScore { score: u8, max_score: u8 },
+    #[error(
+        "password hashing params are invalid: time cost ({time_cost}) and parallelism ({parallelism}) should be positive, memory cost ({memory_cost_kib} KiB) should be at least 8 KiB per lane"
+    )]
+    PasswordHashingParams {
+        memory_cost_kib: u32,
+        time_cost: u32,
+        parallelism: u32,
+    },
+    #[error(
+        "signin lockout base duration ({base_lockout_seconds}s) should be positive and not greater than max duration ({max_lockout_seconds}s)"
+    )]
+    SigninLockoutDurations {
+        base_lockout_seconds: usize,
+        max_lockout_seconds: usize,
+    },
+    #[error(
+        "realm ({realm}) should be `<name>` or `<name>@<host>` with a name of lowercase letters, digits and `-` up to {max_length} characters"
+    )]
+    InvalidRealm { realm: String, max_length: usize },
+    #[error("realm with name: {name} is configured more than once")]
+    DuplicateRealm { name: String },
+    #[error("access token issuer should not be empty")]
+    EmptyAccessTokenIssuer,
+    #[error("at least one access token audience should be allowed")]
+    EmptyAccessTokenAudiences,
+    #[error(
+        "oidc provider ({name}) should have a name of lowercase letters, digits and `-` up to {max_length} characters, a client id and the `openid` scope"
+    )]
+    InvalidOidcProvider { name: String, max_length: usize },
+    #[error("oidc provider with name: {name} is configured more than once")]
+    DuplicateOidcProvider { name: String },
 }
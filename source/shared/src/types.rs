@@ -1,4 +1,14 @@
-use crate::define_enum;
+use crate::{
+    constants::{
+        ACCESS_TOKEN_AUDIENCES_COMMA_SEPARATED_DEFAULT, ACCESS_TOKEN_ISSUER_DEFAULT,
+        DEFAULT_REALM_NAME, PASSWORD_ALLOW_SPACES_DEFAULT, PASSWORD_ALLOW_UNICODE_DEFAULT,
+        PASSWORD_HASH_MEMORY_COST_KIB_DEFAULT, PASSWORD_HASH_PARALLELISM_DEFAULT,
+        PASSWORD_HASH_TIME_COST_DEFAULT, PASSWORD_MAX_LENGTH_DEFAULT, PASSWORD_MIN_LENGTH_DEFAULT,
+        PASSWORD_MIN_STRENGTH_SCORE_DEFAULT, SIGNIN_LOCKOUT_BASE_SECONDS_DEFAULT,
+        SIGNIN_LOCKOUT_MAX_FAILED_ATTEMPTS_DEFAULT, SIGNIN_LOCKOUT_MAX_SECONDS_DEFAULT,
+    },
+    define_enum,
+};
 
 #[derive(Clone, Copy, Debug)]
 pub struct SessionExpirationSeconds(pub usize);
@@ -6,12 +16,165 @@ pub struct SessionExpirationSeconds(pub usize);
 #[derive(Clone, Copy, Debug)]
 pub struct AccessTokenExpirationSeconds(pub usize);
 
+/// Groups over the limit are left out of access tokens, which are marked as overflowed then
+#[derive(Clone, Copy, Debug)]
+pub struct AccessTokenMaxGroups(pub usize);
+
+/// Names of user attributes copied into access tokens, other attributes stay in the store
+#[derive(Clone, Debug, Default)]
+pub struct AccessTokenAttributes(pub Vec<String>);
+
+/// Own namespace of users, sessions and keypairs with its own access token issuers and audiences
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Realm {
+    pub name: String,
+    /// Requests to this host are routed to the realm in addition to the `/realms/{name}` prefix
+    pub host: Option<String>,
+    /// Issuer of new access tokens
+    pub issuer: String,
+    /// Issuers accepted on verification besides `issuer`
+    pub trusted_issuers: Vec<String>,
+    /// Audiences clients may request, the first one is used when none is requested
+    pub audiences: Vec<String>,
+}
+
+impl Realm {
+    /// Realm of the same deployment, issuers get the realm path and the realm name goes first in audiences
+    pub fn derive(&self, name: &str, host: Option<&str>) -> Self {
+        Self {
+            name: name.to_string(),
+            host: host.map(|host| host.to_string()),
+            issuer: format!("{}/realms/{name}", self.issuer),
+            trusted_issuers: self
+                .trusted_issuers
+                .iter()
+                .map(|issuer| format!("{issuer}/realms/{name}"))
+                .collect(),
+            audiences: [name.to_string()]
+                .into_iter()
+                .chain(self.audiences.iter().cloned())
+                .collect(),
+        }
+    }
+
+    pub fn default_audience(&self) -> Option<&str> {
+        self.audiences.first().map(String::as_str)
+    }
+
+    pub fn is_default(&self) -> bool {
+        self.name == DEFAULT_REALM_NAME
+    }
+}
+
+impl Default for Realm {
+    fn default() -> Self {
+        Self {
+            name: DEFAULT_REALM_NAME.to_string(),
+            host: None,
+            issuer: ACCESS_TOKEN_ISSUER_DEFAULT.to_string(),
+            trusted_issuers: vec![],
+            audiences: vec![ACCESS_TOKEN_AUDIENCES_COMMA_SEPARATED_DEFAULT.to_string()],
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug)]
 pub struct PostgresDbMaxConnections(pub usize);
 
 define_enum! {
-    pub enum UserRole {
-        Default,
-        Admin,
+    pub enum UserStatus {
+        Active,
+        Suspended,
+        Deleted,
+    }
+}
+
+define_enum! {
+    pub enum PasswordCharacterClass {
+        Uppercase,
+        Lowercase,
+        Digit,
+        Punctuation,
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PasswordPolicy {
+    pub min_length: usize,
+    pub max_length: usize,
+    pub required_character_classes: Vec<PasswordCharacterClass>,
+    pub allow_unicode: bool,
+    pub allow_spaces: bool,
+    /// Minimum zxcvbn strength score in range `0..=4`, `0` disables the check
+    pub min_strength_score: u8,
+}
+
+impl Default for PasswordPolicy {
+    fn default() -> Self {
+        Self {
+            min_length: PASSWORD_MIN_LENGTH_DEFAULT,
+            max_length: PASSWORD_MAX_LENGTH_DEFAULT,
+            required_character_classes: vec![
+                PasswordCharacterClass::Uppercase,
+                PasswordCharacterClass::Lowercase,
+                PasswordCharacterClass::Digit,
+                PasswordCharacterClass::Punctuation,
+            ],
+            allow_unicode: PASSWORD_ALLOW_UNICODE_DEFAULT,
+            allow_spaces: PASSWORD_ALLOW_SPACES_DEFAULT,
+            min_strength_score: PASSWORD_MIN_STRENGTH_SCORE_DEFAULT,
+        }
+    }
+}
+
+/// Upstream OpenID Connect provider users can sign in with and link to their accounts
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OidcProvider {
+    /// Identifies the provider in routes and linked identities
+    pub name: String,
+    /// Endpoints and signing keys are discovered at `{issuer}/.well-known/openid-configuration`
+    pub issuer: String,
+    pub client_id: String,
+    pub client_secret: String,
+    /// Page the provider redirects users back to, it has to be registered at the provider
+    pub redirect_uri: String,
+    pub scopes: Vec<String>,
+}
+
+/// Argon2id cost parameters used for new password hashes
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PasswordHashingParams {
+    pub memory_cost_kib: u32,
+    pub time_cost: u32,
+    pub parallelism: u32,
+}
+
+impl Default for PasswordHashingParams {
+    fn default() -> Self {
+        Self {
+            memory_cost_kib: PASSWORD_HASH_MEMORY_COST_KIB_DEFAULT,
+            time_cost: PASSWORD_HASH_TIME_COST_DEFAULT,
+            parallelism: PASSWORD_HASH_PARALLELISM_DEFAULT,
+        }
+    }
+}
+
+/// Temporary account lockout after repeated failed signins, each failure past the
+/// threshold doubles the lockout duration up to `max_lockout_seconds`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SigninLockoutPolicy {
+    /// Failed attempts before the first lockout, `0` disables lockout
+    pub max_failed_attempts: u32,
+    pub base_lockout_seconds: usize,
+    pub max_lockout_seconds: usize,
+}
+
+impl Default for SigninLockoutPolicy {
+    fn default() -> Self {
+        Self {
+            max_failed_attempts: SIGNIN_LOCKOUT_MAX_FAILED_ATTEMPTS_DEFAULT,
+            base_lockout_seconds: SIGNIN_LOCKOUT_BASE_SECONDS_DEFAULT,
+            max_lockout_seconds: SIGNIN_LOCKOUT_MAX_SECONDS_DEFAULT,
+        }
     }
 }
@@ -1,2 +1,3 @@
 pub mod mocks;
+pub mod stub_identity_provider;
 pub mod utils;
@@ -2,22 +2,49 @@ use std::sync::Arc;
 
 use dashmap::DashMap;
 use nimbus_auth_domain::{
-    entities::{Entity, keypair::SomeKeyPair, session::SomeSession, user::User},
+    entities::{
+        Entity,
+        api_key::ApiKey,
+        authorization_code::AuthorizationCode,
+        device_authorization::DeviceAuthorization,
+        external_identity::ExternalIdentity,
+        federated_authorization::FederatedAuthorization,
+        group::{Group, value_objects::group_name::GroupName},
+        impersonation::Impersonation,
+        keypair::SomeKeyPair,
+        oauth_client::OAuthClient,
+        role::{Role, value_objects::role_name::RoleName},
+        session::SomeSession,
+        user::{SomeUser, User},
+    },
     value_objects::identifier::Identifier,
 };
 use ulid::Ulid;
 
+use crate::utils::get_built_in_roles;
+
 pub struct MockDatastore {
-    users: Arc<DashMap<Identifier<Ulid, User>, User>>,
+    users: Arc<DashMap<Identifier<Ulid, User>, SomeUser<'static>>>,
     sessions: Arc<DashMap<Identifier<Ulid, SomeSession<'static>>, SomeSession<'static>>>,
     keypairs: Arc<DashMap<Identifier<Ulid, SomeKeyPair<'static>>, SomeKeyPair<'static>>>,
+    roles: Arc<DashMap<RoleName, Role>>,
+    groups: Arc<DashMap<GroupName, Group>>,
+    oauth_clients: Arc<DashMap<Identifier<Ulid, OAuthClient>, OAuthClient>>,
+    authorization_codes: Arc<DashMap<Identifier<Ulid, AuthorizationCode>, AuthorizationCode>>,
+    api_keys: Arc<DashMap<Identifier<Ulid, ApiKey>, ApiKey>>,
+    device_authorizations: Arc<DashMap<Identifier<Ulid, DeviceAuthorization>, DeviceAuthorization>>,
+    impersonations: Arc<DashMap<Identifier<Ulid, Impersonation>, Impersonation>>,
+    external_identities: Arc<DashMap<Identifier<Ulid, ExternalIdentity>, ExternalIdentity>>,
+    federated_authorizations:
+        Arc<DashMap<Identifier<Ulid, FederatedAuthorization>, FederatedAuthorization>>,
 }
 
 impl MockDatastore {
     pub fn new(
-        users: Option<Vec<User>>,
+        users: Option<Vec<SomeUser<'static>>>,
         sessions: Option<Vec<SomeSession<'static>>>,
         keypairs: Option<Vec<SomeKeyPair<'static>>>,
+        oauth_clients: Option<Vec<OAuthClient>>,
     ) -> Self {
         Self {
             users: Arc::new(
@@ -41,10 +68,31 @@ impl MockDatastore {
                     .map(|keypair| (keypair.id().clone(), keypair))
                     .collect(),
             ),
+            // built-in roles are always there, like after migrations
+            roles: Arc::new(
+                get_built_in_roles()
+                    .into_iter()
+                    .map(|role| (role.name().clone(), role))
+                    .collect(),
+            ),
+            groups: Arc::new(DashMap::new()),
+            oauth_clients: Arc::new(
+                oauth_clients
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|client| (client.id().clone(), client))
+                    .collect(),
+            ),
+            authorization_codes: Arc::new(DashMap::new()),
+            api_keys: Arc::new(DashMap::new()),
+            device_authorizations: Arc::new(DashMap::new()),
+            impersonations: Arc::new(DashMap::new()),
+            external_identities: Arc::new(DashMap::new()),
+            federated_authorizations: Arc::new(DashMap::new()),
         }
     }
 
-    pub fn users(&self) -> Arc<DashMap<Identifier<Ulid, User>, User>> {
+    pub fn users(&self) -> Arc<DashMap<Identifier<Ulid, User>, SomeUser<'static>>> {
         self.users.clone()
     }
 
@@ -59,4 +107,48 @@ impl MockDatastore {
     ) -> Arc<DashMap<Identifier<Ulid, SomeKeyPair<'static>>, SomeKeyPair<'static>>> {
         self.keypairs.clone()
     }
+
+    pub fn roles(&self) -> Arc<DashMap<RoleName, Role>> {
+        self.roles.clone()
+    }
+
+    pub fn groups(&self) -> Arc<DashMap<GroupName, Group>> {
+        self.groups.clone()
+    }
+
+    pub fn oauth_clients(&self) -> Arc<DashMap<Identifier<Ulid, OAuthClient>, OAuthClient>> {
+        self.oauth_clients.clone()
+    }
+
+    pub fn authorization_codes(
+        &self,
+    ) -> Arc<DashMap<Identifier<Ulid, AuthorizationCode>, AuthorizationCode>> {
+        self.authorization_codes.clone()
+    }
+
+    pub fn api_keys(&self) -> Arc<DashMap<Identifier<Ulid, ApiKey>, ApiKey>> {
+        self.api_keys.clone()
+    }
+
+    pub fn device_authorizations(
+        &self,
+    ) -> Arc<DashMap<Identifier<Ulid, DeviceAuthorization>, DeviceAuthorization>> {
+        self.device_authorizations.clone()
+    }
+
+    pub fn impersonations(&self) -> Arc<DashMap<Identifier<Ulid, Impersonation>, Impersonation>> {
+        self.impersonations.clone()
+    }
+
+    pub fn external_identities(
+        &self,
+    ) -> Arc<DashMap<Identifier<Ulid, ExternalIdentity>, ExternalIdentity>> {
+        self.external_identities.clone()
+    }
+
+    pub fn federated_authorizations(
+        &self,
+    ) -> Arc<DashMap<Identifier<Ulid, FederatedAuthorization>, FederatedAuthorization>> {
+        self.federated_authorizations.clone()
+    }
 }
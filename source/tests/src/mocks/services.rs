@@ -1,3 +1,14 @@
+pub mod api_key_repository;
+pub mod authorization_code_repository;
+pub mod device_authorization_repository;
+pub mod external_identity_repository;
+pub mod federated_authorization_repository;
+pub mod group_repository;
+pub mod impersonation_repository;
 pub mod keypair_repository;
+pub mod legacy_authenticator;
+pub mod oauth_client_repository;
+pub mod role_repository;
 pub mod session_repository;
+pub mod signup_notifier;
 pub mod user_repository;
@@ -0,0 +1,68 @@
+use std::sync::Arc;
+
+use nimbus_auth_application::services::api_key_repository::{
+    ApiKeyRepository, errors::ApiKeyRepositoryError,
+};
+use nimbus_auth_domain::{
+    entities::{Entity, api_key::ApiKey, user::User},
+    value_objects::identifier::Identifier,
+};
+use nimbus_auth_shared::futures::{StaticPinnedFuture, pin_static_future};
+use ulid::Ulid;
+
+use crate::mocks::datastore::MockDatastore;
+
+pub struct MockApiKeyRepository {
+    datastore: Arc<MockDatastore>,
+}
+
+impl MockApiKeyRepository {
+    pub fn new(datastore: Arc<MockDatastore>) -> Self {
+        MockApiKeyRepository { datastore }
+    }
+}
+
+impl ApiKeyRepository for MockApiKeyRepository {
+    fn get_by_id(
+        &self,
+        id: &Identifier<Ulid, ApiKey>,
+    ) -> StaticPinnedFuture<Option<ApiKey>, ApiKeyRepositoryError> {
+        let datastore_clone = self.datastore.clone();
+        let id = id.clone();
+        pin_static_future(async move {
+            Ok(datastore_clone
+                .api_keys()
+                .get(&id)
+                .map(|api_key_ref| api_key_ref.value().clone()))
+        })
+    }
+
+    fn get_by_user_id(
+        &self,
+        user_id: &Identifier<Ulid, User>,
+    ) -> StaticPinnedFuture<Vec<ApiKey>, ApiKeyRepositoryError> {
+        let datastore_clone = self.datastore.clone();
+        let user_id = user_id.clone();
+        pin_static_future(async move {
+            let mut api_keys: Vec<ApiKey> = datastore_clone
+                .api_keys()
+                .iter()
+                .filter(|api_key_ref| api_key_ref.value().user_id() == &user_id)
+                .map(|api_key_ref| api_key_ref.value().clone())
+                .collect();
+            api_keys.sort_by_key(|api_key| (*api_key.created_at(), api_key.id().to_string()));
+            Ok(api_keys)
+        })
+    }
+
+    fn save(&self, api_key: &ApiKey) -> StaticPinnedFuture<(), ApiKeyRepositoryError> {
+        let datastore_clone = self.datastore.clone();
+        let api_key = api_key.clone();
+        pin_static_future(async move {
+            datastore_clone
+                .api_keys()
+                .insert(api_key.id().clone(), api_key);
+            Ok(())
+        })
+    }
+}
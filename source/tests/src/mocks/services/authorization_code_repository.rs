@@ -0,0 +1,53 @@
+use std::sync::Arc;
+
+use nimbus_auth_application::services::authorization_code_repository::{
+    AuthorizationCodeRepository, errors::AuthorizationCodeRepositoryError,
+};
+use nimbus_auth_domain::{
+    entities::{Entity, authorization_code::AuthorizationCode},
+    value_objects::identifier::Identifier,
+};
+use nimbus_auth_shared::futures::{StaticPinnedFuture, pin_static_future};
+use ulid::Ulid;
+
+use crate::mocks::datastore::MockDatastore;
+
+pub struct MockAuthorizationCodeRepository {
+    datastore: Arc<MockDatastore>,
+}
+
+impl MockAuthorizationCodeRepository {
+    pub fn new(datastore: Arc<MockDatastore>) -> Self {
+        MockAuthorizationCodeRepository { datastore }
+    }
+}
+
+impl AuthorizationCodeRepository for MockAuthorizationCodeRepository {
+    fn save(
+        &self,
+        code: &AuthorizationCode,
+    ) -> StaticPinnedFuture<(), AuthorizationCodeRepositoryError> {
+        let datastore_clone = self.datastore.clone();
+        let code = code.clone();
+        pin_static_future(async move {
+            datastore_clone
+                .authorization_codes()
+                .insert(code.id().clone(), code);
+            Ok(())
+        })
+    }
+
+    fn take(
+        &self,
+        id: &Identifier<Ulid, AuthorizationCode>,
+    ) -> StaticPinnedFuture<Option<AuthorizationCode>, AuthorizationCodeRepositoryError> {
+        let datastore_clone = self.datastore.clone();
+        let id = id.clone();
+        pin_static_future(async move {
+            Ok(datastore_clone
+                .authorization_codes()
+                .remove(&id)
+                .map(|(_, code)| code))
+        })
+    }
+}
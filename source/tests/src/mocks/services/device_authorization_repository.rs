@@ -0,0 +1,86 @@
+use std::sync::Arc;
+
+use nimbus_auth_application::services::device_authorization_repository::{
+    DeviceAuthorizationRepository, errors::DeviceAuthorizationRepositoryError,
+};
+use nimbus_auth_domain::{
+    entities::{
+        Entity,
+        device_authorization::{DeviceAuthorization, value_objects::user_code::UserCode},
+    },
+    value_objects::identifier::Identifier,
+};
+use nimbus_auth_shared::futures::{StaticPinnedFuture, pin_static_future};
+use ulid::Ulid;
+
+use crate::mocks::datastore::MockDatastore;
+
+pub struct MockDeviceAuthorizationRepository {
+    datastore: Arc<MockDatastore>,
+}
+
+impl MockDeviceAuthorizationRepository {
+    pub fn new(datastore: Arc<MockDatastore>) -> Self {
+        MockDeviceAuthorizationRepository { datastore }
+    }
+}
+
+impl DeviceAuthorizationRepository for MockDeviceAuthorizationRepository {
+    fn get_by_id(
+        &self,
+        id: &Identifier<Ulid, DeviceAuthorization>,
+    ) -> StaticPinnedFuture<Option<DeviceAuthorization>, DeviceAuthorizationRepositoryError> {
+        let datastore_clone = self.datastore.clone();
+        let id = id.clone();
+        pin_static_future(async move {
+            Ok(datastore_clone
+                .device_authorizations()
+                .get(&id)
+                .map(|device_authorization| device_authorization.clone()))
+        })
+    }
+
+    fn get_by_user_code(
+        &self,
+        user_code: &UserCode,
+    ) -> StaticPinnedFuture<Option<DeviceAuthorization>, DeviceAuthorizationRepositoryError> {
+        let datastore_clone = self.datastore.clone();
+        let user_code = user_code.clone();
+        pin_static_future(async move {
+            Ok(datastore_clone
+                .device_authorizations()
+                .iter()
+                .filter(|device_authorization| device_authorization.user_code() == &user_code)
+                .max_by_key(|device_authorization| device_authorization.expires_at())
+                .map(|device_authorization| device_authorization.clone()))
+        })
+    }
+
+    fn save(
+        &self,
+        device_authorization: &DeviceAuthorization,
+    ) -> StaticPinnedFuture<(), DeviceAuthorizationRepositoryError> {
+        let datastore_clone = self.datastore.clone();
+        let device_authorization = device_authorization.clone();
+        pin_static_future(async move {
+            datastore_clone
+                .device_authorizations()
+                .insert(device_authorization.id().clone(), device_authorization);
+            Ok(())
+        })
+    }
+
+    fn take(
+        &self,
+        id: &Identifier<Ulid, DeviceAuthorization>,
+    ) -> StaticPinnedFuture<Option<DeviceAuthorization>, DeviceAuthorizationRepositoryError> {
+        let datastore_clone = self.datastore.clone();
+        let id = id.clone();
+        pin_static_future(async move {
+            Ok(datastore_clone
+                .device_authorizations()
+                .remove(&id)
+                .map(|(_, device_authorization)| device_authorization))
+        })
+    }
+}
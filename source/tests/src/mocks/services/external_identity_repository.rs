@@ -0,0 +1,89 @@
+use std::sync::Arc;
+
+use nimbus_auth_application::services::external_identity_repository::{
+    ExternalIdentityRepository, errors::ExternalIdentityRepositoryError,
+};
+use nimbus_auth_domain::{
+    entities::{Entity, external_identity::ExternalIdentity, user::User},
+    value_objects::identifier::Identifier,
+};
+use nimbus_auth_shared::futures::{StaticPinnedFuture, pin_static_future};
+use ulid::Ulid;
+
+use crate::mocks::datastore::MockDatastore;
+
+pub struct MockExternalIdentityRepository {
+    datastore: Arc<MockDatastore>,
+}
+
+impl MockExternalIdentityRepository {
+    pub fn new(datastore: Arc<MockDatastore>) -> Self {
+        MockExternalIdentityRepository { datastore }
+    }
+}
+
+impl ExternalIdentityRepository for MockExternalIdentityRepository {
+    fn get_by_subject(
+        &self,
+        provider: &str,
+        subject: &str,
+    ) -> StaticPinnedFuture<Option<ExternalIdentity>, ExternalIdentityRepositoryError> {
+        let datastore_clone = self.datastore.clone();
+        let provider = provider.to_string();
+        let subject = subject.to_string();
+        pin_static_future(async move {
+            Ok(datastore_clone
+                .external_identities()
+                .iter()
+                .find(|identity_ref| {
+                    identity_ref.value().provider() == provider
+                        && identity_ref.value().subject() == subject
+                })
+                .map(|identity_ref| identity_ref.value().clone()))
+        })
+    }
+
+    fn get_by_user_id(
+        &self,
+        user_id: &Identifier<Ulid, User>,
+    ) -> StaticPinnedFuture<Vec<ExternalIdentity>, ExternalIdentityRepositoryError> {
+        let datastore_clone = self.datastore.clone();
+        let user_id = user_id.clone();
+        pin_static_future(async move {
+            let mut identities: Vec<ExternalIdentity> = datastore_clone
+                .external_identities()
+                .iter()
+                .filter(|identity_ref| identity_ref.value().user_id() == &user_id)
+                .map(|identity_ref| identity_ref.value().clone())
+                .collect();
+            identities.sort_by_key(|identity| (identity.linked_at(), identity.id().to_string()));
+            Ok(identities)
+        })
+    }
+
+    fn save(
+        &self,
+        identity: &ExternalIdentity,
+    ) -> StaticPinnedFuture<(), ExternalIdentityRepositoryError> {
+        let datastore_clone = self.datastore.clone();
+        let identity = identity.clone();
+        pin_static_future(async move {
+            datastore_clone
+                .external_identities()
+                .insert(identity.id().clone(), identity);
+            Ok(())
+        })
+    }
+
+    fn delete(
+        &self,
+        identity: &ExternalIdentity,
+    ) -> StaticPinnedFuture<(), ExternalIdentityRepositoryError> {
+        let datastore_clone = self.datastore.clone();
+        let id = identity.id().clone();
+        pin_static_future(async move {
+            datastore_clone.external_identities().remove(&id);
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,54 @@
+use std::sync::Arc;
+
+use nimbus_auth_application::services::federated_authorization_repository::{
+    FederatedAuthorizationRepository, errors::FederatedAuthorizationRepositoryError,
+};
+use nimbus_auth_domain::{
+    entities::{Entity, federated_authorization::FederatedAuthorization},
+    value_objects::identifier::Identifier,
+};
+use nimbus_auth_shared::futures::{StaticPinnedFuture, pin_static_future};
+use ulid::Ulid;
+
+use crate::mocks::datastore::MockDatastore;
+
+pub struct MockFederatedAuthorizationRepository {
+    datastore: Arc<MockDatastore>,
+}
+
+impl MockFederatedAuthorizationRepository {
+    pub fn new(datastore: Arc<MockDatastore>) -> Self {
+        MockFederatedAuthorizationRepository { datastore }
+    }
+}
+
+impl FederatedAuthorizationRepository for MockFederatedAuthorizationRepository {
+    fn save(
+        &self,
+        authorization: &FederatedAuthorization,
+    ) -> StaticPinnedFuture<(), FederatedAuthorizationRepositoryError> {
+        let datastore_clone = self.datastore.clone();
+        let authorization = authorization.clone();
+        pin_static_future(async move {
+            datastore_clone
+                .federated_authorizations()
+                .insert(authorization.id().clone(), authorization);
+            Ok(())
+        })
+    }
+
+    fn take(
+        &self,
+        id: &Identifier<Ulid, FederatedAuthorization>,
+    ) -> StaticPinnedFuture<Option<FederatedAuthorization>, FederatedAuthorizationRepositoryError>
+    {
+        let datastore_clone = self.datastore.clone();
+        let id = id.clone();
+        pin_static_future(async move {
+            Ok(datastore_clone
+                .federated_authorizations()
+                .remove(&id)
+                .map(|(_, authorization)| authorization))
+        })
+    }
+}
@@ -0,0 +1,99 @@
+use std::sync::Arc;
+
+use nimbus_auth_application::services::group_repository::{
+    GroupRepository, errors::GroupRepositoryError,
+};
+use nimbus_auth_domain::entities::{
+    Entity,
+    group::{Group, value_objects::group_name::GroupName},
+};
+use nimbus_auth_shared::futures::{StaticPinnedFuture, pin_static_future};
+
+use crate::mocks::datastore::MockDatastore;
+
+pub struct MockGroupRepository {
+    datastore: Arc<MockDatastore>,
+}
+
+impl MockGroupRepository {
+    pub fn new(datastore: Arc<MockDatastore>) -> Self {
+        MockGroupRepository { datastore }
+    }
+}
+
+impl GroupRepository for MockGroupRepository {
+    fn get_by_name(
+        &self,
+        name: &GroupName,
+    ) -> StaticPinnedFuture<Option<Group>, GroupRepositoryError> {
+        let datastore_clone = self.datastore.clone();
+        let name = name.clone();
+        pin_static_future(async move {
+            Ok(datastore_clone
+                .groups()
+                .get(&name)
+                .map(|group_ref| group_ref.value().clone()))
+        })
+    }
+
+    fn get_by_names(
+        &self,
+        names: &[GroupName],
+    ) -> StaticPinnedFuture<Vec<Group>, GroupRepositoryError> {
+        let datastore_clone = self.datastore.clone();
+        let names = names.to_vec();
+        pin_static_future(async move {
+            Ok(names
+                .iter()
+                .filter_map(|name| {
+                    datastore_clone
+                        .groups()
+                        .get(name)
+                        .map(|group_ref| group_ref.value().clone())
+                })
+                .collect())
+        })
+    }
+
+    fn list(&self) -> StaticPinnedFuture<Vec<Group>, GroupRepositoryError> {
+        let datastore_clone = self.datastore.clone();
+        pin_static_future(async move {
+            let mut groups: Vec<Group> = datastore_clone
+                .groups()
+                .iter()
+                .map(|group_ref| group_ref.value().clone())
+                .collect();
+            groups.sort_by(|a, b| a.name().cmp(b.name()));
+            Ok(groups)
+        })
+    }
+
+    fn save(&self, group: &Group) -> StaticPinnedFuture<(), GroupRepositoryError> {
+        let datastore_clone = self.datastore.clone();
+        let group = group.clone();
+        pin_static_future(async move {
+            datastore_clone.groups().insert(group.name().clone(), group);
+            Ok(())
+        })
+    }
+
+    fn delete(&self, group: &Group) -> StaticPinnedFuture<(), GroupRepositoryError> {
+        let datastore_clone = self.datastore.clone();
+        let group = group.clone();
+        pin_static_future(async move {
+            datastore_clone.groups().remove(group.name());
+            // memberships go away with the group, like with the cascade in db
+            for mut user_ref in datastore_clone.users().iter_mut() {
+                let groups = user_ref
+                    .groups()
+                    .iter()
+                    .filter(|user_group| user_group.id() != group.id())
+                    .cloned()
+                    .collect::<Vec<_>>();
+                let user = user_ref.value().clone().with_groups(&groups);
+                *user_ref.value_mut() = user;
+            }
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use nimbus_auth_application::services::impersonation_repository::{
+    ImpersonationRepository, errors::ImpersonationRepositoryError,
+};
+use nimbus_auth_domain::entities::{Entity, impersonation::Impersonation};
+use nimbus_auth_shared::futures::{StaticPinnedFuture, pin_static_future};
+
+use crate::mocks::datastore::MockDatastore;
+
+pub struct MockImpersonationRepository {
+    datastore: Arc<MockDatastore>,
+}
+
+impl MockImpersonationRepository {
+    pub fn new(datastore: Arc<MockDatastore>) -> Self {
+        MockImpersonationRepository { datastore }
+    }
+}
+
+impl ImpersonationRepository for MockImpersonationRepository {
+    fn save(
+        &self,
+        impersonation: &Impersonation,
+    ) -> StaticPinnedFuture<(), ImpersonationRepositoryError> {
+        let datastore_clone = self.datastore.clone();
+        let impersonation = impersonation.clone();
+        pin_static_future(async move {
+            datastore_clone
+                .impersonations()
+                .insert(impersonation.id().clone(), impersonation);
+            Ok(())
+        })
+    }
+}
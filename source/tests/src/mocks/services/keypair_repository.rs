@@ -76,6 +76,25 @@ impl KeyPairRepository for MockKeyPairRepository {
         })
     }
 
+    fn get_verifying(
+        &self,
+    ) -> StaticPinnedFuture<Vec<SomeKeyPair<'static>>, KeyPairRepositoryError> {
+        let datastore_clone: Arc<MockDatastore> = self.datastore.clone();
+        pin_static_future(async move {
+            Ok(datastore_clone
+                .keypairs()
+                .iter()
+                .filter(|entry| {
+                    matches!(
+                        entry.value(),
+                        SomeKeyPair::Active(_) | SomeKeyPair::Expiring(_)
+                    )
+                })
+                .map(|entry| entry.value().clone())
+                .collect())
+        })
+    }
+
     fn save(&self, keypair: SomeKeyPair) -> StaticPinnedFuture<(), KeyPairRepositoryError> {
         let datastore_clone: Arc<MockDatastore> = self.datastore.clone();
         let keypair_clone = keypair.into_owned();
@@ -0,0 +1,64 @@
+use std::{collections::HashMap, sync::Arc, time::Duration};
+
+use nimbus_auth_application::services::legacy_authenticator::{
+    LegacyAuthenticator, errors::LegacyAuthenticatorError,
+};
+use nimbus_auth_domain::entities::user::value_objects::{password::Password, user_name::UserName};
+use nimbus_auth_shared::futures::{StaticPinnedFuture, pin_static_future};
+use tokio::{sync::Mutex, time::sleep};
+
+/// Legacy backend with plain text credentials, records who it was asked about
+/// so tests can check that migrated users no longer go through it
+#[derive(Clone, Default)]
+pub struct MockLegacyAuthenticator {
+    credentials: Arc<HashMap<String, String>>,
+    authentications: Arc<Mutex<Vec<String>>>,
+    latency: Duration,
+}
+
+impl MockLegacyAuthenticator {
+    pub fn new(credentials: &[(&str, &str)]) -> Self {
+        Self {
+            credentials: Arc::new(
+                credentials
+                    .iter()
+                    .map(|(user_name, password)| (user_name.to_string(), password.to_string()))
+                    .collect(),
+            ),
+            authentications: Arc::new(Mutex::new(Vec::new())),
+            latency: Duration::ZERO,
+        }
+    }
+
+    /// Every authentication takes at least this long, like a call to a remote backend
+    pub fn with_latency(mut self, latency: Duration) -> Self {
+        self.latency = latency;
+        self
+    }
+
+    pub async fn authentications(&self) -> Vec<String> {
+        self.authentications.lock().await.clone()
+    }
+}
+
+impl LegacyAuthenticator for MockLegacyAuthenticator {
+    fn authenticate(
+        &self,
+        user_name: &UserName,
+        password: &Password,
+    ) -> StaticPinnedFuture<bool, LegacyAuthenticatorError> {
+        let credentials = self.credentials.clone();
+        let authentications = self.authentications.clone();
+        let user_name = user_name.value().to_string();
+        let password = password.value().to_string();
+        let latency = self.latency;
+        pin_static_future(async move {
+            sleep(latency).await;
+            let is_authenticated = credentials
+                .get(&user_name)
+                .is_some_and(|legacy_password| *legacy_password == password);
+            authentications.lock().await.push(user_name);
+            Ok(is_authenticated)
+        })
+    }
+}
@@ -0,0 +1,50 @@
+use std::sync::Arc;
+
+use nimbus_auth_application::services::oauth_client_repository::{
+    OAuthClientRepository, errors::OAuthClientRepositoryError,
+};
+use nimbus_auth_domain::{
+    entities::{Entity, oauth_client::OAuthClient},
+    value_objects::identifier::Identifier,
+};
+use nimbus_auth_shared::futures::{StaticPinnedFuture, pin_static_future};
+use ulid::Ulid;
+
+use crate::mocks::datastore::MockDatastore;
+
+pub struct MockOAuthClientRepository {
+    datastore: Arc<MockDatastore>,
+}
+
+impl MockOAuthClientRepository {
+    pub fn new(datastore: Arc<MockDatastore>) -> Self {
+        MockOAuthClientRepository { datastore }
+    }
+}
+
+impl OAuthClientRepository for MockOAuthClientRepository {
+    fn get_by_id(
+        &self,
+        id: &Identifier<Ulid, OAuthClient>,
+    ) -> StaticPinnedFuture<Option<OAuthClient>, OAuthClientRepositoryError> {
+        let datastore_clone = self.datastore.clone();
+        let id = id.clone();
+        pin_static_future(async move {
+            Ok(datastore_clone
+                .oauth_clients()
+                .get(&id)
+                .map(|client_ref| client_ref.value().clone()))
+        })
+    }
+
+    fn save(&self, client: &OAuthClient) -> StaticPinnedFuture<(), OAuthClientRepositoryError> {
+        let datastore_clone = self.datastore.clone();
+        let client = client.clone();
+        pin_static_future(async move {
+            datastore_clone
+                .oauth_clients()
+                .insert(client.id().clone(), client);
+            Ok(())
+        })
+    }
+}
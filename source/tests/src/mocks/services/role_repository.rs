@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use nimbus_auth_application::services::role_repository::{
+    RoleRepository, errors::RoleRepositoryError,
+};
+use nimbus_auth_domain::entities::role::{Role, value_objects::role_name::RoleName};
+use nimbus_auth_shared::futures::{StaticPinnedFuture, pin_static_future};
+
+use crate::mocks::datastore::MockDatastore;
+
+pub struct MockRoleRepository {
+    datastore: Arc<MockDatastore>,
+}
+
+impl MockRoleRepository {
+    pub fn new(datastore: Arc<MockDatastore>) -> Self {
+        MockRoleRepository { datastore }
+    }
+}
+
+impl RoleRepository for MockRoleRepository {
+    fn get_by_name(
+        &self,
+        name: &RoleName,
+    ) -> StaticPinnedFuture<Option<Role>, RoleRepositoryError> {
+        let datastore_clone = self.datastore.clone();
+        let name = name.clone();
+        pin_static_future(async move {
+            Ok(datastore_clone
+                .roles()
+                .get(&name)
+                .map(|role_ref| role_ref.value().clone()))
+        })
+    }
+
+    fn get_by_names(
+        &self,
+        names: &[RoleName],
+    ) -> StaticPinnedFuture<Vec<Role>, RoleRepositoryError> {
+        let datastore_clone = self.datastore.clone();
+        let names = names.to_vec();
+        pin_static_future(async move {
+            Ok(names
+                .iter()
+                .filter_map(|name| {
+                    datastore_clone
+                        .roles()
+                        .get(name)
+                        .map(|role_ref| role_ref.value().clone())
+                })
+                .collect())
+        })
+    }
+
+    fn list(&self) -> StaticPinnedFuture<Vec<Role>, RoleRepositoryError> {
+        let datastore_clone = self.datastore.clone();
+        pin_static_future(async move {
+            let mut roles: Vec<Role> = datastore_clone
+                .roles()
+                .iter()
+                .map(|role_ref| role_ref.value().clone())
+                .collect();
+            roles.sort_by(|a, b| a.name().cmp(b.name()));
+            Ok(roles)
+        })
+    }
+
+    fn save(&self, role: &Role) -> StaticPinnedFuture<(), RoleRepositoryError> {
+        let datastore_clone = self.datastore.clone();
+        let role = role.clone();
+        pin_static_future(async move {
+            datastore_clone.roles().insert(role.name().clone(), role);
+            Ok(())
+        })
+    }
+
+    fn delete(&self, role: &Role) -> StaticPinnedFuture<(), RoleRepositoryError> {
+        let datastore_clone = self.datastore.clone();
+        let name = role.name().clone();
+        pin_static_future(async move {
+            datastore_clone.roles().remove(&name);
+            Ok(())
+        })
+    }
+}
@@ -4,10 +4,15 @@ use nimbus_auth_application::services::session_repository::{
     SessionRepository, SessionRepositoryWithTransaction, errors::SessionRepositoryError,
 };
 use nimbus_auth_domain::{
-    entities::{Entity, session::SomeSession},
+    entities::{
+        Entity,
+        session::{Active, Session, SomeSession},
+        user::User,
+    },
     value_objects::identifier::Identifier,
 };
 use nimbus_auth_shared::futures::{StaticPinnedFuture, pin_static_future};
+use time::OffsetDateTime;
 use tokio::sync::Mutex;
 use ulid::Ulid;
 
@@ -63,6 +68,22 @@ impl SessionRepository for MockSessionRepository {
         })
     }
 
+    fn get_active_by_user_id(
+        &self,
+        user_id: &Identifier<Ulid, User>,
+        current_time: OffsetDateTime,
+    ) -> StaticPinnedFuture<Vec<Session<Active>>, SessionRepositoryError> {
+        let datastore_clone = self.datastore.clone();
+        let user_id_clone = user_id.clone();
+        pin_static_future(async move {
+            Ok(get_active_sessions_by_user_id(
+                &datastore_clone,
+                &user_id_clone,
+                current_time,
+            ))
+        })
+    }
+
     fn save(&self, session: SomeSession) -> StaticPinnedFuture<(), SessionRepositoryError> {
         let datastore_clone = self.datastore.clone();
         let session_clone = session.into_owned();
@@ -119,6 +140,25 @@ impl<'a> SessionRepositoryWithTransaction for MockSessionRepositoryWithTransacti
         })
     }
 
+    fn get_active_by_user_id(
+        self: Box<Self>,
+        user_id: &Identifier<Ulid, User>,
+        current_time: OffsetDateTime,
+    ) -> StaticPinnedFuture<
+        (
+            Box<dyn SessionRepositoryWithTransaction>,
+            Vec<Session<Active>>,
+        ),
+        SessionRepositoryError,
+    > {
+        let user_id_clone = user_id.clone();
+        pin_static_future(async move {
+            let sessions =
+                get_active_sessions_by_user_id(&self.datastore, &user_id_clone, current_time);
+            Ok((self as Box<dyn SessionRepositoryWithTransaction>, sessions))
+        })
+    }
+
     fn save(
         self: Box<Self>,
         session: SomeSession,
@@ -145,3 +185,22 @@ impl<'a> SessionRepositoryWithTransaction for MockSessionRepositoryWithTransacti
         })
     }
 }
+
+fn get_active_sessions_by_user_id(
+    datastore: &MockDatastore,
+    user_id: &Identifier<Ulid, User>,
+    current_time: OffsetDateTime,
+) -> Vec<Session<Active>> {
+    datastore
+        .sessions()
+        .iter()
+        .filter_map(|session_ref| match session_ref.value() {
+            SomeSession::Active(session)
+                if session.user_claims().id() == user_id && session.expires_at() > current_time =>
+            {
+                Some(session.clone().into_owned())
+            }
+            _ => None,
+        })
+        .collect()
+}
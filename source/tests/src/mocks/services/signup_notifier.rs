@@ -0,0 +1,36 @@
+use std::sync::Arc;
+
+use nimbus_auth_application::services::signup_notifier::{
+    SignUpNotification, SignUpNotifier, errors::SignUpNotifierError,
+};
+use nimbus_auth_shared::futures::{StaticPinnedFuture, pin_static_future};
+use tokio::sync::Mutex;
+
+/// Keeps delivered notifications in memory so tests can check the outcomes hidden from the client
+#[derive(Clone, Default)]
+pub struct MockSignUpNotifier {
+    notifications: Arc<Mutex<Vec<SignUpNotification>>>,
+}
+
+impl MockSignUpNotifier {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub async fn notifications(&self) -> Vec<SignUpNotification> {
+        self.notifications.lock().await.clone()
+    }
+}
+
+impl SignUpNotifier for MockSignUpNotifier {
+    fn notify(
+        &self,
+        notification: SignUpNotification,
+    ) -> StaticPinnedFuture<(), SignUpNotifierError> {
+        let notifications = self.notifications.clone();
+        pin_static_future(async move {
+            notifications.lock().await.push(notification);
+            Ok(())
+        })
+    }
+}
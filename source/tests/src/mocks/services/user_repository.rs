@@ -1,13 +1,15 @@
 use std::sync::Arc;
 
+use dashmap::DashMap;
 use nimbus_auth_application::services::user_repository::{
-    UserRepository, UserRepositoryWithTransaction, errors::UserRepositoryError,
+    UserListFilter, UserRepository, UserRepositoryWithTransaction, errors::UserRepositoryError,
 };
 use nimbus_auth_domain::{
     entities::{
         Entity,
+        external_identity::ExternalIdentity,
         session::{Active, Session},
-        user::{User, value_objects::user_name::UserName},
+        user::{SomeUser, User, value_objects::user_name::UserName},
     },
     value_objects::identifier::Identifier,
 };
@@ -22,8 +24,13 @@ pub struct MockUserRepository {
 }
 
 struct UserSave {
-    old: Option<User>,
-    new: User,
+    old: Option<SomeUser<'static>>,
+    new: SomeUser<'static>,
+}
+
+struct ExternalIdentitySave {
+    old: Option<ExternalIdentity>,
+    new: ExternalIdentity,
 }
 
 /// Represents mock user repository with active transaction
@@ -32,6 +39,7 @@ struct UserSave {
 pub struct MockUserRepositoryWithTransaction {
     datastore: Arc<MockDatastore>,
     user_saves: Arc<Mutex<Vec<UserSave>>>,
+    identity_saves: Arc<Mutex<Vec<ExternalIdentitySave>>>,
 }
 
 impl MockUserRepository {
@@ -49,6 +57,7 @@ impl UserRepository for MockUserRepository {
             Ok(Box::new(MockUserRepositoryWithTransaction {
                 datastore: datastore_clone,
                 user_saves: Arc::new(Mutex::new(Vec::new())),
+                identity_saves: Arc::new(Mutex::new(Vec::new())),
             }) as Box<dyn UserRepositoryWithTransaction>)
         })
     }
@@ -56,7 +65,7 @@ impl UserRepository for MockUserRepository {
     fn get_by_id(
         &self,
         id: &Identifier<Ulid, User>,
-    ) -> StaticPinnedFuture<Option<User>, UserRepositoryError> {
+    ) -> StaticPinnedFuture<Option<SomeUser<'static>>, UserRepositoryError> {
         let datastore_clone: Arc<MockDatastore> = self.datastore.clone();
         let id_clone = id.clone();
         pin_static_future(async move {
@@ -70,7 +79,7 @@ impl UserRepository for MockUserRepository {
     fn get_by_name(
         &self,
         user_name: &UserName,
-    ) -> StaticPinnedFuture<Option<User>, UserRepositoryError> {
+    ) -> StaticPinnedFuture<Option<SomeUser<'static>>, UserRepositoryError> {
         let datastore_clone: Arc<MockDatastore> = self.datastore.clone();
         let user_name_value = user_name.value().to_string();
         pin_static_future(async move {
@@ -92,17 +101,30 @@ impl UserRepository for MockUserRepository {
             Ok(datastore_clone
                 .users()
                 .get(&user_id)
-                .map(|user_ref| user_ref.value().clone()))
+                .and_then(|user_ref| match user_ref.value() {
+                    SomeUser::Active(user) => Some(user.clone().into_owned()),
+                    _ => None,
+                }))
         })
     }
 
-    fn save(&self, user: &User) -> StaticPinnedFuture<(), UserRepositoryError> {
+    fn list(
+        &self,
+        filter: &UserListFilter,
+    ) -> StaticPinnedFuture<Vec<SomeUser<'static>>, UserRepositoryError> {
+        let users = list_users(&self.datastore, filter);
+        pin_static_future(async move { Ok(users) })
+    }
+
+    fn save(&self, user: SomeUser) -> StaticPinnedFuture<(), UserRepositoryError> {
         let datastore_clone: Arc<MockDatastore> = self.datastore.clone();
-        let user_clone = user.clone();
+        let user_clone = user.into_owned();
         pin_static_future(async move {
-            datastore_clone
-                .users()
-                .insert(user_clone.id().clone(), user_clone);
+            let users = datastore_clone.users();
+            if is_user_name_taken(&users, &user_clone) {
+                return Err(UserRepositoryError::UserNameIsTaken);
+            }
+            users.insert(user_clone.id().clone(), user_clone);
             Ok(())
         })
     }
@@ -127,6 +149,18 @@ impl UserRepositoryWithTransaction for MockUserRepositoryWithTransaction {
                     }
                 }
             }
+            let mut saves = self.identity_saves.lock().await;
+            let identities = self.datastore.external_identities();
+            while let Some(save) = saves.pop() {
+                match save.old {
+                    Some(old) => {
+                        identities.insert(old.id().clone(), old);
+                    }
+                    None => {
+                        identities.remove(save.new.id());
+                    }
+                }
+            }
             Ok(())
         })
     }
@@ -135,7 +169,10 @@ impl UserRepositoryWithTransaction for MockUserRepositoryWithTransaction {
         self: Box<Self>,
         id: &Identifier<Ulid, User>,
     ) -> StaticPinnedFuture<
-        (Box<dyn UserRepositoryWithTransaction>, Option<User>),
+        (
+            Box<dyn UserRepositoryWithTransaction>,
+            Option<SomeUser<'static>>,
+        ),
         UserRepositoryError,
     > {
         let id_clone = id.clone();
@@ -153,7 +190,10 @@ impl UserRepositoryWithTransaction for MockUserRepositoryWithTransaction {
         self: Box<Self>,
         user_name: &UserName,
     ) -> StaticPinnedFuture<
-        (Box<dyn UserRepositoryWithTransaction>, Option<User>),
+        (
+            Box<dyn UserRepositoryWithTransaction>,
+            Option<SomeUser<'static>>,
+        ),
         UserRepositoryError,
     > {
         let user_name_value = user_name.value().to_string();
@@ -177,25 +217,45 @@ impl UserRepositoryWithTransaction for MockUserRepositoryWithTransaction {
     > {
         let user_id = session.user_claims().id().clone();
         pin_static_future(async move {
-            let user = self
-                .datastore
-                .users()
-                .get(&user_id)
-                .map(|user_ref| user_ref.value().clone());
+            let user =
+                self.datastore
+                    .users()
+                    .get(&user_id)
+                    .and_then(|user_ref| match user_ref.value() {
+                        SomeUser::Active(user) => Some(user.clone().into_owned()),
+                        _ => None,
+                    });
             Ok((self as Box<dyn UserRepositoryWithTransaction>, user))
         })
     }
 
+    fn list(
+        self: Box<Self>,
+        filter: &UserListFilter,
+    ) -> StaticPinnedFuture<
+        (
+            Box<dyn UserRepositoryWithTransaction>,
+            Vec<SomeUser<'static>>,
+        ),
+        UserRepositoryError,
+    > {
+        let users = list_users(&self.datastore, filter);
+        pin_static_future(
+            async move { Ok((self as Box<dyn UserRepositoryWithTransaction>, users)) },
+        )
+    }
+
     fn save(
         self: Box<Self>,
-        user: &User,
+        user: SomeUser,
     ) -> StaticPinnedFuture<(Box<dyn UserRepositoryWithTransaction>, ()), UserRepositoryError> {
-        let user_clone = user.clone();
+        let user_clone = user.into_owned();
         pin_static_future(async move {
-            let old = self
-                .datastore
-                .users()
-                .insert(user_clone.id().clone(), user_clone.clone());
+            let users = self.datastore.users();
+            if is_user_name_taken(&users, &user_clone) {
+                return Err(UserRepositoryError::UserNameIsTaken);
+            }
+            let old = users.insert(user_clone.id().clone(), user_clone.clone());
 
             let save_record = UserSave {
                 old,
@@ -210,4 +270,67 @@ impl UserRepositoryWithTransaction for MockUserRepositoryWithTransaction {
             Ok((self as Box<dyn UserRepositoryWithTransaction>, ()))
         })
     }
+
+    fn save_external_identity(
+        self: Box<Self>,
+        identity: &ExternalIdentity,
+    ) -> StaticPinnedFuture<(Box<dyn UserRepositoryWithTransaction>, ()), UserRepositoryError> {
+        let identity = identity.clone();
+        pin_static_future(async move {
+            let old = self
+                .datastore
+                .external_identities()
+                .insert(identity.id().clone(), identity.clone());
+
+            {
+                let mut saves = self.identity_saves.lock().await;
+                saves.push(ExternalIdentitySave { old, new: identity });
+            }
+
+            Ok((self as Box<dyn UserRepositoryWithTransaction>, ()))
+        })
+    }
+}
+
+fn list_users(datastore: &MockDatastore, filter: &UserListFilter) -> Vec<SomeUser<'static>> {
+    let name_query = filter
+        .name_query
+        .as_ref()
+        .map(|name_query| name_query.to_lowercase());
+    let after_id = filter
+        .after_id
+        .as_ref()
+        .map(|after_id| after_id.to_string());
+    let mut users: Vec<SomeUser<'static>> = datastore
+        .users()
+        .iter()
+        .filter(|entry| {
+            let user = entry.value();
+            name_query
+                .as_ref()
+                .is_none_or(|name_query| user.name().value().to_lowercase().contains(name_query))
+                && filter
+                    .role
+                    .as_ref()
+                    .is_none_or(|role| user.roles().contains(role))
+                && filter.status.is_none_or(|status| user.status() == status)
+                && after_id
+                    .as_ref()
+                    .is_none_or(|after_id| user.id().to_string() > *after_id)
+        })
+        .map(|user_ref| user_ref.value().clone())
+        .collect();
+    users.sort_by_key(|user| user.id().to_string());
+    users.truncate(filter.limit);
+    users
+}
+
+/// Mirrors the unique key of user names, which is checked by the database otherwise
+fn is_user_name_taken(
+    users: &DashMap<Identifier<Ulid, User>, SomeUser<'static>>,
+    user: &SomeUser,
+) -> bool {
+    users
+        .iter()
+        .any(|entry| entry.key() != user.id() && entry.name() == user.name())
 }
@@ -0,0 +1,236 @@
+use std::{collections::HashMap, sync::Arc};
+
+use argon2::password_hash::rand_core::OsRng;
+use axum::{
+    Form, Json, Router,
+    extract::{Query, State},
+    http::{StatusCode, header::LOCATION},
+    response::{IntoResponse, Response},
+    routing::{get, post},
+};
+use base64::{Engine, prelude::BASE64_URL_SAFE_NO_PAD};
+use dashmap::DashMap;
+use ed25519_dalek::{SigningKey, pkcs8::EncodePrivateKey, pkcs8::spki::der::pem::LineEnding};
+use jsonwebtoken::{Algorithm, EncodingKey, Header, encode};
+use nimbus_auth_domain::entities::authorization_code::value_objects::code_challenge::CodeChallenge;
+use nimbus_auth_shared::errors::ErrorBoxed;
+
+use serde::Deserialize;
+use serde_json::json;
+use time::OffsetDateTime;
+use tokio::{net::TcpListener, spawn, task::JoinHandle};
+use ulid::Ulid;
+use url::Url;
+
+const KEY_ID: &str = "stub-key";
+const ID_TOKEN_EXPIRATION_SECONDS: i64 = 300;
+
+/// Account the stub provider signs in, chosen by the `login_hint` of the authorization request
+#[derive(Clone)]
+pub struct StubAccount {
+    pub subject: String,
+    pub email: Option<String>,
+    pub preferred_username: Option<String>,
+}
+
+/// Minimal OpenID Connect provider for api tests, users are signed in without any prompt
+///
+/// The server is stopped when the value is dropped
+pub struct StubIdentityProvider {
+    server: JoinHandle<()>,
+}
+
+struct StubState {
+    issuer: String,
+    client_id: String,
+    client_secret: String,
+    signing_key: SigningKey,
+    accounts: HashMap<String, StubAccount>,
+    codes: DashMap<String, PendingCode>,
+}
+
+struct PendingCode {
+    subject: String,
+    nonce: String,
+    code_challenge: String,
+    redirect_uri: String,
+}
+
+#[derive(Deserialize)]
+struct AuthorizeQuery {
+    client_id: String,
+    redirect_uri: String,
+    state: String,
+    nonce: String,
+    code_challenge: String,
+    login_hint: String,
+}
+
+#[derive(Deserialize)]
+struct TokenForm {
+    grant_type: String,
+    code: String,
+    redirect_uri: String,
+    client_id: String,
+    client_secret: String,
+    code_verifier: String,
+}
+
+impl StubIdentityProvider {
+    pub async fn start(
+        addr: &str,
+        client_id: &str,
+        client_secret: &str,
+        accounts: Vec<StubAccount>,
+    ) -> Result<Self, ErrorBoxed> {
+        let state = Arc::new(StubState {
+            issuer: format!("http://{addr}"),
+            client_id: client_id.to_string(),
+            client_secret: client_secret.to_string(),
+            signing_key: SigningKey::generate(&mut OsRng),
+            accounts: accounts
+                .into_iter()
+                .map(|account| (account.subject.clone(), account))
+                .collect(),
+            codes: DashMap::new(),
+        });
+        let router = Router::new()
+            .route("/.well-known/openid-configuration", get(handle_discovery))
+            .route("/jwks", get(handle_jwks))
+            .route("/authorize", get(handle_authorize))
+            .route("/token", post(handle_token))
+            .with_state(state);
+
+        let listener = TcpListener::bind(addr).await?;
+        let server = spawn(async move {
+            axum::serve(listener, router)
+                .await
+                .expect("stub identity provider should have been served");
+        });
+
+        Ok(Self { server })
+    }
+
+    /// Issuer to configure the provider with
+    pub fn issuer(addr: &str) -> String {
+        format!("http://{addr}")
+    }
+}
+
+impl Drop for StubIdentityProvider {
+    fn drop(&mut self) {
+        self.server.abort();
+    }
+}
+
+async fn handle_discovery(State(state): State<Arc<StubState>>) -> impl IntoResponse {
+    Json(json!({
+        "issuer": state.issuer,
+        "authorization_endpoint": format!("{}/authorize", state.issuer),
+        "token_endpoint": format!("{}/token", state.issuer),
+        "jwks_uri": format!("{}/jwks", state.issuer),
+    }))
+}
+
+async fn handle_jwks(State(state): State<Arc<StubState>>) -> impl IntoResponse {
+    Json(json!({
+        "keys": [{
+            "kty": "OKP",
+            "crv": "Ed25519",
+            "alg": "EdDSA",
+            "use": "sig",
+            "kid": KEY_ID,
+            "x": BASE64_URL_SAFE_NO_PAD.encode(state.signing_key.verifying_key().to_bytes()),
+        }]
+    }))
+}
+
+async fn handle_authorize(
+    State(state): State<Arc<StubState>>,
+    Query(query): Query<AuthorizeQuery>,
+) -> Response {
+    if query.client_id != state.client_id || !state.accounts.contains_key(&query.login_hint) {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+    let Ok(mut redirect_uri) = Url::parse(&query.redirect_uri) else {
+        return StatusCode::BAD_REQUEST.into_response();
+    };
+
+    let code = Ulid::new().to_string();
+    state.codes.insert(
+        code.clone(),
+        PendingCode {
+            subject: query.login_hint,
+            nonce: query.nonce,
+            code_challenge: query.code_challenge,
+            redirect_uri: query.redirect_uri,
+        },
+    );
+    redirect_uri
+        .query_pairs_mut()
+        .append_pair("code", &code)
+        .append_pair("state", &query.state);
+
+    (
+        StatusCode::SEE_OTHER,
+        [(LOCATION, redirect_uri.to_string())],
+    )
+        .into_response()
+}
+
+async fn handle_token(
+    State(state): State<Arc<StubState>>,
+    Form(form): Form<TokenForm>,
+) -> Response {
+    let invalid_grant = (
+        StatusCode::BAD_REQUEST,
+        Json(json!({ "error": "invalid_grant" })),
+    );
+    if form.grant_type != "authorization_code"
+        || form.client_id != state.client_id
+        || form.client_secret != state.client_secret
+    {
+        return invalid_grant.into_response();
+    }
+    // codes are single use
+    let Some((_, pending_code)) = state.codes.remove(&form.code) else {
+        return invalid_grant.into_response();
+    };
+    if pending_code.redirect_uri != form.redirect_uri
+        || CodeChallenge::derive(&form.code_verifier).value() != pending_code.code_challenge
+    {
+        return invalid_grant.into_response();
+    }
+
+    let account = &state.accounts[&pending_code.subject];
+    let now = OffsetDateTime::now_utc().unix_timestamp();
+    let claims = json!({
+        "iss": state.issuer,
+        "sub": account.subject,
+        "aud": state.client_id,
+        "iat": now,
+        "exp": now + ID_TOKEN_EXPIRATION_SECONDS,
+        "nonce": pending_code.nonce,
+        "email": account.email,
+        "preferred_username": account.preferred_username,
+    });
+    let mut header = Header::new(Algorithm::EdDSA);
+    header.kid = Some(KEY_ID.to_string());
+    let pem = state
+        .signing_key
+        .to_pkcs8_pem(LineEnding::LF)
+        .expect("signing key should have been encoded");
+    let id_token = encode(
+        &header,
+        &claims,
+        &EncodingKey::from_ed_pem(pem.as_bytes()).expect("encoding key should have been parsed"),
+    )
+    .expect("ID token should have been signed");
+
+    Json(json!({
+        "access_token": Ulid::new().to_string(),
+        "token_type": "Bearer",
+        "id_token": id_token,
+    }))
+    .into_response()
+}
@@ -1,9 +1,38 @@
-use argon2::password_hash::rand_core::OsRng;
+use argon2::password_hash::{SaltString, rand_core::OsRng};
 use ed25519_dalek::{SigningKey, pkcs8::EncodePrivateKey, pkcs8::spki::der::pem::LineEnding};
-use nimbus_auth_domain::entities::keypair::{
-    Active, KeyPair, SomeKeyPair, specifications::NewKeyPairSpecification,
-    value_objects::KeyPairValue,
+use nimbus_auth_domain::{
+    entities::{
+        keypair::{
+            Active, KeyPair, SomeKeyPair, specifications::NewKeyPairSpecification,
+            value_objects::KeyPairValue,
+        },
+        role::{
+            Role,
+            specifications::NewRoleSpecification,
+            value_objects::{permission::Permission, role_name::RoleName},
+        },
+        user::{
+            User,
+            specifications::NewUserSpecification,
+            value_objects::{password::Password, password_hash::PasswordHash, user_name::UserName},
+        },
+    },
+    value_objects::{
+        access_token::AccessToken, audiences::Audiences, password_peppers::PasswordPeppers,
+        user_claims::UserClaims,
+    },
 };
+use nimbus_auth_shared::{
+    constants::{
+        ACCESS_TOKEN_EXPIRATION_SECONDS_DEFAULT, ACCESS_TOKEN_MAX_GROUPS_DEFAULT, AUTH_PERMISSIONS,
+    },
+    types::{
+        AccessTokenAttributes, AccessTokenExpirationSeconds, AccessTokenMaxGroups,
+        PasswordHashingParams, Realm,
+    },
+};
+use time::OffsetDateTime;
+use zeroize::Zeroizing;
 
 pub fn get_active_keypair() -> KeyPair<Active> {
     let mut rng = OsRng;
@@ -13,3 +42,75 @@ pub fn get_active_keypair() -> KeyPair<Active> {
         value: KeyPairValue::from_pem(pem).expect("key pair value should have been constructed"),
     })
 }
+
+/// Built-in roles as they are seeded by migrations, admin holds all auth permissions
+pub fn get_built_in_roles() -> [Role; 2] {
+    [
+        Role::new(NewRoleSpecification {
+            name: RoleName::default_role(),
+            permissions: Default::default(),
+        }),
+        Role::new(NewRoleSpecification {
+            name: RoleName::admin(),
+            permissions: AUTH_PERMISSIONS
+                .iter()
+                .map(|permission| {
+                    Permission::from(permission).expect("permission should have been constructed")
+                })
+                .collect(),
+        }),
+    ]
+}
+
+/// User holds the default role, as after signup
+pub fn get_user(user_name: &str, password: &str, params: &PasswordHashingParams) -> User {
+    let salt = SaltString::generate(&mut OsRng);
+    let password_hash = PasswordHash::hash(
+        Password::from_unvalidated(&Zeroizing::new(password.to_string())),
+        salt.as_str(),
+        params,
+        &PasswordPeppers::empty(),
+    )
+    .expect("password hash should have been constructed");
+    User::new(NewUserSpecification {
+        user_name: UserName::from(user_name).expect("user name should have been constructed"),
+        password_hash,
+        roles: vec![get_built_in_roles()[0].clone()],
+    })
+}
+
+pub fn get_signed_access_token(user_claims: &UserClaims, keypair: &KeyPair<Active>) -> String {
+    get_signed_realm_access_token(user_claims, keypair, &Realm::default())
+}
+
+pub fn get_signed_realm_access_token(
+    user_claims: &UserClaims,
+    keypair: &KeyPair<Active>,
+    realm: &Realm,
+) -> String {
+    get_signed_audiences_access_token(user_claims, keypair, realm, &[])
+}
+
+/// No audiences target the default audience of the realm
+pub fn get_signed_audiences_access_token(
+    user_claims: &UserClaims,
+    keypair: &KeyPair<Active>,
+    realm: &Realm,
+    audiences: &[&str],
+) -> String {
+    AccessToken::new(
+        user_claims.clone(),
+        OffsetDateTime::now_utc(),
+        AccessTokenExpirationSeconds(ACCESS_TOKEN_EXPIRATION_SECONDS_DEFAULT),
+        AccessTokenMaxGroups(ACCESS_TOKEN_MAX_GROUPS_DEFAULT),
+        &AccessTokenAttributes::default(),
+    )
+    .with_audiences(Audiences::restore(
+        audiences
+            .iter()
+            .map(|audience| audience.to_string())
+            .collect(),
+    ))
+    .sign(keypair, realm)
+    .expect("access token should have been signed")
+}
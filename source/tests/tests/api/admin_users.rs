@@ -0,0 +1,3 @@
+mod demotion;
+mod impersonation;
+mod list_users;
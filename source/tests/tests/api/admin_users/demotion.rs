@@ -0,0 +1,275 @@
+use std::{error::Error, path::PathBuf, str::FromStr};
+
+use nimbus_auth_domain::entities::{
+    keypair::SomeKeyPair, role::value_objects::role_name::RoleName, user::SomeUser,
+};
+use nimbus_auth_proto::proto::nimbus::{
+    admin::users::v1::{
+        AdminUsersErrorCodeProto, ChangeUserRolesRequestProto, ChangeUserRolesResponseProto,
+        ListUsersRequestProto, ListUsersResponseProto, change_user_roles_response_proto,
+        list_users_response_proto,
+    },
+    auth::{
+        refresh::v1::{
+            RefreshErrorCodeProto, RefreshRequestProto, RefreshResponseProto,
+            refresh_response_proto,
+        },
+        signin::v1::{SignInRequestProto, SignInResponseProto, sign_in_response_proto},
+    },
+};
+use nimbus_auth_shared::{
+    config::{AppConfigBuilder, AppConfigRequiredOptions},
+    constants::{CLIENT_TYPE_HEADER_NAME, CLIENT_TYPE_PC_HEADER_VALUE, SESSION_HEADER_NAME},
+    errors::ErrorBoxed,
+    types::PasswordHashingParams,
+};
+use nimbus_auth_tests::utils::{
+    get_active_keypair, get_built_in_roles, get_signed_access_token, get_user,
+};
+use prost::Message;
+use reqwest::{
+    Client, StatusCode,
+    header::{AUTHORIZATION, CONTENT_TYPE},
+};
+
+use crate::api::{ApiTestState, run_api_test};
+
+const SERVER_ADDR: &str = "localhost:5019";
+const KEYPAIRS_STORE_PATH: &str = "/temp";
+const POSTGRES_DB_URL: &str =
+    "postgresql://<username>:<password>@<host>:<port>/<database>?<options>";
+
+const ADMIN_USER_NAME: &str = "administrator";
+const DEMOTED_USER_NAME: &str = "demotedadmin";
+const REVOKED_USER_NAME: &str = "revokedadmin";
+const PASSWORD: &str = "StrongPassword123!";
+
+const PASSWORD_HASHING_PARAMS: PasswordHashingParams = PasswordHashingParams {
+    memory_cost_kib: 8 * 1024,
+    time_cost: 1,
+    parallelism: 1,
+};
+
+const SIGNIN_ENDPOINT: &str = "auth/signin";
+const REFRESH_ENDPOINT: &str = "auth/refresh";
+const LIST_USERS_ENDPOINT: &str = "admin/users/search";
+
+#[tokio::test]
+async fn demoted_admin_loses_admin_permissions_on_refresh() -> Result<(), Box<dyn Error>> {
+    let mut app_config_builder = AppConfigBuilder::new(AppConfigRequiredOptions {
+        server_addr: SERVER_ADDR.to_string(),
+        keypairs_store_path: PathBuf::from_str(KEYPAIRS_STORE_PATH)?,
+        postgres_db_url: POSTGRES_DB_URL.to_string(),
+    });
+    app_config_builder
+        .with_password_hash_memory_cost_kib(PASSWORD_HASHING_PARAMS.memory_cost_kib)
+        .with_password_hash_time_cost(PASSWORD_HASHING_PARAMS.time_cost)
+        .with_password_hash_parallelism(PASSWORD_HASHING_PARAMS.parallelism);
+    let app_config = app_config_builder.build()?;
+
+    let keypair = get_active_keypair();
+    let [_, admin_role] = get_built_in_roles();
+    let admin_user = get_user(ADMIN_USER_NAME, PASSWORD, &PASSWORD_HASHING_PARAMS)
+        .with_roles(std::slice::from_ref(&admin_role));
+    let demoted_user = get_user(DEMOTED_USER_NAME, PASSWORD, &PASSWORD_HASHING_PARAMS)
+        .with_roles(std::slice::from_ref(&admin_role));
+    let revoked_user =
+        get_user(REVOKED_USER_NAME, PASSWORD, &PASSWORD_HASHING_PARAMS).with_roles(&[admin_role]);
+
+    let admin_access_token = get_signed_access_token(admin_user.claims(), &keypair);
+
+    let test_state = ApiTestState {
+        users: Some(vec![
+            SomeUser::from(admin_user),
+            SomeUser::from(demoted_user),
+            SomeUser::from(revoked_user),
+        ]),
+        keypairs: Some(vec![SomeKeyPair::from(keypair)]),
+        ..Default::default()
+    };
+
+    run_api_test(|| test_action(admin_access_token), app_config, test_state)
+        .await
+        .map_err(|boxed| boxed.inner())
+}
+
+async fn test_action(admin_access_token: String) -> Result<(), ErrorBoxed> {
+    // arrange
+    let client = Client::new();
+    let (demoted_access_token, demoted_session_id) = signin(&client, DEMOTED_USER_NAME).await?;
+    let (_, revoked_session_id) = signin(&client, REVOKED_USER_NAME).await?;
+
+    // act
+    let (before_demotion_status, _) = list_users(&client, &demoted_access_token).await?;
+    let demoted_result = demote(&client, &admin_access_token, DEMOTED_USER_NAME, false).await?;
+    let (refresh_status, refresh_result) = refresh(&client, &demoted_session_id).await?;
+    let revoked_result = demote(&client, &admin_access_token, REVOKED_USER_NAME, true).await?;
+    let (revoked_refresh_status, revoked_refresh_result) =
+        refresh(&client, &revoked_session_id).await?;
+
+    // assert
+    if before_demotion_status != StatusCode::OK {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected admin to list users before demotion, got {before_demotion_status}"
+        )));
+    }
+
+    match demoted_result {
+        Some(change_user_roles_response_proto::Result::Success(success))
+            if success.revoked_sessions_count == 0 => {}
+        result => {
+            return Err(ErrorBoxed::from_str(format!(
+                "expected demotion keeping sessions, got {result:?}"
+            )));
+        }
+    }
+
+    let refreshed_access_token = match refresh_result {
+        Some(refresh_response_proto::Result::Success(success)) => {
+            success.access_token.unwrap_or_default().token
+        }
+        result => {
+            return Err(ErrorBoxed::from_str(format!(
+                "expected session of demoted user to be refreshed, got {refresh_status}: {result:?}"
+            )));
+        }
+    };
+    let (after_demotion_status, after_demotion_result) =
+        list_users(&client, &refreshed_access_token).await?;
+    let forbidden =
+        list_users_response_proto::Result::Error(AdminUsersErrorCodeProto::Forbidden.into());
+    if after_demotion_status != StatusCode::FORBIDDEN || after_demotion_result != Some(forbidden) {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected refreshed token to lose admin permissions, got {after_demotion_status}: {after_demotion_result:?}"
+        )));
+    }
+
+    match revoked_result {
+        Some(change_user_roles_response_proto::Result::Success(success))
+            if success.revoked_sessions_count == 1 => {}
+        result => {
+            return Err(ErrorBoxed::from_str(format!(
+                "expected demotion to revoke the only session, got {result:?}"
+            )));
+        }
+    }
+
+    let session_invalid =
+        refresh_response_proto::Result::Error(RefreshErrorCodeProto::SessionInvalid.into());
+    if revoked_refresh_status != StatusCode::BAD_REQUEST
+        || revoked_refresh_result != Some(session_invalid)
+    {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected revoked session not to be refreshed, got {revoked_refresh_status}: {revoked_refresh_result:?}"
+        )));
+    }
+
+    Ok(())
+}
+
+/// Returns the access token along with the session id
+async fn signin(client: &Client, user_name: &str) -> Result<(String, String), ErrorBoxed> {
+    let mut request_payload = Vec::new();
+    SignInRequestProto {
+        user_name: user_name.to_string(),
+        password: PASSWORD.to_string(),
+        audiences: vec![],
+    }
+    .encode(&mut request_payload)?;
+
+    let response = client
+        .post(format!("http://{SERVER_ADDR}/{SIGNIN_ENDPOINT}"))
+        .header(CONTENT_TYPE, "application/x-protobuf")
+        .header(CLIENT_TYPE_HEADER_NAME, CLIENT_TYPE_PC_HEADER_VALUE)
+        .body(request_payload)
+        .send()
+        .await?;
+    let status = response.status();
+    let session_id = response
+        .headers()
+        .get(SESSION_HEADER_NAME)
+        .and_then(|session_id| session_id.to_str().ok())
+        .map(str::to_string)
+        .ok_or(ErrorBoxed::from_str(format!(
+            "expected session header, got {status}"
+        )))?;
+    let response_proto = SignInResponseProto::decode(response.bytes().await?)?;
+
+    match response_proto.result {
+        Some(sign_in_response_proto::Result::Success(success)) => {
+            Ok((success.access_token.unwrap_or_default().token, session_id))
+        }
+        result => Err(ErrorBoxed::from_str(format!(
+            "expected signin, got {status}: {result:?}"
+        ))),
+    }
+}
+
+async fn demote(
+    client: &Client,
+    access_token: &str,
+    user_name: &str,
+    revoke_sessions: bool,
+) -> Result<Option<change_user_roles_response_proto::Result>, ErrorBoxed> {
+    let mut request_payload = Vec::new();
+    ChangeUserRolesRequestProto {
+        granted_roles: vec![],
+        revoked_roles: vec![RoleName::admin().to_string()],
+        revoke_sessions,
+    }
+    .encode(&mut request_payload)?;
+
+    let response = client
+        .post(format!(
+            "http://{SERVER_ADDR}/admin/users/{user_name}/roles"
+        ))
+        .header(CONTENT_TYPE, "application/x-protobuf")
+        .header(AUTHORIZATION, format!("Bearer {access_token}"))
+        .body(request_payload)
+        .send()
+        .await?;
+    let response_proto = ChangeUserRolesResponseProto::decode(response.bytes().await?)?;
+
+    Ok(response_proto.result)
+}
+
+async fn refresh(
+    client: &Client,
+    session_id: &str,
+) -> Result<(StatusCode, Option<refresh_response_proto::Result>), ErrorBoxed> {
+    let mut request_payload = Vec::new();
+    RefreshRequestProto::default().encode(&mut request_payload)?;
+
+    let response = client
+        .post(format!("http://{SERVER_ADDR}/{REFRESH_ENDPOINT}"))
+        .header(CONTENT_TYPE, "application/x-protobuf")
+        .header(CLIENT_TYPE_HEADER_NAME, CLIENT_TYPE_PC_HEADER_VALUE)
+        .header(SESSION_HEADER_NAME, session_id)
+        .body(request_payload)
+        .send()
+        .await?;
+    let status = response.status();
+    let response_proto = RefreshResponseProto::decode(response.bytes().await?)?;
+
+    Ok((status, response_proto.result))
+}
+
+async fn list_users(
+    client: &Client,
+    access_token: &str,
+) -> Result<(StatusCode, Option<list_users_response_proto::Result>), ErrorBoxed> {
+    let mut request_payload = Vec::new();
+    ListUsersRequestProto::default().encode(&mut request_payload)?;
+
+    let response = client
+        .post(format!("http://{SERVER_ADDR}/{LIST_USERS_ENDPOINT}"))
+        .header(CONTENT_TYPE, "application/x-protobuf")
+        .header(AUTHORIZATION, format!("Bearer {access_token}"))
+        .body(request_payload)
+        .send()
+        .await?;
+    let status = response.status();
+    let response_proto = ListUsersResponseProto::decode(response.bytes().await?)?;
+
+    Ok((status, response_proto.result))
+}
@@ -0,0 +1,251 @@
+use std::{error::Error, path::PathBuf, str::FromStr};
+
+use nimbus_auth_domain::{
+    entities::{
+        Entity,
+        keypair::{Active, KeyPair, SomeKeyPair},
+        user::SomeUser,
+    },
+    value_objects::access_token::AccessToken,
+};
+use nimbus_auth_proto::proto::nimbus::{
+    admin::users::v1::{
+        AdminUsersErrorCodeProto, ImpersonateUserRequestProto, ImpersonateUserResponseProto,
+        ListUsersRequestProto, ListUsersResponseProto, impersonate_user_response_proto,
+        list_users_response_proto,
+    },
+    auth::api_keys::v1::{
+        ApiKeysErrorCodeProto, CreateApiKeyRequestProto, CreateApiKeyResponseProto,
+        create_api_key_response_proto,
+    },
+};
+use nimbus_auth_shared::{
+    config::{AppConfigBuilder, AppConfigRequiredOptions},
+    errors::ErrorBoxed,
+    types::{PasswordHashingParams, Realm},
+};
+use nimbus_auth_tests::utils::{
+    get_active_keypair, get_built_in_roles, get_signed_access_token, get_user,
+};
+use prost::Message;
+use reqwest::{
+    Client, StatusCode,
+    header::{AUTHORIZATION, CONTENT_TYPE},
+};
+
+use crate::api::{ApiTestState, run_api_test};
+
+const SERVER_ADDR: &str = "localhost:5013";
+const KEYPAIRS_STORE_PATH: &str = "/temp";
+const POSTGRES_DB_URL: &str =
+    "postgresql://<username>:<password>@<host>:<port>/<database>?<options>";
+
+const ADMIN_USER_NAME: &str = "administrator";
+const TARGET_USER_NAME: &str = "supportedadmin";
+const DEFAULT_USER_NAME: &str = "defaultuser";
+const PASSWORD: &str = "StrongPassword123!";
+const REASON: &str = "Reproducing ticket #4242";
+
+const PASSWORD_HASHING_PARAMS: PasswordHashingParams = PasswordHashingParams {
+    memory_cost_kib: 8 * 1024,
+    time_cost: 1,
+    parallelism: 1,
+};
+
+const LIST_USERS_ENDPOINT: &str = "admin/users/search";
+const API_KEYS_ENDPOINT: &str = "api_keys";
+
+#[tokio::test]
+async fn impersonated_token_carries_admin_as_actor_and_loses_admin_privileges()
+-> Result<(), Box<dyn Error>> {
+    let mut app_config_builder = AppConfigBuilder::new(AppConfigRequiredOptions {
+        server_addr: SERVER_ADDR.to_string(),
+        keypairs_store_path: PathBuf::from_str(KEYPAIRS_STORE_PATH)?,
+        postgres_db_url: POSTGRES_DB_URL.to_string(),
+    });
+    app_config_builder
+        .with_password_hash_memory_cost_kib(PASSWORD_HASHING_PARAMS.memory_cost_kib)
+        .with_password_hash_time_cost(PASSWORD_HASHING_PARAMS.time_cost)
+        .with_password_hash_parallelism(PASSWORD_HASHING_PARAMS.parallelism);
+    let app_config = app_config_builder.build()?;
+
+    let keypair = get_active_keypair();
+    let [_, admin_role] = get_built_in_roles();
+    let admin_user = get_user(ADMIN_USER_NAME, PASSWORD, &PASSWORD_HASHING_PARAMS)
+        .with_roles(std::slice::from_ref(&admin_role));
+    // the target is an admin too, so the impersonated token proves admin privileges are stripped
+    let target_user =
+        get_user(TARGET_USER_NAME, PASSWORD, &PASSWORD_HASHING_PARAMS).with_roles(&[admin_role]);
+    let default_user = get_user(DEFAULT_USER_NAME, PASSWORD, &PASSWORD_HASHING_PARAMS);
+
+    let admin_id = admin_user.id().to_string();
+    let admin_access_token = get_signed_access_token(admin_user.claims(), &keypair);
+    let default_access_token = get_signed_access_token(default_user.claims(), &keypair);
+
+    let test_state = ApiTestState {
+        users: Some(vec![
+            SomeUser::from(admin_user),
+            SomeUser::from(target_user),
+            SomeUser::from(default_user),
+        ]),
+        keypairs: Some(vec![SomeKeyPair::from(keypair.clone())]),
+        ..Default::default()
+    };
+
+    run_api_test(
+        || test_action(admin_access_token, default_access_token, admin_id, keypair),
+        app_config,
+        test_state,
+    )
+    .await
+    .map_err(|boxed| boxed.inner())
+}
+
+async fn test_action(
+    admin_access_token: String,
+    default_access_token: String,
+    admin_id: String,
+    keypair: KeyPair<Active>,
+) -> Result<(), ErrorBoxed> {
+    // arrange
+    let client = Client::new();
+
+    // act
+    let (status, result) =
+        impersonate_user(&client, &admin_access_token, TARGET_USER_NAME, REASON).await?;
+    let Some(impersonate_user_response_proto::Result::Success(impersonation)) = result else {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected impersonation, got {status}: {result:?}"
+        )));
+    };
+    let (empty_reason_status, empty_reason_result) =
+        impersonate_user(&client, &admin_access_token, TARGET_USER_NAME, " ").await?;
+    let (default_user_status, default_user_result) =
+        impersonate_user(&client, &default_access_token, TARGET_USER_NAME, REASON).await?;
+    let (list_users_status, list_users_result) =
+        list_users(&client, &impersonation.access_token).await?;
+    let (create_api_key_status, create_api_key_result) =
+        create_api_key(&client, &impersonation.access_token).await?;
+
+    // assert
+    let impersonated_token =
+        AccessToken::verify_with_active(&impersonation.access_token, &keypair, &Realm::default())?;
+    if impersonated_token.user_claims().name().value() != TARGET_USER_NAME
+        || impersonated_token.actor().map(|actor| actor.subject()) != Some(admin_id.as_str())
+    {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected token for the target user with the admin as actor, got {:?} acted by {:?}",
+            impersonated_token.user_claims().name(),
+            impersonated_token.actor()
+        )));
+    }
+
+    let validation_error = impersonate_user_response_proto::Result::Error(
+        AdminUsersErrorCodeProto::ValidationError.into(),
+    );
+    if empty_reason_status != StatusCode::BAD_REQUEST
+        || empty_reason_result != Some(validation_error)
+    {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected validation error for empty reason, got {empty_reason_status}: {empty_reason_result:?}"
+        )));
+    }
+
+    let forbidden =
+        impersonate_user_response_proto::Result::Error(AdminUsersErrorCodeProto::Forbidden.into());
+    if default_user_status != StatusCode::FORBIDDEN || default_user_result != Some(forbidden) {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected forbidden error for default user, got {default_user_status}: {default_user_result:?}"
+        )));
+    }
+
+    let forbidden =
+        list_users_response_proto::Result::Error(AdminUsersErrorCodeProto::Forbidden.into());
+    if list_users_status != StatusCode::FORBIDDEN || list_users_result != Some(forbidden) {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected impersonated token to lose admin access, got {list_users_status}: {list_users_result:?}"
+        )));
+    }
+
+    let forbidden =
+        create_api_key_response_proto::Result::Error(ApiKeysErrorCodeProto::Forbidden.into());
+    if create_api_key_status != StatusCode::FORBIDDEN || create_api_key_result != Some(forbidden) {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected impersonated token to be denied api keys, got {create_api_key_status}: {create_api_key_result:?}"
+        )));
+    }
+
+    Ok(())
+}
+
+async fn impersonate_user(
+    client: &Client,
+    access_token: &str,
+    user_name: &str,
+    reason: &str,
+) -> Result<(StatusCode, Option<impersonate_user_response_proto::Result>), ErrorBoxed> {
+    let mut request_payload = Vec::new();
+    ImpersonateUserRequestProto {
+        reason: reason.to_string(),
+    }
+    .encode(&mut request_payload)?;
+
+    let response = client
+        .post(format!(
+            "http://{SERVER_ADDR}/admin/users/{user_name}/impersonate"
+        ))
+        .header(CONTENT_TYPE, "application/x-protobuf")
+        .header(AUTHORIZATION, format!("Bearer {access_token}"))
+        .body(request_payload)
+        .send()
+        .await?;
+    let status = response.status();
+    let response_proto = ImpersonateUserResponseProto::decode(response.bytes().await?)?;
+
+    Ok((status, response_proto.result))
+}
+
+async fn list_users(
+    client: &Client,
+    access_token: &str,
+) -> Result<(StatusCode, Option<list_users_response_proto::Result>), ErrorBoxed> {
+    let mut request_payload = Vec::new();
+    ListUsersRequestProto::default().encode(&mut request_payload)?;
+
+    let response = client
+        .post(format!("http://{SERVER_ADDR}/{LIST_USERS_ENDPOINT}"))
+        .header(CONTENT_TYPE, "application/x-protobuf")
+        .header(AUTHORIZATION, format!("Bearer {access_token}"))
+        .body(request_payload)
+        .send()
+        .await?;
+    let status = response.status();
+    let response_proto = ListUsersResponseProto::decode(response.bytes().await?)?;
+
+    Ok((status, response_proto.result))
+}
+
+async fn create_api_key(
+    client: &Client,
+    access_token: &str,
+) -> Result<(StatusCode, Option<create_api_key_response_proto::Result>), ErrorBoxed> {
+    let mut request_payload = Vec::new();
+    CreateApiKeyRequestProto {
+        name: "impersonated".to_string(),
+        scopes: Vec::new(),
+        expires_in_seconds: Some(3600),
+    }
+    .encode(&mut request_payload)?;
+
+    let response = client
+        .post(format!("http://{SERVER_ADDR}/{API_KEYS_ENDPOINT}"))
+        .header(CONTENT_TYPE, "application/x-protobuf")
+        .header(AUTHORIZATION, format!("Bearer {access_token}"))
+        .body(request_payload)
+        .send()
+        .await?;
+    let status = response.status();
+    let response_proto = CreateApiKeyResponseProto::decode(response.bytes().await?)?;
+
+    Ok((status, response_proto.result))
+}
@@ -0,0 +1,173 @@
+use std::{error::Error, path::PathBuf, str::FromStr};
+
+use nimbus_auth_domain::entities::{keypair::SomeKeyPair, user::SomeUser};
+use nimbus_auth_proto::proto::nimbus::admin::users::v1::{
+    AdminUsersErrorCodeProto, ListUsersRequestProto, ListUsersResponseProto,
+    list_users_response_proto,
+};
+use nimbus_auth_shared::{
+    config::{AppConfigBuilder, AppConfigRequiredOptions},
+    constants::DEFAULT_ROLE_NAME,
+    errors::ErrorBoxed,
+    types::PasswordHashingParams,
+};
+use nimbus_auth_tests::utils::{
+    get_active_keypair, get_built_in_roles, get_signed_access_token, get_user,
+};
+use prost::Message;
+use reqwest::{
+    Client, StatusCode,
+    header::{AUTHORIZATION, CONTENT_TYPE},
+};
+
+use crate::api::{ApiTestState, run_api_test};
+
+const SERVER_ADDR: &str = "localhost:5005";
+const KEYPAIRS_STORE_PATH: &str = "/temp";
+const POSTGRES_DB_URL: &str =
+    "postgresql://<username>:<password>@<host>:<port>/<database>?<options>";
+
+const ADMIN_USER_NAME: &str = "administrator";
+const DEFAULT_USER_NAMES: [&str; 3] = ["firstuser", "seconduser", "thirduser"];
+const PASSWORD: &str = "StrongPassword123!";
+
+const PASSWORD_HASHING_PARAMS: PasswordHashingParams = PasswordHashingParams {
+    memory_cost_kib: 8 * 1024,
+    time_cost: 1,
+    parallelism: 1,
+};
+
+const ENDPOINT: &str = "admin/users/search";
+
+#[tokio::test]
+async fn admin_lists_users_page_by_page() -> Result<(), Box<dyn Error>> {
+    let mut app_config_builder = AppConfigBuilder::new(AppConfigRequiredOptions {
+        server_addr: SERVER_ADDR.to_string(),
+        keypairs_store_path: PathBuf::from_str(KEYPAIRS_STORE_PATH)?,
+        postgres_db_url: POSTGRES_DB_URL.to_string(),
+    });
+    app_config_builder
+        .with_password_hash_memory_cost_kib(PASSWORD_HASHING_PARAMS.memory_cost_kib)
+        .with_password_hash_time_cost(PASSWORD_HASHING_PARAMS.time_cost)
+        .with_password_hash_parallelism(PASSWORD_HASHING_PARAMS.parallelism);
+    let app_config = app_config_builder.build()?;
+
+    let keypair = get_active_keypair();
+    let [_, admin_role] = get_built_in_roles();
+    let admin_user =
+        get_user(ADMIN_USER_NAME, PASSWORD, &PASSWORD_HASHING_PARAMS).with_roles(&[admin_role]);
+    let default_users: Vec<_> = DEFAULT_USER_NAMES
+        .iter()
+        .map(|user_name| get_user(user_name, PASSWORD, &PASSWORD_HASHING_PARAMS))
+        .collect();
+
+    let admin_access_token = get_signed_access_token(admin_user.claims(), &keypair);
+    let default_access_token = get_signed_access_token(default_users[0].claims(), &keypair);
+
+    let mut users = vec![SomeUser::from(admin_user)];
+    users.extend(default_users.into_iter().map(SomeUser::from));
+
+    let test_state = ApiTestState {
+        users: Some(users),
+        keypairs: Some(vec![SomeKeyPair::from(keypair)]),
+        ..Default::default()
+    };
+
+    run_api_test(
+        || test_action(admin_access_token, default_access_token),
+        app_config,
+        test_state,
+    )
+    .await
+    .map_err(|boxed| boxed.inner())
+}
+
+async fn test_action(
+    admin_access_token: String,
+    default_access_token: String,
+) -> Result<(), ErrorBoxed> {
+    // arrange
+    let client = Client::new();
+    let default_users_request = ListUsersRequestProto {
+        role: Some(DEFAULT_ROLE_NAME.to_string()),
+        page_size: Some(2),
+        ..Default::default()
+    };
+
+    // act
+    let (first_page_status, first_page_result) =
+        list_users(&client, &admin_access_token, default_users_request.clone()).await?;
+    let Some(list_users_response_proto::Result::Success(first_page)) = first_page_result else {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected first page, got {first_page_status}: {first_page_result:?}"
+        )));
+    };
+    let (second_page_status, second_page_result) = list_users(
+        &client,
+        &admin_access_token,
+        ListUsersRequestProto {
+            cursor: first_page.next_cursor.clone(),
+            ..default_users_request.clone()
+        },
+    )
+    .await?;
+    let Some(list_users_response_proto::Result::Success(second_page)) = second_page_result else {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected second page, got {second_page_status}: {second_page_result:?}"
+        )));
+    };
+    let (forbidden_status, forbidden_result) =
+        list_users(&client, &default_access_token, default_users_request).await?;
+
+    // assert
+    let mut listed_user_names: Vec<String> = first_page
+        .users
+        .iter()
+        .chain(second_page.users.iter())
+        .map(|user| user.user_name.clone())
+        .collect();
+    listed_user_names.sort();
+    let mut expected_user_names = DEFAULT_USER_NAMES.map(str::to_string).to_vec();
+    expected_user_names.sort();
+    if first_page.users.len() != 2
+        || first_page.next_cursor.is_none()
+        || second_page.next_cursor.is_some()
+        || listed_user_names != expected_user_names
+    {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected default users split into two pages, got {listed_user_names:?} with cursors {:?} and {:?}",
+            first_page.next_cursor, second_page.next_cursor
+        )));
+    }
+
+    let forbidden =
+        list_users_response_proto::Result::Error(AdminUsersErrorCodeProto::Forbidden.into());
+    if forbidden_status != StatusCode::FORBIDDEN || forbidden_result != Some(forbidden) {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected forbidden error for default user, got {forbidden_status}: {forbidden_result:?}"
+        )));
+    }
+
+    Ok(())
+}
+
+async fn list_users(
+    client: &Client,
+    access_token: &str,
+    request: ListUsersRequestProto,
+) -> Result<(StatusCode, Option<list_users_response_proto::Result>), ErrorBoxed> {
+    let mut request_payload = Vec::new();
+    request.encode(&mut request_payload)?;
+
+    let response = client
+        .post(format!("http://{SERVER_ADDR}/{ENDPOINT}"))
+        .header(CONTENT_TYPE, "application/x-protobuf")
+        .header(AUTHORIZATION, format!("Bearer {access_token}"))
+        .body(request_payload)
+        .send()
+        .await?;
+    let status = response.status();
+    let list_users_response_proto = ListUsersResponseProto::decode(response.bytes().await?)?;
+
+    Ok((status, list_users_response_proto.result))
+}
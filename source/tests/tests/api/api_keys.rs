@@ -0,0 +1 @@
+mod scoped_api_key;
@@ -0,0 +1,243 @@
+use std::{error::Error, path::PathBuf, str::FromStr};
+
+use nimbus_auth_domain::entities::{keypair::SomeKeyPair, user::SomeUser};
+use nimbus_auth_proto::proto::nimbus::{
+    admin::users::v1::ListUsersRequestProto,
+    auth::api_keys::v1::{
+        ApiKeysErrorCodeProto, CreateApiKeyRequestProto, CreateApiKeyResponseProto,
+        ListApiKeysResponseProto, create_api_key_response_proto, list_api_keys_response_proto,
+    },
+};
+use nimbus_auth_shared::{
+    config::{AppConfigBuilder, AppConfigRequiredOptions},
+    constants::PERMISSION_READ_USERS,
+    errors::ErrorBoxed,
+    types::PasswordHashingParams,
+};
+use nimbus_auth_tests::utils::{
+    get_active_keypair, get_built_in_roles, get_signed_access_token, get_user,
+};
+use prost::Message;
+use reqwest::{
+    Client, StatusCode,
+    header::{AUTHORIZATION, CONTENT_TYPE},
+};
+
+use crate::api::{ApiTestState, run_api_test};
+
+const SERVER_ADDR: &str = "localhost:5010";
+const KEYPAIRS_STORE_PATH: &str = "/temp";
+const POSTGRES_DB_URL: &str =
+    "postgresql://<username>:<password>@<host>:<port>/<database>?<options>";
+
+const ADMIN_USER_NAME: &str = "administrator";
+const DEFAULT_USER_NAME: &str = "defaultuser";
+const PASSWORD: &str = "StrongPassword123!";
+const API_KEY_NAME: &str = "ci";
+const NOT_GRANTED_SCOPE: &str = "invoices:write";
+
+const PASSWORD_HASHING_PARAMS: PasswordHashingParams = PasswordHashingParams {
+    memory_cost_kib: 8 * 1024,
+    time_cost: 1,
+    parallelism: 1,
+};
+
+const API_KEYS_ENDPOINT: &str = "api_keys";
+const SCOPED_ENDPOINT: &str = "admin/users/search";
+const OUT_OF_SCOPE_ENDPOINT: &str = "admin/groups/developers";
+
+#[tokio::test]
+async fn api_key_grants_only_its_scopes_until_revoked() -> Result<(), Box<dyn Error>> {
+    let mut app_config_builder = AppConfigBuilder::new(AppConfigRequiredOptions {
+        server_addr: SERVER_ADDR.to_string(),
+        keypairs_store_path: PathBuf::from_str(KEYPAIRS_STORE_PATH)?,
+        postgres_db_url: POSTGRES_DB_URL.to_string(),
+    });
+    app_config_builder
+        .with_password_hash_memory_cost_kib(PASSWORD_HASHING_PARAMS.memory_cost_kib)
+        .with_password_hash_time_cost(PASSWORD_HASHING_PARAMS.time_cost)
+        .with_password_hash_parallelism(PASSWORD_HASHING_PARAMS.parallelism);
+    let app_config = app_config_builder.build()?;
+
+    let keypair = get_active_keypair();
+    let [_, admin_role] = get_built_in_roles();
+    let admin_user =
+        get_user(ADMIN_USER_NAME, PASSWORD, &PASSWORD_HASHING_PARAMS).with_roles(&[admin_role]);
+    let default_user = get_user(DEFAULT_USER_NAME, PASSWORD, &PASSWORD_HASHING_PARAMS);
+
+    let admin_access_token = get_signed_access_token(admin_user.claims(), &keypair);
+    let default_access_token = get_signed_access_token(default_user.claims(), &keypair);
+
+    let test_state = ApiTestState {
+        users: Some(vec![
+            SomeUser::from(admin_user),
+            SomeUser::from(default_user),
+        ]),
+        keypairs: Some(vec![SomeKeyPair::from(keypair)]),
+        ..Default::default()
+    };
+
+    run_api_test(
+        || test_action(admin_access_token, default_access_token),
+        app_config,
+        test_state,
+    )
+    .await
+    .map_err(|boxed| boxed.inner())
+}
+
+async fn test_action(
+    admin_access_token: String,
+    default_access_token: String,
+) -> Result<(), ErrorBoxed> {
+    // arrange
+    let client = Client::new();
+
+    // act
+    let (create_status, create_result) = create_api_key(
+        &client,
+        &admin_access_token,
+        &[PERMISSION_READ_USERS.to_string()],
+    )
+    .await?;
+    let Some(create_api_key_response_proto::Result::Success(created)) = create_result else {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected api key to be created, got {create_status}: {create_result:?}"
+        )));
+    };
+    let api_key_id = created
+        .api_key
+        .as_ref()
+        .map(|api_key| api_key.id.clone())
+        .unwrap_or_default();
+    let (not_granted_status, _) = create_api_key(
+        &client,
+        &default_access_token,
+        &[NOT_GRANTED_SCOPE.to_string()],
+    )
+    .await?;
+
+    let (created_by_api_key_status, created_by_api_key_result) = create_api_key(
+        &client,
+        &created.token,
+        &[PERMISSION_READ_USERS.to_string()],
+    )
+    .await?;
+
+    let scoped_status = call_scoped_endpoint(&client, &created.token).await?;
+    let out_of_scope_status = client
+        .post(format!("http://{SERVER_ADDR}/{OUT_OF_SCOPE_ENDPOINT}"))
+        .header(AUTHORIZATION, format!("Bearer {}", created.token))
+        .send()
+        .await?
+        .status();
+
+    let list_response = client
+        .get(format!("http://{SERVER_ADDR}/{API_KEYS_ENDPOINT}"))
+        .header(AUTHORIZATION, format!("Bearer {admin_access_token}"))
+        .send()
+        .await?;
+    let list_result = ListApiKeysResponseProto::decode(list_response.bytes().await?)?.result;
+
+    let revoke_status = client
+        .post(format!(
+            "http://{SERVER_ADDR}/{API_KEYS_ENDPOINT}/{api_key_id}/revoke"
+        ))
+        .header(AUTHORIZATION, format!("Bearer {admin_access_token}"))
+        .send()
+        .await?
+        .status();
+    let revoked_status = call_scoped_endpoint(&client, &created.token).await?;
+
+    // assert
+    if create_status != StatusCode::CREATED || !created.token.contains(&api_key_id) {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected api key token to contain its id, got {create_status}"
+        )));
+    }
+
+    if not_granted_status != StatusCode::FORBIDDEN {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected scope not granted to the user to be forbidden, got {not_granted_status}"
+        )));
+    }
+
+    // keys could otherwise mint keys outliving them
+    let forbidden =
+        create_api_key_response_proto::Result::Error(ApiKeysErrorCodeProto::Forbidden.into());
+    if created_by_api_key_status != StatusCode::FORBIDDEN
+        || created_by_api_key_result != Some(forbidden)
+    {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected api key creation by api key to be forbidden, got {created_by_api_key_status}: {created_by_api_key_result:?}"
+        )));
+    }
+
+    if scoped_status != StatusCode::OK {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected api key to grant its scope, got {scoped_status}"
+        )));
+    }
+
+    // other permissions of the user are not granted to the key
+    if out_of_scope_status != StatusCode::FORBIDDEN {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected api key to be limited to its scopes, got {out_of_scope_status}"
+        )));
+    }
+
+    match list_result {
+        Some(list_api_keys_response_proto::Result::Success(listed))
+            if listed.api_keys.len() == 1
+                && listed.api_keys[0].name == API_KEY_NAME
+                && listed.api_keys[0].scopes == [PERMISSION_READ_USERS.to_string()] => {}
+        _ => {
+            return Err(ErrorBoxed::from_str(format!(
+                "expected created api key to be listed, got {list_result:?}"
+            )));
+        }
+    }
+
+    if revoke_status != StatusCode::OK || revoked_status != StatusCode::UNAUTHORIZED {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected revoked api key to be unauthorized, got {revoke_status} and {revoked_status}"
+        )));
+    }
+
+    Ok(())
+}
+
+async fn create_api_key(
+    client: &Client,
+    access_token: &str,
+    scopes: &[String],
+) -> Result<(StatusCode, Option<create_api_key_response_proto::Result>), ErrorBoxed> {
+    let request = CreateApiKeyRequestProto {
+        name: API_KEY_NAME.to_string(),
+        scopes: scopes.to_vec(),
+        expires_in_seconds: Some(3600),
+    };
+
+    let response = client
+        .post(format!("http://{SERVER_ADDR}/{API_KEYS_ENDPOINT}"))
+        .header(AUTHORIZATION, format!("Bearer {access_token}"))
+        .header(CONTENT_TYPE, "application/x-protobuf")
+        .body(request.encode_to_vec())
+        .send()
+        .await?;
+    let status = response.status();
+    let result = CreateApiKeyResponseProto::decode(response.bytes().await?)?.result;
+
+    Ok((status, result))
+}
+
+async fn call_scoped_endpoint(client: &Client, api_key: &str) -> Result<StatusCode, ErrorBoxed> {
+    Ok(client
+        .post(format!("http://{SERVER_ADDR}/{SCOPED_ENDPOINT}"))
+        .header(AUTHORIZATION, format!("Bearer {api_key}"))
+        .header(CONTENT_TYPE, "application/x-protobuf")
+        .body(ListUsersRequestProto::default().encode_to_vec())
+        .send()
+        .await?
+        .status())
+}
@@ -0,0 +1 @@
+mod external_identity_linking;
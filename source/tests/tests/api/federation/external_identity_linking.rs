@@ -0,0 +1,467 @@
+use std::{error::Error, path::PathBuf, str::FromStr};
+
+use nimbus_auth_domain::entities::{Entity, keypair::SomeKeyPair, user::SomeUser};
+use nimbus_auth_proto::proto::nimbus::auth::federation::v1::{
+    FederatedAuthorizeResponseProto, FederatedSignInRequestProto, FederatedSignInResponseProto,
+    FederationErrorCodeProto, LinkExternalIdentityRequestProto, LinkExternalIdentityResponseProto,
+    ListExternalIdentitiesResponseProto, UnlinkExternalIdentityResponseProto,
+    federated_authorize_response_proto, federated_sign_in_response_proto,
+    link_external_identity_response_proto, list_external_identities_response_proto,
+    unlink_external_identity_response_proto,
+};
+use nimbus_auth_shared::{
+    config::{AppConfigBuilder, AppConfigRequiredOptions},
+    constants::{
+        CLIENT_TYPE_HEADER_NAME, CLIENT_TYPE_PC_HEADER_VALUE, FEDERATED_STATE_COOKIE_NAME,
+    },
+    errors::ErrorBoxed,
+    types::{OidcProvider, PasswordHashingParams},
+};
+use nimbus_auth_tests::{
+    stub_identity_provider::{StubAccount, StubIdentityProvider},
+    utils::{get_active_keypair, get_signed_access_token, get_user},
+};
+use prost::Message;
+use reqwest::{
+    Client, StatusCode,
+    header::{AUTHORIZATION, CONTENT_TYPE, COOKIE, LOCATION, SET_COOKIE},
+    redirect::Policy,
+};
+use url::Url;
+
+use crate::api::{ApiTestState, run_api_test};
+
+const SERVER_ADDR: &str = "localhost:5014";
+const IDENTITY_PROVIDER_ADDR: &str = "localhost:5015";
+const KEYPAIRS_STORE_PATH: &str = "/temp";
+const POSTGRES_DB_URL: &str =
+    "postgresql://<username>:<password>@<host>:<port>/<database>?<options>";
+
+const PROVIDER: &str = "stub";
+const CLIENT_ID: &str = "nimbus";
+const CLIENT_SECRET: &str = "stub-client-secret";
+const REDIRECT_URI: &str = "http://127.0.0.1:8080/federation/callback";
+
+const USER_NAME: &str = "stanislau";
+const PASSWORD: &str = "StrongPassword123!";
+const NEW_SUBJECT: &str = "248289761001";
+const NEW_USER_NAME: &str = "janedoe";
+// named after the existing user, which must not let the identity take over the account
+const EXISTING_USER_SUBJECT: &str = "248289761002";
+
+const PASSWORD_HASHING_PARAMS: PasswordHashingParams = PasswordHashingParams {
+    memory_cost_kib: 8 * 1024,
+    time_cost: 1,
+    parallelism: 1,
+};
+
+#[tokio::test]
+async fn federated_signin_provisions_users_and_links_identities() -> Result<(), Box<dyn Error>> {
+    let mut app_config_builder = AppConfigBuilder::new(AppConfigRequiredOptions {
+        server_addr: SERVER_ADDR.to_string(),
+        keypairs_store_path: PathBuf::from_str(KEYPAIRS_STORE_PATH)?,
+        postgres_db_url: POSTGRES_DB_URL.to_string(),
+    });
+    app_config_builder
+        .with_password_hash_memory_cost_kib(PASSWORD_HASHING_PARAMS.memory_cost_kib)
+        .with_password_hash_time_cost(PASSWORD_HASHING_PARAMS.time_cost)
+        .with_password_hash_parallelism(PASSWORD_HASHING_PARAMS.parallelism)
+        .with_oidc_provider(OidcProvider {
+            name: PROVIDER.to_string(),
+            issuer: StubIdentityProvider::issuer(IDENTITY_PROVIDER_ADDR),
+            client_id: CLIENT_ID.to_string(),
+            client_secret: CLIENT_SECRET.to_string(),
+            redirect_uri: REDIRECT_URI.to_string(),
+            scopes: vec!["openid".to_string(), "email".to_string()],
+        });
+    let app_config = app_config_builder.build()?;
+
+    let _identity_provider = StubIdentityProvider::start(
+        IDENTITY_PROVIDER_ADDR,
+        CLIENT_ID,
+        CLIENT_SECRET,
+        vec![
+            StubAccount {
+                subject: NEW_SUBJECT.to_string(),
+                email: Some(format!("{NEW_USER_NAME}@example.com")),
+                preferred_username: None,
+            },
+            StubAccount {
+                subject: EXISTING_USER_SUBJECT.to_string(),
+                email: Some("stanislau@example.com".to_string()),
+                preferred_username: Some(USER_NAME.to_string()),
+            },
+        ],
+    )
+    .await
+    .map_err(|boxed| boxed.inner())?;
+
+    let keypair = get_active_keypair();
+    let user = get_user(USER_NAME, PASSWORD, &PASSWORD_HASHING_PARAMS);
+    let user_id = user.id().to_string();
+    let access_token = get_signed_access_token(user.claims(), &keypair);
+
+    let test_state = ApiTestState {
+        users: Some(vec![SomeUser::from(user)]),
+        keypairs: Some(vec![SomeKeyPair::from(keypair)]),
+        ..Default::default()
+    };
+
+    run_api_test(
+        || test_action(access_token, user_id),
+        app_config,
+        test_state,
+    )
+    .await
+    .map_err(|boxed| boxed.inner())
+}
+
+async fn test_action(access_token: String, user_id: String) -> Result<(), ErrorBoxed> {
+    // arrange
+    let client = Client::builder().redirect(Policy::none()).build()?;
+
+    // act
+    // callback of an authorization the attacker started, delivered to the victim with their own state cookie
+    let (_, victim_state_cookie) =
+        authorize(&client, "auth/federation/stub/authorize", None).await?;
+    let (authorization_url, _) = authorize(&client, "auth/federation/stub/authorize", None).await?;
+    let (code, state) = authorize_at_provider(&client, &authorization_url, NEW_SUBJECT).await?;
+    let (csrf_status, csrf_result) =
+        federated_signin(&client, &code, &state, &victim_state_cookie).await?;
+
+    let (authorization_url, state_cookie) =
+        authorize(&client, "auth/federation/stub/authorize", None).await?;
+    let (code, state) = authorize_at_provider(&client, &authorization_url, NEW_SUBJECT).await?;
+    let (_, first_signin_result) = federated_signin(&client, &code, &state, &state_cookie).await?;
+    let (replayed_status, replayed_result) =
+        federated_signin(&client, &code, &state, &state_cookie).await?;
+
+    let (authorization_url, state_cookie) =
+        authorize(&client, "auth/federation/stub/authorize", None).await?;
+    let (code, state) = authorize_at_provider(&client, &authorization_url, NEW_SUBJECT).await?;
+    let (_, second_signin_result) = federated_signin(&client, &code, &state, &state_cookie).await?;
+
+    let (authorization_url, state_cookie) =
+        authorize(&client, "auth/federation/stub/authorize", None).await?;
+    let (code, state) =
+        authorize_at_provider(&client, &authorization_url, EXISTING_USER_SUBJECT).await?;
+    let (name_taken_status, name_taken_result) =
+        federated_signin(&client, &code, &state, &state_cookie).await?;
+
+    let (authorization_url, state_cookie) = authorize(
+        &client,
+        "external_identities/stub/authorize",
+        Some(&access_token),
+    )
+    .await?;
+    let (code, state) =
+        authorize_at_provider(&client, &authorization_url, EXISTING_USER_SUBJECT).await?;
+    let (_, link_result) =
+        link_identity(&client, &access_token, &code, &state, &state_cookie).await?;
+
+    let (authorization_url, state_cookie) =
+        authorize(&client, "auth/federation/stub/authorize", None).await?;
+    let (code, state) =
+        authorize_at_provider(&client, &authorization_url, EXISTING_USER_SUBJECT).await?;
+    let (_, linked_signin_result) = federated_signin(&client, &code, &state, &state_cookie).await?;
+
+    let linked_identities = list_identities(&client, &access_token).await?;
+    let (_, unlink_result) = unlink_identity(&client, &access_token).await?;
+    let unlinked_identities = list_identities(&client, &access_token).await?;
+
+    // assert
+    let invalid_state = federated_sign_in_response_proto::Result::Error(
+        FederationErrorCodeProto::InvalidState.into(),
+    );
+    if csrf_status != StatusCode::BAD_REQUEST || csrf_result != Some(invalid_state) {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected callback without the state cookie of its authorization to be rejected, got {csrf_status}: {csrf_result:?}"
+        )));
+    }
+
+    let Some(federated_sign_in_response_proto::Result::Success(first_signin)) = first_signin_result
+    else {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected provisioning signin, got {first_signin_result:?}"
+        )));
+    };
+    let provisioned_user = first_signin.user.unwrap_or_default();
+    if provisioned_user.user_name != NEW_USER_NAME {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected user named after the email, got {}",
+            provisioned_user.user_name
+        )));
+    }
+
+    let invalid_state = federated_sign_in_response_proto::Result::Error(
+        FederationErrorCodeProto::InvalidState.into(),
+    );
+    if replayed_status != StatusCode::BAD_REQUEST || replayed_result != Some(invalid_state) {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected replayed callback to be rejected, got {replayed_status}: {replayed_result:?}"
+        )));
+    }
+
+    let second_signin_user_id = match second_signin_result {
+        Some(federated_sign_in_response_proto::Result::Success(signin)) => {
+            signin.user.unwrap_or_default().id
+        }
+        result => {
+            return Err(ErrorBoxed::from_str(format!(
+                "expected second signin, got {result:?}"
+            )));
+        }
+    };
+    if second_signin_user_id != provisioned_user.id {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected the provisioned user on the second signin, got {second_signin_user_id}"
+        )));
+    }
+
+    let user_name_taken = federated_sign_in_response_proto::Result::Error(
+        FederationErrorCodeProto::UserNameTaken.into(),
+    );
+    if name_taken_status != StatusCode::CONFLICT || name_taken_result != Some(user_name_taken) {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected unlinked identity not to sign in as existing user, got {name_taken_status}: {name_taken_result:?}"
+        )));
+    }
+
+    match link_result {
+        Some(link_external_identity_response_proto::Result::Success(link))
+            if link
+                .identity
+                .as_ref()
+                .is_some_and(|identity| !identity.provisioned) => {}
+        result => {
+            return Err(ErrorBoxed::from_str(format!(
+                "expected identity to be linked, got {result:?}"
+            )));
+        }
+    }
+
+    let linked_signin_user_id = match linked_signin_result {
+        Some(federated_sign_in_response_proto::Result::Success(signin)) => {
+            signin.user.unwrap_or_default().id
+        }
+        result => {
+            return Err(ErrorBoxed::from_str(format!(
+                "expected signin with linked identity, got {result:?}"
+            )));
+        }
+    };
+    if linked_signin_user_id != user_id {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected signin as the user the identity is linked to, got {linked_signin_user_id}"
+        )));
+    }
+
+    if linked_identities.len() != 1 || !unlinked_identities.is_empty() {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected one identity before unlinking and none after, got {} and {}",
+            linked_identities.len(),
+            unlinked_identities.len()
+        )));
+    }
+    if !matches!(
+        unlink_result,
+        Some(unlink_external_identity_response_proto::Result::Success(_))
+    ) {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected identity to be unlinked, got {unlink_result:?}"
+        )));
+    }
+
+    let provisioned_access_token = first_signin.access_token.unwrap_or_default().token;
+    let (provisioned_unlink_status, provisioned_unlink_result) =
+        unlink_identity(&client, &provisioned_access_token).await?;
+    let identity_provisioned = unlink_external_identity_response_proto::Result::Error(
+        FederationErrorCodeProto::IdentityProvisioned.into(),
+    );
+    if provisioned_unlink_status != StatusCode::CONFLICT
+        || provisioned_unlink_result != Some(identity_provisioned)
+    {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected identity of provisioned user to stay linked, got {provisioned_unlink_status}: {provisioned_unlink_result:?}"
+        )));
+    }
+
+    Ok(())
+}
+
+/// Returns the authorization url along with the state cookie, to be sent back as a browser would
+async fn authorize(
+    client: &Client,
+    endpoint: &str,
+    access_token: Option<&str>,
+) -> Result<(String, String), ErrorBoxed> {
+    let mut request = client.post(format!("http://{SERVER_ADDR}/{endpoint}"));
+    if let Some(access_token) = access_token {
+        request = request.header(AUTHORIZATION, format!("Bearer {access_token}"));
+    }
+    let response = request.send().await?;
+    let status = response.status();
+    let state_cookie = response
+        .headers()
+        .get_all(SET_COOKIE)
+        .iter()
+        .filter_map(|cookie| cookie.to_str().ok())
+        .filter_map(|cookie| cookie.split(';').next())
+        .find(|cookie| cookie.starts_with(&format!("{FEDERATED_STATE_COOKIE_NAME}=")))
+        .map(str::to_string)
+        .ok_or(ErrorBoxed::from_str(format!(
+            "expected state cookie, got {status}"
+        )))?;
+    let response_proto = FederatedAuthorizeResponseProto::decode(response.bytes().await?)?;
+
+    match response_proto.result {
+        Some(federated_authorize_response_proto::Result::Success(success)) => {
+            Ok((success.authorization_url, state_cookie))
+        }
+        result => Err(ErrorBoxed::from_str(format!(
+            "expected authorization url, got {status}: {result:?}"
+        ))),
+    }
+}
+
+/// Follows the authorization url as the user would, returns the code and state of the callback
+async fn authorize_at_provider(
+    client: &Client,
+    authorization_url: &str,
+    subject: &str,
+) -> Result<(String, String), ErrorBoxed> {
+    let mut authorization_url = Url::parse(authorization_url)?;
+    authorization_url
+        .query_pairs_mut()
+        .append_pair("login_hint", subject);
+
+    let response = client.get(authorization_url).send().await?;
+    let location = response
+        .headers()
+        .get(LOCATION)
+        .and_then(|location| location.to_str().ok())
+        .ok_or(ErrorBoxed::from_str(format!(
+            "expected redirect to the callback, got {}",
+            response.status()
+        )))?;
+    let callback = Url::parse(location)?;
+    let get_param = |name: &str| {
+        callback
+            .query_pairs()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.to_string())
+            .ok_or(ErrorBoxed::from_str(format!("callback has no {name}")))
+    };
+
+    Ok((get_param("code")?, get_param("state")?))
+}
+
+async fn federated_signin(
+    client: &Client,
+    code: &str,
+    state: &str,
+    state_cookie: &str,
+) -> Result<(StatusCode, Option<federated_sign_in_response_proto::Result>), ErrorBoxed> {
+    let mut request_payload = Vec::new();
+    FederatedSignInRequestProto {
+        code: code.to_string(),
+        state: state.to_string(),
+        audiences: Vec::new(),
+    }
+    .encode(&mut request_payload)?;
+
+    let response = client
+        .post(format!(
+            "http://{SERVER_ADDR}/auth/federation/{PROVIDER}/signin"
+        ))
+        .header(CONTENT_TYPE, "application/x-protobuf")
+        .header(CLIENT_TYPE_HEADER_NAME, CLIENT_TYPE_PC_HEADER_VALUE)
+        .header(COOKIE, state_cookie)
+        .body(request_payload)
+        .send()
+        .await?;
+    let status = response.status();
+    let response_proto = FederatedSignInResponseProto::decode(response.bytes().await?)?;
+
+    Ok((status, response_proto.result))
+}
+
+async fn link_identity(
+    client: &Client,
+    access_token: &str,
+    code: &str,
+    state: &str,
+    state_cookie: &str,
+) -> Result<
+    (
+        StatusCode,
+        Option<link_external_identity_response_proto::Result>,
+    ),
+    ErrorBoxed,
+> {
+    let mut request_payload = Vec::new();
+    LinkExternalIdentityRequestProto {
+        code: code.to_string(),
+        state: state.to_string(),
+    }
+    .encode(&mut request_payload)?;
+
+    let response = client
+        .post(format!(
+            "http://{SERVER_ADDR}/external_identities/{PROVIDER}"
+        ))
+        .header(CONTENT_TYPE, "application/x-protobuf")
+        .header(AUTHORIZATION, format!("Bearer {access_token}"))
+        .header(COOKIE, state_cookie)
+        .body(request_payload)
+        .send()
+        .await?;
+    let status = response.status();
+    let response_proto = LinkExternalIdentityResponseProto::decode(response.bytes().await?)?;
+
+    Ok((status, response_proto.result))
+}
+
+async fn unlink_identity(
+    client: &Client,
+    access_token: &str,
+) -> Result<
+    (
+        StatusCode,
+        Option<unlink_external_identity_response_proto::Result>,
+    ),
+    ErrorBoxed,
+> {
+    let response = client
+        .delete(format!(
+            "http://{SERVER_ADDR}/external_identities/{PROVIDER}"
+        ))
+        .header(AUTHORIZATION, format!("Bearer {access_token}"))
+        .send()
+        .await?;
+    let status = response.status();
+    let response_proto = UnlinkExternalIdentityResponseProto::decode(response.bytes().await?)?;
+
+    Ok((status, response_proto.result))
+}
+
+async fn list_identities(client: &Client, access_token: &str) -> Result<Vec<String>, ErrorBoxed> {
+    let response = client
+        .get(format!("http://{SERVER_ADDR}/external_identities"))
+        .header(AUTHORIZATION, format!("Bearer {access_token}"))
+        .send()
+        .await?;
+    let status = response.status();
+    let response_proto = ListExternalIdentitiesResponseProto::decode(response.bytes().await?)?;
+
+    match response_proto.result {
+        Some(list_external_identities_response_proto::Result::Success(success)) => Ok(success
+            .identities
+            .into_iter()
+            .map(|identity| identity.subject)
+            .collect()),
+        result => Err(ErrorBoxed::from_str(format!(
+            "expected identities, got {status}: {result:?}"
+        ))),
+    }
+}
@@ -1,49 +1,105 @@
-use std::{sync::Arc, time::Duration};
+use std::{
+    sync::{Arc, Once},
+    time::Duration,
+};
 
-use nimbus_auth_application::use_cases::{UseCases, UseCasesConfig, UseCasesServices};
-use nimbus_auth_domain::entities::{keypair::SomeKeyPair, session::SomeSession, user::User};
+use argon2::password_hash::{SaltString, rand_core::OsRng};
+use nimbus_auth_application::{
+    services::{
+        identity_provider::IdentityProvider, legacy_authenticator::LegacyAuthenticator,
+        signup_notifier::SignUpNotifier,
+    },
+    use_cases::{UseCases, UseCasesConfig, UseCasesServices},
+};
+use nimbus_auth_domain::{
+    entities::{
+        keypair::SomeKeyPair,
+        oauth_client::OAuthClient,
+        session::SomeSession,
+        user::{SomeUser, value_objects::password_hash::PasswordHash},
+    },
+    value_objects::{
+        breached_passwords_filter::BreachedPasswordsFilter, password_peppers::PasswordPeppers,
+    },
+};
 use nimbus_auth_infrastructure::{
     services_implementations::{
-        os_random_service::OsRandomService, os_time_service::OsTimeService,
+        oidc_identity_provider::OidcIdentityProvider, os_random_service::OsRandomService,
+        os_time_service::OsTimeService,
     },
     web_api::WebApi,
 };
-use nimbus_auth_shared::{config::AppConfig, errors::ErrorBoxed};
+use nimbus_auth_shared::{config::AppConfig, errors::ErrorBoxed, types::Realm};
 use nimbus_auth_tests::mocks::{
     datastore::MockDatastore,
     services::{
-        keypair_repository::MockKeyPairRepository, session_repository::MockSessionRepository,
-        user_repository::MockUserRepository,
+        api_key_repository::MockApiKeyRepository,
+        authorization_code_repository::MockAuthorizationCodeRepository,
+        device_authorization_repository::MockDeviceAuthorizationRepository,
+        external_identity_repository::MockExternalIdentityRepository,
+        federated_authorization_repository::MockFederatedAuthorizationRepository,
+        group_repository::MockGroupRepository,
+        impersonation_repository::MockImpersonationRepository,
+        keypair_repository::MockKeyPairRepository,
+        oauth_client_repository::MockOAuthClientRepository, role_repository::MockRoleRepository,
+        session_repository::MockSessionRepository, user_repository::MockUserRepository,
     },
 };
 use tokio::{spawn, sync::oneshot, time::sleep};
 use tracing::subscriber;
 use tracing_subscriber::{EnvFilter, Registry, fmt, layer::SubscriberExt};
 
+mod admin_users;
+mod api_keys;
+mod federation;
+mod oauth;
+mod public_keys;
+mod realms;
 mod signin;
 mod signup;
 
 const ACTION_RUN_DELAY_MS: u64 = 1000u64;
 
+#[derive(Default)]
 struct ApiTestState<'a> {
-    pub users: Option<Vec<User>>,
+    pub users: Option<Vec<SomeUser<'static>>>,
     pub sessions: Option<Vec<SomeSession<'a>>>,
     pub keypairs: Option<Vec<SomeKeyPair<'a>>>,
+    pub oauth_clients: Option<Vec<OAuthClient>>,
+    pub signup_notifier: Option<Arc<dyn SignUpNotifier>>,
+    pub legacy_authenticator: Option<Arc<dyn LegacyAuthenticator>>,
+    /// Kept by the test to check what was stored, entities of the state are not added to it
+    pub datastore: Option<Arc<MockDatastore>>,
 }
 
 async fn run_api_test<Fut: Future<Output = Result<(), ErrorBoxed>>, TAction: FnOnce() -> Fut>(
     action: TAction,
     config: AppConfig,
     state: ApiTestState<'static>,
+) -> Result<(), ErrorBoxed> {
+    run_realms_api_test(action, config, vec![(Realm::default(), state)]).await
+}
+
+/// Each realm gets its own datastore, the default realm should go first
+async fn run_realms_api_test<
+    Fut: Future<Output = Result<(), ErrorBoxed>>,
+    TAction: FnOnce() -> Fut,
+>(
+    action: TAction,
+    config: AppConfig,
+    realms_states: Vec<(Realm, ApiTestState<'static>)>,
 ) -> Result<(), ErrorBoxed> {
     configure_tracing(&config);
 
-    let use_cases = build_use_cases(&config, state).await?;
+    let mut realms_use_cases = Vec::with_capacity(realms_states.len());
+    for (realm, state) in realms_states {
+        realms_use_cases.push(build_use_cases(&config, realm, state).await?);
+    }
 
     let (shutdown_signal_sender, shutdown_signal_receiver) = oneshot::channel();
 
     let join_handle = spawn(async move {
-        WebApi::serve(&config, use_cases, shutdown_signal_receiver).await?;
+        WebApi::serve(&config, realms_use_cases, shutdown_signal_receiver).await?;
         Ok::<(), ErrorBoxed>(())
     });
 
@@ -62,42 +118,106 @@ async fn run_api_test<Fut: Future<Output = Result<(), ErrorBoxed>>, TAction: FnO
 
 async fn build_use_cases(
     config: &AppConfig,
+    realm: Realm,
     state: ApiTestState<'static>,
 ) -> Result<UseCases, ErrorBoxed> {
+    let dummy_password_hash = match config.user_enumeration_protection() {
+        true => Some(Arc::new(PasswordHash::dummy(
+            SaltString::generate(&mut OsRng).as_str(),
+            &config.password_hashing_params(),
+            &PasswordPeppers::empty(),
+        )?)),
+        false => None,
+    };
+
     let use_cases_config = UseCasesConfig {
         session_expiration_seconds: config.session_expiration_seconds(),
         access_token_expiration_seconds: config.access_token_expiration_seconds(),
+        access_token_max_groups: config.access_token_max_groups(),
+        access_token_attributes: config.access_token_attributes().clone(),
+        realm,
+        password_policy: config.password_policy().clone(),
+        password_hashing_params: config.password_hashing_params(),
+        password_peppers: Arc::new(PasswordPeppers::empty()),
+        breached_passwords_filter: Arc::new(BreachedPasswordsFilter::empty()),
+        signin_lockout_policy: config.signin_lockout_policy(),
+        dummy_password_hash,
+        oauth_device_verification_uri: config
+            .oauth_device_verification_uri()
+            .map(|uri| uri.to_string()),
     };
 
-    let datastore = Arc::new(MockDatastore::new(
-        state.users,
-        state.sessions,
-        state.keypairs,
-    ));
+    let datastore = state.datastore.unwrap_or_else(|| {
+        Arc::new(MockDatastore::new(
+            state.users,
+            state.sessions,
+            state.keypairs,
+            state.oauth_clients,
+        ))
+    });
 
     let user_repository = MockUserRepository::new(datastore.clone());
+    let role_repository = MockRoleRepository::new(datastore.clone());
+    let group_repository = MockGroupRepository::new(datastore.clone());
     let session_repository = MockSessionRepository::new(datastore.clone());
     let keypair_repository = MockKeyPairRepository::new(datastore.clone());
+    let oauth_client_repository = MockOAuthClientRepository::new(datastore.clone());
+    let authorization_code_repository = MockAuthorizationCodeRepository::new(datastore.clone());
+    let device_authorization_repository = MockDeviceAuthorizationRepository::new(datastore.clone());
+    let api_key_repository = MockApiKeyRepository::new(datastore.clone());
+    let impersonation_repository = MockImpersonationRepository::new(datastore.clone());
+    let external_identity_repository = MockExternalIdentityRepository::new(datastore.clone());
+    let federated_authorization_repository =
+        MockFederatedAuthorizationRepository::new(datastore.clone());
+    // providers are real clients, tests point them to a stub provider
+    let identity_providers = config
+        .oidc_providers()
+        .iter()
+        .map(|provider| {
+            OidcIdentityProvider::new(provider).map(|identity_provider| {
+                (
+                    provider.name.clone(),
+                    Arc::new(identity_provider) as Arc<dyn IdentityProvider>,
+                )
+            })
+        })
+        .collect::<Result<_, _>>()?;
 
     let time_service = OsTimeService::new();
     let random_service = OsRandomService::new();
 
     let use_cases_services = UseCasesServices {
         user_repository: Arc::new(user_repository),
+        role_repository: Arc::new(role_repository),
+        group_repository: Arc::new(group_repository),
         session_repository: Arc::new(session_repository),
         keypair_repository: Arc::new(keypair_repository),
+        oauth_client_repository: Arc::new(oauth_client_repository),
+        authorization_code_repository: Arc::new(authorization_code_repository),
+        device_authorization_repository: Arc::new(device_authorization_repository),
+        api_key_repository: Arc::new(api_key_repository),
+        impersonation_repository: Arc::new(impersonation_repository),
+        identity_providers,
+        external_identity_repository: Arc::new(external_identity_repository),
+        federated_authorization_repository: Arc::new(federated_authorization_repository),
         time_service: Arc::new(time_service),
         random_service: Arc::new(random_service),
+        legacy_authenticator: state.legacy_authenticator,
+        signup_notifier: state.signup_notifier,
     };
 
     Ok(UseCases::new(use_cases_config, use_cases_services))
 }
 
+/// Api tests of one binary share the global subscriber, so only the first one sets it
 fn configure_tracing(_: &AppConfig) {
-    let subscriber = Registry::default()
-        .with(fmt::Layer::default())
-        .with(EnvFilter::new("debug"));
-
-    subscriber::set_global_default(subscriber)
-        .expect("tracing should have been configured successfully");
+    static CONFIGURE_TRACING: Once = Once::new();
+    CONFIGURE_TRACING.call_once(|| {
+        let subscriber = Registry::default()
+            .with(fmt::Layer::default())
+            .with(EnvFilter::new("debug"));
+
+        subscriber::set_global_default(subscriber)
+            .expect("tracing should have been configured successfully");
+    });
 }
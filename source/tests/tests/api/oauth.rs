@@ -0,0 +1,5 @@
+mod authorization_code_flow;
+mod client_credentials;
+mod device_flow;
+mod token_exchange;
+mod token_exchange_audience;
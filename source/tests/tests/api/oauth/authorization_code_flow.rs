@@ -0,0 +1,311 @@
+use std::{collections::BTreeSet, error::Error, path::PathBuf, str::FromStr};
+
+use nimbus_auth_domain::entities::{
+    Entity,
+    keypair::SomeKeyPair,
+    oauth_client::{
+        OAuthClient,
+        specifications::NewOAuthClientSpecification,
+        value_objects::{oauth_client_name::OAuthClientName, redirect_uri::RedirectUri},
+    },
+    user::SomeUser,
+};
+use nimbus_auth_proto::proto::nimbus::auth::signin::v1::SignInRequestProto;
+use nimbus_auth_shared::{
+    config::{AppConfigBuilder, AppConfigRequiredOptions},
+    constants::{
+        CLIENT_TYPE_HEADER_NAME, CLIENT_TYPE_PC_HEADER_VALUE, SESSION_COOKIE_NAME,
+        SESSION_HEADER_NAME,
+    },
+    errors::ErrorBoxed,
+    types::PasswordHashingParams,
+};
+use nimbus_auth_tests::utils::{get_active_keypair, get_user};
+use prost::Message;
+use reqwest::{
+    Client, StatusCode,
+    header::{CONTENT_TYPE, COOKIE, LOCATION},
+    redirect::Policy,
+};
+use serde_json::Value;
+use url::{Url, form_urlencoded::Serializer};
+
+use crate::api::{ApiTestState, run_api_test};
+
+const SERVER_ADDR: &str = "localhost:5008";
+const KEYPAIRS_STORE_PATH: &str = "/temp";
+const POSTGRES_DB_URL: &str =
+    "postgresql://<username>:<password>@<host>:<port>/<database>?<options>";
+
+const USER_NAME: &str = "stanislau";
+const PASSWORD: &str = "StrongPassword123!";
+const CLIENT_NAME: &str = "notes";
+const OTHER_CLIENT_NAME: &str = "calendar";
+const REDIRECT_URI: &str = "http://127.0.0.1:8080/callback";
+const STATE: &str = "af0ifjsldkj";
+const CODE_VERIFIER: &str = "dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk";
+const CODE_CHALLENGE: &str = "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM";
+
+const PASSWORD_HASHING_PARAMS: PasswordHashingParams = PasswordHashingParams {
+    memory_cost_kib: 8 * 1024,
+    time_cost: 1,
+    parallelism: 1,
+};
+
+const SIGNIN_ENDPOINT: &str = "auth/signin";
+const AUTHORIZE_ENDPOINT: &str = "oauth/authorize";
+const TOKEN_ENDPOINT: &str = "oauth/token";
+
+#[tokio::test]
+async fn authorization_code_flow_with_pkce() -> Result<(), Box<dyn Error>> {
+    let mut app_config_builder = AppConfigBuilder::new(AppConfigRequiredOptions {
+        server_addr: SERVER_ADDR.to_string(),
+        keypairs_store_path: PathBuf::from_str(KEYPAIRS_STORE_PATH)?,
+        postgres_db_url: POSTGRES_DB_URL.to_string(),
+    });
+    app_config_builder
+        .with_password_hash_memory_cost_kib(PASSWORD_HASHING_PARAMS.memory_cost_kib)
+        .with_password_hash_time_cost(PASSWORD_HASHING_PARAMS.time_cost)
+        .with_password_hash_parallelism(PASSWORD_HASHING_PARAMS.parallelism);
+    let app_config = app_config_builder.build()?;
+
+    let oauth_client = OAuthClient::new(NewOAuthClientSpecification {
+        name: OAuthClientName::from(CLIENT_NAME)?,
+        redirect_uris: vec![RedirectUri::from(REDIRECT_URI)?],
+        secret_hash: None,
+        scopes: BTreeSet::new(),
+    });
+    let client_id = oauth_client.id().to_string();
+    let other_oauth_client = OAuthClient::new(NewOAuthClientSpecification {
+        name: OAuthClientName::from(OTHER_CLIENT_NAME)?,
+        redirect_uris: vec![RedirectUri::from(REDIRECT_URI)?],
+        secret_hash: None,
+        scopes: BTreeSet::new(),
+    });
+    let other_client_id = other_oauth_client.id().to_string();
+
+    let test_state = ApiTestState {
+        users: Some(vec![SomeUser::from(get_user(
+            USER_NAME,
+            PASSWORD,
+            &PASSWORD_HASHING_PARAMS,
+        ))]),
+        keypairs: Some(vec![SomeKeyPair::from(get_active_keypair())]),
+        oauth_clients: Some(vec![oauth_client, other_oauth_client]),
+        ..Default::default()
+    };
+
+    run_api_test(
+        move || test_action(client_id, other_client_id),
+        app_config,
+        test_state,
+    )
+    .await
+    .map_err(|boxed| boxed.inner())
+}
+
+async fn test_action(client_id: String, other_client_id: String) -> Result<(), ErrorBoxed> {
+    // arrange
+    let client = Client::builder().redirect(Policy::none()).build()?;
+    let session_id = signin(&client).await?;
+
+    // act
+    let code = authorize(&client, &client_id, &session_id).await?;
+
+    let wrong_verifier_response = token(
+        &client,
+        &[
+            ("grant_type", "authorization_code"),
+            ("client_id", &client_id),
+            ("code", &code),
+            ("redirect_uri", REDIRECT_URI),
+            ("code_verifier", &CODE_VERIFIER.replace('d', "e")),
+        ],
+    )
+    .await?;
+
+    let code = authorize(&client, &client_id, &session_id).await?;
+    let code_response = token(
+        &client,
+        &[
+            ("grant_type", "authorization_code"),
+            ("client_id", &client_id),
+            ("code", &code),
+            ("redirect_uri", REDIRECT_URI),
+            ("code_verifier", CODE_VERIFIER),
+        ],
+    )
+    .await?;
+    let replayed_code_response = token(
+        &client,
+        &[
+            ("grant_type", "authorization_code"),
+            ("client_id", &client_id),
+            ("code", &code),
+            ("redirect_uri", REDIRECT_URI),
+            ("code_verifier", CODE_VERIFIER),
+        ],
+    )
+    .await?;
+
+    // assert
+    expect_error(wrong_verifier_response, "invalid_grant")?;
+    expect_error(replayed_code_response, "invalid_grant")?;
+
+    let (status, body) = code_response;
+    let refresh_token = match (&body["access_token"], &body["refresh_token"]) {
+        (Value::String(_), Value::String(refresh_token)) if status == StatusCode::OK => {
+            refresh_token.clone()
+        }
+        _ => {
+            return Err(ErrorBoxed::from_str(format!(
+                "expected tokens for authorization code, got {status}: {body}"
+            )));
+        }
+    };
+
+    // refresh tokens are bound to the client they are issued to
+    let other_client_refresh_response = token(
+        &client,
+        &[
+            ("grant_type", "refresh_token"),
+            ("client_id", &other_client_id),
+            ("refresh_token", &refresh_token),
+        ],
+    )
+    .await?;
+    expect_error(other_client_refresh_response, "invalid_grant")?;
+
+    let first_party_session_refresh_response = token(
+        &client,
+        &[
+            ("grant_type", "refresh_token"),
+            ("client_id", &client_id),
+            ("refresh_token", &session_id),
+        ],
+    )
+    .await?;
+    expect_error(first_party_session_refresh_response, "invalid_grant")?;
+
+    let (status, body) = token(
+        &client,
+        &[
+            ("grant_type", "refresh_token"),
+            ("client_id", &client_id),
+            ("refresh_token", &refresh_token),
+        ],
+    )
+    .await?;
+    if status != StatusCode::OK || !body["access_token"].is_string() {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected tokens for refresh token, got {status}: {body}"
+        )));
+    }
+
+    Ok(())
+}
+
+async fn signin(client: &Client) -> Result<String, ErrorBoxed> {
+    let signin_request_proto = SignInRequestProto {
+        user_name: USER_NAME.to_string(),
+        password: PASSWORD.to_string(),
+        audiences: vec![],
+    };
+    let mut request_payload = Vec::new();
+    signin_request_proto.encode(&mut request_payload)?;
+
+    let response = client
+        .post(format!("http://{SERVER_ADDR}/{SIGNIN_ENDPOINT}"))
+        .header(CONTENT_TYPE, "application/x-protobuf")
+        .header(CLIENT_TYPE_HEADER_NAME, CLIENT_TYPE_PC_HEADER_VALUE)
+        .body(request_payload)
+        .send()
+        .await?;
+
+    response
+        .headers()
+        .get(SESSION_HEADER_NAME)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string)
+        .ok_or_else(|| ErrorBoxed::from_str("expected session id header from signin"))
+}
+
+async fn authorize(
+    client: &Client,
+    client_id: &str,
+    session_id: &str,
+) -> Result<String, ErrorBoxed> {
+    let mut authorize_url = Url::parse(&format!("http://{SERVER_ADDR}/{AUTHORIZE_ENDPOINT}"))?;
+    authorize_url.query_pairs_mut().extend_pairs([
+        ("response_type", "code"),
+        ("client_id", client_id),
+        ("redirect_uri", REDIRECT_URI),
+        ("code_challenge", CODE_CHALLENGE),
+        ("code_challenge_method", "S256"),
+        ("state", STATE),
+    ]);
+
+    let response = client
+        .get(authorize_url)
+        .header(COOKIE, format!("{SESSION_COOKIE_NAME}={session_id}"))
+        .send()
+        .await?;
+
+    let status = response.status();
+    let location = response
+        .headers()
+        .get(LOCATION)
+        .and_then(|value| value.to_str().ok())
+        .map(Url::parse)
+        .transpose()?
+        .ok_or_else(|| {
+            ErrorBoxed::from_str(format!("expected redirect from authorize, got {status}"))
+        })?;
+
+    if !location.as_str().starts_with(REDIRECT_URI) {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected redirect to registered uri, got {location}"
+        )));
+    }
+    if !location
+        .query_pairs()
+        .any(|(name, value)| name == "state" && value == STATE)
+    {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected state to be passed back, got {location}"
+        )));
+    }
+
+    location
+        .query_pairs()
+        .find(|(name, _)| name == "code")
+        .map(|(_, code)| code.into_owned())
+        .ok_or_else(|| ErrorBoxed::from_str(format!("expected code in redirect, got {location}")))
+}
+
+async fn token(
+    client: &Client,
+    params: &[(&str, &str)],
+) -> Result<(StatusCode, Value), ErrorBoxed> {
+    let body = Serializer::new(String::new()).extend_pairs(params).finish();
+
+    let response = client
+        .post(format!("http://{SERVER_ADDR}/{TOKEN_ENDPOINT}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(body)
+        .send()
+        .await?;
+    let status = response.status();
+    let body = serde_json::from_slice(&response.bytes().await?)?;
+
+    Ok((status, body))
+}
+
+fn expect_error((status, body): (StatusCode, Value), error: &str) -> Result<(), ErrorBoxed> {
+    if status != StatusCode::BAD_REQUEST || body["error"] != error {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected {error} error, got {status}: {body}"
+        )));
+    }
+    Ok(())
+}
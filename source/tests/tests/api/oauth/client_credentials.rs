@@ -0,0 +1,157 @@
+use std::{collections::BTreeSet, error::Error, path::PathBuf, str::FromStr};
+
+use nimbus_auth_domain::{
+    entities::{
+        Entity,
+        keypair::SomeKeyPair,
+        oauth_client::{
+            OAuthClient, specifications::NewOAuthClientSpecification,
+            value_objects::oauth_client_name::OAuthClientName,
+        },
+        role::value_objects::permission::Permission,
+    },
+    value_objects::secret_hash::SecretHash,
+};
+use nimbus_auth_shared::{
+    config::{AppConfigBuilder, AppConfigRequiredOptions},
+    errors::ErrorBoxed,
+};
+use nimbus_auth_tests::utils::get_active_keypair;
+use reqwest::{Client, StatusCode, header::CONTENT_TYPE};
+use serde_json::Value;
+use url::form_urlencoded::Serializer;
+
+use crate::api::{ApiTestState, run_api_test};
+
+const SERVER_ADDR: &str = "localhost:5009";
+const KEYPAIRS_STORE_PATH: &str = "/temp";
+const POSTGRES_DB_URL: &str =
+    "postgresql://<username>:<password>@<host>:<port>/<database>?<options>";
+
+const CLIENT_NAME: &str = "billing";
+const CLIENT_SECRET: &str = "yD8lWq3h0C0vJf6Qb2sZpVtE9kN4mA7xR1uGcHiLoPw";
+const CLIENT_SCOPES: [&str; 2] = ["invoices:write", "orders:read"];
+const NOT_ALLOWED_SCOPE: &str = "auth:users:manage";
+
+const TOKEN_ENDPOINT: &str = "oauth/token";
+const USER_ONLY_ENDPOINT: &str = "admin/users/search";
+
+#[tokio::test]
+async fn client_credentials_grant() -> Result<(), Box<dyn Error>> {
+    let app_config = AppConfigBuilder::new(AppConfigRequiredOptions {
+        server_addr: SERVER_ADDR.to_string(),
+        keypairs_store_path: PathBuf::from_str(KEYPAIRS_STORE_PATH)?,
+        postgres_db_url: POSTGRES_DB_URL.to_string(),
+    })
+    .build()?;
+
+    let oauth_client = OAuthClient::new(NewOAuthClientSpecification {
+        name: OAuthClientName::from(CLIENT_NAME)?,
+        redirect_uris: Vec::new(),
+        secret_hash: Some(SecretHash::hash(CLIENT_SECRET)),
+        scopes: CLIENT_SCOPES
+            .iter()
+            .map(|scope| Permission::from(scope))
+            .collect::<Result<BTreeSet<_>, _>>()?,
+    });
+    let client_id = oauth_client.id().to_string();
+
+    let test_state = ApiTestState {
+        keypairs: Some(vec![SomeKeyPair::from(get_active_keypair())]),
+        oauth_clients: Some(vec![oauth_client]),
+        ..Default::default()
+    };
+
+    run_api_test(move || test_action(client_id), app_config, test_state)
+        .await
+        .map_err(|boxed| boxed.inner())
+}
+
+async fn test_action(client_id: String) -> Result<(), ErrorBoxed> {
+    // arrange
+    let client = Client::new();
+
+    // act
+    let (status, body) = token(&client, &client_id, CLIENT_SECRET, None).await?;
+    let (wrong_secret_status, wrong_secret_body) =
+        token(&client, &client_id, "wrong-secret", None).await?;
+    let (not_allowed_scope_status, not_allowed_scope_body) = token(
+        &client,
+        &client_id,
+        CLIENT_SECRET,
+        Some(&format!("{} {NOT_ALLOWED_SCOPE}", CLIENT_SCOPES[0])),
+    )
+    .await?;
+
+    // assert
+    let access_token = match (&body["access_token"], &body["scope"]) {
+        (Value::String(access_token), Value::String(scope))
+            if status == StatusCode::OK
+                && *scope == CLIENT_SCOPES.join(" ")
+                && body.get("refresh_token").is_none() =>
+        {
+            access_token.clone()
+        }
+        _ => {
+            return Err(ErrorBoxed::from_str(format!(
+                "expected access token with all client scopes, got {status}: {body}"
+            )));
+        }
+    };
+
+    if wrong_secret_status != StatusCode::UNAUTHORIZED
+        || wrong_secret_body["error"] != "invalid_client"
+    {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected invalid client error, got {wrong_secret_status}: {wrong_secret_body}"
+        )));
+    }
+
+    if not_allowed_scope_status != StatusCode::BAD_REQUEST
+        || not_allowed_scope_body["error"] != "invalid_scope"
+    {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected invalid scope error, got {not_allowed_scope_status}: {not_allowed_scope_body}"
+        )));
+    }
+
+    // client principals are told apart from users
+    let user_only_status = client
+        .post(format!("http://{SERVER_ADDR}/{USER_ONLY_ENDPOINT}"))
+        .bearer_auth(access_token)
+        .send()
+        .await?
+        .status();
+    if user_only_status != StatusCode::FORBIDDEN {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected client token to be forbidden for users endpoint, got {user_only_status}"
+        )));
+    }
+
+    Ok(())
+}
+
+async fn token(
+    client: &Client,
+    client_id: &str,
+    client_secret: &str,
+    scope: Option<&str>,
+) -> Result<(StatusCode, Value), ErrorBoxed> {
+    let mut body = Serializer::new(String::new());
+    body.append_pair("grant_type", "client_credentials");
+    if let Some(scope) = scope {
+        body.append_pair("scope", scope);
+    }
+
+    let response = client
+        .post(format!("http://{SERVER_ADDR}/{TOKEN_ENDPOINT}"))
+        .basic_auth(client_id, Some(client_secret))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(body.finish())
+        .send()
+        .await?;
+    let status = response.status();
+    let body = serde_json::from_slice(&response.bytes().await?)?;
+
+    Ok((status, body))
+}
@@ -0,0 +1,187 @@
+use std::{collections::BTreeSet, error::Error, path::PathBuf, str::FromStr};
+
+use nimbus_auth_domain::entities::{
+    Entity,
+    keypair::SomeKeyPair,
+    oauth_client::{
+        OAuthClient, specifications::NewOAuthClientSpecification,
+        value_objects::oauth_client_name::OAuthClientName,
+    },
+    user::SomeUser,
+};
+use nimbus_auth_shared::{
+    config::{AppConfigBuilder, AppConfigRequiredOptions},
+    errors::ErrorBoxed,
+    types::PasswordHashingParams,
+};
+use nimbus_auth_tests::utils::{get_active_keypair, get_signed_access_token, get_user};
+use reqwest::{
+    Client, StatusCode,
+    header::{AUTHORIZATION, CONTENT_TYPE},
+};
+use serde_json::Value;
+use url::form_urlencoded::Serializer;
+
+use crate::api::{ApiTestState, run_api_test};
+
+const SERVER_ADDR: &str = "localhost:5011";
+const KEYPAIRS_STORE_PATH: &str = "/temp";
+const POSTGRES_DB_URL: &str =
+    "postgresql://<username>:<password>@<host>:<port>/<database>?<options>";
+
+const USER_NAME: &str = "stanislau";
+const PASSWORD: &str = "StrongPassword123!";
+const CLIENT_NAME: &str = "cli";
+const VERIFICATION_URI: &str = "https://auth.example.com/device";
+
+const PASSWORD_HASHING_PARAMS: PasswordHashingParams = PasswordHashingParams {
+    memory_cost_kib: 8 * 1024,
+    time_cost: 1,
+    parallelism: 1,
+};
+
+const DEVICE_AUTHORIZATION_ENDPOINT: &str = "oauth/device_authorization";
+const DEVICE_APPROVE_ENDPOINT: &str = "oauth/device/approve";
+const TOKEN_ENDPOINT: &str = "oauth/token";
+const GRANT_TYPE_DEVICE_CODE: &str = "urn:ietf:params:oauth:grant-type:device_code";
+
+#[tokio::test]
+async fn device_flow_issues_tokens_once_user_approves() -> Result<(), Box<dyn Error>> {
+    let mut app_config_builder = AppConfigBuilder::new(AppConfigRequiredOptions {
+        server_addr: SERVER_ADDR.to_string(),
+        keypairs_store_path: PathBuf::from_str(KEYPAIRS_STORE_PATH)?,
+        postgres_db_url: POSTGRES_DB_URL.to_string(),
+    });
+    app_config_builder
+        .with_password_hash_memory_cost_kib(PASSWORD_HASHING_PARAMS.memory_cost_kib)
+        .with_password_hash_time_cost(PASSWORD_HASHING_PARAMS.time_cost)
+        .with_password_hash_parallelism(PASSWORD_HASHING_PARAMS.parallelism)
+        .with_oauth_device_verification_uri(VERIFICATION_URI);
+    let app_config = app_config_builder.build()?;
+
+    let oauth_client = OAuthClient::new(NewOAuthClientSpecification {
+        name: OAuthClientName::from(CLIENT_NAME)?,
+        redirect_uris: vec![],
+        secret_hash: None,
+        scopes: BTreeSet::new(),
+    });
+    let client_id = oauth_client.id().to_string();
+
+    let keypair = get_active_keypair();
+    let user = get_user(USER_NAME, PASSWORD, &PASSWORD_HASHING_PARAMS);
+    let access_token = get_signed_access_token(user.claims(), &keypair);
+
+    let test_state = ApiTestState {
+        users: Some(vec![SomeUser::from(user)]),
+        keypairs: Some(vec![SomeKeyPair::from(keypair)]),
+        oauth_clients: Some(vec![oauth_client]),
+        ..Default::default()
+    };
+
+    run_api_test(
+        move || test_action(client_id, access_token),
+        app_config,
+        test_state,
+    )
+    .await
+    .map_err(|boxed| boxed.inner())
+}
+
+async fn test_action(client_id: String, access_token: String) -> Result<(), ErrorBoxed> {
+    // arrange
+    let client = Client::new();
+    let (status, body) = post_form(
+        &client,
+        DEVICE_AUTHORIZATION_ENDPOINT,
+        None,
+        &[("client_id", &client_id)],
+    )
+    .await?;
+    let (Value::String(device_code), Value::String(user_code)) =
+        (&body["device_code"], &body["user_code"])
+    else {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected device and user codes, got {status}: {body}"
+        )));
+    };
+    if body["verification_uri"] != VERIFICATION_URI {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected configured verification uri, got {body}"
+        )));
+    }
+    let device_token_params = [
+        ("grant_type", GRANT_TYPE_DEVICE_CODE),
+        ("client_id", &client_id),
+        ("device_code", device_code),
+    ];
+
+    // act
+    let pending_response = post_form(&client, TOKEN_ENDPOINT, None, &device_token_params).await?;
+    let slow_down_response = post_form(&client, TOKEN_ENDPOINT, None, &device_token_params).await?;
+
+    // users type codes in any case and without the separator
+    let typed_user_code = user_code.replace('-', "").to_lowercase();
+    let (approve_status, approve_body) = post_form(
+        &client,
+        DEVICE_APPROVE_ENDPOINT,
+        Some(&access_token),
+        &[("user_code", &typed_user_code)],
+    )
+    .await?;
+
+    let (token_status, token_body) =
+        post_form(&client, TOKEN_ENDPOINT, None, &device_token_params).await?;
+    let replayed_response = post_form(&client, TOKEN_ENDPOINT, None, &device_token_params).await?;
+
+    // assert
+    expect_error(pending_response, "authorization_pending")?;
+    expect_error(slow_down_response, "slow_down")?;
+    expect_error(replayed_response, "invalid_grant")?;
+
+    if approve_status != StatusCode::OK || approve_body["client_name"] != CLIENT_NAME {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected device to be approved, got {approve_status}: {approve_body}"
+        )));
+    }
+    if token_status != StatusCode::OK
+        || !token_body["access_token"].is_string()
+        || !token_body["refresh_token"].is_string()
+    {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected tokens for approved device, got {token_status}: {token_body}"
+        )));
+    }
+
+    Ok(())
+}
+
+async fn post_form(
+    client: &Client,
+    endpoint: &str,
+    access_token: Option<&str>,
+    params: &[(&str, &str)],
+) -> Result<(StatusCode, Value), ErrorBoxed> {
+    let body = Serializer::new(String::new()).extend_pairs(params).finish();
+
+    let mut request = client
+        .post(format!("http://{SERVER_ADDR}/{endpoint}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(body);
+    if let Some(access_token) = access_token {
+        request = request.header(AUTHORIZATION, format!("Bearer {access_token}"));
+    }
+    let response = request.send().await?;
+    let status = response.status();
+    let body = serde_json::from_slice(&response.bytes().await?)?;
+
+    Ok((status, body))
+}
+
+fn expect_error((status, body): (StatusCode, Value), error: &str) -> Result<(), ErrorBoxed> {
+    if status != StatusCode::BAD_REQUEST || body["error"] != error {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected {error} error, got {status}: {body}"
+        )));
+    }
+    Ok(())
+}
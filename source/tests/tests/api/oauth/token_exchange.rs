@@ -0,0 +1,248 @@
+use std::{collections::BTreeSet, error::Error, path::PathBuf, str::FromStr};
+
+use nimbus_auth_domain::{
+    entities::{
+        Entity,
+        keypair::{Active, KeyPair, SomeKeyPair},
+        oauth_client::{
+            OAuthClient, specifications::NewOAuthClientSpecification,
+            value_objects::oauth_client_name::OAuthClientName,
+        },
+        user::SomeUser,
+    },
+    value_objects::{access_token::AccessToken, secret_hash::SecretHash},
+};
+use nimbus_auth_shared::{
+    config::{AppConfigBuilder, AppConfigRequiredOptions},
+    constants::{ACCESS_TOKEN_AUDIENCES_COMMA_SEPARATED_DEFAULT, PERMISSION_READ_USERS},
+    errors::ErrorBoxed,
+    types::{PasswordHashingParams, Realm},
+};
+use nimbus_auth_tests::utils::{
+    get_active_keypair, get_built_in_roles, get_signed_audiences_access_token, get_user,
+};
+use reqwest::{Client, StatusCode, header::CONTENT_TYPE};
+use serde_json::Value;
+use url::form_urlencoded::Serializer;
+
+use crate::api::{ApiTestState, run_realms_api_test};
+
+const SERVER_ADDR: &str = "localhost:5012";
+const KEYPAIRS_STORE_PATH: &str = "/temp";
+const POSTGRES_DB_URL: &str =
+    "postgresql://<username>:<password>@<host>:<port>/<database>?<options>";
+
+const USER_NAME: &str = "administrator";
+const PASSWORD: &str = "StrongPassword123!";
+const CLIENT_NAME: &str = "gateway";
+const CLIENT_SECRET: &str = "yD8lWq3h0C0vJf6Qb2sZpVtE9kN4mA7xR1uGcHiLoPw";
+const DOWNSTREAM_AUDIENCE: &str = "billing";
+const NOT_ALLOWED_AUDIENCE: &str = "payments";
+const NOT_GRANTED_SCOPE: &str = "invoices:write";
+
+const PASSWORD_HASHING_PARAMS: PasswordHashingParams = PasswordHashingParams {
+    memory_cost_kib: 8 * 1024,
+    time_cost: 1,
+    parallelism: 1,
+};
+
+const TOKEN_ENDPOINT: &str = "oauth/token";
+const GRANT_TYPE_TOKEN_EXCHANGE: &str = "urn:ietf:params:oauth:grant-type:token-exchange";
+const TOKEN_TYPE_ACCESS_TOKEN: &str = "urn:ietf:params:oauth:token-type:access_token";
+
+#[tokio::test]
+async fn token_exchange_narrows_token_and_records_actor() -> Result<(), Box<dyn Error>> {
+    let mut app_config_builder = AppConfigBuilder::new(AppConfigRequiredOptions {
+        server_addr: SERVER_ADDR.to_string(),
+        keypairs_store_path: PathBuf::from_str(KEYPAIRS_STORE_PATH)?,
+        postgres_db_url: POSTGRES_DB_URL.to_string(),
+    });
+    app_config_builder
+        .with_password_hash_memory_cost_kib(PASSWORD_HASHING_PARAMS.memory_cost_kib)
+        .with_password_hash_time_cost(PASSWORD_HASHING_PARAMS.time_cost)
+        .with_password_hash_parallelism(PASSWORD_HASHING_PARAMS.parallelism);
+    let app_config = app_config_builder.build()?;
+
+    let realm = Realm {
+        audiences: [
+            Realm::default().audiences,
+            vec![DOWNSTREAM_AUDIENCE.to_string()],
+        ]
+        .concat(),
+        ..Realm::default()
+    };
+
+    let oauth_client = OAuthClient::new(NewOAuthClientSpecification {
+        name: OAuthClientName::from(CLIENT_NAME)?,
+        redirect_uris: Vec::new(),
+        secret_hash: Some(SecretHash::hash(CLIENT_SECRET)),
+        scopes: BTreeSet::new(),
+    });
+    let client_id = oauth_client.id().to_string();
+
+    let keypair = get_active_keypair();
+    let [_, admin_role] = get_built_in_roles();
+    let user = get_user(USER_NAME, PASSWORD, &PASSWORD_HASHING_PARAMS).with_roles(&[admin_role]);
+    // exchange narrows the token to one of its audiences
+    let subject_token = get_signed_audiences_access_token(
+        user.claims(),
+        &keypair,
+        &realm,
+        &[
+            ACCESS_TOKEN_AUDIENCES_COMMA_SEPARATED_DEFAULT,
+            DOWNSTREAM_AUDIENCE,
+        ],
+    );
+
+    let test_state = ApiTestState {
+        users: Some(vec![SomeUser::from(user)]),
+        keypairs: Some(vec![SomeKeyPair::from(keypair.clone())]),
+        oauth_clients: Some(vec![oauth_client]),
+        ..Default::default()
+    };
+
+    run_realms_api_test(
+        {
+            let realm = realm.clone();
+            move || test_action(client_id, subject_token, keypair, realm)
+        },
+        app_config,
+        vec![(realm, test_state)],
+    )
+    .await
+    .map_err(|boxed| boxed.inner())
+}
+
+async fn test_action(
+    client_id: String,
+    subject_token: String,
+    keypair: KeyPair<Active>,
+    realm: Realm,
+) -> Result<(), ErrorBoxed> {
+    // arrange
+    let client = Client::new();
+    let (status, body) =
+        token(&client, &client_id, &[("grant_type", "client_credentials")]).await?;
+    let Value::String(actor_token) = &body["access_token"] else {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected access token of the gateway, got {status}: {body}"
+        )));
+    };
+
+    // act
+    let (status, body) = token(
+        &client,
+        &client_id,
+        &[
+            ("grant_type", GRANT_TYPE_TOKEN_EXCHANGE),
+            ("subject_token", &subject_token),
+            ("subject_token_type", TOKEN_TYPE_ACCESS_TOKEN),
+            ("actor_token", actor_token),
+            ("actor_token_type", TOKEN_TYPE_ACCESS_TOKEN),
+            ("audience", DOWNSTREAM_AUDIENCE),
+            ("scope", PERMISSION_READ_USERS),
+        ],
+    )
+    .await?;
+    let not_granted_scope_response = token(
+        &client,
+        &client_id,
+        &[
+            ("grant_type", GRANT_TYPE_TOKEN_EXCHANGE),
+            ("subject_token", &subject_token),
+            ("subject_token_type", TOKEN_TYPE_ACCESS_TOKEN),
+            ("scope", NOT_GRANTED_SCOPE),
+        ],
+    )
+    .await?;
+    let not_allowed_audience_response = token(
+        &client,
+        &client_id,
+        &[
+            ("grant_type", GRANT_TYPE_TOKEN_EXCHANGE),
+            ("subject_token", &subject_token),
+            ("subject_token_type", TOKEN_TYPE_ACCESS_TOKEN),
+            ("audience", NOT_ALLOWED_AUDIENCE),
+        ],
+    )
+    .await?;
+    // tokens of clients act for users, they are not subjects themselves
+    let client_subject_response = token(
+        &client,
+        &client_id,
+        &[
+            ("grant_type", GRANT_TYPE_TOKEN_EXCHANGE),
+            ("subject_token", actor_token),
+            ("subject_token_type", TOKEN_TYPE_ACCESS_TOKEN),
+        ],
+    )
+    .await?;
+
+    // assert
+    expect_error(not_granted_scope_response, "invalid_scope")?;
+    expect_error(not_allowed_audience_response, "invalid_target")?;
+    expect_error(client_subject_response, "invalid_grant")?;
+
+    let exchanged_token = match (&body["access_token"], &body["issued_token_type"]) {
+        (Value::String(access_token), Value::String(issued_token_type))
+            if status == StatusCode::OK
+                && issued_token_type == TOKEN_TYPE_ACCESS_TOKEN
+                && body["scope"] == PERMISSION_READ_USERS
+                && body.get("refresh_token").is_none() =>
+        {
+            AccessToken::verify_with_active(access_token, &keypair, &realm)?
+        }
+        _ => {
+            return Err(ErrorBoxed::from_str(format!(
+                "expected exchanged access token, got {status}: {body}"
+            )));
+        }
+    };
+
+    if exchanged_token.audiences().value() != [DOWNSTREAM_AUDIENCE] {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected token for the downstream service, got {:?}",
+            exchanged_token.audiences()
+        )));
+    }
+    if exchanged_token.actor().map(|actor| actor.subject()) != Some(client_id.as_str()) {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected gateway to be the actor, got {:?}",
+            exchanged_token.actor()
+        )));
+    }
+
+    Ok(())
+}
+
+async fn token(
+    client: &Client,
+    client_id: &str,
+    params: &[(&str, &str)],
+) -> Result<(StatusCode, Value), ErrorBoxed> {
+    let body = Serializer::new(String::new())
+        .extend_pairs(params)
+        .append_pair("client_id", client_id)
+        .append_pair("client_secret", CLIENT_SECRET)
+        .finish();
+
+    let response = client
+        .post(format!("http://{SERVER_ADDR}/{TOKEN_ENDPOINT}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(body)
+        .send()
+        .await?;
+    let status = response.status();
+    let body = serde_json::from_slice(&response.bytes().await?)?;
+
+    Ok((status, body))
+}
+
+fn expect_error((status, body): (StatusCode, Value), error: &str) -> Result<(), ErrorBoxed> {
+    if status != StatusCode::BAD_REQUEST || body["error"] != error {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected {error} error, got {status}: {body}"
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,158 @@
+use std::{collections::BTreeSet, error::Error, path::PathBuf, str::FromStr};
+
+use nimbus_auth_domain::{
+    entities::{
+        Entity,
+        keypair::SomeKeyPair,
+        oauth_client::{
+            OAuthClient, specifications::NewOAuthClientSpecification,
+            value_objects::oauth_client_name::OAuthClientName,
+        },
+        user::SomeUser,
+    },
+    value_objects::secret_hash::SecretHash,
+};
+use nimbus_auth_shared::{
+    config::{AppConfigBuilder, AppConfigRequiredOptions},
+    constants::ACCESS_TOKEN_AUDIENCES_COMMA_SEPARATED_DEFAULT,
+    errors::ErrorBoxed,
+    types::{PasswordHashingParams, Realm},
+};
+use nimbus_auth_tests::utils::{
+    get_active_keypair, get_built_in_roles, get_signed_audiences_access_token, get_user,
+};
+use reqwest::{Client, StatusCode, header::CONTENT_TYPE};
+use serde_json::Value;
+use url::form_urlencoded::Serializer;
+
+use crate::api::{ApiTestState, run_realms_api_test};
+
+const SERVER_ADDR: &str = "localhost:5023";
+const KEYPAIRS_STORE_PATH: &str = "/temp";
+const POSTGRES_DB_URL: &str =
+    "postgresql://<username>:<password>@<host>:<port>/<database>?<options>";
+
+const USER_NAME: &str = "administrator";
+const PASSWORD: &str = "StrongPassword123!";
+const CLIENT_NAME: &str = "billing";
+const CLIENT_SECRET: &str = "yD8lWq3h0C0vJf6Qb2sZpVtE9kN4mA7xR1uGcHiLoPw";
+const NARROW_AUDIENCE: &str = "billing";
+
+const PASSWORD_HASHING_PARAMS: PasswordHashingParams = PasswordHashingParams {
+    memory_cost_kib: 8 * 1024,
+    time_cost: 1,
+    parallelism: 1,
+};
+
+const TOKEN_ENDPOINT: &str = "oauth/token";
+const GRANT_TYPE_TOKEN_EXCHANGE: &str = "urn:ietf:params:oauth:grant-type:token-exchange";
+const TOKEN_TYPE_ACCESS_TOKEN: &str = "urn:ietf:params:oauth:token-type:access_token";
+
+#[tokio::test]
+async fn narrow_token_is_not_exchanged_for_another_audience() -> Result<(), Box<dyn Error>> {
+    let mut app_config_builder = AppConfigBuilder::new(AppConfigRequiredOptions {
+        server_addr: SERVER_ADDR.to_string(),
+        keypairs_store_path: PathBuf::from_str(KEYPAIRS_STORE_PATH)?,
+        postgres_db_url: POSTGRES_DB_URL.to_string(),
+    });
+    app_config_builder
+        .with_password_hash_memory_cost_kib(PASSWORD_HASHING_PARAMS.memory_cost_kib)
+        .with_password_hash_time_cost(PASSWORD_HASHING_PARAMS.time_cost)
+        .with_password_hash_parallelism(PASSWORD_HASHING_PARAMS.parallelism);
+    let app_config = app_config_builder.build()?;
+
+    let realm = Realm {
+        audiences: [
+            Realm::default().audiences,
+            vec![NARROW_AUDIENCE.to_string()],
+        ]
+        .concat(),
+        ..Realm::default()
+    };
+
+    let oauth_client = OAuthClient::new(NewOAuthClientSpecification {
+        name: OAuthClientName::from(CLIENT_NAME)?,
+        redirect_uris: Vec::new(),
+        secret_hash: Some(SecretHash::hash(CLIENT_SECRET)),
+        scopes: BTreeSet::new(),
+    });
+    let client_id = oauth_client.id().to_string();
+
+    let keypair = get_active_keypair();
+    let [_, admin_role] = get_built_in_roles();
+    let user = get_user(USER_NAME, PASSWORD, &PASSWORD_HASHING_PARAMS).with_roles(&[admin_role]);
+    // token the billing service got, it must not be turned into a token for other services
+    let subject_token =
+        get_signed_audiences_access_token(user.claims(), &keypair, &realm, &[NARROW_AUDIENCE]);
+
+    let test_state = ApiTestState {
+        users: Some(vec![SomeUser::from(user)]),
+        keypairs: Some(vec![SomeKeyPair::from(keypair)]),
+        oauth_clients: Some(vec![oauth_client]),
+        ..Default::default()
+    };
+
+    run_realms_api_test(
+        move || test_action(client_id, subject_token),
+        app_config,
+        vec![(realm, test_state)],
+    )
+    .await
+    .map_err(|boxed| boxed.inner())
+}
+
+async fn test_action(client_id: String, subject_token: String) -> Result<(), ErrorBoxed> {
+    // arrange
+    let client = Client::new();
+
+    // act
+    let (status, body) = exchange(&client, &client_id, &subject_token, NARROW_AUDIENCE).await?;
+    let (wider_status, wider_body) = exchange(
+        &client,
+        &client_id,
+        &subject_token,
+        ACCESS_TOKEN_AUDIENCES_COMMA_SEPARATED_DEFAULT,
+    )
+    .await?;
+
+    // assert
+    if status != StatusCode::OK || !body["access_token"].is_string() {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected token for the same audience, got {status}: {body}"
+        )));
+    }
+    if wider_status != StatusCode::BAD_REQUEST || wider_body["error"] != "invalid_target" {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected invalid_target error for another audience, got {wider_status}: {wider_body}"
+        )));
+    }
+
+    Ok(())
+}
+
+async fn exchange(
+    client: &Client,
+    client_id: &str,
+    subject_token: &str,
+    audience: &str,
+) -> Result<(StatusCode, Value), ErrorBoxed> {
+    let body = Serializer::new(String::new())
+        .append_pair("grant_type", GRANT_TYPE_TOKEN_EXCHANGE)
+        .append_pair("subject_token", subject_token)
+        .append_pair("subject_token_type", TOKEN_TYPE_ACCESS_TOKEN)
+        .append_pair("audience", audience)
+        .append_pair("client_id", client_id)
+        .append_pair("client_secret", CLIENT_SECRET)
+        .finish();
+
+    let response = client
+        .post(format!("http://{SERVER_ADDR}/{TOKEN_ENDPOINT}"))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(body)
+        .send()
+        .await?;
+    let status = response.status();
+    let body = serde_json::from_slice(&response.bytes().await?)?;
+
+    Ok((status, body))
+}
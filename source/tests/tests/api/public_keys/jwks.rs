@@ -0,0 +1,100 @@
+use std::{error::Error, path::PathBuf, str::FromStr};
+
+use jsonwebtoken::{Algorithm, DecodingKey, Validation};
+use nimbus_auth_domain::entities::{Entity, keypair::SomeKeyPair, user::SomeUser};
+use nimbus_auth_shared::{
+    config::{AppConfigBuilder, AppConfigRequiredOptions},
+    errors::ErrorBoxed,
+    types::PasswordHashingParams,
+};
+use nimbus_auth_tests::utils::{get_active_keypair, get_signed_access_token, get_user};
+use reqwest::{Client, StatusCode};
+
+use crate::api::{ApiTestState, run_api_test};
+
+const SERVER_ADDR: &str = "localhost:5017";
+const KEYPAIRS_STORE_PATH: &str = "/temp";
+const POSTGRES_DB_URL: &str =
+    "postgresql://<username>:<password>@<host>:<port>/<database>?<options>";
+
+const USER_NAME: &str = "someuser";
+const PASSWORD: &str = "StrongPassword123!";
+
+const PASSWORD_HASHING_PARAMS: PasswordHashingParams = PasswordHashingParams {
+    memory_cost_kib: 8 * 1024,
+    time_cost: 1,
+    parallelism: 1,
+};
+
+const ENDPOINT: &str = ".well-known/jwks.json";
+
+#[tokio::test]
+async fn jwks_verifies_issued_access_tokens() -> Result<(), Box<dyn Error>> {
+    let app_config = AppConfigBuilder::new(AppConfigRequiredOptions {
+        server_addr: SERVER_ADDR.to_string(),
+        keypairs_store_path: PathBuf::from_str(KEYPAIRS_STORE_PATH)?,
+        postgres_db_url: POSTGRES_DB_URL.to_string(),
+    })
+    .build()?;
+
+    let keypair = get_active_keypair();
+    let key_id = keypair.id().to_string();
+    let user = get_user(USER_NAME, PASSWORD, &PASSWORD_HASHING_PARAMS);
+    let access_token = get_signed_access_token(user.claims(), &keypair);
+
+    let test_state = ApiTestState {
+        users: Some(vec![SomeUser::from(user)]),
+        keypairs: Some(vec![SomeKeyPair::from(keypair)]),
+        ..Default::default()
+    };
+
+    run_api_test(|| test_action(key_id, access_token), app_config, test_state)
+        .await
+        .map_err(|boxed| boxed.inner())
+}
+
+async fn test_action(key_id: String, access_token: String) -> Result<(), ErrorBoxed> {
+    // arrange
+    let client = Client::new();
+
+    // act
+    let response = client
+        .get(format!("http://{SERVER_ADDR}/{ENDPOINT}"))
+        .send()
+        .await?;
+    let status = response.status();
+    let jwks: serde_json::Value = serde_json::from_slice(&response.bytes().await?)?;
+
+    // assert
+    if status != StatusCode::OK {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected jwks to be served, got {status}: {jwks}"
+        )));
+    }
+
+    let keys = jwks["keys"].as_array().cloned().unwrap_or_default();
+    let [jwk] = keys.as_slice() else {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected a single key in jwks, got {jwks}"
+        )));
+    };
+    if jwk["kid"] != key_id.as_str() || jwk["kty"] != "OKP" || jwk["crv"] != "Ed25519" {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected Ed25519 key {key_id}, got {jwk}"
+        )));
+    }
+
+    let x = jwk["x"].as_str().unwrap_or_default();
+    let decoding_key = DecodingKey::from_ed_components(x)?;
+    let mut validation = Validation::new(Algorithm::EdDSA);
+    validation.validate_aud = false;
+    if let Err(err) =
+        jsonwebtoken::decode::<serde_json::Value>(&access_token, &decoding_key, &validation)
+    {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected access token to be verified by jwks key, got {err}"
+        )));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,2 @@
+mod groups_per_realm;
+mod routing_by_path_prefix;
@@ -0,0 +1,170 @@
+use std::{error::Error, path::PathBuf, str::FromStr};
+
+use nimbus_auth_domain::entities::{keypair::SomeKeyPair, user::SomeUser};
+use nimbus_auth_proto::proto::nimbus::admin::groups::v1::{
+    CreateGroupResponseProto, ListGroupsResponseProto, create_group_response_proto,
+    list_groups_response_proto,
+};
+use nimbus_auth_shared::{
+    config::{AppConfigBuilder, AppConfigRequiredOptions},
+    errors::ErrorBoxed,
+    types::{PasswordHashingParams, Realm},
+};
+use nimbus_auth_tests::utils::{
+    get_active_keypair, get_built_in_roles, get_signed_access_token, get_signed_realm_access_token,
+    get_user,
+};
+use prost::Message;
+use reqwest::{Client, StatusCode, header::AUTHORIZATION};
+
+use crate::api::{ApiTestState, run_realms_api_test};
+
+const SERVER_ADDR: &str = "localhost:5021";
+const KEYPAIRS_STORE_PATH: &str = "/temp";
+const POSTGRES_DB_URL: &str =
+    "postgresql://<username>:<password>@<host>:<port>/<database>?<options>";
+
+const SHOP_REALM_NAME: &str = "shop";
+const ADMIN_USER_NAME: &str = "administrator";
+const PASSWORD: &str = "StrongPassword123!";
+const GROUP_NAME: &str = "billing";
+
+const PASSWORD_HASHING_PARAMS: PasswordHashingParams = PasswordHashingParams {
+    memory_cost_kib: 8 * 1024,
+    time_cost: 1,
+    parallelism: 1,
+};
+
+const ENDPOINT: &str = "admin/groups";
+
+#[tokio::test]
+async fn same_named_groups_are_created_in_each_realm() -> Result<(), Box<dyn Error>> {
+    let mut app_config_builder = AppConfigBuilder::new(AppConfigRequiredOptions {
+        server_addr: SERVER_ADDR.to_string(),
+        keypairs_store_path: PathBuf::from_str(KEYPAIRS_STORE_PATH)?,
+        postgres_db_url: POSTGRES_DB_URL.to_string(),
+    });
+    app_config_builder
+        .with_password_hash_memory_cost_kib(PASSWORD_HASHING_PARAMS.memory_cost_kib)
+        .with_password_hash_time_cost(PASSWORD_HASHING_PARAMS.time_cost)
+        .with_password_hash_parallelism(PASSWORD_HASHING_PARAMS.parallelism)
+        .with_realms_comma_separated(SHOP_REALM_NAME);
+    let app_config = app_config_builder.build()?;
+    let shop_realm = app_config.realms()[1].clone();
+
+    let keypair = get_active_keypair();
+    let [_, admin_role] = get_built_in_roles();
+    let admin_user =
+        get_user(ADMIN_USER_NAME, PASSWORD, &PASSWORD_HASHING_PARAMS).with_roles(&[admin_role]);
+
+    let default_realm_access_token = get_signed_access_token(admin_user.claims(), &keypair);
+    let shop_realm_access_token =
+        get_signed_realm_access_token(admin_user.claims(), &keypair, &shop_realm);
+
+    let default_realm_state = ApiTestState {
+        users: Some(vec![SomeUser::from(admin_user.clone())]),
+        keypairs: Some(vec![SomeKeyPair::from(keypair.clone())]),
+        ..Default::default()
+    };
+    let shop_realm_state = ApiTestState {
+        users: Some(vec![SomeUser::from(admin_user)]),
+        keypairs: Some(vec![SomeKeyPair::from(keypair)]),
+        ..Default::default()
+    };
+
+    run_realms_api_test(
+        || test_action(default_realm_access_token, shop_realm_access_token),
+        app_config,
+        vec![
+            (Realm::default(), default_realm_state),
+            (shop_realm, shop_realm_state),
+        ],
+    )
+    .await
+    .map_err(|boxed| boxed.inner())
+}
+
+async fn test_action(
+    default_realm_access_token: String,
+    shop_realm_access_token: String,
+) -> Result<(), ErrorBoxed> {
+    // arrange
+    let client = Client::new();
+    let default_realm_url = format!("http://{SERVER_ADDR}/{ENDPOINT}");
+    let shop_realm_url = format!("http://{SERVER_ADDR}/realms/{SHOP_REALM_NAME}/{ENDPOINT}");
+
+    // act
+    let default_realm_create_status =
+        create_group(&client, &default_realm_url, &default_realm_access_token).await?;
+    let shop_realm_group_names_before =
+        list_group_names(&client, &shop_realm_url, &shop_realm_access_token).await?;
+    let shop_realm_create_status =
+        create_group(&client, &shop_realm_url, &shop_realm_access_token).await?;
+    let shop_realm_group_names_after =
+        list_group_names(&client, &shop_realm_url, &shop_realm_access_token).await?;
+
+    // assert
+    if default_realm_create_status != StatusCode::CREATED {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected group to be created in the default realm, got {default_realm_create_status}"
+        )));
+    }
+
+    if !shop_realm_group_names_before.is_empty() {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected no groups in the shop realm, got {shop_realm_group_names_before:?}"
+        )));
+    }
+
+    if shop_realm_create_status != StatusCode::CREATED
+        || shop_realm_group_names_after != [GROUP_NAME]
+    {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected group of the same name to be created in the shop realm, \
+            got {shop_realm_create_status}: {shop_realm_group_names_after:?}"
+        )));
+    }
+
+    Ok(())
+}
+
+async fn create_group(
+    client: &Client,
+    url: &str,
+    access_token: &str,
+) -> Result<StatusCode, ErrorBoxed> {
+    let response = client
+        .post(format!("{url}/{GROUP_NAME}"))
+        .header(AUTHORIZATION, format!("Bearer {access_token}"))
+        .send()
+        .await?;
+    let status = response.status();
+
+    match CreateGroupResponseProto::decode(response.bytes().await?)?.result {
+        Some(create_group_response_proto::Result::Success(_)) => Ok(status),
+        result => Err(ErrorBoxed::from_str(format!(
+            "expected created group, got {status}: {result:?}"
+        ))),
+    }
+}
+
+async fn list_group_names(
+    client: &Client,
+    url: &str,
+    access_token: &str,
+) -> Result<Vec<String>, ErrorBoxed> {
+    let response = client
+        .get(url)
+        .header(AUTHORIZATION, format!("Bearer {access_token}"))
+        .send()
+        .await?;
+
+    match ListGroupsResponseProto::decode(response.bytes().await?)?.result {
+        Some(list_groups_response_proto::Result::Success(success)) => {
+            Ok(success.groups.into_iter().map(|group| group.name).collect())
+        }
+        result => Err(ErrorBoxed::from_str(format!(
+            "expected groups, got {result:?}"
+        ))),
+    }
+}
@@ -0,0 +1,183 @@
+use std::{error::Error, path::PathBuf, str::FromStr};
+
+use nimbus_auth_domain::entities::{keypair::SomeKeyPair, user::SomeUser};
+use nimbus_auth_proto::proto::nimbus::admin::users::v1::{
+    ListUsersRequestProto, ListUsersResponseProto, list_users_response_proto,
+};
+use nimbus_auth_shared::{
+    config::{AppConfigBuilder, AppConfigRequiredOptions},
+    errors::ErrorBoxed,
+    types::{PasswordHashingParams, Realm},
+};
+use nimbus_auth_tests::utils::{
+    get_active_keypair, get_built_in_roles, get_signed_access_token, get_signed_realm_access_token,
+    get_user,
+};
+use prost::Message;
+use reqwest::{
+    Client, StatusCode,
+    header::{AUTHORIZATION, CONTENT_TYPE},
+};
+
+use crate::api::{ApiTestState, run_realms_api_test};
+
+const SERVER_ADDR: &str = "localhost:5006";
+const KEYPAIRS_STORE_PATH: &str = "/temp";
+const POSTGRES_DB_URL: &str =
+    "postgresql://<username>:<password>@<host>:<port>/<database>?<options>";
+
+const SHOP_REALM_NAME: &str = "shop";
+const ADMIN_USER_NAME: &str = "administrator";
+const DEFAULT_REALM_USER_NAME: &str = "defaultrealmuser";
+const SHOP_REALM_USER_NAME: &str = "shoprealmuser";
+const PASSWORD: &str = "StrongPassword123!";
+
+const PASSWORD_HASHING_PARAMS: PasswordHashingParams = PasswordHashingParams {
+    memory_cost_kib: 8 * 1024,
+    time_cost: 1,
+    parallelism: 1,
+};
+
+const ENDPOINT: &str = "admin/users/search";
+
+#[tokio::test]
+async fn realms_are_isolated_by_path_prefix() -> Result<(), Box<dyn Error>> {
+    let mut app_config_builder = AppConfigBuilder::new(AppConfigRequiredOptions {
+        server_addr: SERVER_ADDR.to_string(),
+        keypairs_store_path: PathBuf::from_str(KEYPAIRS_STORE_PATH)?,
+        postgres_db_url: POSTGRES_DB_URL.to_string(),
+    });
+    app_config_builder
+        .with_password_hash_memory_cost_kib(PASSWORD_HASHING_PARAMS.memory_cost_kib)
+        .with_password_hash_time_cost(PASSWORD_HASHING_PARAMS.time_cost)
+        .with_password_hash_parallelism(PASSWORD_HASHING_PARAMS.parallelism)
+        .with_realms_comma_separated(SHOP_REALM_NAME);
+    let app_config = app_config_builder.build()?;
+    let shop_realm = app_config.realms()[1].clone();
+
+    // both realms trust the same keypair, so only the issuer and audience tell their tokens apart
+    let keypair = get_active_keypair();
+    let [_, admin_role] = get_built_in_roles();
+    let admin_user =
+        get_user(ADMIN_USER_NAME, PASSWORD, &PASSWORD_HASHING_PARAMS).with_roles(&[admin_role]);
+
+    let default_realm_access_token = get_signed_access_token(admin_user.claims(), &keypair);
+    let shop_realm_access_token =
+        get_signed_realm_access_token(admin_user.claims(), &keypair, &shop_realm);
+
+    let default_realm_state = ApiTestState {
+        users: Some(vec![
+            SomeUser::from(admin_user.clone()),
+            SomeUser::from(get_user(
+                DEFAULT_REALM_USER_NAME,
+                PASSWORD,
+                &PASSWORD_HASHING_PARAMS,
+            )),
+        ]),
+        keypairs: Some(vec![SomeKeyPair::from(keypair.clone())]),
+        ..Default::default()
+    };
+    let shop_realm_state = ApiTestState {
+        users: Some(vec![
+            SomeUser::from(admin_user),
+            SomeUser::from(get_user(
+                SHOP_REALM_USER_NAME,
+                PASSWORD,
+                &PASSWORD_HASHING_PARAMS,
+            )),
+        ]),
+        keypairs: Some(vec![SomeKeyPair::from(keypair)]),
+        ..Default::default()
+    };
+
+    run_realms_api_test(
+        || test_action(default_realm_access_token, shop_realm_access_token),
+        app_config,
+        vec![
+            (Realm::default(), default_realm_state),
+            (shop_realm, shop_realm_state),
+        ],
+    )
+    .await
+    .map_err(|boxed| boxed.inner())
+}
+
+async fn test_action(
+    default_realm_access_token: String,
+    shop_realm_access_token: String,
+) -> Result<(), ErrorBoxed> {
+    // arrange
+    let client = Client::new();
+    let default_realm_url = format!("http://{SERVER_ADDR}/{ENDPOINT}");
+    let shop_realm_url = format!("http://{SERVER_ADDR}/realms/{SHOP_REALM_NAME}/{ENDPOINT}");
+
+    // act
+    let (default_realm_status, default_realm_user_names) =
+        list_user_names(&client, &default_realm_url, &default_realm_access_token).await?;
+    let (shop_realm_status, shop_realm_user_names) =
+        list_user_names(&client, &shop_realm_url, &shop_realm_access_token).await?;
+    let (foreign_token_status, _) =
+        list_user_names(&client, &shop_realm_url, &default_realm_access_token).await?;
+
+    // assert
+    if default_realm_status != StatusCode::OK
+        || default_realm_user_names != [ADMIN_USER_NAME, DEFAULT_REALM_USER_NAME]
+    {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected users of the default realm, got {default_realm_status}: {default_realm_user_names:?}"
+        )));
+    }
+
+    if shop_realm_status != StatusCode::OK
+        || shop_realm_user_names != [ADMIN_USER_NAME, SHOP_REALM_USER_NAME]
+    {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected users of the shop realm, got {shop_realm_status}: {shop_realm_user_names:?}"
+        )));
+    }
+
+    if foreign_token_status != StatusCode::BAD_REQUEST {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected token of the default realm to be rejected by the shop realm, got {foreign_token_status}"
+        )));
+    }
+
+    Ok(())
+}
+
+async fn list_user_names(
+    client: &Client,
+    url: &str,
+    access_token: &str,
+) -> Result<(StatusCode, Vec<String>), ErrorBoxed> {
+    let mut request_payload = Vec::new();
+    ListUsersRequestProto::default().encode(&mut request_payload)?;
+
+    let response = client
+        .post(url)
+        .header(CONTENT_TYPE, "application/x-protobuf")
+        .header(AUTHORIZATION, format!("Bearer {access_token}"))
+        .body(request_payload)
+        .send()
+        .await?;
+    let status = response.status();
+    if status != StatusCode::OK {
+        return Ok((status, vec![]));
+    }
+
+    let mut user_names = match ListUsersResponseProto::decode(response.bytes().await?)?.result {
+        Some(list_users_response_proto::Result::Success(page)) => page
+            .users
+            .into_iter()
+            .map(|user| user.user_name)
+            .collect::<Vec<_>>(),
+        result => {
+            return Err(ErrorBoxed::from_str(format!(
+                "expected users page, got {result:?}"
+            )));
+        }
+    };
+    user_names.sort();
+
+    Ok((status, user_names))
+}
@@ -1 +1,5 @@
-
+mod legacy_migration;
+mod lockout;
+mod password_rehash;
+mod suspended_user;
+mod user_enumeration_protection_timing;
@@ -0,0 +1,149 @@
+use std::{error::Error, path::PathBuf, str::FromStr, sync::Arc};
+
+use nimbus_auth_domain::entities::keypair::SomeKeyPair;
+use nimbus_auth_proto::proto::nimbus::auth::signin::v1::{
+    SignInErrorCodeProto, SignInRequestProto, SignInResponseProto, sign_in_response_proto,
+};
+use nimbus_auth_shared::{
+    config::{AppConfigBuilder, AppConfigRequiredOptions},
+    constants::{CLIENT_TYPE_HEADER_NAME, CLIENT_TYPE_PC_HEADER_VALUE},
+    errors::ErrorBoxed,
+    types::PasswordHashingParams,
+};
+use nimbus_auth_tests::{
+    mocks::services::legacy_authenticator::MockLegacyAuthenticator, utils::get_active_keypair,
+};
+use prost::Message;
+use reqwest::{Client, StatusCode, header::CONTENT_TYPE};
+
+use crate::api::{ApiTestState, run_api_test};
+
+const SERVER_ADDR: &str = "localhost:5018";
+const KEYPAIRS_STORE_PATH: &str = "/temp";
+const POSTGRES_DB_URL: &str =
+    "postgresql://<username>:<password>@<host>:<port>/<database>?<options>";
+
+const LEGACY_USER_NAME: &str = "legacyuser";
+const PASSWORD: &str = "StrongPassword123!";
+const WRONG_PASSWORD: &str = "WrongPassword123!";
+
+const PASSWORD_HASHING_PARAMS: PasswordHashingParams = PasswordHashingParams {
+    memory_cost_kib: 8 * 1024,
+    time_cost: 1,
+    parallelism: 1,
+};
+
+const ENDPOINT: &str = "auth/signin";
+
+#[tokio::test]
+async fn legacy_user_is_migrated_on_first_signin() -> Result<(), Box<dyn Error>> {
+    let mut app_config_builder = AppConfigBuilder::new(AppConfigRequiredOptions {
+        server_addr: SERVER_ADDR.to_string(),
+        keypairs_store_path: PathBuf::from_str(KEYPAIRS_STORE_PATH)?,
+        postgres_db_url: POSTGRES_DB_URL.to_string(),
+    });
+    app_config_builder
+        .with_password_hash_memory_cost_kib(PASSWORD_HASHING_PARAMS.memory_cost_kib)
+        .with_password_hash_time_cost(PASSWORD_HASHING_PARAMS.time_cost)
+        .with_password_hash_parallelism(PASSWORD_HASHING_PARAMS.parallelism);
+    let app_config = app_config_builder.build()?;
+
+    let legacy_authenticator = MockLegacyAuthenticator::new(&[(LEGACY_USER_NAME, PASSWORD)]);
+
+    let test_state = ApiTestState {
+        keypairs: Some(vec![SomeKeyPair::from(get_active_keypair())]),
+        legacy_authenticator: Some(Arc::new(legacy_authenticator.clone())),
+        ..Default::default()
+    };
+
+    run_api_test(|| test_action(legacy_authenticator), app_config, test_state)
+        .await
+        .map_err(|boxed| boxed.inner())
+}
+
+async fn test_action(legacy_authenticator: MockLegacyAuthenticator) -> Result<(), ErrorBoxed> {
+    // arrange
+    let client = Client::new();
+
+    // act
+    let (first_status, first_result) = signin(&client, PASSWORD).await?;
+    let authentications_after_migration = legacy_authenticator.authentications().await;
+    let (second_status, second_result) = signin(&client, PASSWORD).await?;
+    let (wrong_password_status, wrong_password_result) = signin(&client, WRONG_PASSWORD).await?;
+    let authentications = legacy_authenticator.authentications().await;
+
+    // assert
+    let migrated_user_id = match first_result {
+        Some(sign_in_response_proto::Result::Success(signin)) if first_status == StatusCode::OK => {
+            signin.user.unwrap_or_default().id
+        }
+        result => {
+            return Err(ErrorBoxed::from_str(format!(
+                "expected legacy user to sign in, got {first_status}: {result:?}"
+            )));
+        }
+    };
+    if authentications_after_migration != [LEGACY_USER_NAME] {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected first signin to go through the legacy backend, got {authentications_after_migration:?}"
+        )));
+    }
+
+    // the migrated user signs in with the argon2 hash stored on the first signin
+    match second_result {
+        Some(sign_in_response_proto::Result::Success(signin))
+            if second_status == StatusCode::OK
+                && signin
+                    .user
+                    .as_ref()
+                    .is_some_and(|user| user.id == migrated_user_id) => {}
+        result => {
+            return Err(ErrorBoxed::from_str(format!(
+                "expected migrated user to sign in, got {second_status}: {result:?}"
+            )));
+        }
+    }
+
+    let wrong_credentials =
+        sign_in_response_proto::Result::Error(SignInErrorCodeProto::WrongCredentials.into());
+    if wrong_password_status != StatusCode::BAD_REQUEST
+        || wrong_password_result != Some(wrong_credentials)
+    {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected wrong password of migrated user to be rejected, got {wrong_password_status}: {wrong_password_result:?}"
+        )));
+    }
+
+    if authentications != authentications_after_migration {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected signins of migrated user to skip the legacy backend, got {authentications:?}"
+        )));
+    }
+
+    Ok(())
+}
+
+async fn signin(
+    client: &Client,
+    password: &str,
+) -> Result<(StatusCode, Option<sign_in_response_proto::Result>), ErrorBoxed> {
+    let signin_request_proto = SignInRequestProto {
+        user_name: LEGACY_USER_NAME.to_string(),
+        password: password.to_string(),
+        audiences: vec![],
+    };
+    let mut request_payload = Vec::new();
+    signin_request_proto.encode(&mut request_payload)?;
+
+    let response = client
+        .post(format!("http://{SERVER_ADDR}/{ENDPOINT}"))
+        .header(CONTENT_TYPE, "application/x-protobuf")
+        .header(CLIENT_TYPE_HEADER_NAME, CLIENT_TYPE_PC_HEADER_VALUE)
+        .body(request_payload)
+        .send()
+        .await?;
+    let status = response.status();
+    let signin_response_proto = SignInResponseProto::decode(response.bytes().await?)?;
+
+    Ok((status, signin_response_proto.result))
+}
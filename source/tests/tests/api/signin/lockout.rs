@@ -0,0 +1,231 @@
+use std::{error::Error, path::PathBuf, str::FromStr};
+
+use nimbus_auth_domain::entities::{keypair::SomeKeyPair, user::SomeUser};
+use nimbus_auth_proto::proto::nimbus::auth::{
+    signin::v1::{
+        SignInErrorCodeProto, SignInRequestProto, SignInResponseProto, sign_in_response_proto,
+    },
+    user_signin_lockout::v1::{
+        GetUserSigninLockoutResponseProto, ResetUserSigninLockoutResponseProto,
+        UserSigninLockoutErrorCodeProto, get_user_signin_lockout_response_proto,
+        reset_user_signin_lockout_response_proto,
+    },
+};
+use nimbus_auth_shared::{
+    config::{AppConfigBuilder, AppConfigRequiredOptions},
+    constants::{CLIENT_TYPE_HEADER_NAME, CLIENT_TYPE_PC_HEADER_VALUE},
+    errors::ErrorBoxed,
+    types::PasswordHashingParams,
+};
+use nimbus_auth_tests::utils::{
+    get_active_keypair, get_built_in_roles, get_signed_access_token, get_user,
+};
+use prost::Message;
+use reqwest::{
+    Client, StatusCode,
+    header::{AUTHORIZATION, CONTENT_TYPE},
+};
+
+use crate::api::{ApiTestState, run_api_test};
+
+const SERVER_ADDR: &str = "localhost:5016";
+const KEYPAIRS_STORE_PATH: &str = "/temp";
+const POSTGRES_DB_URL: &str =
+    "postgresql://<username>:<password>@<host>:<port>/<database>?<options>";
+
+const ADMIN_USER_NAME: &str = "administrator";
+const USER_NAME: &str = "lockeduser";
+const PASSWORD: &str = "StrongPassword123!";
+const WRONG_PASSWORD: &str = "WrongPassword123!";
+const MAX_FAILED_ATTEMPTS: u32 = 3;
+
+const PASSWORD_HASHING_PARAMS: PasswordHashingParams = PasswordHashingParams {
+    memory_cost_kib: 8 * 1024,
+    time_cost: 1,
+    parallelism: 1,
+};
+
+const SIGNIN_ENDPOINT: &str = "auth/signin";
+
+#[tokio::test]
+async fn user_is_locked_out_after_failed_attempts_until_admin_resets_it()
+-> Result<(), Box<dyn Error>> {
+    let mut app_config_builder = AppConfigBuilder::new(AppConfigRequiredOptions {
+        server_addr: SERVER_ADDR.to_string(),
+        keypairs_store_path: PathBuf::from_str(KEYPAIRS_STORE_PATH)?,
+        postgres_db_url: POSTGRES_DB_URL.to_string(),
+    });
+    app_config_builder
+        .with_password_hash_memory_cost_kib(PASSWORD_HASHING_PARAMS.memory_cost_kib)
+        .with_password_hash_time_cost(PASSWORD_HASHING_PARAMS.time_cost)
+        .with_password_hash_parallelism(PASSWORD_HASHING_PARAMS.parallelism)
+        .with_signin_lockout_max_failed_attempts(MAX_FAILED_ATTEMPTS);
+    let app_config = app_config_builder.build()?;
+
+    let keypair = get_active_keypair();
+    let [_, admin_role] = get_built_in_roles();
+    let admin_user =
+        get_user(ADMIN_USER_NAME, PASSWORD, &PASSWORD_HASHING_PARAMS).with_roles(&[admin_role]);
+    let user = get_user(USER_NAME, PASSWORD, &PASSWORD_HASHING_PARAMS);
+
+    let admin_access_token = get_signed_access_token(admin_user.claims(), &keypair);
+    let user_access_token = get_signed_access_token(user.claims(), &keypair);
+
+    let test_state = ApiTestState {
+        users: Some(vec![SomeUser::from(admin_user), SomeUser::from(user)]),
+        keypairs: Some(vec![SomeKeyPair::from(keypair)]),
+        ..Default::default()
+    };
+
+    run_api_test(
+        || test_action(admin_access_token, user_access_token),
+        app_config,
+        test_state,
+    )
+    .await
+    .map_err(|boxed| boxed.inner())
+}
+
+async fn test_action(
+    admin_access_token: String,
+    user_access_token: String,
+) -> Result<(), ErrorBoxed> {
+    // arrange
+    let client = Client::new();
+
+    // act
+    for _ in 0..MAX_FAILED_ATTEMPTS {
+        signin(&client, WRONG_PASSWORD).await?;
+    }
+    let (locked_status, locked_result) = signin(&client, PASSWORD).await?;
+    let (lockout_status, lockout_result) = get_lockout(&client, &admin_access_token).await?;
+    let (forbidden_reset_status, forbidden_reset_result) =
+        reset_lockout(&client, &user_access_token).await?;
+    let (reset_status, reset_result) = reset_lockout(&client, &admin_access_token).await?;
+    let (after_reset_status, after_reset_result) = signin(&client, PASSWORD).await?;
+
+    // assert
+    let user_locked_out =
+        sign_in_response_proto::Result::Error(SignInErrorCodeProto::UserLockedOut.into());
+    if locked_status != StatusCode::TOO_MANY_REQUESTS || locked_result != Some(user_locked_out) {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected user locked out error even with the right password, got {locked_status}: {locked_result:?}"
+        )));
+    }
+
+    match lockout_result {
+        Some(get_user_signin_lockout_response_proto::Result::Success(lockout))
+            if lockout.failed_attempts == MAX_FAILED_ATTEMPTS
+                && lockout.locked_until_unix_timestamp.is_some() => {}
+        lockout_result => {
+            return Err(ErrorBoxed::from_str(format!(
+                "expected {MAX_FAILED_ATTEMPTS} failed attempts with a lockout, got {lockout_status}: {lockout_result:?}"
+            )));
+        }
+    }
+
+    let forbidden = reset_user_signin_lockout_response_proto::Result::Error(
+        UserSigninLockoutErrorCodeProto::Forbidden.into(),
+    );
+    if forbidden_reset_status != StatusCode::FORBIDDEN || forbidden_reset_result != Some(forbidden)
+    {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected reset without permission to be forbidden, got {forbidden_reset_status}: {forbidden_reset_result:?}"
+        )));
+    }
+
+    if reset_status != StatusCode::OK
+        || !matches!(
+            reset_result,
+            Some(reset_user_signin_lockout_response_proto::Result::Success(_))
+        )
+    {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected lockout reset, got {reset_status}: {reset_result:?}"
+        )));
+    }
+
+    if after_reset_status != StatusCode::OK
+        || !matches!(
+            after_reset_result,
+            Some(sign_in_response_proto::Result::Success(_))
+        )
+    {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected signin after lockout reset to succeed, got {after_reset_status}: {after_reset_result:?}"
+        )));
+    }
+
+    Ok(())
+}
+
+async fn signin(
+    client: &Client,
+    password: &str,
+) -> Result<(StatusCode, Option<sign_in_response_proto::Result>), ErrorBoxed> {
+    let signin_request_proto = SignInRequestProto {
+        user_name: USER_NAME.to_string(),
+        password: password.to_string(),
+        audiences: vec![],
+    };
+    let mut request_payload = Vec::new();
+    signin_request_proto.encode(&mut request_payload)?;
+
+    let response = client
+        .post(format!("http://{SERVER_ADDR}/{SIGNIN_ENDPOINT}"))
+        .header(CONTENT_TYPE, "application/x-protobuf")
+        .header(CLIENT_TYPE_HEADER_NAME, CLIENT_TYPE_PC_HEADER_VALUE)
+        .body(request_payload)
+        .send()
+        .await?;
+    let status = response.status();
+    let signin_response_proto = SignInResponseProto::decode(response.bytes().await?)?;
+
+    Ok((status, signin_response_proto.result))
+}
+
+async fn get_lockout(
+    client: &Client,
+    access_token: &str,
+) -> Result<
+    (
+        StatusCode,
+        Option<get_user_signin_lockout_response_proto::Result>,
+    ),
+    ErrorBoxed,
+> {
+    let response = client
+        .get(format!(
+            "http://{SERVER_ADDR}/admin/users/{USER_NAME}/signin_lockout"
+        ))
+        .header(AUTHORIZATION, format!("Bearer {access_token}"))
+        .send()
+        .await?;
+    let status = response.status();
+    let response_proto = GetUserSigninLockoutResponseProto::decode(response.bytes().await?)?;
+
+    Ok((status, response_proto.result))
+}
+
+async fn reset_lockout(
+    client: &Client,
+    access_token: &str,
+) -> Result<
+    (
+        StatusCode,
+        Option<reset_user_signin_lockout_response_proto::Result>,
+    ),
+    ErrorBoxed,
+> {
+    let response = client
+        .post(format!(
+            "http://{SERVER_ADDR}/admin/users/{USER_NAME}/signin_lockout/reset"
+        ))
+        .header(AUTHORIZATION, format!("Bearer {access_token}"))
+        .send()
+        .await?;
+    let status = response.status();
+    let response_proto = ResetUserSigninLockoutResponseProto::decode(response.bytes().await?)?;
+
+    Ok((status, response_proto.result))
+}
@@ -0,0 +1,159 @@
+use std::{error::Error, path::PathBuf, str::FromStr, sync::Arc};
+
+use nimbus_auth_domain::{
+    entities::{Entity, keypair::SomeKeyPair, user::SomeUser},
+    value_objects::password_peppers::PasswordPeppers,
+};
+use nimbus_auth_proto::proto::nimbus::auth::signin::v1::{
+    SignInRequestProto, SignInResponseProto, sign_in_response_proto,
+};
+use nimbus_auth_shared::{
+    config::{AppConfigBuilder, AppConfigRequiredOptions},
+    constants::{CLIENT_TYPE_HEADER_NAME, CLIENT_TYPE_PC_HEADER_VALUE},
+    errors::ErrorBoxed,
+    types::PasswordHashingParams,
+};
+use nimbus_auth_tests::{
+    mocks::datastore::MockDatastore,
+    utils::{get_active_keypair, get_user},
+};
+use prost::Message;
+use reqwest::{Client, StatusCode, header::CONTENT_TYPE};
+
+use crate::api::{ApiTestState, run_api_test};
+
+const SERVER_ADDR: &str = "localhost:5020";
+const KEYPAIRS_STORE_PATH: &str = "/temp";
+const POSTGRES_DB_URL: &str =
+    "postgresql://<username>:<password>@<host>:<port>/<database>?<options>";
+
+const USER_NAME: &str = "outdateduser";
+const PASSWORD: &str = "StrongPassword123!";
+
+const OUTDATED_PASSWORD_HASHING_PARAMS: PasswordHashingParams = PasswordHashingParams {
+    memory_cost_kib: 8 * 1024,
+    time_cost: 1,
+    parallelism: 1,
+};
+const PASSWORD_HASHING_PARAMS: PasswordHashingParams = PasswordHashingParams {
+    memory_cost_kib: 8 * 1024,
+    time_cost: 2,
+    parallelism: 1,
+};
+
+const ENDPOINT: &str = "auth/signin";
+
+#[tokio::test]
+async fn outdated_password_hash_is_upgraded_on_signin() -> Result<(), Box<dyn Error>> {
+    let mut app_config_builder = AppConfigBuilder::new(AppConfigRequiredOptions {
+        server_addr: SERVER_ADDR.to_string(),
+        keypairs_store_path: PathBuf::from_str(KEYPAIRS_STORE_PATH)?,
+        postgres_db_url: POSTGRES_DB_URL.to_string(),
+    });
+    app_config_builder
+        .with_password_hash_memory_cost_kib(PASSWORD_HASHING_PARAMS.memory_cost_kib)
+        .with_password_hash_time_cost(PASSWORD_HASHING_PARAMS.time_cost)
+        .with_password_hash_parallelism(PASSWORD_HASHING_PARAMS.parallelism);
+    let app_config = app_config_builder.build()?;
+
+    let user = get_user(USER_NAME, PASSWORD, &OUTDATED_PASSWORD_HASHING_PARAMS);
+    let user_id = user.id().to_string();
+    let outdated_password_hash = user.password_hash().value().to_string();
+
+    let datastore = Arc::new(MockDatastore::new(
+        Some(vec![SomeUser::from(user)]),
+        None,
+        Some(vec![SomeKeyPair::from(get_active_keypair())]),
+        None,
+    ));
+
+    let test_state = ApiTestState {
+        datastore: Some(datastore.clone()),
+        ..Default::default()
+    };
+
+    run_api_test(
+        || test_action(datastore, user_id, outdated_password_hash),
+        app_config,
+        test_state,
+    )
+    .await
+    .map_err(|boxed| boxed.inner())
+}
+
+async fn test_action(
+    datastore: Arc<MockDatastore>,
+    user_id: String,
+    outdated_password_hash: String,
+) -> Result<(), ErrorBoxed> {
+    // arrange
+    let client = Client::new();
+
+    // act
+    let (first_status, first_result) = signin(&client).await?;
+    let upgraded_password_hash = datastore
+        .users()
+        .iter()
+        .find(|entry| entry.id().to_string() == user_id)
+        .map(|entry| entry.password_hash().clone())
+        .ok_or(ErrorBoxed::from_str("expected user to be kept"))?;
+    let (second_status, second_result) = signin(&client).await?;
+
+    // assert
+    if first_status != StatusCode::OK
+        || !matches!(
+            first_result,
+            Some(sign_in_response_proto::Result::Success(_))
+        )
+    {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected signin with outdated hash, got {first_status}: {first_result:?}"
+        )));
+    }
+
+    if upgraded_password_hash.value() == outdated_password_hash
+        || upgraded_password_hash.needs_rehash(&PASSWORD_HASHING_PARAMS, &PasswordPeppers::empty())
+    {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected stored hash to be upgraded to current params, got {}",
+            upgraded_password_hash.value()
+        )));
+    }
+
+    if second_status != StatusCode::OK
+        || !matches!(
+            second_result,
+            Some(sign_in_response_proto::Result::Success(_))
+        )
+    {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected signin with upgraded hash, got {second_status}: {second_result:?}"
+        )));
+    }
+
+    Ok(())
+}
+
+async fn signin(
+    client: &Client,
+) -> Result<(StatusCode, Option<sign_in_response_proto::Result>), ErrorBoxed> {
+    let signin_request_proto = SignInRequestProto {
+        user_name: USER_NAME.to_string(),
+        password: PASSWORD.to_string(),
+        audiences: vec![],
+    };
+    let mut request_payload = Vec::new();
+    signin_request_proto.encode(&mut request_payload)?;
+
+    let response = client
+        .post(format!("http://{SERVER_ADDR}/{ENDPOINT}"))
+        .header(CONTENT_TYPE, "application/x-protobuf")
+        .header(CLIENT_TYPE_HEADER_NAME, CLIENT_TYPE_PC_HEADER_VALUE)
+        .body(request_payload)
+        .send()
+        .await?;
+    let status = response.status();
+    let signin_response_proto = SignInResponseProto::decode(response.bytes().await?)?;
+
+    Ok((status, signin_response_proto.result))
+}
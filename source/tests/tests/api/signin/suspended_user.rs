@@ -0,0 +1,131 @@
+use std::{error::Error, path::PathBuf, str::FromStr};
+
+use nimbus_auth_domain::entities::{keypair::SomeKeyPair, user::SomeUser};
+use nimbus_auth_proto::proto::nimbus::auth::signin::v1::{
+    SignInErrorCodeProto, SignInRequestProto, SignInResponseProto, sign_in_response_proto,
+};
+use nimbus_auth_shared::{
+    config::{AppConfigBuilder, AppConfigRequiredOptions},
+    constants::{CLIENT_TYPE_HEADER_NAME, CLIENT_TYPE_PC_HEADER_VALUE},
+    errors::ErrorBoxed,
+    types::PasswordHashingParams,
+};
+use nimbus_auth_tests::utils::{get_active_keypair, get_user};
+use prost::Message;
+use reqwest::{Client, StatusCode, header::CONTENT_TYPE};
+use time::{Duration, OffsetDateTime};
+
+use crate::api::{ApiTestState, run_api_test};
+
+const SERVER_ADDR: &str = "localhost:5004";
+const KEYPAIRS_STORE_PATH: &str = "/temp";
+const POSTGRES_DB_URL: &str =
+    "postgresql://<username>:<password>@<host>:<port>/<database>?<options>";
+
+const SUSPENDED_USER_NAME: &str = "suspended";
+const FORMERLY_SUSPENDED_USER_NAME: &str = "formerlysuspended";
+const PASSWORD: &str = "StrongPassword123!";
+
+const PASSWORD_HASHING_PARAMS: PasswordHashingParams = PasswordHashingParams {
+    memory_cost_kib: 8 * 1024,
+    time_cost: 1,
+    parallelism: 1,
+};
+
+const ENDPOINT: &str = "auth/signin";
+
+#[tokio::test]
+async fn suspended_user_is_rejected_until_suspension_is_over() -> Result<(), Box<dyn Error>> {
+    let mut app_config_builder = AppConfigBuilder::new(AppConfigRequiredOptions {
+        server_addr: SERVER_ADDR.to_string(),
+        keypairs_store_path: PathBuf::from_str(KEYPAIRS_STORE_PATH)?,
+        postgres_db_url: POSTGRES_DB_URL.to_string(),
+    });
+    app_config_builder
+        .with_password_hash_memory_cost_kib(PASSWORD_HASHING_PARAMS.memory_cost_kib)
+        .with_password_hash_time_cost(PASSWORD_HASHING_PARAMS.time_cost)
+        .with_password_hash_parallelism(PASSWORD_HASHING_PARAMS.parallelism);
+    let app_config = app_config_builder.build()?;
+
+    let suspended_user = get_user(SUSPENDED_USER_NAME, PASSWORD, &PASSWORD_HASHING_PARAMS)
+        .suspend("abuse".to_string(), None);
+    let formerly_suspended_user = get_user(
+        FORMERLY_SUSPENDED_USER_NAME,
+        PASSWORD,
+        &PASSWORD_HASHING_PARAMS,
+    )
+    .suspend(
+        "abuse".to_string(),
+        Some(OffsetDateTime::now_utc() - Duration::hours(1)),
+    );
+
+    let test_state = ApiTestState {
+        users: Some(vec![
+            SomeUser::from(suspended_user),
+            SomeUser::from(formerly_suspended_user),
+        ]),
+        keypairs: Some(vec![SomeKeyPair::from(get_active_keypair())]),
+        ..Default::default()
+    };
+
+    run_api_test(test_action, app_config, test_state)
+        .await
+        .map_err(|boxed| boxed.inner())
+}
+
+async fn test_action() -> Result<(), ErrorBoxed> {
+    // arrange
+    let client = Client::new();
+
+    // act
+    let (suspended_status, suspended_result) = signin(&client, SUSPENDED_USER_NAME).await?;
+    let (formerly_suspended_status, formerly_suspended_result) =
+        signin(&client, FORMERLY_SUSPENDED_USER_NAME).await?;
+
+    // assert
+    let user_suspended =
+        sign_in_response_proto::Result::Error(SignInErrorCodeProto::UserSuspended.into());
+    if suspended_status != StatusCode::FORBIDDEN || suspended_result != Some(user_suspended) {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected user suspended error, got {suspended_status}: {suspended_result:?}"
+        )));
+    }
+
+    if formerly_suspended_status != StatusCode::OK
+        || !matches!(
+            formerly_suspended_result,
+            Some(sign_in_response_proto::Result::Success(_))
+        )
+    {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected signin after suspension is over to succeed, got {formerly_suspended_status}: {formerly_suspended_result:?}"
+        )));
+    }
+
+    Ok(())
+}
+
+async fn signin(
+    client: &Client,
+    user_name: &str,
+) -> Result<(StatusCode, Option<sign_in_response_proto::Result>), ErrorBoxed> {
+    let signin_request_proto = SignInRequestProto {
+        user_name: user_name.to_string(),
+        password: PASSWORD.to_string(),
+        audiences: vec![],
+    };
+    let mut request_payload = Vec::new();
+    signin_request_proto.encode(&mut request_payload)?;
+
+    let response = client
+        .post(format!("http://{SERVER_ADDR}/{ENDPOINT}"))
+        .header(CONTENT_TYPE, "application/x-protobuf")
+        .header(CLIENT_TYPE_HEADER_NAME, CLIENT_TYPE_PC_HEADER_VALUE)
+        .body(request_payload)
+        .send()
+        .await?;
+    let status = response.status();
+    let signin_response_proto = SignInResponseProto::decode(response.bytes().await?)?;
+
+    Ok((status, signin_response_proto.result))
+}
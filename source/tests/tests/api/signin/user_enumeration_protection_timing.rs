@@ -0,0 +1,192 @@
+use std::{
+    error::Error,
+    path::PathBuf,
+    str::FromStr,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use nimbus_auth_domain::entities::{keypair::SomeKeyPair, user::SomeUser};
+use nimbus_auth_proto::proto::nimbus::auth::signin::v1::{
+    SignInErrorCodeProto, SignInRequestProto, SignInResponseProto, sign_in_response_proto,
+};
+use nimbus_auth_shared::{
+    config::{AppConfig, AppConfigBuilder, AppConfigRequiredOptions},
+    constants::{CLIENT_TYPE_HEADER_NAME, CLIENT_TYPE_PC_HEADER_VALUE},
+    errors::ErrorBoxed,
+    types::PasswordHashingParams,
+};
+use nimbus_auth_tests::{
+    mocks::services::legacy_authenticator::MockLegacyAuthenticator,
+    utils::{get_active_keypair, get_user},
+};
+use prost::Message;
+use reqwest::{Client, StatusCode, header::CONTENT_TYPE};
+use tracing::debug;
+
+use crate::api::{ApiTestState, run_api_test};
+
+const SERVER_ADDR: &str = "localhost:5002";
+const LEGACY_BACKEND_SERVER_ADDR: &str = "localhost:5022";
+const KEYPAIRS_STORE_PATH: &str = "/temp";
+const POSTGRES_DB_URL: &str =
+    "postgresql://<username>:<password>@<host>:<port>/<database>?<options>";
+
+const EXISTING_USER_NAME: &str = "stanislau";
+const EXISTING_USER_PASSWORD: &str = "StrongPassword123!";
+const MISSING_USER_NAME: &str = "nobodyhere";
+const WRONG_PASSWORD: &str = "WrongPassword123!";
+
+const PASSWORD_HASHING_PARAMS: PasswordHashingParams = PasswordHashingParams {
+    memory_cost_kib: 8 * 1024,
+    time_cost: 1,
+    parallelism: 1,
+};
+
+/// Far longer than hashing, so a legacy backend asked only about unknown users makes the ratio exceed the max
+const LEGACY_BACKEND_LATENCY: Duration = Duration::from_millis(100);
+
+const SAMPLES: usize = 10;
+/// Max ratio between median response times, hashing dominates them so without the protection it is far bigger
+const MAX_MEDIAN_RATIO: f64 = 1.5;
+
+const ENDPOINT: &str = "auth/signin";
+
+#[tokio::test]
+async fn unknown_user_takes_as_long_as_wrong_password() -> Result<(), Box<dyn Error>> {
+    let app_config = get_app_config(SERVER_ADDR)?;
+
+    let test_state = ApiTestState {
+        users: Some(vec![SomeUser::from(get_user(
+            EXISTING_USER_NAME,
+            EXISTING_USER_PASSWORD,
+            &PASSWORD_HASHING_PARAMS,
+        ))]),
+        keypairs: Some(vec![SomeKeyPair::from(get_active_keypair())]),
+        ..Default::default()
+    };
+
+    run_api_test(|| test_action(SERVER_ADDR), app_config, test_state)
+        .await
+        .map_err(|boxed| boxed.inner())
+}
+
+#[tokio::test]
+async fn unknown_user_takes_as_long_as_wrong_password_with_legacy_backend()
+-> Result<(), Box<dyn Error>> {
+    let app_config = get_app_config(LEGACY_BACKEND_SERVER_ADDR)?;
+
+    let test_state = ApiTestState {
+        users: Some(vec![SomeUser::from(get_user(
+            EXISTING_USER_NAME,
+            EXISTING_USER_PASSWORD,
+            &PASSWORD_HASHING_PARAMS,
+        ))]),
+        keypairs: Some(vec![SomeKeyPair::from(get_active_keypair())]),
+        legacy_authenticator: Some(Arc::new(
+            MockLegacyAuthenticator::new(&[]).with_latency(LEGACY_BACKEND_LATENCY),
+        )),
+        ..Default::default()
+    };
+
+    run_api_test(
+        || test_action(LEGACY_BACKEND_SERVER_ADDR),
+        app_config,
+        test_state,
+    )
+    .await
+    .map_err(|boxed| boxed.inner())
+}
+
+fn get_app_config(server_addr: &str) -> Result<AppConfig, Box<dyn Error>> {
+    let mut app_config_builder = AppConfigBuilder::new(AppConfigRequiredOptions {
+        server_addr: server_addr.to_string(),
+        keypairs_store_path: PathBuf::from_str(KEYPAIRS_STORE_PATH)?,
+        postgres_db_url: POSTGRES_DB_URL.to_string(),
+    });
+    app_config_builder
+        .with_user_enumeration_protection()
+        .with_password_hash_memory_cost_kib(PASSWORD_HASHING_PARAMS.memory_cost_kib)
+        .with_password_hash_time_cost(PASSWORD_HASHING_PARAMS.time_cost)
+        .with_password_hash_parallelism(PASSWORD_HASHING_PARAMS.parallelism);
+    Ok(app_config_builder.build()?)
+}
+
+async fn test_action(server_addr: &str) -> Result<(), ErrorBoxed> {
+    // arrange
+    let client = Client::new();
+    signin_with_wrong_credentials(&client, server_addr, EXISTING_USER_NAME).await?;
+    signin_with_wrong_credentials(&client, server_addr, MISSING_USER_NAME).await?;
+
+    // act
+    let mut existing_user_durations = Vec::with_capacity(SAMPLES);
+    let mut missing_user_durations = Vec::with_capacity(SAMPLES);
+    for _ in 0..SAMPLES {
+        existing_user_durations
+            .push(signin_with_wrong_credentials(&client, server_addr, EXISTING_USER_NAME).await?);
+        missing_user_durations
+            .push(signin_with_wrong_credentials(&client, server_addr, MISSING_USER_NAME).await?);
+    }
+
+    // assert
+    let existing_user_median = get_median(existing_user_durations);
+    let missing_user_median = get_median(missing_user_durations);
+    debug!(
+        "existing user median: {existing_user_median:?}, missing user median: {missing_user_median:?}"
+    );
+
+    let ratio = existing_user_median.max(missing_user_median).as_secs_f64()
+        / existing_user_median.min(missing_user_median).as_secs_f64();
+    if ratio > MAX_MEDIAN_RATIO {
+        return Err(ErrorBoxed::from_str(format!(
+            "signin response time reveals whether user exists: existing user median {existing_user_median:?}, missing user median {missing_user_median:?}"
+        )));
+    }
+
+    Ok(())
+}
+
+/// Returns response time, fails if the response is not a wrong credentials error
+async fn signin_with_wrong_credentials(
+    client: &Client,
+    server_addr: &str,
+    user_name: &str,
+) -> Result<Duration, ErrorBoxed> {
+    let signin_request_proto = SignInRequestProto {
+        user_name: user_name.to_string(),
+        password: WRONG_PASSWORD.to_string(),
+        audiences: vec![],
+    };
+    let mut request_payload = Vec::new();
+    signin_request_proto.encode(&mut request_payload)?;
+
+    let start = Instant::now();
+    let response = client
+        .post(format!("http://{server_addr}/{ENDPOINT}"))
+        .header(CONTENT_TYPE, "application/x-protobuf")
+        .header(CLIENT_TYPE_HEADER_NAME, CLIENT_TYPE_PC_HEADER_VALUE)
+        .body(request_payload)
+        .send()
+        .await?;
+    let status = response.status();
+    let response_payload = response.bytes().await?;
+    let duration = start.elapsed();
+
+    let signin_response_proto = SignInResponseProto::decode(response_payload)?;
+    let wrong_credentials =
+        sign_in_response_proto::Result::Error(SignInErrorCodeProto::WrongCredentials.into());
+    if status != StatusCode::BAD_REQUEST || signin_response_proto.result != Some(wrong_credentials)
+    {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected wrong credentials error for user {user_name}, got {status}: {:?}",
+            signin_response_proto.result
+        )));
+    }
+
+    Ok(duration)
+}
+
+fn get_median(mut durations: Vec<Duration>) -> Duration {
+    durations.sort();
+    durations[durations.len() / 2]
+}
@@ -1 +1,3 @@
+mod disallowed_audience_validation_error;
+mod user_enumeration_protection_uniform_response;
 mod valid_data_no_existing_user_success;
@@ -0,0 +1,91 @@
+use std::{error::Error, path::PathBuf, str::FromStr};
+
+use nimbus_auth_domain::entities::keypair::SomeKeyPair;
+use nimbus_auth_proto::proto::nimbus::auth::signup::v1::{
+    SignUpErrorCodeProto, SignUpRequestProto, SignUpResponseProto, sign_up_response_proto,
+};
+use nimbus_auth_shared::{
+    config::{AppConfigBuilder, AppConfigRequiredOptions},
+    constants::{CLIENT_TYPE_HEADER_NAME, CLIENT_TYPE_PC_HEADER_VALUE},
+    errors::ErrorBoxed,
+};
+use nimbus_auth_tests::utils::get_active_keypair;
+use prost::Message;
+use reqwest::{Client, StatusCode, header::CONTENT_TYPE};
+
+use crate::api::{ApiTestState, run_api_test};
+
+const SERVER_ADDR: &str = "localhost:5007";
+const KEYPAIRS_STORE_PATH: &str = "/temp";
+const POSTGRES_DB_URL: &str =
+    "postgresql://<username>:<password>@<host>:<port>/<database>?<options>";
+
+const VALID_USER_NAME: &str = "stanislau";
+const VALID_PASSWORD: &str = "StrongPassword123!";
+const ALLOWED_AUDIENCES: &str = "nimbus,billing";
+const DISALLOWED_AUDIENCE: &str = "payments";
+
+const ENDPOINT: &str = "auth/signup";
+
+#[tokio::test]
+async fn disallowed_audience() -> Result<(), Box<dyn Error>> {
+    let mut app_config_builder = AppConfigBuilder::new(AppConfigRequiredOptions {
+        server_addr: SERVER_ADDR.to_string(),
+        keypairs_store_path: PathBuf::from_str(KEYPAIRS_STORE_PATH)?,
+        postgres_db_url: POSTGRES_DB_URL.to_string(),
+    });
+    app_config_builder.with_access_token_audiences_comma_separated(ALLOWED_AUDIENCES);
+    let app_config = app_config_builder.build()?;
+
+    let active_keypair = get_active_keypair();
+
+    let test_state = ApiTestState {
+        keypairs: Some(vec![SomeKeyPair::from(active_keypair)]),
+        ..Default::default()
+    };
+
+    run_api_test(test_action, app_config, test_state)
+        .await
+        .map_err(|boxed| boxed.inner())
+}
+
+async fn test_action() -> Result<(), ErrorBoxed> {
+    // act
+    let signup_request_proto = SignUpRequestProto {
+        user_name: VALID_USER_NAME.to_string(),
+        password: VALID_PASSWORD.to_string(),
+        audiences: vec!["billing".to_string(), DISALLOWED_AUDIENCE.to_string()],
+    };
+    let mut request_payload = Vec::new();
+    signup_request_proto.encode(&mut request_payload)?;
+
+    let client = Client::new();
+    let response = client
+        .post(format!("http://{SERVER_ADDR}/{ENDPOINT}"))
+        .header(CONTENT_TYPE, "application/x-protobuf")
+        .header(CLIENT_TYPE_HEADER_NAME, CLIENT_TYPE_PC_HEADER_VALUE)
+        .body(request_payload)
+        .send()
+        .await?;
+
+    let status = response.status();
+    let signup_response_proto = SignUpResponseProto::decode(response.bytes().await?)?;
+
+    // assert
+    if status != StatusCode::BAD_REQUEST {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected bad request status, got {status}"
+        )));
+    }
+
+    match signup_response_proto.result {
+        Some(sign_up_response_proto::Result::Error(error_code))
+            if error_code == SignUpErrorCodeProto::ValidationError as i32 =>
+        {
+            Ok(())
+        }
+        result => Err(ErrorBoxed::from_str(format!(
+            "expected validation error from api, got {result:?}"
+        ))),
+    }
+}
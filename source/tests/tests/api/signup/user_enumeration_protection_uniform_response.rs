@@ -0,0 +1,174 @@
+use std::{
+    error::Error,
+    path::PathBuf,
+    str::FromStr,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use nimbus_auth_application::services::signup_notifier::SignUpNotification;
+use nimbus_auth_domain::entities::{keypair::SomeKeyPair, user::SomeUser};
+use nimbus_auth_proto::proto::nimbus::auth::signup::v1::{
+    SignUpAcceptedResponseProto, SignUpRequestProto, SignUpResponseProto, sign_up_response_proto,
+};
+use nimbus_auth_shared::{
+    config::{AppConfigBuilder, AppConfigRequiredOptions},
+    constants::{CLIENT_TYPE_HEADER_NAME, CLIENT_TYPE_PC_HEADER_VALUE, SESSION_HEADER_NAME},
+    errors::ErrorBoxed,
+    types::PasswordHashingParams,
+};
+use nimbus_auth_tests::{
+    mocks::services::signup_notifier::MockSignUpNotifier,
+    utils::{get_active_keypair, get_user},
+};
+use prost::Message;
+use reqwest::{Client, StatusCode, header::CONTENT_TYPE};
+use tokio::time::sleep;
+use tracing::debug;
+
+use crate::api::{ApiTestState, run_api_test};
+
+const SERVER_ADDR: &str = "localhost:5003";
+const KEYPAIRS_STORE_PATH: &str = "/temp";
+const POSTGRES_DB_URL: &str =
+    "postgresql://<username>:<password>@<host>:<port>/<database>?<options>";
+
+const EXISTING_USER_NAME: &str = "stanislau";
+const NEW_USER_NAME_PREFIX: &str = "newuser";
+const VALID_PASSWORD: &str = "StrongPassword123!";
+
+const PASSWORD_HASHING_PARAMS: PasswordHashingParams = PasswordHashingParams {
+    memory_cost_kib: 8 * 1024,
+    time_cost: 1,
+    parallelism: 1,
+};
+
+const SAMPLES: usize = 10;
+/// Max ratio between median response times, hashing dominates them so without the protection it is far bigger
+const MAX_MEDIAN_RATIO: f64 = 1.5;
+
+const NOTIFICATIONS_DELIVERY_DELAY: Duration = Duration::from_millis(200);
+
+const ENDPOINT: &str = "auth/signup";
+
+#[tokio::test]
+async fn taken_user_name_gets_same_response() -> Result<(), Box<dyn Error>> {
+    let mut app_config_builder = AppConfigBuilder::new(AppConfigRequiredOptions {
+        server_addr: SERVER_ADDR.to_string(),
+        keypairs_store_path: PathBuf::from_str(KEYPAIRS_STORE_PATH)?,
+        postgres_db_url: POSTGRES_DB_URL.to_string(),
+    });
+    app_config_builder
+        .with_user_enumeration_protection()
+        .with_password_hash_memory_cost_kib(PASSWORD_HASHING_PARAMS.memory_cost_kib)
+        .with_password_hash_time_cost(PASSWORD_HASHING_PARAMS.time_cost)
+        .with_password_hash_parallelism(PASSWORD_HASHING_PARAMS.parallelism);
+    let app_config = app_config_builder.build()?;
+
+    let signup_notifier = MockSignUpNotifier::new();
+
+    let test_state = ApiTestState {
+        users: Some(vec![SomeUser::from(get_user(
+            EXISTING_USER_NAME,
+            VALID_PASSWORD,
+            &PASSWORD_HASHING_PARAMS,
+        ))]),
+        keypairs: Some(vec![SomeKeyPair::from(get_active_keypair())]),
+        signup_notifier: Some(Arc::new(signup_notifier.clone())),
+        ..Default::default()
+    };
+
+    run_api_test(|| test_action(signup_notifier), app_config, test_state)
+        .await
+        .map_err(|boxed| boxed.inner())
+}
+
+async fn test_action(signup_notifier: MockSignUpNotifier) -> Result<(), ErrorBoxed> {
+    // arrange
+    let client = Client::new();
+    signup_accepted(&client, EXISTING_USER_NAME).await?;
+
+    // act
+    let mut existing_user_durations = Vec::with_capacity(SAMPLES);
+    let mut new_user_durations = Vec::with_capacity(SAMPLES);
+    for sample in 0..SAMPLES {
+        existing_user_durations.push(signup_accepted(&client, EXISTING_USER_NAME).await?);
+        new_user_durations
+            .push(signup_accepted(&client, &format!("{NEW_USER_NAME_PREFIX}{sample}")).await?);
+    }
+
+    // assert
+    let existing_user_median = get_median(existing_user_durations);
+    let new_user_median = get_median(new_user_durations);
+    debug!("existing user median: {existing_user_median:?}, new user median: {new_user_median:?}");
+
+    let ratio = existing_user_median.max(new_user_median).as_secs_f64()
+        / existing_user_median.min(new_user_median).as_secs_f64();
+    if ratio > MAX_MEDIAN_RATIO {
+        return Err(ErrorBoxed::from_str(format!(
+            "signup response time reveals whether user exists: existing user median {existing_user_median:?}, new user median {new_user_median:?}"
+        )));
+    }
+
+    // notifications are delivered in the background after the responses
+    sleep(NOTIFICATIONS_DELIVERY_DELAY).await;
+    let notifications = signup_notifier.notifications().await;
+    let already_exists_count = notifications
+        .iter()
+        .filter(|notification| matches!(notification, SignUpNotification::UserAlreadyExists { user_name } if user_name.value() == EXISTING_USER_NAME))
+        .count();
+    let created_count = notifications
+        .iter()
+        .filter(|notification| matches!(notification, SignUpNotification::UserCreated { .. }))
+        .count();
+    if already_exists_count != SAMPLES + 1 || created_count != SAMPLES {
+        return Err(ErrorBoxed::from_str(format!(
+            "signup outcomes were not delivered out of band: {notifications:?}"
+        )));
+    }
+
+    Ok(())
+}
+
+/// Returns response time, fails if the response is not a uniform accepted response
+async fn signup_accepted(client: &Client, user_name: &str) -> Result<Duration, ErrorBoxed> {
+    let signup_request_proto = SignUpRequestProto {
+        user_name: user_name.to_string(),
+        password: VALID_PASSWORD.to_string(),
+        audiences: vec![],
+    };
+    let mut request_payload = Vec::new();
+    signup_request_proto.encode(&mut request_payload)?;
+
+    let start = Instant::now();
+    let response = client
+        .post(format!("http://{SERVER_ADDR}/{ENDPOINT}"))
+        .header(CONTENT_TYPE, "application/x-protobuf")
+        .header(CLIENT_TYPE_HEADER_NAME, CLIENT_TYPE_PC_HEADER_VALUE)
+        .body(request_payload)
+        .send()
+        .await?;
+    let status = response.status();
+    let has_session_header = response.headers().contains_key(SESSION_HEADER_NAME);
+    let response_payload = response.bytes().await?;
+    let duration = start.elapsed();
+
+    let signup_response_proto = SignUpResponseProto::decode(response_payload)?;
+    let accepted = sign_up_response_proto::Result::Accepted(SignUpAcceptedResponseProto {});
+    if status != StatusCode::ACCEPTED
+        || has_session_header
+        || signup_response_proto.result != Some(accepted)
+    {
+        return Err(ErrorBoxed::from_str(format!(
+            "expected uniform accepted response for user {user_name}, got {status}: {:?}",
+            signup_response_proto.result
+        )));
+    }
+
+    Ok(duration)
+}
+
+fn get_median(mut durations: Vec<Duration>) -> Duration {
+    durations.sort();
+    durations[durations.len() / 2]
+}
@@ -43,9 +43,8 @@ async fn valid_data_no_existing_user() -> Result<(), Box<dyn Error>> {
     let active_keypair = get_active_keypair();
 
     let test_state = ApiTestState {
-        users: None,
-        sessions: None,
         keypairs: Some(vec![SomeKeyPair::from(active_keypair)]),
+        ..Default::default()
     };
 
     run_api_test(test_action, app_config, test_state)
@@ -61,6 +60,7 @@ async fn test_action() -> Result<(), ErrorBoxed> {
     let signup_request_proto = SignUpRequestProto {
         user_name: VALID_USER_NAME.to_string(),
         password: VALID_PASSWORD.to_string(),
+        audiences: vec![],
     };
     let mut request_payload = Vec::new();
     signup_request_proto.encode(&mut request_payload)?;
@@ -129,6 +129,16 @@ async fn test_action() -> Result<(), ErrorBoxed> {
                 "got error code from api: {error_code}"
             )));
         }
+        sign_up_response_proto::Result::PasswordPolicyViolations(violations) => {
+            return Err(ErrorBoxed::from_str(format!(
+                "got password policy violations from api: {violations:?}"
+            )));
+        }
+        sign_up_response_proto::Result::Accepted(_) => {
+            return Err(ErrorBoxed::from_str(
+                "got accepted response from api without user enumeration protection",
+            ));
+        }
     };
 
     success_signup_response_proto
@@ -0,0 +1,129 @@
+use std::{borrow::Cow, env, error::Error, path::PathBuf};
+
+use nimbus_auth_application::services::keypair_repository::KeyPairRepository;
+use nimbus_auth_domain::entities::{Entity, keypair::SomeKeyPair};
+use nimbus_auth_infrastructure::services_implementations::filesystem_inmemory_cached_keypair_repository::FileSystemInMemoryCachedKeyPairRepository;
+use nimbus_auth_shared::types::AccessTokenExpirationSeconds;
+use nimbus_auth_tests::utils::get_active_keypair;
+use time::OffsetDateTime;
+use ulid::Ulid;
+
+#[tokio::test]
+async fn rotated_keypairs_are_restored_after_restart() -> Result<(), Box<dyn Error>> {
+    // arrange
+    let keypairs_location = get_keypairs_location();
+    let keypair_repository =
+        FileSystemInMemoryCachedKeyPairRepository::init(&keypairs_location).await?;
+    let keypair = get_active_keypair();
+    let keypair_id = keypair.id().to_string();
+    keypair_repository
+        .save(SomeKeyPair::Active(Cow::Borrowed(&keypair)))
+        .await?;
+
+    // act
+    let transactional_keypair_repository = keypair_repository.start_transaction().await?;
+    let (transactional_keypair_repository, active_keypair) =
+        transactional_keypair_repository.get_active().await?;
+    let (expiring_keypair, new_active_keypair) = active_keypair
+        .expect("key pair should have been active")
+        .rotate(
+            get_active_keypair().value().clone(),
+            OffsetDateTime::now_utc(),
+            AccessTokenExpirationSeconds(60),
+        );
+    let (transactional_keypair_repository, _) = transactional_keypair_repository
+        .save(SomeKeyPair::Expiring(Cow::Borrowed(&expiring_keypair)))
+        .await?;
+    let (transactional_keypair_repository, _) = transactional_keypair_repository
+        .save(SomeKeyPair::Active(Cow::Borrowed(&new_active_keypair)))
+        .await?;
+    transactional_keypair_repository.commit().await?;
+    let restarted_keypair_repository =
+        FileSystemInMemoryCachedKeyPairRepository::init(&keypairs_location).await?;
+
+    // assert
+    let active_keypair = restarted_keypair_repository
+        .get_active()
+        .await?
+        .expect("key pair should have been active");
+    assert_eq!(active_keypair.id(), new_active_keypair.id());
+    assert_eq!(
+        active_keypair.value().public_key_pem(),
+        new_active_keypair.value().public_key_pem()
+    );
+    match restarted_keypair_repository
+        .get_by_id(keypair.id().as_other_entity_ref())
+        .await?
+    {
+        Some(SomeKeyPair::Expiring(restored_keypair)) => {
+            assert_eq!(restored_keypair.id().to_string(), keypair_id);
+            assert_eq!(
+                restored_keypair.value().public_key_pem(),
+                keypair.value().public_key_pem()
+            );
+        }
+        _ => panic!("rotated key pair should have been expiring"),
+    }
+    assert_eq!(restarted_keypair_repository.get_verifying().await?.len(), 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn keypair_revoked_by_another_process_is_seen_without_restart() -> Result<(), Box<dyn Error>>
+{
+    // arrange
+    let keypairs_location = get_keypairs_location();
+    let keypair_repository =
+        FileSystemInMemoryCachedKeyPairRepository::init(&keypairs_location).await?;
+    let keypair = get_active_keypair();
+    keypair_repository
+        .save(SomeKeyPair::Active(Cow::Borrowed(&keypair)))
+        .await?;
+    let other_keypair_repository =
+        FileSystemInMemoryCachedKeyPairRepository::init(&keypairs_location).await?;
+
+    // act
+    let revoked_keypair = keypair.clone().revoke(OffsetDateTime::now_utc());
+    other_keypair_repository
+        .save(SomeKeyPair::Revoked(Cow::Borrowed(&revoked_keypair)))
+        .await?;
+
+    // assert
+    assert!(matches!(
+        keypair_repository
+            .get_by_id(keypair.id().as_other_entity_ref())
+            .await?,
+        Some(SomeKeyPair::Revoked(_))
+    ));
+    assert!(keypair_repository.get_active().await?.is_none());
+    assert!(keypair_repository.get_verifying().await?.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn revoked_keypair_is_not_saved_unless_stored() -> Result<(), Box<dyn Error>> {
+    // arrange
+    let keypairs_location = get_keypairs_location();
+    let keypair_repository =
+        FileSystemInMemoryCachedKeyPairRepository::init(&keypairs_location).await?;
+    let revoked_keypair = get_active_keypair().revoke(OffsetDateTime::now_utc());
+
+    // act
+    let result = keypair_repository
+        .save(SomeKeyPair::Revoked(Cow::Borrowed(&revoked_keypair)))
+        .await;
+
+    // assert
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+/// Each test gets a location of its own
+fn get_keypairs_location() -> PathBuf {
+    env::temp_dir()
+        .join("nimbus-auth-tests")
+        .join(Ulid::new().to_string())
+}
@@ -0,0 +1,46 @@
+use std::{error::Error, sync::Arc};
+
+use nimbus_auth_application::services::group_repository::GroupRepository;
+use nimbus_auth_domain::entities::{
+    Entity,
+    group::{Group, specifications::NewGroupSpecification, value_objects::group_name::GroupName},
+};
+use nimbus_auth_infrastructure::services_implementations::postgres_group_repository::PostgresGroupRepository;
+
+use crate::services::TestDatabase;
+
+const GROUP_NAME: &str = "billing";
+
+#[tokio::test]
+async fn same_named_groups_are_kept_apart_in_realms() -> Result<(), Box<dyn Error>> {
+    // arrange
+    let test_database = TestDatabase::start().await.map_err(|boxed| boxed.inner())?;
+    let group_repository =
+        PostgresGroupRepository::new(Arc::clone(&test_database.database), &test_database.realm);
+    let other_realm_group_repository = PostgresGroupRepository::new(
+        Arc::clone(&test_database.database),
+        &format!("{}-other", test_database.realm),
+    );
+    let group = Group::new(NewGroupSpecification {
+        name: GroupName::from(GROUP_NAME)?,
+    });
+    let other_realm_group = Group::new(NewGroupSpecification {
+        name: GroupName::from(GROUP_NAME)?,
+    });
+
+    // act
+    group_repository.save(&group).await?;
+    other_realm_group_repository
+        .save(&other_realm_group)
+        .await?;
+    let groups = group_repository.list().await?;
+    let other_realm_groups = other_realm_group_repository.list().await?;
+
+    // assert
+    assert_eq!(groups.len(), 1);
+    assert_eq!(groups[0].id(), group.id());
+    assert_eq!(other_realm_groups.len(), 1);
+    assert_eq!(other_realm_groups[0].id(), other_realm_group.id());
+
+    Ok(())
+}
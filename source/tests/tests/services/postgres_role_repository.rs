@@ -0,0 +1,82 @@
+use std::{collections::BTreeSet, error::Error};
+
+use nimbus_auth_application::services::role_repository::RoleRepository;
+use nimbus_auth_domain::entities::{
+    Entity,
+    role::{
+        Role,
+        specifications::NewRoleSpecification,
+        value_objects::{permission::Permission, role_name::RoleName},
+    },
+};
+use nimbus_auth_infrastructure::services_implementations::postgres_role_repository::PostgresRoleRepository;
+use nimbus_auth_shared::constants::AUTH_PERMISSIONS;
+
+use crate::services::TestDatabase;
+
+const ROLE_NAME: &str = "billing";
+const PERMISSION: &str = "billing:invoices:read";
+
+#[tokio::test]
+async fn built_in_roles_are_created_in_realm() -> Result<(), Box<dyn Error>> {
+    // arrange
+    let test_database = TestDatabase::start().await.map_err(|boxed| boxed.inner())?;
+
+    // act
+    let role_repository =
+        PostgresRoleRepository::init(test_database.database.clone(), &test_database.realm).await?;
+    let roles = role_repository
+        .get_by_names(&[RoleName::default_role(), RoleName::admin()])
+        .await?;
+
+    // assert
+    assert_eq!(roles.len(), 2);
+    let admin_role = roles
+        .iter()
+        .find(|role| role.name() == &RoleName::admin())
+        .expect("admin role should have been created");
+    assert_eq!(admin_role.permissions().len(), AUTH_PERMISSIONS.len());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn same_named_roles_are_kept_apart_in_realms() -> Result<(), Box<dyn Error>> {
+    // arrange
+    let test_database = TestDatabase::start().await.map_err(|boxed| boxed.inner())?;
+    let role_repository =
+        PostgresRoleRepository::init(test_database.database.clone(), &test_database.realm).await?;
+    let other_realm_role_repository = PostgresRoleRepository::init(
+        test_database.database.clone(),
+        &format!("{}-other", test_database.realm),
+    )
+    .await?;
+    let role = Role::new(NewRoleSpecification {
+        name: RoleName::from(ROLE_NAME)?,
+        permissions: BTreeSet::from([Permission::from(PERMISSION)?]),
+    });
+    let other_realm_role = Role::new(NewRoleSpecification {
+        name: RoleName::from(ROLE_NAME)?,
+        permissions: BTreeSet::new(),
+    });
+
+    // act
+    role_repository.save(&role).await?;
+    other_realm_role_repository.save(&other_realm_role).await?;
+    let restored_role = role_repository
+        .get_by_name(role.name())
+        .await?
+        .expect("role should have been restored");
+    let other_realm_restored_role = other_realm_role_repository
+        .get_by_name(role.name())
+        .await?
+        .expect("role of the other realm should have been restored");
+
+    // assert
+    assert_eq!(restored_role.id(), role.id());
+    assert_eq!(restored_role.permissions(), role.permissions());
+    assert_eq!(other_realm_restored_role.id(), other_realm_role.id());
+    assert!(other_realm_restored_role.permissions().is_empty());
+
+    Ok(())
+}
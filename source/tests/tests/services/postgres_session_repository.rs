@@ -0,0 +1,86 @@
+use std::{borrow::Cow, error::Error};
+
+use nimbus_auth_application::services::{
+    session_repository::SessionRepository, user_repository::UserRepository,
+};
+use nimbus_auth_domain::entities::{
+    Entity,
+    session::{SomeSession, specifications::NewSessionSpecification},
+    user::SomeUser,
+};
+use nimbus_auth_infrastructure::services_implementations::{
+    postgres_session_repository::PostgresSessionRepository,
+    postgres_user_repository::PostgresUserRepository,
+};
+use nimbus_auth_shared::types::{PasswordHashingParams, SessionExpirationSeconds};
+use nimbus_auth_tests::utils::get_user;
+use time::OffsetDateTime;
+
+use crate::services::TestDatabase;
+
+const USER_NAME: &str = "stanislau";
+const PASSWORD: &str = "StrongPassword123!";
+
+const PASSWORD_HASHING_PARAMS: PasswordHashingParams = PasswordHashingParams {
+    memory_cost_kib: 8 * 1024,
+    time_cost: 1,
+    parallelism: 1,
+};
+
+#[tokio::test]
+async fn revoked_session_is_restored_revoked() -> Result<(), Box<dyn Error>> {
+    // arrange
+    let test_database = TestDatabase::start().await.map_err(|boxed| boxed.inner())?;
+    let user_repository =
+        PostgresUserRepository::new(test_database.database.clone(), &test_database.realm);
+    let session_repository =
+        PostgresSessionRepository::new(test_database.database.clone(), &test_database.realm);
+    let user = get_user(USER_NAME, PASSWORD, &PASSWORD_HASHING_PARAMS);
+    user_repository.save(SomeUser::from(&user)).await?;
+    let current_time = OffsetDateTime::now_utc();
+    let session = SomeSession::new(NewSessionSpecification {
+        user_claims: user.claims().clone(),
+        client_id: None,
+        current_time,
+        expiration_seconds: SessionExpirationSeconds(60),
+    });
+    session_repository
+        .save(SomeSession::Active(Cow::Borrowed(&session)))
+        .await?;
+    let active_sessions = session_repository
+        .get_active_by_user_id(user.id(), current_time)
+        .await?;
+
+    // act
+    let transactional_session_repository = session_repository.start_transaction().await?;
+    let (transactional_session_repository, stored_session) = transactional_session_repository
+        .get_by_id(session.id().as_other_entity_ref())
+        .await?;
+    let Some(SomeSession::Active(stored_session)) = stored_session else {
+        panic!("saved session should have been active");
+    };
+    let revoked_session = stored_session.into_owned().revoke(current_time);
+    let (transactional_session_repository, _) = transactional_session_repository
+        .save(SomeSession::Revoked(Cow::Borrowed(&revoked_session)))
+        .await?;
+    transactional_session_repository.commit().await?;
+
+    // assert
+    assert_eq!(active_sessions.len(), 1);
+    assert_eq!(active_sessions[0].id(), session.id());
+    assert_eq!(active_sessions[0].user_claims().name(), user.name());
+    assert!(matches!(
+        session_repository
+            .get_by_id(session.id().as_other_entity_ref())
+            .await?,
+        Some(SomeSession::Revoked(_))
+    ));
+    assert!(
+        session_repository
+            .get_active_by_user_id(user.id(), current_time)
+            .await?
+            .is_empty()
+    );
+
+    Ok(())
+}
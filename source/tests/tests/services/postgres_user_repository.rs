@@ -0,0 +1,207 @@
+use std::{error::Error, sync::Arc};
+
+use nimbus_auth_application::services::{
+    group_repository::GroupRepository,
+    user_repository::{UserRepository, errors::UserRepositoryError},
+};
+use nimbus_auth_domain::entities::{
+    Entity,
+    group::{Group, specifications::NewGroupSpecification, value_objects::group_name::GroupName},
+    role::value_objects::role_name::RoleName,
+    user::{SomeUser, value_objects::user_attributes::UserAttributes},
+};
+use nimbus_auth_infrastructure::services_implementations::{
+    postgres_group_repository::PostgresGroupRepository,
+    postgres_user_repository::PostgresUserRepository,
+};
+use nimbus_auth_shared::types::{PasswordHashingParams, SigninLockoutPolicy, UserStatus};
+use nimbus_auth_tests::utils::{get_built_in_roles, get_user};
+use time::OffsetDateTime;
+
+use crate::services::TestDatabase;
+
+const USER_NAME: &str = "stanislau";
+const PASSWORD: &str = "StrongPassword123!";
+const SUSPENSION_REASON: &str = "suspicious activity";
+const GROUP_NAME: &str = "billing";
+
+const PASSWORD_HASHING_PARAMS: PasswordHashingParams = PasswordHashingParams {
+    memory_cost_kib: 8 * 1024,
+    time_cost: 1,
+    parallelism: 1,
+};
+
+#[tokio::test]
+async fn saved_user_is_restored_by_name() -> Result<(), Box<dyn Error>> {
+    // arrange
+    let test_database = TestDatabase::start().await.map_err(|boxed| boxed.inner())?;
+    let user_repository =
+        PostgresUserRepository::new(test_database.database.clone(), &test_database.realm);
+    let group = save_group(&test_database).await?;
+    // timestamps are stored with microseconds precision
+    let current_time =
+        OffsetDateTime::from_unix_timestamp(OffsetDateTime::now_utc().unix_timestamp())?;
+    let user = get_user(USER_NAME, PASSWORD, &PASSWORD_HASHING_PARAMS)
+        .with_roles(&get_built_in_roles())
+        .with_groups(std::slice::from_ref(&group))
+        .with_attributes(UserAttributes::from_json(r#"{"department":"billing"}"#)?)
+        .with_failed_signin_attempt(
+            current_time,
+            &SigninLockoutPolicy {
+                max_failed_attempts: 1,
+                ..Default::default()
+            },
+        )
+        .suspend(
+            SUSPENSION_REASON.to_string(),
+            Some(current_time + time::Duration::days(1)),
+        );
+    let user = SomeUser::from(user);
+
+    // act
+    user_repository.save(user.clone()).await?;
+    let restored_user = user_repository
+        .get_by_name(user.name())
+        .await?
+        .expect("user should have been restored");
+
+    // assert
+    assert_eq!(restored_user.id(), user.id());
+    assert_eq!(restored_user.name(), user.name());
+    assert_eq!(
+        restored_user.password_hash().value(),
+        user.password_hash().value()
+    );
+    assert_eq!(restored_user.roles(), user.roles());
+    assert!(!restored_user.permissions().is_empty());
+    assert_eq!(restored_user.groups().len(), 1);
+    assert_eq!(restored_user.groups()[0].id(), group.id());
+    assert_eq!(
+        restored_user.attributes().value(),
+        user.attributes().value()
+    );
+    assert_eq!(
+        restored_user.signin_lockout().failed_attempts(),
+        user.signin_lockout().failed_attempts()
+    );
+    assert_eq!(
+        restored_user.signin_lockout().locked_until(),
+        user.signin_lockout().locked_until()
+    );
+    assert_eq!(restored_user.status(), UserStatus::Suspended);
+    match restored_user {
+        SomeUser::Suspended(restored_user) => {
+            assert_eq!(restored_user.reason(), SUSPENSION_REASON);
+            assert_eq!(
+                restored_user.suspended_until(),
+                Some(current_time + time::Duration::days(1))
+            );
+        }
+        _ => panic!("user should have been restored suspended"),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn saved_user_replaces_previous_state() -> Result<(), Box<dyn Error>> {
+    // arrange
+    let test_database = TestDatabase::start().await.map_err(|boxed| boxed.inner())?;
+    let user_repository =
+        PostgresUserRepository::new(test_database.database.clone(), &test_database.realm);
+    let group = save_group(&test_database).await?;
+    let current_time =
+        OffsetDateTime::from_unix_timestamp(OffsetDateTime::now_utc().unix_timestamp())?;
+    let user = get_user(USER_NAME, PASSWORD, &PASSWORD_HASHING_PARAMS)
+        .with_roles(&get_built_in_roles())
+        .with_groups(std::slice::from_ref(&group));
+    user_repository.save(SomeUser::from(&user)).await?;
+    let user = user
+        .with_roles(&get_built_in_roles()[..1])
+        .with_groups(&[])
+        .delete(current_time);
+
+    // act
+    user_repository.save(SomeUser::from(&user)).await?;
+    let restored_user = user_repository
+        .get_by_id(user.id())
+        .await?
+        .expect("user should have been restored");
+
+    // assert
+    assert_eq!(
+        restored_user.roles().iter().collect::<Vec<_>>(),
+        vec![&RoleName::default_role()]
+    );
+    assert!(restored_user.groups().is_empty());
+    match restored_user {
+        SomeUser::Deleted(restored_user) => {
+            assert_eq!(restored_user.deleted_at(), current_time);
+        }
+        _ => panic!("user should have been restored deleted"),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn saved_user_is_not_restored_in_another_realm() -> Result<(), Box<dyn Error>> {
+    // arrange
+    let test_database = TestDatabase::start().await.map_err(|boxed| boxed.inner())?;
+    let user_repository =
+        PostgresUserRepository::new(test_database.database.clone(), &test_database.realm);
+    let other_realm_user_repository = PostgresUserRepository::new(
+        test_database.database.clone(),
+        &format!("{}-other", test_database.realm),
+    );
+    let user = SomeUser::from(get_user(USER_NAME, PASSWORD, &PASSWORD_HASHING_PARAMS));
+    user_repository.save(user.clone()).await?;
+
+    // act
+    let user_by_name = other_realm_user_repository.get_by_name(user.name()).await?;
+    let user_by_id = other_realm_user_repository.get_by_id(user.id()).await?;
+
+    // assert
+    assert!(user_by_name.is_none());
+    assert!(user_by_id.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn user_with_taken_name_is_not_saved() -> Result<(), Box<dyn Error>> {
+    // arrange
+    let test_database = TestDatabase::start().await.map_err(|boxed| boxed.inner())?;
+    let user_repository =
+        PostgresUserRepository::new(test_database.database.clone(), &test_database.realm);
+    let user = SomeUser::from(get_user(USER_NAME, PASSWORD, &PASSWORD_HASHING_PARAMS));
+    user_repository.save(user.clone()).await?;
+    let same_named_user = SomeUser::from(get_user(USER_NAME, PASSWORD, &PASSWORD_HASHING_PARAMS));
+
+    // act
+    let result = user_repository
+        .start_transaction()
+        .await?
+        .save(same_named_user)
+        .await;
+
+    // assert
+    assert!(matches!(result, Err(UserRepositoryError::UserNameIsTaken)));
+    let restored_user = user_repository
+        .get_by_name(user.name())
+        .await?
+        .expect("user should have been restored");
+    assert_eq!(restored_user.id(), user.id());
+
+    Ok(())
+}
+
+async fn save_group(test_database: &TestDatabase) -> Result<Group, Box<dyn Error>> {
+    let group_repository =
+        PostgresGroupRepository::new(Arc::clone(&test_database.database), &test_database.realm);
+    let group = Group::new(NewGroupSpecification {
+        name: GroupName::from(GROUP_NAME)?,
+    });
+    group_repository.save(&group).await?;
+    Ok(group)
+}
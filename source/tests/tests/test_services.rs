@@ -1 +1 @@
-
+mod services;
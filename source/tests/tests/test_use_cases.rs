@@ -1 +1 @@
-
+mod use_cases;
@@ -0,0 +1,115 @@
+use std::{error::Error, sync::Arc};
+
+use nimbus_auth_application::use_cases::{BootstrapAdminError, BootstrapAdminRequest};
+use nimbus_auth_domain::entities::{
+    Entity, role::value_objects::role_name::RoleName, user::SomeUser,
+};
+use nimbus_auth_tests::{
+    mocks::datastore::MockDatastore,
+    utils::{get_built_in_roles, get_user},
+};
+
+use crate::use_cases::{PASSWORD_HASHING_PARAMS, build_use_cases, find_user};
+
+const ADMIN_USER_NAME: &str = "administrator";
+const USER_NAME: &str = "stanislau";
+const PASSWORD: &str = "StrongPassword123!";
+
+#[tokio::test]
+async fn bootstrap_creates_admin() -> Result<(), Box<dyn Error>> {
+    // arrange
+    let datastore = Arc::new(MockDatastore::new(None, None, None, None));
+    let use_cases = build_use_cases(datastore.clone()).map_err(|boxed| boxed.inner())?;
+    let password_hash = get_password_hash();
+
+    // act
+    let response = use_cases
+        .bootstrap_admin(BootstrapAdminRequest {
+            user_name: ADMIN_USER_NAME,
+            password_hash: Some(&password_hash),
+        })
+        .await?;
+
+    // assert
+    assert!(response.is_created);
+    assert_eq!(response.user.name, ADMIN_USER_NAME);
+    let admin = find_user(&datastore, ADMIN_USER_NAME).expect("admin should have been saved");
+    assert!(admin.roles().contains(&RoleName::admin()));
+    assert!(admin.roles().contains(&RoleName::default_role()));
+    assert_eq!(admin.password_hash().value(), password_hash);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn bootstrap_promotes_existing_user() -> Result<(), Box<dyn Error>> {
+    // arrange
+    let user = get_user(USER_NAME, PASSWORD, &PASSWORD_HASHING_PARAMS);
+    let user_id = user.id().to_string();
+    let datastore = Arc::new(MockDatastore::new(
+        Some(vec![SomeUser::from(user)]),
+        None,
+        None,
+        None,
+    ));
+    let use_cases = build_use_cases(datastore.clone()).map_err(|boxed| boxed.inner())?;
+
+    // act
+    let response = use_cases
+        .bootstrap_admin(BootstrapAdminRequest {
+            user_name: USER_NAME,
+            password_hash: None,
+        })
+        .await?;
+
+    // assert
+    assert!(!response.is_created);
+    assert_eq!(response.user.id, user_id);
+    let admin = find_user(&datastore, USER_NAME).expect("user should have been kept");
+    assert!(admin.roles().contains(&RoleName::admin()));
+    assert!(admin.roles().contains(&RoleName::default_role()));
+    assert_eq!(datastore.users().len(), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn bootstrap_is_refused_once_admin_exists() -> Result<(), Box<dyn Error>> {
+    // arrange
+    let [_, admin_role] = get_built_in_roles();
+    let admin =
+        get_user(ADMIN_USER_NAME, PASSWORD, &PASSWORD_HASHING_PARAMS).with_roles(&[admin_role]);
+    let user = get_user(USER_NAME, PASSWORD, &PASSWORD_HASHING_PARAMS);
+    let datastore = Arc::new(MockDatastore::new(
+        Some(vec![SomeUser::from(admin), SomeUser::from(user)]),
+        None,
+        None,
+        None,
+    ));
+    let use_cases = build_use_cases(datastore.clone()).map_err(|boxed| boxed.inner())?;
+
+    // act
+    let result = use_cases
+        .bootstrap_admin(BootstrapAdminRequest {
+            user_name: USER_NAME,
+            password_hash: None,
+        })
+        .await;
+
+    // assert
+    assert!(matches!(
+        result,
+        Err(BootstrapAdminError::AdminAlreadyExists)
+    ));
+    let user = find_user(&datastore, USER_NAME).expect("user should have been kept");
+    assert!(!user.roles().contains(&RoleName::admin()));
+
+    Ok(())
+}
+
+fn get_password_hash() -> String {
+    get_user(ADMIN_USER_NAME, PASSWORD, &PASSWORD_HASHING_PARAMS)
+        .password_hash()
+        .value()
+        .to_string()
+}
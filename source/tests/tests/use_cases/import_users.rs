@@ -0,0 +1,192 @@
+use std::{env, error::Error, path::PathBuf, sync::Arc};
+
+use nimbus_auth_application::use_cases::{
+    ImportUserRecord, ImportUserRejection, ImportUsersError, ImportUsersRequest,
+};
+use nimbus_auth_domain::entities::{
+    role::value_objects::role_name::RoleName,
+    user::{SomeUser, value_objects::password_hash::errors::PasswordHashError},
+};
+use nimbus_auth_entrypoint::commands::import_users::run_import_users;
+use nimbus_auth_tests::{mocks::datastore::MockDatastore, utils::get_user};
+use tokio::fs;
+use ulid::Ulid;
+
+use crate::use_cases::{PASSWORD_HASHING_PARAMS, build_use_cases, find_user};
+
+const PASSWORD: &str = "StrongPassword123!";
+const BCRYPT_HASH: &str = "$2b$12$LQv3c1yqBWVHxkd0LHAkCOYz6TtxMQJqhN8/LewdBPj/RK.s5uO9G";
+const UNSUPPORTED_HASH: &str = "$md5$c2FsdHNhbHQ$aGFzaGhhc2hoYXNoaGFzaA";
+
+#[tokio::test]
+async fn import_command_creates_users_from_jsonl() -> Result<(), Box<dyn Error>> {
+    // arrange
+    let datastore = Arc::new(MockDatastore::new(None, None, None, None));
+    let use_cases = build_use_cases(datastore.clone()).map_err(|boxed| boxed.inner())?;
+    let argon2_hash = get_argon2_hash();
+    let content = format!(
+        "{{\"user_name\":\"argon2user\",\"password_hash\":\"{argon2_hash}\"}}\n\n{{\"user_name\":\"bcryptuser\",\"password_hash\":\"{BCRYPT_HASH}\"}}\n"
+    );
+    let input_path = write_import_file("jsonl", &content).await?;
+
+    // act
+    let result = run_import_users(
+        &use_cases,
+        &["jsonl".to_string(), input_path.display().to_string()],
+    )
+    .await;
+    fs::remove_file(&input_path).await?;
+
+    // assert
+    result?;
+    assert_imported_user(&datastore, "argon2user", &argon2_hash);
+    assert_imported_user(&datastore, "bcryptuser", BCRYPT_HASH);
+    assert_eq!(datastore.users().len(), 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn import_command_creates_users_from_csv() -> Result<(), Box<dyn Error>> {
+    // arrange
+    let datastore = Arc::new(MockDatastore::new(None, None, None, None));
+    let use_cases = build_use_cases(datastore.clone()).map_err(|boxed| boxed.inner())?;
+    let argon2_hash = get_argon2_hash();
+    // argon2 params are comma separated, so the hash is quoted
+    let content = format!(
+        "user_name,password_hash\nargon2user,\"{argon2_hash}\"\nbcryptuser,{BCRYPT_HASH}\n"
+    );
+    let input_path = write_import_file("csv", &content).await?;
+
+    // act
+    let result = run_import_users(
+        &use_cases,
+        &["csv".to_string(), input_path.display().to_string()],
+    )
+    .await;
+    fs::remove_file(&input_path).await?;
+
+    // assert
+    result?;
+    assert_imported_user(&datastore, "argon2user", &argon2_hash);
+    assert_imported_user(&datastore, "bcryptuser", BCRYPT_HASH);
+    assert_eq!(datastore.users().len(), 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn duplicate_in_import_rolls_back_whole_import() -> Result<(), Box<dyn Error>> {
+    // arrange
+    let datastore = Arc::new(MockDatastore::new(None, None, None, None));
+    let use_cases = build_use_cases(datastore.clone()).map_err(|boxed| boxed.inner())?;
+    let users = [
+        get_record("firstuser", BCRYPT_HASH),
+        get_record("duplicateuser", BCRYPT_HASH),
+        get_record("duplicateuser", BCRYPT_HASH),
+    ];
+
+    // act
+    let result = use_cases
+        .import_users(ImportUsersRequest { users: &users })
+        .await;
+
+    // assert
+    assert!(matches!(
+        result,
+        Err(ImportUsersError::DuplicateInImport { user_name }) if user_name == "duplicateuser"
+    ));
+    assert!(datastore.users().is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn existing_user_rolls_back_whole_import() -> Result<(), Box<dyn Error>> {
+    // arrange
+    let existing_user = get_user("existinguser", PASSWORD, &PASSWORD_HASHING_PARAMS);
+    let datastore = Arc::new(MockDatastore::new(
+        Some(vec![SomeUser::from(existing_user)]),
+        None,
+        None,
+        None,
+    ));
+    let use_cases = build_use_cases(datastore.clone()).map_err(|boxed| boxed.inner())?;
+    let users = [
+        get_record("firstuser", BCRYPT_HASH),
+        get_record("existinguser", BCRYPT_HASH),
+    ];
+
+    // act
+    let result = use_cases
+        .import_users(ImportUsersRequest { users: &users })
+        .await;
+
+    // assert
+    assert!(matches!(
+        result,
+        Err(ImportUsersError::UserAlreadyExists { user_name }) if user_name == "existinguser"
+    ));
+    assert_eq!(datastore.users().len(), 1);
+    assert!(find_user(&datastore, "firstuser").is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn unsupported_hash_is_rejected() -> Result<(), Box<dyn Error>> {
+    // arrange
+    let datastore = Arc::new(MockDatastore::new(None, None, None, None));
+    let use_cases = build_use_cases(datastore.clone()).map_err(|boxed| boxed.inner())?;
+    let users = [
+        get_record("bcryptuser", BCRYPT_HASH),
+        get_record("md5user", UNSUPPORTED_HASH),
+    ];
+
+    // act
+    let response = use_cases
+        .import_users(ImportUsersRequest { users: &users })
+        .await?;
+
+    // assert
+    assert_eq!(response.imported_count, 1);
+    assert_eq!(response.rejected.len(), 1);
+    assert_eq!(response.rejected[0].user_name, "md5user");
+    assert!(matches!(
+        &response.rejected[0].reason,
+        ImportUserRejection::InvalidPasswordHash(PasswordHashError::UnsupportedAlgorithm { algorithm })
+            if algorithm == "md5"
+    ));
+    assert!(find_user(&datastore, "bcryptuser").is_some());
+    assert!(find_user(&datastore, "md5user").is_none());
+
+    Ok(())
+}
+
+fn get_argon2_hash() -> String {
+    get_user("argon2user", PASSWORD, &PASSWORD_HASHING_PARAMS)
+        .password_hash()
+        .value()
+        .to_string()
+}
+
+fn get_record(user_name: &str, password_hash: &str) -> ImportUserRecord {
+    ImportUserRecord {
+        user_name: user_name.to_string(),
+        password_hash: password_hash.to_string(),
+    }
+}
+
+async fn write_import_file(extension: &str, content: &str) -> Result<PathBuf, Box<dyn Error>> {
+    let input_path =
+        env::temp_dir().join(format!("nimbus_auth_import_{}.{extension}", Ulid::new()));
+    fs::write(&input_path, content).await?;
+    Ok(input_path)
+}
+
+fn assert_imported_user(datastore: &MockDatastore, user_name: &str, password_hash: &str) {
+    let user = find_user(datastore, user_name)
+        .unwrap_or_else(|| panic!("user {user_name} should have been imported"));
+    assert_eq!(user.password_hash().value(), password_hash);
+    assert!(user.roles().contains(&RoleName::default_role()));
+}
@@ -0,0 +1,112 @@
+use std::{path::PathBuf, str::FromStr, sync::Arc};
+
+use nimbus_auth_application::use_cases::{UseCases, UseCasesConfig, UseCasesServices};
+use nimbus_auth_domain::{
+    entities::user::SomeUser,
+    value_objects::{
+        breached_passwords_filter::BreachedPasswordsFilter, password_peppers::PasswordPeppers,
+    },
+};
+use nimbus_auth_infrastructure::services_implementations::{
+    os_random_service::OsRandomService, os_time_service::OsTimeService,
+};
+use nimbus_auth_shared::{
+    config::{AppConfigBuilder, AppConfigRequiredOptions},
+    errors::ErrorBoxed,
+    types::{PasswordHashingParams, Realm},
+};
+use nimbus_auth_tests::mocks::{
+    datastore::MockDatastore,
+    services::{
+        api_key_repository::MockApiKeyRepository,
+        authorization_code_repository::MockAuthorizationCodeRepository,
+        device_authorization_repository::MockDeviceAuthorizationRepository,
+        external_identity_repository::MockExternalIdentityRepository,
+        federated_authorization_repository::MockFederatedAuthorizationRepository,
+        group_repository::MockGroupRepository,
+        impersonation_repository::MockImpersonationRepository,
+        keypair_repository::MockKeyPairRepository,
+        oauth_client_repository::MockOAuthClientRepository, role_repository::MockRoleRepository,
+        session_repository::MockSessionRepository, user_repository::MockUserRepository,
+    },
+};
+
+mod bootstrap_admin;
+mod import_users;
+
+const KEYPAIRS_STORE_PATH: &str = "/temp";
+const POSTGRES_DB_URL: &str =
+    "postgresql://<username>:<password>@<host>:<port>/<database>?<options>";
+
+const PASSWORD_HASHING_PARAMS: PasswordHashingParams = PasswordHashingParams {
+    memory_cost_kib: 8 * 1024,
+    time_cost: 1,
+    parallelism: 1,
+};
+
+/// Use cases of the default realm over the given datastore, as commands of the entrypoint get them
+fn build_use_cases(datastore: Arc<MockDatastore>) -> Result<UseCases, ErrorBoxed> {
+    let mut app_config_builder = AppConfigBuilder::new(AppConfigRequiredOptions {
+        server_addr: String::new(),
+        keypairs_store_path: PathBuf::from_str(KEYPAIRS_STORE_PATH)?,
+        postgres_db_url: POSTGRES_DB_URL.to_string(),
+    });
+    app_config_builder
+        .with_password_hash_memory_cost_kib(PASSWORD_HASHING_PARAMS.memory_cost_kib)
+        .with_password_hash_time_cost(PASSWORD_HASHING_PARAMS.time_cost)
+        .with_password_hash_parallelism(PASSWORD_HASHING_PARAMS.parallelism);
+    let config = app_config_builder.build()?;
+
+    let use_cases_config = UseCasesConfig {
+        session_expiration_seconds: config.session_expiration_seconds(),
+        access_token_expiration_seconds: config.access_token_expiration_seconds(),
+        access_token_max_groups: config.access_token_max_groups(),
+        access_token_attributes: config.access_token_attributes().clone(),
+        realm: Realm::default(),
+        password_policy: config.password_policy().clone(),
+        password_hashing_params: config.password_hashing_params(),
+        password_peppers: Arc::new(PasswordPeppers::empty()),
+        breached_passwords_filter: Arc::new(BreachedPasswordsFilter::empty()),
+        signin_lockout_policy: config.signin_lockout_policy(),
+        dummy_password_hash: None,
+        oauth_device_verification_uri: None,
+    };
+
+    let use_cases_services = UseCasesServices {
+        user_repository: Arc::new(MockUserRepository::new(datastore.clone())),
+        role_repository: Arc::new(MockRoleRepository::new(datastore.clone())),
+        group_repository: Arc::new(MockGroupRepository::new(datastore.clone())),
+        session_repository: Arc::new(MockSessionRepository::new(datastore.clone())),
+        keypair_repository: Arc::new(MockKeyPairRepository::new(datastore.clone())),
+        oauth_client_repository: Arc::new(MockOAuthClientRepository::new(datastore.clone())),
+        authorization_code_repository: Arc::new(MockAuthorizationCodeRepository::new(
+            datastore.clone(),
+        )),
+        device_authorization_repository: Arc::new(MockDeviceAuthorizationRepository::new(
+            datastore.clone(),
+        )),
+        api_key_repository: Arc::new(MockApiKeyRepository::new(datastore.clone())),
+        impersonation_repository: Arc::new(MockImpersonationRepository::new(datastore.clone())),
+        identity_providers: Default::default(),
+        external_identity_repository: Arc::new(MockExternalIdentityRepository::new(
+            datastore.clone(),
+        )),
+        federated_authorization_repository: Arc::new(MockFederatedAuthorizationRepository::new(
+            datastore,
+        )),
+        time_service: Arc::new(OsTimeService::new()),
+        random_service: Arc::new(OsRandomService::new()),
+        legacy_authenticator: None,
+        signup_notifier: None,
+    };
+
+    Ok(UseCases::new(use_cases_config, use_cases_services))
+}
+
+fn find_user(datastore: &MockDatastore, user_name: &str) -> Option<SomeUser<'static>> {
+    datastore
+        .users()
+        .iter()
+        .find(|entry| entry.name().value() == user_name)
+        .map(|entry| entry.value().clone())
+}